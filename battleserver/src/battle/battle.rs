@@ -18,15 +18,17 @@ use crate::room::map_data::TileMap;
 use crate::room::{RoomState, RoomType, MEMBER_MAX};
 use crate::task_timer::{Task, TaskCmd};
 use crossbeam::channel::Sender;
-use log::error;
+use log::{error, warn};
 use std::borrow::BorrowMut;
 use std::collections::HashMap;
+use std::str::FromStr;
 use tools::protos::base::{ActionUnitPt, SummaryDataPt};
 use tools::templates::skill_temp::SkillTemp;
 
 use super::battle_cter::BattleCharacter;
 use super::battle_enum::skill_type::{ORDER_MINON_ATTACK, SUMMON_MINON, TRANSFORM};
 use super::battle_enum::BattlePlayerState;
+use super::battle_event::{BattleEventType, BattleEvents};
 use super::battle_player::BattlePlayer;
 use super::battle_skill::{order_minon_attack, summon_minon, transform};
 
@@ -58,14 +60,18 @@ type SkillFn = HashMap<
 
 #[derive(Clone, Debug, Default)]
 pub struct SummaryUser {
-    pub user_id: u32,         //玩家id
-    pub name: String,         //名称
-    pub cter_temp_id: u32,    //角色id
-    pub grade: u8,            //玩家等级
-    pub summary_rank: u8,     //玩家当局排名
-    pub league: League,       //段位数据
-    pub reward_score: i32,    //当局奖励积分
-    pub push_to_server: bool, //是否推送过给游戏服务器
+    pub user_id: u32,                //玩家id
+    pub name: String,                //名称
+    pub cter_temp_id: u32,           //角色id
+    pub grade: u8,                   //玩家等级
+    pub summary_rank: u8,            //玩家当局排名
+    pub league: League,              //段位数据
+    pub reward_score: i32,           //当局奖励积分
+    pub kills: u32,                  //当局击杀数
+    pub battle_events: BattleEvents, //当局战斗事件累计
+    pub push_to_server: bool,        //是否推送过给游戏服务器
+    pub team_id: u8,                 //队伍id
+    pub is_punishment: bool,         //是否逃跑惩罚
 }
 
 impl From<&BattlePlayer> for SummaryUser {
@@ -76,6 +82,9 @@ impl From<&BattlePlayer> for SummaryUser {
         sp.cter_temp_id = battle_player.get_cter_temp_id();
         sp.grade = battle_player.grade;
         sp.league = battle_player.league.clone();
+        sp.kills = battle_player.kills;
+        sp.battle_events = battle_player.battle_events.clone();
+        sp.team_id = battle_player.team_id;
         sp
     }
 }
@@ -88,6 +97,10 @@ impl Into<SummaryDataPt> for SummaryUser {
         smp.rank = self.summary_rank as u32;
         smp.grade = self.grade as u32;
         smp.reward_score = self.reward_score;
+        smp.kills = self.kills;
+        for event in self.battle_events.into_pts() {
+            smp.events.push(event);
+        }
         let lp = self.league.into_pt();
         smp.set_league(lp);
         smp
@@ -120,7 +133,7 @@ pub struct BattleData {
     pub room_type: RoomType,
     pub tile_map: TileMap,                         //地图数据
     pub next_turn_index: usize,                    //下个turn的下标,从0-4循环
-    pub turn_orders: [u32; MEMBER_MAX],            //turn行动队列，里面放玩家id
+    pub turn_orders: Vec<u32>,                     //turn行动队列，里面放玩家id
     pub reflash_map_turn: Option<usize>,           //刷新地图时的turn下标
     pub battle_player: HashMap<u32, BattlePlayer>, //玩家战斗数据
     pub cter_player: HashMap<u32, u32>,            //角色对应玩家,cter_id user_id
@@ -129,6 +142,7 @@ pub struct BattleData {
     pub leave_user: (u32, bool),                   //离开玩家id,是否惩罚
     pub leave_map: HashMap<u32, i8>,               //段位快照
     pub turn_limit_time: u64,                      //战斗turn时间限制
+    pub turn_reserve: Option<(u32, i64)>,          //正在消耗备用时间的玩家id和开始消耗的时间
     pub turn: u32,                                 //turn
     pub round: u16,                                //round
     pub cycle_count: u32,                          //周期
@@ -140,13 +154,11 @@ pub struct BattleData {
     pub tcp_sender: Sender<Vec<u8>>,               //sender
     pub cter_id: u32,                              //角色id动态id,从101开始
     pub state: RoomState,
+    pub world_boss_damage: HashMap<u32, u64>, //对世界boss造成的伤害,user_id damage
 }
 
 tools::get_mut_ref!(BattleData);
 
-unsafe impl Send for BattleData {}
-unsafe impl Sync for BattleData {}
-
 impl BattleData {
     pub fn get_team_id(&self, cter_id: u32) -> u8 {
         let cter = self.get_battle_cter(cter_id, false).unwrap();
@@ -218,6 +230,8 @@ impl BattleData {
             sp.cter_temp_id = battle_player.get_cter_temp_id();
             sp.league = battle_player.league.clone();
             sp.grade = battle_player.grade;
+            sp.kills = battle_player.kills;
+            sp.battle_events = battle_player.battle_events.clone();
             if battle_player.is_world_boss {
                 if allive_is_world_boss {
                     sp.grade = battle_player.grade + 1;
@@ -245,6 +259,7 @@ impl BattleData {
                     if sp.grade > 2 {
                         sp.grade = 2;
                     }
+                    sp.battle_events.add(BattleEventType::DefeatWorldBoss, 1);
                 }
             }
             if self.summary_vec.get(sp.summary_rank as usize).is_none() {
@@ -255,6 +270,126 @@ impl BattleData {
         }
     }
 
+    ///组队模式结算,同队共享胜负
+    /// 胜方排名0,败方排名1,逃跑的按失败算
+    pub fn summary_for_team(&mut self) {
+        self.summary_vec.clear();
+        //两个队伍
+        for _ in 0..2 {
+            self.summary_vec.push(vec![]);
+        }
+        let mut sp_v: Vec<SummaryUser> = self.summary_vec_temp.drain(..).collect();
+        for battle_player in self.battle_player.values() {
+            if battle_player.is_died() {
+                continue;
+            }
+            sp_v.push(SummaryUser::from(battle_player));
+        }
+        //逃跑的玩家已经不在battle_player里了,队伍从结算数据里取
+        let team_map: HashMap<u32, u8> = sp_v.iter().map(|x| (x.user_id, x.team_id)).collect();
+        //活着的队伍就是胜方,全灭了就都算输
+        let win_team_id = self
+            .battle_player
+            .values()
+            .find(|x| !x.is_died())
+            .map(|x| x.team_id)
+            .unwrap_or(0);
+
+        let is_match_type = self.room_type.is_match_type();
+        let summary_award_temp_mgr = crate::TEMPLATES.summary_award_temp_mgr();
+        let con_temp_mgr = crate::TEMPLATES.constant_temp_mgr();
+        let mut max_grade = 2;
+        if let Some(res) = con_temp_mgr.temps.get("max_grade") {
+            match u8::from_str(res.value.as_str()) {
+                Ok(res) => max_grade = res,
+                Err(e) => warn!("{:?}", e),
+            }
+        }
+        let mut punishment_score = -50;
+        if let Some(res) = con_temp_mgr.temps.get("punishment_summary") {
+            match f64::from_str(res.value.as_str()) {
+                Ok(res) => punishment_score = res as i32,
+                Err(e) => warn!("{:?}", e),
+            }
+        }
+        //胜方取1,2名的平均分,败方取3,4名的平均分
+        let get_base_score = |ranks: [u8; 2]| -> i32 {
+            let mut score = 0;
+            for &rank in ranks.iter() {
+                match summary_award_temp_mgr.get_score_by_rank(rank) {
+                    Ok(res) => score += res as i32,
+                    Err(e) => warn!("{:?}", e),
+                }
+            }
+            score / ranks.len() as i32
+        };
+        let win_base_score = get_base_score([1, 2]);
+        let lose_base_score = get_base_score([3, 4]);
+
+        for mut sp in sp_v {
+            let is_win = !sp.is_punishment && win_team_id > 0 && sp.team_id == win_team_id;
+            sp.summary_rank = if is_win { 0 } else { 1 };
+            //不是匹配房间不结算段位，积分
+            if is_match_type {
+                let reward_score;
+                if sp.is_punishment {
+                    reward_score = punishment_score;
+                    sp.grade = sp.grade.saturating_sub(1).max(1);
+                } else {
+                    let base_score = if is_win {
+                        sp.grade = (sp.grade + 1).min(max_grade);
+                        win_base_score
+                    } else {
+                        sp.grade = sp.grade.saturating_sub(1).max(1);
+                        lose_base_score
+                    };
+                    //计算浮动分,对面队伍平均段位比自己高才加分
+                    let self_league = sp.league.get_league_id() as i32;
+                    let mut enemy_league = 0;
+                    let mut enemy_count = 0;
+                    for (id, league_id) in self.leave_map.iter() {
+                        let team_id = team_map.get(id);
+                        if team_id.is_none() || *team_id.unwrap() == sp.team_id {
+                            continue;
+                        }
+                        enemy_count += 1;
+                        enemy_league += *league_id as i32;
+                    }
+                    let mut unstable = 0;
+                    if enemy_count > 0 {
+                        enemy_league /= enemy_count;
+                        if enemy_league > self_league {
+                            unstable = (enemy_league - self_league) * 10;
+                        }
+                    }
+                    reward_score = base_score + unstable;
+                }
+                sp.reward_score = reward_score;
+                let res = sp.league.update_score(reward_score);
+                if res == 0 {
+                    sp.reward_score = 0;
+                }
+            }
+            let v = self.summary_vec.get_mut(sp.summary_rank as usize).unwrap();
+            v.push(sp);
+        }
+    }
+
+    ///是否是友军伤害,只有组队模式才有
+    pub fn is_friendly_fire(&self, from_cter_id: u32, target_cter_id: u32) -> bool {
+        if !self.room_type.is_team_type() || from_cter_id == target_cter_id {
+            return false;
+        }
+        let from_cter = self.get_battle_cter(from_cter_id, true);
+        let target_cter = self.get_battle_cter(target_cter_id, true);
+        match (from_cter, target_cter) {
+            (Ok(from_cter), Ok(target_cter)) => {
+                from_cter.base_attr.team_id == target_cter.base_attr.team_id
+            }
+            _ => false,
+        }
+    }
+
     pub fn get_world_boss_ref(&self) -> Option<&BattlePlayer> {
         let worldboss_temps = &crate::TEMPLATES.worldboss_temp_mgr().temps;
         self.battle_player
@@ -262,6 +397,22 @@ impl BattleData {
             .find(|x| x.is_robot() && worldboss_temps.contains_key(&x.get_cter_temp_id()))
     }
 
+    ///记录对世界boss造成的伤害
+    pub fn add_world_boss_damage(&mut self, from_user_id: u32, target_user_id: u32, damage: u64) {
+        if !self.room_type.is_boss_type() || damage == 0 {
+            return;
+        }
+        if from_user_id == 0 || from_user_id == target_user_id {
+            return;
+        }
+        match self.battle_player.get(&target_user_id) {
+            Some(battle_player) if battle_player.is_world_boss => {}
+            _ => return,
+        }
+        let res = self.world_boss_damage.entry(from_user_id).or_insert(0);
+        *res += damage;
+    }
+
     pub fn remove_player(&mut self, user_id: u32) {
         //移除战斗角色
         let battle_player = self.battle_player.remove(&user_id);
@@ -393,11 +544,14 @@ impl BattleData {
     ///初始化战斗数据
     pub fn new(
         room_type: RoomType,
+        member_count: usize,
         task_sender: Sender<Task>,
         tcp_sender: Sender<Vec<u8>>,
     ) -> Self {
+        //人数超过默认上限时按实际人数分配
+        let member_max = member_count.max(MEMBER_MAX);
         let mut v = Vec::new();
-        for _ in 0..MEMBER_MAX {
+        for _ in 0..member_max {
             v.push(Vec::new());
         }
         let time = chrono::Local::now();
@@ -405,7 +559,7 @@ impl BattleData {
             room_type,
             tile_map: TileMap::default(),
             next_turn_index: 0,
-            turn_orders: [0; MEMBER_MAX],
+            turn_orders: vec![0; member_max],
             reflash_map_turn: None,
             battle_player: HashMap::new(),
             cter_player: HashMap::new(),
@@ -414,6 +568,7 @@ impl BattleData {
             leave_user: (0, false),
             leave_map: HashMap::new(),
             turn_limit_time: 60000, //默认一分钟
+            turn_reserve: None,
             turn: 0,
             round: 0,
             cycle_count: 0,
@@ -425,6 +580,7 @@ impl BattleData {
             tcp_sender,
             cter_id: 100,
             state: RoomState::ChoiceIndex,
+            world_boss_damage: HashMap::new(),
         };
 
         //初始化函数指针，封装到map里
@@ -4,7 +4,7 @@ use crate::battle::battle_enum::buff_type::{
     PAIR_SAME_ELEMENT_CURE, PAIR_WOOD_ADD_ATTACK,
 };
 use crate::battle::battle_enum::EffectType;
-use crate::battle::battle_enum::{TargetType, TRIGGER_SCOPE_NEAR};
+use crate::battle::battle_enum::TargetType;
 use crate::handlers::battle_handler::{Delete, Find};
 use crate::room::map_data::MapCell;
 use crate::TEMPLATES;
@@ -215,7 +215,8 @@ impl BattleData {
         }
         let buff_temp = buff_temp.unwrap();
 
-        let isize_index = index as isize;
+        //周围一圈的格子
+        let near_indexes = self.tile_map.near_indexes(index as usize);
 
         let battle_data_ptr = self as *mut BattleData;
         unsafe {
@@ -229,10 +230,9 @@ impl BattleData {
                 if cter.base_attr.cter_id == cter_id {
                     continue;
                 }
-                let cter_index = cter.get_map_cell_index() as isize;
-                for scope_index in TRIGGER_SCOPE_NEAR.iter() {
-                    let res = isize_index + *scope_index;
-                    if res != cter_index {
+                let cter_index = cter.get_map_cell_index();
+                for &near_index in near_indexes.iter() {
+                    if near_index != cter_index {
                         continue;
                     }
                     if cter.base_attr.max_energy > 0 {
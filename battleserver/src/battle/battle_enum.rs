@@ -19,8 +19,6 @@ pub const TURN_DEFAULT_MOVEMENT_POINTS: u8 = 2;
 ///最大turn次数限制
 pub const LIMIT_TOTAL_TURN_TIMES: u16 = 999;
 
-///触发范围一圈不包括中心
-pub const TRIGGER_SCOPE_NEAR: [isize; 6] = [-6, -5, -1, 1, 5, 6];
 ///触发范围一圈不包括中心
 pub const TRIGGER_SCOPE_NEAR_TEMP_ID: u32 = 2;
 
@@ -0,0 +1,56 @@
+use super::mission::MissionTriggerType;
+use num_enum::IntoPrimitive;
+use num_enum::TryFromPrimitive;
+use std::collections::BTreeMap;
+use tools::protos::base::BattleEventPt;
+
+///战斗事件类型,结算的时候带给游戏服做成就,数值和游戏服那边保持一致
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum BattleEventType {
+    OpenCell = 1,        //翻地图块
+    Pair = 2,            //配对
+    Attack = 3,          //攻击
+    UseSkill = 4,        //使用技能
+    GetGold = 5,         //获得金币
+    DefeatWorldBoss = 6, //打败世界boss
+}
+
+impl From<&MissionTriggerType> for BattleEventType {
+    fn from(trigger_type: &MissionTriggerType) -> Self {
+        match trigger_type {
+            MissionTriggerType::OpenCell => BattleEventType::OpenCell,
+            MissionTriggerType::Pair => BattleEventType::Pair,
+            MissionTriggerType::Attack => BattleEventType::Attack,
+            MissionTriggerType::UseSkill => BattleEventType::UseSkill,
+            MissionTriggerType::GetGold => BattleEventType::GetGold,
+        }
+    }
+}
+
+///玩家当局的战斗事件累计
+#[derive(Debug, Clone, Default)]
+pub struct BattleEvents {
+    pub counts: BTreeMap<BattleEventType, u32>, //key:事件类型 value:累计值
+}
+
+impl BattleEvents {
+    pub fn add(&mut self, event_type: BattleEventType, value: u32) {
+        if value == 0 {
+            return;
+        }
+        *self.counts.entry(event_type).or_insert(0) += value;
+    }
+
+    pub fn into_pts(&self) -> Vec<BattleEventPt> {
+        self.counts
+            .iter()
+            .map(|(&event_type, &value)| {
+                let mut pt = BattleEventPt::new();
+                pt.set_event_type(event_type.into());
+                pt.set_value(value);
+                pt
+            })
+            .collect()
+    }
+}
@@ -17,19 +17,12 @@ use tools::protos::base::{ActionUnitPt, SummaryDataPt};
 use tools::protos::battle::S_SUMMARY_NOTICE;
 use tools::protos::server_protocol::B_S_SUMMARY;
 
-use super::battle_enum::BattlePlayerState;
 use super::mission::{trigger_mission, MissionTriggerType};
 
 impl BattleData {
     ///处理战斗结算核心逻辑，不管地图刷新逻辑
     /// 返回一个元组类型：是否结算，存活玩家数量，第一名的玩家列表
     pub fn summary(&mut self) -> Vec<B_S_SUMMARY> {
-        let allive_count = self
-            .battle_player
-            .values()
-            .filter(|x| x.status.battle_state == BattlePlayerState::Normal)
-            .count();
-
         //回客户端消息
         let mut ssn = S_SUMMARY_NOTICE::new();
         let mut need_summary = false;
@@ -37,12 +30,14 @@ impl BattleData {
         let (leave_user, punishment) = self.leave_user;
         let self_ptr = self as *mut BattleData;
         //判断结算方式
-        if allive_count <= 1 {
+        if self.is_battle_over() {
             //如果房间就只有最后一个人了，直接计算
             need_summary = true;
             //如果达到结算条件，则进行结算
             if self.room_type.is_boss_type() {
                 self.summary_for_world_boss();
+            } else if self.room_type.is_team_type() {
+                self.summary_for_team();
             } else {
                 let mut user_id;
                 unsafe {
@@ -245,6 +240,13 @@ impl BattleData {
             warn!("the attack target can not be Self!user_id:{}", cter_id);
             anyhow::bail!("")
         }
+        if self.is_friendly_fire(cter_id, target_cter_id) {
+            warn!(
+                "the attack target can not be teammate!cter_id:{},target_cter_id:{}",
+                cter_id, target_cter_id
+            );
+            anyhow::bail!("")
+        }
 
         let mut target_v = vec![];
         target_v.push((target_cter_id, DamageType::Attack(0)));
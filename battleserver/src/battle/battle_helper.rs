@@ -7,7 +7,6 @@ use crate::battle::battle_skill::Skill;
 use crate::battle::battle_trigger::TriggerEvent;
 use crate::robot::robot_trigger::RobotTriggerType;
 use crate::room::map_data::{MapCell, MapCellType, TileMap};
-use crate::room::MEMBER_MAX;
 use crate::task_timer::{Task, TaskCmd};
 use crate::TEMPLATES;
 use log::{error, info, warn};
@@ -37,18 +36,12 @@ use crate::JsonValue;
 impl BattleData {
     ///检测地图刷新
     pub fn check_refresh_map(&mut self) -> bool {
-        let allive_count = self
-            .battle_player
-            .values()
-            .filter(|x| x.status.battle_state == BattlePlayerState::Normal)
-            .count();
-
         let un_open_count = self.tile_map.un_pair_map.len();
         let mut need_reflash_map = false;
         if un_open_count <= 2 {
             need_reflash_map = true;
         }
-        if allive_count >= 2 && need_reflash_map {
+        if !self.is_battle_over() && need_reflash_map {
             return true;
         }
         false
@@ -277,7 +270,7 @@ impl BattleData {
         let battle_data_ptr = self as *mut BattleData;
         self.next_turn_index += 1;
         let index = self.next_turn_index;
-        if index >= MEMBER_MAX {
+        if index >= self.turn_orders.len() {
             self.next_turn_index = 0;
         }
         let user_id = self.get_turn_user(None);
@@ -318,12 +311,14 @@ impl BattleData {
 
     ///下一个
     pub fn add_next_turn(&mut self, need_push_battle_turn_notice: bool) {
+        //先把上个turn用掉的备用时间扣了,turn推送里面要带最新的备用时间
+        self.settle_reserve_time();
         let battle_data_ptr = self as *mut BattleData;
         self.next_turn_index += 1;
         self.turn += 1;
         self.add_total_turn_times();
         let index = self.next_turn_index;
-        if index >= MEMBER_MAX {
+        if index >= self.turn_orders.len() {
             self.next_turn_index = 0;
         }
         //开始回合触发
@@ -661,6 +656,27 @@ impl BattleData {
         alive_count
     }
 
+    ///存活的队伍数量
+    pub fn get_alive_team_num(&self) -> usize {
+        let mut team_v = self
+            .battle_player
+            .values()
+            .filter(|x| x.status.battle_state == BattlePlayerState::Normal)
+            .map(|x| x.team_id)
+            .collect::<Vec<u8>>();
+        team_v.sort();
+        team_v.dedup();
+        team_v.len()
+    }
+
+    ///战斗是否结束,组队模式只剩一个队伍就算结束
+    pub fn is_battle_over(&self) -> bool {
+        if self.room_type.is_team_type() {
+            return self.get_alive_team_num() <= 1;
+        }
+        self.get_alive_player_num() <= 1
+    }
+
     pub fn new_target_pt(&self, cter_id: u32) -> anyhow::Result<TargetPt> {
         let battle_cter = self.get_battle_cter(cter_id, false)?;
         let mut target_pt = TargetPt::new();
@@ -702,6 +718,10 @@ impl BattleData {
             if let Err(_) = target_cter {
                 continue;
             }
+            //组队模式不能伤害队友
+            if self.is_friendly_fire(from_cter_id, target_cter_id) {
+                continue;
+            }
             if index == targets.len() - 1 {
                 is_last_one = true;
             }
@@ -842,6 +862,10 @@ impl BattleData {
         target_pt: &mut TargetPt,
         is_last_one: bool,
     ) -> anyhow::Result<(u32, Vec<TargetPt>)> {
+        //组队模式不能伤害队友
+        if self.is_friendly_fire(from_cter_id, target_cter_id) {
+            return Ok((0, Vec::new()));
+        }
         let battle_data_ptr = self as *mut BattleData;
 
         let mut ep = EffectPt::new();
@@ -914,6 +938,9 @@ impl BattleData {
         }
         ep.effect_value = res as u32;
         target_pt.effects.push(ep);
+        //溢出的伤害不算
+        let real_damage = std::cmp::min(res, target_cter.base_attr.hp).max(0);
+        self.add_world_boss_damage(from_user_id, target_user_id, real_damage as u64);
         let is_die = target_cter.add_hp(-res);
 
         let mut other_target_pts = vec![];
@@ -1111,6 +1138,7 @@ impl BattleData {
             let mut sbtn = S_BATTLE_TURN_NOTICE::new();
             sbtn.set_cycle_count(self.cycle_count);
             sbtn.set_user_id(self.get_turn_user(None).unwrap());
+            sbtn.set_turn_time(self.turn_limit_time as u32);
             push_map.insert(user_id, sbtn);
             cell_buff_map.insert(user_id, HashMap::new());
         }
@@ -1543,8 +1571,51 @@ impl BattleData {
         Ok(())
     }
 
+    ///初始化所有玩家的备用时间
+    pub fn init_reserve_time(&mut self, reserve_time: u64) {
+        self.turn_reserve = None;
+        for battle_player in self.battle_player.values_mut() {
+            battle_player.reserve_time = reserve_time;
+        }
+    }
+
+    ///结算上一个turn用掉的备用时间
+    fn settle_reserve_time(&mut self) {
+        let (user_id, start_time) = match self.turn_reserve.take() {
+            Some(res) => res,
+            None => return,
+        };
+        let used = chrono::Local::now().timestamp_millis() - start_time;
+        if let Some(battle_player) = self.battle_player.get_mut(&user_id) {
+            let used = std::cmp::max(used, 0) as u64;
+            battle_player.reserve_time = battle_player.reserve_time.saturating_sub(used);
+        }
+    }
+
+    ///备用时间也用完了,直接清零
+    pub fn clear_reserve_time(&mut self, user_id: u32) {
+        self.turn_reserve = None;
+        if let Some(battle_player) = self.battle_player.get_mut(&user_id) {
+            battle_player.reserve_time = 0;
+        }
+    }
+
+    ///回合基础时间用完了,开始消耗备用时间,重新创建定时器任务
+    pub fn build_reserve_turn_task(&mut self, user_id: u32) -> bool {
+        let reserve_time = match self.battle_player.get(&user_id) {
+            Some(battle_player) => battle_player.reserve_time,
+            None => return false,
+        };
+        if reserve_time == 0 {
+            return false;
+        }
+        self.turn_reserve = Some((user_id, chrono::Local::now().timestamp_millis()));
+        self.send_battle_turn_task(user_id, reserve_time, true);
+        true
+    }
+
     ///新建战斗回合定时器任务
-    pub fn build_battle_turn_task(&self) {
+    pub fn build_battle_turn_task(&mut self) {
         let next_turn_index = self.next_turn_index;
         let user_id = self.turn_orders.get(next_turn_index);
         if user_id.is_none() {
@@ -1554,19 +1625,24 @@ impl BattleData {
             );
             return;
         }
-        let user_id = user_id.unwrap();
+        let user_id = *user_id.unwrap();
         let time_limit = self.turn_limit_time;
         //如果不限制时间,直接跳过
         if time_limit == 0 {
             return;
         }
+        self.send_battle_turn_task(user_id, time_limit, false);
+    }
+
+    fn send_battle_turn_task(&self, user_id: u32, delay: u64, is_reserve: bool) {
         let mut task = Task::default();
-        task.delay = time_limit;
+        task.delay = delay;
         task.cmd = TaskCmd::BattleTurnTime;
         task.turn = self.turn;
         task.battle_id = self.battle_id;
         let mut map = serde_json::Map::new();
-        map.insert("user_id".to_owned(), JsonValue::from(*user_id));
+        map.insert("user_id".to_owned(), JsonValue::from(user_id));
+        map.insert("is_reserve".to_owned(), JsonValue::from(is_reserve));
         task.data = JsonValue::from(map);
         let res = self.task_sender.send(task);
         if res.is_err() {
@@ -1691,16 +1767,16 @@ impl BattleData {
                     for coord_temp in direction_temp2d.direction2d.iter() {
                         let x = center_map_cell.x + coord_temp.x;
                         let y = center_map_cell.y + coord_temp.y;
-                        let map_cell_index = self.tile_map.coord_map.get(&(x, y));
+                        let map_cell_index = self.tile_map.get_index_by_coord(x, y);
                         if let None = map_cell_index {
                             continue;
                         }
                         let map_cell_index = map_cell_index.unwrap();
-                        let map_cell = self.tile_map.map_cells.get(*map_cell_index);
+                        let map_cell = self.tile_map.map_cells.get(map_cell_index);
                         if map_cell.is_none() {
                             continue;
                         }
-                        v.push(*map_cell_index);
+                        v.push(map_cell_index);
                         let map_cell = map_cell.unwrap();
                         if map_cell.cter_id <= 0 {
                             continue;
@@ -1764,17 +1840,17 @@ impl BattleData {
                 for coord_temp in dir.direction2d.iter() {
                     let x = center_map_cell.x + coord_temp.x;
                     let y = center_map_cell.y + coord_temp.y;
-                    let map_cell_index = self.tile_map.coord_map.get(&(x, y));
+                    let map_cell_index = self.tile_map.get_index_by_coord(x, y);
                     if let None = map_cell_index {
                         warn!("there is no map_cell for {:?}", (x, y));
                         continue;
                     }
                     let map_cell_index = map_cell_index.unwrap();
-                    let map_cell = self.tile_map.map_cells.get(*map_cell_index);
+                    let map_cell = self.tile_map.map_cells.get(map_cell_index);
                     if let None = map_cell {
                         continue;
                     }
-                    v.push(*map_cell_index);
+                    v.push(map_cell_index);
                     let map_cell = map_cell.unwrap();
                     for index in targets.iter() {
                         if map_cell.index as u32 != *index {
@@ -2190,3 +2266,100 @@ pub fn build_action_unit_pt(
     }
     au
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::room::RoomType;
+    use crossbeam::channel::{unbounded, Receiver};
+
+    fn new_battle_data(reserve_time: u64) -> (BattleData, Receiver<Task>) {
+        let (task_sender, task_receiver) = unbounded();
+        let (tcp_sender, _) = unbounded();
+        let mut battle_data =
+            BattleData::new(RoomType::OneVOneVOneVOneMatch, 2, task_sender, tcp_sender);
+        battle_data.turn_limit_time = 10000;
+        battle_data.turn_orders = vec![1, 2];
+        for user_id in 1..=2 {
+            battle_data
+                .battle_player
+                .insert(user_id, BattlePlayer::default());
+        }
+        battle_data.init_reserve_time(reserve_time);
+        (battle_data, task_receiver)
+    }
+
+    fn reserve_time(battle_data: &BattleData, user_id: u32) -> u64 {
+        battle_data
+            .battle_player
+            .get(&user_id)
+            .unwrap()
+            .reserve_time
+    }
+
+    fn is_reserve(task: &Task) -> bool {
+        task.data.get("is_reserve").unwrap().as_bool().unwrap()
+    }
+
+    #[test]
+    fn use_reserve() {
+        let (mut battle_data, task_receiver) = new_battle_data(30000);
+        //基础时间用完了,按剩下的备用时间重新建定时器
+        assert!(battle_data.build_reserve_turn_task(1));
+        let task = task_receiver.try_recv().unwrap();
+        assert_eq!(task.delay, 30000);
+        assert!(is_reserve(&task));
+
+        //用了10秒备用时间
+        let now = chrono::Local::now().timestamp_millis();
+        battle_data.turn_reserve = Some((1, now - 10000));
+        battle_data.settle_reserve_time();
+        assert!(battle_data.turn_reserve.is_none());
+        let left = reserve_time(&battle_data, 1);
+        assert!(left <= 20000 && left > 19000);
+        //别人的不动
+        assert_eq!(reserve_time(&battle_data, 2), 30000);
+
+        //下次只剩这么多
+        assert!(battle_data.build_reserve_turn_task(1));
+        assert_eq!(task_receiver.try_recv().unwrap().delay, left);
+    }
+
+    #[test]
+    fn base_time_every_turn() {
+        let (mut battle_data, task_receiver) = new_battle_data(30000);
+        let now = chrono::Local::now().timestamp_millis();
+        battle_data.turn_reserve = Some((1, now - 10000));
+        battle_data.settle_reserve_time();
+        //备用时间用过了,每个turn的基础时间还是满的
+        for next_turn_index in 0..2 {
+            battle_data.next_turn_index = next_turn_index;
+            battle_data.build_battle_turn_task();
+            let task = task_receiver.try_recv().unwrap();
+            assert_eq!(task.delay, 10000);
+            assert!(!is_reserve(&task));
+        }
+        //备用时间不会补回来
+        assert!(reserve_time(&battle_data, 1) <= 20000);
+    }
+
+    #[test]
+    fn reserve_empty() {
+        let (mut battle_data, task_receiver) = new_battle_data(5000);
+        //用超了也只扣到0
+        let now = chrono::Local::now().timestamp_millis();
+        battle_data.turn_reserve = Some((1, now - 10000));
+        battle_data.settle_reserve_time();
+        assert_eq!(reserve_time(&battle_data, 1), 0);
+        //没有备用时间了,直接算超时
+        assert!(!battle_data.build_reserve_turn_task(1));
+        assert!(task_receiver.try_recv().is_err());
+
+        //备用时间的定时器到了也清零
+        assert!(battle_data.build_reserve_turn_task(2));
+        battle_data.clear_reserve_time(2);
+        assert!(battle_data.turn_reserve.is_none());
+        assert_eq!(reserve_time(&battle_data, 2), 0);
+        assert!(!battle_data.build_reserve_turn_task(2));
+    }
+}
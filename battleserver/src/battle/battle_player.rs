@@ -1,13 +1,14 @@
 use crate::battle::battle_enum::{
     AttackState, BattleCterState, BattlePlayerState, FromType, TURN_DEFAULT_MOVEMENT_POINTS,
 };
+use crate::battle::battle_event::BattleEvents;
 use crate::battle::mission::MissionData;
 use crate::battle::mission::MissionResetType;
 use crate::battle::{battle::BattleData, mission::MissionCompleteType};
 use crate::mgr::League;
 use crate::robot::robot_action::RobotStatusAction;
 use crate::robot::robot_task_mgr::RobotTask;
-use crate::robot::RobotData;
+use crate::robot::{get_auto_robot_profile, get_robot_profile, RobotData};
 use crate::room::member::Member;
 use crate::TEMPLATES;
 use crossbeam::channel::Sender;
@@ -109,6 +110,11 @@ pub struct BattlePlayer {
     pub robot_data: Option<RobotData>,        //机器人数据;如果有值，则是机器人，没有则是玩家
     pub team_id: u8,                          //队伍id
     pub is_world_boss: bool,                  //是否是worldboss
+    pub is_auto: bool,                        //是否托管中,托管的时候robot_data有值
+    pub missed_turns: u8,                     //连续超时的turn次数
+    pub reserve_time: u64,                    //剩余备用时间(毫秒)
+    pub kills: u32,                           //当局击杀数
+    pub battle_events: BattleEvents,          //当局战斗事件累计
 }
 
 tools::get_mut_ref!(BattlePlayer);
@@ -118,7 +124,7 @@ impl BattlePlayer {
     pub fn init(
         member: &Member,
         battle_data: &mut BattleData,
-        remember_size: u32,
+        ai_level: u8,
         robot_sender: Sender<RobotTask>,
     ) -> anyhow::Result<Self> {
         let cter_id = battle_data.generate_cter_id();
@@ -137,7 +143,7 @@ impl BattlePlayer {
                 battle_player.user_id,
                 member.robot_temp_id,
                 battle_data as *mut BattleData,
-                remember_size,
+                get_robot_profile(member.robot_temp_id, ai_level),
                 robot_sender,
             );
             battle_player.robot_data = Some(robot_data);
@@ -163,6 +169,8 @@ impl BattlePlayer {
         battle_player_pt.set_mission(self.mission_data.into_mission_pt());
         battle_player_pt.set_is_robot(self.is_robot());
         battle_player_pt.set_is_died(self.is_died());
+        battle_player_pt.set_is_auto(self.is_auto);
+        battle_player_pt.set_reserve_time(self.reserve_time as u32);
         for cter in self.cters.values() {
             battle_player_pt
                 .cters
@@ -404,10 +412,42 @@ impl BattlePlayer {
         self.status.attack_state
     }
 
+    ///是否是机器人,托管中的玩家不算
     pub fn is_robot(&self) -> bool {
+        self.robot_data.is_some() && !self.is_auto
+    }
+
+    ///是否由机器人控制,包括托管中的玩家
+    pub fn is_robot_controlled(&self) -> bool {
         self.robot_data.is_some()
     }
 
+    ///开始托管,交给临时的机器人控制
+    pub fn start_auto(&mut self, battle_data: *mut BattleData, robot_sender: Sender<RobotTask>) {
+        if self.robot_data.is_some() {
+            return;
+        }
+        let robot_data = RobotData::new(
+            self.user_id,
+            0,
+            battle_data,
+            get_auto_robot_profile(),
+            robot_sender,
+        );
+        self.robot_data = Some(robot_data);
+        self.is_auto = true;
+    }
+
+    ///结束托管,玩家拿回控制权
+    pub fn stop_auto(&mut self) {
+        if !self.is_auto {
+            return;
+        }
+        self.robot_data = None;
+        self.is_auto = false;
+        self.missed_turns = 0;
+    }
+
     ///重制角色数据
     pub fn round_reset(&mut self) -> Vec<u32> {
         self.status.is_attacked = false;
@@ -422,7 +462,7 @@ impl BattlePlayer {
     }
 
     pub fn robot_reset(&mut self) {
-        if self.is_robot() {
+        if self.is_robot_controlled() {
             self.robot_data.as_mut().unwrap().remember_map_cell.clear();
         }
     }
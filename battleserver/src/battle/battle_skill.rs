@@ -133,7 +133,7 @@ pub unsafe fn change_map_cell_index(
         return None;
     }
 
-    let map_ptr = battle_data.tile_map.map_cells.borrow_mut() as *mut [MapCell; 30];
+    let map_ptr = &mut battle_data.tile_map.map_cells as *mut Vec<MapCell>;
     //玩家proto封装
     let mut au_vec = vec![];
     //目标proto
@@ -812,7 +812,7 @@ pub unsafe fn auto_pair_map_cell(
 
     let battle_data_ptr = battle_data as *mut BattleData;
     let battle_data_mut = battle_data_ptr.as_mut().unwrap();
-    let map = &mut battle_data.tile_map.map_cells as *mut [MapCell; 30];
+    let map = &mut battle_data.tile_map.map_cells as *mut Vec<MapCell>;
 
     //校验目标下标的地图块
     let map_cell = battle_data_mut
@@ -8,7 +8,7 @@ use crate::battle::battle_enum::skill_type::{
     CHARGE_SKILL_DAMGE_ABSORPTION, FULL_MAP_DAMAGE, WATER_TURRET,
 };
 use crate::battle::battle_enum::EffectType::AddSkill;
-use crate::battle::battle_enum::{ActionType, EffectType, TRIGGER_SCOPE_NEAR};
+use crate::battle::battle_enum::{ActionType, EffectType};
 use crate::battle::battle_skill::Skill;
 use crate::robot::robot_trigger::RobotTriggerType;
 use crate::robot::RememberCell;
@@ -339,7 +339,7 @@ impl TriggerEvent for BattleData {
         let rc = RememberCell::new(index, map_cell.id);
         for battle_player in self.battle_player.values_mut() {
             //如果不是机器人就continue；
-            if !battle_player.is_robot() {
+            if !battle_player.is_robot_controlled() {
                 continue;
             }
             battle_player
@@ -414,9 +414,8 @@ impl TriggerEvent for BattleData {
                     if is_change_index_both {
                         continue;
                     }
-                    for scope_index in TRIGGER_SCOPE_NEAR.iter() {
-                        let res = cter_index + scope_index;
-                        if index != res {
+                    for near_index in self.tile_map.near_indexes(cter_index as usize) {
+                        if index != near_index as isize {
                             continue;
                         }
                         let mut other_aupt =
@@ -464,9 +463,8 @@ impl TriggerEvent for BattleData {
             //         continue;
             //     }
             //     let mut need_rank = true;
-            //     for scope_index in TRIGGER_SCOPE_NEAR.iter() {
-            //         let res = index + scope_index;
-            //         if cter_index != res {
+            //     for near_index in self.tile_map.near_indexes(index as usize) {
+            //         if cter_index != near_index as isize {
             //             continue;
             //         }
             //         let target_pt = self.deduct_hp(
@@ -939,6 +937,8 @@ impl TriggerEvent for BattleData {
                 .get_battle_player_mut(Some(die_user_id), false)
                 .unwrap();
             die_battle_player.player_die(str);
+            //托管到死都没回来的,按逃跑惩罚
+            let is_punishment = is_punishment || die_battle_player.is_auto;
 
             let self_league = die_battle_player.league.get_league_id();
             let player_name = die_battle_player.name.clone();
@@ -962,6 +962,10 @@ impl TriggerEvent for BattleData {
             sp.cter_temp_id = die_battle_player.get_cter_temp_id();
             sp.league = die_battle_player.league.clone();
             sp.grade = die_battle_player.grade;
+            sp.kills = die_battle_player.kills;
+            sp.battle_events = die_battle_player.battle_events.clone();
+            sp.team_id = die_battle_player.team_id;
+            sp.is_punishment = is_punishment;
             let gold = die_battle_player.gold;
             let player_count = self.get_alive_player_num() as i32;
             let rank_vec_temp = &mut self.summary_vec_temp;
@@ -972,10 +976,11 @@ impl TriggerEvent for BattleData {
                 let from_cter = self_mut.get_battle_player_mut(Some(from_user_id), true);
                 if let Ok(from_cter) = from_cter {
                     from_cter.add_gold(gold);
+                    from_cter.kills += 1;
                 }
             }
-            //如果是worldboss房间，不用往下执行，到战斗结束的时候那一刻再结算
-            if self.room_type.is_boss_type() {
+            //如果是worldboss房间或者组队房间，不用往下执行，到战斗结束的时候那一刻再结算
+            if self.room_type.is_boss_type() || self.room_type.is_team_type() {
                 return;
             }
             if player_count == 1 {
@@ -77,7 +77,7 @@ pub fn handler_buy(battle_data: &mut BattleData, user_id: u32, merchandise_id: u
     }
     let battle_player = battle_data.battle_player.get_mut(&user_id).unwrap();
     //如果是机器人，继续行动
-    if battle_player.is_robot() {
+    if battle_player.is_robot_controlled() {
         battle_player.robot_start_action(battle_data_ptr);
     }
 }
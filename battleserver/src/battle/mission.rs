@@ -11,6 +11,7 @@ use num_enum::IntoPrimitive;
 use num_enum::TryFromPrimitive;
 
 use super::battle::BattleData;
+use super::battle_event::BattleEventType;
 
 use tools::protos::base::MissionPt;
 
@@ -261,6 +262,12 @@ pub fn trigger_mission(
     mission_parm: (u32, u32),
 ) {
     let battle_player = battle_data.battle_player.get_mut(&user_id).unwrap();
+    //不管有没有任务都记下来,结算的时候给成就用
+    for (trigger_type, value) in trigger_types.iter() {
+        battle_player
+            .battle_events
+            .add(BattleEventType::from(trigger_type), *value as u32);
+    }
     //如果任务是空的，或者任务完成了则直接返回
     if battle_player.mission_data.mission.is_none()
         || battle_player
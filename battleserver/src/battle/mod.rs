@@ -2,6 +2,7 @@ pub mod battle;
 pub mod battle_buff;
 pub mod battle_cter;
 pub mod battle_enum;
+pub mod battle_event;
 pub mod battle_flow;
 pub mod battle_helper;
 pub mod battle_player;
@@ -21,6 +21,7 @@ use tools::cmd_code::{ClientCode, GameCode};
 use tools::protos::base::ActionUnitPt;
 use tools::protos::battle::C_BUY;
 use tools::protos::battle::{C_ACTION, C_CHOOSE_INDEX, C_POS, S_ACTION_NOTICE, S_POS_NOTICE};
+use tools::protos::battle::{C_VOTE_END, S_SURRENDER};
 use tools::protos::room::C_EMOJI;
 use tools::protos::server_protocol::{R_B_START, R_S_UPDATE_SEASON};
 use tools::templates::emoji_temp::EmojiTemp;
@@ -143,6 +144,7 @@ pub fn buy(bm: &mut BattleMgr, packet: Packet) {
     }
     //执行购买
     handler_buy(battle_data, user_id, merchandise_id);
+    bm.oper_ok = true;
 }
 
 ///行动请求
@@ -249,6 +251,9 @@ pub fn action(bm: &mut BattleMgr, packet: Packet) {
         warn!("{:?}!action_type:{:?},user_id:{}", e, action_type, user_id);
         return;
     }
+    unsafe {
+        rm_ptr.as_mut().unwrap().oper_ok = true;
+    }
 
     //回给客户端,添加action主动方
     let mut san_push_all = S_ACTION_NOTICE::new();
@@ -305,7 +310,7 @@ pub fn action(bm: &mut BattleMgr, packet: Packet) {
 
         //如果角色没死，并且是机器人，则通知机器人执行完了,并且启动机器人action
         if !current_cter_is_died
-            && battle_player.is_robot()
+            && battle_player.is_robot_controlled()
             && battle_player.get_user_id() == user_id
         {
             battle_player.robot_start_action(battle_data_ptr);
@@ -317,6 +322,71 @@ pub fn action(bm: &mut BattleMgr, packet: Packet) {
     }
 }
 
+///投降
+pub fn surrender(bm: &mut BattleMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let room = bm.get_room_mut(&user_id);
+    if let None = room {
+        warn!("this player is not in the room!user_id:{}", user_id);
+        return;
+    }
+    let room = room.unwrap();
+    let room_id = room.get_room_id();
+    //投降的正好是当前turn的人,结算完还要轮到下一个
+    let is_turn_user = room.get_turn_user(None).map_or(false, |id| id == user_id);
+    let res = room.surrender(user_id);
+    let mut ss = S_SURRENDER::new();
+    match res {
+        Ok(_) => ss.set_is_succ(true),
+        Err(e) => {
+            warn!("{:?}", e);
+            ss.set_err_mess(e.to_string());
+        }
+    }
+    let is_succ = ss.is_succ;
+    room.send_2_client(ClientCode::Surrender, user_id, ss.write_to_bytes().unwrap());
+    if !is_succ {
+        return;
+    }
+    unsafe {
+        let is_summary = process_summary(bm, room_id);
+        if is_summary {
+            return;
+        }
+    }
+    if is_turn_user {
+        if let Some(room) = bm.rooms.get_mut(&room_id) {
+            room.battle_data.next_turn(true);
+        }
+    }
+}
+
+///投票结束战斗
+pub fn vote_end(bm: &mut BattleMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = C_VOTE_END::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let room = bm.get_room_mut(&user_id);
+    if let None = room {
+        warn!("this player is not in the room!user_id:{}", user_id);
+        return;
+    }
+    let room = room.unwrap();
+    let room_id = room.get_room_id();
+    let res = room.vote_end(user_id, proto.agree);
+    match res {
+        Ok(true) => unsafe {
+            process_summary(bm, room_id);
+        },
+        Ok(false) => {}
+        Err(e) => warn!("{:?}", e),
+    }
+}
+
 ///处理战斗结算
 /// 在action末尾处,用于处理战斗推进过程中的战斗结算
 pub unsafe fn process_summary(bm: &mut BattleMgr, room_id: u32) -> bool {
@@ -668,7 +738,7 @@ fn skip_turn(
 
     //没有翻过地图块，则跳过
     let battle_player = battle_player.unwrap();
-    if !battle_player.get_is_can_end_turn() && !battle_player.is_robot() {
+    if !battle_player.get_is_can_end_turn() && !battle_player.is_robot_controlled() {
         warn!("this player not open any map_cell yet!user_id:{}", user_id);
         anyhow::bail!("")
     }
@@ -899,6 +969,7 @@ pub fn choice_index(bm: &mut BattleMgr, packet: Packet) {
         return;
     }
     room.choice_index(major_cter, index);
+    bm.oper_ok = true;
 }
 
 ///结束操作
@@ -911,7 +982,7 @@ fn unlock_oper(
     let battle_player = rm.battle_data.battle_player.get_mut(&user_id).unwrap();
     let v = *au.action_value.get(0).unwrap();
     if battle_player.status.locked_oper == 0
-        || (battle_player.status.locked_oper != v && !battle_player.is_robot())
+        || (battle_player.status.locked_oper != v && !battle_player.is_robot_controlled())
     {
         anyhow::bail!("{there is no show cell skill activate!}")
     }
@@ -0,0 +1,160 @@
+use crate::battle::battle::Item;
+use crate::battle::battle_trigger::TriggerEvent;
+use crate::handlers::battle_handler::process_summary;
+use crate::mgr::battle_mgr::BattleMgr;
+use crate::room::RoomState;
+use log::{error, info, warn};
+use protobuf::Message;
+use tools::cmd_code::ClientCode;
+use tools::gm::{build_result_proto, parse_arg, GmContext, GmLevel, GmRegistry};
+use tools::protos::server_protocol::G_GM_COMMAND;
+use tools::util::packet::Packet;
+
+///注册战斗服的gm命令
+pub fn init_gm_registry(registry: &mut GmRegistry<BattleMgr>) {
+    registry.register("add_gold", GmLevel::Qa, "<value>", add_gold);
+    registry.register("add_item", GmLevel::Qa, "<item_id>", add_item);
+    registry.register("end_battle", GmLevel::Qa, "[winner_id]", end_battle);
+}
+
+///游戏服转发过来的gm命令,包头的user_id是目标玩家
+pub fn gm_command(bm: &mut BattleMgr, packet: Packet) {
+    let mut proto = G_GM_COMMAND::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let ctx = GmContext {
+        operator_id: proto.get_operator_id(),
+        level: GmLevel::from_u32(proto.get_level()),
+        target_id: packet.get_user_id(),
+    };
+    let args = proto.get_args().to_vec();
+    let res = match bm.gm_registry.check(proto.get_name(), ctx.level) {
+        Ok(f) => {
+            info!(
+                "执行gm命令!operator_id:{},target_id:{},name:{},args:{:?}",
+                ctx.operator_id,
+                ctx.target_id,
+                proto.get_name(),
+                args
+            );
+            f(bm, &ctx, args.as_slice())
+        }
+        Err(e) => Err(e),
+    };
+    //http过来的没有执行者,只打日志
+    if ctx.operator_id == 0 {
+        info!("gm command result:{:?}", res);
+        return;
+    }
+    let bytes = build_result_proto(res).write_to_bytes();
+    if let Err(e) = bytes {
+        error!("{:?}", e);
+        return;
+    }
+    let bytes = Packet::build_packet_bytes(
+        ClientCode::GmCommand.into_u32(),
+        ctx.operator_id,
+        bytes.unwrap(),
+        true,
+        true,
+    );
+    let res = bm.get_game_center_channel_mut().send(bytes);
+    if let Err(e) = res {
+        warn!("{:?}", e);
+    }
+}
+
+///加金币,负数就是扣
+fn add_gold(bm: &mut BattleMgr, ctx: &GmContext, args: &[String]) -> anyhow::Result<String> {
+    let value: i32 = parse_arg(args, 0, "value")?;
+    let room = bm.get_room_mut(&ctx.target_id);
+    if room.is_none() {
+        anyhow::bail!("this player is not in battle!user_id:{}", ctx.target_id)
+    }
+    let battle_player = room
+        .unwrap()
+        .battle_data
+        .battle_player
+        .get_mut(&ctx.target_id);
+    if battle_player.is_none() {
+        anyhow::bail!("could not find battle_player!user_id:{}", ctx.target_id)
+    }
+    let gold = battle_player.unwrap().add_gold(value);
+    Ok(format!("user_id:{},gold:{}", ctx.target_id, gold))
+}
+
+///给当前角色加道具
+fn add_item(bm: &mut BattleMgr, ctx: &GmContext, args: &[String]) -> anyhow::Result<String> {
+    let item_id: u32 = parse_arg(args, 0, "item_id")?;
+    let item_temp = crate::TEMPLATES.item_temp_mgr().get_temp(&item_id)?;
+    let skill_temp = crate::TEMPLATES
+        .skill_temp_mgr()
+        .get_temp(&item_temp.trigger_skill)?;
+    let room = bm.get_room_mut(&ctx.target_id);
+    if room.is_none() {
+        anyhow::bail!("this player is not in battle!user_id:{}", ctx.target_id)
+    }
+    let battle_player = room
+        .unwrap()
+        .battle_data
+        .battle_player
+        .get_mut(&ctx.target_id);
+    if battle_player.is_none() {
+        anyhow::bail!("could not find battle_player!user_id:{}", ctx.target_id)
+    }
+    let item = Item {
+        id: item_id,
+        skill_temp,
+    };
+    battle_player
+        .unwrap()
+        .get_current_cter_mut()
+        .items
+        .insert(item_id, item);
+    Ok(format!("user_id:{},item_id:{}", ctx.target_id, item_id))
+}
+
+///强制结束战斗,除了胜利者其他活着的玩家都按死亡处理,不填胜利者就是目标玩家
+fn end_battle(bm: &mut BattleMgr, ctx: &GmContext, args: &[String]) -> anyhow::Result<String> {
+    let winner_id = match args.get(0) {
+        Some(_) => parse_arg(args, 0, "winner_id")?,
+        None => ctx.target_id,
+    };
+    let room = bm.get_room_mut(&ctx.target_id);
+    if room.is_none() {
+        anyhow::bail!("this player is not in battle!user_id:{}", ctx.target_id)
+    }
+    let room = room.unwrap();
+    if room.state != RoomState::ChoiceIndex && room.state != RoomState::BattleStarted {
+        anyhow::bail!(
+            "battle is not started!room_id:{},state:{:?}",
+            room.get_room_id(),
+            room.state
+        )
+    }
+    if !room.battle_data.battle_player.contains_key(&winner_id) {
+        anyhow::bail!("winner is not in this battle!winner_id:{}", winner_id)
+    }
+    let die_users: Vec<u32> = room
+        .battle_data
+        .battle_player
+        .values()
+        .filter(|battle_player| !battle_player.is_died())
+        .map(|battle_player| battle_player.get_user_id())
+        .filter(|&user_id| user_id != winner_id)
+        .collect();
+    for &user_id in die_users.iter() {
+        let str = format!("player die!because gm end battle!user_id:{}", user_id);
+        room.battle_data
+            .after_player_died_trigger(winner_id, user_id, false, false, Some(str));
+    }
+    let room_id = room.get_room_id();
+    let is_summary = unsafe { process_summary(bm, room_id) };
+    Ok(format!(
+        "room_id:{},winner_id:{},is_summary:{}",
+        room_id, winner_id, is_summary
+    ))
+}
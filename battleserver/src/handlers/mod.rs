@@ -1 +1,2 @@
 pub mod battle_handler;
+pub mod gm_handler;
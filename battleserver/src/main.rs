@@ -13,19 +13,21 @@ use crate::task_timer::init_timer;
 use async_std::sync::Mutex;
 use lazy_static::lazy_static;
 use log::{error, info, warn};
-use scheduled_thread_pool::ScheduledThreadPool;
+use tools::scheduler::DelayTimer;
 use std::env;
 use std::sync::Arc;
 use tools::conf::Conf;
+use tools::metrics::MetricsHttpHandler;
 use tools::redis_pool::RedisPoolTool;
 use tools::templates::template::{init_temps_mgr, TemplatesMgr};
+use tools::thread_pool::ThreadWorkPool;
 
 //初始化全局线程池
 lazy_static! {
 
     ///定时器任务队列
-    static ref SCHEDULED_MGR : ScheduledThreadPool = {
-        let stp = ScheduledThreadPool::with_name("TASK_TIMER",8);
+    static ref SCHEDULED_MGR : DelayTimer = {
+        let stp = DelayTimer::with_name("TASK_TIMER",8);
         stp
     };
 
@@ -35,11 +37,16 @@ lazy_static! {
     };
 
     ///机器人定时器任务队列
-    static ref ROBOT_SCHEDULED_MGR : ScheduledThreadPool = {
-        let stp = ScheduledThreadPool::with_name("ROBOT_TASK_TIMER",8);
+    static ref ROBOT_SCHEDULED_MGR : DelayTimer = {
+        let stp = DelayTimer::with_name("ROBOT_TASK_TIMER",8);
         stp
     };
 
+    ///机器人推演线程池,推演比较耗时,不放在战斗线程里面做
+    static ref ROBOT_PLAN_POOL : ThreadWorkPool = {
+        ThreadWorkPool::new("ROBOT_PLAN",2)
+    };
+
     ///配置文件
     static ref CONF_MAP: Conf = {
         let path = env::current_dir().unwrap();
@@ -110,6 +117,9 @@ fn main() -> anyhow::Result<()> {
     //初始化赛季
     init_season();
 
+    //初始化http服务
+    init_http_server(bm.clone());
+
     //初始化网络
     init_tcp_client(bm);
 
@@ -117,6 +127,12 @@ fn main() -> anyhow::Result<()> {
 }
 
 fn init_log() {
+    //配置了log_format就用tracing输出,日志会带上trace_id/user_id/room_id/battle_id
+    let log_format = CONF_MAP.get_str("log_format", "");
+    if !log_format.is_empty() {
+        tools::my_log::init_trace_log(log_format == "json");
+        return;
+    }
     let info_log = &CONF_MAP.get_str("info_log_path", "");
     let error_log = &CONF_MAP.get_str("error_log_path", "");
     tools::my_log::init_log(info_log, error_log);
@@ -135,6 +151,16 @@ fn init_tcp_client(bm: Lock) {
     tcp_client::new(tcp_port, bm);
 }
 
+///初始化http服务端,目前只有监控指标
+fn init_http_server(bm: Lock) {
+    let http_port = CONF_MAP.get_usize("http_port", 0) as u16;
+    tools::http::Builder::new()
+        .route(Box::new(MetricsHttpHandler::new(move || {
+            async_std::task::block_on(bm.lock()).collect_metrics();
+        })))
+        .bind(http_port);
+}
+
 ///初始化赛季信息
 fn init_season() {
     let mut lock = REDIS_POOL.lock().unwrap();
@@ -1,6 +1,8 @@
 use crate::handlers::battle_handler::{
-    action, buy, choice_index, emoji, leave_room, off_line, pos, reload_temps, start, update_season,
+    action, buy, choice_index, emoji, leave_room, off_line, pos, reload_temps, start, surrender,
+    update_season, vote_end,
 };
+use crate::handlers::gm_handler;
 use crate::robot::robot_task_mgr::RobotTask;
 use crate::room::room::Room;
 use crate::room::{MemberLeaveNoticeType, RoomState};
@@ -10,6 +12,9 @@ use log::{info, warn};
 use std::collections::hash_map::RandomState;
 use std::collections::HashMap;
 use tools::cmd_code::{BattleCode, ServerCommonCode};
+use tools::gm::GmRegistry;
+use tools::metrics;
+use tools::trace;
 use tools::util::packet::Packet;
 
 type CmdFn = HashMap<u32, fn(&mut BattleMgr, Packet), RandomState>;
@@ -23,10 +28,16 @@ pub struct BattleMgr {
     pub game_center_channel: Option<Sender<Vec<u8>>>, //tcp客户的
     pub task_sender: Option<Sender<Task>>,            //task channel的发送方
     pub robot_task_sender: Option<Sender<RobotTask>>, //机器人task channel的发送方
+    pub gm_registry: GmRegistry<BattleMgr>,           //gm命令
+    pub oper_ok: bool,                                //这次操作是否执行成功,handler成功了自己设置
 }
 
 tools::get_mut_ref!(BattleMgr);
 
+///房间里的机器人数据带着指向战斗数据的指针,BattleMgr只在锁里面访问
+unsafe impl Send for BattleMgr {}
+unsafe impl Sync for BattleMgr {}
+
 impl BattleMgr {
     pub fn set_game_center_channel(&mut self, ts: Sender<Vec<u8>>) {
         self.game_center_channel = Some(ts);
@@ -35,6 +46,7 @@ impl BattleMgr {
     pub fn new() -> BattleMgr {
         let mut bm = BattleMgr::default();
         bm.cmd_init();
+        gm_handler::init_gm_registry(&mut bm.gm_registry);
         bm
     }
 
@@ -78,12 +90,55 @@ impl BattleMgr {
     ///执行函数，通过packet拿到cmd，然后从cmdmap拿到函数指针调用
     pub fn invok(&mut self, packet: Packet) {
         let cmd = packet.get_cmd();
+        let _trace = trace::enter(&packet);
+        if let Some(battle_id) = self.player_room.get(&packet.get_user_id()) {
+            trace::record_battle_id(*battle_id);
+        }
         let f = self.cmd_map.get_mut(&cmd);
         if f.is_none() {
             warn!("there is no handler of cmd:{:?}!", cmd);
             return;
         }
-        let _ = f.unwrap()(self, packet);
+        let f = *f.unwrap();
+        let user_id = packet.get_user_id();
+        self.oper_ok = false;
+        let time = std::time::Instant::now();
+        let _ = f(self, packet);
+        metrics::record_invok(cmd, time.elapsed());
+        //托管中的玩家自己操作成功了,拿回控制权
+        if self.oper_ok {
+            self.check_stop_auto(cmd, user_id);
+        }
+    }
+
+    ///刷新监控指标
+    pub fn collect_metrics(&self) {
+        metrics::set_gauge("active_rooms", self.rooms.len() as i64);
+        metrics::set_gauge("battle_players", self.player_room.len() as i64);
+        if let Some(sender) = self.game_center_channel.as_ref() {
+            metrics::set_gauge("game_center_channel_backlog", sender.len() as i64);
+        }
+        if let Some(sender) = self.task_sender.as_ref() {
+            metrics::set_gauge("task_channel_backlog", sender.len() as i64);
+        }
+        if let Some(sender) = self.robot_task_sender.as_ref() {
+            metrics::set_gauge("robot_task_channel_backlog", sender.len() as i64);
+        }
+    }
+
+    ///玩家自己的战斗操作成功了,如果在托管中就结束托管
+    /// 机器人的操作不走invok,不会进来
+    fn check_stop_auto(&mut self, cmd: u32, user_id: u32) {
+        if cmd != BattleCode::Action.into_u32()
+            && cmd != BattleCode::ChoiceIndex.into_u32()
+            && cmd != BattleCode::Buy.into_u32()
+        {
+            return;
+        }
+        let room = self.get_room_mut(&user_id);
+        if let Some(room) = room {
+            room.stop_auto(user_id);
+        }
     }
 
     pub fn get_room_mut(&mut self, user_id: &u32) -> Option<&mut Room> {
@@ -190,5 +245,105 @@ impl BattleMgr {
         self.cmd_map.insert(BattleCode::Pos.into_u32(), pos);
         //购物
         self.cmd_map.insert(BattleCode::Buy.into_u32(), buy);
+        //投降
+        self.cmd_map
+            .insert(BattleCode::Surrender.into_u32(), surrender);
+        //投票结束战斗
+        self.cmd_map
+            .insert(BattleCode::VoteEnd.into_u32(), vote_end);
+        //gm命令
+        self.cmd_map
+            .insert(BattleCode::GmCommand.into_u32(), gm_handler::gm_command);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::battle_player::BattlePlayer;
+    use crate::room::room::get_max_missed_turns;
+    use crate::room::RoomType;
+    use crossbeam::channel::unbounded;
+    use tools::protos::base::{RoomPt, RoomSettingPt};
+
+    const USER_ID: u32 = 1001;
+
+    fn new_battle_mgr() -> BattleMgr {
+        let mut bm = BattleMgr::new();
+        let mut rp = RoomPt::new();
+        rp.room_id = 1;
+        rp.room_type = RoomType::OneVOneVOneVOneMatch.into_u32();
+        rp.set_setting(RoomSettingPt::new());
+        let (tcp_sender, _) = unbounded();
+        let (task_sender, _) = unbounded();
+        let (robot_sender, _) = unbounded();
+        let mut room = Room::new(&rp, tcp_sender, task_sender, robot_sender).unwrap();
+        let mut battle_player = BattlePlayer::default();
+        battle_player.user_id = USER_ID;
+        room.battle_data
+            .battle_player
+            .insert(USER_ID, battle_player);
+        bm.rooms.insert(1, room);
+        bm.player_room.insert(USER_ID, 1);
+        //handler换成假的,只管成没成功
+        bm.cmd_map.insert(BattleCode::Action.into_u32(), oper_ok);
+        bm.cmd_map.insert(BattleCode::Buy.into_u32(), oper_failed);
+        bm.cmd_map.insert(BattleCode::Emoji.into_u32(), oper_ok);
+        bm
+    }
+
+    fn oper_ok(bm: &mut BattleMgr, _: Packet) {
+        bm.oper_ok = true;
+    }
+
+    fn oper_failed(_: &mut BattleMgr, _: Packet) {}
+
+    fn invok(bm: &mut BattleMgr, cmd: BattleCode) {
+        bm.invok(Packet::new(cmd.into_u32(), 0, USER_ID));
+    }
+
+    fn battle_player(bm: &BattleMgr) -> &BattlePlayer {
+        bm.get_room_ref(&USER_ID)
+            .unwrap()
+            .get_battle_player_ref(&USER_ID)
+            .unwrap()
+    }
+
+    #[test]
+    fn start_auto() {
+        let mut bm = new_battle_mgr();
+        let max_missed_turns = get_max_missed_turns();
+        for _ in 1..max_missed_turns {
+            bm.get_room_mut(&USER_ID).unwrap().add_missed_turn(USER_ID);
+            assert!(!battle_player(&bm).is_auto);
+        }
+        //自己操作成功了,超时次数重新算
+        invok(&mut bm, BattleCode::Action);
+        assert_eq!(battle_player(&bm).missed_turns, 0);
+        for _ in 0..max_missed_turns {
+            bm.get_room_mut(&USER_ID).unwrap().add_missed_turn(USER_ID);
+        }
+        //连续超时够了就托管
+        assert!(battle_player(&bm).is_auto);
+        assert!(battle_player(&bm).is_robot_controlled());
+        assert!(!battle_player(&bm).is_robot());
+    }
+
+    #[test]
+    fn stop_auto() {
+        let mut bm = new_battle_mgr();
+        bm.get_room_mut(&USER_ID).unwrap().start_auto(USER_ID);
+        assert!(battle_player(&bm).is_auto);
+        //操作失败了不算回来
+        invok(&mut bm, BattleCode::Buy);
+        assert!(battle_player(&bm).is_auto);
+        //不是战斗操作的也不算
+        invok(&mut bm, BattleCode::Emoji);
+        assert!(battle_player(&bm).is_auto);
+        //自己的战斗操作成功了才拿回控制权
+        invok(&mut bm, BattleCode::Action);
+        assert!(!battle_player(&bm).is_auto);
+        assert!(!battle_player(&bm).is_robot_controlled());
+        assert_eq!(battle_player(&bm).missed_turns, 0);
     }
 }
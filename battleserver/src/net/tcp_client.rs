@@ -27,9 +27,8 @@ impl ClientHandler for TcpClientHandler {
     }
 
     async fn on_close(&mut self) {
-        let address = crate::CONF_MAP.get_str("tcp_port", "");
-
-        self.on_read(address.to_string()).await;
+        //on_read里面会自动重连,这里不用再连了
+        warn!("disconnect with server!waiting for reconnect!");
     }
 
     async fn on_message(&mut self, mess: Vec<u8>) {
@@ -15,8 +15,10 @@ impl GoalEvaluator for AttackTargetGoalEvaluator {
             return 0;
         }
         //如果状态是可以攻击，期望值大于0，当没有其他高优先级的事件，则执行攻击
+        //攻击欲望越大期望值越高,默认50的时候是80
         if robot.is_can_attack() {
-            return 80;
+            let aggression = robot.robot_data.as_ref().unwrap().profile.aggression as u32;
+            return 40 + std::cmp::min(aggression, 100) * 4 / 5;
         }
         0
     }
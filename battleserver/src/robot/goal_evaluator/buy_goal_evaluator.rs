@@ -116,5 +116,10 @@ pub fn check_buy(battle_player: &BattlePlayer, robot_temp_id: u32) -> Vec<u32> {
         }
         res_v.push(merchandise);
     }
+    //按性格的购物优先级排序
+    if let Some(robot_data) = battle_player.robot_data.as_ref() {
+        let profile = &robot_data.profile;
+        res_v.sort_by_key(|&merchandise| profile.shop_priority_of(merchandise));
+    }
     res_v
 }
@@ -0,0 +1,54 @@
+use crate::battle::{battle::BattleData, battle_player::BattlePlayer};
+use crate::robot::goal_evaluator::GoalEvaluator;
+use crate::robot::robot_status::lookahead_action::LookaheadRobotAction;
+use crate::robot::robot_task_mgr::RobotTask;
+use crate::robot::RobotData;
+use crossbeam::channel::Sender;
+
+///推演搜索评估,机器人配置了难度等级才会生效
+#[derive(Default)]
+pub struct LookaheadGoalEvaluator {
+    // desirability: AtomicCell<u32>,
+}
+
+///拿机器人的推演等级,机器人配置优先,没有就用性格难度配置的
+pub fn get_robot_level(robot_data: &RobotData) -> u8 {
+    let temp = crate::TEMPLATES
+        .robot_temp_mgr()
+        .get_temp_ref(&robot_data.temp_id);
+    match temp {
+        Some(temp) if temp.level > 0 => temp.level,
+        _ => robot_data.profile.plan_level,
+    }
+}
+
+impl GoalEvaluator for LookaheadGoalEvaluator {
+    fn calculate_desirability(&self, robot: &BattlePlayer) -> u32 {
+        if !robot.get_current_cter().map_cell_index_is_choiced() {
+            return 0;
+        }
+        //被锁住的时候交给解锁
+        if robot.is_locked() {
+            return 0;
+        }
+        let robot_data = robot.robot_data.as_ref().unwrap();
+        if get_robot_level(robot_data) == 0 {
+            return 0;
+        }
+        //有难度等级的机器人,turn内的行为全部走推演
+        150
+    }
+
+    fn set_status(
+        &self,
+        robot: &BattlePlayer,
+        sender: Sender<RobotTask>,
+        battle_data: *mut BattleData,
+    ) {
+        let mut res = LookaheadRobotAction::new(battle_data, sender);
+        res.cter_id = robot.get_cter_temp_id();
+        res.robot_id = robot.get_user_id();
+        res.temp_id = robot.robot_data.as_ref().unwrap().temp_id;
+        robot.change_robot_status(Box::new(res));
+    }
+}
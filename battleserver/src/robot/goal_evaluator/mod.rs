@@ -1,6 +1,7 @@
 pub mod attack_goal_evaluator;
 pub mod buy_goal_evaluator;
 pub mod choice_index_goal_evaluator;
+pub mod lookahead_goal_evaluator;
 pub mod open_cell_goal_evaluator;
 pub mod skip_goal_evaluator;
 pub mod unlock_goal_evaluator;
@@ -25,7 +25,9 @@ impl GoalEvaluator for OpenCellGoalEvaluator {
             {
                 return 0;
             }
-            50
+            //翻未知地图块,越敢冒险期望值越高,默认50的时候是50
+            let risk_tolerance = robot_data.profile.risk_tolerance as u32;
+            25 + std::cmp::min(risk_tolerance, 100) / 2
         }
     }
 
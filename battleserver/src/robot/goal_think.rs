@@ -7,8 +7,10 @@ use crate::robot::goal_evaluator::GoalEvaluator;
 use crate::robot::robot_task_mgr::RobotTask;
 use crossbeam::channel::Sender;
 use log::{info, warn};
+use rand::Rng;
 
 use super::goal_evaluator::choice_index_goal_evaluator::ChoiceIndexGoalEvaluator;
+use super::goal_evaluator::lookahead_goal_evaluator::LookaheadGoalEvaluator;
 use crate::robot::goal_evaluator::skip_goal_evaluator::SkipGoalEvaluator;
 use crate::robot::goal_evaluator::use_item_goal_evaluator::UseItemGoalEvaluator;
 use crate::robot::goal_evaluator::use_skill_goal_evaluator::UseSkillGoalEvaluator;
@@ -44,6 +46,8 @@ impl GoalThink {
             .push(Box::new(BuyGoalEvaluator::default()));
         gt.goal_evaluators
             .push(Box::new(UnlockGoalEvaluator::default()));
+        gt.goal_evaluators
+            .push(Box::new(LookaheadGoalEvaluator::default()));
         gt
     }
 
@@ -73,7 +77,21 @@ impl GoalThink {
                 return;
             }
         }
-        let best_goal_evaluator = best_goal_evaluator.unwrap();
+        let mut best_goal_evaluator = best_goal_evaluator.unwrap();
+        //按性格的失误率随便挑一个能做的事情,被锁住的时候不失误
+        let robot_data = robot.robot_data.as_ref().unwrap();
+        if !robot.is_locked() && robot_data.is_mistake() {
+            let v: Vec<&Box<dyn GoalEvaluator>> = self
+                .goal_evaluators
+                .iter()
+                .filter(|x| x.calculate_desirability(robot) > 0)
+                .collect();
+            if !v.is_empty() {
+                let index = rand::thread_rng().gen_range(0..v.len());
+                best_goal_evaluator = v.get(index).unwrap();
+                info!("机器人失误了!robot_id:{}", robot.get_user_id());
+            }
+        }
         //设置状态
         best_goal_evaluator.set_status(robot, sender, battle_data);
     }
@@ -2,6 +2,7 @@ pub mod goal_evaluator;
 pub mod goal_think;
 pub mod robot_action;
 pub mod robot_helper;
+pub mod robot_planner;
 pub mod robot_skill;
 pub mod robot_status;
 pub mod robot_task_mgr;
@@ -16,7 +17,10 @@ use crossbeam::channel::Sender;
 use log::warn;
 use num_enum::IntoPrimitive;
 use num_enum::TryFromPrimitive;
+use rand::Rng;
 use std::collections::VecDeque;
+use std::str::FromStr;
+use tools::templates::robot_profile_temp::RobotProfileTemp;
 
 use self::robot_helper::check_can_open;
 
@@ -75,7 +79,9 @@ pub struct RobotData {
     pub robot_status: Option<Box<dyn RobotStatusAction>>, //状态,
     pub remember_map_cell: VecDeque<RememberCell>,        //记忆地图块
     pub remember_size: u32,                               //记忆队列长度
+    pub profile: RobotProfileTemp,                        //性格难度配置
     pub sender: Sender<RobotTask>,                        //机器人任务sender
+    pub simulation: bool,                                 //是否是推演用的副本,副本不进行思考
 }
 
 impl RobotData {
@@ -84,7 +90,7 @@ impl RobotData {
         robot_id: u32,
         temp_id: u32,
         battle_data: *mut BattleData,
-        remember_size: u32,
+        profile: RobotProfileTemp,
         sender: Sender<RobotTask>,
     ) -> Self {
         RobotData {
@@ -94,8 +100,10 @@ impl RobotData {
             goal_think: GoalThink::new(),
             robot_status: None,
             remember_map_cell: VecDeque::new(),
-            remember_size,
+            remember_size: profile.remember_size,
+            profile,
             sender,
+            simulation: false,
         }
     }
 
@@ -139,12 +147,33 @@ impl RobotData {
         }
     }
 
+    ///随机一个思考延迟
+    pub fn think_delay(&self) -> u64 {
+        let (min, max) = self.profile.think_delay_range();
+        if min >= max {
+            return min;
+        }
+        rand::thread_rng().gen_range(min..=max)
+    }
+
+    ///这次是否失误
+    pub fn is_mistake(&self) -> bool {
+        let mistake_rate = self.profile.mistake_rate as u32;
+        if mistake_rate == 0 {
+            return false;
+        }
+        rand::thread_rng().gen_range(0..100) < mistake_rate
+    }
+
     pub fn clone_battle_data_ptr(&self) -> *mut BattleData {
         self.battle_data
     }
 
     ///思考做做什么，这里会执行仲裁，数值最高的会挑出来进行执行
     pub fn thinking_do_something(&self) {
+        if self.simulation {
+            return;
+        }
         let robot_id = self.robot_id;
         let sender = self.sender.clone();
         let battle_data_cp = self.clone_battle_data_ptr();
@@ -185,7 +214,77 @@ impl Clone for RobotData {
             robot_status: None,
             remember_map_cell: self.remember_map_cell.clone(),
             remember_size: self.remember_size,
+            profile: self.profile.clone(),
             sender: self.sender.clone(),
+            simulation: self.simulation,
+        }
+    }
+}
+
+///拿机器人的性格难度配置
+/// 机器人配置里面指定了就用指定的,否则跟随房间的ai等级
+pub fn get_robot_profile(robot_temp_id: u32, ai_level: u8) -> RobotProfileTemp {
+    let mut profile_id = ai_level as u32;
+    let robot_temp = crate::TEMPLATES
+        .robot_temp_mgr()
+        .get_temp_ref(&robot_temp_id);
+    if let Some(robot_temp) = robot_temp {
+        if robot_temp.profile > 0 {
+            profile_id = robot_temp.profile;
+        }
+    }
+    let res = crate::TEMPLATES
+        .robot_profile_temp_mgr()
+        .get_temp_ref(&profile_id);
+    match res {
+        Some(profile) => profile.clone(),
+        None => {
+            warn!(
+                "RobotProfileTemp is not find!profile_id:{},robot_temp_id:{}",
+                profile_id, robot_temp_id
+            );
+            //没有配置的时候保持原来的行为,ai等级就是记忆长度
+            let mut profile = RobotProfileTemp::default();
+            profile.remember_size = ai_level as u32;
+            profile
+        }
+    }
+}
+
+///拿托管用的性格难度配置
+pub fn get_auto_robot_profile() -> RobotProfileTemp {
+    let mut profile_id = 0;
+    let con_temp = crate::TEMPLATES
+        .constant_temp_mgr()
+        .temps
+        .get("auto_robot_profile");
+    if let Some(con_temp) = con_temp {
+        match u8::from_str(con_temp.value.as_str()) {
+            Ok(res) => profile_id = res,
+            Err(e) => warn!("{:?}", e),
+        }
+    }
+    get_robot_profile(0, profile_id)
+}
+
+///拿机器人的思考延迟,拿不到就用默认的
+pub fn robot_think_delay(battle_data: Option<*mut BattleData>, robot_id: u32) -> u64 {
+    let default_delay = RobotTask::default().delay;
+    let battle_data = match battle_data {
+        Some(battle_data) => battle_data,
+        None => return default_delay,
+    };
+    unsafe {
+        let battle_data = match battle_data.as_ref() {
+            Some(battle_data) => battle_data,
+            None => return default_delay,
+        };
+        match battle_data.battle_player.get(&robot_id) {
+            Some(battle_player) => match battle_player.robot_data.as_ref() {
+                Some(robot_data) => robot_data.think_delay(),
+                None => default_delay,
+            },
+            None => default_delay,
         }
     }
 }
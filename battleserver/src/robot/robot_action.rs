@@ -17,6 +17,8 @@ pub trait RobotStatusAction {
     fn get_status(&self) -> RobotStatus;
     fn get_robot_id(&self) -> u32;
     fn get_sender(&self) -> &Sender<RobotTask>;
+    ///思考延迟,机器人任务延迟多久执行
+    fn get_think_delay(&self) -> u64;
     fn send_2_battle(
         &self,
         target_index: usize,
@@ -26,6 +28,7 @@ pub trait RobotStatusAction {
         let mut robot_task = RobotTask::default();
         robot_task.action_type = robot_action_type;
         robot_task.robot_id = self.get_robot_id();
+        robot_task.delay = self.get_think_delay();
         let mut map = Map::new();
         map.insert("target_index".to_owned(), JsonValue::from(target_index));
         map.insert("cmd".to_owned(), JsonValue::from(cmd.into_u32()));
@@ -0,0 +1,464 @@
+use crate::battle::battle::BattleData;
+use crate::battle::battle_enum::ActionType;
+use crate::battle::battle_helper::build_action_unit_pt;
+use crate::battle::battle_player::BattlePlayer;
+use crate::battle::market::handler_buy;
+use crate::robot::goal_evaluator::buy_goal_evaluator::check_buy;
+use crate::robot::robot_helper::check_can_open;
+use crate::robot::robot_skill::{skill_condition, skill_target};
+use crate::robot::RobotData;
+use crate::room::map_data::MapCellType;
+use crate::task_timer::Task;
+use crossbeam::channel::Receiver;
+use log::info;
+use rand::seq::SliceRandom;
+use std::collections::HashSet;
+use tools::thread_pool::ThreadIndex;
+
+use super::robot_task_mgr::RobotTask;
+
+///自己死亡的分数
+const SCORE_SELF_DIED: i32 = -100000;
+///每击杀一个敌人的分数
+const SCORE_KILL: i32 = 500;
+///一次推演最多克隆多少份战斗数据,超过了就不再往下展开
+const MAX_SIMULATIONS: u32 = 256;
+
+///推演出来的行为
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlanAction {
+    Open(usize),          //翻地图块,地图块下标
+    Attack(usize),        //普通攻击,目标所在地图块下标
+    Skill(u32, Vec<u32>), //使用技能,技能id,目标
+    Buy(u32),             //购买,商品id
+    Skip,                 //结束turn
+}
+
+///推演参数,由机器人配置的难度等级决定
+#[derive(Debug, Clone, Copy)]
+pub struct PlanParam {
+    pub depth: u8,    //推演深度(本turn内连续行动的次数)
+    pub samples: u32, //每个行为的采样次数(未知地图块随机分布的次数)
+}
+
+impl PlanParam {
+    pub fn from_level(level: u8) -> Self {
+        match level {
+            0 | 1 => PlanParam {
+                depth: 1,
+                samples: 4,
+            },
+            2 => PlanParam {
+                depth: 2,
+                samples: 6,
+            },
+            _ => PlanParam {
+                depth: 2,
+                samples: 12,
+            },
+        }
+    }
+}
+
+///推演用的战斗数据副本
+/// 所有sender都换成了本地的channel,推演过程不会有任何消息发出去
+/// 机器人的指针都指向副本自己,不会碰到真实战斗的数据
+pub struct Simulation {
+    pub battle_data: Box<BattleData>,
+    _tcp_rec: Receiver<Vec<u8>>,
+    _task_rec: Receiver<Task>,
+    _robot_rec: Receiver<RobotTask>,
+}
+
+//new里面已经把所有机器人指针换成了副本自己的,没有状态机,可以整个交给推演线程
+unsafe impl Send for Simulation {}
+
+impl Simulation {
+    ///从真实战斗数据克隆一份
+    pub fn new(battle_data: &BattleData) -> Self {
+        let (tcp_sender, tcp_rec) = crossbeam::channel::unbounded();
+        let (task_sender, task_rec) = crossbeam::channel::unbounded();
+        let (robot_sender, robot_rec) = crossbeam::channel::unbounded();
+        let mut sim = Box::new(battle_data.clone());
+        sim.tcp_sender = tcp_sender;
+        sim.task_sender = task_sender;
+        let sim_ptr = sim.as_mut() as *mut BattleData;
+        for battle_player in sim.battle_player.values_mut() {
+            if let Some(robot_data) = battle_player.robot_data.as_mut() {
+                robot_data.battle_data = sim_ptr;
+                robot_data.robot_status = None;
+                robot_data.sender = robot_sender.clone();
+                robot_data.simulation = true;
+            }
+        }
+        let sim = Simulation {
+            battle_data: sim,
+            _tcp_rec: tcp_rec,
+            _task_rec: task_rec,
+            _robot_rec: robot_rec,
+        };
+        debug_assert!(sim.is_detached());
+        sim
+    }
+
+    ///是否已经和真实战斗完全脱离,机器人指针都指向自己,没有状态机
+    pub fn is_detached(&self) -> bool {
+        let sim_ptr = self.battle_data.as_ref() as *const BattleData;
+        self.battle_data
+            .battle_player
+            .values()
+            .filter_map(|battle_player| battle_player.robot_data.as_ref())
+            .all(|robot_data| {
+                robot_data.battle_data as *const BattleData == sim_ptr
+                    && robot_data.robot_status.is_none()
+                    && robot_data.simulation
+            })
+    }
+
+    ///再克隆一份,用于往下一层推演
+    pub fn fork(&self) -> Self {
+        Simulation::new(self.battle_data.as_ref())
+    }
+
+    ///把机器人看不到的地图块打乱,只保留机器人知道的信息
+    pub fn determinize(&mut self, robot_id: u32) {
+        let battle_data = self.battle_data.as_mut();
+        let known = known_map_cells(battle_data, robot_id);
+        let mut unknown = vec![];
+        for (&index, _) in battle_data.tile_map.un_pair_map.iter() {
+            if known.contains(&index) {
+                continue;
+            }
+            unknown.push(index);
+        }
+        if unknown.len() < 2 {
+            return;
+        }
+        unknown.sort();
+        let mut shuffled = unknown.clone();
+        shuffled.shuffle(&mut rand::thread_rng());
+        let map_cells = &mut battle_data.tile_map.map_cells;
+        let contents: Vec<_> = shuffled
+            .iter()
+            .map(|&index| {
+                let map_cell = map_cells.get(index).unwrap();
+                (
+                    map_cell.id,
+                    map_cell.element,
+                    map_cell.buffs.clone(),
+                    map_cell.passive_buffs.clone(),
+                )
+            })
+            .collect();
+        for (&index, (id, element, buffs, passive_buffs)) in unknown.iter().zip(contents) {
+            let map_cell = map_cells.get_mut(index).unwrap();
+            map_cell.id = id;
+            map_cell.element = element;
+            map_cell.buffs = buffs;
+            map_cell.passive_buffs = passive_buffs;
+            battle_data.tile_map.un_pair_map.insert(index, id);
+        }
+    }
+
+    ///按真实规则执行行为,返回是否执行成功
+    pub fn apply(&mut self, robot_id: u32, action: &PlanAction) -> bool {
+        let battle_data = self.battle_data.as_mut();
+        let battle_player = battle_data.battle_player.get(&robot_id);
+        if battle_player.is_none() {
+            return false;
+        }
+        let battle_player = battle_player.unwrap();
+        if battle_player.is_died() || battle_player.is_locked() {
+            return false;
+        }
+        let cter_id = battle_player.current_cter.0;
+        match action {
+            PlanAction::Open(index) => {
+                if !check_open_index(battle_data, battle_player, *index) {
+                    return false;
+                }
+                let mut au = build_action_unit_pt(cter_id, ActionType::Open, Some(*index as u32));
+                battle_data.open_map_cell(cter_id, *index, &mut au).is_ok()
+            }
+            PlanAction::Attack(index) => {
+                if !battle_player.is_can_attack() {
+                    return false;
+                }
+                let mut au = build_action_unit_pt(cter_id, ActionType::Attack, None);
+                unsafe {
+                    battle_data
+                        .attack(cter_id, vec![*index as u32], &mut au)
+                        .is_ok()
+                }
+            }
+            PlanAction::Skill(skill_id, targets) => {
+                let mut au = build_action_unit_pt(cter_id, ActionType::Skill, Some(*skill_id));
+                battle_data
+                    .use_skill(cter_id, *skill_id, false, targets.clone(), &mut au)
+                    .is_ok()
+            }
+            PlanAction::Buy(merchandise_id) => {
+                if battle_player.get_current_cter_index() != battle_data.tile_map.market_cell.0 {
+                    return false;
+                }
+                handler_buy(battle_data, robot_id, *merchandise_id);
+                true
+            }
+            PlanAction::Skip => true,
+        }
+    }
+}
+
+///机器人知道的地图块:翻开的,配对的,非普通块,以及记住的
+fn known_map_cells(battle_data: &BattleData, robot_id: u32) -> HashSet<usize> {
+    let mut res = HashSet::new();
+    for map_cell in battle_data.tile_map.map_cells.iter() {
+        if map_cell.open_cter > 0
+            || map_cell.pair_index.is_some()
+            || map_cell.cell_type != MapCellType::Valid
+        {
+            res.insert(map_cell.index);
+        }
+    }
+    let robot = battle_data.battle_player.get(&robot_id);
+    if let Some(robot_data) = robot.and_then(|robot| robot.robot_data.as_ref()) {
+        for re_cell in robot_data.remember_map_cell.iter() {
+            res.insert(re_cell.cell_index);
+        }
+    }
+    res
+}
+
+///和战斗handler里面翻块前的校验保持一致
+fn check_open_index(battle_data: &BattleData, battle_player: &BattlePlayer, index: usize) -> bool {
+    if battle_player.flow_data.residue_movement_points == 0 {
+        return false;
+    }
+    if battle_player
+        .flow_data
+        .open_map_cell_vec_history
+        .contains(&index)
+    {
+        return false;
+    }
+    let res = battle_data.check_choice_index(index, false, true, true, true, true, false);
+    if res.is_err() {
+        return false;
+    }
+    let map_cell = battle_data.tile_map.map_cells.get(index).unwrap();
+    if map_cell.is_market() && battle_player.get_current_cter_index() == index {
+        return false;
+    }
+    check_can_open(battle_player.current_cter.0, map_cell, battle_data)
+}
+
+///列出当前可以做的所有行为
+pub fn candidate_actions(battle_data: &BattleData, robot_data: &RobotData) -> Vec<PlanAction> {
+    let mut res = vec![];
+    let robot_id = robot_data.robot_id;
+    let battle_player = battle_data.battle_player.get(&robot_id);
+    if battle_player.is_none() {
+        return res;
+    }
+    let battle_player = battle_player.unwrap();
+    if battle_player.is_died() || battle_player.is_locked() {
+        return res;
+    }
+
+    //翻块,包括走去商店
+    let market_index = battle_data.tile_map.market_cell.0;
+    let mut indexes: Vec<usize> = battle_data.tile_map.un_pair_map.keys().copied().collect();
+    if !battle_player.is_world_boss && !check_buy(battle_player, robot_data.temp_id).is_empty() {
+        indexes.push(market_index);
+    }
+    indexes.sort();
+    for index in indexes {
+        if check_open_index(battle_data, battle_player, index) {
+            res.push(PlanAction::Open(index));
+        }
+    }
+
+    //普通攻击
+    if battle_player.is_can_attack() {
+        for other in battle_data.battle_player.values() {
+            if other.get_user_id() == robot_id || other.is_died() {
+                continue;
+            }
+            if other.team_id == battle_player.team_id {
+                continue;
+            }
+            if !other.get_current_cter().map_cell_index_is_choiced() {
+                continue;
+            }
+            res.push(PlanAction::Attack(other.get_current_cter_index()));
+        }
+    }
+
+    //技能
+    for skill in battle_player.get_current_cter().skills.values() {
+        if !skill_condition(battle_data, skill, robot_data) {
+            continue;
+        }
+        let targets = skill_target(battle_data, skill, robot_data);
+        if let Ok(targets) = targets {
+            let targets = targets.iter().map(|&index| index as u32).collect();
+            res.push(PlanAction::Skill(skill.id, targets));
+        }
+    }
+
+    //购买
+    if battle_player.get_current_cter_index() == market_index {
+        for merchandise_id in check_buy(battle_player, robot_data.temp_id) {
+            res.push(PlanAction::Buy(merchandise_id));
+        }
+    }
+
+    res.push(PlanAction::Skip);
+    res
+}
+
+///局面评分,站在机器人的角度
+pub fn evaluate(battle_data: &BattleData, robot_id: u32) -> i32 {
+    let robot = battle_data.battle_player.get(&robot_id);
+    if robot.is_none() {
+        return SCORE_SELF_DIED;
+    }
+    let robot = robot.unwrap();
+    if robot.is_died() {
+        return SCORE_SELF_DIED;
+    }
+    let cter = robot.get_current_cter();
+    let mut score = cter.base_attr.hp as i32 * 10
+        + cter.base_attr.atk as i32 * 15
+        + cter.base_attr.energy as i32 * 3
+        + robot.gold;
+    if robot.is_can_attack() {
+        score += 30;
+    }
+    if robot.status.is_pair {
+        score += 20;
+    }
+    for other in battle_data.battle_player.values() {
+        if other.get_user_id() == robot_id || other.team_id == robot.team_id {
+            continue;
+        }
+        if other.is_died() {
+            score += SCORE_KILL;
+            continue;
+        }
+        score -= other.get_current_cter().base_attr.hp as i32 * 8;
+    }
+    score
+}
+
+///往下推演,返回这个局面往后能达到的最好分数
+/// 每往下展开一个行为消耗一次budget,用完了就直接用当前局面的分数
+fn search(sim: &Simulation, robot_data: &RobotData, depth: u8, budget: &mut u32) -> i32 {
+    let robot_id = robot_data.robot_id;
+    let score = evaluate(sim.battle_data.as_ref(), robot_id);
+    if depth == 0 || *budget == 0 {
+        return score;
+    }
+    let mut best = score;
+    for action in candidate_actions(sim.battle_data.as_ref(), robot_data) {
+        if action == PlanAction::Skip {
+            continue;
+        }
+        if *budget == 0 {
+            break;
+        }
+        *budget -= 1;
+        let mut next = sim.fork();
+        if !next.apply(robot_id, &action) {
+            continue;
+        }
+        let res = search(&next, robot_data, depth - 1, budget);
+        if res > best {
+            best = res;
+        }
+    }
+    best
+}
+
+///推演出当前最好的行为
+/// 每次采样打乱一次未知地图块,所有候选行为都在同一个打乱的局面上执行,取平均分最高的
+/// 克隆次数不超过MAX_SIMULATIONS,候选太多的时候减少采样次数和往下推演的数量
+pub fn plan(battle_data: &BattleData, robot_data: &RobotData, param: PlanParam) -> PlanAction {
+    let robot_id = robot_data.robot_id;
+    let candidates = candidate_actions(battle_data, robot_data);
+    if candidates.len() <= 1 {
+        return candidates.into_iter().next().unwrap_or(PlanAction::Skip);
+    }
+    //每次采样克隆一份打乱的局面,再给每个候选克隆一份
+    let count = candidates.len() as u32;
+    let samples = param
+        .samples
+        .clamp(1, std::cmp::max(1, MAX_SIMULATIONS / (count + 1)));
+    //剩下的克隆次数平分给每个候选往下推演
+    let rest = MAX_SIMULATIONS.saturating_sub(samples * (count + 1));
+    let search_budget = rest / (samples * count);
+
+    let mut totals: Vec<Option<i64>> = vec![Some(0); candidates.len()];
+    for _ in 0..samples {
+        let mut base = Simulation::new(battle_data);
+        base.determinize(robot_id);
+        for (action, total) in candidates.iter().zip(totals.iter_mut()) {
+            if total.is_none() {
+                continue;
+            }
+            let mut sim = base.fork();
+            if !sim.apply(robot_id, action) {
+                *total = None;
+                continue;
+            }
+            let depth = if *action == PlanAction::Skip {
+                0
+            } else {
+                param.depth.saturating_sub(1)
+            };
+            let mut budget = search_budget;
+            let score = search(&sim, robot_data, depth, &mut budget);
+            if let Some(total) = total.as_mut() {
+                *total += score as i64;
+            }
+        }
+    }
+    let mut best = (PlanAction::Skip, i64::MIN);
+    for (action, total) in candidates.into_iter().zip(totals) {
+        let total = match total {
+            Some(total) => total,
+            None => continue,
+        };
+        let avg = total / samples as i64;
+        if avg > best.1 {
+            best = (action, avg);
+        }
+    }
+    info!(
+        "机器人推演结果!robot_id:{},action:{:?},score:{}",
+        robot_id, best.0, best.1
+    );
+    best.0
+}
+
+///克隆一份战斗数据快照,扔到推演线程池里面推演,推演完回调
+/// 战斗线程只做一次克隆,搜索全部在快照上面做
+pub fn plan_async<F>(battle_data: &BattleData, robot_id: u32, param: PlanParam, callback: F)
+where
+    F: FnOnce(PlanAction) + Send + 'static,
+{
+    let snapshot = Simulation::new(battle_data);
+    let m = move || {
+        let battle_data = snapshot.battle_data.as_ref();
+        let robot_data = battle_data
+            .battle_player
+            .get(&robot_id)
+            .and_then(|battle_player| battle_player.robot_data.as_ref());
+        let action = match robot_data {
+            Some(robot_data) => plan(battle_data, robot_data, param),
+            None => PlanAction::Skip,
+        };
+        callback(action);
+    };
+    crate::ROBOT_PLAN_POOL.execute(ThreadIndex::Rankdom, m);
+}
@@ -4,6 +4,7 @@ use log::{error, warn};
 use rand::Rng;
 use serde_json::{Map, Value};
 use tools::cmd_code::BattleCode;
+use tools::templates::robot_profile_temp::{TARGET_PREFERENCE_HP_MIN, TARGET_PREFERENCE_LEADER};
 
 use crate::battle::battle_enum::SkillConsumeType;
 use crate::battle::{battle_enum::TargetType, battle_player::BattlePlayer};
@@ -38,6 +39,7 @@ pub fn robot_use_skill(battle_data: &mut BattleData, skill: &Skill, robot: &Robo
     let mut robot_task = RobotTask::default();
     robot_task.action_type = RobotActionType::Skill;
     robot_task.robot_id = robot_id;
+    robot_task.delay = robot.think_delay();
     let mut map = Map::new();
     map.insert("target_index".to_owned(), Value::from(targets));
     map.insert("cmd".to_owned(), Value::from(BattleCode::Action.into_u32()));
@@ -252,7 +254,7 @@ pub fn skill_target(
         }
         //除自己外最大血量的目标
         i if [123, 331, 433, 20001, 20002, 20003, 20004, 20005].contains(&i) => {
-            let res = get_hp_max_cter(battle_data, robot_id, None);
+            let res = get_hp_max_cter(battle_data, robot_id, Some(team_id));
             match res {
                 Some(res) => targets.push(res),
                 None => warn!("get_hp_max_cter res is None!"),
@@ -795,6 +797,50 @@ pub fn get_hp_max_cter(
     Some(res.1)
 }
 
+///按目标偏好获得除robot_id之外的角色位置
+pub fn get_preference_cter(
+    battle_data: &BattleData,
+    robot_id: u32,
+    team_id: Option<u8>,
+    preference: u8,
+) -> Option<usize> {
+    if preference != TARGET_PREFERENCE_HP_MIN && preference != TARGET_PREFERENCE_LEADER {
+        return get_hp_max_cter(battle_data, robot_id, team_id);
+    }
+    let mut res: Option<(i32, usize)> = None;
+    for battle_player in battle_data.battle_player.values() {
+        //排除死掉的
+        if battle_player.is_died() {
+            continue;
+        }
+        //排除自己所有的角色
+        if battle_player.get_user_id() == robot_id {
+            continue;
+        }
+        if let Some(team_id) = team_id {
+            if battle_player.team_id == team_id {
+                continue;
+            }
+        }
+        for battle_cter in battle_player.cters.values() {
+            if battle_cter.is_died() || !battle_cter.map_cell_index_is_choiced() {
+                continue;
+            }
+            let hp = battle_cter.base_attr.hp as i32;
+            //血最少的,或者金币最多的(金币一样就找血多的)
+            let value = if preference == TARGET_PREFERENCE_HP_MIN {
+                -hp
+            } else {
+                battle_player.gold * 1000 + hp
+            };
+            if res.is_none() || value > res.unwrap().0 {
+                res = Some((value, battle_cter.get_map_cell_index()));
+            }
+        }
+    }
+    res.map(|(_, index)| index)
+}
+
 pub fn pair_useable_skill(robot: &BattlePlayer) -> bool {
     robot.flow_data.pair_usable_skills.contains(&331)
 }
@@ -846,37 +892,7 @@ pub fn get_roundness_aoe(
         }
 
         //以一个地图块为中心点，计算它的周围
-        for i in 0..6 {
-            let mut coord_index = (map_cell.x, map_cell.y);
-            match i {
-                0 => {
-                    coord_index.0 -= 1;
-                    coord_index.1 += 1;
-                }
-                1 => {
-                    coord_index.1 += 1;
-                }
-                2 => {
-                    coord_index.0 += 1;
-                }
-                3 => {
-                    coord_index.0 += 1;
-                    coord_index.1 -= 1;
-                }
-                4 => {
-                    coord_index.1 -= 1;
-                }
-                5 => {
-                    coord_index.0 -= 1;
-                }
-                _ => {}
-            }
-
-            let cell_index = battle_data.tile_map.coord_map.get(&coord_index);
-            if cell_index.is_none() {
-                continue;
-            }
-            let cell_index = *cell_index.unwrap();
+        for cell_index in battle_data.tile_map.near_indexes(map_cell.index) {
             let cell = battle_data.tile_map.map_cells.get(cell_index);
             if cell.is_none() {
                 continue;
@@ -997,11 +1013,13 @@ pub fn get_triangle_aoe(
                 }
                 _ => coord_index = (map_cell.x, map_cell.y),
             }
-            let index = battle_data.tile_map.coord_map.get(&temp_index);
+            let index = battle_data
+                .tile_map
+                .get_index_by_coord(temp_index.0, temp_index.1);
             if index.is_none() {
                 continue;
             }
-            let index = *index.unwrap();
+            let index = index.unwrap();
             let res_cell = battle_data.tile_map.map_cells.get(index);
             if res_cell.is_none() {
                 continue;
@@ -1132,11 +1150,13 @@ pub fn get_line_aoe(cter_id: u32, battle_data: &BattleData) -> Option<(u32, Vec<
                     _ => {}
                 }
                 let coord_index = (coord_index_x, coord_index_y);
-                let res = battle_data.tile_map.coord_map.get(&coord_index);
+                let res = battle_data
+                    .tile_map
+                    .get_index_by_coord(coord_index.0, coord_index.1);
                 if res.is_none() {
                     continue;
                 }
-                let index = *res.unwrap();
+                let index = res.unwrap();
                 let map_cell = battle_data.tile_map.map_cells.get(index);
                 match map_cell {
                     Some(map_cell) => {
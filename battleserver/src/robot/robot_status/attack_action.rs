@@ -1,7 +1,7 @@
 use super::*;
 use crate::{
     battle::battle_enum::{TargetType, TRIGGER_SCOPE_CENTER_NEAR_TEMP_ID},
-    robot::{robot_skill::get_hp_max_cter, RobotActionType},
+    robot::{robot_skill::get_preference_cter, RobotActionType},
 };
 use log::warn;
 use tools::cmd_code::BattleCode;
@@ -57,10 +57,17 @@ impl RobotStatusAction for AttackRobotAction {
         let robot_id = self.robot_id;
         let robot = battle_data.battle_player.get(&robot_id).unwrap();
         let cter_id = robot.current_cter.0;
+        let preference = robot.robot_data.as_ref().unwrap().profile.target_preference;
         let skill_scope_temp = crate::TEMPLATES
             .skill_scope_temp_mgr()
             .get_temp(&TRIGGER_SCOPE_CENTER_NEAR_TEMP_ID)
             .unwrap();
+        //组队模式不打队友
+        let team_id = if battle_data.room_type.is_team_type() {
+            Some(robot.team_id)
+        } else {
+            None
+        };
         //如果有这个buff，就找人最多的
         if robot.get_current_cter().is_has_add_attack_and_aoe() {
             let mut player_index;
@@ -75,6 +82,9 @@ impl RobotStatusAction for AttackRobotAction {
                 if user_id == robot_id {
                     continue;
                 }
+                if team_id.is_some() && team_id.unwrap() == player.team_id {
+                    continue;
+                }
                 let (_, count_v) = battle_data.cal_scope(
                     cter_id,
                     player_index as isize,
@@ -91,8 +101,8 @@ impl RobotStatusAction for AttackRobotAction {
             if player_count.1 > 1 {
                 target_index = player_count.0;
             } else {
-                //如果没有就找血最多的
-                let res = get_hp_max_cter(battle_data, robot_id, None);
+                //如果没有就按目标偏好找
+                let res = get_preference_cter(battle_data, robot_id, team_id, preference);
                 if let None = res {
                     warn!("attack counld not find target!robot_id:{}", robot_id);
                     return;
@@ -100,8 +110,8 @@ impl RobotStatusAction for AttackRobotAction {
                 target_index = res.unwrap();
             }
         } else {
-            //如果没有就找血最多的
-            let res = get_hp_max_cter(battle_data, robot_id, None);
+            //如果没有就按目标偏好找
+            let res = get_preference_cter(battle_data, robot_id, team_id, preference);
             if let None = res {
                 warn!("attack counld not find target!robot_id:{}", robot_id);
                 return;
@@ -126,4 +136,8 @@ impl RobotStatusAction for AttackRobotAction {
     fn get_sender(&self) -> &Sender<RobotTask> {
         self.sender.as_ref().unwrap()
     }
+
+    fn get_think_delay(&self) -> u64 {
+        robot_think_delay(self.battle_data, self.robot_id)
+    }
 }
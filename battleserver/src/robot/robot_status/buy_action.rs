@@ -6,7 +6,7 @@ use crate::{
     battle::battle::BattleData,
     robot::{
         goal_evaluator::buy_goal_evaluator::check_buy, robot_action::RobotStatusAction,
-        robot_task_mgr::RobotTask, RobotActionType,
+        robot_task_mgr::RobotTask, robot_think_delay, RobotActionType,
     },
 };
 
@@ -50,6 +50,7 @@ impl BuyRobotAction {
         let mut robot_task = RobotTask::default();
         robot_task.action_type = robot_action_type;
         robot_task.robot_id = self.robot_id;
+        robot_task.delay = self.get_think_delay();
         let mut map = serde_json::Map::new();
         map.insert(
             "merchandise_id".to_owned(),
@@ -120,4 +121,8 @@ impl RobotStatusAction for BuyRobotAction {
     fn get_sender(&self) -> &Sender<RobotTask> {
         self.sender.as_ref().unwrap()
     }
+
+    fn get_think_delay(&self) -> u64 {
+        robot_think_delay(self.battle_data, self.robot_id)
+    }
 }
@@ -90,4 +90,8 @@ impl RobotStatusAction for ChoiceIndexRobotAction {
     fn get_sender(&self) -> &Sender<RobotTask> {
         self.sender.as_ref().unwrap()
     }
+
+    fn get_think_delay(&self) -> u64 {
+        robot_think_delay(self.battle_data, self.robot_id)
+    }
 }
@@ -0,0 +1,137 @@
+use super::*;
+use crate::robot::goal_evaluator::lookahead_goal_evaluator::get_robot_level;
+use crate::robot::robot_planner::{plan_async, PlanAction, PlanParam};
+use crate::robot::RobotActionType;
+use crate::JsonValue;
+use log::{error, warn};
+use serde_json::Map;
+use std::time::Instant;
+use tools::cmd_code::BattleCode;
+
+///推演搜索状态,推演出最好的行为之后发给战斗
+#[derive(Default)]
+pub struct LookaheadRobotAction {
+    pub robot_id: u32,
+    pub cter_id: u32,
+    pub temp_id: u32,
+    pub battle_data: Option<*mut BattleData>,
+    pub status: RobotStatus,
+    pub sender: Option<Sender<RobotTask>>,
+}
+
+get_mut_ref!(LookaheadRobotAction);
+
+impl LookaheadRobotAction {
+    pub fn get_battle_data_mut_ref(&self) -> Option<&mut BattleData> {
+        unsafe {
+            if self.battle_data.unwrap().is_null() {
+                return None;
+            }
+
+            Some(self.battle_data.unwrap().as_mut().unwrap())
+        }
+    }
+
+    pub fn new(battle_data: *mut BattleData, sender: Sender<RobotTask>) -> Self {
+        let mut lookahead_action = LookaheadRobotAction::default();
+        lookahead_action.battle_data = Some(battle_data);
+        lookahead_action.sender = Some(sender);
+        lookahead_action
+    }
+}
+
+///把推演结果转换成机器人任务
+fn build_task(robot_id: u32, action: PlanAction, delay: u64) -> RobotTask {
+    let mut map = Map::new();
+    let (action_type, cmd) = match action {
+        PlanAction::Open(index) => {
+            map.insert("value".to_owned(), JsonValue::from(index));
+            (RobotActionType::Open, BattleCode::Action)
+        }
+        PlanAction::Attack(index) => {
+            map.insert("target_index".to_owned(), JsonValue::from(index));
+            (RobotActionType::Attack, BattleCode::Action)
+        }
+        PlanAction::Skill(skill_id, targets) => {
+            map.insert("target_index".to_owned(), JsonValue::from(targets));
+            map.insert("skill_id".to_owned(), JsonValue::from(skill_id));
+            (RobotActionType::Skill, BattleCode::Action)
+        }
+        PlanAction::Buy(merchandise_id) => {
+            map.insert("merchandise_id".to_owned(), JsonValue::from(merchandise_id));
+            (RobotActionType::Buy, BattleCode::Buy)
+        }
+        PlanAction::Skip => {
+            map.insert("target_index".to_owned(), JsonValue::from(0));
+            (RobotActionType::Skip, BattleCode::Action)
+        }
+    };
+    map.insert("cmd".to_owned(), JsonValue::from(cmd.into_u32()));
+    let mut robot_task = RobotTask::default();
+    robot_task.action_type = action_type;
+    robot_task.robot_id = robot_id;
+    robot_task.delay = delay;
+    robot_task.data = JsonValue::from(map);
+    robot_task
+}
+
+impl RobotStatusAction for LookaheadRobotAction {
+    fn set_sender(&mut self, sender: Sender<RobotTask>) {
+        self.sender = Some(sender);
+    }
+
+    fn enter(&self) {
+        info!("robot:{} 进入推演状态！", self.robot_id);
+        self.execute();
+    }
+
+    fn execute(&self) {
+        let battle_data = self.get_battle_data_mut_ref();
+        if battle_data.is_none() {
+            warn!("the point *const BattleData is null!");
+            return;
+        }
+        let battle_data = battle_data.unwrap();
+        let battle_player = battle_data.battle_player.get(&self.robot_id);
+        if battle_player.is_none() {
+            warn!("robot's cter is None!robot_id:{}", self.robot_id);
+            return;
+        }
+        let robot_data = battle_player.unwrap().robot_data.as_ref().unwrap();
+        let param = PlanParam::from_level(get_robot_level(robot_data));
+
+        //推演在线程池里面做,结果回来再按思考延迟发给战斗,推演花掉的时间算在延迟里面
+        let robot_id = self.robot_id;
+        let delay = self.get_think_delay();
+        let sender = self.get_sender().clone();
+        let start_time = Instant::now();
+        plan_async(battle_data, robot_id, param, move |action| {
+            let cost = start_time.elapsed().as_millis() as u64;
+            let robot_task = build_task(robot_id, action, delay.saturating_sub(cost));
+            let res = sender.send(robot_task);
+            if let Err(e) = res {
+                error!("{:?}", e);
+            }
+        });
+    }
+
+    fn exit(&self) {
+        // info!("robot:{} 退出推演状态！", self.robot_id);
+    }
+
+    fn get_status(&self) -> RobotStatus {
+        self.status
+    }
+
+    fn get_robot_id(&self) -> u32 {
+        self.robot_id
+    }
+
+    fn get_sender(&self) -> &Sender<RobotTask> {
+        self.sender.as_ref().unwrap()
+    }
+
+    fn get_think_delay(&self) -> u64 {
+        robot_think_delay(self.battle_data, self.robot_id)
+    }
+}
@@ -1,6 +1,7 @@
 pub mod attack_action;
 pub mod buy_action;
 pub mod choice_index_action;
+pub mod lookahead_action;
 pub mod open_cell_action;
 pub mod skip_action;
 pub mod unlock_action;
@@ -10,6 +11,7 @@ pub mod use_skill_action;
 use crate::battle::battle::BattleData;
 use crate::robot::robot_action::RobotStatusAction;
 use crate::robot::robot_task_mgr::RobotTask;
+use crate::robot::robot_think_delay;
 use crossbeam::channel::Sender;
 use rand::Rng;
 
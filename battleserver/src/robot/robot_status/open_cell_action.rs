@@ -200,6 +200,10 @@ impl RobotStatusAction for OpenCellRobotAction {
         self.sender.as_ref().unwrap()
     }
 
+    fn get_think_delay(&self) -> u64 {
+        robot_think_delay(self.battle_data, self.robot_id)
+    }
+
     fn send_2_battle(
         &self,
         target_index: usize,
@@ -209,6 +213,7 @@ impl RobotStatusAction for OpenCellRobotAction {
         let mut robot_task = RobotTask::default();
         robot_task.action_type = robot_action_type;
         robot_task.robot_id = self.robot_id;
+        robot_task.delay = self.get_think_delay();
         let mut map = Map::new();
         map.insert("value".to_owned(), JsonValue::from(target_index));
         map.insert("cmd".to_owned(), JsonValue::from(cmd.into_u32()));
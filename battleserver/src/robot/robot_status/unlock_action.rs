@@ -72,4 +72,8 @@ impl RobotStatusAction for UnlockRobotAction {
     fn get_sender(&self) -> &Sender<RobotTask> {
         self.sender.as_ref().unwrap()
     }
+
+    fn get_think_delay(&self) -> u64 {
+        robot_think_delay(self.battle_data, self.robot_id)
+    }
 }
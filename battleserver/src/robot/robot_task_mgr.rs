@@ -71,6 +71,23 @@ pub fn robot_init_timer(bm: Lock) {
     info!("初始化定时器任务执行器成功!");
 }
 
+///执行机器人的命令,已经不归机器人控制的(比如托管结束了)就不执行
+fn robot_invok(rm: Lock, cmd: u32, packet: Packet) {
+    let lock = block_on(rm.lock());
+    let robot_id = packet.get_user_id();
+    let is_robot_controlled = lock
+        .get_room_ref(&robot_id)
+        .and_then(|room| room.get_battle_player_ref(&robot_id))
+        .map_or(false, |battle_player| battle_player.is_robot_controlled());
+    if !is_robot_controlled {
+        return;
+    }
+    //拿到BattleMgr的可变指针
+    let rm_mut_ref = lock.get_mut_ref();
+    let func = lock.cmd_map.get(&cmd).unwrap();
+    func(rm_mut_ref, packet);
+}
+
 ///机器人选择站位函数
 pub fn choice_index(rm: Lock, task: RobotTask) {
     let json_value = task.data;
@@ -88,11 +105,7 @@ pub fn choice_index(rm: Lock, task: RobotTask) {
     proto.set_index(target_index);
     packet.set_data(proto.write_to_bytes().unwrap().as_slice());
 
-    let lock = block_on(rm.lock());
-    //拿到BattleMgr的可变指针
-    let rm_mut_ref = lock.get_mut_ref();
-    let func = lock.cmd_map.get(&cmd).unwrap();
-    func(rm_mut_ref, packet);
+    robot_invok(rm, cmd, packet);
 }
 
 ///普通攻击
@@ -111,12 +124,7 @@ pub fn buy(rm: Lock, task: RobotTask) {
     let mut ca = C_BUY::new();
     ca.merchandise_id = merchandise_id;
     packet.set_data(ca.write_to_bytes().unwrap().as_slice());
-    //解锁,获得函数指针，执行普通攻击逻辑
-    let lock = block_on(rm.lock());
-    //拿到BattleMgr的可变指针
-    let rm_mut_ref = lock.get_mut_ref();
-    let func = lock.cmd_map.get(&cmd).unwrap();
-    func(rm_mut_ref, packet);
+    robot_invok(rm, cmd, packet);
 }
 
 pub fn unlock(rm: Lock, task: RobotTask) {
@@ -134,12 +142,7 @@ pub fn unlock(rm: Lock, task: RobotTask) {
     ca.target_index.push(target_index);
     ca.action_type = ActionType::EndShowMapCell.into_u32();
     packet.set_data(ca.write_to_bytes().unwrap().as_slice());
-    //解锁,获得函数指针，执行普通攻击逻辑
-    let lock = block_on(rm.lock());
-    //拿到BattleMgr的可变指针
-    let rm_mut_ref = lock.get_mut_ref();
-    let func = lock.cmd_map.get(&cmd).unwrap();
-    func(rm_mut_ref, packet);
+    robot_invok(rm, cmd, packet);
 }
 
 ///普通攻击
@@ -162,12 +165,7 @@ pub fn attack(rm: Lock, task: RobotTask) {
     ca.target_index.push(target_index);
     ca.action_type = ActionType::Attack.into_u32();
     packet.set_data(ca.write_to_bytes().unwrap().as_slice());
-    //解锁,获得函数指针，执行普通攻击逻辑
-    let lock = block_on(rm.lock());
-    //拿到BattleMgr的可变指针
-    let rm_mut_ref = lock.get_mut_ref();
-    let func = lock.cmd_map.get(&cmd).unwrap();
-    func(rm_mut_ref, packet);
+    robot_invok(rm, cmd, packet);
 }
 
 ///打开地图块
@@ -187,12 +185,7 @@ pub fn open_cell(rm: Lock, task: RobotTask) {
     ca.value = value;
     ca.action_type = ActionType::Open.into_u32();
     packet.set_data(ca.write_to_bytes().unwrap().as_slice());
-    //解锁,获得函数指针，执行普通攻击逻辑
-    let lock = block_on(rm.lock());
-    //拿到BattleMgr的可变指针
-    let rm_mut_ref = lock.get_mut_ref();
-    let func = lock.cmd_map.get(&cmd).unwrap();
-    func(rm_mut_ref, packet);
+    robot_invok(rm, cmd, packet);
 }
 
 ///跳过回合
@@ -214,12 +207,7 @@ pub fn skip_turn(rm: Lock, task: RobotTask) {
     let mut ca = C_ACTION::new();
     ca.action_type = ActionType::Skip.into_u32();
     packet.set_data(ca.write_to_bytes().unwrap().as_slice());
-    //解锁,获得函数指针，执行普通攻击逻辑
-    let lock = block_on(rm.lock());
-    //拿到BattleMgr的可变指针
-    let rm_mut_ref = lock.get_mut_ref();
-    let func = lock.cmd_map.get(&cmd).unwrap();
-    func(rm_mut_ref, packet);
+    robot_invok(rm, cmd, packet);
 }
 
 ///使用技能
@@ -244,11 +232,7 @@ pub fn use_skill(rm: Lock, task: RobotTask) {
 
     let mut packet = Packet::new(cmd, 0, robot_id);
     packet.set_data(proto.write_to_bytes().unwrap().as_slice());
-    let lock = block_on(rm.lock());
-    //拿到BattleMgr的可变指针
-    let rm_mut_ref = lock.get_mut_ref();
-    let func = lock.cmd_map.get(&cmd).unwrap();
-    func(rm_mut_ref, packet);
+    robot_invok(rm, cmd, packet);
 }
 
 ///使用道具
@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+///投票结束战斗的有效时间(毫秒),过了还没通过就重新投
+pub const END_VOTE_TIMEOUT: i64 = 30_000;
+
+///投票结束战斗,有投票权的玩家过半同意就通过
+#[derive(Debug, Clone, Default)]
+pub struct EndVote {
+    pub agrees: HashSet<u32>, //已同意的玩家
+    pub start_time: i64,      //这一轮投票开始时间(毫秒),0代表没在投
+}
+
+impl EndVote {
+    ///通过需要的票数
+    pub fn need_count(voters: &[u32]) -> usize {
+        voters.len() / 2 + 1
+    }
+
+    ///投票,voters为当前有投票权的玩家,返回是否通过,通过了由外面推送完再清空
+    pub fn vote(&mut self, user_id: u32, agree: bool, voters: &[u32], now: i64) -> bool {
+        //超时了重新投
+        if self.start_time > 0 && now - self.start_time >= END_VOTE_TIMEOUT {
+            self.clear();
+        }
+        //有人反对就重新投
+        if !agree {
+            self.clear();
+            return false;
+        }
+        if self.start_time == 0 {
+            self.start_time = now;
+        }
+        self.agrees.insert(user_id);
+        self.agrees.retain(|id| voters.contains(id));
+        self.agrees.len() >= EndVote::need_count(voters)
+    }
+
+    pub fn clear(&mut self) {
+        self.agrees.clear();
+        self.start_time = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn majority() {
+        let voters = [1, 2, 3, 4];
+        let mut vote = EndVote::default();
+        assert_eq!(EndVote::need_count(&voters), 3);
+        assert!(!vote.vote(1, true, &voters, 1));
+        //重复投不算
+        assert!(!vote.vote(1, true, &voters, 2));
+        assert!(!vote.vote(2, true, &voters, 3));
+        assert!(vote.vote(3, true, &voters, 4));
+        assert_eq!(vote.agrees.len(), 3);
+
+        //三个人两票就够
+        vote.clear();
+        let voters = [1, 2, 3];
+        assert!(!vote.vote(1, true, &voters, 5));
+        assert!(vote.vote(2, true, &voters, 6));
+    }
+
+    #[test]
+    fn against() {
+        let voters = [1, 2, 3];
+        let mut vote = EndVote::default();
+        vote.vote(1, true, &voters, 1);
+        //有人反对就重新投
+        assert!(!vote.vote(2, false, &voters, 2));
+        assert!(vote.agrees.is_empty());
+        assert!(!vote.vote(3, true, &voters, 3));
+        assert!(vote.vote(1, true, &voters, 4));
+    }
+
+    #[test]
+    fn voter_changed() {
+        let mut vote = EndVote::default();
+        vote.vote(1, true, &[1, 2, 3, 4], 1);
+        //投过票的人死了或者托管了就不算
+        assert!(!vote.vote(2, true, &[2, 3, 4], 2));
+        assert!(!vote.agrees.contains(&1));
+        assert!(vote.vote(3, true, &[2, 3, 4], 3));
+    }
+
+    #[test]
+    fn timeout() {
+        let voters = [1, 2, 3];
+        let mut vote = EndVote::default();
+        vote.vote(1, true, &voters, 1000);
+        //超时了之前的票作废
+        assert!(!vote.vote(2, true, &voters, 1000 + END_VOTE_TIMEOUT));
+        assert_eq!(vote.agrees.len(), 1);
+        assert_eq!(vote.start_time, 1000 + END_VOTE_TIMEOUT);
+        assert!(vote.vote(1, true, &voters, 1000 + END_VOTE_TIMEOUT + 1));
+    }
+}
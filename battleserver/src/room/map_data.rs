@@ -4,6 +4,7 @@ use crate::battle::battle_enum::buff_type::FIRE_MAP_CELL;
 use crate::battle::battle_enum::buff_type::LOCKED;
 use crate::battle::battle_enum::buff_type::TRAPS;
 use crate::battle::battle_enum::BattlePlayerState;
+use crate::room::map_geometry::MapGeometry;
 use crate::room::RoomType;
 use crate::TEMPLATES;
 use num_enum::IntoPrimitive;
@@ -45,7 +46,8 @@ impl MapCellType {
 #[derive(Debug, Default, Clone)]
 pub struct TileMap {
     pub id: u32,                                   //地图id
-    pub map_cells: [MapCell; 30],                  //地图格子vec
+    pub map_cells: Vec<MapCell>,                   //地图格子vec
+    pub geometry: MapGeometry,                     //地图几何信息
     pub coord_map: HashMap<(isize, isize), usize>, //坐标对应格子
     pub world_cell: (usize, u32),                  //世界块 index,世界块id
     pub market_cell: (usize, u32),                 //商店 index,商店id
@@ -80,6 +82,30 @@ impl TileMap {
         }
         res
     }
+
+    ///通过坐标获得格子下标
+    pub fn get_index_by_coord(&self, x: isize, y: isize) -> Option<usize> {
+        self.coord_map.get(&(x, y)).copied()
+    }
+
+    ///获得格子周围一圈的格子下标(不包括中心)
+    pub fn near_indexes(&self, index: usize) -> Vec<usize> {
+        let map_cell = self.map_cells.get(index);
+        if map_cell.is_none() {
+            return Vec::new();
+        }
+        let map_cell = map_cell.unwrap();
+        self.geometry
+            .near_coords(map_cell.x, map_cell.y)
+            .iter()
+            .filter_map(|&(x, y)| self.get_index_by_coord(x, y))
+            .collect()
+    }
+
+    ///判断两个格子是否相邻
+    pub fn is_near(&self, index: usize, other_index: usize) -> bool {
+        self.near_indexes(index).contains(&other_index)
+    }
 }
 
 ///块的封装结构体
@@ -215,7 +241,9 @@ impl TileMap {
         //第一次初始化地图
         if last_map_id == 0 {
             match room_type {
-                RoomType::OneVOneVOneVOneCustom | RoomType::WorldBossCustom => {
+                RoomType::OneVOneVOneVOneCustom
+                | RoomType::WorldBossCustom
+                | RoomType::TwoVTwoCustom => {
                     //如果赛季id==-1则随机一个出来
                     if season_is_open {
                         unsafe {
@@ -223,7 +251,9 @@ impl TileMap {
                         }
                     }
                 }
-                RoomType::OneVOneVOneVOneMatch | RoomType::WorldBoseMatch => {
+                RoomType::OneVOneVOneVOneMatch
+                | RoomType::WorldBoseMatch
+                | RoomType::TwoVTwoMatch => {
                     //如果是匹配房,第一次进行随机
                     //否则进行随机，0-1，0代表不开启世界块
                     let res = rand.gen_range(0..2);
@@ -239,7 +269,9 @@ impl TileMap {
             if let Ok(tile_map_temp) = tile_map_temp {
                 if tile_map_temp.world_cell > 0 {
                     match room_type {
-                        RoomType::OneVOneVOneVOneMatch | RoomType::WorldBoseMatch => unsafe {
+                        RoomType::OneVOneVOneVOneMatch
+                        | RoomType::WorldBoseMatch
+                        | RoomType::TwoVTwoMatch => unsafe {
                             season_id = crate::SEASON.season_id;
                         },
                         _ => {}
@@ -304,10 +336,20 @@ impl TileMap {
         let map_random_index = rand.gen_range(0..tile_map_temp_v.len());
 
         let tile_map_temp = tile_map_temp_v.get(map_random_index).unwrap();
+        let geometry = MapGeometry::from(tile_map_temp);
+        if tile_map_temp.map.len() != geometry.size() {
+            anyhow::bail!(
+                "map size is not match width*height!map_id:{},map_len:{},width:{},height:{}",
+                tile_map_temp.id,
+                tile_map_temp.map.len(),
+                geometry.width,
+                geometry.height
+            )
+        }
         let mut tmp = TileMap::default();
         tmp.id = tile_map_temp.id;
 
-        let mut map = [(0, MapCellType::default()); 30];
+        let mut map = vec![(0, MapCellType::default()); geometry.size()];
         tile_map_temp.map.iter().enumerate().for_each(|i| {
             let index = i.0;
             let &value = i.1;
@@ -384,18 +426,9 @@ impl TileMap {
             }
         }
         let mut index = 0;
-        let mut x = 0_isize;
-        let mut y = 0_isize;
         let mut un_pair_v = vec![];
         for (map_cell_id, cell_type) in map.iter() {
-            if x > 5 {
-                x = 0;
-                y += 1;
-            }
-
-            if y >= 5 {
-                y = 0;
-            }
+            let (x, y) = geometry.coord(index);
             let mut map_cell = MapCell::default();
             map_cell.id = *map_cell_id;
             map_cell.index = index;
@@ -403,7 +436,6 @@ impl TileMap {
             map_cell.x = x;
             map_cell.y = y;
             tmp.coord_map.insert((x, y), index);
-            x += 1;
             let mut buffs: Option<Iter<u32>> = None;
             if map_cell.is_world() {
                 let world_map_cell = TEMPLATES
@@ -429,7 +461,7 @@ impl TileMap {
                 }
                 map_cell.buffs = buff_map;
             }
-            tmp.map_cells[index] = map_cell;
+            tmp.map_cells.push(map_cell);
             index += 1;
         }
         //先给worldboss放位置
@@ -440,6 +472,7 @@ impl TileMap {
             tmp.world_boss_init_index = map_cell.index;
         }
         tmp.season_id = season_id;
+        tmp.geometry = geometry;
         Ok(tmp)
     }
 }
@@ -0,0 +1,125 @@
+use tools::templates::tile_map_temp::{default_height, default_layout, default_width, TileMapTemp};
+
+///地图几何信息，负责下标与坐标的换算和相邻格子计算
+#[derive(Debug, Clone)]
+pub struct MapGeometry {
+    pub width: isize,                //地图宽
+    pub height: isize,               //地图高
+    pub layout: Vec<(isize, isize)>, //相邻格子的坐标偏移
+}
+
+impl Default for MapGeometry {
+    fn default() -> Self {
+        let mut temp = TileMapTemp::default();
+        temp.width = default_width();
+        temp.height = default_height();
+        temp.layout = default_layout();
+        MapGeometry::from(&temp)
+    }
+}
+
+impl From<&TileMapTemp> for MapGeometry {
+    fn from(temp: &TileMapTemp) -> Self {
+        let layout = temp.layout.iter().map(|coord| (coord.x, coord.y)).collect();
+        MapGeometry {
+            width: temp.width as isize,
+            height: temp.height as isize,
+            layout,
+        }
+    }
+}
+
+impl MapGeometry {
+    ///地图格子总数
+    pub fn size(&self) -> usize {
+        (self.width * self.height) as usize
+    }
+
+    ///通过下标计算坐标
+    pub fn coord(&self, index: usize) -> (isize, isize) {
+        let index = index as isize;
+        (index % self.width, index / self.width)
+    }
+
+    ///判断坐标是否在地图内
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        x >= 0 && x < self.width && y >= 0 && y < self.height
+    }
+
+    ///获得坐标周围一圈的坐标(不包括中心)
+    pub fn near_coords(&self, x: isize, y: isize) -> Vec<(isize, isize)> {
+        self.layout
+            .iter()
+            .map(|&(dx, dy)| (x + dx, y + dy))
+            .filter(|&(x, y)| self.contains(x, y))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    ///改成坐标之前用的下标偏移
+    const OLD_NEAR: [isize; 6] = [-6, -5, -1, 1, 5, 6];
+
+    #[test]
+    fn test_coord() {
+        let geometry = MapGeometry::default();
+        assert_eq!(geometry.size(), 30);
+        assert_eq!(geometry.coord(0), (0, 0));
+        assert_eq!(geometry.coord(5), (5, 0));
+        assert_eq!(geometry.coord(6), (0, 1));
+        assert_eq!(geometry.coord(29), (5, 4));
+        assert!(geometry.contains(5, 4));
+        assert!(!geometry.contains(6, 0));
+        assert!(!geometry.contains(0, 5));
+        assert!(!geometry.contains(-1, 0));
+    }
+
+    ///默认6x5的地图,除了行首行尾,周围一圈和原来的下标偏移一致
+    /// 行首行尾原来会绕到上下一行的另一头,现在不会了
+    #[test]
+    fn test_near_coords() {
+        let geometry = MapGeometry::default();
+        for index in 0..geometry.size() {
+            let (x, y) = geometry.coord(index);
+            let mut new: Vec<isize> = geometry
+                .near_coords(x, y)
+                .iter()
+                .map(|&(x, y)| y * geometry.width + x)
+                .collect();
+            new.sort();
+            let old: Vec<isize> = OLD_NEAR
+                .iter()
+                .map(|&offset| index as isize + offset)
+                .filter(|&i| i >= 0 && i < geometry.size() as isize)
+                .collect();
+            if x > 0 && x < geometry.width - 1 {
+                assert_eq!(new, old, "index:{}", index);
+                continue;
+            }
+            //行首行尾只会少,少掉的都是绕到另一头的
+            for i in new.iter() {
+                assert!(old.contains(i), "index:{},near:{}", index, i);
+            }
+            for i in old.iter().filter(|i| !new.contains(i)) {
+                let (near_x, _) = geometry.coord(*i as usize);
+                assert!((near_x - x).abs() > 1, "index:{},near:{}", index, i);
+            }
+        }
+        //行首行尾的具体例子
+        let near = |index: usize| {
+            let (x, y) = geometry.coord(index);
+            let mut res: Vec<isize> = geometry
+                .near_coords(x, y)
+                .iter()
+                .map(|&(x, y)| y * geometry.width + x)
+                .collect();
+            res.sort();
+            res
+        };
+        assert_eq!(near(6), vec![0, 1, 7, 12]);
+        assert_eq!(near(11), vec![5, 10, 16, 17]);
+    }
+}
@@ -1,5 +1,7 @@
 pub mod character;
+pub mod end_vote;
 pub mod map_data;
+pub mod map_geometry;
 pub mod member;
 pub mod room;
 
@@ -10,6 +12,9 @@ use tools::protos::base::RoomSettingPt;
 ///最大成员数量
 pub const MEMBER_MAX: usize = 4;
 
+///组队模式每队最大人数
+pub const TEAM_MEMBER_MAX: usize = 2;
+
 ///teamID枚举
 #[derive(Debug, Clone, Copy, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
 #[repr(u8)]
@@ -27,11 +32,15 @@ pub enum RoomType {
     OneVOneVOneVOneMatch = 2,  //1v1v1v1匹配房间
     WorldBossCustom = 3,       //世界boss自定义房间
     WorldBoseMatch = 4,        //世界boss匹配房间
+    TwoVTwoCustom = 5,         //2v2自定义房间
+    TwoVTwoMatch = 6,          //2v2匹配房间
 }
 
 impl RoomType {
     pub fn is_match_type(self) -> bool {
-        self == RoomType::WorldBoseMatch || self == RoomType::OneVOneVOneVOneMatch
+        self == RoomType::WorldBoseMatch
+            || self == RoomType::OneVOneVOneVOneMatch
+            || self == RoomType::TwoVTwoMatch
     }
 
     pub fn is_boss_type(self) -> bool {
@@ -39,7 +48,14 @@ impl RoomType {
     }
 
     pub fn is_custom_type(self) -> bool {
-        self == RoomType::OneVOneVOneVOneCustom || self == RoomType::WorldBossCustom
+        self == RoomType::OneVOneVOneVOneCustom
+            || self == RoomType::WorldBossCustom
+            || self == RoomType::TwoVTwoCustom
+    }
+
+    ///组队对战模式
+    pub fn is_team_type(self) -> bool {
+        self == RoomType::TwoVTwoCustom || self == RoomType::TwoVTwoMatch
     }
     pub fn into_u8(self) -> u8 {
         let res: u8 = self.into();
@@ -88,18 +104,21 @@ impl MemberLeaveNoticeType {
 ///房间设置
 #[derive(Debug, Copy, Clone, Default)]
 pub struct RoomSetting {
-    pub turn_limit_time: u32, //回合限制时间
-    pub season_is_open: bool, //赛季开关
-    pub ai_level: u8,         //ai等级
+    pub turn_limit_time: u32,   //回合限制时间
+    pub turn_reserve_time: u32, //每个玩家的备用时间
+    pub season_is_open: bool,   //赛季开关
+    pub ai_level: u8,           //ai等级
 }
 
 impl From<&RoomSettingPt> for RoomSetting {
     fn from(rs_pt: &RoomSettingPt) -> Self {
         let ai_level = rs_pt.ai_level as u8;
         let turn_limit_time = rs_pt.turn_limit_time;
+        let turn_reserve_time = rs_pt.turn_reserve_time;
         let season_is_open = rs_pt.season_is_open;
         let rs = RoomSetting {
             turn_limit_time,
+            turn_reserve_time,
             season_is_open,
             ai_level,
         };
@@ -112,6 +131,7 @@ impl From<RoomSetting> for RoomSettingPt {
         let mut rsp = RoomSettingPt::new();
         rsp.set_season_is_open(r.season_is_open);
         rsp.set_turn_limit_time(r.turn_limit_time);
+        rsp.set_turn_reserve_time(r.turn_reserve_time);
         rsp.set_ai_level(r.ai_level as u32);
         rsp
     }
@@ -3,6 +3,7 @@ use crate::battle::battle_trigger::TriggerEvent;
 use crate::battle::mission::random_mission;
 use crate::battle::{battle::BattleData, battle_player::BattlePlayer};
 use crate::robot::robot_task_mgr::RobotTask;
+use crate::room::end_vote::EndVote;
 use crate::room::map_data::TileMap;
 use crate::room::member::Member;
 use crate::room::{RoomSetting, RoomState, RoomType, MEMBER_MAX};
@@ -17,14 +18,15 @@ use std::borrow::{Borrow, BorrowMut};
 use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::str::FromStr;
-use tools::cmd_code::{ClientCode, GameCode, RoomCode};
+use tools::cmd_code::{ClientCode, GameCode, RankCode, RoomCode};
 use tools::macros::GetMutRef;
-use tools::protos::base::{IndexCter, RoomPt, WorldCellPt};
+use tools::protos::base::{IndexCter, RoomPt, WorldBossRankPt, WorldCellPt};
 use tools::protos::battle::{
-    S_BATTLE_START_NOTICE, S_CHOOSE_INDEX_NOTICE, S_MAP_REFRESH_NOTICE, S_START_NOTICE,
+    S_AUTO_NOTICE, S_BATTLE_START_NOTICE, S_CHOOSE_INDEX_NOTICE, S_MAP_REFRESH_NOTICE,
+    S_START_NOTICE, S_VOTE_END_NOTICE,
 };
 use tools::protos::room::{S_EMOJI, S_EMOJI_NOTICE, S_ROOM_MEMBER_LEAVE_NOTICE};
-use tools::protos::server_protocol::{B_R_G_PUNISH_MATCH, B_R_SUMMARY};
+use tools::protos::server_protocol::{B_R_G_PUNISH_MATCH, B_R_SUMMARY, B_R_WORLD_BOSS_DAMAGE};
 use tools::util::packet::Packet;
 
 use super::MemberLeaveNoticeType;
@@ -32,18 +34,19 @@ use super::MemberLeaveNoticeType;
 ///房间结构体，封装房间必要信息
 #[derive(Clone)]
 pub struct Room {
-    id: u32,                             //房间id
-    room_type: RoomType,                 //房间类型
-    owner_id: u32,                       //房主id
-    pub state: RoomState,                //房间状态
-    pub members: HashMap<u32, Member>,   //玩家id对应角色id
-    pub member_index: [u32; MEMBER_MAX], //玩家对应的位置
-    pub setting: RoomSetting,            //房间设置
-    pub battle_data: BattleData,         //战斗相关数据封装
-    pub tcp_sender: Sender<Vec<u8>>,     //tcpsender
-    task_sender: Sender<Task>,           //任务sender
-    robot_sender: Sender<RobotTask>,     //机器人sender
-    time: DateTime<Utc>,                 //房间创建时间
+    id: u32,                           //房间id
+    room_type: RoomType,               //房间类型
+    owner_id: u32,                     //房主id
+    pub state: RoomState,              //房间状态
+    pub members: HashMap<u32, Member>, //玩家id对应角色id
+    pub member_index: Vec<u32>,        //玩家对应的位置
+    pub setting: RoomSetting,          //房间设置
+    pub battle_data: BattleData,       //战斗相关数据封装
+    pub tcp_sender: Sender<Vec<u8>>,   //tcpsender
+    task_sender: Sender<Task>,         //任务sender
+    robot_sender: Sender<RobotTask>,   //机器人sender
+    time: DateTime<Utc>,               //房间创建时间
+    pub end_vote: EndVote,             //投票结束战斗
 }
 
 tools::get_mut_ref!(Room);
@@ -64,7 +67,8 @@ impl Room {
         }
         let room_type = room_type.unwrap();
         let mut members = HashMap::new();
-        let mut member_index: [u32; MEMBER_MAX] = [0; MEMBER_MAX];
+        let member_count = rp.members.len();
+        let mut member_index = vec![0; member_count.max(MEMBER_MAX)];
         let mut index = 0;
         for member_pt in rp.members.iter() {
             members.insert(member_pt.user_id, Member::from(member_pt));
@@ -82,18 +86,24 @@ impl Room {
             member_index,
             state: RoomState::ChoiceIndex,
             setting: room_setting,
-            battle_data: BattleData::new(room_type, task_sender.clone(), tcp_sender.clone()),
+            battle_data: BattleData::new(
+                room_type,
+                member_count,
+                task_sender.clone(),
+                tcp_sender.clone(),
+            ),
             room_type,
             tcp_sender,
             task_sender,
             robot_sender,
             time,
+            end_vote: EndVote::default(),
         };
         Ok(room)
     }
 
     pub fn get_last_order_user(&self) -> u32 {
-        let mut index = (MEMBER_MAX - 1) as usize;
+        let mut index = self.battle_data.turn_orders.len() - 1;
         loop {
             let res = self.battle_data.turn_orders[index];
             if res != 0 {
@@ -201,8 +211,7 @@ impl Room {
             }
         }
 
-        let size = self.battle_data.get_alive_player_num();
-        if size <= 1 {
+        if self.battle_data.is_battle_over() {
             is_battle_over = true;
             self.state = RoomState::BattleOvered;
             self.battle_data.state = RoomState::BattleOvered;
@@ -210,6 +219,11 @@ impl Room {
             is_battle_over = false;
         }
 
+        //世界boss伤害上报给排行榜服
+        if self.state == RoomState::BattleOvered && self.room_type.is_boss_type() {
+            self.report_world_boss_damage();
+        }
+
         //发给房间服
         if self.state == RoomState::BattleOvered {
             let user_id = self.random_user_id();
@@ -217,6 +231,12 @@ impl Room {
                 let mut proto = B_R_SUMMARY::new();
                 proto.room_type = self.room_type.into_u32();
                 proto.room_id = self.get_room_id();
+                //按名次带上玩家,锦标赛要用
+                for summary_users in self.battle_data.summary_vec.iter() {
+                    for su in summary_users.iter() {
+                        proto.ranks.push(su.user_id);
+                    }
+                }
                 let bytes = proto.write_to_bytes();
                 match bytes {
                     Ok(bytes) => {
@@ -232,6 +252,43 @@ impl Room {
         is_battle_over
     }
 
+    ///上报这场战斗对世界boss造成的伤害
+    pub fn report_world_boss_damage(&mut self) {
+        if self.battle_data.world_boss_damage.is_empty() {
+            return;
+        }
+        let world_boss = self.battle_data.get_world_boss_ref();
+        if world_boss.is_none() {
+            warn!("could not find world boss!room_id:{}", self.get_room_id());
+            return;
+        }
+        let mut proto = B_R_WORLD_BOSS_DAMAGE::new();
+        proto.world_boss_id = world_boss.unwrap().get_cter_temp_id() as i32;
+        for (&user_id, &damage) in self.battle_data.world_boss_damage.iter() {
+            let battle_player = self.battle_data.battle_player.get(&user_id);
+            //机器人的不算
+            match battle_player {
+                Some(battle_player) if !battle_player.is_robot() => {
+                    let mut pt = WorldBossRankPt::new();
+                    pt.user_id = user_id;
+                    pt.name = battle_player.name.clone();
+                    pt.damage = damage;
+                    proto.damages.push(pt);
+                }
+                _ => {}
+            }
+        }
+        self.battle_data.world_boss_damage.clear();
+        if proto.damages.is_empty() {
+            return;
+        }
+        let bytes = proto.write_to_bytes();
+        match bytes {
+            Ok(bytes) => self.send_2_server(RankCode::WorldBossDamage.into_u32(), 0, bytes),
+            Err(e) => error!("{:?}", e),
+        }
+    }
+
     ///刷新地图
     pub fn refresh_map(&mut self) -> bool {
         let need_refresh = self.battle_data.check_refresh_map();
@@ -413,6 +470,160 @@ impl Room {
         self.battle_data.battle_player.get_mut(key)
     }
 
+    ///记录一次超时,连续超时次数达到上限就交给机器人托管
+    pub fn add_missed_turn(&mut self, user_id: u32) {
+        let max_missed_turns = get_max_missed_turns();
+        let battle_player = self.get_battle_player_mut_ref(&user_id);
+        if battle_player.is_none() {
+            return;
+        }
+        let battle_player = battle_player.unwrap();
+        battle_player.missed_turns += 1;
+        if battle_player.missed_turns < max_missed_turns {
+            return;
+        }
+        self.start_auto(user_id);
+    }
+
+    ///开始托管
+    pub fn start_auto(&mut self, user_id: u32) {
+        let battle_data_ptr = &mut self.battle_data as *mut BattleData;
+        let robot_sender = self.robot_sender.clone();
+        let battle_player = self.get_battle_player_mut_ref(&user_id);
+        if battle_player.is_none() {
+            return;
+        }
+        let battle_player = battle_player.unwrap();
+        if battle_player.is_robot_controlled() || battle_player.is_died() {
+            return;
+        }
+        battle_player.start_auto(battle_data_ptr, robot_sender);
+        info!(
+            "玩家连续超时,开始托管!room_id:{},user_id:{}",
+            self.id, user_id
+        );
+        self.push_auto_notice(user_id, true);
+    }
+
+    ///结束托管,玩家自己操作了就拿回控制权
+    pub fn stop_auto(&mut self, user_id: u32) {
+        let battle_player = self.get_battle_player_mut_ref(&user_id);
+        if battle_player.is_none() {
+            return;
+        }
+        let battle_player = battle_player.unwrap();
+        //正常操作了就清掉超时次数
+        battle_player.missed_turns = 0;
+        if !battle_player.is_auto {
+            return;
+        }
+        battle_player.stop_auto();
+        info!(
+            "玩家回来了,结束托管!room_id:{},user_id:{}",
+            self.id, user_id
+        );
+        self.push_auto_notice(user_id, false);
+    }
+
+    ///投降,直接按当前最后一名结算,不算逃跑
+    pub fn surrender(&mut self, user_id: u32) -> anyhow::Result<()> {
+        if self.state != RoomState::BattleStarted {
+            anyhow::bail!(
+                "battle is not started!room_id:{},room_state:{:?}",
+                self.id,
+                self.state
+            )
+        }
+        let battle_player = self.battle_data.get_battle_player(Some(user_id), true)?;
+        if battle_player.is_world_boss {
+            anyhow::bail!("world boss can not surrender!user_id:{}", user_id)
+        }
+        //托管中死掉会按逃跑算,投降了就是人回来了
+        self.stop_auto(user_id);
+        self.battle_data.leave_user = (user_id, false);
+        let str = format!("player die!because surrender!user_id:{}", user_id);
+        self.battle_data
+            .after_player_died_trigger(user_id, user_id, true, false, Some(str));
+        info!("玩家投降!room_id:{},user_id:{}", self.id, user_id);
+        Ok(())
+    }
+
+    ///投票结束战斗,世界boss打不动的时候活着的玩家过半同意就直接判boss赢
+    ///返回投票是否通过
+    pub fn vote_end(&mut self, user_id: u32, agree: bool) -> anyhow::Result<bool> {
+        if !self.room_type.is_boss_type() {
+            anyhow::bail!(
+                "only world boss room can vote to end!room_id:{},room_type:{:?}",
+                self.id,
+                self.room_type
+            )
+        }
+        if self.state != RoomState::BattleStarted {
+            anyhow::bail!(
+                "battle is not started!room_id:{},room_state:{:?}",
+                self.id,
+                self.state
+            )
+        }
+        let battle_player = self.battle_data.get_battle_player(Some(user_id), true)?;
+        if battle_player.is_world_boss {
+            anyhow::bail!("world boss can not vote!user_id:{}", user_id)
+        }
+        self.stop_auto(user_id);
+        //活着的玩家才能投,托管和机器人不算
+        let voters: Vec<u32> = self
+            .battle_data
+            .battle_player
+            .values()
+            .filter(|x| !x.is_died() && !x.is_world_boss && !x.is_robot_controlled())
+            .map(|x| x.get_user_id())
+            .collect();
+        let now = chrono::Local::now().timestamp_millis();
+        let passed = self.end_vote.vote(user_id, agree, &voters, now);
+
+        let mut sven = S_VOTE_END_NOTICE::new();
+        sven.set_user_id(user_id);
+        sven.set_agree(agree);
+        sven.set_votes(self.end_vote.agrees.iter().copied().collect());
+        sven.set_need_count(EndVote::need_count(&voters) as u32);
+        match sven.write_to_bytes() {
+            Ok(bytes) => self.send_2_all_client(ClientCode::VoteEndNotice, bytes),
+            Err(e) => error!("{:?}", e),
+        }
+        if !passed {
+            return Ok(false);
+        }
+        self.end_vote.clear();
+
+        //投票通过,活着的玩家全部当死亡处理,世界boss结算的时候判boss赢
+        let alive_players: Vec<u32> = self
+            .battle_data
+            .battle_player
+            .values()
+            .filter(|x| !x.is_died() && !x.is_world_boss)
+            .map(|x| x.get_user_id())
+            .collect();
+        for id in alive_players {
+            let str = format!("player die!because vote to end!user_id:{}", id);
+            self.battle_data
+                .after_player_died_trigger(id, id, true, false, Some(str));
+        }
+        info!("投票结束战斗通过!room_id:{}", self.id);
+        Ok(true)
+    }
+
+    ///推送托管状态
+    fn push_auto_notice(&mut self, user_id: u32, is_auto: bool) {
+        let mut san = S_AUTO_NOTICE::new();
+        san.set_user_id(user_id);
+        san.set_is_auto(is_auto);
+        let bytes = san.write_to_bytes();
+        match bytes {
+            Ok(bytes) => self.send_2_all_client(ClientCode::AutoNotice, bytes),
+            Err(e) => error!("{:?}", e),
+        }
+    }
+
     pub fn get_battle_cter_mut(&mut self, cter_id: u32) -> anyhow::Result<&mut BattleCharacter> {
         self.battle_data.get_battle_cter_mut(cter_id, true)
     }
@@ -425,7 +636,7 @@ impl Room {
     pub fn choice_index(&mut self, cter_id: u32, index: u32) {
         let user_id = self.battle_data.get_user_id(cter_id).unwrap();
         let turn_index = self.get_next_turn_index();
-        let turn_order = self.battle_data.turn_orders;
+        let turn_order = self.battle_data.turn_orders.clone();
         let battle_cter = self.get_battle_cter_mut(cter_id);
         if let Err(e) = battle_cter {
             error!("{:?}", e);
@@ -854,7 +1065,7 @@ impl Room {
     }
 
     pub fn cter_2_battle_cter(&mut self) {
-        let ai_level = self.setting.ai_level as u32;
+        let ai_level = self.setting.ai_level;
         for member in self.members.values_mut() {
             let battle_player = BattlePlayer::init(
                 &member,
@@ -912,6 +1123,8 @@ impl Room {
         self.battle_data.last_map_id = tmd.id;
         self.battle_data.tile_map = tmd;
         self.battle_data.turn_limit_time = self.setting.turn_limit_time as u64;
+        self.battle_data
+            .init_reserve_time(self.setting.turn_reserve_time as u64);
         Ok(())
     }
 
@@ -1019,3 +1232,19 @@ impl Room {
         }
     }
 }
+
+///连续超时多少次开始托管
+pub fn get_max_missed_turns() -> u8 {
+    let mut max_missed_turns = 2;
+    let con_temp = TEMPLATES
+        .constant_temp_mgr()
+        .temps
+        .get("auto_pilot_missed_turns");
+    if let Some(con_temp) = con_temp {
+        match u8::from_str(con_temp.value.as_str()) {
+            Ok(res) => max_missed_turns = res,
+            Err(e) => warn!("{:?}", e),
+        }
+    }
+    max_missed_turns
+}
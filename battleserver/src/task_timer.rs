@@ -152,6 +152,10 @@ fn battle_turn_time(bm: Arc<Mutex<BattleMgr>>, task: Task) {
         return;
     }
     let user_id = user_id.unwrap() as u32;
+    let is_reserve = map
+        .get("is_reserve")
+        .and_then(|res| res.as_bool())
+        .unwrap_or(false);
 
     let mut lock = block_on(bm.lock());
 
@@ -203,31 +207,32 @@ fn battle_turn_time(bm: Arc<Mutex<BattleMgr>>, task: Task) {
     if battle_player.is_robot() {
         return;
     }
+    let is_robot_controlled = battle_player.is_robot_controlled();
 
-    //如果玩家啥都没做，就T出房间
-    let need_kick = battle_player.flow_data.open_map_cell_vec.is_empty();
-    if need_kick {
-        room.remove_member(MemberLeaveNoticeType::Kicked.into(), &user_id, true);
-        info!("定时检turn任务,没有翻的人T出去,user_id:{}", user_id);
-        let room_state = room.state;
-        let is_empty = room.is_empty();
-        if is_empty || room_state == RoomState::BattleOvered {
-            let room_id = room.get_room_id();
-            lock.rm_room(room_id);
-        }
-        lock.player_room.remove(&user_id);
+    //基础时间用完了,还有备用时间的话接着扣备用时间,托管中的不用
+    if !is_reserve && !is_robot_controlled && room.battle_data.build_reserve_turn_task(user_id) {
+        info!("回合基础时间用完,开始消耗备用时间!user_id:{}", user_id);
+        return;
+    }
+    //备用时间也用完了
+    if is_reserve {
+        room.battle_data.clear_reserve_time(user_id);
+    }
+
+    //玩家超时不再T出房间,连续超时次数够了就交给机器人托管
+    //托管中的机器人没在时间内做完,直接帮他跳过
+    if !is_robot_controlled {
+        room.add_missed_turn(user_id);
+    }
+    let need_refresh_map = room.battle_data.check_refresh_map();
+    //如果需要刷新地图，走地图刷新next turn逻辑
+    if need_refresh_map {
+        room.refresh_map();
+        room.check_next_choice_index();
+        info!("定时检测turn任务,刷新地图");
     } else {
-        let need_refresh_map = room.battle_data.check_refresh_map();
-        //如果需要刷新地图，走地图刷新next turn逻辑
-        if need_refresh_map {
-            room.refresh_map();
-            room.check_next_choice_index();
-            info!("定时检测turn任务,刷新地图");
-        } else {
-            //如果用过移动点数就帮他跳过
-            room.battle_data.next_turn(true);
-            info!("定时检测turn任务,自动帮玩家跳过turn!user_id:{}", user_id);
-        }
+        room.battle_data.next_turn(true);
+        info!("定时检测turn任务,自动帮玩家跳过turn!user_id:{}", user_id);
     }
 }
 
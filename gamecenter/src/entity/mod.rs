@@ -1 +1,2 @@
 pub mod league;
+pub mod tournament;
@@ -0,0 +1,664 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use tools::protos::base::{TournamentMatchPt, TournamentPlayerPt, TournamentPt};
+
+///一个房间最多几个人
+pub const ROOM_MEMBER_MAX: usize = 4;
+///最少几个人才能开赛
+pub const MIN_PLAYERS: usize = 2;
+
+///赛制
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+pub enum TournamentFormat {
+    SingleElimination = 1, //单败淘汰
+    DoubleElimination = 2, //双败淘汰
+}
+
+impl TournamentFormat {
+    pub fn from_u32(value: u32) -> anyhow::Result<Self> {
+        match value {
+            1 => Ok(TournamentFormat::SingleElimination),
+            2 => Ok(TournamentFormat::DoubleElimination),
+            _ => anyhow::bail!("tournament format is error!format:{}", value),
+        }
+    }
+
+    ///输几次淘汰
+    pub fn max_losses(self) -> u32 {
+        match self {
+            TournamentFormat::SingleElimination => 1,
+            TournamentFormat::DoubleElimination => 2,
+        }
+    }
+}
+
+///锦标赛状态
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+pub enum TournamentState {
+    Pending = 1,   //等待报名
+    SignUp = 2,    //报名中
+    Running = 3,   //进行中
+    Finished = 4,  //已结束
+    Cancelled = 5, //已取消
+}
+
+///对局状态
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+pub enum MatchState {
+    Waiting = 1,  //等待开始
+    Playing = 2,  //进行中
+    Finished = 3, //已结束
+}
+
+///对局所在的分组
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+pub enum Bracket {
+    Winners = 1, //胜者组,单败淘汰只有这个
+    Losers = 2,  //败者组,双败淘汰输过一次的
+    Final = 3,   //决赛,剩下的人不超过一个房间
+}
+
+///推送类型
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum NoticeType {
+    MatchStart = 1, //对局开始
+    Advance = 2,    //晋级
+    Eliminated = 3, //淘汰
+    Finished = 4,   //锦标赛结束
+    Cancelled = 5,  //锦标赛取消
+}
+
+///锦标赛产生的要发出去的消息,由中心服负责发
+#[derive(Debug, Clone)]
+pub enum TournamentEvent {
+    //通知玩家进房间
+    StartMatch {
+        tournament_id: u32,
+        match_id: u32,
+        user_ids: Vec<u32>,
+    },
+    //推送给玩家
+    Notice {
+        tournament_id: u32,
+        user_id: u32,
+        notice_type: NoticeType,
+        round: u32,
+        match_id: u32,
+        final_rank: u32,
+    },
+}
+
+///选手
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TournamentPlayer {
+    pub user_id: u32,      //玩家id
+    pub name: String,      //名字
+    pub league_id: i32,    //报名时的段位id
+    pub league_score: i32, //报名时的段位积分
+    pub sign_up_time: u64, //报名时间
+    pub seed: u32,         //种子序号,从1开始
+    pub losses: u32,       //失败次数
+    pub eliminated: bool,  //是否已淘汰
+    pub final_rank: u32,   //最终名次,0代表还没出结果
+}
+
+///对局
+#[derive(Debug, Clone, Serialize)]
+pub struct TournamentMatch {
+    pub match_id: u32,     //对局id
+    pub round: u32,        //轮次
+    pub bracket: Bracket,  //分组
+    pub state: MatchState, //状态
+    pub players: Vec<u32>, //参赛玩家
+    pub ranks: Vec<u32>,   //按名次排好的玩家id,没出现的是弃权
+    pub deadline: u64,     //超时时间,过了还没结果就当都弃权
+}
+
+impl TournamentMatch {
+    pub fn into_pt(&self) -> TournamentMatchPt {
+        let mut pt = TournamentMatchPt::new();
+        pt.set_match_id(self.match_id);
+        pt.set_round(self.round);
+        pt.set_bracket(self.bracket as u32);
+        pt.set_state(self.state as u32);
+        pt.set_players(self.players.clone());
+        pt.set_ranks(self.ranks.clone());
+        pt
+    }
+}
+
+///锦标赛,数据只在中心服内存里
+#[derive(Debug, Clone, Serialize)]
+pub struct Tournament {
+    pub id: u32,                                 //锦标赛id
+    pub name: String,                            //名字
+    pub format: TournamentFormat,                //赛制
+    pub state: TournamentState,                  //状态
+    pub sign_up_start: u64,                      //报名开始时间(毫秒)
+    pub sign_up_end: u64,                        //报名结束时间(毫秒)
+    pub start_time: u64,                         //开赛时间(毫秒)
+    pub max_players: u32,                        //人数上限
+    pub advance_count: u32,                      //每个房间前几名晋级
+    pub round_interval: u64,                     //两轮之间间隔(毫秒)
+    pub match_timeout: u64,                      //对局超时时间(毫秒)
+    pub players: HashMap<u32, TournamentPlayer>, //选手
+    pub matches: Vec<TournamentMatch>,           //所有对局
+    pub round: u32,                              //当前轮次
+    pub next_round_time: u64,                    //下一轮开始时间,0代表这一轮还没打完
+}
+
+impl Tournament {
+    pub fn is_over(&self) -> bool {
+        self.state == TournamentState::Finished || self.state == TournamentState::Cancelled
+    }
+
+    ///报名
+    pub fn sign_up(
+        &mut self,
+        user_id: u32,
+        name: String,
+        league_id: i32,
+        league_score: i32,
+        now: u64,
+    ) -> anyhow::Result<()> {
+        if self.state != TournamentState::SignUp {
+            anyhow::bail!("it is not time to sign up!tournament_id:{}", self.id)
+        }
+        if self.players.contains_key(&user_id) {
+            anyhow::bail!("this player already signed up!user_id:{}", user_id)
+        }
+        if self.players.len() >= self.max_players as usize {
+            anyhow::bail!("this tournament is full!tournament_id:{}", self.id)
+        }
+        let player = TournamentPlayer {
+            user_id,
+            name,
+            league_id,
+            league_score,
+            sign_up_time: now,
+            ..Default::default()
+        };
+        self.players.insert(user_id, player);
+        Ok(())
+    }
+
+    ///定时检查,报名开始结束,开新一轮,对局超时
+    pub fn update(&mut self, now: u64, is_online: &dyn Fn(u32) -> bool) -> Vec<TournamentEvent> {
+        let mut events = Vec::new();
+        if self.state == TournamentState::Pending && now >= self.sign_up_start {
+            self.state = TournamentState::SignUp;
+        }
+        if self.state == TournamentState::SignUp && now >= self.sign_up_end {
+            if self.players.len() < MIN_PLAYERS {
+                self.state = TournamentState::Cancelled;
+                for &user_id in self.players.keys() {
+                    events.push(self.build_notice(user_id, NoticeType::Cancelled, 0, 0));
+                }
+                return events;
+            }
+            self.seeding();
+            self.state = TournamentState::Running;
+            self.next_round_time = std::cmp::max(self.start_time, now);
+        }
+        if self.state != TournamentState::Running {
+            return events;
+        }
+        //超时的对局当都弃权
+        let timeout: Vec<u32> = self
+            .matches
+            .iter()
+            .filter(|m| m.state == MatchState::Playing && now >= m.deadline)
+            .map(|m| m.match_id)
+            .collect();
+        for match_id in timeout {
+            events.extend(self.on_result(match_id, vec![], now));
+        }
+        if self.next_round_time > 0 && now >= self.next_round_time {
+            events.extend(self.start_round(now, is_online));
+        }
+        events
+    }
+
+    ///按段位排种子,段位高的在前,同段位先报名的在前
+    fn seeding(&mut self) {
+        let mut players: Vec<&mut TournamentPlayer> = self.players.values_mut().collect();
+        players.sort_by(|a, b| {
+            b.league_id
+                .cmp(&a.league_id)
+                .then(b.league_score.cmp(&a.league_score))
+                .then(a.sign_up_time.cmp(&b.sign_up_time))
+                .then(a.user_id.cmp(&b.user_id))
+        });
+        for (index, player) in players.into_iter().enumerate() {
+            player.seed = index as u32 + 1;
+        }
+    }
+
+    ///还没淘汰的人,按种子排好
+    fn get_alive_players(&self, losses: Option<u32>) -> Vec<u32> {
+        let mut players: Vec<&TournamentPlayer> = self
+            .players
+            .values()
+            .filter(|p| !p.eliminated)
+            .filter(|p| losses.map_or(true, |losses| p.losses == losses))
+            .collect();
+        players.sort_by_key(|p| p.seed);
+        players.iter().map(|p| p.user_id).collect()
+    }
+
+    ///开新一轮,剩下的人一个房间装得下就是决赛
+    fn start_round(&mut self, now: u64, is_online: &dyn Fn(u32) -> bool) -> Vec<TournamentEvent> {
+        self.next_round_time = 0;
+        self.round += 1;
+        let alive = self.get_alive_players(None);
+        let mut groups = Vec::new();
+        if alive.len() <= ROOM_MEMBER_MAX {
+            groups.push((Bracket::Final, alive));
+        } else if self.format == TournamentFormat::DoubleElimination {
+            groups.push((Bracket::Winners, self.get_alive_players(Some(0))));
+            groups.push((Bracket::Losers, self.get_alive_players(Some(1))));
+        } else {
+            groups.push((Bracket::Winners, alive));
+        }
+        let mut new_matches = Vec::new();
+        for (bracket, players) in groups {
+            for room in split_rooms(players) {
+                let match_id = self.matches.len() as u32 + 1;
+                self.matches.push(TournamentMatch {
+                    match_id,
+                    round: self.round,
+                    bracket,
+                    state: MatchState::Waiting,
+                    players: room,
+                    ranks: Vec::new(),
+                    deadline: 0,
+                });
+                new_matches.push(match_id);
+            }
+        }
+        let mut events = Vec::new();
+        for match_id in new_matches {
+            let index = match_id as usize - 1;
+            let tm = &self.matches[index];
+            //人数不超过晋级名额的直接轮空晋级
+            if tm.bracket != Bracket::Final && tm.players.len() <= self.advance_count as usize {
+                let ranks = tm.players.clone();
+                events.extend(self.on_result(match_id, ranks, now));
+                continue;
+            }
+            //不在线的算弃权,不够两个人就不用开房间了
+            let online: Vec<u32> = tm
+                .players
+                .iter()
+                .copied()
+                .filter(|&id| is_online(id))
+                .collect();
+            if online.len() < MIN_PLAYERS {
+                events.extend(self.on_result(match_id, online, now));
+                continue;
+            }
+            let tm = &mut self.matches[index];
+            tm.state = MatchState::Playing;
+            tm.deadline = now + self.match_timeout;
+            let round = tm.round;
+            for &user_id in online.iter() {
+                events.push(self.build_notice(user_id, NoticeType::MatchStart, round, match_id));
+            }
+            events.push(TournamentEvent::StartMatch {
+                tournament_id: self.id,
+                match_id,
+                user_ids: online,
+            });
+        }
+        events
+    }
+
+    ///校验玩家是不是正在进行的这个对局的选手
+    pub fn check_join(&self, match_id: u32, user_id: u32) -> anyhow::Result<()> {
+        let tm = self.matches.iter().find(|m| m.match_id == match_id);
+        match tm {
+            Some(tm) if tm.state == MatchState::Playing && tm.players.contains(&user_id) => Ok(()),
+            _ => anyhow::bail!(
+                "this player can not join this tournament match!tournament_id:{},match_id:{},user_id:{}",
+                self.id,
+                match_id,
+                user_id
+            ),
+        }
+    }
+
+    ///记录对局结果,ranks里没有的人算弃权
+    pub fn on_result(&mut self, match_id: u32, ranks: Vec<u32>, now: u64) -> Vec<TournamentEvent> {
+        let mut events = Vec::new();
+        let tm = self.matches.iter_mut().find(|m| m.match_id == match_id);
+        if tm.is_none() {
+            log::warn!(
+                "could not find tournament match!tournament_id:{},match_id:{}",
+                self.id,
+                match_id
+            );
+            return events;
+        }
+        let tm = tm.unwrap();
+        if tm.state == MatchState::Finished {
+            return events;
+        }
+        let mut res = Vec::new();
+        for user_id in ranks {
+            if tm.players.contains(&user_id) && !res.contains(&user_id) {
+                res.push(user_id);
+            }
+        }
+        tm.state = MatchState::Finished;
+        tm.ranks = res;
+        let tm = tm.clone();
+        if tm.bracket == Bracket::Final {
+            self.finish(&tm.ranks, &tm.players, &mut events);
+            return events;
+        }
+        let advance = std::cmp::min(self.advance_count as usize, tm.ranks.len());
+        let max_losses = self.format.max_losses();
+        for user_id in tm.players.iter() {
+            if tm.ranks[..advance].contains(user_id) {
+                continue;
+            }
+            if let Some(player) = self.players.get_mut(user_id) {
+                player.losses += 1;
+                player.eliminated = player.losses >= max_losses;
+            }
+        }
+        let round_over = self
+            .matches
+            .iter()
+            .all(|m| m.round != self.round || m.state == MatchState::Finished);
+        if round_over {
+            self.finish_round(now, &mut events);
+        }
+        events
+    }
+
+    ///一轮打完,给这一轮淘汰的人定名次
+    fn finish_round(&mut self, now: u64, events: &mut Vec<TournamentEvent>) {
+        let alive = self.get_alive_players(None);
+        let round = self.round;
+        let final_rank = alive.len() as u32 + 1;
+        let mut eliminated = Vec::new();
+        for player in self.players.values_mut() {
+            if player.eliminated && player.final_rank == 0 {
+                player.final_rank = final_rank;
+                eliminated.push(player.user_id);
+            }
+        }
+        for user_id in eliminated {
+            events.push(self.build_notice(user_id, NoticeType::Eliminated, round, 0));
+        }
+        //全弃权了,剩一个或者没人了就直接结束
+        if alive.len() <= 1 {
+            self.finish(&alive, &alive, events);
+            return;
+        }
+        for user_id in alive {
+            events.push(self.build_notice(user_id, NoticeType::Advance, round, 0));
+        }
+        self.next_round_time = now + self.round_interval;
+    }
+
+    ///结束,ranks是最后一局的名次,players里没在ranks的并列最后
+    fn finish(&mut self, ranks: &[u32], players: &[u32], events: &mut Vec<TournamentEvent>) {
+        self.state = TournamentState::Finished;
+        self.next_round_time = 0;
+        for user_id in players.iter() {
+            let final_rank = match ranks.iter().position(|id| id == user_id) {
+                Some(index) => index as u32 + 1,
+                None => ranks.len() as u32 + 1,
+            };
+            if let Some(player) = self.players.get_mut(user_id) {
+                player.final_rank = final_rank;
+                player.eliminated = final_rank > 1;
+            }
+        }
+        for &user_id in self.players.keys() {
+            events.push(self.build_notice(user_id, NoticeType::Finished, self.round, 0));
+        }
+        log::info!(
+            "锦标赛结束!tournament_id:{},champion:{:?}",
+            self.id,
+            ranks.first()
+        );
+    }
+
+    fn build_notice(
+        &self,
+        user_id: u32,
+        notice_type: NoticeType,
+        round: u32,
+        match_id: u32,
+    ) -> TournamentEvent {
+        let final_rank = self.players.get(&user_id).map_or(0, |p| p.final_rank);
+        TournamentEvent::Notice {
+            tournament_id: self.id,
+            user_id,
+            notice_type,
+            round,
+            match_id,
+            final_rank,
+        }
+    }
+
+    pub fn into_pt(&self) -> TournamentPt {
+        let mut pt = TournamentPt::new();
+        pt.set_tournament_id(self.id);
+        pt.set_name(self.name.clone());
+        pt.set_format(self.format as u32);
+        pt.set_state(self.state as u32);
+        pt.set_sign_up_start(self.sign_up_start);
+        pt.set_sign_up_end(self.sign_up_end);
+        pt.set_start_time(self.start_time);
+        pt.set_max_players(self.max_players);
+        pt.set_advance_count(self.advance_count);
+        pt.set_player_count(self.players.len() as u32);
+        pt.set_round(self.round);
+        pt
+    }
+
+    ///选手按种子排好,还没排种子的按报名时间
+    pub fn player_pts(&self) -> Vec<TournamentPlayerPt> {
+        let mut players: Vec<&TournamentPlayer> = self.players.values().collect();
+        players.sort_by_key(|p| (p.seed, p.sign_up_time));
+        players
+            .into_iter()
+            .map(|p| {
+                let mut pt = TournamentPlayerPt::new();
+                pt.set_user_id(p.user_id);
+                pt.set_name(p.name.clone());
+                pt.set_seed(p.seed);
+                pt.set_losses(p.losses);
+                pt.set_eliminated(p.eliminated);
+                pt.set_final_rank(p.final_rank);
+                pt
+            })
+            .collect()
+    }
+}
+
+///按种子蛇形分房间,高种子分散开,房间人数最多差一个
+fn split_rooms(players: Vec<u32>) -> Vec<Vec<u32>> {
+    if players.is_empty() {
+        return vec![];
+    }
+    let room_count = (players.len() + ROOM_MEMBER_MAX - 1) / ROOM_MEMBER_MAX;
+    let mut rooms = vec![Vec::new(); room_count];
+    for (index, user_id) in players.into_iter().enumerate() {
+        let pos = index % (room_count * 2);
+        let room = if pos < room_count {
+            pos
+        } else {
+            room_count * 2 - 1 - pos
+        };
+        rooms[room].push(user_id);
+    }
+    rooms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIGN_UP_END: u64 = 1000;
+    const INTERVAL: u64 = 100;
+    const TIMEOUT: u64 = 500;
+
+    ///报名结束直接开赛,玩家id就是种子序号
+    fn new_tournament(format: TournamentFormat, count: u32) -> Tournament {
+        let mut t = Tournament {
+            id: 1,
+            name: "test".to_owned(),
+            format,
+            state: TournamentState::Pending,
+            sign_up_start: 0,
+            sign_up_end: SIGN_UP_END,
+            start_time: SIGN_UP_END,
+            max_players: 16,
+            advance_count: 2,
+            round_interval: INTERVAL,
+            match_timeout: TIMEOUT,
+            players: HashMap::new(),
+            matches: Vec::new(),
+            round: 0,
+            next_round_time: 0,
+        };
+        t.update(0, &|_| true);
+        for user_id in (1..=count).rev() {
+            let league_id = 100 - user_id as i32;
+            t.sign_up(user_id, String::new(), league_id, 0, 0).unwrap();
+        }
+        t
+    }
+
+    fn start_matches(events: &[TournamentEvent]) -> Vec<(u32, Vec<u32>)> {
+        events
+            .iter()
+            .filter_map(|e| match e {
+                TournamentEvent::StartMatch {
+                    match_id, user_ids, ..
+                } => Some((*match_id, user_ids.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn single_elimination() {
+        let mut t = new_tournament(TournamentFormat::SingleElimination, 8);
+        //报名截止之前不能开赛
+        assert!(start_matches(&t.update(SIGN_UP_END - 1, &|_| true)).is_empty());
+        let starts = start_matches(&t.update(SIGN_UP_END, &|_| true));
+        assert_eq!(t.state, TournamentState::Running);
+        //高种子蛇形分开
+        assert_eq!(starts, vec![(1, vec![1, 4, 5, 8]), (2, vec![2, 3, 6, 7])]);
+        assert!(t.check_join(1, 4).is_ok());
+        assert!(t.check_join(1, 2).is_err());
+
+        t.on_result(1, vec![5, 1, 4, 8], SIGN_UP_END);
+        assert_eq!(t.next_round_time, 0);
+        t.on_result(2, vec![2, 3, 6, 7], SIGN_UP_END);
+        //前两名晋级,剩下的淘汰
+        for &id in [4, 8, 6, 7].iter() {
+            assert!(t.players[&id].eliminated);
+            assert_eq!(t.players[&id].final_rank, 5);
+        }
+        assert_eq!(t.next_round_time, SIGN_UP_END + INTERVAL);
+
+        //剩四个人直接决赛
+        let starts = start_matches(&t.update(SIGN_UP_END + INTERVAL, &|_| true));
+        assert_eq!(starts, vec![(3, vec![1, 2, 3, 5])]);
+        assert_eq!(t.matches[2].bracket, Bracket::Final);
+        t.on_result(3, vec![3, 1, 2, 5], SIGN_UP_END + INTERVAL);
+        assert_eq!(t.state, TournamentState::Finished);
+        assert_eq!(t.players[&3].final_rank, 1);
+        assert!(!t.players[&3].eliminated);
+        assert_eq!(t.players[&5].final_rank, 4);
+    }
+
+    #[test]
+    fn bye() {
+        let mut t = new_tournament(TournamentFormat::SingleElimination, 5);
+        let starts = start_matches(&t.update(SIGN_UP_END, &|_| true));
+        //两个人的房间不超过晋级名额,直接轮空晋级
+        assert_eq!(starts, vec![(1, vec![1, 4, 5])]);
+        assert_eq!(t.matches[1].players, vec![2, 3]);
+        assert_eq!(t.matches[1].state, MatchState::Finished);
+        assert_eq!(t.players[&2].losses, 0);
+        assert_eq!(t.players[&3].losses, 0);
+
+        t.on_result(1, vec![1, 4, 5], SIGN_UP_END);
+        assert!(t.players[&5].eliminated);
+        let starts = start_matches(&t.update(SIGN_UP_END + INTERVAL, &|_| true));
+        assert_eq!(starts, vec![(3, vec![1, 2, 3, 4])]);
+    }
+
+    #[test]
+    fn forfeit() {
+        let mut t = new_tournament(TournamentFormat::SingleElimination, 8);
+        //不在线的算弃权,房间里不够两个人直接出结果
+        let offline = [2, 3, 6];
+        let starts = start_matches(&t.update(SIGN_UP_END, &|id| !offline.contains(&id)));
+        assert_eq!(starts, vec![(1, vec![1, 4, 5, 8])]);
+        assert_eq!(t.matches[1].ranks, vec![7]);
+        assert!(!t.players[&7].eliminated);
+        for &id in offline.iter() {
+            assert!(t.players[&id].eliminated);
+        }
+
+        //超时没结果就当都弃权
+        let events = t.update(SIGN_UP_END + TIMEOUT, &|_| true);
+        assert_eq!(t.matches[0].state, MatchState::Finished);
+        assert!(t.matches[0].ranks.is_empty());
+        for &id in [1, 4, 5, 8].iter() {
+            assert!(t.players[&id].eliminated);
+        }
+        //只剩一个人,直接拿冠军
+        assert_eq!(t.state, TournamentState::Finished);
+        assert_eq!(t.players[&7].final_rank, 1);
+        let finished = events
+            .iter()
+            .filter(|e| match e {
+                TournamentEvent::Notice { notice_type, .. } => *notice_type == NoticeType::Finished,
+                _ => false,
+            })
+            .count();
+        assert_eq!(finished, 8);
+        //结束了再报结果没用
+        assert!(t.on_result(1, vec![1], SIGN_UP_END + TIMEOUT).is_empty());
+    }
+
+    #[test]
+    fn double_elimination() {
+        let mut t = new_tournament(TournamentFormat::DoubleElimination, 8);
+        t.update(SIGN_UP_END, &|_| true);
+        t.on_result(1, vec![1, 4, 5, 8], SIGN_UP_END);
+        t.on_result(2, vec![2, 3, 6, 7], SIGN_UP_END);
+        //输一次还没淘汰,进败者组
+        assert!(t.players.values().all(|p| !p.eliminated));
+        let starts = start_matches(&t.update(SIGN_UP_END + INTERVAL, &|_| true));
+        assert_eq!(starts, vec![(3, vec![1, 2, 3, 4]), (4, vec![5, 6, 7, 8])]);
+        assert_eq!(t.matches[2].bracket, Bracket::Winners);
+        assert_eq!(t.matches[3].bracket, Bracket::Losers);
+
+        t.on_result(3, vec![1, 2, 3, 4], SIGN_UP_END + INTERVAL);
+        t.on_result(4, vec![5, 6, 7, 8], SIGN_UP_END + INTERVAL);
+        //败者组再输就淘汰,胜者组输的掉到败者组
+        assert!(t.players[&7].eliminated && t.players[&8].eliminated);
+        assert!(!t.players[&3].eliminated);
+        assert_eq!(t.players[&3].losses, 1);
+        assert_eq!(t.players[&7].final_rank, 7);
+    }
+
+    #[test]
+    fn cancel() {
+        let mut t = new_tournament(TournamentFormat::SingleElimination, 1);
+        t.update(SIGN_UP_END, &|_| true);
+        assert_eq!(t.state, TournamentState::Cancelled);
+        assert!(t.is_over());
+    }
+}
@@ -7,12 +7,16 @@ use crate::net::http::{ReloadTempsHandler, UpdateSeasonHandler};
 use crate::net::room_tcp_client::RoomTcpClientHandler;
 use crate::net::{battle_tcp_server, gate_tcp_server};
 use async_std::sync::Mutex;
-use net::http::{KickPlayerHandler, StopAllServerHandler, UpdateWorldBossHandler};
+use net::http::{
+    CreateTournamentHandler, KickPlayerHandler, SendMailHandler, StopAllServerHandler,
+    TournamentQueryHandler, UpdateWorldBossHandler,
+};
 use net::rank_tcp_client::RankTcpClientHandler;
 use std::env;
 use std::sync::Arc;
 use std::time::Duration;
 use tools::conf::Conf;
+use tools::metrics::MetricsHttpHandler;
 use tools::tcp::ClientHandler;
 
 #[macro_use]
@@ -41,6 +45,9 @@ fn main() {
     //初始化tcp服务端
     init_tcp_server(game_center.clone());
 
+    //初始化锦标赛定时器
+    init_tournament_timer(game_center.clone());
+
     //初始化http服务器
     init_http_server(game_center.clone());
 
@@ -49,6 +56,12 @@ fn main() {
 }
 
 fn init_log() {
+    //配置了log_format就用tracing输出,日志会带上trace_id/user_id/room_id/battle_id
+    let log_format = CONF_MAP.get_str("log_format", "");
+    if !log_format.is_empty() {
+        tools::my_log::init_trace_log(log_format == "json");
+        return;
+    }
     let info_log = CONF_MAP.get_str("info_log_path", "");
     let error_log = CONF_MAP.get_str("error_log_path", "");
     tools::my_log::init_log(info_log.as_str(), error_log.as_str());
@@ -63,6 +76,17 @@ fn init_tcp_server(gm: Lock) {
     battle_tcp_server::new(tcp_port.to_owned(), gm);
 }
 
+///锦标赛定时器,每秒检查一次
+fn init_tournament_timer(gm: Lock) {
+    let m = async move {
+        loop {
+            async_std::task::sleep(Duration::from_secs(1)).await;
+            gm.lock().await.tournament_tick();
+        }
+    };
+    async_std::task::spawn(m);
+}
+
 ///初始化tcp客户端
 fn init_tcp_client(gm: Lock) {
     let mut rth = RoomTcpClientHandler { gm: gm.clone() };
@@ -87,5 +111,11 @@ fn init_http_server(gm: Lock) {
         .route(Box::new(StopAllServerHandler::new(gm.clone())))
         .route(Box::new(KickPlayerHandler::new(gm.clone())))
         .route(Box::new(UpdateWorldBossHandler::new(gm.clone())))
+        .route(Box::new(SendMailHandler::new(gm.clone())))
+        .route(Box::new(CreateTournamentHandler::new(gm.clone())))
+        .route(Box::new(TournamentQueryHandler::new(gm.clone())))
+        .route(Box::new(MetricsHttpHandler::new(move || {
+            async_std::task::block_on(gm.lock()).collect_metrics();
+        })))
         .bind(http_port);
 }
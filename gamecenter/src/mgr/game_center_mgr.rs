@@ -1,13 +1,51 @@
+use crate::entity::tournament::TournamentEvent;
+use crate::mgr::tournament_mgr::{CreateTournamentParam, TournamentMgr};
 use crossbeam::channel::Sender;
-use log::warn;
+use log::{error, info, warn};
 use protobuf::Message;
+use serde::Deserialize;
 use serde_json::Value;
 use std::collections::HashMap;
-use tools::cmd_code::{BattleCode, GameCode, GateCode, RankCode, RoomCode, ServerCommonCode};
+use tools::cmd_code::{
+    BattleCode, CenterCode, ClientCode, GameCode, GateCode, RankCode, RoomCode, ServerCommonCode,
+};
+use tools::metrics;
 use tools::net_message_io::NetHandler;
-use tools::protos::server_protocol::{R_B_START, R_S_UPDATE_SEASON, UPDATE_WORLD_BOSS_PUSH};
+use tools::protos::base::{AttachmentPt, MailPt};
+use tools::protos::protocol::{
+    C_TOURNAMENT_BRACKET, S_TOURNAMENT_BRACKET, S_TOURNAMENT_LIST, S_TOURNAMENT_NOTICE,
+    S_TOURNAMENT_SIGN_UP,
+};
+use tools::protos::server_protocol::{
+    G_C_TOURNAMENT_SIGN_UP, G_R_TOURNAMENT_JOIN, G_SEND_MAIL, G_TOURNAMENT_MATCH, R_B_START,
+    R_C_TOURNAMENT_RESULT, R_S_UPDATE_SEASON, UPDATE_WORLD_BOSS_PUSH,
+};
 use tools::util::packet::Packet;
 
+///邮件默认有效天数
+const DEFAULT_MAIL_EXPIRE_DAYS: i64 = 30;
+
+///发邮件的附件参数
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct MailAttachmentParam {
+    pub attach_type: u32, //附件类型 1:grade相框 2:灵魂头像
+    pub id: u32,          //配置id
+    pub count: u32,       //数量
+}
+
+///发邮件的http参数,all为true发全服,否则发给user_ids
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SendMailParam {
+    pub all: bool,                             //是否全服邮件
+    pub user_ids: Vec<u32>,                    //收件人
+    pub title: String,                         //标题
+    pub content: String,                       //内容
+    pub attachments: Vec<MailAttachmentParam>, //附件
+    pub expire_days: Option<i64>,              //有效天数,0代表不过期,不填默认30天
+}
+
 #[derive(Default)]
 pub struct GameCenterMgr {
     pub rank_server: Option<Sender<Vec<u8>>>,         //排行榜服
@@ -16,6 +54,8 @@ pub struct GameCenterMgr {
     pub battle_clients: HashMap<usize, BattleClient>, //战斗服客户端,key:token,value:BattleClient
     pub user_w_gate: HashMap<u32, usize>,             //玩家对应gate
     pub user_w_battle: HashMap<u32, usize>,           //玩家对应战斗服
+    last_broadcast_id: u64,                           //上一封全服邮件id
+    pub tournament_mgr: TournamentMgr,                //锦标赛
 }
 
 impl GameCenterMgr {
@@ -112,6 +152,14 @@ impl GameCenterMgr {
         let bytes = packet.build_server_bytes();
         let _ = self.get_room_center_mut().send(bytes);
 
+        //通知排行榜服,世界boss的血量和伤害排行在那边
+        packet.set_cmd(RankCode::UpdateWorldBossPush.into_u32());
+        let bytes = packet.build_server_bytes();
+        let res = self.get_rank_center_mut().send(bytes);
+        if let Err(e) = res {
+            warn!("{:?}", e);
+        }
+
         packet.set_cmd(GameCode::UpdateWorldBossPush.into_u32());
         let bytes = packet.build_server_bytes();
         //推送给所有游戏服
@@ -120,6 +168,293 @@ impl GameCenterMgr {
         }
     }
 
+    ///发邮件,全服邮件广播给所有游戏服,个人邮件发到玩家所在的游戏服,不在线随便找一个游戏服写db
+    pub fn send_mail_handler(&mut self, value: Value) -> anyhow::Result<()> {
+        let param: SendMailParam = serde_json::from_value(value)?;
+        if param.title.is_empty() {
+            anyhow::bail!("the title is empty!")
+        }
+        if !param.all && param.user_ids.is_empty() {
+            anyhow::bail!("the user_ids is empty!")
+        }
+        if self.gate_clients.is_empty() {
+            anyhow::bail!("there is no game server!")
+        }
+        let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?;
+        let now_secs = now.as_secs() as i64;
+        let expire_days = param.expire_days.unwrap_or(DEFAULT_MAIL_EXPIRE_DAYS);
+        let mut mail = MailPt::new();
+        mail.set_title(param.title);
+        mail.set_content(param.content);
+        for attachment in param.attachments.iter() {
+            let mut ap = AttachmentPt::new();
+            ap.set_attach_type(attachment.attach_type);
+            ap.set_id(attachment.id);
+            ap.set_count(attachment.count);
+            mail.attachments.push(ap);
+        }
+        mail.set_send_time(now_secs);
+        if expire_days > 0 {
+            mail.set_expire_time(now_secs + expire_days * 24 * 3600);
+        }
+        let mut gsm = G_SEND_MAIL::new();
+        gsm.set_mail(mail);
+        let cmd = GameCode::SendMail.into_u32();
+        if param.all {
+            //毫秒时间戳当id,保证递增
+            let broadcast_id = std::cmp::max(now.as_millis() as u64, self.last_broadcast_id + 1);
+            self.last_broadcast_id = broadcast_id;
+            gsm.set_broadcast_id(broadcast_id);
+            let mut packet = Packet::new(cmd, 0, 0);
+            packet.set_is_client(false);
+            packet.set_is_broad(true);
+            packet.set_data(&gsm.write_to_bytes()?[..]);
+            let bytes = packet.build_server_bytes();
+            for gate_client in self.gate_clients.values() {
+                gate_client.send(bytes.as_slice());
+            }
+            info!("发送全服邮件!broadcast_id:{}", broadcast_id);
+            return Ok(());
+        }
+        let bytes = gsm.write_to_bytes()?;
+        for user_id in param.user_ids {
+            if user_id == 0 {
+                continue;
+            }
+            let gate_client = match self.get_gate_client(user_id) {
+                Ok(gate_client) => gate_client,
+                Err(_) => self.gate_clients.values().next().unwrap(),
+            };
+            let packet_bytes = Packet::build_packet_bytes(cmd, user_id, bytes.clone(), true, false);
+            gate_client.send(packet_bytes.as_slice());
+        }
+        info!("发送个人邮件!title:{}", gsm.get_mail().get_title());
+        Ok(())
+    }
+
+    ///http创建锦标赛
+    pub fn create_tournament_handler(&mut self, value: Value) -> anyhow::Result<u32> {
+        let param: CreateTournamentParam = serde_json::from_value(value)?;
+        self.tournament_mgr.create(param)
+    }
+
+    ///http查锦标赛,带tournament_id返回完整对阵图,不带返回所有锦标赛
+    pub fn tournament_query_handler(&self, value: Value) -> anyhow::Result<Value> {
+        let tournament_id = value.get("tournament_id").and_then(|id| id.as_u64());
+        if let Some(tournament_id) = tournament_id {
+            let tournament = self.tournament_mgr.get(tournament_id as u32)?;
+            return Ok(serde_json::to_value(tournament)?);
+        }
+        let list: Vec<Value> = self
+            .tournament_mgr
+            .tournaments
+            .values()
+            .map(|t| {
+                serde_json::json!({
+                    "id": t.id,
+                    "name": t.name,
+                    "format": t.format,
+                    "state": t.state,
+                    "player_count": t.players.len(),
+                    "round": t.round,
+                })
+            })
+            .collect();
+        Ok(Value::from(list))
+    }
+
+    ///处理中心服自己的命令
+    pub fn tournament_handler(&mut self, packet: &Packet) {
+        let cmd = packet.get_cmd();
+        let user_id = packet.get_user_id();
+        if cmd == CenterCode::TournamentList.into_u32() {
+            let mut proto = S_TOURNAMENT_LIST::new();
+            for tournament in self.tournament_mgr.tournaments.values() {
+                if tournament.is_over() {
+                    continue;
+                }
+                proto.tournaments.push(tournament.into_pt());
+                if tournament.players.contains_key(&user_id) {
+                    proto.signed_ids.push(tournament.id);
+                }
+            }
+            self.send_2_client(ClientCode::TournamentList, user_id, proto.write_to_bytes());
+        } else if cmd == CenterCode::TournamentBracket.into_u32() {
+            let mut proto = C_TOURNAMENT_BRACKET::new();
+            if let Err(e) = proto.merge_from_bytes(packet.get_data()) {
+                warn!("{:?}", e);
+                return;
+            }
+            let mut stb = S_TOURNAMENT_BRACKET::new();
+            match self.tournament_mgr.get(proto.tournament_id) {
+                Ok(tournament) => {
+                    stb.set_is_succ(true);
+                    stb.set_tournament(tournament.into_pt());
+                    for pt in tournament.player_pts() {
+                        stb.players.push(pt);
+                    }
+                    for tm in tournament.matches.iter() {
+                        stb.matches.push(tm.into_pt());
+                    }
+                }
+                Err(e) => stb.set_err_mess(e.to_string()),
+            }
+            self.send_2_client(ClientCode::TournamentBracket, user_id, stb.write_to_bytes());
+        } else if cmd == CenterCode::TournamentSignUp.into_u32() {
+            let mut proto = G_C_TOURNAMENT_SIGN_UP::new();
+            if let Err(e) = proto.merge_from_bytes(packet.get_data()) {
+                warn!("{:?}", e);
+                return;
+            }
+            let tournament_id = proto.tournament_id;
+            let league = proto.get_league();
+            let res = self.tournament_mgr.get_mut(tournament_id).and_then(|t| {
+                t.sign_up(
+                    user_id,
+                    proto.get_nick_name().to_owned(),
+                    league.league_id,
+                    league.league_score,
+                    now_millis(),
+                )
+            });
+            let mut sts = S_TOURNAMENT_SIGN_UP::new();
+            sts.set_tournament_id(tournament_id);
+            match res {
+                Ok(_) => {
+                    sts.set_is_succ(true);
+                    info!(
+                        "锦标赛报名成功!tournament_id:{},user_id:{}",
+                        tournament_id, user_id
+                    );
+                }
+                Err(e) => {
+                    warn!("{:?}", e);
+                    sts.set_err_mess(e.to_string());
+                }
+            }
+            self.send_2_client(ClientCode::TournamentSignUp, user_id, sts.write_to_bytes());
+        } else if cmd == CenterCode::TournamentResult.into_u32() {
+            let mut proto = R_C_TOURNAMENT_RESULT::new();
+            if let Err(e) = proto.merge_from_bytes(packet.get_data()) {
+                warn!("{:?}", e);
+                return;
+            }
+            let res = self.tournament_mgr.on_result(
+                proto.tournament_id,
+                proto.match_id,
+                proto.take_ranks(),
+                now_millis(),
+            );
+            match res {
+                Ok(events) => self.dispatch_tournament_events(events),
+                Err(e) => warn!("{:?}", e),
+            }
+        }
+    }
+
+    ///进锦标赛房间前校验玩家是不是这个对局的选手
+    pub fn check_tournament_join(&self, packet: &Packet) -> bool {
+        let mut proto = G_R_TOURNAMENT_JOIN::new();
+        if let Err(e) = proto.merge_from_bytes(packet.get_data()) {
+            warn!("{:?}", e);
+            return false;
+        }
+        let res = self
+            .tournament_mgr
+            .get(proto.tournament_id)
+            .and_then(|tournament| tournament.check_join(proto.match_id, packet.get_user_id()));
+        if let Err(e) = res {
+            warn!("{:?}", e);
+            return false;
+        }
+        true
+    }
+
+    ///锦标赛定时检查,报名截止,开新一轮和对局超时
+    pub fn tournament_tick(&mut self) {
+        let user_w_gate = &self.user_w_gate;
+        let is_online = |user_id: u32| user_w_gate.contains_key(&user_id);
+        let events = self.tournament_mgr.update(now_millis(), &is_online);
+        self.dispatch_tournament_events(events);
+    }
+
+    ///把锦标赛产生的消息发出去
+    fn dispatch_tournament_events(&mut self, events: Vec<TournamentEvent>) {
+        for event in events {
+            match event {
+                TournamentEvent::StartMatch {
+                    tournament_id,
+                    match_id,
+                    user_ids,
+                } => {
+                    let mut gtm = G_TOURNAMENT_MATCH::new();
+                    gtm.set_tournament_id(tournament_id);
+                    gtm.set_match_id(match_id);
+                    gtm.set_count(user_ids.len() as u32);
+                    let bytes = match gtm.write_to_bytes() {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            error!("{:?}", e);
+                            continue;
+                        }
+                    };
+                    let cmd = GameCode::TournamentMatch.into_u32();
+                    for user_id in user_ids {
+                        let packet_bytes =
+                            Packet::build_packet_bytes(cmd, user_id, bytes.clone(), true, false);
+                        match self.get_gate_client(user_id) {
+                            Ok(gc) => gc.send(packet_bytes.as_slice()),
+                            Err(e) => warn!("{:?}", e),
+                        }
+                    }
+                    info!(
+                        "锦标赛对局开始!tournament_id:{},match_id:{}",
+                        tournament_id, match_id
+                    );
+                }
+                TournamentEvent::Notice {
+                    tournament_id,
+                    user_id,
+                    notice_type,
+                    round,
+                    match_id,
+                    final_rank,
+                } => {
+                    let mut stn = S_TOURNAMENT_NOTICE::new();
+                    stn.set_tournament_id(tournament_id);
+                    stn.set_notice_type(notice_type as u32);
+                    stn.set_round(round);
+                    stn.set_match_id(match_id);
+                    stn.set_final_rank(final_rank);
+                    self.send_2_client(ClientCode::TournamentNotice, user_id, stn.write_to_bytes());
+                }
+            }
+        }
+    }
+
+    ///发给客户端,玩家不在线就算了
+    fn send_2_client(
+        &self,
+        cmd: ClientCode,
+        user_id: u32,
+        bytes: protobuf::ProtobufResult<Vec<u8>>,
+    ) {
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                error!("{:?}", e);
+                return;
+            }
+        };
+        let gate_client = self.get_gate_client(user_id);
+        if let Err(e) = gate_client {
+            warn!("{:?},cmd:{:?}", e, cmd);
+            return;
+        }
+        let bytes = Packet::build_packet_bytes(cmd.into_u32(), user_id, bytes, true, true);
+        gate_client.unwrap().send(bytes.as_slice());
+    }
+
     ///停服
     pub fn stop_all_server_handler(&mut self) {
         let bytes =
@@ -180,6 +515,10 @@ impl GameCenterMgr {
         if cmd == BattleCode::Start.into_u32() {
             self.slb(packet.clone());
         }
+        //gm命令包头里的是目标玩家,不一定在这个gate上
+        if cmd == RoomCode::GmCommand.into_u32() || cmd == BattleCode::GmCommand.into_u32() {
+            return;
+        }
         //绑定玩家到gate
         let user_id = packet.get_user_id();
         if user_id <= 0 {
@@ -258,6 +597,22 @@ impl GameCenterMgr {
         }
     }
 
+    ///刷新监控指标
+    pub fn collect_metrics(&self) {
+        metrics::set_gauge("online_users", self.user_w_gate.len() as i64);
+        metrics::set_gauge("battle_users", self.user_w_battle.len() as i64);
+        metrics::set_gauge("gate_clients", self.gate_clients.len() as i64);
+        metrics::set_gauge("battle_clients", self.battle_clients.len() as i64);
+        let rooms: u32 = self.battle_clients.values().map(|bc| bc.room_num).sum();
+        metrics::set_gauge("active_rooms", rooms as i64);
+        if let Some(sender) = self.room_center.as_ref() {
+            metrics::set_gauge("room_channel_backlog", sender.len() as i64);
+        }
+        if let Some(sender) = self.rank_server.as_ref() {
+            metrics::set_gauge("rank_channel_backlog", sender.len() as i64);
+        }
+    }
+
     pub fn get_room_center_mut(&mut self) -> &mut Sender<Vec<u8>> {
         self.room_center.as_mut().unwrap()
     }
@@ -315,6 +670,18 @@ impl GameCenterMgr {
     }
 }
 
+///当前毫秒时间戳
+fn now_millis() -> u64 {
+    let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH);
+    match now {
+        Ok(now) => now.as_millis() as u64,
+        Err(e) => {
+            error!("{:?}", e);
+            0
+        }
+    }
+}
+
 pub struct GateClient {
     net_handler: NetHandler,
 }
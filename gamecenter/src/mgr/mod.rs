@@ -1 +1,2 @@
-pub mod game_center_mgr;
\ No newline at end of file
+pub mod game_center_mgr;
+pub mod tournament_mgr;
\ No newline at end of file
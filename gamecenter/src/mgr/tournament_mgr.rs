@@ -0,0 +1,128 @@
+use crate::entity::tournament::{
+    Tournament, TournamentEvent, TournamentFormat, TournamentState, ROOM_MEMBER_MAX,
+};
+use log::info;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+///默认每个房间前两名晋级
+const DEFAULT_ADVANCE_COUNT: u32 = 2;
+///默认人数上限
+const DEFAULT_MAX_PLAYERS: u32 = 64;
+///默认两轮之间间隔30秒
+const DEFAULT_ROUND_INTERVAL: u64 = 30000;
+///默认对局超时60分钟
+const DEFAULT_MATCH_TIMEOUT: u64 = 3600000;
+
+///创建锦标赛的http参数,时间都是毫秒时间戳
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct CreateTournamentParam {
+    pub name: String,                //名字
+    pub format: u32,                 //赛制 1:单败淘汰 2:双败淘汰
+    pub sign_up_start: u64,          //报名开始时间
+    pub sign_up_end: u64,            //报名结束时间
+    pub start_time: u64,             //开赛时间,不填就是报名结束马上开
+    pub max_players: Option<u32>,    //人数上限,不填默认64
+    pub advance_count: Option<u32>,  //每个房间前几名晋级,不填默认2
+    pub round_interval: Option<u64>, //两轮之间间隔,不填默认30秒
+    pub match_timeout: Option<u64>,  //对局超时时间,不填默认60分钟
+}
+
+///锦标赛管理器
+#[derive(Default)]
+pub struct TournamentMgr {
+    pub tournaments: BTreeMap<u32, Tournament>, //key:锦标赛id
+    next_id: u32,                               //下一个锦标赛id
+}
+
+impl TournamentMgr {
+    ///创建锦标赛,返回锦标赛id
+    pub fn create(&mut self, param: CreateTournamentParam) -> anyhow::Result<u32> {
+        if param.name.is_empty() {
+            anyhow::bail!("the name is empty!")
+        }
+        let format = TournamentFormat::from_u32(param.format)?;
+        if param.sign_up_end <= param.sign_up_start {
+            anyhow::bail!("sign_up_end must be later than sign_up_start!")
+        }
+        let advance_count = param.advance_count.unwrap_or(DEFAULT_ADVANCE_COUNT);
+        //晋级名额超过半个房间,分组轮空的人可能一直打不完
+        if advance_count == 0 || advance_count as usize > ROOM_MEMBER_MAX / 2 {
+            anyhow::bail!("advance_count is error!advance_count:{}", advance_count)
+        }
+        let max_players = param.max_players.unwrap_or(DEFAULT_MAX_PLAYERS);
+        if max_players < 2 {
+            anyhow::bail!("max_players is error!max_players:{}", max_players)
+        }
+        self.next_id += 1;
+        let id = self.next_id;
+        let tournament = Tournament {
+            id,
+            name: param.name,
+            format,
+            state: TournamentState::Pending,
+            sign_up_start: param.sign_up_start,
+            sign_up_end: param.sign_up_end,
+            start_time: std::cmp::max(param.start_time, param.sign_up_end),
+            max_players,
+            advance_count,
+            round_interval: param.round_interval.unwrap_or(DEFAULT_ROUND_INTERVAL),
+            match_timeout: param.match_timeout.unwrap_or(DEFAULT_MATCH_TIMEOUT),
+            players: Default::default(),
+            matches: Vec::new(),
+            round: 0,
+            next_round_time: 0,
+        };
+        info!(
+            "创建锦标赛!tournament_id:{},name:{},format:{:?}",
+            id, tournament.name, format
+        );
+        self.tournaments.insert(id, tournament);
+        Ok(id)
+    }
+
+    pub fn get(&self, tournament_id: u32) -> anyhow::Result<&Tournament> {
+        match self.tournaments.get(&tournament_id) {
+            Some(tournament) => Ok(tournament),
+            None => anyhow::bail!("could not find tournament!tournament_id:{}", tournament_id),
+        }
+    }
+
+    pub fn get_mut(&mut self, tournament_id: u32) -> anyhow::Result<&mut Tournament> {
+        match self.tournaments.get_mut(&tournament_id) {
+            Some(tournament) => Ok(tournament),
+            None => anyhow::bail!("could not find tournament!tournament_id:{}", tournament_id),
+        }
+    }
+
+    ///对局结果
+    pub fn on_result(
+        &mut self,
+        tournament_id: u32,
+        match_id: u32,
+        ranks: Vec<u32>,
+        now: u64,
+    ) -> anyhow::Result<Vec<TournamentEvent>> {
+        let tournament = self.get_mut(tournament_id)?;
+        if tournament.state != TournamentState::Running {
+            anyhow::bail!(
+                "this tournament is not running!tournament_id:{}",
+                tournament_id
+            )
+        }
+        Ok(tournament.on_result(match_id, ranks, now))
+    }
+
+    ///定时检查所有没结束的锦标赛
+    pub fn update(&mut self, now: u64, is_online: &dyn Fn(u32) -> bool) -> Vec<TournamentEvent> {
+        let mut events = Vec::new();
+        for tournament in self.tournaments.values_mut() {
+            if tournament.is_over() {
+                continue;
+            }
+            events.extend(tournament.update(now, is_online));
+        }
+        events
+    }
+}
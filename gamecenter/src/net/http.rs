@@ -9,6 +9,16 @@ use tools::{
     json::{JsonValue, JsonValueTrait},
 };
 
+///校验gm token,没配置token的时候一律拒绝
+fn check_gm_token(params: &JsonValue) -> anyhow::Result<()> {
+    let token = params.get("token").and_then(|v| v.as_str()).unwrap_or("");
+    let gm_token = crate::CONF_MAP.get_str("gm_token", "");
+    if gm_token.is_empty() || token != gm_token {
+        anyhow::bail!("gm token is error!")
+    }
+    Ok(())
+}
+
 pub struct StopAllServerHandler {
     gm: Lock,
 }
@@ -188,3 +198,128 @@ impl HttpServerHandler for UpdateWorldBossHandler {
         Ok(value)
     }
 }
+
+pub struct SendMailHandler {
+    gm: Lock,
+}
+
+impl SendMailHandler {
+    pub fn new(gm: Lock) -> Self {
+        SendMailHandler { gm }
+    }
+}
+
+impl HttpServerHandler for SendMailHandler {
+    fn get_path(&self) -> &str {
+        "/send_mail"
+    }
+
+    fn do_post(
+        &mut self,
+        _uri: String,
+        _uri_params: HashMap<String, String>,
+        _json_params: &[u8],
+    ) -> anyhow::Result<JsonValue> {
+        let _json_params = JsonValue::from_bytes(_json_params);
+        if let Err(err) = _json_params {
+            log::warn!("{:?}", err);
+            return Ok(json!(r#"{"result": "fail!","errMessage":"参数有问题!"}"#));
+        }
+        let params = _json_params.unwrap();
+        if let Err(e) = check_gm_token(&params) {
+            log::warn!("{:?}", e);
+            return Ok(json!({ "status":"false", "error":e.to_string() }));
+        }
+        let mut lock = block_on(self.gm.lock());
+        let res = lock.send_mail_handler(params);
+        if let Err(e) = res {
+            log::warn!("{:?}", e);
+            let value = json!({ "status":"false", "error":e.to_string() });
+            return Ok(value);
+        }
+        let value = json!({ "status":"OK" });
+        Ok(value)
+    }
+}
+
+pub struct CreateTournamentHandler {
+    gm: Lock,
+}
+
+impl CreateTournamentHandler {
+    pub fn new(gm: Lock) -> Self {
+        CreateTournamentHandler { gm }
+    }
+}
+
+impl HttpServerHandler for CreateTournamentHandler {
+    fn get_path(&self) -> &str {
+        "/tournament/create"
+    }
+
+    fn do_post(
+        &mut self,
+        _uri: String,
+        _uri_params: HashMap<String, String>,
+        _json_params: &[u8],
+    ) -> anyhow::Result<JsonValue> {
+        let _json_params = JsonValue::from_bytes(_json_params);
+        if let Err(err) = _json_params {
+            log::warn!("{:?}", err);
+            return Ok(json!(r#"{"result": "fail!","errMessage":"参数有问题!"}"#));
+        }
+        let params = _json_params.unwrap();
+        if let Err(e) = check_gm_token(&params) {
+            log::warn!("{:?}", e);
+            return Ok(json!({ "status":"false", "error":e.to_string() }));
+        }
+        let mut lock = block_on(self.gm.lock());
+        let res = lock.create_tournament_handler(params);
+        match res {
+            Ok(tournament_id) => Ok(json!({ "status":"OK", "tournament_id":tournament_id })),
+            Err(e) => {
+                log::warn!("{:?}", e);
+                Ok(json!({ "status":"false", "error":e.to_string() }))
+            }
+        }
+    }
+}
+
+pub struct TournamentQueryHandler {
+    gm: Lock,
+}
+
+impl TournamentQueryHandler {
+    pub fn new(gm: Lock) -> Self {
+        TournamentQueryHandler { gm }
+    }
+}
+
+impl HttpServerHandler for TournamentQueryHandler {
+    fn get_path(&self) -> &str {
+        "/tournament/query"
+    }
+
+    fn do_post(
+        &mut self,
+        _uri: String,
+        _uri_params: HashMap<String, String>,
+        _json_params: &[u8],
+    ) -> anyhow::Result<JsonValue> {
+        let _json_params = JsonValue::from_bytes(_json_params);
+        if let Err(err) = _json_params {
+            log::warn!("{:?}", err);
+            return Ok(json!(r#"{"result": "fail!","errMessage":"参数有问题!"}"#));
+        }
+        let params = _json_params.unwrap();
+        let lock = block_on(self.gm.lock());
+        let res = lock.tournament_query_handler(params);
+        match res {
+            Ok(data) => Ok(json!({ "status":"OK", "data":data })),
+            Err(e) => {
+                log::warn!("{:?}", e);
+                Ok(json!({ "status":"false", "error":e.to_string() }))
+            }
+        }
+    }
+}
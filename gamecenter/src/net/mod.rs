@@ -9,8 +9,12 @@ use std::collections::VecDeque;
 use crate::Lock;
 use async_trait::async_trait;
 use log::warn;
-use tools::cmd_code::{BattleCode, ClientCode, GameCode, RankCode, RoomCode};
+use tools::cmd_code::{
+    BattleCode, CenterCode, ClientCode, GameCode, GateCode, RankCode, RoomCode,
+};
+use tools::metrics;
 use tools::net_message_io::TransportWay;
+use tools::trace;
 use tools::util::packet::Packet;
 
 use self::battle_tcp_server::BattleTcpServerHandler;
@@ -32,6 +36,8 @@ trait Forward {
         let mut lock = lock.lock().await;
         for mut packet in packet_array {
             let cmd = packet.get_cmd();
+            let _trace = trace::enter(&packet);
+            metrics::inc_counter("packets_total", cmd);
             let user_id = packet.get_user_id();
             let mut bytes = packet.build_server_bytes();
             let is_broad = packet.is_broad();
@@ -55,6 +61,12 @@ trait Forward {
             } else if cmd > RoomCode::Min.into_u32()//转发给房间服
                 && cmd < RoomCode::Max.into_u32()
             {
+                //锦标赛房间只有对局里的选手能进
+                if cmd == RoomCode::TournamentJoin.into_u32()
+                    && !lock.check_tournament_join(&packet)
+                {
+                    continue;
+                }
                 if let Some(gate_token) = gate_token {
                     packet.set_server_token(gate_token as u32);
                     bytes = packet.build_server_bytes();
@@ -120,6 +132,12 @@ trait Forward {
                     Ok(_) => {}
                     Err(e) => warn!("{:?},cmd:{:?}", e, cmd),
                 }
+            } else if cmd > CenterCode::Min.into_u32()//中心服自己处理
+                && cmd < CenterCode::Max.into_u32()
+            {
+                lock.tournament_handler(&packet);
+            } else if cmd == GateCode::SyncOnlineUsers.into_u32() {
+                //gate重连之后同步在线玩家,上面handler里已经绑定好了
             } else {
                 warn!("could not find cmd {}!", cmd);
                 return;
@@ -3,7 +3,7 @@ use crate::Lock;
 use async_std::task::block_on;
 use async_trait::async_trait;
 use crossbeam::channel::Sender;
-use log::error;
+use log::{error, warn};
 use tools::tcp::ClientHandler;
 use tools::util::packet::Packet;
 
@@ -35,9 +35,8 @@ impl ClientHandler for RoomTcpClientHandler {
     }
 
     async fn on_close(&mut self) {
-        let address = crate::CONF_MAP.get_str("room_port", "");
-
-        self.on_read(address.to_string()).await;
+        //on_read里面会自动重连,这里不用再连了
+        warn!("disconnect with server!waiting for reconnect!");
     }
 
     async fn on_message(&mut self, mess: Vec<u8>) {
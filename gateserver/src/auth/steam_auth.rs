@@ -4,39 +4,183 @@ use std::str::FromStr;
 
 use crate::{net::query_user_id_from_redis, CONF_MAP};
 
-pub fn auth_account(ticket: &str) -> anyhow::Result<u32> {
-    let steam_id = auth_user_ticket(ticket)?;
-    let user_id = query_user_id_from_redis(steam_id.to_string().as_str())?;
-    Ok(user_id)
+lazy_static! {
+    ///steam api客户端
+    pub static ref STEAM_API: Box<dyn SteamApi> = {
+        //配置有问题的时候用默认值,登录校验会因为没有key直接失败
+        let api = HttpSteamApi::from_conf().unwrap_or_else(|e| {
+            log::warn!("{:?}", e);
+            HttpSteamApi::default()
+        });
+        Box::new(api)
+    };
 }
 
-pub fn auth_user_ticket(ticket: &str) -> anyhow::Result<u64> {
-    let res = CONF_MAP.conf.get(&"steam".to_owned());
-    if let None = res {
-        anyhow::bail!("steam auth fail!steam key is not find!")
+///所有对steam的外部调用
+pub trait SteamApi: Send + Sync {
+    ///当前游戏的app_id
+    fn app_id(&self) -> u64;
+
+    ///校验客户端登录ticket,返回steam的原始json
+    fn authenticate_user_ticket(&self, ticket: &str) -> anyhow::Result<String>;
+
+    ///检测玩家是否拥有某个app(游戏本体或者dlc),返回steam的原始json
+    fn check_app_ownership(&self, steam_id: u64, app_id: u64) -> anyhow::Result<String>;
+
+    ///需要同步权益的dlc app_id
+    fn dlc_app_ids(&self) -> &[u64];
+}
+
+///基于http的steam api客户端,地址取配置里面的url,本地测试的时候可以指向mock服务器
+pub struct HttpSteamApi {
+    url: String,
+    web_api_key: String,
+    app_id: u64,
+    dlc_app_ids: Vec<u64>,
+}
+
+impl Default for HttpSteamApi {
+    fn default() -> Self {
+        HttpSteamApi::new("https://partner.steam-api.com", "", 0, Vec::new())
     }
+}
 
-    let json_value = res.unwrap();
+impl HttpSteamApi {
+    pub fn new(url: &str, web_api_key: &str, app_id: u64, dlc_app_ids: Vec<u64>) -> Self {
+        HttpSteamApi {
+            url: url.trim_end_matches('/').to_owned(),
+            web_api_key: web_api_key.to_owned(),
+            app_id,
+            dlc_app_ids,
+        }
+    }
 
-    let res = json_value.as_array().unwrap().get(0).unwrap();
-    let json_value = res.as_object().unwrap();
+    ///从config.conf的steam配置创建,缺字段或者类型不对返回错误
+    pub fn from_conf() -> anyhow::Result<Self> {
+        let mut api = HttpSteamApi::default();
+        let res = CONF_MAP.conf.get("steam");
+        if res.is_none() {
+            anyhow::bail!("steam key is not find!")
+        }
+        let json_value = res
+            .unwrap()
+            .as_array()
+            .and_then(|array| array.get(0))
+            .and_then(|v| v.as_object());
+        if json_value.is_none() {
+            anyhow::bail!("steam config must be an array of object!")
+        }
+        let json_value = json_value.unwrap();
+
+        let web_api_key = json_value.get("web_api_key").and_then(|v| v.as_str());
+        if web_api_key.is_none() {
+            anyhow::bail!("steam web_api_key is not find!")
+        }
+        api.web_api_key = web_api_key.unwrap().to_owned();
+
+        let app_id = json_value
+            .get("player_test_app_id")
+            .and_then(|v| v.as_u64());
+        if app_id.is_none() {
+            anyhow::bail!("steam player_test_app_id is not find!")
+        }
+        api.app_id = app_id.unwrap();
+
+        if let Some(url) = json_value.get("url") {
+            if let Some(url) = url.as_str() {
+                api.url = url.trim_end_matches('/').to_owned();
+            }
+        }
+
+        if let Some(dlc_app_ids) = json_value.get("dlc_app_ids") {
+            if let Some(dlc_app_ids) = dlc_app_ids.as_array() {
+                api.dlc_app_ids = dlc_app_ids.iter().filter_map(|v| v.as_u64()).collect();
+            }
+        }
+        Ok(api)
+    }
+}
 
-    let web_api_key = json_value.get("web_api_key").unwrap();
-    let web_api_key = web_api_key.as_str().unwrap();
+impl SteamApi for HttpSteamApi {
+    fn app_id(&self) -> u64 {
+        self.app_id
+    }
 
-    let app_id = json_value.get("player_test_app_id").unwrap();
-    let app_id = app_id.as_u64().unwrap();
+    fn authenticate_user_ticket(&self, ticket: &str) -> anyhow::Result<String> {
+        if self.web_api_key.is_empty() {
+            anyhow::bail!("steam auth fail!steam key is not find!")
+        }
+        let url = format!(
+            "{}/ISteamUserAuth/AuthenticateUserTicket/v1/?key={}&appid={}&ticket={}",
+            self.url, self.web_api_key, self.app_id, ticket
+        );
+        tools::http::send_get(url.as_str(), None, None)
+    }
 
-    let url = format!("https://partner.steam-api.com/ISteamUserAuth/AuthenticateUserTicket/v1/?key={:?}&appid={}&ticket={:?}",web_api_key,app_id,ticket);
-    let url = url.replace(r#"""#, "");
+    fn check_app_ownership(&self, steam_id: u64, app_id: u64) -> anyhow::Result<String> {
+        let url = format!(
+            "{}/ISteamUser/CheckAppOwnership/v2/?key={}&appid={}&steamid={}",
+            self.url, self.web_api_key, app_id, steam_id
+        );
+        tools::http::send_get(url.as_str(), None, None)
+    }
 
-    // let res = isahc::get(url);
-    // if let Err(e) = res {
-    //     anyhow::bail!("{:?}", e)
-    // }
-    // let mut res = res.unwrap();
-    let res = String::new();
-    // let res = res.text().unwrap();
+    fn dlc_app_ids(&self) -> &[u64] {
+        self.dlc_app_ids.as_slice()
+    }
+}
+
+pub fn auth_account(ticket: &str) -> anyhow::Result<u32> {
+    let steam_id = auth_user_ticket(ticket)?;
+    let user_id = query_user_id_from_redis(steam_id.to_string().as_str())?;
+    //dlc权益以steam为准,异步同步给游戏服,不卡登陆
+    std::thread::spawn(move || sync_dlc_entitlements(user_id, steam_id));
+    Ok(user_id)
+}
+
+///把steam上dlc的拥有情况同步到游戏服的权益数据
+pub fn sync_dlc_entitlements(user_id: u32, steam_id: u64) {
+    let game_http = CONF_MAP.get_str("game_http", "");
+    if game_http.is_empty() {
+        log::warn!("game_http is not find!");
+        return;
+    }
+    //游戏服的权益接口要带entitlement_token
+    let token = CONF_MAP.get_str("entitlement_token", "");
+    let url = format!(
+        "{}/entitlement?token={}",
+        game_http.trim_end_matches('/'),
+        token
+    );
+    for app_id in STEAM_API.dlc_app_ids() {
+        //查询失败的不同步,免得把玩家的权益收回了
+        let owns = match owns_app(steam_id, *app_id) {
+            Ok(owns) => owns,
+            Err(e) => {
+                log::warn!("{:?}", e);
+                continue;
+            }
+        };
+        let state = if owns { "Active" } else { "Revoked" };
+        let json = serde_json::json!({
+            "user_id": user_id,
+            "product_id": format!("steam_{}", app_id),
+            "purchase_token": format!("steam_{}", steam_id),
+            "order_id": "",
+            "is_subscription": false,
+            "state": state,
+            "expiry_time": 0,
+            "event_time": chrono::Local::now().timestamp_millis(),
+        });
+        let res = tools::http::send_post(url.as_str(), Some(json));
+        if let Err(e) = res {
+            log::error!("{:?}", e);
+        }
+    }
+}
+
+pub fn auth_user_ticket(ticket: &str) -> anyhow::Result<u64> {
+    let res = STEAM_API.authenticate_user_ticket(ticket)?;
 
     let json = serde_json::Value::from_str(res.as_str());
 
@@ -111,25 +255,24 @@ pub fn auth_user_ticket(ticket: &str) -> anyhow::Result<u64> {
     let steam_id = steam_id.unwrap();
 
     //检测是否拥有app
-    let res = check_app_owner_ship(steam_id, web_api_key, app_id);
+    let res = check_app_owner_ship(steam_id, STEAM_API.app_id());
     if let Err(e) = res {
         anyhow::bail!("{:?}", e);
     }
     Ok(steam_id)
 }
 
-pub fn check_app_owner_ship(steam_id: u64, web_api_key: &str, app_id: u64) -> anyhow::Result<()> {
-    let url = format!("https://partner.steam-api.com/ISteamUser/CheckAppOwnership/v2/?key={:?}&appid={}&steamid={:?}",web_api_key,app_id,steam_id);
-    let url = url.replace(r#"""#, "");
-
-    // let res = isahc::get(url);
-    // if let Err(e) = res {
-    //     anyhow::bail!("{:?}", e)
-    // }
-    // let mut res = res.unwrap();
+pub fn check_app_owner_ship(steam_id: u64, app_id: u64) -> anyhow::Result<()> {
+    let ownsapp = owns_app(steam_id, app_id)?;
+    if !ownsapp {
+        anyhow::bail!("auth fail!ownsapp is false!steam_id:{:?}", steam_id)
+    }
+    Ok(())
+}
 
-    // let res = res.text().unwrap();
-    let res = String::new();
+///查询玩家是否拥有某个app
+pub fn owns_app(steam_id: u64, app_id: u64) -> anyhow::Result<bool> {
+    let res = STEAM_API.check_app_ownership(steam_id, app_id)?;
 
     let json = serde_json::Value::from_str(res.as_str());
 
@@ -173,9 +316,50 @@ pub fn check_app_owner_ship(steam_id: u64, web_api_key: &str, app_id: u64) -> an
         anyhow::bail!("auth fail!ownsapp is None!steam_id:{:?}", steam_id)
     }
 
-    let ownsapp = ownsapp.unwrap();
-    if !ownsapp {
-        anyhow::bail!("auth fail!ownsapp is false!steam_id:{:?}", steam_id)
+    Ok(ownsapp.unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    ///测试用的mock服务器,每个进程只启动一次
+    fn mock_api() -> HttpSteamApi {
+        static START: std::sync::Once = std::sync::Once::new();
+        START.call_once(|| {
+            tools::mock_api::start(18181, "../tools/examples/mock_api/mock.json").unwrap();
+        });
+        HttpSteamApi::new("http://127.0.0.1:18181/", "mock_key", 480, vec![])
+    }
+
+    #[test]
+    fn test_authenticate_user_ticket() {
+        let api = mock_api();
+        let res = api.authenticate_user_ticket("mock_ticket").unwrap();
+        let json = serde_json::Value::from_str(res.as_str()).unwrap();
+        assert_eq!(json["response"]["params"]["result"], "OK");
+        assert_eq!(json["response"]["params"]["steamid"], "76561197960287930");
+
+        //脚本里面配置了bad_ticket返回错误
+        let res = api.authenticate_user_ticket("bad_ticket").unwrap();
+        let json = serde_json::Value::from_str(res.as_str()).unwrap();
+        assert!(json["response"]["params"].is_null());
+        assert_eq!(json["response"]["error"]["errorcode"], 101);
+
+        //没有key直接失败,不发请求
+        let api = HttpSteamApi::new("http://127.0.0.1:18181", "", 480, vec![]);
+        assert!(api.authenticate_user_ticket("mock_ticket").is_err());
+    }
+
+    #[test]
+    fn test_check_app_ownership() {
+        let api = mock_api();
+        let res = api.check_app_ownership(76561197960287930, 480).unwrap();
+        let json = serde_json::Value::from_str(res.as_str()).unwrap();
+        assert_eq!(json["appownership"]["ownsapp"], true);
+
+        let res = api.check_app_ownership(76561197960287931, 480).unwrap();
+        let json = serde_json::Value::from_str(res.as_str()).unwrap();
+        assert_eq!(json["appownership"]["ownsapp"], false);
     }
-    Ok(())
 }
@@ -18,6 +18,7 @@ use crate::net::tcp_server;
 use std::env;
 use std::time::Duration;
 use tools::http::HttpServerHandler;
+use tools::metrics::MetricsHttpHandler;
 use tools::redis_pool::RedisPoolTool;
 use tools::tcp::ClientHandler;
 use tools::thread_pool::MyThreadPool;
@@ -79,6 +80,12 @@ fn main() {
 }
 
 fn init_log() {
+    //配置了log_format就用tracing输出,日志会带上trace_id/user_id/room_id/battle_id
+    let log_format = CONF_MAP.get_str("log_format", "");
+    if !log_format.is_empty() {
+        tools::my_log::init_trace_log(log_format == "json");
+        return;
+    }
     let info_log = &CONF_MAP.get_str("info_log_path", "");
     let error_log = &CONF_MAP.get_str("error_log_path", "");
     tools::my_log::init_log(info_log, error_log);
@@ -88,8 +95,12 @@ fn init_log() {
 fn init_http_server(cm: Arc<Mutex<ChannelMgr>>) {
     std::thread::sleep(Duration::from_millis(10));
     let http_port = CONF_MAP.get_usize("http_port", 0);
+    let metrics_cm = cm.clone();
     tools::http::Builder::new()
         .route(Box::new(KickPlayerHttpHandler::new(cm)))
+        .route(Box::new(MetricsHttpHandler::new(move || {
+            async_std::task::block_on(metrics_cm.lock()).collect_metrics();
+        })))
         .bind(http_port as u16);
 }
 
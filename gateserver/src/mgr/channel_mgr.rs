@@ -5,7 +5,8 @@ use crossbeam::channel::Sender;
 use log::warn;
 use log::{error, info};
 use std::collections::HashMap;
-use tools::cmd_code::RoomCode;
+use tools::cmd_code::{GateCode, RoomCode};
+use tools::metrics;
 use tools::net_message_io::NetHandler;
 use tools::util::packet::Packet;
 
@@ -40,6 +41,18 @@ impl ChannelMgr {
         cm
     }
 
+    ///刷新监控指标
+    pub fn collect_metrics(&self) {
+        metrics::set_gauge("online_users", self.user_channel.len() as i64);
+        metrics::set_gauge("temp_channels", self.temp_channels.len() as i64);
+        if let Some(sender) = self.game_client_channel.as_ref() {
+            metrics::set_gauge("game_channel_backlog", sender.len() as i64);
+        }
+        if let Some(sender) = self.game_center_client_channel.as_ref() {
+            metrics::set_gauge("game_center_channel_backlog", sender.len() as i64);
+        }
+    }
+
     pub fn set_game_client_channel(&mut self, ts: Sender<Vec<u8>>) {
         self.game_client_channel = Some(ts);
     }
@@ -88,6 +101,25 @@ impl ChannelMgr {
         async_std::task::spawn(notice_user_center(user_id, UserCenterNoticeType::OffLine));
     }
 
+    ///断线重连之后,把在线玩家同步给游戏中心,让游戏中心重新绑定玩家和gate,
+    ///走重连钩子的sender,排在断线期间缓存的消息前面
+    pub fn sync_online_users(&mut self, sender: &Sender<Vec<u8>>) {
+        let cmd = GateCode::SyncOnlineUsers.into_u32();
+        let user_ids: Vec<u32> = self.user_channel.keys().copied().collect();
+        for user_id in user_ids.iter() {
+            let mut packet = Packet::default();
+            packet.set_user_id(*user_id);
+            packet.set_len(14_u32);
+            packet.set_is_client(false);
+            packet.set_is_broad(false);
+            packet.set_cmd(cmd);
+            if let Err(e) = sender.send(packet.build_server_bytes()) {
+                error!("{:?}", e);
+            }
+        }
+        info!("同步在线玩家给游戏中心!count:{}", user_ids.len());
+    }
+
     ///写到游戏服
     pub fn write_to_game(&mut self, packet: Packet) {
         if self.game_client_channel.is_none() {
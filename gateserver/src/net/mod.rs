@@ -13,7 +13,7 @@ use crate::mgr::channel_mgr::ChannelMgr;
 
 use tools::util::packet::Packet;
 
-use tools::cmd_code::GameCode;
+use tools::cmd_code::{BattleCode, CenterCode, GameCode, RoomCode};
 use tools::protos::protocol::{C_USER_LOGIN, S_USER_LOGIN};
 
 use async_std::sync::{Mutex, MutexGuard};
@@ -22,6 +22,67 @@ use std::str::FromStr;
 
 type Lock = Arc<Mutex<ChannelMgr>>;
 
+///客户端能发的命令号段,不在里面的直接丢掉
+const CLIENT_CMD_RANGES: &[(u32, u32)] = &[
+    (GameCode::Min as u32, GameCode::Max as u32),
+    (RoomCode::Min as u32, RoomCode::Max as u32),
+    (BattleCode::Min as u32, BattleCode::Max as u32),
+];
+
+///中心服的命令客户端只能发查询,报名要走游戏服带上段位
+const CLIENT_CENTER_CMDS: &[u32] = &[
+    CenterCode::TournamentList as u32,
+    CenterCode::TournamentBracket as u32,
+];
+
+///客户端号段里面只能由服务器之间发送的命令号
+const SERVER_ONLY_CMDS: &[u32] = &[
+    GameCode::SendMail as u32,
+    GameCode::WorldBossPush as u32,
+    //锦标赛进房间只能由中心服发起
+    GameCode::TournamentMatch as u32,
+    RoomCode::TournamentJoin as u32,
+    //结算只能由战斗服发
+    GameCode::Summary as u32,
+    //聊天要先经过游戏服的禁言,限流和屏蔽字校验
+    RoomCode::Chat as u32,
+    //改名要先经过游戏服的格式,屏蔽字和唯一性校验
+    RoomCode::ModifyNickName as u32,
+    //在线状态只能由游戏服同步
+    GameCode::SyncPresence as u32,
+    //gm命令只能由游戏服校验权限后转发
+    RoomCode::GmCommand as u32,
+    BattleCode::GmCommand as u32,
+    //惩罚,排行榜和赛季数据只能由房间服,战斗服和排行服同步
+    GameCode::SyncPunish as u32,
+    GameCode::SyncRank as u32,
+    GameCode::SyncRankNickName as u32,
+    GameCode::UpdateSeasonPush as u32,
+    GameCode::UpdateWorldBossPush as u32,
+    RoomCode::Summary as u32,
+    RoomCode::UpdateSeasonPush as u32,
+    RoomCode::UpdateWorldBossPush as u32,
+    //下线由网关和游戏服自己通知
+    GameCode::UnloadUser as u32,
+    RoomCode::OffLine as u32,
+    //开战和离开战斗只能由房间服发
+    BattleCode::Start as u32,
+    BattleCode::UpdateSeasonPush as u32,
+    BattleCode::OffLine as u32,
+    BattleCode::LeaveRoom as u32,
+];
+
+///判断是否是客户端能发送的命令
+pub fn is_client_cmd(cmd: u32) -> bool {
+    if SERVER_ONLY_CMDS.contains(&cmd) {
+        return false;
+    }
+    CLIENT_CENTER_CMDS.contains(&cmd)
+        || CLIENT_CMD_RANGES
+            .iter()
+            .any(|&(min, max)| cmd >= min && cmd <= max)
+}
+
 ///从redis查找user_id
 pub fn query_pid_from_redis(user_id: u32) -> anyhow::Result<String> {
     let user_id_str = user_id.to_string();
@@ -114,3 +175,33 @@ fn check_mem_online(user_id: &u32, write: &mut MutexGuard<ChannelMgr>) -> bool {
     }
     res
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tools::cmd_code::{ClientCode, GateCode, RankCode, ServerCommonCode};
+
+    #[test]
+    fn client_cmd() {
+        assert!(is_client_cmd(GameCode::Login.into_u32()));
+        assert!(is_client_cmd(RoomCode::CreateRoom.into_u32()));
+        assert!(is_client_cmd(BattleCode::Action.into_u32()));
+        assert!(is_client_cmd(CenterCode::TournamentList.into_u32()));
+        assert!(is_client_cmd(CenterCode::TournamentBracket.into_u32()));
+        //号段里面服务器专用的
+        assert!(!is_client_cmd(GameCode::SendMail.into_u32()));
+        assert!(!is_client_cmd(RoomCode::Chat.into_u32()));
+        assert!(!is_client_cmd(BattleCode::GmCommand.into_u32()));
+        assert!(!is_client_cmd(GameCode::SyncPunish.into_u32()));
+        assert!(!is_client_cmd(BattleCode::Start.into_u32()));
+        //客户端号段以外的都不行
+        assert!(!is_client_cmd(CenterCode::TournamentSignUp.into_u32()));
+        assert!(!is_client_cmd(CenterCode::TournamentResult.into_u32()));
+        assert!(!is_client_cmd(CenterCode::Max.into_u32()));
+        assert!(!is_client_cmd(RankCode::Min.into_u32() + 1));
+        assert!(!is_client_cmd(GateCode::KickPlayer.into_u32()));
+        assert!(!is_client_cmd(ClientCode::HeartBeat.into_u32()));
+        assert!(!is_client_cmd(ServerCommonCode::ReloadTemps.into_u32()));
+        assert!(!is_client_cmd(0));
+    }
+}
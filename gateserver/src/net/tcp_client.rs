@@ -6,7 +6,10 @@ use async_std::task::block_on;
 use async_trait::async_trait;
 use crossbeam::channel::Sender;
 use log::error;
-use tools::cmd_code::{ClientCode, GateCode, RankCode, RoomCode, ServerCommonCode};
+use tools::cmd_code::{
+    BattleCode, CenterCode, ClientCode, GateCode, RankCode, RoomCode, ServerCommonCode,
+};
+use tools::trace;
 
 pub enum TcpClientType {
     GameServer,
@@ -45,19 +48,27 @@ impl ClientHandler for TcpClientHandler {
     }
 
     async fn on_close(&mut self) {
-        let address: Option<String>;
+        //on_read里面会自动重连,断线期间发的消息会先缓存起来
         match self.client_type {
             TcpClientType::GameServer => {
-                address = Some(CONF_MAP.get_str("game_port", ""));
+                warn!("disconnect with Game-Server!waiting for reconnect!");
             }
             TcpClientType::GameCenter => {
-                address = Some(CONF_MAP.get_str("game_center_port", ""));
+                warn!("disconnect with Game-Center!waiting for reconnect!");
             }
-            _ => {
-                address = None;
+        }
+    }
+
+    async fn on_reconnect(&mut self, ts: Sender<Vec<u8>>) {
+        match self.client_type {
+            TcpClientType::GameServer => {
+                info!("reconnect Game-Server success!");
+            }
+            TcpClientType::GameCenter => {
+                //游戏中心可能重启过,重新注册在线玩家
+                block_on(self.cp.lock()).sync_online_users(&ts);
             }
         }
-        self.on_read(address.unwrap()).await;
     }
 
     async fn on_message(&mut self, mess: Vec<u8>) {
@@ -74,6 +85,7 @@ impl ClientHandler for TcpClientHandler {
 
 fn handler_mess_s(cp: Lock, packet_array: VecDeque<Packet>) {
     for mut packet in packet_array {
+        let _trace = trace::enter(&packet);
         let mut lock = async_std::task::block_on(cp.lock());
         let user_id = packet.get_user_id();
         let cmd = packet.get_cmd();
@@ -129,8 +141,10 @@ fn arrange_packet(cp: async_std::sync::MutexGuard<ChannelMgr>, packet: Packet) {
         lock.write_to_game(packet);
     } else if (cmd >= RoomCode::Min.into_u32() && cmd <= RoomCode::Max.into_u32())
         || (cmd >= RankCode::Min.into_u32() && cmd <= RankCode::Max.into_u32())
+        || (cmd >= BattleCode::Min.into_u32() && cmd <= BattleCode::Max.into_u32())
+        || (cmd >= CenterCode::Min.into_u32() && cmd <= CenterCode::Max.into_u32())
     {
-        //转发到房间服
+        //转发到游戏中心,再由游戏中心转发到房间服,排行榜服和战斗服
         lock.write_to_game_center(packet);
     } else if cmd == GateCode::StopServer.into_u32() {
         lock.stop_server();
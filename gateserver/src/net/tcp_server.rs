@@ -6,10 +6,11 @@ use async_std::sync::{Mutex, MutexGuard};
 use async_std::task::block_on;
 use async_trait::async_trait;
 use chrono::Local;
-use tools::cmd_code::{BattleCode, ClientCode, RoomCode};
+use tools::cmd_code::{BattleCode, CenterCode, ClientCode, RoomCode};
 use tools::net_message_io::NetHandler;
 use tools::net_message_io::TransportWay;
 use tools::protos::protocol::HEART_BEAT;
+use tools::trace::{self, Instrument};
 
 #[derive(Clone)]
 struct TcpServerHandler {
@@ -55,10 +56,15 @@ impl tools::net_message_io::MessageHandler for TcpServerHandler {
         let packet_array = packet_array.unwrap();
 
         let mut res;
-        for packet in packet_array {
+        for mut packet in packet_array {
             let cmd = packet.get_cmd();
-            info!("GateServer receive data of client!cmd:{}", cmd);
-            res = self.handle_binary(packet).await;
+            //每个客户端包生成一个trace id,转发到其他服的时候一直带着
+            packet.set_trace_id(trace::new_trace_id());
+            let span = trace::packet_span(&packet);
+            span.in_scope(|| info!("GateServer receive data of client!cmd:{}", cmd));
+            let time = std::time::Instant::now();
+            res = self.handle_binary(packet).instrument(span).await;
+            tools::metrics::record_invok(cmd, time.elapsed());
             if !res {
                 self.shut_down();
                 return;
@@ -153,6 +159,7 @@ impl TcpServerHandler {
             u_id = *user_id.unwrap();
         }
         packet.set_user_id(u_id);
+        trace::record_user_id(u_id);
 
         if packet.get_cmd() == ClientCode::HeartBeat.into_u32() {
             let mut hb = HEART_BEAT::new();
@@ -175,6 +182,8 @@ impl TcpServerHandler {
                 packet.get_user_id(),
                 packet.get_cmd(),
             );
+            //心跳网关自己回,不用转发
+            return true;
         }
         std::mem::drop(lock);
         //转发函数
@@ -184,6 +193,15 @@ impl TcpServerHandler {
 
     ///数据包转发
     fn arrange_packet(&mut self, packet: Packet) {
+        //不是客户端能发的命令直接丢掉
+        if !super::is_client_cmd(packet.get_cmd()) {
+            warn!(
+                "client can not send this cmd!user_id:{},cmd:{}",
+                packet.get_user_id(),
+                packet.get_cmd()
+            );
+            return;
+        }
         let mut lock = block_on(self.cm.lock());
         //转发到游戏服
         if packet.get_cmd() >= GameCode::Min as u32 && packet.get_cmd() <= GameCode::Max as u32 {
@@ -199,6 +217,12 @@ impl TcpServerHandler {
             lock.write_to_game_center(packet);
             return;
         }
+        //锦标赛查询直接发到中心服,报名要走游戏服带上段位
+        if packet.get_cmd() == CenterCode::TournamentList.into_u32()
+            || packet.get_cmd() == CenterCode::TournamentBracket.into_u32()
+        {
+            lock.write_to_game_center(packet);
+        }
     }
 }
 
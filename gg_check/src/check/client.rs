@@ -0,0 +1,273 @@
+use crate::check::login::{GoogleUserTokenInfo, TokenResponse};
+use crate::check::pay::{
+    GooglePurchaseResponse, GoogleSubscriptionResponse, VoidedPurchasesResponse,
+};
+use crate::check::GoogleApiToken;
+use anyhow::{anyhow, Result};
+use once_cell::sync::Lazy;
+use reqwest::header;
+use std::time::Duration;
+
+// 谷歌api客户端,地址都从server.config里面读,本地测试的时候可以指向mock服务器
+pub static GOOGLE_API: Lazy<Box<dyn GoogleApi>> = Lazy::new(|| {
+    let api = HttpGoogleApi::new(
+        crate::GOOGLE_OAUTH_URL.as_str(),
+        crate::TOKEN_URL.as_str(),
+        crate::ANDROID_PUBLISHER_URL.as_str(),
+    );
+    Box::new(api)
+});
+
+/// 所有对谷歌的外部调用
+pub trait GoogleApi: Send + Sync {
+    /// 用客户端授权码换取令牌
+    fn exchange_code(&self, params: &[(&str, &str)]) -> Result<TokenResponse>;
+
+    /// 查询id_token信息
+    fn token_info(&self, id_token: &str) -> Result<GoogleUserTokenInfo>;
+
+    /// 用服务账号jwt换取api令牌
+    fn service_account_token(&self, token_uri: &str, jwt: &str) -> Result<GoogleApiToken>;
+
+    /// 查询购买信息
+    fn get_purchase(
+        &self,
+        access_token: &str,
+        package_name: &str,
+        product_id: &str,
+        purchase_token: &str,
+        is_subscription: bool,
+    ) -> Result<GooglePurchaseResponse>;
+
+    /// 查询订阅购买信息
+    fn get_subscription_purchase(
+        &self,
+        access_token: &str,
+        package_name: &str,
+        subscription_id: &str,
+        purchase_token: &str,
+    ) -> Result<GoogleSubscriptionResponse>;
+
+    /// 确认购买
+    fn acknowledge(
+        &self,
+        access_token: &str,
+        package_name: &str,
+        product_id: &str,
+        purchase_token: &str,
+        is_subscription: bool,
+    ) -> Result<()>;
+
+    /// 查询作废购买列表(包括订阅),start_time之后作废的,毫秒
+    fn get_voided_purchases(
+        &self,
+        access_token: &str,
+        package_name: &str,
+        start_time: i64,
+        page_token: Option<&str>,
+    ) -> Result<VoidedPurchasesResponse>;
+}
+
+/// 基于http的谷歌api客户端
+pub struct HttpGoogleApi {
+    oauth_url: String,     //oauth2地址(tokeninfo)
+    token_url: String,     //授权码换令牌地址
+    publisher_url: String, //androidpublisher地址
+    client: reqwest::blocking::Client,
+}
+
+impl HttpGoogleApi {
+    pub fn new(oauth_url: &str, token_url: &str, publisher_url: &str) -> Self {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(5))
+            .build()
+            .unwrap();
+        HttpGoogleApi {
+            oauth_url: oauth_url.trim_end_matches('/').to_owned(),
+            token_url: token_url.to_owned(),
+            publisher_url: publisher_url.trim_end_matches('/').to_owned(),
+            client,
+        }
+    }
+
+    /// 拼接androidpublisher购买查询地址
+    fn purchase_url(
+        &self,
+        package_name: &str,
+        product_id: &str,
+        purchase_token: &str,
+        is_subscription: bool,
+    ) -> String {
+        let purchase_type = if is_subscription {
+            "subscriptions"
+        } else {
+            "products"
+        };
+        format!(
+            "{}/androidpublisher/v3/applications/{}/purchases/{}/{}/tokens/{}",
+            self.publisher_url,
+            percent_encoding::utf8_percent_encode(package_name, percent_encoding::NON_ALPHANUMERIC),
+            purchase_type,
+            percent_encoding::utf8_percent_encode(product_id, percent_encoding::NON_ALPHANUMERIC),
+            purchase_token
+        )
+    }
+}
+
+impl GoogleApi for HttpGoogleApi {
+    fn exchange_code(&self, params: &[(&str, &str)]) -> Result<TokenResponse> {
+        let response = self
+            .client
+            .post(self.token_url.as_str())
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .form(params)
+            .send()?;
+
+        if !response.status().is_success() {
+            let error_text = response.text()?;
+            return Err(anyhow!("Error from Google API: {}", error_text));
+        }
+        let token_response: TokenResponse = response.json()?;
+        Ok(token_response)
+    }
+
+    fn token_info(&self, id_token: &str) -> Result<GoogleUserTokenInfo> {
+        let url = format!("{}/tokeninfo?id_token={}", self.oauth_url, id_token);
+        let response = self.client.get(&url).send()?;
+        if !response.status().is_success() {
+            anyhow::bail!("Google API returned error: {}", response.status());
+        }
+        let token_info: GoogleUserTokenInfo = response.json()?;
+        Ok(token_info)
+    }
+
+    fn service_account_token(&self, token_uri: &str, jwt: &str) -> Result<GoogleApiToken> {
+        let response = self
+            .client
+            .post(token_uri)
+            .form(&[
+                ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+                ("assertion", jwt),
+            ])
+            .send()?;
+
+        if !response.status().is_success() {
+            let error_text = response.text()?;
+            return Err(anyhow!("Token request failed: {}", error_text));
+        }
+        let token_response: GoogleApiToken = response.json()?;
+        Ok(token_response)
+    }
+
+    fn get_purchase(
+        &self,
+        access_token: &str,
+        package_name: &str,
+        product_id: &str,
+        purchase_token: &str,
+        is_subscription: bool,
+    ) -> Result<GooglePurchaseResponse> {
+        let endpoint = self.purchase_url(package_name, product_id, purchase_token, is_subscription);
+        let response = self
+            .client
+            .get(&endpoint)
+            .bearer_auth(access_token)
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json")
+            .send()?;
+
+        if !response.status().is_success() {
+            let error_text = response.text()?;
+            return Err(anyhow!("Verification failed: {}", error_text));
+        }
+        let purchase_response: GooglePurchaseResponse = response.json()?;
+        Ok(purchase_response)
+    }
+
+    fn get_subscription_purchase(
+        &self,
+        access_token: &str,
+        package_name: &str,
+        subscription_id: &str,
+        purchase_token: &str,
+    ) -> Result<GoogleSubscriptionResponse> {
+        let endpoint = self.purchase_url(package_name, subscription_id, purchase_token, true);
+        let response = self
+            .client
+            .get(&endpoint)
+            .bearer_auth(access_token)
+            .header("Accept", "application/json")
+            .send()?;
+
+        if !response.status().is_success() {
+            let error_text = response.text()?;
+            return Err(anyhow!("Query subscription failed: {}", error_text));
+        }
+        let subscription_response: GoogleSubscriptionResponse = response.json()?;
+        Ok(subscription_response)
+    }
+
+    fn acknowledge(
+        &self,
+        access_token: &str,
+        package_name: &str,
+        product_id: &str,
+        purchase_token: &str,
+        is_subscription: bool,
+    ) -> Result<()> {
+        let url = format!(
+            "{}:acknowledge",
+            self.purchase_url(package_name, product_id, purchase_token, is_subscription)
+        );
+        // 构建请求体 (可选developerPayload)
+        let request_body = serde_json::json!({
+            "developerPayload": "your_custom_data_here" // 可选字段
+        });
+        let response = self
+            .client
+            .post(&url)
+            .bearer_auth(access_token)
+            .json(&request_body)
+            .send()?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text()?;
+            anyhow::bail!("acknowledge failed: {}   {}", status, error_text);
+        }
+        Ok(())
+    }
+
+    fn get_voided_purchases(
+        &self,
+        access_token: &str,
+        package_name: &str,
+        start_time: i64,
+        page_token: Option<&str>,
+    ) -> Result<VoidedPurchasesResponse> {
+        let endpoint = format!(
+            "{}/androidpublisher/v3/applications/{}/purchases/voidedpurchases",
+            self.publisher_url,
+            percent_encoding::utf8_percent_encode(package_name, percent_encoding::NON_ALPHANUMERIC),
+        );
+        let start_time = start_time.to_string();
+        //type=1代表订阅也要查出来
+        let mut query = vec![("type", "1"), ("startTime", start_time.as_str())];
+        if let Some(page_token) = page_token {
+            query.push(("token", page_token));
+        }
+        let response = self
+            .client
+            .get(&endpoint)
+            .bearer_auth(access_token)
+            .query(&query)
+            .header("Accept", "application/json")
+            .send()?;
+
+        if !response.status().is_success() {
+            let error_text = response.text()?;
+            return Err(anyhow!("Query voided purchases failed: {}", error_text));
+        }
+        let voided_response: VoidedPurchasesResponse = response.json()?;
+        Ok(voided_response)
+    }
+}
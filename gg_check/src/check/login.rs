@@ -6,6 +6,7 @@ use reqwest::{header, Client};
 use serde::Deserialize;
 use serde::Serialize;
 use tools::json::{JsonValue, JsonValueTrait};
+use crate::check::client::GOOGLE_API;
 use crate::{CLIENT_ID, CLIENT_SECRET, REDIRECT_URI, TOKEN_URL};
 
 // Google API 响应结构
@@ -66,18 +67,7 @@ pub struct TokenResponse {
 pub  fn verify_google_id_token(
     token: &str,
 ) -> anyhow::Result<GoogleUserTokenInfo> {
-    let client = reqwest::blocking::Client::new();
-    let url = format!(
-        "https://oauth2.googleapis.com/tokeninfo?id_token={}",
-        token
-    );
-
-    let response = client.get(&url).send()?;
-    if !response.status().is_success() {
-        anyhow::bail!("Google API returned error: {}", response.status());
-    }
-
-    let token_info: GoogleUserTokenInfo = response.json()?;
+    let token_info = GOOGLE_API.token_info(token)?;
 
     // 验证 token 是否过期（可选）
     let now = chrono::Utc::now().timestamp();
@@ -103,22 +93,11 @@ pub fn exchange_code_for_token(
         ("grant_type", "authorization_code"),
     ];
     info!("params: {:?}", params);
-    let client = reqwest::blocking::Client::builder().timeout(Duration::from_secs(5)).build()?;
-    let response = client
-        .post(TOKEN_URL.as_str())
-        .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
-        .form(&params)
-        .send()?;
-
-    if !response.status().is_success() {
-        let error_text = response.text()?;
-        return Err(format!("Error from Google API: token:{},res_mes:{}", authorization_code,error_text).into());
+    let res = GOOGLE_API.exchange_code(&params);
+    if let Err(e) = res {
+        return Err(format!("Error from Google API: token:{},res_mes:{:?}", authorization_code, e).into());
     }
-
-    let json_res = response.json().unwrap();
-
-    let token_response: TokenResponse = json_res;
-    Ok(token_response)
+    Ok(res.unwrap())
 }
 
 async fn refresh_token(refresh_token: &str) -> Result<TokenResponse, Box<dyn Error>> {
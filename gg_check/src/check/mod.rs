@@ -1,11 +1,14 @@
+pub mod client;
+pub mod notify;
 pub mod pay;
 pub mod login;
 
 use serde::{Deserialize, Serialize};
 use std::fs;
-use anyhow::{anyhow, Context};
+use anyhow::Context;
 use chrono::{Duration, Utc};
 use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use crate::check::client::GOOGLE_API;
 
 
 
@@ -65,22 +68,7 @@ fn load_service_account(path: &str) -> anyhow::Result<ServiceAccountCredentials>
 pub fn get_access_token(account: &ServiceAccountCredentials) -> anyhow::Result<GoogleApiToken> {
     let jwt = generate_jwt(account)?;
 
-    let client = reqwest::blocking::Client::new();
-    let response = client
-        .post(&account.token_uri)
-        .form(&[
-            ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
-            ("assertion", &jwt),
-        ])
-        .send()?;
-
-
-    if !response.status().is_success() {
-        let error_text = response.text()?;
-        return Err(anyhow!("Token request failed: {}", error_text));
-    }
-
-    let token_response: GoogleApiToken = response.json()?;
+    let token_response = GOOGLE_API.service_account_token(&account.token_uri, &jwt)?;
     Ok(token_response)
 }
 
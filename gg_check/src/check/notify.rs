@@ -0,0 +1,293 @@
+use crate::check::pay::{acknowledge_purchase, get_google_subscription, is_purchase_voided};
+use crate::entity::entitlement::{
+    get_entitlement, notify_game, update_entitlement, Entitlement, EntitlementState,
+};
+use anyhow::{anyhow, Context, Result};
+use base64::Engine;
+use log::{info, warn};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+// 订阅通知类型
+pub const SUBSCRIPTION_RECOVERED: i32 = 1; //从账号保留状态恢复
+pub const SUBSCRIPTION_RENEWED: i32 = 2; //续订成功
+pub const SUBSCRIPTION_CANCELED: i32 = 3; //取消续订
+pub const SUBSCRIPTION_PURCHASED: i32 = 4; //新购买
+pub const SUBSCRIPTION_ON_HOLD: i32 = 5; //进入账号保留状态
+pub const SUBSCRIPTION_IN_GRACE_PERIOD: i32 = 6; //进入宽限期
+pub const SUBSCRIPTION_RESTARTED: i32 = 7; //用户恢复了订阅
+pub const SUBSCRIPTION_PAUSED: i32 = 10; //订阅已暂停
+pub const SUBSCRIPTION_REVOKED: i32 = 12; //到期前被撤销
+pub const SUBSCRIPTION_EXPIRED: i32 = 13; //订阅已过期
+
+// 作废购买的商品类型
+pub const VOIDED_PRODUCT_TYPE_SUBSCRIPTION: i32 = 1;
+
+// 查询作废购买列表时往前多查的时间(毫秒),作废时间会比通知时间稍早
+const VOIDED_QUERY_WINDOW: i64 = 24 * 3600 * 1000;
+
+// Pub/Sub 推送的请求体
+#[derive(Debug, Deserialize)]
+pub struct PubSubPush {
+    pub message: PubSubMessage,
+    pub subscription: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PubSubMessage {
+    pub data: String, //base64编码的DeveloperNotification
+    #[serde(rename = "messageId")]
+    pub message_id: Option<String>,
+    pub attributes: Option<HashMap<String, String>>,
+}
+
+// 实时开发者通知
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct DeveloperNotification {
+    pub version: String,
+    #[serde(rename = "packageName")]
+    pub package_name: String,
+    #[serde(rename = "eventTimeMillis")]
+    pub event_time_millis: String,
+    #[serde(rename = "subscriptionNotification")]
+    pub subscription_notification: Option<SubscriptionNotification>,
+    #[serde(rename = "oneTimeProductNotification")]
+    pub one_time_product_notification: Option<OneTimeProductNotification>,
+    #[serde(rename = "voidedPurchaseNotification")]
+    pub voided_purchase_notification: Option<VoidedPurchaseNotification>,
+    #[serde(rename = "testNotification")]
+    pub test_notification: Option<TestNotification>,
+}
+
+impl DeveloperNotification {
+    ///通知时间(毫秒)
+    pub fn get_event_time(&self) -> i64 {
+        i64::from_str(self.event_time_millis.as_str()).unwrap_or(0)
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct SubscriptionNotification {
+    pub version: String,
+    #[serde(rename = "notificationType")]
+    pub notification_type: i32,
+    #[serde(rename = "purchaseToken")]
+    pub purchase_token: String,
+    #[serde(rename = "subscriptionId")]
+    pub subscription_id: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct OneTimeProductNotification {
+    pub version: String,
+    #[serde(rename = "notificationType")]
+    pub notification_type: i32,
+    #[serde(rename = "purchaseToken")]
+    pub purchase_token: String,
+    pub sku: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct VoidedPurchaseNotification {
+    #[serde(rename = "purchaseToken")]
+    pub purchase_token: String,
+    #[serde(rename = "orderId")]
+    pub order_id: String,
+    #[serde(rename = "productType")]
+    pub product_type: i32, //1:订阅 2:一次性商品
+    #[serde(rename = "refundType")]
+    pub refund_type: i32, //1:全额退款 2:部分退款
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct TestNotification {
+    pub version: String,
+}
+
+///解析Pub/Sub推送的消息
+pub fn parse_push(body: &[u8]) -> Result<DeveloperNotification> {
+    let push: PubSubPush = serde_json::from_slice(body).context("invalid pub/sub push body")?;
+    let data = base64::engine::general_purpose::STANDARD
+        .decode(push.message.data.as_bytes())
+        .context("invalid pub/sub message data")?;
+    let notification: DeveloperNotification =
+        serde_json::from_slice(data.as_slice()).context("invalid developer notification")?;
+    info!(
+        "receive developer notification!message_id:{:?},notification:{:?}",
+        push.message.message_id, notification
+    );
+    Ok(notification)
+}
+
+///处理实时开发者通知,返回发生变更的权益
+pub fn handle_notification(notification: &DeveloperNotification) -> Result<Option<Entitlement>> {
+    if notification.package_name != crate::PACKAGE_NAME.as_str() {
+        anyhow::bail!(
+            "package_name is not match!package_name:{}",
+            notification.package_name
+        );
+    }
+    let event_time = notification.get_event_time();
+    let entitlement = if let Some(sn) = notification.subscription_notification.as_ref() {
+        handle_subscription(sn, event_time)?
+    } else if let Some(vn) = notification.voided_purchase_notification.as_ref() {
+        handle_voided_purchase(vn, event_time)?
+    } else if let Some(on) = notification.one_time_product_notification.as_ref() {
+        //一次性商品由客户端调用check_pay发货,这里只做记录
+        info!(
+            "one time product notification!type:{},sku:{},purchase_token:{}",
+            on.notification_type, on.sku, on.purchase_token
+        );
+        None
+    } else {
+        if notification.test_notification.is_some() {
+            info!("receive test notification!");
+        }
+        None
+    };
+
+    let entitlement = match entitlement {
+        Some(entitlement) => entitlement,
+        None => return Ok(None),
+    };
+    if !update_entitlement(entitlement.clone()) {
+        return Ok(None);
+    }
+    notify_game(&entitlement)?;
+    Ok(Some(entitlement))
+}
+
+///处理订阅通知
+fn handle_subscription(
+    sn: &SubscriptionNotification,
+    event_time: i64,
+) -> Result<Option<Entitlement>> {
+    let read = crate::SERVER_TOKEN.read().unwrap();
+    let access_token = read.access_token.clone();
+    drop(read);
+
+    let package_name = crate::PACKAGE_NAME.as_str();
+    let subscription = get_google_subscription(
+        access_token.as_str(),
+        package_name,
+        sn.subscription_id.as_str(),
+        sn.purchase_token.as_str(),
+    )?;
+
+    let state = match sn.notification_type {
+        SUBSCRIPTION_RECOVERED
+        | SUBSCRIPTION_RENEWED
+        | SUBSCRIPTION_PURCHASED
+        | SUBSCRIPTION_IN_GRACE_PERIOD
+        | SUBSCRIPTION_RESTARTED => EntitlementState::Active,
+        SUBSCRIPTION_CANCELED => EntitlementState::Canceled,
+        SUBSCRIPTION_ON_HOLD | SUBSCRIPTION_PAUSED => EntitlementState::OnHold,
+        SUBSCRIPTION_REVOKED => EntitlementState::Revoked,
+        SUBSCRIPTION_EXPIRED => EntitlementState::Expired,
+        _ => {
+            info!(
+                "ignore subscription notification!type:{},subscription_id:{}",
+                sn.notification_type, sn.subscription_id
+            );
+            return Ok(None);
+        }
+    };
+
+    //新订阅需要确认,不然3天之后系统自动退款
+    if state == EntitlementState::Active && subscription.acknowledgement_state == Some(0) {
+        acknowledge_purchase(
+            access_token.as_str(),
+            package_name,
+            sn.subscription_id.as_str(),
+            sn.purchase_token.as_str(),
+            true,
+        )?;
+    }
+
+    let user_id = match subscription.obfuscated_external_account_id.as_ref() {
+        Some(account_id) => u32::from_str(account_id.as_str()).ok(),
+        None => None,
+    };
+    //没有玩家id就从本地记录里面找
+    let user_id = match user_id {
+        Some(user_id) => user_id,
+        None => get_entitlement(sn.purchase_token.as_str())
+            .map(|e| e.user_id)
+            .ok_or_else(|| {
+                anyhow!(
+                    "could not find user_id for purchase_token:{}",
+                    sn.purchase_token
+                )
+            })?,
+    };
+
+    let mut entitlement = Entitlement::default();
+    entitlement.user_id = user_id;
+    entitlement.product_id = sn.subscription_id.clone();
+    entitlement.purchase_token = sn.purchase_token.clone();
+    entitlement.order_id = subscription.order_id.clone().unwrap_or_default();
+    entitlement.is_subscription = true;
+    entitlement.state = state;
+    entitlement.expiry_time = subscription.get_expiry_time();
+    entitlement.event_time = event_time;
+
+    //升级/降级之后旧的令牌作废
+    if let Some(linked_token) = subscription.linked_purchase_token.as_ref() {
+        if let Some(mut old) = get_entitlement(linked_token.as_str()) {
+            if old.state.is_valid() {
+                old.state = EntitlementState::Expired;
+                old.event_time = event_time;
+                if update_entitlement(old.clone()) {
+                    notify_game(&old)?;
+                }
+            }
+        }
+    }
+    Ok(Some(entitlement))
+}
+
+///处理作废购买(退款,撤单),先到谷歌的作废购买列表里面核实再收回权益
+fn handle_voided_purchase(
+    vn: &VoidedPurchaseNotification,
+    event_time: i64,
+) -> Result<Option<Entitlement>> {
+    let entitlement = get_entitlement(vn.purchase_token.as_str());
+    if entitlement.is_none() {
+        warn!(
+            "voided purchase could not find entitlement!order_id:{},product_type:{},purchase_token:{}",
+            vn.order_id, vn.product_type, vn.purchase_token
+        );
+        return Ok(None);
+    }
+
+    let read = crate::SERVER_TOKEN.read().unwrap();
+    let access_token = read.access_token.clone();
+    drop(read);
+    let start_time = (event_time - VOIDED_QUERY_WINDOW).max(0);
+    let voided = is_purchase_voided(
+        access_token.as_str(),
+        crate::PACKAGE_NAME.as_str(),
+        vn.purchase_token.as_str(),
+        start_time,
+    )?;
+    //作废列表可能有延迟,返回错误让Pub/Sub重新推送
+    if !voided {
+        anyhow::bail!(
+            "voided purchase is not in voided list!order_id:{},purchase_token:{}",
+            vn.order_id,
+            vn.purchase_token
+        );
+    }
+
+    let mut entitlement = entitlement.unwrap();
+    entitlement.is_subscription = vn.product_type == VOIDED_PRODUCT_TYPE_SUBSCRIPTION;
+    entitlement.state = EntitlementState::Revoked;
+    entitlement.event_time = event_time;
+    Ok(Some(entitlement))
+}
@@ -1,10 +1,11 @@
-use anyhow::{Context, Result, anyhow};
+use anyhow::{Context, Result};
 use chrono::{Duration, Utc};
 use jsonwebtoken::{Algorithm, EncodingKey, Header};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::fs;
 use tools::json::{JsonValue, JsonValueTrait};
+use crate::check::client::GOOGLE_API;
 use crate::check::exchange_token;
 
 // Google Play 验证响应结构
@@ -22,6 +23,70 @@ pub struct GooglePurchaseResponse {
     pub acknowledgement_state: Option<i32>,//0：未确认（Yet to be acknowledged） 1：已确认（Acknowledged）
     #[serde(rename = "kind")]
     pub kind: Option<String>,
+    #[serde(rename = "obfuscatedExternalAccountId")]
+    pub obfuscated_external_account_id: Option<String>,//下单时客户端传的玩家id
+}
+
+// Google Play 订阅查询响应结构
+#[derive(Debug, Default, Deserialize)]
+pub struct GoogleSubscriptionResponse {
+    #[serde(rename = "startTimeMillis")]
+    pub start_time_millis: Option<String>,
+    #[serde(rename = "expiryTimeMillis")]
+    pub expiry_time_millis: Option<String>,//订阅到期时间
+    #[serde(rename = "autoRenewing")]
+    pub auto_renewing: Option<bool>,
+    #[serde(rename = "paymentState")]
+    pub payment_state: Option<i32>,//0：待付款 1：已付款 2：免费试用 3：待延期升级/降级
+    #[serde(rename = "cancelReason")]
+    pub cancel_reason: Option<i32>,//0：用户取消 1：系统取消 2：被新订阅替换 3：开发者取消
+    #[serde(rename = "orderId")]
+    pub order_id: Option<String>,
+    #[serde(rename = "acknowledgementState")]
+    pub acknowledgement_state: Option<i32>,//0：未确认 1：已确认
+    #[serde(rename = "obfuscatedExternalAccountId")]
+    pub obfuscated_external_account_id: Option<String>,//下单时客户端传的玩家id
+    #[serde(rename = "linkedPurchaseToken")]
+    pub linked_purchase_token: Option<String>,//升级/降级之前的购买令牌
+}
+
+// 作废购买记录
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct VoidedPurchase {
+    #[serde(rename = "purchaseToken")]
+    pub purchase_token: String,
+    #[serde(rename = "orderId")]
+    pub order_id: String,
+    #[serde(rename = "voidedTimeMillis")]
+    pub voided_time_millis: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct TokenPagination {
+    #[serde(rename = "nextPageToken")]
+    pub next_page_token: Option<String>,
+}
+
+// 作废购买列表查询响应结构
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct VoidedPurchasesResponse {
+    #[serde(rename = "voidedPurchases")]
+    pub voided_purchases: Vec<VoidedPurchase>,
+    #[serde(rename = "tokenPagination")]
+    pub token_pagination: Option<TokenPagination>,
+}
+
+impl GoogleSubscriptionResponse {
+    ///订阅到期时间,毫秒
+    pub fn get_expiry_time(&self) -> i64 {
+        match self.expiry_time_millis.as_ref() {
+            Some(time) => time.parse::<i64>().unwrap_or(0),
+            None => 0,
+        }
+    }
 }
 
 impl GooglePurchaseResponse {
@@ -67,34 +132,69 @@ pub fn verify_google_purchase(
     purchase_token: &str,
     is_subscription: bool,
 ) -> Result<GooglePurchaseResponse> {
-    let endpoint = if is_subscription {
-        format!(
-            "https://androidpublisher.googleapis.com/androidpublisher/v3/applications/{}/purchases/subscriptions/{}/tokens/{}",
-            package_name, product_id, purchase_token
-        )
-    } else {
-        format!(
-            "https://androidpublisher.googleapis.com/androidpublisher/v3/applications/{}/purchases/products/{}/tokens/{}",
-            percent_encoding::utf8_percent_encode(package_name, percent_encoding::NON_ALPHANUMERIC),
-            percent_encoding::utf8_percent_encode(product_id, percent_encoding::NON_ALPHANUMERIC),
-            purchase_token
-        )
-    };
-    let client = reqwest::blocking::Client::builder().timeout(std::time::Duration::from_secs(5)).build()?;
-    let response = client
-        .get(&endpoint)
-        .bearer_auth(&access_token)
-        .header("Content-Type", "application/json")
-        .header("Accept", "application/json")
-        .send()?;
-
-    if !response.status().is_success() {
-        let error_text = response.text()?;
-        return Err(anyhow!("Verification failed: {}", error_text));
+    GOOGLE_API.get_purchase(
+        access_token,
+        package_name,
+        product_id,
+        purchase_token,
+        is_subscription,
+    )
+}
+
+/// 查询订阅购买信息
+pub fn get_google_subscription(
+    access_token: &str,
+    package_name: &str,
+    subscription_id: &str,
+    purchase_token: &str,
+) -> Result<GoogleSubscriptionResponse> {
+    GOOGLE_API.get_subscription_purchase(access_token, package_name, subscription_id, purchase_token)
+}
+
+/// 到谷歌的作废购买列表里面查询令牌是否真的被作废了(退款,撤单)
+pub fn is_purchase_voided(
+    access_token: &str,
+    package_name: &str,
+    purchase_token: &str,
+    start_time: i64,
+) -> Result<bool> {
+    let mut page_token: Option<String> = None;
+    loop {
+        let res = GOOGLE_API.get_voided_purchases(
+            access_token,
+            package_name,
+            start_time,
+            page_token.as_deref(),
+        )?;
+        if res
+            .voided_purchases
+            .iter()
+            .any(|vp| vp.purchase_token == purchase_token)
+        {
+            return Ok(true);
+        }
+        page_token = res.token_pagination.and_then(|tp| tp.next_page_token);
+        if page_token.is_none() {
+            return Ok(false);
+        }
     }
+}
 
-    let purchase_response: GooglePurchaseResponse = response.json()?;
-    Ok(purchase_response)
+/// 向谷歌发送购买确认,不然3天之后系统自动退款
+pub fn acknowledge_purchase(
+    access_token: &str,
+    package_name: &str,
+    product_id: &str,
+    purchase_token: &str,
+    is_subscription: bool,
+) -> Result<()> {
+    GOOGLE_API.acknowledge(
+        access_token,
+        package_name,
+        product_id,
+        purchase_token,
+        is_subscription,
+    )
 }
 
 /// 验证购买结果
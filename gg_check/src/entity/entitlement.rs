@@ -0,0 +1,142 @@
+use log::{error, info, warn};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::Duration;
+
+// 权益数据,key:purchase_token
+static ENTITLEMENTS: Lazy<RwLock<HashMap<String, Entitlement>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+// 权益状态
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum EntitlementState {
+    Active,   //生效中
+    Canceled, //已取消续订,到期之前依然有效
+    OnHold,   //账号保留/暂停,暂时收回权益
+    Expired,  //已过期
+    Revoked,  //已撤销(退款,作废)
+}
+
+impl Default for EntitlementState {
+    fn default() -> Self {
+        EntitlementState::Active
+    }
+}
+
+impl EntitlementState {
+    ///是否享有权益
+    pub fn is_valid(&self) -> bool {
+        match self {
+            EntitlementState::Active | EntitlementState::Canceled => true,
+            _ => false,
+        }
+    }
+}
+
+// 权益数据
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Entitlement {
+    pub user_id: u32,           //玩家id
+    pub product_id: String,     //商品id(订阅的话为订阅id)
+    pub purchase_token: String, //购买令牌
+    pub order_id: String,       //订单id
+    pub is_subscription: bool,  //是否订阅
+    pub state: EntitlementState, //权益状态
+    pub expiry_time: i64,       //到期时间(毫秒),0代表永久
+    pub event_time: i64,        //最后一次更新对应的通知时间(毫秒)
+}
+
+///从文件加载权益数据
+pub fn init_store() {
+    let path = crate::ENTITLEMENT_STORE_PATH.as_str();
+    let content = std::fs::read_to_string(path);
+    let content = match content {
+        Ok(content) => content,
+        Err(e) => {
+            warn!("entitlement store not found! path:{},{:?}", path, e);
+            return;
+        }
+    };
+    let res: serde_json::Result<HashMap<String, Entitlement>> = serde_json::from_str(content.as_str());
+    match res {
+        Ok(map) => {
+            let size = map.len();
+            let mut write = ENTITLEMENTS.write().unwrap();
+            *write = map;
+            info!("初始化权益数据完成!数量:{}", size);
+        }
+        Err(e) => {
+            error!("entitlement store parse error! path:{},{:?}", path, e);
+        }
+    }
+}
+
+///落地到文件
+fn save_store(map: &HashMap<String, Entitlement>) {
+    let path = crate::ENTITLEMENT_STORE_PATH.as_str();
+    let content = serde_json::to_string(map);
+    if let Err(e) = content {
+        error!("{:?}", e);
+        return;
+    }
+    let res = std::fs::write(path, content.unwrap());
+    if let Err(e) = res {
+        error!("save entitlement store error! path:{},{:?}", path, e);
+    }
+}
+
+///获得权益数据
+pub fn get_entitlement(purchase_token: &str) -> Option<Entitlement> {
+    let read = ENTITLEMENTS.read().unwrap();
+    read.get(purchase_token).cloned()
+}
+
+///更新权益数据,返回是否有改动(过时的通知直接忽略)
+pub fn update_entitlement(entitlement: Entitlement) -> bool {
+    let mut write = ENTITLEMENTS.write().unwrap();
+    if let Some(old) = write.get(entitlement.purchase_token.as_str()) {
+        if old.event_time > entitlement.event_time {
+            warn!(
+                "entitlement notification is out of date!purchase_token:{},old_event_time:{},event_time:{}",
+                entitlement.purchase_token, old.event_time, entitlement.event_time
+            );
+            return false;
+        }
+        if old.state == entitlement.state && old.expiry_time == entitlement.expiry_time {
+            return false;
+        }
+    }
+    write.insert(entitlement.purchase_token.clone(), entitlement);
+    save_store(&write);
+    true
+}
+
+///通知游戏服权益变更
+pub fn notify_game(entitlement: &Entitlement) -> anyhow::Result<()> {
+    let url = crate::GAME_NOTIFY_URL.as_str();
+    if url.is_empty() {
+        warn!("game_notify_url is empty!entitlement:{:?}", entitlement);
+        return Ok(());
+    }
+    let client = reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(5))
+        .build()?;
+    let response = client
+        .post(url)
+        .query(&[("token", crate::GAME_NOTIFY_TOKEN.as_str())])
+        .json(entitlement)
+        .send()?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text()?;
+        anyhow::bail!("notify game failed: {}   {}", status, error_text);
+    }
+    info!(
+        "notify game entitlement success!user_id:{},product_id:{},state:{:?}",
+        entitlement.user_id, entitlement.product_id, entitlement.state
+    );
+    Ok(())
+}
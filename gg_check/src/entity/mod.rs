@@ -1 +1,2 @@
-pub mod user;
+pub mod entitlement;
+pub mod user;
\ No newline at end of file
@@ -1,9 +1,9 @@
-use std::time::Duration;
+use std::str::FromStr;
 use super::*;
 use crate::PACKAGE_NAME;
-use crate::check::pay::{validate_purchase_response, verify_google_purchase};
+use crate::check::pay::{acknowledge_purchase, validate_purchase_response, verify_google_purchase};
+use crate::entity::entitlement::{update_entitlement, Entitlement};
 use log::info;
-use serde_json::json;
 
 pub struct CheckPayHandler;
 impl HttpServerHandler for CheckPayHandler {
@@ -58,28 +58,36 @@ impl HttpServerHandler for CheckPayHandler {
             return Ok(res);
         }
         //给谷歌发送确认
-        let url = format!("https://androidpublisher.googleapis.com/androidpublisher/v3/applications/{}/purchases/products/{}/tokens/{}:acknowledge", PACKAGE_NAME.as_str(), product_id, purchase_token);
-        let client = reqwest::blocking::Client::builder().timeout(Duration::from_secs(5)).build()?;
-        // 3. 构建请求体 (可选developerPayload)
-        let request_body = json!({
-                "developerPayload": "your_custom_data_here" // 可选字段
-            });
-        let read = crate::SERVER_TOKEN.read().unwrap();
-        let access_token = read.access_token.clone();
-        drop(read);
-        let response = client
-            .post(&url)
-            .bearer_auth(&access_token)
-            .json(&request_body)
-            .send()
-            ?;
-        // 5. 处理响应
-        if response.status().is_success() {
-            info!("✅ 购买确认成功！");
-        } else {
-            let status = response.status();
-            let error_text = response.text()?;
-            error!("❌ 确认失败: {}   {}", status,error_text);
+        let res = acknowledge_purchase(
+            &access_token,
+            PACKAGE_NAME.as_str(),
+            product_id,
+            purchase_token,
+            is_subscription,
+        );
+        match res {
+            Ok(_) => info!("✅ 购买确认成功！"),
+            Err(e) => error!("❌ 确认失败: {:?}", e),
+        }
+
+        //记录权益,退款的时候用来找到对应玩家,玩家id只认谷歌返回的下单信息
+        let user_id = purchase_response
+            .obfuscated_external_account_id
+            .as_ref()
+            .and_then(|user_id| u32::from_str(user_id.as_str()).ok());
+        match user_id {
+            Some(user_id) => {
+                let mut entitlement = Entitlement::default();
+                entitlement.user_id = user_id;
+                entitlement.product_id = product_id.clone();
+                entitlement.purchase_token = purchase_token.clone();
+                entitlement.order_id = purchase_response.order_id.clone().unwrap_or_default();
+                entitlement.event_time = chrono::Utc::now().timestamp_millis();
+                update_entitlement(entitlement);
+            }
+            None => {
+                error!("check_pay has no user_id!order_id:{:?}", purchase_response.order_id);
+            }
         }
         let res = build_res(200, "success", Some(purchase_response.to_json())).to_string();
         Ok(res)
@@ -1,5 +1,6 @@
 pub mod check_login;
 pub mod check_pay;
+pub mod rtdn;
 mod test;
 
 use log::{error};
@@ -10,6 +11,7 @@ use tools::http::HttpServerHandler;
 use tools::json::{JsonValue, JsonValueTrait};
 use crate::http::check_login::CheckLoginHandler;
 use crate::http::check_pay::CheckPayHandler;
+use crate::http::rtdn::RtdnHandler;
 use crate::http::test::TestHandler;
 
 fn build_res(code: i32, mess: &str, data: Option<JsonValue>) -> JsonValue {
@@ -27,6 +29,7 @@ pub fn init_server() {
     tools::http::Builder::new()
         .route(Box::new(CheckLoginHandler))
         .route(Box::new(CheckPayHandler))
+        .route(Box::new(RtdnHandler))
         .route(Box::new(TestHandler))
         .bind(port as u16);
 }
@@ -0,0 +1,45 @@
+use super::*;
+use crate::check::notify::{handle_notification, parse_push};
+
+///谷歌实时开发者通知(Pub/Sub推送)
+pub struct RtdnHandler;
+impl HttpServerHandler for RtdnHandler {
+    fn get_path(&self) -> &str {
+        "/gg_check/rtdn"
+    }
+
+    fn do_post(
+        &mut self,
+        _uri: String,
+        _uri_params: HashMap<String, String>,
+        _json_params: &[u8],
+    ) -> anyhow::Result<serde_json::Value> {
+        //Pub/Sub推送地址上带着配置的token,对不上的直接拒绝
+        let token = _uri_params.get("token").map(|t| t.as_str()).unwrap_or("");
+        let rtdn_token = crate::RTDN_TOKEN.as_str();
+        if rtdn_token.is_empty() || token != rtdn_token {
+            anyhow::bail!("rtdn token is error!")
+        }
+
+        //解析失败的消息重试也没用,直接返回成功让Pub/Sub不再推送
+        let notification = parse_push(_json_params);
+        if let Err(e) = notification {
+            error!("{:?}", e);
+            let res = build_res(-100, format!("{:?}", e).as_str(), None);
+            return Ok(res);
+        }
+        let notification = notification.unwrap();
+
+        //处理失败返回错误,Pub/Sub会重新推送
+        let res = handle_notification(&notification);
+        if let Err(e) = res {
+            error!("{:?}", e);
+            return Err(e);
+        }
+        let data = match res.unwrap() {
+            Some(entitlement) => serde_json::to_value(entitlement).ok(),
+            None => None,
+        };
+        Ok(build_res(200, "success", data))
+    }
+}
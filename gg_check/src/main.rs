@@ -1,6 +1,7 @@
 mod check;
 mod entity;
 mod http;
+#[cfg(test)]
 mod test;
 
 use crate::http::init_server;
@@ -65,6 +66,42 @@ pub static PACKAGE_NAME: Lazy<String> = Lazy::new(|| {
     res
 });
 
+// oauth2 api地址(tokeninfo),本地测试的时候可以配置成mock服务器地址
+pub static GOOGLE_OAUTH_URL: Lazy<String> = Lazy::new(|| {
+    let res = CONF_MAP.get_str("google_oauth_url", "https://oauth2.googleapis.com");
+    res
+});
+
+// androidpublisher api地址,本地测试的时候可以配置成mock服务器地址
+pub static ANDROID_PUBLISHER_URL: Lazy<String> = Lazy::new(|| {
+    let res = CONF_MAP.get_str("androidpublisher_url", "https://androidpublisher.googleapis.com");
+    res
+});
+
+// 游戏服接收权益变更通知的地址
+pub static GAME_NOTIFY_URL: Lazy<String> = Lazy::new(|| {
+    let res = CONF_MAP.get_str("game_notify_url", "");
+    res
+});
+
+// 游戏服接收权益变更通知的校验token
+pub static GAME_NOTIFY_TOKEN: Lazy<String> = Lazy::new(|| {
+    let res = CONF_MAP.get_str("game_notify_token", "");
+    res
+});
+
+// Pub/Sub推送地址上带的校验token(?token=xxx),为空的时候拒绝所有推送
+pub static RTDN_TOKEN: Lazy<String> = Lazy::new(|| {
+    let res = CONF_MAP.get_str("rtdn_token", "");
+    res
+});
+
+// 权益数据持久化文件
+pub static ENTITLEMENT_STORE_PATH: Lazy<String> = Lazy::new(|| {
+    let res = CONF_MAP.get_str("entitlement_store_path", "./entitlement.json");
+    res
+});
+
 fn init_timer() {
     let read = SERVER_TOKEN.read().unwrap();
     let expires_in = (read.expires_in - 5) as u64;
@@ -112,6 +149,8 @@ fn main(){
     init_log();
     //刷新谷歌api token
     init_timer();
+    //加载权益数据
+    entity::entitlement::init_store();
     //初始化http服务器
     init_server();
     info!("启动成功!耗时:{}ms", time.elapsed().as_millis());
@@ -0,0 +1,112 @@
+use crate::check::client::{GoogleApi, HttpGoogleApi};
+
+//测试用的mock服务器,每个进程只启动一次
+fn mock_api() -> HttpGoogleApi {
+    static START: std::sync::Once = std::sync::Once::new();
+    START.call_once(|| {
+        tools::mock_api::start(18182, "../tools/examples/mock_api/mock.json").unwrap();
+    });
+    let url = "http://127.0.0.1:18182";
+    HttpGoogleApi::new(url, format!("{}/token", url).as_str(), url)
+}
+
+#[test]
+fn test_exchange_code() {
+    let api = mock_api();
+    let res = api.exchange_code(&[("code", "mock_code")]).unwrap();
+    assert_eq!(res.token_type, "Bearer");
+    assert!(!res.access_token.is_empty());
+}
+
+#[test]
+fn test_get_purchase() {
+    let api = mock_api();
+    let res = api
+        .get_purchase(
+            "mock_access_token",
+            "org.zqwz.cc",
+            "gem_60",
+            "mock_token",
+            false,
+        )
+        .unwrap();
+    assert_eq!(res.purchase_state, Some(0));
+    assert_eq!(res.order_id.as_deref(), Some("GPA.mock-mock_token"));
+
+    //脚本里面配置的退款单
+    let res = api
+        .get_purchase(
+            "mock_access_token",
+            "org.zqwz.cc",
+            "gem_60",
+            "refunded_token",
+            false,
+        )
+        .unwrap();
+    assert_eq!(res.purchase_state, Some(1));
+
+    //脚本里面配置的接口报错
+    let res = api.get_purchase(
+        "mock_access_token",
+        "org.zqwz.cc",
+        "gem_60",
+        "down_token",
+        false,
+    );
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_get_subscription_purchase() {
+    let api = mock_api();
+    let res = api
+        .get_subscription_purchase(
+            "mock_access_token",
+            "org.zqwz.cc",
+            "monthly_card",
+            "mock_token",
+        )
+        .unwrap();
+    assert!(res.get_expiry_time() > chrono::Local::now().timestamp_millis());
+
+    let res = api
+        .get_subscription_purchase(
+            "mock_access_token",
+            "org.zqwz.cc",
+            "monthly_card",
+            "expired_token",
+        )
+        .unwrap();
+    assert_eq!(res.get_expiry_time(), 1000);
+}
+
+#[test]
+fn test_acknowledge() {
+    let api = mock_api();
+    let res = api.acknowledge(
+        "mock_access_token",
+        "org.zqwz.cc",
+        "gem_60",
+        "mock_token",
+        false,
+    );
+    assert!(res.is_ok());
+    let res = api.acknowledge(
+        "mock_access_token",
+        "org.zqwz.cc",
+        "gem_60",
+        "ack_fail_token",
+        false,
+    );
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_get_voided_purchases() {
+    let api = mock_api();
+    let res = api
+        .get_voided_purchases("mock_access_token", "org.zqwz.cc", 0, None)
+        .unwrap();
+    assert!(res.voided_purchases.is_empty());
+    assert!(res.token_pagination.is_none());
+}
@@ -1,2 +1,4 @@
+pub mod client;
 pub mod login;
+pub mod notify;
 pub mod pay;
\ No newline at end of file
@@ -0,0 +1,63 @@
+use base64::Engine;
+use serde_json::json;
+use crate::check::notify::{parse_push, SUBSCRIPTION_CANCELED};
+use crate::check::pay::VoidedPurchasesResponse;
+
+#[test]
+fn test_parse_push() {
+    let notification = json!({
+        "version": "1.0",
+        "packageName": "org.zqwz.cc",
+        "eventTimeMillis": "1755487252000",
+        "subscriptionNotification": {
+            "version": "1.0",
+            "notificationType": SUBSCRIPTION_CANCELED,
+            "purchaseToken": "purchase_token",
+            "subscriptionId": "monthly_card"
+        }
+    });
+    let data = base64::engine::general_purpose::STANDARD.encode(notification.to_string());
+    let push = json!({
+        "message": {
+            "data": data,
+            "messageId": "136969346945"
+        },
+        "subscription": "projects/zqwz/subscriptions/rtdn"
+    });
+    let res = parse_push(push.to_string().as_bytes()).unwrap();
+    assert_eq!(res.package_name, "org.zqwz.cc");
+    assert_eq!(res.get_event_time(), 1755487252000);
+    let sn = res.subscription_notification.unwrap();
+    assert_eq!(sn.notification_type, SUBSCRIPTION_CANCELED);
+    assert_eq!(sn.subscription_id, "monthly_card");
+    assert!(res.voided_purchase_notification.is_none());
+}
+
+#[test]
+fn test_parse_voided_purchases() {
+    let body = json!({
+        "voidedPurchases": [{
+            "kind": "androidpublisher#voidedPurchase",
+            "purchaseToken": "purchase_token",
+            "purchaseTimeMillis": "1755487252000",
+            "voidedTimeMillis": "1755487352000",
+            "orderId": "GPA.1234",
+            "voidedSource": 0,
+            "voidedReason": 1
+        }],
+        "tokenPagination": {
+            "nextPageToken": "next_page"
+        }
+    });
+    let res: VoidedPurchasesResponse = serde_json::from_value(body).unwrap();
+    assert_eq!(res.voided_purchases.len(), 1);
+    assert_eq!(res.voided_purchases[0].purchase_token, "purchase_token");
+    assert_eq!(res.voided_purchases[0].order_id, "GPA.1234");
+    let next = res.token_pagination.and_then(|tp| tp.next_page_token);
+    assert_eq!(next.as_deref(), Some("next_page"));
+
+    //没有作废记录的时候谷歌返回空对象
+    let res: VoidedPurchasesResponse = serde_json::from_value(json!({})).unwrap();
+    assert!(res.voided_purchases.is_empty());
+    assert!(res.token_pagination.is_none());
+}
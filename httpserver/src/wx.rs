@@ -4,16 +4,63 @@ use tools::json::{JsonValue, JsonValueTrait};
 
 use tools::http::{send_get, send_post};
 
-static SEND_SUBSCRIBE_URL: &str = "https://api.weixin.qq.com/cgi-bin/message/subscribe/send";
+lazy_static::lazy_static! {
+    ///微信api客户端
+    pub static ref WX_API: Box<dyn WxApi> = Box::new(HttpWxApi::from_conf());
+}
+
+///所有对微信的外部调用
+pub trait WxApi: Send + Sync {
+    ///获取access_token
+    fn get_access_token(&self, app_id: &str, app_secret: &str) -> anyhow::Result<String>;
+
+    ///发送订阅消息,返回微信的原始json
+    fn send_subscribe(&self, access_token: &str, param: JsonValue) -> anyhow::Result<String>;
+}
+
+///基于http的微信api客户端,地址取server.config,本地测试的时候可以指向mock服务器
+pub struct HttpWxApi {
+    token_url: String, //游戏圈获取access_token的地址
+    api_url: String,   //微信api地址
+}
+
+impl HttpWxApi {
+    pub fn from_conf() -> Self {
+        let token_url = crate::CONF_MAP.get_str("wx_token_url", "https://mp-weixin.q1.com");
+        let api_url = crate::CONF_MAP.get_str("wx_api_url", "https://api.weixin.qq.com");
+        HttpWxApi::new(token_url.as_str(), api_url.as_str())
+    }
+
+    pub fn new(token_url: &str, api_url: &str) -> Self {
+        HttpWxApi {
+            token_url: token_url.trim_end_matches('/').to_owned(),
+            api_url: api_url.trim_end_matches('/').to_owned(),
+        }
+    }
+}
+
+impl WxApi for HttpWxApi {
+    fn get_access_token(&self, app_id: &str, app_secret: &str) -> anyhow::Result<String> {
+        let url = format!(
+            "{}/WXAPIService.asmx/GetAccessTokenS?appId={}&secret={}",
+            self.token_url, app_id, app_secret
+        );
+        send_get(&url, None, None)
+    }
+
+    fn send_subscribe(&self, access_token: &str, param: JsonValue) -> anyhow::Result<String> {
+        let url = format!(
+            "{}/cgi-bin/message/subscribe/send?access_token={}",
+            self.api_url, access_token
+        );
+        send_post(url.as_str(), Some(param))
+    }
+}
 
 pub fn get_access_token() -> Option<String> {
     let app_id = crate::CONF_MAP.get_str("app_id", "");
     let app_secret = crate::CONF_MAP.get_str("app_secret", "");
-    let url = format!(
-        "https://mp-weixin.q1.com/WXAPIService.asmx/GetAccessTokenS?appId={}&secret={}",
-        app_id, app_secret
-    );
-    let res = send_get(&url, None, None);
+    let res = WX_API.get_access_token(app_id.as_str(), app_secret.as_str());
     if let Err(err) = res {
         log::warn!("{:?}", err);
         return None;
@@ -34,14 +81,13 @@ pub fn send_subscribe(open_id: &str, temp_id: &str, data: serde_json::Value) ->
         return false;
     }
     let access_token = access_token.unwrap();
-    let url = format!("{}?access_token={}", SEND_SUBSCRIBE_URL, access_token);
     let mut param_map = serde_json::Map::new();
     param_map.insert("touser".to_string(), JsonValue::from(open_id));
     param_map.insert("template_id".to_string(), JsonValue::from(temp_id));
     param_map.insert("data".to_string(), data);
     param_map.insert("miniprogram_state".to_string(), JsonValue::from("formal"));
 
-    let res = send_post(url.as_str(), Some(JsonValue::from(param_map)));
+    let res = WX_API.send_subscribe(access_token.as_str(), JsonValue::from(param_map));
     if let Err(err) = res {
         log::error!("发送订阅失败！openId:{} 错误信息:{:?}", "open_id", err);
         return false;
@@ -81,3 +127,44 @@ pub fn send_subscribe(open_id: &str, temp_id: &str, data: serde_json::Value) ->
     }
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    ///测试用的mock服务器,每个进程只启动一次
+    fn mock_api() -> HttpWxApi {
+        static START: std::sync::Once = std::sync::Once::new();
+        START.call_once(|| {
+            tools::mock_api::start(18183, "../tools/examples/mock_api/mock.json").unwrap();
+        });
+        HttpWxApi::new("http://127.0.0.1:18183", "http://127.0.0.1:18183/")
+    }
+
+    #[test]
+    fn test_get_access_token() {
+        let api = mock_api();
+        let res = api.get_access_token("mock_app_id", "mock_secret").unwrap();
+        assert_eq!(res, "mock_wx_access_token");
+
+        //脚本里面配置的接口报错
+        assert!(api
+            .get_access_token("outage_app_id", "mock_secret")
+            .is_err());
+    }
+
+    #[test]
+    fn test_send_subscribe() {
+        let api = mock_api();
+        let param = serde_json::json!({ "touser": "mock_open_id" });
+        let res = api.send_subscribe("mock_wx_access_token", param).unwrap();
+        let json = JsonValue::from_str(res.as_str()).unwrap();
+        assert_eq!(json["errcode"], 0);
+
+        //脚本里面配置的玩家拒收
+        let param = serde_json::json!({ "touser": "blocked_open_id" });
+        let res = api.send_subscribe("mock_wx_access_token", param).unwrap();
+        let json = JsonValue::from_str(res.as_str()).unwrap();
+        assert_eq!(json["errcode"], 43101);
+    }
+}
@@ -28,7 +28,6 @@ use std::sync::atomic::Ordering;
 use std::task::Poll;
 use std::time::{Duration, SystemTime};
 use tools::net_message_io::MessageHandler;
-use tools::net_message_io::TransportWay;
 use tools::tcp::ClientHandler;
 use tracing_subscriber::EnvFilter;
 
@@ -476,7 +475,6 @@ impl tools::net_message_io::MessageHandler for MessageClient {
 
     async fn on_close(&mut self) {
         println!("断开了");
-        self.connect(TransportWay::Tcp, "127.0.0.1:16801").await;
     }
 
     async fn on_message(&mut self, mess: &[u8]) {}
@@ -499,7 +497,6 @@ impl tools::tcp::ClientHandler for TcpClientTestt {
 
     async fn on_close(&mut self) {
         println!("断开了");
-        self.on_read("127.0.0.1:16801".to_string()).await;
     }
 
     async fn on_message(&mut self, mess: Vec<u8>) {}
@@ -73,9 +73,6 @@ impl ClientHandler for TcpClientHandler {
 
     async fn on_close(&mut self) {
         println!("断开链接");
-        //let address = "192.168.1.100:16801";
-        let address = "localhost:16801";
-        self.on_read(address.to_string()).await;
     }
 
     async fn on_message(&mut self, mess: Vec<u8>) {
@@ -13,6 +13,7 @@ use tools::cmd_code::{BattleCode, GameCode, RoomCode};
 use tools::protos::server_protocol::B_S_SUMMARY;
 use tools::protos::server_protocol::G_S_MODIFY_NICK_NAME;
 use tools::protos::server_protocol::R_S_UPDATE_SEASON;
+use tools::protos::server_protocol::{B_R_WORLD_BOSS_DAMAGE, UPDATE_WORLD_BOSS_PUSH};
 use tools::util::packet::Packet;
 
 ///修改名字
@@ -242,3 +243,72 @@ pub fn update_rank(rm: &mut RankMgr, packet: Packet) {
     }
     rm.need_rank = true;
 }
+
+///推送世界boss数据给所有游戏服
+fn push_world_boss(rm: &mut RankMgr, notice: bool) {
+    let proto = rm.world_boss.build_push(notice);
+    let bytes = proto.write_to_bytes();
+    match bytes {
+        Ok(bytes) => rm.push_2_server(GameCode::WorldBossPush.into_u32(), 0, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+///世界boss伤害上报
+pub fn world_boss_damage(rm: &mut RankMgr, packet: Packet) {
+    let mut proto = B_R_WORLD_BOSS_DAMAGE::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    //上一轮的伤害不算
+    if proto.world_boss_id != rm.world_boss.world_boss_id {
+        warn!(
+            "world_boss_id is not match!report:{},current:{}",
+            proto.world_boss_id, rm.world_boss.world_boss_id
+        );
+        return;
+    }
+    if !rm.world_boss.is_alive() {
+        return;
+    }
+    for damage_pt in proto.damages.iter() {
+        rm.world_boss
+            .add_damage(damage_pt.user_id, damage_pt.get_name(), damage_pt.damage);
+    }
+    let mut notice = rm.world_boss.check_milestone();
+    //被打死了直接结算
+    if rm.world_boss.hp == 0 {
+        rm.world_boss.settle();
+        notice = true;
+    }
+    rm.world_boss.save();
+    push_world_boss(rm, notice);
+}
+
+///更新世界boss,上一轮没结算的先结算
+pub fn update_world_boss(rm: &mut RankMgr, packet: Packet) {
+    let mut proto = UPDATE_WORLD_BOSS_PUSH::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let world_boss_id = proto.world_boss_id;
+    let next_update_time = proto.next_update_time;
+    //同一轮的重复通知
+    if world_boss_id == rm.world_boss.world_boss_id
+        && next_update_time == rm.world_boss.next_update_time
+    {
+        return;
+    }
+    let need_push = rm.world_boss.world_boss_id > 0 && !rm.world_boss.is_settled;
+    rm.world_boss.settle();
+    if need_push {
+        push_world_boss(rm, true);
+    }
+    rm.world_boss.reset(world_boss_id, next_update_time);
+    rm.world_boss.save();
+    push_world_boss(rm, true);
+}
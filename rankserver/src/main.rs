@@ -6,6 +6,7 @@ mod task_timer;
 use std::env;
 
 use crate::mgr::rank_mgr::RankMgr;
+use crate::mgr::world_boss::WorldBoss;
 use crate::net::tcp_server;
 use async_std::sync::Mutex;
 use log::error;
@@ -14,6 +15,7 @@ use rayon::slice::ParallelSliceMut;
 use std::sync::Arc;
 use task_timer::init_timer;
 use tools::conf::Conf;
+use tools::metrics::MetricsHttpHandler;
 use tools::redis_pool::RedisPoolTool;
 use tools::templates::template::{init_temps_mgr, TemplatesMgr};
 
@@ -72,6 +74,12 @@ const REDIS_KEY_HISTORY_RANK: &str = "history_rank";
 ///最佳排行
 const REDIS_KEY_BEST_RANK: &str = "best_rank";
 
+///世界boss当前这一轮的数据
+const REDIS_KEY_WORLD_BOSS: &str = "world_boss_data";
+
+///世界boss待领取的奖励
+const REDIS_KEY_WORLD_BOSS_REWARD: &str = "world_boss_reward";
+
 fn main() {
     let rm = Arc::new(Mutex::new(RankMgr::new()));
 
@@ -81,14 +89,26 @@ fn main() {
     //初始化排行榜
     init_rank(rm.clone());
 
+    //初始化世界boss
+    init_world_boss(rm.clone());
+
     //初始化定时器
     init_timer(rm.clone());
 
+    //初始化http服务
+    init_http_server(rm.clone());
+
     //初始化网络
     init_tcp_server(rm.clone());
 }
 
 fn init_log() {
+    //配置了log_format就用tracing输出,日志会带上trace_id/user_id/room_id/battle_id
+    let log_format = CONF_MAP.get_str("log_format", "");
+    if !log_format.is_empty() {
+        tools::my_log::init_trace_log(log_format == "json");
+        return;
+    }
     let info_log = &CONF_MAP.get_str("info_log_path", "");
     let error_log = &CONF_MAP.get_str("error_log_path", "");
     tools::my_log::init_log(info_log, error_log);
@@ -100,6 +120,22 @@ fn init_tcp_server(rm: Lock) {
     tcp_server::new(tcp_port, rm);
 }
 
+///初始化http服务端,目前只有监控指标
+fn init_http_server(rm: Lock) {
+    let http_port = CONF_MAP.get_usize("http_port", 0) as u16;
+    tools::http::Builder::new()
+        .route(Box::new(MetricsHttpHandler::new(move || {
+            async_std::task::block_on(rm.lock()).collect_metrics();
+        })))
+        .bind(http_port);
+}
+
+///初始化世界boss
+fn init_world_boss(rm: Lock) {
+    let mut lock = async_std::task::block_on(rm.lock());
+    lock.world_boss = WorldBoss::load();
+}
+
 ///初始化排行榜
 fn init_rank(rm: Lock) {
     let mut redis_lock = async_std::task::block_on(REDIS_POOL.lock());
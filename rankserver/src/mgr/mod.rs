@@ -1,5 +1,6 @@
 use tools::protos::base::{LeaguePt, SummaryDataPt};
 pub mod rank_mgr;
+pub mod world_boss;
 use serde::{Deserialize, Serialize};
 
 pub struct RankInfoPtr(pub *mut RankInfo);
@@ -1,7 +1,10 @@
 use std::collections::HashMap;
 
+use super::world_boss::WorldBoss;
 use super::RankInfo;
-use crate::handler::{modify_nick_name, update_rank, update_season};
+use crate::handler::{
+    modify_nick_name, update_rank, update_season, update_world_boss, world_boss_damage,
+};
 use crate::task_timer::Task;
 use crate::{REDIS_INDEX_RANK, REDIS_KEY_CURRENT_RANK};
 use async_std::task::block_on;
@@ -10,7 +13,9 @@ use log::warn;
 use rayon::slice::ParallelSliceMut;
 use std::collections::hash_map::RandomState;
 use tools::cmd_code::RankCode;
+use tools::metrics;
 use tools::net_message_io::NetHandler;
+use tools::trace;
 use tools::util::packet::Packet;
 
 type CmdFn = HashMap<u32, fn(&mut RankMgr, Packet), RandomState>;
@@ -22,6 +27,7 @@ pub struct RankMgr {
     pub need_rank: bool,                        //是否需要排序
     pub last_rank: Vec<RankInfo>,               //上一赛季排行榜数据
     pub user_best_rank: HashMap<u32, RankInfo>, //玩家历史最好排行数据
+    pub world_boss: WorldBoss,                  //世界boss全服数据
     net_handler: Option<NetHandler>,            //tcp channel的发送方
     pub task_sender: Option<Sender<Task>>,      //任务发送方
 }
@@ -70,17 +76,39 @@ impl RankMgr {
         //修改名字
         self.cmd_map
             .insert(RankCode::ModifyNickName.into_u32(), modify_nick_name);
+        //世界boss伤害上报
+        self.cmd_map
+            .insert(RankCode::WorldBossDamage.into_u32(), world_boss_damage);
+        //更新世界boss
+        self.cmd_map
+            .insert(RankCode::UpdateWorldBossPush.into_u32(), update_world_boss);
+    }
+
+    ///刷新监控指标
+    pub fn collect_metrics(&self) {
+        metrics::set_gauge("rank_players", self.rank_vec.len() as i64);
+        metrics::set_gauge(
+            "world_boss_contributors",
+            self.world_boss.contributions.len() as i64,
+        );
+        if let Some(sender) = self.task_sender.as_ref() {
+            metrics::set_gauge("task_channel_backlog", sender.len() as i64);
+        }
     }
 
     ///执行函数，通过packet拿到cmd，然后从cmdmap拿到函数指针调用
     pub fn invok(&mut self, packet: Packet) {
         let cmd = packet.get_cmd();
+        let _trace = trace::enter(&packet);
         let f = self.cmd_map.get_mut(&cmd);
         if f.is_none() {
             warn!("there is no handler of cmd:{:?}!", cmd);
             return;
         }
-        let _ = f.unwrap()(self, packet);
+        let f = *f.unwrap();
+        let time = std::time::Instant::now();
+        let _ = f(self, packet);
+        metrics::record_invok(cmd, time.elapsed());
     }
 }
 
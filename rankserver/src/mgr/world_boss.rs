@@ -0,0 +1,308 @@
+use crate::{REDIS_INDEX_RANK, REDIS_KEY_WORLD_BOSS, REDIS_KEY_WORLD_BOSS_REWARD, REDIS_POOL};
+use async_std::task::block_on;
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tools::protos::base::WorldBossRankPt;
+use tools::protos::server_protocol::R_G_WORLD_BOSS_PUSH;
+
+///推送给游戏服的排行数量
+pub const WORLD_BOSS_RANK_SHOW: usize = 10;
+///当前这一轮数据在redis hash里面的key
+const CURRENT_FIELD: &str = "current";
+///往玩家待领取奖励列表里追加一条,和游戏服领取互斥,避免读改写丢奖励
+const APPEND_REWARD_SCRIPT: &str = r#"
+local v = redis.call('HGET', KEYS[1], ARGV[1])
+if v and v ~= '[]' then
+    v = string.sub(v, 1, -2) .. ',' .. ARGV[2] .. ']'
+else
+    v = '[' .. ARGV[2] .. ']'
+end
+return redis.call('HSET', KEYS[1], ARGV[1], v)
+"#;
+
+///玩家对世界boss的贡献
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorldBossContribution {
+    pub user_id: u32, //玩家id
+    pub name: String, //名字
+    pub damage: u64,  //累计伤害
+    pub time: i64,    //最后一次造成伤害的时间,伤害一样的时候先到的排前面
+}
+
+///待领取的世界boss奖励
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorldBossReward {
+    pub world_boss_id: i32,     //哪一轮的boss
+    pub rank: u32,              //名次,从1开始
+    pub grade_frames: Vec<u32>, //奖励grade相框
+    pub souls: Vec<u32>,        //奖励灵魂头像
+}
+
+///世界boss全服共享数据,一轮boss一份
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorldBoss {
+    pub world_boss_id: i32,                                 //boss id
+    pub next_update_time: u64,                              //这一轮结束的时间
+    pub max_hp: u64,                                        //最大血量
+    pub hp: u64,                                            //剩余血量
+    pub milestone: u32,                                     //已经广播过的血量节点(剩余百分比)
+    pub is_settled: bool,                                   //是否已经结算过
+    pub contributions: HashMap<u32, WorldBossContribution>, //伤害贡献
+}
+
+impl WorldBoss {
+    ///从redis加载
+    pub fn load() -> Self {
+        let mut redis_lock = block_on(REDIS_POOL.lock());
+        let res: Option<String> =
+            redis_lock.hget(REDIS_INDEX_RANK, REDIS_KEY_WORLD_BOSS, CURRENT_FIELD);
+        if res.is_none() {
+            return WorldBoss::default();
+        }
+        let res = serde_json::from_str(res.unwrap().as_str());
+        match res {
+            Ok(wb) => wb,
+            Err(e) => {
+                error!("{:?}", e);
+                WorldBoss::default()
+            }
+        }
+    }
+
+    ///持久化到redis
+    pub fn save(&self) {
+        let json = serde_json::to_string(self);
+        if let Err(e) = json {
+            error!("{:?}", e);
+            return;
+        }
+        let mut redis_lock = block_on(REDIS_POOL.lock());
+        let _: Option<u32> = redis_lock.hset(
+            REDIS_INDEX_RANK,
+            REDIS_KEY_WORLD_BOSS,
+            CURRENT_FIELD,
+            json.unwrap().as_str(),
+        );
+    }
+
+    ///开启新的一轮
+    pub fn reset(&mut self, world_boss_id: i32, next_update_time: u64) {
+        let temp = crate::TEMPLATES
+            .worldboss_temp_mgr()
+            .get_temp_ref(&(world_boss_id as u32));
+        let max_hp = match temp {
+            Some(temp) => temp.hp,
+            None => {
+                warn!(
+                    "could not find WorldBossTemp!world_boss_id:{}",
+                    world_boss_id
+                );
+                0
+            }
+        };
+        self.world_boss_id = world_boss_id;
+        self.next_update_time = next_update_time;
+        self.max_hp = max_hp;
+        self.hp = max_hp;
+        self.milestone = 100;
+        self.is_settled = false;
+        self.contributions.clear();
+        info!(
+            "世界boss开启新一轮!world_boss_id:{},max_hp:{}",
+            world_boss_id, max_hp
+        );
+    }
+
+    ///boss是否还能打
+    pub fn is_alive(&self) -> bool {
+        self.world_boss_id > 0 && !self.is_settled && self.hp > 0
+    }
+
+    ///扣血并记录贡献,返回实际扣掉的血量
+    pub fn add_damage(&mut self, user_id: u32, name: &str, damage: u64) -> u64 {
+        if !self.is_alive() || damage == 0 {
+            return 0;
+        }
+        let real_damage = std::cmp::min(damage, self.hp);
+        self.hp -= real_damage;
+        let contribution =
+            self.contributions
+                .entry(user_id)
+                .or_insert_with(|| WorldBossContribution {
+                    user_id,
+                    ..Default::default()
+                });
+        contribution.name = name.to_owned();
+        contribution.damage += real_damage;
+        contribution.time = chrono::Local::now().timestamp_millis();
+        real_damage
+    }
+
+    ///检查是否跨过了新的血量节点,跨过了返回true
+    pub fn check_milestone(&mut self) -> bool {
+        if self.max_hp == 0 {
+            return false;
+        }
+        let temp = crate::TEMPLATES
+            .worldboss_temp_mgr()
+            .get_temp_ref(&(self.world_boss_id as u32));
+        if temp.is_none() {
+            return false;
+        }
+        self.reach_milestone(temp.unwrap().milestones.as_slice())
+    }
+
+    ///按当前血量更新已经广播过的节点,跨过了新节点返回true
+    fn reach_milestone(&mut self, milestones: &[u32]) -> bool {
+        if self.max_hp == 0 {
+            return false;
+        }
+        let percent = (self.hp * 100 / self.max_hp) as u32;
+        let res = milestones
+            .iter()
+            .filter(|&&m| percent <= m && m < self.milestone)
+            .min();
+        match res {
+            Some(&milestone) => {
+                self.milestone = milestone;
+                true
+            }
+            None => false,
+        }
+    }
+
+    ///按伤害排好序的贡献
+    pub fn get_sorted_contributions(&self) -> Vec<&WorldBossContribution> {
+        let mut v: Vec<&WorldBossContribution> = self.contributions.values().collect();
+        v.sort_by(|a, b| b.damage.cmp(&a.damage).then(a.time.cmp(&b.time)));
+        v
+    }
+
+    ///封装推送给游戏服的proto
+    pub fn build_push(&self, notice: bool) -> R_G_WORLD_BOSS_PUSH {
+        let mut proto = R_G_WORLD_BOSS_PUSH::new();
+        proto.set_world_boss_id(self.world_boss_id);
+        proto.set_hp(self.hp);
+        proto.set_max_hp(self.max_hp);
+        proto.set_milestone(self.milestone);
+        proto.set_notice(notice);
+        proto.set_is_settled(self.is_settled);
+        for (index, c) in self
+            .get_sorted_contributions()
+            .into_iter()
+            .take(WORLD_BOSS_RANK_SHOW)
+            .enumerate()
+        {
+            let mut pt = WorldBossRankPt::new();
+            pt.set_user_id(c.user_id);
+            pt.set_name(c.name.clone());
+            pt.set_damage(c.damage);
+            pt.set_rank(index as u32 + 1);
+            proto.ranks.push(pt);
+        }
+        proto
+    }
+
+    ///结算,按伤害排名发奖励,奖励先放redis里,玩家上线或者在线的时候由游戏服领取
+    pub fn settle(&mut self) {
+        if self.is_settled || self.world_boss_id == 0 {
+            return;
+        }
+        self.is_settled = true;
+        let killed = self.hp == 0;
+        let temp = crate::TEMPLATES
+            .worldboss_temp_mgr()
+            .get_temp_ref(&(self.world_boss_id as u32));
+        if temp.is_none() {
+            warn!(
+                "could not find WorldBossTemp!world_boss_id:{}",
+                self.world_boss_id
+            );
+            return;
+        }
+        let temp = temp.unwrap();
+        let mut redis_lock = block_on(REDIS_POOL.lock());
+        for (index, c) in self.get_sorted_contributions().into_iter().enumerate() {
+            let rank = index as u32 + 1;
+            let reward_temp = temp.get_reward(rank, killed);
+            if reward_temp.is_none() {
+                continue;
+            }
+            let reward_temp = reward_temp.unwrap();
+            let reward = WorldBossReward {
+                world_boss_id: self.world_boss_id,
+                rank,
+                grade_frames: reward_temp.grade_frames.clone(),
+                souls: reward_temp.souls.clone(),
+            };
+            let user_id = c.user_id.to_string();
+            let json = serde_json::to_string(&reward).unwrap();
+            //可能还有以前没领的,在redis里追加
+            let _: Option<u32> = redis_lock.eval(
+                REDIS_INDEX_RANK,
+                APPEND_REWARD_SCRIPT,
+                &[REDIS_KEY_WORLD_BOSS_REWARD],
+                &[user_id.as_str(), json.as_str()],
+            );
+        }
+        info!(
+            "世界boss结算完成!world_boss_id:{},killed:{},player_count:{}",
+            self.world_boss_id,
+            killed,
+            self.contributions.len()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_world_boss(max_hp: u64) -> WorldBoss {
+        WorldBoss {
+            world_boss_id: 1,
+            max_hp,
+            hp: max_hp,
+            milestone: 100,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn add_damage() {
+        let mut wb = new_world_boss(100);
+        assert_eq!(wb.add_damage(1, "a", 30), 30);
+        assert_eq!(wb.add_damage(2, "b", 0), 0);
+        assert_eq!(wb.add_damage(1, "a2", 20), 20);
+        assert_eq!(wb.hp, 50);
+        assert_eq!(wb.contributions[&1].damage, 50);
+        assert_eq!(wb.contributions[&1].name, "a2");
+        //超出剩余血量只算剩余的
+        assert_eq!(wb.add_damage(2, "b", 80), 50);
+        assert_eq!(wb.hp, 0);
+        assert!(!wb.is_alive());
+        assert_eq!(wb.add_damage(1, "a", 10), 0);
+        let sorted = wb.get_sorted_contributions();
+        assert_eq!(sorted[0].user_id, 1);
+        assert_eq!(sorted[1].user_id, 2);
+    }
+
+    #[test]
+    fn check_milestone() {
+        let milestones = [75, 50, 25];
+        let mut wb = new_world_boss(100);
+        wb.add_damage(1, "a", 10);
+        assert!(!wb.reach_milestone(&milestones));
+        wb.add_damage(1, "a", 15);
+        assert!(wb.reach_milestone(&milestones));
+        assert_eq!(wb.milestone, 75);
+        //同一个节点只广播一次
+        assert!(!wb.reach_milestone(&milestones));
+        //一次跨过多个节点取最低的
+        wb.add_damage(1, "a", 60);
+        assert!(wb.reach_milestone(&milestones));
+        assert_eq!(wb.milestone, 25);
+        assert!(!new_world_boss(0).reach_milestone(&milestones));
+    }
+}
@@ -5,7 +5,7 @@ use std::collections::HashMap;
 #[derive(Debug, Default, Clone)]
 pub struct TileMap {
     pub id: u32,                                   //地图id
-    pub map_cells: [MapCell; 30],                  //地图格子vec
+    pub map_cells: Vec<MapCell>,                   //地图格子vec
     pub coord_map: HashMap<(isize, isize), usize>, //坐标对应格子
     pub world_cell_map: HashMap<u32, u32>,         //世界块map，index，map_cellid
     pub un_pair_map: HashMap<usize, u32>,          //未配对的地图块map
@@ -0,0 +1,750 @@
+use crate::load_test::stats::STATS;
+use crate::load_test::{LoadTestConfig, Scenario};
+use crate::TEMPLATES;
+use log::{info, warn};
+use protobuf::Message;
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+use std::io::{ErrorKind, Read, Write};
+use std::net::TcpStream;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tools::cmd_code::{BattleCode, ClientCode, GameCode, RoomCode};
+use tools::protos::base::CharacterPt;
+use tools::protos::battle::{
+    C_ACTION, C_CHOOSE_INDEX, S_BATTLE_TURN_NOTICE, S_CHOOSE_INDEX_NOTICE, S_START_NOTICE,
+};
+use tools::protos::protocol::{C_USER_LOGIN, S_USER_LOGIN};
+use tools::protos::room::{
+    C_CHOOSE_CHARACTER, C_CHOOSE_SKILL, C_CONFIRM_INTO_ROOM, C_CREATE_ROOM, C_DRAFT_BAN,
+    C_DRAFT_PICK, C_LEAVE_ROOM, C_PREPARE_CANCEL, C_SEARCH_ROOM, S_CHOOSE_CHARACTER,
+    S_CHOOSE_CHARACTER_NOTICE, S_CHOOSE_SKILL, S_DRAFT_BAN, S_DRAFT_NOTICE, S_DRAFT_PICK,
+    S_LEAVE_ROOM, S_PREPARE_CANCEL, S_ROOM,
+};
+use tools::util::packet::Packet;
+
+///客户端包头长度
+const HEAD_LEN: usize = 16;
+///1v1v1v1自定义房间
+const ROOM_TYPE_CUSTOM: u32 = 1;
+///1v1v1v1匹配房间
+const ROOM_TYPE_MATCH: u32 = 2;
+///行动类型:跳过turn
+const ACTION_SKIP: u32 = 3;
+///行动类型:翻块
+const ACTION_OPEN: u32 = 4;
+///地图块类型:有效块
+const MAP_CELL_VALID: u32 = 2;
+///禁选阶段:禁用
+const DRAFT_PHASE_BAN: u32 = 1;
+///禁选阶段:结束
+const DRAFT_PHASE_FINISH: u32 = 3;
+
+///机器人状态
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum BotState {
+    Login,       //登录中
+    Lobby,       //大厅
+    Searching,   //匹配中
+    InRoom,      //房间内
+    ChoiceIndex, //选择占位
+    Battle,      //战斗中
+}
+
+///机器人接下来要做的操作
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum BotAction {
+    Search,
+    CreateRoom,
+    ConfirmIntoRoom,
+    ChooseCharacter,
+    DraftBan,
+    DraftPick,
+    ChooseSkill,
+    Prepare,
+    LeaveRoom,
+    ChooseIndex,
+    Open,
+    Skip,
+    Relogin,
+}
+
+///模拟客户端,走真实的网关和协议
+pub struct Bot {
+    user_id: u32,
+    scenario: Scenario,
+    conf: Arc<LoadTestConfig>,
+    stream: Option<TcpStream>,
+    read_buf: Vec<u8>,
+    state: BotState,
+    pending: HashMap<u32, (u32, Instant)>, //key:等待的返回命令号 value:(请求命令号,发送时间)
+    next_action: Option<(Instant, BotAction)>,
+    last_heart_beat: Instant,
+    cters: Vec<CharacterPt>,     //玩家拥有的角色
+    chose_cter: u32,             //已选的角色
+    taken_cters: HashSet<u32>,   //房间内别人选了的角色
+    banned_cters: HashSet<u32>,  //禁选阶段被禁用的角色
+    drafting: bool,              //是否在禁选阶段
+    tile_map_id: u32,            //当前战斗地图
+    turn_orders: Vec<u32>,       //选择占位顺序
+    chose_index_users: HashSet<u32>, //已经选择了占位的玩家
+    taken_indexes: HashSet<u32>, //已经被占的位置
+    self_index: u32,             //自己的位置
+    opened_indexes: HashSet<u32>, //本场战斗自己翻过的块
+}
+
+impl Bot {
+    pub fn new(user_id: u32, scenario: Scenario, conf: Arc<LoadTestConfig>) -> Self {
+        Bot {
+            user_id,
+            scenario,
+            conf,
+            stream: None,
+            read_buf: Vec::new(),
+            state: BotState::Login,
+            pending: HashMap::new(),
+            next_action: None,
+            last_heart_beat: Instant::now(),
+            cters: Vec::new(),
+            chose_cter: 0,
+            taken_cters: HashSet::new(),
+            banned_cters: HashSet::new(),
+            drafting: false,
+            tile_map_id: 0,
+            turn_orders: Vec::new(),
+            chose_index_users: HashSet::new(),
+            taken_indexes: HashSet::new(),
+            self_index: 0,
+            opened_indexes: HashSet::new(),
+        }
+    }
+
+    ///跑到压测结束
+    pub fn run(&mut self, end_time: Instant) {
+        while Instant::now() < end_time {
+            if self.stream.is_none() {
+                if let Err(e) = self.connect() {
+                    warn!("bot connect fail!user_id:{},{:?}", self.user_id, e);
+                    STATS.lock().unwrap().connect_fail += 1;
+                    std::thread::sleep(Duration::from_secs(1));
+                    continue;
+                }
+            }
+            if let Err(e) = self.tick() {
+                warn!("bot disconnect!user_id:{},{:?}", self.user_id, e);
+                self.close();
+                STATS.lock().unwrap().disconnects += 1;
+                std::thread::sleep(Duration::from_secs(1));
+            }
+        }
+        //压测结束,还在房间里面的先离开房间
+        if self.state == BotState::InRoom {
+            let bytes = C_LEAVE_ROOM::new().write_to_bytes().unwrap();
+            let _ = self.send(RoomCode::LeaveRoom.into_u32(), bytes);
+        }
+        self.close();
+    }
+
+    fn connect(&mut self) -> anyhow::Result<()> {
+        let stream = TcpStream::connect(self.conf.gate_address.as_str())?;
+        stream.set_nodelay(true)?;
+        stream.set_read_timeout(Some(Duration::from_millis(50)))?;
+        self.stream = Some(stream);
+        self.read_buf.clear();
+        self.pending.clear();
+        self.next_action = None;
+        self.state = BotState::Login;
+        STATS.lock().unwrap().online += 1;
+
+        let mut cul = C_USER_LOGIN::new();
+        cul.set_user_id(self.user_id);
+        cul.set_register_platform("test".to_owned());
+        cul.set_platform_value(format!("robot_{}", self.user_id));
+        self.request(GameCode::Login.into_u32(), cul.write_to_bytes()?, ClientCode::Login.into_u32())?;
+        Ok(())
+    }
+
+    fn close(&mut self) {
+        if let Some(stream) = self.stream.take() {
+            let _ = stream.shutdown(std::net::Shutdown::Both);
+            STATS.lock().unwrap().online -= 1;
+        }
+        self.state = BotState::Login;
+    }
+
+    ///读消息,检查超时,执行到时间的操作
+    fn tick(&mut self) -> anyhow::Result<()> {
+        self.read()?;
+
+        //检查超时
+        let now = Instant::now();
+        let timeout = Duration::from_millis(self.conf.request_timeout_ms);
+        let match_timeout = Duration::from_millis(self.conf.match_timeout_ms);
+        let mut timeout_v = Vec::new();
+        for (&res_cmd, &(req_cmd, time)) in self.pending.iter() {
+            let limit = if req_cmd == GameCode::SearchRoom.into_u32() {
+                match_timeout
+            } else {
+                timeout
+            };
+            if now.duration_since(time) > limit {
+                timeout_v.push((res_cmd, req_cmd));
+            }
+        }
+        for (res_cmd, req_cmd) in timeout_v {
+            self.pending.remove(&res_cmd);
+            STATS.lock().unwrap().record_timeout(req_cmd);
+            self.on_timeout(req_cmd);
+        }
+
+        //心跳
+        if self.state != BotState::Login
+            && now.duration_since(self.last_heart_beat).as_secs() >= self.conf.heart_beat_secs
+        {
+            self.last_heart_beat = now;
+            self.request(
+                ClientCode::HeartBeat.into_u32(),
+                Vec::new(),
+                ClientCode::HeartBeat.into_u32(),
+            )?;
+        }
+
+        //执行到时间的操作
+        if let Some((time, action)) = self.next_action {
+            if now >= time {
+                self.next_action = None;
+                self.do_action(action)?;
+            }
+        }
+        Ok(())
+    }
+
+    ///读取并拆包
+    fn read(&mut self) -> anyhow::Result<()> {
+        let mut bytes = [0; 8192];
+        let res = self.stream.as_mut().unwrap().read(&mut bytes);
+        match res {
+            Ok(0) => anyhow::bail!("connection closed by server"),
+            Ok(size) => self.read_buf.extend_from_slice(&bytes[..size]),
+            Err(e) if e.kind() == ErrorKind::WouldBlock || e.kind() == ErrorKind::TimedOut => {
+                return Ok(())
+            }
+            Err(e) => return Err(e.into()),
+        }
+        loop {
+            if self.read_buf.len() < HEAD_LEN {
+                return Ok(());
+            }
+            let mut len_bytes = [0; 4];
+            len_bytes.copy_from_slice(&self.read_buf[4..8]);
+            let len = u32::from_ne_bytes(len_bytes) as usize;
+            if len < HEAD_LEN {
+                anyhow::bail!("packet len is error!len:{}", len);
+            }
+            if self.read_buf.len() < len {
+                return Ok(());
+            }
+            let bytes: Vec<u8> = self.read_buf.drain(..len).collect();
+            let packet = Packet::from_only_client(bytes)?;
+            self.handle_packet(packet)?;
+        }
+    }
+
+    fn send(&mut self, cmd: u32, bytes: Vec<u8>) -> anyhow::Result<()> {
+        let mut packet = Packet::new(cmd, 0, 0);
+        packet.set_data_from_vec(bytes);
+        let bytes = packet.build_client_bytes();
+        self.stream.as_mut().unwrap().write_all(bytes.as_slice())?;
+        Ok(())
+    }
+
+    ///发送请求并等待对应的返回命令号来统计延迟
+    fn request(&mut self, cmd: u32, bytes: Vec<u8>, res_cmd: u32) -> anyhow::Result<()> {
+        self.send(cmd, bytes)?;
+        self.pending.insert(res_cmd, (cmd, Instant::now()));
+        Ok(())
+    }
+
+    ///收到返回,统计延迟,is_succ为false的算错误
+    fn response(&mut self, res_cmd: u32, is_succ: bool) {
+        let res = self.pending.remove(&res_cmd);
+        if res.is_none() {
+            return;
+        }
+        let (req_cmd, time) = res.unwrap();
+        let mut stats = STATS.lock().unwrap();
+        if is_succ {
+            stats.record_latency(req_cmd, time.elapsed().as_millis() as u64);
+        } else {
+            stats.record_error(req_cmd);
+        }
+    }
+
+    ///思考一会再执行操作
+    fn schedule(&mut self, action: BotAction) {
+        let (min, max) = self.conf.think_time_ms;
+        let think = if max > min {
+            rand::thread_rng().gen_range(min, max)
+        } else {
+            min
+        };
+        self.next_action = Some((Instant::now() + Duration::from_millis(think), action));
+    }
+
+    ///大厅里面根据场景决定下一步
+    fn schedule_lobby(&mut self) {
+        self.state = BotState::Lobby;
+        match self.scenario {
+            Scenario::Match => self.schedule(BotAction::Search),
+            Scenario::Custom => self.schedule(BotAction::CreateRoom),
+            Scenario::Idle => {}
+            Scenario::Login => self.schedule(BotAction::Relogin),
+        }
+    }
+
+    fn on_timeout(&mut self, req_cmd: u32) {
+        if req_cmd == RoomCode::ChoiceCharacter.into_u32() && !self.drafting {
+            //可能被别人抢了,换一个
+            self.taken_cters.insert(self.chose_cter);
+            self.chose_cter = 0;
+            self.schedule(BotAction::ChooseCharacter);
+        } else if req_cmd == GameCode::SearchRoom.into_u32()
+            || req_cmd == GameCode::CreateRoom.into_u32()
+        {
+            self.schedule_lobby();
+        }
+    }
+
+    fn do_action(&mut self, action: BotAction) -> anyhow::Result<()> {
+        match action {
+            BotAction::Search => {
+                let mut csr = C_SEARCH_ROOM::new();
+                csr.set_room_type(ROOM_TYPE_MATCH);
+                self.state = BotState::Searching;
+                self.request(
+                    GameCode::SearchRoom.into_u32(),
+                    csr.write_to_bytes()?,
+                    ClientCode::MatchSuccessNotice.into_u32(),
+                )?;
+            }
+            BotAction::CreateRoom => {
+                let mut ccr = C_CREATE_ROOM::new();
+                ccr.set_room_type(ROOM_TYPE_CUSTOM);
+                self.request(
+                    GameCode::CreateRoom.into_u32(),
+                    ccr.write_to_bytes()?,
+                    ClientCode::Room.into_u32(),
+                )?;
+            }
+            BotAction::ConfirmIntoRoom => {
+                let mut ccir = C_CONFIRM_INTO_ROOM::new();
+                ccir.set_confirm(true);
+                self.request(
+                    RoomCode::ConfirmIntoRoom.into_u32(),
+                    ccir.write_to_bytes()?,
+                    ClientCode::Room.into_u32(),
+                )?;
+            }
+            BotAction::ChooseCharacter => {
+                let cters: Vec<u32> = self
+                    .cters
+                    .iter()
+                    .map(|c| c.cter_temp_id)
+                    .filter(|id| !self.taken_cters.contains(id) && !self.banned_cters.contains(id))
+                    .collect();
+                if cters.is_empty() {
+                    warn!("bot has no character to choose!user_id:{}", self.user_id);
+                    return self.do_action(BotAction::LeaveRoom);
+                }
+                let index = rand::thread_rng().gen_range(0, cters.len());
+                self.chose_cter = cters[index];
+                let mut ccc = C_CHOOSE_CHARACTER::new();
+                ccc.set_cter_temp_id(self.chose_cter);
+                self.request(
+                    RoomCode::ChoiceCharacter.into_u32(),
+                    ccc.write_to_bytes()?,
+                    ClientCode::ChoiceCharacter.into_u32(),
+                )?;
+            }
+            BotAction::DraftBan => {
+                //优先禁自己没有的角色
+                let all: Vec<u32> = TEMPLATES
+                    .character_temp_mgr()
+                    .temps
+                    .keys()
+                    .copied()
+                    .filter(|id| !self.banned_cters.contains(id))
+                    .collect();
+                let not_owned: Vec<u32> = all
+                    .iter()
+                    .copied()
+                    .filter(|id| !self.cters.iter().any(|c| c.cter_temp_id == *id))
+                    .collect();
+                let cters = if not_owned.is_empty() { all } else { not_owned };
+                let mut cdb = C_DRAFT_BAN::new();
+                if !cters.is_empty() {
+                    let index = rand::thread_rng().gen_range(0, cters.len());
+                    cdb.set_cter_temp_id(cters[index]);
+                }
+                self.request(
+                    RoomCode::DraftBan.into_u32(),
+                    cdb.write_to_bytes()?,
+                    ClientCode::DraftBan.into_u32(),
+                )?;
+            }
+            BotAction::DraftPick => {
+                let cters: Vec<u32> = self
+                    .cters
+                    .iter()
+                    .map(|c| c.cter_temp_id)
+                    .filter(|id| !self.taken_cters.contains(id) && !self.banned_cters.contains(id))
+                    .collect();
+                if cters.is_empty() {
+                    //没得选了,等服务器超时跳过
+                    warn!("bot has no character to pick!user_id:{}", self.user_id);
+                    return Ok(());
+                }
+                let index = rand::thread_rng().gen_range(0, cters.len());
+                let mut cdp = C_DRAFT_PICK::new();
+                cdp.set_cter_temp_id(cters[index]);
+                self.request(
+                    RoomCode::DraftPick.into_u32(),
+                    cdp.write_to_bytes()?,
+                    ClientCode::DraftPick.into_u32(),
+                )?;
+            }
+            BotAction::ChooseSkill => {
+                let mut ccs = C_CHOOSE_SKILL::new();
+                ccs.set_skills(self.random_skills());
+                self.request(
+                    RoomCode::ChoiceSkill.into_u32(),
+                    ccs.write_to_bytes()?,
+                    ClientCode::ChoiceSkill.into_u32(),
+                )?;
+            }
+            BotAction::Prepare => {
+                let mut cpc = C_PREPARE_CANCEL::new();
+                cpc.set_prepare(true);
+                self.request(
+                    RoomCode::PrepareCancel.into_u32(),
+                    cpc.write_to_bytes()?,
+                    ClientCode::PrepareCancel.into_u32(),
+                )?;
+            }
+            BotAction::LeaveRoom => {
+                self.request(
+                    RoomCode::LeaveRoom.into_u32(),
+                    C_LEAVE_ROOM::new().write_to_bytes()?,
+                    ClientCode::LeaveRoom.into_u32(),
+                )?;
+            }
+            BotAction::ChooseIndex => {
+                let indexes = self.usable_indexes(&self.taken_indexes);
+                if indexes.is_empty() {
+                    return Ok(());
+                }
+                let index = indexes[rand::thread_rng().gen_range(0, indexes.len())];
+                let mut cci = C_CHOOSE_INDEX::new();
+                cci.set_index(index);
+                self.request(
+                    BattleCode::ChoiceIndex.into_u32(),
+                    cci.write_to_bytes()?,
+                    ClientCode::ChoiceIndexNotice.into_u32(),
+                )?;
+            }
+            BotAction::Open => {
+                let mut exclude = self.opened_indexes.clone();
+                exclude.insert(self.self_index);
+                let indexes = self.usable_indexes(&exclude);
+                if indexes.is_empty() {
+                    return self.do_action(BotAction::Skip);
+                }
+                let index = indexes[rand::thread_rng().gen_range(0, indexes.len())];
+                self.opened_indexes.insert(index);
+                let mut ca = C_ACTION::new();
+                ca.set_action_type(ACTION_OPEN);
+                ca.set_value(index);
+                self.request(
+                    BattleCode::Action.into_u32(),
+                    ca.write_to_bytes()?,
+                    ClientCode::ActionNotice.into_u32(),
+                )?;
+                self.schedule(BotAction::Skip);
+            }
+            BotAction::Skip => {
+                let mut ca = C_ACTION::new();
+                ca.set_action_type(ACTION_SKIP);
+                self.request(
+                    BattleCode::Action.into_u32(),
+                    ca.write_to_bytes()?,
+                    ClientCode::BattleTurnNotice.into_u32(),
+                )?;
+            }
+            BotAction::Relogin => {
+                self.close();
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_packet(&mut self, packet: Packet) -> anyhow::Result<()> {
+        let cmd = packet.get_cmd();
+        let data = packet.get_data();
+        match ClientCode::try_from(cmd) {
+            Ok(ClientCode::Login) => {
+                let mut sul = S_USER_LOGIN::new();
+                sul.merge_from_bytes(data)?;
+                self.response(cmd, sul.is_succ);
+                if !sul.is_succ {
+                    anyhow::bail!("login fail!{}", sul.err_mess);
+                }
+                self.cters = sul.cters.to_vec();
+                self.last_heart_beat = Instant::now();
+                self.schedule_lobby();
+            }
+            Ok(ClientCode::HeartBeat) => self.response(cmd, true),
+            Ok(ClientCode::MatchSuccessNotice) => {
+                self.response(cmd, true);
+                self.schedule(BotAction::ConfirmIntoRoom);
+            }
+            Ok(ClientCode::IntoRoomCancelNotice) => {
+                //有人没确认,重新匹配
+                self.schedule_lobby();
+            }
+            Ok(ClientCode::Room) => {
+                let mut sr = S_ROOM::new();
+                sr.merge_from_bytes(data)?;
+                self.response(cmd, sr.is_succ);
+                if !sr.is_succ {
+                    self.schedule_lobby();
+                    return Ok(());
+                }
+                let first_into = self.state != BotState::InRoom;
+                self.state = BotState::InRoom;
+                self.taken_cters.clear();
+                if first_into {
+                    self.banned_cters.clear();
+                    self.drafting = false;
+                }
+                for member in sr.get_room().members.iter() {
+                    if member.user_id == self.user_id {
+                        continue;
+                    }
+                    let cter_temp_id = member.get_cter().cter_temp_id;
+                    if cter_temp_id > 0 {
+                        self.taken_cters.insert(cter_temp_id);
+                    }
+                }
+                if first_into {
+                    self.chose_cter = 0;
+                    self.schedule(BotAction::ChooseCharacter);
+                }
+            }
+            Ok(ClientCode::ChoiceCharacterNotice) => {
+                let mut sccn = S_CHOOSE_CHARACTER_NOTICE::new();
+                sccn.merge_from_bytes(data)?;
+                if sccn.user_id != self.user_id {
+                    self.taken_cters.insert(sccn.cter_temp_id);
+                }
+            }
+            Ok(ClientCode::ChoiceCharacter) => {
+                let mut scc = S_CHOOSE_CHARACTER::new();
+                scc.merge_from_bytes(data)?;
+                self.response(cmd, scc.is_succ);
+                if scc.is_succ {
+                    self.schedule(BotAction::ChooseSkill);
+                } else {
+                    self.taken_cters.insert(self.chose_cter);
+                    self.schedule(BotAction::ChooseCharacter);
+                }
+            }
+            Ok(ClientCode::DraftNotice) => {
+                let mut sdn = S_DRAFT_NOTICE::new();
+                sdn.merge_from_bytes(data)?;
+                self.banned_cters = sdn.bans.iter().copied().collect();
+                let mut self_pick = 0;
+                for pick in sdn.picks.iter() {
+                    if pick.user_id == self.user_id {
+                        self_pick = pick.cter_temp_id;
+                    } else {
+                        self.taken_cters.insert(pick.cter_temp_id);
+                    }
+                }
+                if sdn.phase == DRAFT_PHASE_FINISH {
+                    self.drafting = false;
+                    if self_pick > 0 {
+                        self.chose_cter = self_pick;
+                        self.schedule(BotAction::ChooseSkill);
+                    } else {
+                        self.chose_cter = 0;
+                        self.schedule(BotAction::ChooseCharacter);
+                    }
+                    return Ok(());
+                }
+                //禁选阶段不能自己选角色
+                self.drafting = true;
+                self.pending.remove(&ClientCode::ChoiceCharacter.into_u32());
+                if let Some((_, BotAction::ChooseCharacter)) = self.next_action {
+                    self.next_action = None;
+                }
+                if sdn.user_id == self.user_id {
+                    if sdn.phase == DRAFT_PHASE_BAN {
+                        self.schedule(BotAction::DraftBan);
+                    } else {
+                        self.schedule(BotAction::DraftPick);
+                    }
+                }
+            }
+            Ok(ClientCode::DraftBan) => {
+                let mut sdb = S_DRAFT_BAN::new();
+                sdb.merge_from_bytes(data)?;
+                self.response(cmd, sdb.is_succ);
+            }
+            Ok(ClientCode::DraftPick) => {
+                let mut sdp = S_DRAFT_PICK::new();
+                sdp.merge_from_bytes(data)?;
+                self.response(cmd, sdp.is_succ);
+            }
+            Ok(ClientCode::ChoiceSkill) => {
+                let mut scs = S_CHOOSE_SKILL::new();
+                scs.merge_from_bytes(data)?;
+                self.response(cmd, scs.is_succ);
+                match self.scenario {
+                    Scenario::Match => self.schedule(BotAction::Prepare),
+                    _ => self.schedule(BotAction::LeaveRoom),
+                }
+            }
+            Ok(ClientCode::PrepareCancel) => {
+                let mut spc = S_PREPARE_CANCEL::new();
+                spc.merge_from_bytes(data)?;
+                self.response(cmd, spc.is_succ);
+            }
+            Ok(ClientCode::LeaveRoom) => {
+                let mut slr = S_LEAVE_ROOM::new();
+                slr.merge_from_bytes(data)?;
+                self.response(cmd, slr.is_succ);
+                if self.state == BotState::InRoom {
+                    self.schedule_lobby();
+                }
+            }
+            Ok(ClientCode::StartNotice) => {
+                let mut ssn = S_START_NOTICE::new();
+                ssn.merge_from_bytes(data)?;
+                self.state = BotState::ChoiceIndex;
+                self.tile_map_id = ssn.tile_map_id;
+                self.turn_orders = ssn.turn_order.to_vec();
+                self.chose_index_users.clear();
+                self.taken_indexes.clear();
+                self.opened_indexes.clear();
+                self.self_index = 0;
+                STATS.lock().unwrap().battles_started += 1;
+                self.check_choice_index();
+            }
+            Ok(ClientCode::ChoiceIndexNotice) => {
+                let mut scin = S_CHOOSE_INDEX_NOTICE::new();
+                scin.merge_from_bytes(data)?;
+                self.chose_index_users.insert(scin.user_id);
+                self.taken_indexes.insert(scin.index);
+                if scin.user_id == self.user_id {
+                    self.self_index = scin.index;
+                    self.response(cmd, true);
+                }
+                self.check_choice_index();
+            }
+            Ok(ClientCode::BattleStartedNotice) => {
+                self.state = BotState::Battle;
+            }
+            Ok(ClientCode::BattleTurnNotice) => {
+                let mut sbtn = S_BATTLE_TURN_NOTICE::new();
+                sbtn.merge_from_bytes(data)?;
+                self.response(cmd, true);
+                self.state = BotState::Battle;
+                if sbtn.user_id == self.user_id {
+                    self.schedule(BotAction::Open);
+                } else if let Some((_, BotAction::Skip)) = self.next_action {
+                    //已经不是自己的turn了
+                    self.next_action = None;
+                }
+            }
+            Ok(ClientCode::ActionNotice) => self.response(cmd, true),
+            Ok(ClientCode::SummaryNotice) => {
+                STATS.lock().unwrap().battles_finished += 1;
+                info!("bot battle finished!user_id:{}", self.user_id);
+                self.pending.clear();
+                self.schedule_lobby();
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    ///按turn顺序轮到自己就选占位
+    fn check_choice_index(&mut self) {
+        if self.state != BotState::ChoiceIndex || self.chose_index_users.contains(&self.user_id) {
+            return;
+        }
+        let next = self
+            .turn_orders
+            .iter()
+            .find(|id| **id > 0 && !self.chose_index_users.contains(*id));
+        if next == Some(&self.user_id) {
+            self.schedule(BotAction::ChooseIndex);
+        }
+    }
+
+    ///地图上可以选择的块
+    fn usable_indexes(&self, exclude: &HashSet<u32>) -> Vec<u32> {
+        let mut res = Vec::new();
+        let tile_map_temp = TEMPLATES.tile_map_temp_mgr().get_temp(self.tile_map_id);
+        if let Err(e) = tile_map_temp {
+            warn!("{:?}", e);
+            return res;
+        }
+        let tile_map_temp = tile_map_temp.unwrap();
+        for (index, &cell_type) in tile_map_temp.map.iter().enumerate() {
+            let index = index as u32;
+            if cell_type != MAP_CELL_VALID || exclude.contains(&index) {
+                continue;
+            }
+            if tile_map_temp.world_cell > 0 && tile_map_temp.world_cell_index == index as usize {
+                continue;
+            }
+            res.push(index);
+        }
+        res
+    }
+
+    ///每个技能组随机一个已解锁的技能
+    fn random_skills(&self) -> Vec<u32> {
+        let mut res = Vec::new();
+        let cter = self
+            .cters
+            .iter()
+            .find(|c| c.cter_temp_id == self.chose_cter);
+        let cter_temp = TEMPLATES
+            .character_temp_mgr()
+            .get_temp_ref(&self.chose_cter);
+        if cter.is_none() || cter_temp.is_none() {
+            return res;
+        }
+        let cter = cter.unwrap();
+        let cter_temp = cter_temp.unwrap();
+        let mut rand = rand::thread_rng();
+        for group in cter_temp.skills.iter() {
+            if res.len() >= cter_temp.usable_skill_count as usize {
+                break;
+            }
+            let skills: Vec<u32> = group
+                .group
+                .iter()
+                .filter(|id| cter.skills.contains(*id))
+                .copied()
+                .collect();
+            if skills.is_empty() {
+                continue;
+            }
+            res.push(skills[rand.gen_range(0, skills.len())]);
+        }
+        res
+    }
+}
@@ -0,0 +1,236 @@
+pub mod bot;
+pub mod stats;
+
+use crate::load_test::bot::Bot;
+use crate::load_test::stats::STATS;
+use crate::CONF_MAP;
+use log::{info, warn};
+use rand::Rng;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tools::json::{JsonValue, JsonValueTrait};
+use tools::redis_pool::RedisPoolTool;
+
+///玩家数据的redis索引
+const REDIS_INDEX_USERS: u32 = 0;
+///玩家数据的key
+const REDIS_KEY_USERS: &str = "users";
+///user_id到pid的key
+const REDIS_KEY_UID_2_PID: &str = "uid_2_pid";
+
+///压测场景
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Scenario {
+    Match,  //匹配并打完整场战斗
+    Custom, //创建自定义房间,选完角色技能就离开
+    Idle,   //登录之后只发心跳
+    Login,  //反复登录断开
+}
+
+impl Scenario {
+    pub fn from_str(name: &str) -> Option<Scenario> {
+        match name {
+            "match" => Some(Scenario::Match),
+            "custom" => Some(Scenario::Custom),
+            "idle" => Some(Scenario::Idle),
+            "login" => Some(Scenario::Login),
+            _ => None,
+        }
+    }
+}
+
+///压测配置,对应config.conf里面的load_test
+#[derive(Debug, Clone)]
+pub struct LoadTestConfig {
+    pub gate_address: String,          //网关地址
+    pub bot_count: u32,                //机器人数量
+    pub user_id_start: u32,            //机器人起始user_id
+    pub seed_accounts: bool,           //是否往redis里面写入机器人账号,会写进正式的账号表,只在压测环境打开
+    pub ramp_up_secs: u64,             //多少秒内把机器人全部拉起来
+    pub duration_secs: u64,            //压测持续时间
+    pub report_interval_secs: u64,     //报告间隔
+    pub request_timeout_ms: u64,       //请求超时时间
+    pub match_timeout_ms: u64,         //匹配超时时间
+    pub heart_beat_secs: u64,          //心跳间隔
+    pub think_time_ms: (u64, u64),     //每次操作之前的思考时间范围
+    pub scenarios: Vec<(Scenario, u32)>, //场景以及权重
+}
+
+impl Default for LoadTestConfig {
+    fn default() -> Self {
+        LoadTestConfig {
+            gate_address: "127.0.0.1:16801".to_owned(),
+            bot_count: 100,
+            user_id_start: 900000,
+            seed_accounts: false,
+            ramp_up_secs: 30,
+            duration_secs: 300,
+            report_interval_secs: 10,
+            request_timeout_ms: 5000,
+            match_timeout_ms: 60000,
+            heart_beat_secs: 10,
+            think_time_ms: (200, 800),
+            scenarios: vec![(Scenario::Match, 1)],
+        }
+    }
+}
+
+impl LoadTestConfig {
+    ///从配置文件读取
+    pub fn from_conf() -> Self {
+        let mut conf = LoadTestConfig::default();
+        let value = CONF_MAP.conf.get("load_test");
+        if value.is_none() {
+            warn!("load_test config is not find!use default config!");
+            return conf;
+        }
+        let value: &JsonValue = value.unwrap();
+        if let Some(v) = value.get_str("gate_address") {
+            conf.gate_address = v.to_owned();
+        }
+        if let Some(v) = value.get_u32("bot_count") {
+            conf.bot_count = v;
+        }
+        if let Some(v) = value.get_u32("user_id_start") {
+            conf.user_id_start = v;
+        }
+        if let Some(v) = value.get_bool("seed_accounts") {
+            conf.seed_accounts = v;
+        }
+        if let Some(v) = value.get_u64("ramp_up_secs") {
+            conf.ramp_up_secs = v;
+        }
+        if let Some(v) = value.get_u64("duration_secs") {
+            conf.duration_secs = v;
+        }
+        if let Some(v) = value.get_u64("report_interval_secs") {
+            conf.report_interval_secs = v;
+        }
+        if let Some(v) = value.get_u64("request_timeout_ms") {
+            conf.request_timeout_ms = v;
+        }
+        if let Some(v) = value.get_u64("match_timeout_ms") {
+            conf.match_timeout_ms = v;
+        }
+        if let Some(v) = value.get_u64("heart_beat_secs") {
+            conf.heart_beat_secs = v;
+        }
+        if let Some(v) = value.get_array("think_time_ms") {
+            let min = v.get(0).and_then(|v| v.as_u64()).unwrap_or(0);
+            let max = v.get(1).and_then(|v| v.as_u64()).unwrap_or(min);
+            conf.think_time_ms = (min, std::cmp::max(min, max));
+        }
+        if let Some(v) = value.get_object("scenarios") {
+            let mut scenarios = Vec::new();
+            for (name, weight) in v.iter() {
+                let scenario = Scenario::from_str(name.as_str());
+                if scenario.is_none() {
+                    warn!("unknown load_test scenario:{}", name);
+                    continue;
+                }
+                let weight = weight.as_u64().unwrap_or(0) as u32;
+                if weight == 0 {
+                    continue;
+                }
+                scenarios.push((scenario.unwrap(), weight));
+            }
+            if !scenarios.is_empty() {
+                conf.scenarios = scenarios;
+            }
+        }
+        conf
+    }
+
+    ///按权重随机场景
+    pub fn random_scenario(&self) -> Scenario {
+        let total: u32 = self.scenarios.iter().map(|(_, w)| *w).sum();
+        let mut value = rand::thread_rng().gen_range(0, total);
+        for &(scenario, weight) in self.scenarios.iter() {
+            if value < weight {
+                return scenario;
+            }
+            value -= weight;
+        }
+        self.scenarios.last().unwrap().0
+    }
+}
+
+///往redis写入机器人账号,网关和游戏服登录的时候会校验
+///直接写users和uid_2_pid,不要连着正式服的redis打开
+fn seed_accounts(conf: &LoadTestConfig) {
+    let add = CONF_MAP.get_str("redis_add", "");
+    let pass = CONF_MAP.get_str("redis_pass", "");
+    let mut redis = RedisPoolTool::init(add.as_str(), pass.as_str());
+    for user_id in conf.user_id_start..conf.user_id_start + conf.bot_count {
+        let pid = format!("robot_{}", user_id);
+        let mut value = JsonValue::new();
+        value.insert("user_id".to_owned(), JsonValue::from(user_id));
+        value.insert("nick_name".to_owned(), JsonValue::from(pid.clone()));
+        value.insert("on_line".to_owned(), JsonValue::from(false));
+        let _: Option<u32> = redis.hset(
+            REDIS_INDEX_USERS,
+            REDIS_KEY_UID_2_PID,
+            user_id.to_string().as_str(),
+            pid.as_str(),
+        );
+        let _: Option<u32> = redis.hset(
+            REDIS_INDEX_USERS,
+            REDIS_KEY_USERS,
+            pid.as_str(),
+            value.to_string().as_str(),
+        );
+    }
+    info!("写入机器人账号完成!数量:{}", conf.bot_count);
+}
+
+///开始压测,阻塞到压测结束
+pub fn run(conf: LoadTestConfig) {
+    info!("开始压测!配置:{:?}", conf);
+    if conf.seed_accounts {
+        warn!("seed_accounts已打开,机器人账号会写进redis的账号表!");
+        seed_accounts(&conf);
+    }
+    let conf = Arc::new(conf);
+    let start_time = Instant::now();
+    let end_time = start_time + Duration::from_secs(conf.ramp_up_secs + conf.duration_secs);
+
+    //定时输出报告
+    let report_interval = Duration::from_secs(std::cmp::max(1, conf.report_interval_secs));
+    std::thread::spawn(move || loop {
+        std::thread::sleep(report_interval);
+        if Instant::now() >= end_time {
+            break;
+        }
+        let stats = STATS.lock().unwrap();
+        info!("{}", stats.report(start_time.elapsed()));
+    });
+
+    //按照ramp_up均匀拉起机器人
+    let ramp_step = if conf.bot_count > 0 {
+        Duration::from_millis(conf.ramp_up_secs * 1000 / conf.bot_count as u64)
+    } else {
+        Duration::from_millis(0)
+    };
+    let mut handles = Vec::new();
+    for i in 0..conf.bot_count {
+        let user_id = conf.user_id_start + i;
+        let scenario = conf.random_scenario();
+        let bot_conf = conf.clone();
+        let handle = std::thread::Builder::new()
+            .name(format!("BOT_{}", user_id))
+            .spawn(move || {
+                let mut bot = Bot::new(user_id, scenario, bot_conf);
+                bot.run(end_time);
+            });
+        match handle {
+            Ok(handle) => handles.push(handle),
+            Err(e) => warn!("spawn bot thread fail!user_id:{},{:?}", user_id, e),
+        }
+        std::thread::sleep(ramp_step);
+    }
+    for handle in handles {
+        let _ = handle.join();
+    }
+    let stats = STATS.lock().unwrap();
+    info!("压测结束!{}", stats.report(start_time.elapsed()));
+}
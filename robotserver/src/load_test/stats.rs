@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::sync::Mutex;
+use std::time::Duration;
+use tools::cmd_code::{BattleCode, ClientCode, GameCode, RoomCode};
+
+///延迟直方图桶的数量,1ms一个桶,超过10秒的都算到最后一个桶
+const BUCKET_SIZE: usize = 10001;
+
+lazy_static! {
+    ///压测统计数据
+    pub static ref STATS: Mutex<LoadStats> = Mutex::new(LoadStats::default());
+}
+
+///单个命令号的统计数据
+#[derive(Debug, Clone)]
+pub struct CmdStats {
+    pub count: u64,    //成功返回的数量
+    pub errors: u64,   //返回失败的数量
+    pub timeouts: u64, //超时没返回的数量
+    pub total_ms: u64, //总延迟
+    pub max_ms: u64,   //最大延迟
+    buckets: Vec<u32>, //延迟直方图
+}
+
+impl Default for CmdStats {
+    fn default() -> Self {
+        CmdStats {
+            count: 0,
+            errors: 0,
+            timeouts: 0,
+            total_ms: 0,
+            max_ms: 0,
+            buckets: vec![0; BUCKET_SIZE],
+        }
+    }
+}
+
+impl CmdStats {
+    pub fn record(&mut self, ms: u64) {
+        self.count += 1;
+        self.total_ms += ms;
+        if ms > self.max_ms {
+            self.max_ms = ms;
+        }
+        let index = std::cmp::min(ms as usize, BUCKET_SIZE - 1);
+        self.buckets[index] += 1;
+    }
+
+    ///计算百分位延迟,p取值0-100
+    pub fn percentile(&self, p: f64) -> u64 {
+        if self.count == 0 {
+            return 0;
+        }
+        let target = ((self.count as f64) * p / 100.0).ceil() as u64;
+        let mut sum = 0;
+        for (ms, &num) in self.buckets.iter().enumerate() {
+            sum += num as u64;
+            if sum >= target {
+                return ms as u64;
+            }
+        }
+        self.max_ms
+    }
+
+    pub fn avg(&self) -> u64 {
+        if self.count == 0 {
+            return 0;
+        }
+        self.total_ms / self.count
+    }
+
+    ///错误率(返回失败+超时)
+    pub fn error_rate(&self) -> f64 {
+        let total = self.count + self.errors + self.timeouts;
+        if total == 0 {
+            return 0.0;
+        }
+        (self.errors + self.timeouts) as f64 * 100.0 / total as f64
+    }
+}
+
+///压测统计
+#[derive(Debug, Default)]
+pub struct LoadStats {
+    pub cmds: HashMap<u32, CmdStats>, //key:请求命令号
+    pub online: u32,                  //当前在线机器人数量
+    pub connect_fail: u64,            //连接失败次数
+    pub disconnects: u64,             //被断开次数
+    pub battles_started: u64,         //开始的战斗数量
+    pub battles_finished: u64,        //结算的战斗数量
+}
+
+impl LoadStats {
+    pub fn record_latency(&mut self, cmd: u32, ms: u64) {
+        self.cmds.entry(cmd).or_default().record(ms);
+    }
+
+    pub fn record_error(&mut self, cmd: u32) {
+        self.cmds.entry(cmd).or_default().errors += 1;
+    }
+
+    pub fn record_timeout(&mut self, cmd: u32) {
+        self.cmds.entry(cmd).or_default().timeouts += 1;
+    }
+
+    ///生成报告
+    pub fn report(&self, elapsed: Duration) -> String {
+        let mut res = format!(
+            "\n压测报告 耗时:{}s 在线:{} 连接失败:{} 断开:{} 开始战斗:{} 结算战斗:{}\n",
+            elapsed.as_secs(),
+            self.online,
+            self.connect_fail,
+            self.disconnects,
+            self.battles_started,
+            self.battles_finished
+        );
+        res.push_str(
+            format!(
+                "{:<24}{:>8}{:>8}{:>8}{:>8}{:>8}{:>8}{:>8}{:>8}{:>8}\n",
+                "cmd", "count", "err", "timeout", "err%", "avg", "p50", "p90", "p99", "max"
+            )
+            .as_str(),
+        );
+        let mut cmds: Vec<&u32> = self.cmds.keys().collect();
+        cmds.sort();
+        for cmd in cmds {
+            let cs = self.cmds.get(cmd).unwrap();
+            res.push_str(
+                format!(
+                    "{:<24}{:>8}{:>8}{:>8}{:>8.2}{:>8}{:>8}{:>8}{:>8}{:>8}\n",
+                    cmd_name(*cmd),
+                    cs.count,
+                    cs.errors,
+                    cs.timeouts,
+                    cs.error_rate(),
+                    cs.avg(),
+                    cs.percentile(50.0),
+                    cs.percentile(90.0),
+                    cs.percentile(99.0),
+                    cs.max_ms
+                )
+                .as_str(),
+            );
+        }
+        res
+    }
+}
+
+///命令号转换成可读的名字
+pub fn cmd_name(cmd: u32) -> String {
+    if let Ok(code) = GameCode::try_from(cmd) {
+        return format!("{:?}({})", code, cmd);
+    }
+    if let Ok(code) = RoomCode::try_from(cmd) {
+        return format!("{:?}({})", code, cmd);
+    }
+    if let Ok(code) = BattleCode::try_from(cmd) {
+        return format!("{:?}({})", code, cmd);
+    }
+    if let Ok(code) = ClientCode::try_from(cmd) {
+        return format!("{:?}({})", code, cmd);
+    }
+    cmd.to_string()
+}
@@ -2,6 +2,7 @@ pub mod battle;
 pub mod fsm;
 pub mod goal_ai;
 pub mod handlers;
+pub mod load_test;
 pub mod mgr;
 pub mod net;
 
@@ -19,6 +20,7 @@ use std::sync::{Arc, Mutex};
 use std::{env, time};
 use tools::conf::Conf;
 use tools::tcp::tcp_server;
+use tools::templates::template::{init_temps_mgr, TemplatesMgr};
 
 #[macro_use]
 extern crate lazy_static;
@@ -33,6 +35,16 @@ lazy_static! {
         let conf = Conf::init(res.as_str());
         conf
     };
+
+    ///静态配置文件,压测机器人选技能和地图块用
+    static ref TEMPLATES: TemplatesMgr = {
+        let path = env::current_dir().unwrap();
+        let str = path.as_os_str().to_str().unwrap();
+        let default = str.to_string() + "/template";
+        let res = CONF_MAP.get_str("template_path", "");
+        let res = if res.is_empty() { default } else { res };
+        init_temps_mgr(res.as_str())
+    };
 }
 
 fn main() {
@@ -42,6 +54,13 @@ fn main() {
     let error_log = &CONF_MAP.get_str("error_log_path","");
     //初始化日志
     init_log(info_log, error_log);
+    //压测模式,模拟客户端走网关打满整个流程
+    let mode = CONF_MAP.get_str("mode", "robot");
+    if mode == "load_test" || env::args().any(|arg| arg == "load_test") {
+        lazy_static::initialize(&TEMPLATES);
+        load_test::run(load_test::LoadTestConfig::from_conf());
+        return;
+    }
     let rm = Arc::new(Mutex::new(RobotMgr::new()));
     ///初始化机器人服务器网络
     init_tcp_server(rm.clone());
@@ -0,0 +1,291 @@
+use crate::mgr::room_mgr::RoomMgr;
+use crate::room::member::Member;
+use crate::room::room::ChatChannel;
+use crate::room::room_model::RoomType;
+use crate::{REDIS_INDEX_USERS, REDIS_KEY_CHAT_MUTE, REDIS_POOL};
+use log::{error, warn};
+use protobuf::Message;
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+use tools::cmd_code::ClientCode;
+use tools::protos::base::NoticeMessPt;
+use tools::protos::room::{C_CHAT_MUTE, S_CHAT, S_CHAT_MUTE, S_CHAT_NOTICE};
+use tools::protos::server_protocol::G_R_CHAT;
+use tools::util::packet::Packet;
+
+///每个玩家最多屏蔽的人数
+const CHAT_IGNORE_MAX: usize = 100;
+
+///聊天,游戏服已经校验过禁言,限流和屏蔽字,这里按频道分发
+pub fn chat(rm: &mut RoomMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = G_R_CHAT::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    //发送者以包头为准,不信任消息体里的
+    let mut mess = proto.take_mess();
+    mess.set_user_id(user_id);
+    let res = dispatch_chat(rm, user_id, mess);
+    let mut sc = S_CHAT::new();
+    match res {
+        Ok(_) => sc.set_is_succ(true),
+        Err(e) => {
+            warn!("{:?}", e);
+            sc.set_is_succ(false);
+            sc.set_err_mess(e.to_string());
+        }
+    }
+    match sc.write_to_bytes() {
+        Ok(bytes) => rm.send_2_client(ClientCode::Chat, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+///按频道找到接收者,屏蔽了发送者的人收不到
+fn dispatch_chat(rm: &mut RoomMgr, user_id: u32, mess: NoticeMessPt) -> anyhow::Result<()> {
+    let channel = ChatChannel::try_from(mess.get_channel() as u8);
+    if channel.is_err() {
+        anyhow::bail!("chat channel is error!channel:{}", mess.get_channel())
+    }
+    let channel = channel.unwrap();
+    if channel == ChatChannel::Whisper {
+        return whisper(rm, user_id, mess);
+    }
+    let room = rm.get_room_mut_by_user_id(&user_id);
+    if room.is_none() {
+        anyhow::bail!("this player is not in room!user_id:{}", user_id)
+    }
+    let room = room.unwrap();
+    let member = room.get_member_ref(&user_id);
+    if member.is_none() {
+        anyhow::bail!("this player is not in room!user_id:{}", user_id)
+    }
+    let team_id = member.unwrap().team_id;
+    let res = chat_receivers(
+        channel,
+        room.get_room_type(),
+        room.is_started(),
+        &room.members,
+        team_id,
+    );
+    let receivers = match res {
+        Ok(receivers) => receivers,
+        Err(e) => anyhow::bail!("{:?},room_id:{}", e, room.get_room_id()),
+    };
+    //队伍消息不进房间记录,免得补发给别的队伍
+    if channel != ChatChannel::Team {
+        room.push_chat_history(&mess);
+    }
+    let mut scn = S_CHAT_NOTICE::new();
+    scn.set_mess(mess);
+    let bytes = scn.write_to_bytes()?;
+    for receiver in receivers {
+        if receiver != user_id && is_ignored(rm, receiver, user_id) {
+            continue;
+        }
+        rm.send_2_client(ClientCode::ChatNotice, receiver, bytes.clone());
+    }
+    Ok(())
+}
+
+///按频道校验房间状态,返回要收到消息的真人
+fn chat_receivers(
+    channel: ChatChannel,
+    room_type: RoomType,
+    is_started: bool,
+    members: &HashMap<u32, Member>,
+    team_id: u8,
+) -> anyhow::Result<Vec<u32>> {
+    match channel {
+        ChatChannel::Room if is_started => anyhow::bail!("battle is started!"),
+        ChatChannel::Battle if !is_started => anyhow::bail!("battle is not started!"),
+        ChatChannel::Team
+            if !is_started || !(room_type.is_world_boss_type() || room_type.is_team_type()) =>
+        {
+            anyhow::bail!("team channel is only for world boss or team battle!")
+        }
+        ChatChannel::Whisper => anyhow::bail!("whisper has no room receivers!"),
+        _ => {}
+    }
+    let receivers = members
+        .values()
+        .filter(|member| member.robot_temp_id == 0)
+        .filter(|member| channel != ChatChannel::Team || member.team_id == team_id)
+        .map(|member| member.user_id)
+        .collect();
+    Ok(receivers)
+}
+
+///私聊,由中心服路由到目标玩家所在的网关,同时回显给自己
+fn whisper(rm: &mut RoomMgr, user_id: u32, mess: NoticeMessPt) -> anyhow::Result<()> {
+    let target_id = mess.get_target_id();
+    if target_id == 0 || target_id == user_id {
+        anyhow::bail!("whisper target_id is error!target_id:{}", target_id)
+    }
+    let mut scn = S_CHAT_NOTICE::new();
+    scn.set_mess(mess);
+    let bytes = scn.write_to_bytes()?;
+    if !is_ignored(rm, target_id, user_id) {
+        rm.send_2_client(ClientCode::ChatNotice, target_id, bytes.clone());
+    }
+    rm.send_2_client(ClientCode::ChatNotice, user_id, bytes);
+    Ok(())
+}
+
+///屏蔽/取消屏蔽某个玩家的聊天
+pub fn chat_mute(rm: &mut RoomMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = C_CHAT_MUTE::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let target_id = proto.get_target_id();
+    let is_mute = proto.get_is_mute();
+    let res = do_chat_mute(rm, user_id, target_id, is_mute);
+    let mut scm = S_CHAT_MUTE::new();
+    scm.set_target_id(target_id);
+    scm.set_is_mute(is_mute);
+    match res {
+        Ok(_) => scm.set_is_succ(true),
+        Err(e) => {
+            warn!("{:?}", e);
+            scm.set_is_succ(false);
+            scm.set_err_mess(e.to_string());
+        }
+    }
+    match scm.write_to_bytes() {
+        Ok(bytes) => rm.send_2_client(ClientCode::ChatMute, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+fn do_chat_mute(
+    rm: &mut RoomMgr,
+    user_id: u32,
+    target_id: u32,
+    is_mute: bool,
+) -> anyhow::Result<()> {
+    if target_id == 0 || target_id == user_id {
+        anyhow::bail!("target_id is error!target_id:{}", target_id)
+    }
+    let ignore = get_chat_ignore(rm, user_id);
+    if is_mute {
+        if !ignore.contains(&target_id) && ignore.len() >= CHAT_IGNORE_MAX {
+            anyhow::bail!("chat ignore list is full!user_id:{}", user_id)
+        }
+        ignore.insert(target_id);
+    } else {
+        ignore.remove(&target_id);
+    }
+    //存redis,下次上线还在
+    let json = serde_json::to_string(ignore)?;
+    let mut redis_lock = REDIS_POOL.lock().unwrap();
+    let _: Option<u32> = redis_lock.hset(
+        REDIS_INDEX_USERS,
+        REDIS_KEY_CHAT_MUTE,
+        user_id.to_string().as_str(),
+        json.as_str(),
+    );
+    Ok(())
+}
+
+///user_id是否屏蔽了sender_id
+fn is_ignored(rm: &mut RoomMgr, user_id: u32, sender_id: u32) -> bool {
+    get_chat_ignore(rm, user_id).contains(&sender_id)
+}
+
+///玩家的屏蔽列表,内存里没有就从redis加载
+fn get_chat_ignore(rm: &mut RoomMgr, user_id: u32) -> &mut HashSet<u32> {
+    rm.chat_ignore
+        .entry(user_id)
+        .or_insert_with(|| load_chat_ignore(user_id))
+}
+
+fn load_chat_ignore(user_id: u32) -> HashSet<u32> {
+    let mut redis_lock = REDIS_POOL.lock().unwrap();
+    let res: Option<String> = redis_lock.hget(
+        REDIS_INDEX_USERS,
+        REDIS_KEY_CHAT_MUTE,
+        user_id.to_string().as_str(),
+    );
+    match res {
+        Some(res) => serde_json::from_str(res.as_str()).unwrap_or_default(),
+        None => HashSet::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_members() -> HashMap<u32, Member> {
+        let mut members = HashMap::new();
+        for &(user_id, team_id, robot_temp_id) in
+            [(1, 1, 0), (2, 1, 0), (3, 2, 0), (4, 2, 1)].iter()
+        {
+            let mut member = Member::default();
+            member.user_id = user_id;
+            member.team_id = team_id;
+            member.robot_temp_id = robot_temp_id;
+            members.insert(user_id, member);
+        }
+        members
+    }
+
+    fn receivers(
+        channel: ChatChannel,
+        room_type: RoomType,
+        is_started: bool,
+        team_id: u8,
+    ) -> anyhow::Result<Vec<u32>> {
+        let members = new_members();
+        let mut res = chat_receivers(channel, room_type, is_started, &members, team_id)?;
+        res.sort_unstable();
+        Ok(res)
+    }
+
+    #[test]
+    fn room_and_battle_channel() {
+        let room_type = RoomType::OneVOneVOneVOneCustom;
+        //机器人收不到
+        assert_eq!(
+            receivers(ChatChannel::Room, room_type, false, 1).unwrap(),
+            vec![1, 2, 3]
+        );
+        assert!(receivers(ChatChannel::Room, room_type, true, 1).is_err());
+        assert_eq!(
+            receivers(ChatChannel::Battle, room_type, true, 1).unwrap(),
+            vec![1, 2, 3]
+        );
+        assert!(receivers(ChatChannel::Battle, room_type, false, 1).is_err());
+        assert!(receivers(ChatChannel::Whisper, room_type, false, 1).is_err());
+    }
+
+    #[test]
+    fn team_channel() {
+        //只发给自己队伍的
+        let room_type = RoomType::TwoVTwoMatch;
+        assert_eq!(
+            receivers(ChatChannel::Team, room_type, true, 1).unwrap(),
+            vec![1, 2]
+        );
+        assert_eq!(
+            receivers(ChatChannel::Team, room_type, true, 2).unwrap(),
+            vec![3]
+        );
+        assert!(receivers(ChatChannel::Team, room_type, false, 1).is_err());
+        let room_type = RoomType::WorldBossCustom;
+        assert_eq!(
+            receivers(ChatChannel::Team, room_type, true, 1).unwrap(),
+            vec![1, 2]
+        );
+        //个人混战没有队伍频道
+        let room_type = RoomType::OneVOneVOneVOneMatch;
+        assert!(receivers(ChatChannel::Team, room_type, true, 1).is_err());
+    }
+}
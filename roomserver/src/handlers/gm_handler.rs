@@ -0,0 +1,117 @@
+use crate::handlers::room_handler::add_robot;
+use crate::mgr::room_mgr::RoomMgr;
+use crate::room::room::{RoomState, MEMBER_MAX};
+use log::{error, info};
+use protobuf::Message;
+use tools::cmd_code::ClientCode;
+use tools::gm::{build_result_proto, parse_arg, GmContext, GmLevel, GmRegistry};
+use tools::protos::room::S_CHOICE_AI_NOTICE;
+use tools::protos::server_protocol::G_GM_COMMAND;
+use tools::util::packet::Packet;
+
+///注册房间服的gm命令
+pub fn init_gm_registry(registry: &mut GmRegistry<RoomMgr>) {
+    registry.register(
+        "spawn_robot",
+        GmLevel::Qa,
+        "<robot_temp_id> [index]",
+        spawn_robot,
+    );
+}
+
+///游戏服转发过来的gm命令,包头的user_id是目标玩家
+pub fn gm_command(rm: &mut RoomMgr, packet: Packet) {
+    let mut proto = G_GM_COMMAND::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let ctx = GmContext {
+        operator_id: proto.get_operator_id(),
+        level: GmLevel::from_u32(proto.get_level()),
+        target_id: packet.get_user_id(),
+    };
+    let args = proto.get_args().to_vec();
+    let res = match rm.gm_registry.check(proto.get_name(), ctx.level) {
+        Ok(f) => {
+            info!(
+                "执行gm命令!operator_id:{},target_id:{},name:{},args:{:?}",
+                ctx.operator_id,
+                ctx.target_id,
+                proto.get_name(),
+                args
+            );
+            f(rm, &ctx, args.as_slice())
+        }
+        Err(e) => Err(e),
+    };
+    //http过来的没有执行者,只打日志
+    if ctx.operator_id == 0 {
+        info!("gm command result:{:?}", res);
+        return;
+    }
+    let bytes = build_result_proto(res).write_to_bytes();
+    match bytes {
+        Ok(bytes) => rm.send_2_client(ClientCode::GmCommand, ctx.operator_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+///往目标玩家所在的房间加机器人,不填位置就放到第一个空位
+fn spawn_robot(rm: &mut RoomMgr, ctx: &GmContext, args: &[String]) -> anyhow::Result<String> {
+    let robot_temp_id: u32 = parse_arg(args, 0, "robot_temp_id")?;
+    let robot_temp = crate::TEMPLATES
+        .robot_temp_mgr()
+        .get_temp_ref(&robot_temp_id);
+    if robot_temp.is_none() {
+        anyhow::bail!("could not find RobotTemp!robot_temp_id:{}", robot_temp_id)
+    }
+    let cter_id = robot_temp.unwrap().cter_id;
+    let room = rm.get_room_mut_by_user_id(&ctx.target_id);
+    if room.is_none() {
+        anyhow::bail!("this player is not in room!user_id:{}", ctx.target_id)
+    }
+    let room = room.unwrap();
+    if room.state != RoomState::AwaitReady {
+        anyhow::bail!(
+            "room state is not AwaitReady!room_id:{},state:{:?}",
+            room.get_room_id(),
+            room.state
+        )
+    }
+    let index = match args.get(1) {
+        Some(_) => parse_arg(args, 1, "index")?,
+        None => {
+            let res = room.member_index.iter().position(|&user_id| user_id == 0);
+            if res.is_none() {
+                anyhow::bail!("room is full!room_id:{}", room.get_room_id())
+            }
+            res.unwrap()
+        }
+    };
+    if index >= MEMBER_MAX || room.member_index[index] != 0 {
+        anyhow::bail!("index is not empty!index:{}", index)
+    }
+    for member in room.members.values() {
+        if member.chose_cter.cter_temp_id == cter_id {
+            anyhow::bail!("this cter is already choiced!cter_id:{}", cter_id)
+        }
+    }
+    let room_type = room.get_room_type();
+    let room_id = room.get_room_id();
+    let robot_id = add_robot(rm, room_type, room_id, index, robot_temp_id)?;
+
+    //推送给所有人
+    let mut proto = S_CHOICE_AI_NOTICE::new();
+    proto.set_index(index as u32);
+    proto.set_robot_temp_id(robot_temp_id);
+    proto.set_user_id(robot_id);
+    let bytes = proto.write_to_bytes()?;
+    let room = rm.get_room_mut(room_type, room_id)?;
+    room.send_2_all_client(ClientCode::ChoiceAINotice, bytes);
+    Ok(format!(
+        "room_id:{},index:{},robot_id:{}",
+        room_id, index, robot_id
+    ))
+}
@@ -1 +1,3 @@
+pub mod chat_handler;
+pub mod gm_handler;
 pub mod room_handler;
@@ -4,8 +4,11 @@ use crate::room::character::Character;
 use crate::room::member::MemberState;
 use crate::room::member::{Member, PunishMatch};
 use crate::room::room::{MemberLeaveNoticeType, RoomSettingType, RoomState, MEMBER_MAX};
-use crate::room::room_model::{RoomModel, RoomSetting, RoomType, TeamId};
-use crate::task_timer::build_match_room_ready_task;
+use crate::room::room_model::{
+    get_turn_reserve_time, PresenceState, RoomModel, RoomSetting, RoomType, TeamId,
+};
+use crate::room::tournament::{get_tournament_ready_time, TournamentInfo};
+use crate::task_timer::{build_match_room_ready_task, build_tournament_room_start_task};
 use crate::{SEASON, WORLD_BOSS};
 use log::error;
 use log::info;
@@ -18,13 +21,15 @@ use std::sync::atomic::Ordering;
 use tools::cmd_code::{BattleCode, ClientCode, GameCode};
 use tools::macros::GetMutRef;
 use tools::protos::room::{
-    C_CHANGE_TEAM, C_CHOICE_AI, C_CHOOSE_CHARACTER, C_CHOOSE_SKILL, C_CONFIRM_INTO_ROOM, C_EMOJI,
-    C_KICK_MEMBER, C_PREPARE_CANCEL, C_ROOM_SETTING, S_CHOICE_AI_NOTICE, S_CHOOSE_CHARACTER,
-    S_CHOOSE_CHARACTER_NOTICE, S_CHOOSE_SKILL, S_INTO_ROOM_CANCEL_NOTICE, S_LEAVE_ROOM,
-    S_PUNISH_MATCH_NOTICE, S_ROOM_SETTING, S_START,
+    C_CHANGE_TEAM, C_CHOICE_AI, C_CHOOSE_CHARACTER, C_CHOOSE_SKILL, C_CONFIRM_INTO_ROOM,
+    C_DRAFT_BAN, C_DRAFT_PICK, C_EMOJI, C_KICK_MEMBER, C_PREPARE_CANCEL, C_ROOM_SETTING,
+    S_CHOICE_AI_NOTICE, S_CHOOSE_CHARACTER, S_CHOOSE_CHARACTER_NOTICE, S_CHOOSE_SKILL, S_DRAFT_BAN,
+    S_DRAFT_PICK, S_INTO_ROOM_CANCEL_NOTICE, S_LEAVE_ROOM, S_PUNISH_MATCH_NOTICE, S_REMATCH,
+    S_ROOM_SETTING, S_START,
 };
 use tools::protos::server_protocol::{
-    B_R_G_PUNISH_MATCH, G_R_CREATE_ROOM, G_R_JOIN_ROOM, G_R_SEARCH_ROOM, R_S_UPDATE_SEASON,
+    PlayerBattlePt, B_R_G_PUNISH_MATCH, G_R_CREATE_ROOM, G_R_JOIN_ROOM, G_R_SEARCH_ROOM,
+    G_R_TOURNAMENT_JOIN, G_S_MODIFY_NICK_NAME, R_S_UPDATE_SEASON,
 };
 use tools::protos::server_protocol::{B_R_SUMMARY, UPDATE_WORLD_BOSS_PUSH};
 use tools::templates::emoji_temp::EmojiTemp;
@@ -113,6 +118,26 @@ pub fn update_season(rm: &mut RoomMgr, packet: Packet) {
     }
 }
 
+///游戏服通知玩家改名,更新房间里面的昵称
+pub fn modify_nick_name(rm: &mut RoomMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = G_S_MODIFY_NICK_NAME::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let room = rm.get_room_mut_by_user_id(&user_id);
+    if room.is_none() {
+        return;
+    }
+    let room = room.unwrap();
+    let member = room.get_member_mut(&user_id);
+    if let Some(member) = member {
+        member.nick_name = proto.nick_name;
+    }
+}
+
 ///更新赛季
 pub fn update_worldboss(rm: &mut RoomMgr, packet: Packet) {
     let mut usn = UPDATE_WORLD_BOSS_PUSH::new();
@@ -173,7 +198,7 @@ pub fn create_room(rm: &mut RoomMgr, packet: Packet) {
     let user_id = packet.get_user_id();
     let mut room_setting;
     match room_type {
-        RoomType::OneVOneVOneVOneCustom => {
+        RoomType::OneVOneVOneVOneCustom | RoomType::TwoVTwoCustom => {
             //校验这个用户在不在房间内
             let res = rm.get_room_id(&user_id);
             if let Some(room_id) = res {
@@ -196,35 +221,27 @@ pub fn create_room(rm: &mut RoomMgr, packet: Packet) {
 
             let turn_limit_time_id = room_setting_pt.turn_limit_time as u8;
             let turn_limit_time;
+            let turn_reserve_time;
             let res = crate::TEMPLATES
                 .battle_limit_time_temp_mgr()
                 .get_temp(&turn_limit_time_id);
             match res {
                 Ok(res) => {
                     turn_limit_time = res.ms;
+                    turn_reserve_time = res.reserve_ms;
                 }
                 Err(err) => {
                     warn!("{:?}", err);
                     turn_limit_time = 120000;
+                    turn_reserve_time = 0;
                 }
             }
             room_setting = RoomSetting::from(room_setting_pt);
             room_setting.turn_limit_time = turn_limit_time;
+            room_setting.turn_reserve_time = turn_reserve_time;
 
             let ai_level = room_setting_pt.ai_level as u8;
-            let mut res = crate::TEMPLATES
-                .constant_temp_mgr()
-                .temps
-                .get("ai_level_easy")
-                .unwrap();
-            let re_id1 = u8::from_str(res.value.as_str()).unwrap();
-            res = crate::TEMPLATES
-                .constant_temp_mgr()
-                .temps
-                .get("ai_level_hard")
-                .unwrap();
-            let re_id2 = u8::from_str(res.value.as_str()).unwrap();
-            if ai_level != re_id1 && ai_level != re_id2 {
+            if !check_ai_level(ai_level) {
                 warn!("the ai level is error!id:{}", ai_level);
                 return;
             }
@@ -247,6 +264,7 @@ pub fn create_room(rm: &mut RoomMgr, packet: Packet) {
                 }
             }
             room_setting.turn_limit_time = turn_limit_time;
+            room_setting.turn_reserve_time = get_turn_reserve_time("worldboss_turn_reserve_time");
         }
         _ => {
             warn!("could not create room,the room_type is invalid!");
@@ -270,6 +288,14 @@ pub fn create_room(rm: &mut RoomMgr, packet: Packet) {
                 rm.get_task_sender_clone(),
             );
         }
+        RoomType::TwoVTwoCustom => {
+            res = rm.two_v_two_custom_room.create_room(
+                owner,
+                Some(room_setting),
+                rm.get_net_handler_clone(),
+                rm.get_task_sender_clone(),
+            );
+        }
         RoomType::WorldBossCustom => {
             res = rm.world_boss_custom_room.create_room(
                 owner,
@@ -301,6 +327,7 @@ pub fn create_room(rm: &mut RoomMgr, packet: Packet) {
 
     let res = tools::binary::combine_int_2_long(room_type as u32, room_id);
     rm.player_room.insert(packet.get_user_id(), res);
+    rm.sync_presence(packet.get_user_id(), PresenceState::InRoom);
 }
 
 //离开房间
@@ -378,6 +405,7 @@ pub fn leave_room(rm: &mut RoomMgr, packet: Packet) {
 ///玩家离线
 pub fn off_line(rm: &mut RoomMgr, packet: Packet) {
     let user_id = packet.get_user_id();
+    rm.chat_ignore.remove(&user_id);
     //校验房间是否存在
     let room = rm.get_room_mut_by_user_id(&user_id);
     if room.is_none() {
@@ -445,7 +473,6 @@ pub fn search_room(rm: &mut RoomMgr, packet: Packet) {
         return;
     }
     let room_type = room_type.unwrap();
-    let user_id = packet.get_user_id();
     //校验模式
     if !room_type.is_match_type() {
         warn!(
@@ -479,63 +506,30 @@ pub fn search_room(rm: &mut RoomMgr, packet: Packet) {
         }
     }
 
-    //校验玩家是否已经在房间里
-    if rm.check_player(&user_id) {
-        warn!(
-            "search_room:this player already in the room!user_id:{}",
-            user_id
-        );
-        return;
-    }
     //执行正常流程
     let sender = rm.get_net_handler_clone();
     let task_sender = rm.get_task_sender_clone();
 
-    let mut member = Member::from(grs.get_pbp());
-    member.state = MemberState::AwaitConfirm;
-    let punish_match_pt = grs.get_pbp().get_punish_match();
-    member.punish_match = PunishMatch::from(punish_match_pt);
-    let res = member.reset_punish_match();
-    if let Some(pm) = res {
-        //推送服务器
-        let mut brg = B_R_G_PUNISH_MATCH::new();
-        brg.set_punish_match(pm.into());
-        let bytes = brg.write_to_bytes();
-        match bytes {
-            Ok(bytes) => {
-                rm.send_2_server(GameCode::SyncPunish.into_u32(), user_id, bytes);
-            }
-            Err(e) => {
-                warn!("{:?}", e);
-            }
-        }
-        //推送给客户端
-        let mut proto = S_PUNISH_MATCH_NOTICE::new();
-        proto.set_user_id(user_id);
-        proto.set_punish_match(pm.into());
-        let bytes = proto.write_to_bytes();
-        match bytes {
-            Ok(bytes) => {
-                rm.send_2_client(ClientCode::PunishMatchPush, user_id, bytes);
-            }
-            Err(e) => {
-                warn!("{:?}", e);
-            }
+    //组队的话队伍里每个人都要校验
+    let mut members = Vec::new();
+    for pbp in std::iter::once(grs.get_pbp()).chain(grs.get_party().iter()) {
+        let res = build_match_member(rm, pbp);
+        if let Err(e) = res {
+            warn!("search_room:{:?}", e);
+            return;
         }
+        members.push(res.unwrap());
     }
-    //校验是否允许匹配
-    if member.punish_match.punish_id != 0 {
-        warn!(
-            "search_room:this user could not match now!user_id:{},punish:{:?}",
-            user_id, member.punish_match
-        );
-        return;
-    }
+    let user_ids: Vec<u32> = members.iter().map(|member| member.user_id).collect();
     let room_id;
     match room_type {
         RoomType::OneVOneVOneVOneMatch => {
             let match_room = rm.match_room.borrow_mut();
-            let res = match_room.quickly_start(member, sender, task_sender);
+            let res = if members.len() > 1 {
+                match_room.quickly_start_party(members, sender, task_sender)
+            } else {
+                match_room.quickly_start(members.remove(0), sender, task_sender)
+            };
             //返回错误信息
             if let Err(e) = res {
                 warn!("{:?}", e);
@@ -545,7 +539,23 @@ pub fn search_room(rm: &mut RoomMgr, packet: Packet) {
         }
         RoomType::WorldBoseMatch => {
             let match_room = rm.world_boss_match_room.borrow_mut();
-            let res = match_room.quickly_start(member, sender, task_sender);
+            let res = if members.len() > 1 {
+                match_room.quickly_start_party(members, sender, task_sender)
+            } else {
+                match_room.quickly_start(members.remove(0), sender, task_sender)
+            };
+            //返回错误信息
+            if let Err(e) = res {
+                warn!("{:?}", e);
+                return;
+            };
+            room_id = res.unwrap();
+        }
+        RoomType::TwoVTwoMatch => {
+            //队伍按人数分配,组队的整队进同一边
+            let res = rm
+                .two_v_two_match_room
+                .quickly_start(members, sender, task_sender);
             //返回错误信息
             if let Err(e) = res {
                 warn!("{:?}", e);
@@ -572,7 +582,60 @@ pub fn search_room(rm: &mut RoomMgr, packet: Packet) {
         }
     }
 
-    rm.player_room.insert(user_id, value);
+    for user_id in user_ids {
+        rm.player_room.insert(user_id, value);
+        rm.sync_presence(user_id, PresenceState::InRoom);
+    }
+}
+
+///校验能不能匹配,顺便处理匹配惩罚
+fn build_match_member(rm: &mut RoomMgr, pbp: &PlayerBattlePt) -> anyhow::Result<Member> {
+    let user_id = pbp.get_user_id();
+    //校验玩家是否已经在房间里
+    if rm.check_player(&user_id) {
+        anyhow::bail!("this player already in the room!user_id:{}", user_id)
+    }
+    let mut member = Member::from(pbp);
+    member.state = MemberState::AwaitConfirm;
+    let punish_match_pt = pbp.get_punish_match();
+    member.punish_match = PunishMatch::from(punish_match_pt);
+    let res = member.reset_punish_match();
+    if let Some(pm) = res {
+        //推送服务器
+        let mut brg = B_R_G_PUNISH_MATCH::new();
+        brg.set_punish_match(pm.into());
+        let bytes = brg.write_to_bytes();
+        match bytes {
+            Ok(bytes) => {
+                rm.send_2_server(GameCode::SyncPunish.into_u32(), user_id, bytes);
+            }
+            Err(e) => {
+                warn!("{:?}", e);
+            }
+        }
+        //推送给客户端
+        let mut proto = S_PUNISH_MATCH_NOTICE::new();
+        proto.set_user_id(user_id);
+        proto.set_punish_match(pm.into());
+        let bytes = proto.write_to_bytes();
+        match bytes {
+            Ok(bytes) => {
+                rm.send_2_client(ClientCode::PunishMatchPush, user_id, bytes);
+            }
+            Err(e) => {
+                warn!("{:?}", e);
+            }
+        }
+    }
+    //校验是否允许匹配
+    if member.punish_match.punish_id != 0 {
+        anyhow::bail!(
+            "this user could not match now!user_id:{},punish:{:?}",
+            user_id,
+            member.punish_match
+        )
+    }
+    Ok(member)
 }
 
 ///准备
@@ -646,6 +709,10 @@ pub fn prepare_cancel(rm: &mut RoomMgr, packet: Packet) {
         return;
     }
     room.prepare_cancel(&user_id, prepare);
+    //锦标赛房间人到齐都准备好了就直接开
+    if room.is_tournament() && room.check_tournament_ready() {
+        room.start();
+    }
 }
 
 pub fn battle_kick_member(rm: &mut RoomMgr, packet: Packet) {
@@ -680,6 +747,11 @@ pub fn choice_ai(rm: &mut RoomMgr, packet: Packet) {
         );
         return;
     }
+    //锦标赛房间不能加机器人
+    if room.is_tournament() {
+        warn!("choice_ai:this room is tournament room!room_id:{}", room_id);
+        return;
+    }
 
     let index = proto.get_index() as usize;
 
@@ -764,6 +836,16 @@ pub fn start(rm: &mut RoomMgr, packet: Packet) {
         return;
     }
 
+    //锦标赛房间由服务器开始
+    if room.is_tournament() {
+        warn!(
+            "can not start tournament room by player!room_id:{},user_id:{}",
+            room.get_room_id(),
+            user_id
+        );
+        return;
+    }
+
     //校验准备状态
     if !room.check_ready() {
         warn!("there is player not ready,can not start game!");
@@ -861,19 +943,27 @@ pub fn change_team(rm: &mut RoomMgr, packet: Packet) {
         warn!("target_team_id:{} is invaild!", team_id);
         return;
     }
-    let room_id = rm.get_room_id(user_id);
-    if let None = room_id {
-        warn!("this player is not in the room!user_id:{}", user_id);
-        return;
-    }
-    let room_id = room_id.unwrap();
-    let room = rm.custom_room.rooms.get_mut(&room_id);
+    let room = rm.get_room_mut_by_user_id(user_id);
     if let None = room {
         warn!("this player is not in the room!user_id:{}", user_id);
         return;
     }
 
     let room = room.unwrap();
+    let room_type = room.get_room_type();
+    //只有自定义房间能换队伍,世界boss的队伍是固定的
+    if room_type != RoomType::OneVOneVOneVOneCustom && room_type != RoomType::TwoVTwoCustom {
+        warn!(
+            "this room can not change team!room_type:{:?},user_id:{}",
+            room_type, user_id
+        );
+        return;
+    }
+    //组队模式校验队伍人数
+    if let Err(e) = room.check_change_team(*user_id, team_id as u8) {
+        warn!("{:?}", e);
+        return;
+    }
 
     //校验房间是否已经开始游戏
     if room.is_started() {
@@ -932,6 +1022,15 @@ pub fn kick_member(rm: &mut RoomMgr, packet: Packet) {
         return;
     }
 
+    //锦标赛房间的人是安排好的,不能T
+    if room.is_tournament() {
+        warn!(
+            "kick_member:this room is tournament room!room_id:{}",
+            room.get_room_id()
+        );
+        return;
+    }
+
     //校验房间是否存在target_id这个成员
     if !room.is_exist_member(&target_id) {
         warn!(
@@ -952,6 +1051,7 @@ pub fn kick_member(rm: &mut RoomMgr, packet: Packet) {
     match res {
         Ok(_) => {
             rm.player_room.remove(&target_id);
+            rm.sync_presence(target_id, PresenceState::Online);
         }
         Err(e) => {
             warn!("{:?}", e);
@@ -1005,6 +1105,14 @@ pub fn room_setting(rm: &mut RoomMgr, packet: Packet) {
         );
         return;
     }
+    //锦标赛房间用默认设置
+    if room.is_tournament() {
+        warn!(
+            "room_setting:this room is tournament room!room_id:{}",
+            room.get_room_id()
+        );
+        return;
+    }
     //校验角色状态
     if member.state == MemberState::Ready {
         warn!("this owner is ready!,user_id:{}", user_id);
@@ -1039,35 +1147,26 @@ pub fn room_setting(rm: &mut RoomMgr, packet: Packet) {
                 let id = proto_value as u8;
                 if id == 0 {
                     room.setting.turn_limit_time = 0;
+                    room.setting.turn_reserve_time = 0;
                 } else {
                     let limit_time_mgr = crate::TEMPLATES.battle_limit_time_temp_mgr();
                     let res = limit_time_mgr.get_temp(&id);
                     match res {
                         Ok(temp) => {
                             room.setting.turn_limit_time = temp.ms;
+                            room.setting.turn_reserve_time = temp.reserve_ms;
                         }
                         Err(e) => {
                             warn!("{:?}", e);
                             room.setting.turn_limit_time = 120000;
+                            room.setting.turn_reserve_time = 0;
                         }
                     }
                 }
             }
             RoomSettingType::AILevel => {
                 let id = proto_value as u8;
-                let mut res = crate::TEMPLATES
-                    .constant_temp_mgr()
-                    .temps
-                    .get("ai_level_easy")
-                    .unwrap();
-                let re_id1 = u8::from_str(res.value.as_str()).unwrap();
-                res = crate::TEMPLATES
-                    .constant_temp_mgr()
-                    .temps
-                    .get("ai_level_hard")
-                    .unwrap();
-                let re_id2 = u8::from_str(res.value.as_str()).unwrap();
-                if id != re_id1 && id != re_id2 {
+                if !check_ai_level(id) {
                     warn!("the ai level is error!id:{}", id);
                     return;
                 }
@@ -1118,6 +1217,7 @@ pub fn join_room(rm: &mut RoomMgr, packet: Packet) {
     let room = match room_type {
         RoomType::OneVOneVOneVOneCustom => rm.custom_room.get_mut_room_by_room_id(&room_id),
         RoomType::WorldBossCustom => rm.world_boss_custom_room.get_mut_room_by_room_id(&room_id),
+        RoomType::TwoVTwoCustom => rm.two_v_two_custom_room.get_mut_room_by_room_id(&room_id),
         _ => rm.custom_room.get_mut_room_by_room_id(&0),
     };
 
@@ -1206,6 +1306,7 @@ pub fn join_room(rm: &mut RoomMgr, packet: Packet) {
     let room_id = res.unwrap();
     let value = tools::binary::combine_int_2_long(room.get_room_type() as u32, room_id);
     rm.player_room.insert(user_id, value);
+    rm.sync_presence(user_id, PresenceState::InRoom);
 }
 
 ///选择角色
@@ -1409,6 +1510,64 @@ pub fn choice_skills(rm: &mut RoomMgr, packet: Packet) {
     );
 }
 
+///禁选阶段禁用角色
+pub fn draft_ban(rm: &mut RoomMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut cdb = C_DRAFT_BAN::new();
+    let res = cdb.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let room = rm.get_room_mut_by_user_id(&user_id);
+    if room.is_none() {
+        warn!("this player is not in room!user_id:{}", user_id);
+        return;
+    }
+    let room = room.unwrap();
+    let mut sdb = S_DRAFT_BAN::new();
+    let res = room.draft_ban(user_id, cdb.get_cter_temp_id());
+    match res {
+        Ok(_) => sdb.set_is_succ(true),
+        Err(e) => {
+            warn!("{:?}", e);
+            sdb.set_err_mess(e.to_string());
+        }
+    }
+    room.send_2_client(ClientCode::DraftBan, user_id, sdb.write_to_bytes().unwrap());
+}
+
+///禁选阶段选择角色
+pub fn draft_pick(rm: &mut RoomMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut cdp = C_DRAFT_PICK::new();
+    let res = cdp.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let room = rm.get_room_mut_by_user_id(&user_id);
+    if room.is_none() {
+        warn!("this player is not in room!user_id:{}", user_id);
+        return;
+    }
+    let room = room.unwrap();
+    let mut sdp = S_DRAFT_PICK::new();
+    let res = room.draft_pick(user_id, cdp.get_cter_temp_id());
+    match res {
+        Ok(_) => sdp.set_is_succ(true),
+        Err(e) => {
+            warn!("{:?}", e);
+            sdp.set_err_mess(e.to_string());
+        }
+    }
+    room.send_2_client(
+        ClientCode::DraftPick,
+        user_id,
+        sdp.write_to_bytes().unwrap(),
+    );
+}
+
 ///确认进入房间
 pub fn confirm_into_room(rm: &mut RoomMgr, packet: Packet) {
     let user_id = packet.get_user_id();
@@ -1467,9 +1626,14 @@ pub fn confirm_into_room(rm: &mut RoomMgr, packet: Packet) {
         if res {
             //通知新成员加入
             room.notice_new_member(user_id);
-            room.state = RoomState::AwaitReady;
-            let task_sender = rm.get_task_sender_clone();
-            build_match_room_ready_task(room_id, task_sender);
+            //排位匹配房先走禁选,禁选完了再开始准备倒计时
+            if room.need_draft() {
+                room.start_draft();
+            } else {
+                room.state = RoomState::AwaitReady;
+                let task_sender = rm.get_task_sender_clone();
+                build_match_room_ready_task(room_id, task_sender);
+            }
         }
     } else if room.state == RoomState::AwaitConfirm {
         //解散房间，并通知所有客户端
@@ -1504,22 +1668,30 @@ pub fn summary(rm: &mut RoomMgr, packet: Packet) {
     }
     let room = room.unwrap();
 
+    //锦标赛房间打完就上报结果,房间直接删掉
+    if let Some(info) = room.tournament {
+        rm.rm_room_without_push(room_type, room_id);
+        rm.report_tournament_result(info, proto.take_ranks());
+        return;
+    }
+
     match room_type {
         //如果是匹配房，直接删除房间数据
-        RoomType::OneVOneVOneVOneMatch | RoomType::WorldBoseMatch => {
+        RoomType::OneVOneVOneVOneMatch | RoomType::WorldBoseMatch | RoomType::TwoVTwoMatch => {
             rm.rm_room_without_push(room_type, room_id);
         }
         //如果是自定义房间，重制玩家数据
-        RoomType::OneVOneVOneVOneCustom | RoomType::WorldBossCustom => {
+        RoomType::OneVOneVOneVOneCustom | RoomType::WorldBossCustom | RoomType::TwoVTwoCustom => {
             if room.get_owner_id() == 0 {
                 rm.rm_room_without_push(room_type, room_id);
             } else {
                 room.state = RoomState::AwaitReady;
+                room.sync_presence(PresenceState::InRoom);
+                //上局选的角色留着,再来一局直接用
                 room.members
                     .values_mut()
                     .filter(|member| member.robot_temp_id == 0)
                     .for_each(|member| {
-                        member.chose_cter = Character::default();
                         member.state = MemberState::NotReady;
                     });
                 let world_boss_temps = &crate::TEMPLATES.worldboss_temp_mgr().temps;
@@ -1536,12 +1708,117 @@ pub fn summary(rm: &mut RoomMgr, packet: Packet) {
                     //排除worldboss
                     room.remove_member_without_push(member.get_user_id());
                 }
+                room.open_rematch();
             }
         }
         _ => {}
     }
 }
 
+///再来一局,不想再来的直接离开房间
+pub fn rematch(rm: &mut RoomMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let room = rm.get_room_mut_by_user_id(&user_id);
+    if let None = room {
+        warn!("rematch:this player not in the room!user_id:{}", user_id);
+        return;
+    }
+    let room = room.unwrap();
+    let mut sr = S_REMATCH::new();
+    match room.rematch(user_id) {
+        Ok(_) => sr.set_is_succ(true),
+        Err(e) => {
+            warn!("{:?}", e);
+            sr.set_err_mess(e.to_string());
+        }
+    }
+    room.send_2_client(ClientCode::Rematch, user_id, sr.write_to_bytes().unwrap());
+}
+
+///进锦标赛房间,同一对局第一个到的人建房,后到的直接加进来
+pub fn tournament_join(rm: &mut RoomMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut grtj = G_R_TOURNAMENT_JOIN::new();
+    let res = grtj.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    //已经在别的房间里了,到点没进来就算弃权
+    if rm.check_player(&user_id) {
+        warn!(
+            "tournament_join:this player already in the room!user_id:{}",
+            user_id
+        );
+        return;
+    }
+    let info = TournamentInfo::new(grtj.tournament_id, grtj.match_id, grtj.count);
+    let key = info.get_key();
+    let member = Member::from(grtj.get_pbp());
+    let room_type = RoomType::OneVOneVOneVOneCustom;
+    //房间id会回收,要校验一下是不是这个对局的房间
+    let room = match rm.tournament_rooms.get(&key) {
+        Some(room_id) => rm
+            .custom_room
+            .get_room_mut(room_id)
+            .filter(|room| room.tournament.map(|x| x.get_key()) == Some(key)),
+        None => None,
+    };
+    let room_id = match room {
+        Some(room) => {
+            if room.is_started() || room.get_member_count() >= MEMBER_MAX {
+                warn!(
+                    "tournament_join:this room can not join in!room_id:{},user_id:{}",
+                    room.get_room_id(),
+                    user_id
+                );
+                return;
+            }
+            let res = room.add_member(member, None, true);
+            match res {
+                Ok(room_id) => room_id,
+                Err(e) => {
+                    warn!("{:?}", e);
+                    return;
+                }
+            }
+        }
+        None => {
+            let res = rm.custom_room.create_room(
+                member,
+                None,
+                rm.get_net_handler_clone(),
+                rm.get_task_sender_clone(),
+            );
+            let room_id = match res {
+                Ok(room_id) => room_id,
+                Err(e) => {
+                    warn!("{:?}", e);
+                    return;
+                }
+            };
+            let room = rm.custom_room.get_room_mut(&room_id).unwrap();
+            room.tournament = Some(info);
+            rm.tournament_rooms.insert(key, room_id);
+            //到点还没准备好的算弃权
+            build_tournament_room_start_task(
+                room_id,
+                info,
+                get_tournament_ready_time(),
+                rm.get_task_sender_clone(),
+            );
+            info!(
+                "创建锦标赛房间!tournament_id:{},match_id:{},room_id:{}",
+                info.tournament_id, info.match_id, room_id
+            );
+            room_id
+        }
+    };
+    let value = tools::binary::combine_int_2_long(room_type as u32, room_id);
+    rm.player_room.insert(user_id, value);
+    rm.sync_presence(user_id, PresenceState::InRoom);
+}
+
 ///发送表情
 pub fn emoji(rm: &mut RoomMgr, packet: Packet) {
     let user_id = packet.get_user_id();
@@ -1619,37 +1896,41 @@ pub fn handler_leave_room(
 
     //处理退出房间
     let need_rm = match room_type {
-        RoomType::OneVOneVOneVOneCustom | RoomType::WorldBossCustom => {
-            let res;
-            if room_type == RoomType::OneVOneVOneVOneCustom {
-                res = rm.custom_room.leave_room(
+        RoomType::OneVOneVOneVOneCustom | RoomType::WorldBossCustom | RoomType::TwoVTwoCustom => {
+            let res = match room_type {
+                RoomType::OneVOneVOneVOneCustom => rm.custom_room.leave_room(
                     MemberLeaveNoticeType::Leave as u8,
                     &room_id,
                     &user_id,
                     need_push_self,
                     false,
-                );
-            } else {
-                res = rm.world_boss_custom_room.leave_room(
+                ),
+                RoomType::TwoVTwoCustom => rm.two_v_two_custom_room.leave_room(
                     MemberLeaveNoticeType::Leave as u8,
                     &room_id,
                     &user_id,
                     need_push_self,
                     false,
-                );
-            }
+                ),
+                _ => rm.world_boss_custom_room.leave_room(
+                    MemberLeaveNoticeType::Leave as u8,
+                    &room_id,
+                    &user_id,
+                    need_push_self,
+                    false,
+                ),
+            };
 
             if let Err(e) = res {
                 error!("{:?}", e);
                 return Ok(());
             }
 
-            let room;
-            if room_type == RoomType::OneVOneVOneVOneCustom {
-                room = rm.custom_room.rooms.get(&room_id).unwrap();
-            } else {
-                room = rm.world_boss_custom_room.rooms.get(&room_id).unwrap();
-            }
+            let room = match room_type {
+                RoomType::OneVOneVOneVOneCustom => rm.custom_room.rooms.get(&room_id).unwrap(),
+                RoomType::TwoVTwoCustom => rm.two_v_two_custom_room.rooms.get(&room_id).unwrap(),
+                _ => rm.world_boss_custom_room.rooms.get(&room_id).unwrap(),
+            };
             let owner_id = room.get_owner_id();
             if room.is_empty() || (owner_id == 0 && room.state != RoomState::ChoiceIndex) {
                 true
@@ -1657,34 +1938,38 @@ pub fn handler_leave_room(
                 false
             }
         }
-        RoomType::OneVOneVOneVOneMatch | RoomType::WorldBoseMatch => {
-            let room;
-            if room_type == RoomType::OneVOneVOneVOneMatch {
-                room = rm.match_room.rooms.get_mut(&room_id).unwrap();
-            } else {
-                room = rm.world_boss_match_room.rooms.get_mut(&room_id).unwrap();
-            }
+        RoomType::OneVOneVOneVOneMatch | RoomType::WorldBoseMatch | RoomType::TwoVTwoMatch => {
+            let room = match room_type {
+                RoomType::OneVOneVOneVOneMatch => rm.match_room.rooms.get_mut(&room_id).unwrap(),
+                RoomType::TwoVTwoMatch => rm.two_v_two_match_room.rooms.get_mut(&room_id).unwrap(),
+                _ => rm.world_boss_match_room.rooms.get_mut(&room_id).unwrap(),
+            };
             if room.is_empty() {
                 return Ok(());
             }
-            let res;
-            if room_type == RoomType::OneVOneVOneVOneMatch {
-                res = rm.match_room.leave_room(
+            let res = match room_type {
+                RoomType::OneVOneVOneVOneMatch => rm.match_room.leave_room(
                     MemberLeaveNoticeType::Leave as u8,
                     &room_id,
                     &user_id,
                     need_push_self,
                     need_punish,
-                );
-            } else {
-                res = rm.world_boss_match_room.leave_room(
+                ),
+                RoomType::TwoVTwoMatch => rm.two_v_two_match_room.leave_room(
                     MemberLeaveNoticeType::Leave as u8,
                     &room_id,
                     &user_id,
                     need_push_self,
                     need_punish,
-                );
-            }
+                ),
+                _ => rm.world_boss_match_room.leave_room(
+                    MemberLeaveNoticeType::Leave as u8,
+                    &room_id,
+                    &user_id,
+                    need_push_self,
+                    need_punish,
+                ),
+            };
 
             if let Err(e) = res {
                 error!("{:?}", e);
@@ -1699,12 +1984,11 @@ pub fn handler_leave_room(
                     slr.write_to_bytes().unwrap(),
                 );
             }
-            let room;
-            if room_type == RoomType::OneVOneVOneVOneMatch {
-                room = rm.match_room.rooms.get(&room_id).unwrap();
-            } else {
-                room = rm.world_boss_match_room.rooms.get(&room_id).unwrap();
-            }
+            let room = match room_type {
+                RoomType::OneVOneVOneVOneMatch => rm.match_room.rooms.get(&room_id).unwrap(),
+                RoomType::TwoVTwoMatch => rm.two_v_two_match_room.rooms.get(&room_id).unwrap(),
+                _ => rm.world_boss_match_room.rooms.get(&room_id).unwrap(),
+            };
 
             if room.is_empty() {
                 true
@@ -1717,6 +2001,7 @@ pub fn handler_leave_room(
 
     //删掉当前离开的玩家
     rm.player_room.remove(&user_id);
+    rm.sync_presence(user_id, PresenceState::Online);
     info!(
         "玩家离开{:?}房间，卸载玩家房间数据!user_id:{},room_id:{}",
         room_type, user_id, room_id
@@ -1727,3 +2012,20 @@ pub fn handler_leave_room(
 
     Ok(())
 }
+
+///校验ai等级,只能是配置里面的简单,普通,困难
+fn check_ai_level(ai_level: u8) -> bool {
+    for key in ["ai_level_easy", "ai_level_normal", "ai_level_hard"].iter() {
+        let res = crate::TEMPLATES.constant_temp_mgr().temps.get(*key);
+        if res.is_none() {
+            continue;
+        }
+        let res = u8::from_str(res.unwrap().value.as_str());
+        if let Ok(id) = res {
+            if id == ai_level {
+                return true;
+            }
+        }
+    }
+    false
+}
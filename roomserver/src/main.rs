@@ -10,11 +10,12 @@ use crate::task_timer::init_timer;
 use async_std::sync::{Arc, Mutex};
 use lazy_static::lazy_static;
 use log::{error, info, warn};
-use scheduled_thread_pool::ScheduledThreadPool;
+use tools::scheduler::DelayTimer;
 use serde_json::Value;
 use std::env;
 use std::sync::atomic::AtomicU32;
 use tools::conf::Conf;
+use tools::metrics::MetricsHttpHandler;
 use tools::redis_pool::RedisPoolTool;
 use tools::templates::template::{init_temps_mgr, TemplatesMgr};
 
@@ -35,14 +36,14 @@ lazy_static! {
     };
 
     ///定时器任务队列
-    static ref SCHEDULED_MGR : ScheduledThreadPool = {
-        let stp = ScheduledThreadPool::with_name("TASK_TIMER",8);
+    static ref SCHEDULED_MGR : DelayTimer = {
+        let stp = DelayTimer::with_name("TASK_TIMER",8);
         stp
     };
 
     ///机器人定时器任务队列
-    static ref ROBOT_SCHEDULED_MGR : ScheduledThreadPool = {
-        let stp = ScheduledThreadPool::with_name("ROBOT_TASK_TIMER",8);
+    static ref ROBOT_SCHEDULED_MGR : DelayTimer = {
+        let stp = DelayTimer::with_name("ROBOT_TASK_TIMER",8);
         stp
     };
 
@@ -58,6 +59,9 @@ lazy_static! {
 
 static ROBOT_ID: AtomicU32 = AtomicU32::new(0);
 
+///玩家redis索引
+const REDIS_INDEX_USERS: u32 = 0;
+
 ///赛季redis索引
 const REDIS_INDEX_GAME_SEASON: u32 = 1;
 
@@ -67,6 +71,9 @@ const REDIS_INDEX_RANK: u32 = 2;
 ///赛季redis key
 const REDIS_KEY_GAME_SEASON: &str = "game_season";
 
+///聊天屏蔽列表redis key
+const REDIS_KEY_CHAT_MUTE: &str = "chat_mute";
+
 ///worldboss redis key
 const REDIS_KEY_WORLD_BOSS: &str = "world_boss";
 
@@ -152,11 +159,20 @@ fn main() {
     //初始化房间id
     init_room_id();
 
+    //初始化http服务
+    init_http_server(room_mgr.clone());
+
     //初始化tcp服务
     init_tcp_server(room_mgr.clone());
 }
 
 fn init_log() {
+    //配置了log_format就用tracing输出,日志会带上trace_id/user_id/room_id/battle_id
+    let log_format = CONF_MAP.get_str("log_format", "");
+    if !log_format.is_empty() {
+        tools::my_log::init_trace_log(log_format == "json");
+        return;
+    }
     let info_log = CONF_MAP.get_str("info_log_path");
     let error_log = CONF_MAP.get_str("error_log_path");
     tools::my_log::init_log(info_log, error_log);
@@ -275,3 +291,13 @@ fn init_tcp_server(rm: Lock) {
     let tcp_port: &str = CONF_MAP.get_str("tcp_port");
     tcp_server::new(tcp_port, rm);
 }
+
+///初始化http服务端,目前只有监控指标
+fn init_http_server(rm: Lock) {
+    let http_port = CONF_MAP.get_usize("http_port", 0) as u16;
+    tools::http::Builder::new()
+        .route(Box::new(MetricsHttpHandler::new(move || {
+            async_std::task::block_on(rm.lock()).collect_metrics();
+        })))
+        .bind(http_port);
+}
@@ -1,13 +1,17 @@
 use crate::handlers::room_handler::{
     battle_kick_member, cancel_search_room, change_team, choice_ai, choice_skills,
-    choose_character, confirm_into_room, create_room, emoji, join_room, kick_member, leave_room,
-    off_line, prepare_cancel, reload_temps, room_setting, search_room, start, summary,
-    update_season, update_worldboss,
+    choose_character, confirm_into_room, create_room, draft_ban, draft_pick, emoji, join_room,
+    kick_member, leave_room, modify_nick_name, off_line, prepare_cancel, reload_temps, rematch,
+    room_setting, search_room, start, summary, tournament_join, update_season, update_worldboss,
 };
+use crate::handlers::{chat_handler, gm_handler};
 use crate::room::custom_room::CustomRoom;
 use crate::room::match_room::MatchRoom;
 use crate::room::room::{Room, RoomState};
-use crate::room::room_model::{RoomModel, RoomType};
+use crate::room::room_model::{PresenceState, RoomModel, RoomType};
+use crate::room::tournament::TournamentInfo;
+use crate::room::two_v_two_custom_room::TwoVTwoCustomRoom;
+use crate::room::two_v_two_match_room::TwoVTwoMatchRoom;
 use crate::room::world_boss_custom_room::WorldBossCustomRoom;
 use crate::room::world_boss_match_room::WorldBossMatchRoom;
 use crate::task_timer::Task;
@@ -15,10 +19,14 @@ use crossbeam::channel::Sender;
 use log::{error, info, warn};
 use rayon::slice::ParallelSliceMut;
 use std::collections::hash_map::RandomState;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::convert::TryFrom;
-use tools::cmd_code::{ClientCode, RoomCode, ServerCommonCode};
+use tools::cmd_code::{CenterCode, ClientCode, GameCode, RoomCode, ServerCommonCode};
+use tools::gm::GmRegistry;
+use tools::metrics;
 use tools::net_message_io::NetHandler;
+use tools::protos::server_protocol::R_G_SYNC_PRESENCE;
+use tools::trace;
 use tools::util::packet::Packet;
 
 type CmdFn = HashMap<u32, fn(&mut RoomMgr, Packet), RandomState>;
@@ -30,10 +38,15 @@ pub struct RoomMgr {
     pub match_room: MatchRoom,                       //公共房
     pub world_boss_match_room: WorldBossMatchRoom,   //世界boss自定义房间
     pub world_boss_custom_room: WorldBossCustomRoom, //世界boss自定义房间
+    pub two_v_two_custom_room: TwoVTwoCustomRoom,    //2v2自定义房间
+    pub two_v_two_match_room: TwoVTwoMatchRoom,      //2v2匹配房间
     pub player_room: HashMap<u32, u64>, //玩家对应的房间，key:u32,value:采用一个u64存，通过位运算分出高低位,低32位是房间模式,高32位是房间id
     pub cmd_map: CmdFn,                 //命令管理 key:cmd,value:函数指针
     net_handler: Option<NetHandler>,    //tcp channel的发送方
     pub task_sender: Option<Sender<Task>>, //task channel的发送方
+    pub gm_registry: GmRegistry<RoomMgr>, //gm命令
+    pub chat_ignore: HashMap<u32, HashSet<u32>>, //玩家屏蔽聊天的人
+    pub tournament_rooms: HashMap<u64, u32>, //锦标赛对局对应的房间,key:TournamentInfo::get_key,value:房间id
 }
 
 tools::get_mut_ref!(RoomMgr);
@@ -42,6 +55,7 @@ impl RoomMgr {
     pub fn new() -> RoomMgr {
         let mut rm = RoomMgr::default();
         rm.cmd_init();
+        gm_handler::init_gm_registry(&mut rm.gm_registry);
         rm
     }
 
@@ -51,6 +65,8 @@ impl RoomMgr {
             RoomType::OneVOneVOneVOneMatch => self.match_room.get_room_mut(&room_id),
             RoomType::WorldBossCustom => self.world_boss_custom_room.get_room_mut(&room_id),
             RoomType::WorldBoseMatch => self.world_boss_match_room.get_room_mut(&room_id),
+            RoomType::TwoVTwoCustom => self.two_v_two_custom_room.get_room_mut(&room_id),
+            RoomType::TwoVTwoMatch => self.two_v_two_match_room.get_room_mut(&room_id),
             _ => None,
         };
         if res.is_none() {
@@ -69,6 +85,8 @@ impl RoomMgr {
             RoomType::OneVOneVOneVOneMatch => self.match_room.rm_room(&room_id),
             RoomType::WorldBossCustom => self.world_boss_custom_room.rm_room(&room_id),
             RoomType::WorldBoseMatch => self.world_boss_match_room.rm_room(&room_id),
+            RoomType::TwoVTwoCustom => self.two_v_two_custom_room.rm_room(&room_id),
+            RoomType::TwoVTwoMatch => self.two_v_two_match_room.rm_room(&room_id),
             _ => None,
         };
         if room.is_none() {
@@ -78,8 +96,11 @@ impl RoomMgr {
         if room.is_empty() {
             return;
         }
-        for member in room.members.keys() {
-            self.player_room.remove(member);
+        for member in room.members.values() {
+            self.player_room.remove(&member.user_id);
+            if member.robot_temp_id == 0 {
+                self.sync_presence(member.user_id, PresenceState::Online);
+            }
         }
     }
 
@@ -104,6 +125,8 @@ impl RoomMgr {
             RoomType::OneVOneVOneVOneMatch => self.match_room.get_room_mut(&room_id),
             RoomType::WorldBossCustom => self.world_boss_custom_room.get_room_mut(&room_id),
             RoomType::WorldBoseMatch => self.world_boss_match_room.get_room_mut(&room_id),
+            RoomType::TwoVTwoCustom => self.two_v_two_custom_room.get_room_mut(&room_id),
+            RoomType::TwoVTwoMatch => self.two_v_two_match_room.get_room_mut(&room_id),
             _ => None,
         };
 
@@ -128,10 +151,10 @@ impl RoomMgr {
             let mut need_rm_cache = false;
             let mut need_cache_sort = false;
 
-            let room_cache_iter = if room_type == RoomType::OneVOneVOneVOneMatch {
-                self.match_room.room_cache.iter_mut()
-            } else {
-                self.world_boss_match_room.room_cache.iter_mut()
+            let room_cache_iter = match room_type {
+                RoomType::OneVOneVOneVOneMatch => self.match_room.room_cache.iter_mut(),
+                RoomType::TwoVTwoMatch => self.two_v_two_match_room.room_cache.iter_mut(),
+                _ => self.world_boss_match_room.room_cache.iter_mut(),
             };
 
             for room_cache in room_cache_iter {
@@ -170,6 +193,10 @@ impl RoomMgr {
             self.world_boss_match_room
                 .room_cache
                 .par_sort_by(|a, b| b.count.cmp(&a.count));
+        } else if room_type == RoomType::TwoVTwoMatch {
+            self.two_v_two_match_room
+                .room_cache
+                .par_sort_by(|a, b| b.count.cmp(&a.count));
         }
     }
 
@@ -178,6 +205,8 @@ impl RoomMgr {
             self.match_room.remove_room_cache(&room_id);
         } else if room_type == RoomType::WorldBoseMatch {
             self.world_boss_match_room.remove_room_cache(&room_id);
+        } else if room_type == RoomType::TwoVTwoMatch {
+            self.two_v_two_match_room.remove_room_cache(&room_id);
         }
     }
 
@@ -199,6 +228,28 @@ impl RoomMgr {
         tcp.node_handler.network().send(endpoint, bytes.as_slice());
     }
 
+    ///同步在线状态给游戏服
+    pub fn sync_presence(&mut self, user_id: u32, state: PresenceState) {
+        let mut proto = R_G_SYNC_PRESENCE::new();
+        proto.set_presence(state.into_u32());
+        match proto.write_to_bytes() {
+            Ok(bytes) => self.send_2_server(GameCode::SyncPresence.into_u32(), user_id, bytes),
+            Err(e) => error!("{:?}", e),
+        }
+    }
+
+    ///上报锦标赛对局结果给中心服,对局和房间的对应关系也就没用了
+    pub fn report_tournament_result(&mut self, info: TournamentInfo, ranks: Vec<u32>) {
+        self.tournament_rooms.remove(&info.get_key());
+        info!(
+            "锦标赛对局结束!tournament_id:{},match_id:{},ranks:{:?}",
+            info.tournament_id, info.match_id, ranks
+        );
+        if let Some(bytes) = info.build_result(ranks) {
+            self.send_2_server(CenterCode::TournamentResult.into_u32(), 0, bytes);
+        }
+    }
+
     pub fn set_net_handler(&mut self, sender: NetHandler) {
         self.net_handler = Some(sender);
     }
@@ -222,15 +273,41 @@ impl RoomMgr {
         return Some(room_id);
     }
 
+    ///刷新监控指标
+    pub fn collect_metrics(&self) {
+        let rooms = self.custom_room.rooms.len()
+            + self.match_room.rooms.len()
+            + self.world_boss_custom_room.rooms.len()
+            + self.world_boss_match_room.rooms.len()
+            + self.two_v_two_custom_room.rooms.len()
+            + self.two_v_two_match_room.rooms.len();
+        metrics::set_gauge("active_rooms", rooms as i64);
+        metrics::set_gauge("room_players", self.player_room.len() as i64);
+        let queue_len = self.match_room.room_cache.len()
+            + self.world_boss_match_room.room_cache.len()
+            + self.two_v_two_match_room.room_cache.len();
+        metrics::set_gauge("match_queue_len", queue_len as i64);
+        if let Some(sender) = self.task_sender.as_ref() {
+            metrics::set_gauge("task_channel_backlog", sender.len() as i64);
+        }
+    }
+
     ///执行函数，通过packet拿到cmd，然后从cmdmap拿到函数指针调用
     pub fn invok(&mut self, packet: Packet) {
         let cmd = packet.get_cmd();
+        let _trace = trace::enter(&packet);
+        if let Some(room_id) = self.get_room_id(&packet.get_user_id()) {
+            trace::record_room_id(room_id);
+        }
         let f = self.cmd_map.get_mut(&cmd);
         if f.is_none() {
             warn!("there is no handler of cmd:{:?}!", cmd);
             return;
         }
-        let _ = f.unwrap()(self, packet);
+        let f = *f.unwrap();
+        let time = std::time::Instant::now();
+        let _ = f(self, packet);
+        metrics::record_invok(cmd, time.elapsed());
     }
 
     pub fn get_room_mut_by_user_id(&mut self, user_id: &u32) -> Option<&mut Room> {
@@ -252,6 +329,8 @@ impl RoomMgr {
             RoomType::OneVOneVOneVOneMatch => self.match_room.get_room_mut(&room_id),
             RoomType::WorldBossCustom => self.world_boss_custom_room.get_room_mut(&room_id),
             RoomType::WorldBoseMatch => self.world_boss_match_room.get_room_mut(&room_id),
+            RoomType::TwoVTwoCustom => self.two_v_two_custom_room.get_room_mut(&room_id),
+            RoomType::TwoVTwoMatch => self.two_v_two_match_room.get_room_mut(&room_id),
             _ => None,
         };
         room
@@ -277,6 +356,8 @@ impl RoomMgr {
             RoomType::OneVOneVOneVOneMatch => self.match_room.get_room_ref(&room_id),
             RoomType::WorldBossCustom => self.world_boss_custom_room.get_room_ref(&room_id),
             RoomType::WorldBoseMatch => self.world_boss_match_room.get_room_ref(&room_id),
+            RoomType::TwoVTwoCustom => self.two_v_two_custom_room.get_room_ref(&room_id),
+            RoomType::TwoVTwoMatch => self.two_v_two_match_room.get_room_ref(&room_id),
             _ => None,
         };
         room
@@ -325,6 +406,14 @@ impl RoomMgr {
         //选择技能
         self.cmd_map
             .insert(RoomCode::ChoiceSkill.into_u32(), choice_skills);
+        //禁选阶段禁用角色
+        self.cmd_map
+            .insert(RoomCode::DraftBan.into_u32(), draft_ban);
+        //禁选阶段选择角色
+        self.cmd_map
+            .insert(RoomCode::DraftPick.into_u32(), draft_pick);
+        //再来一局
+        self.cmd_map.insert(RoomCode::Rematch.into_u32(), rematch);
         //发送表情
         self.cmd_map.insert(RoomCode::Emoji.into_u32(), emoji);
         //确认进入房间
@@ -343,5 +432,20 @@ impl RoomMgr {
         //选择ai
         self.cmd_map
             .insert(RoomCode::UpdateWorldBossPush.into_u32(), update_worldboss);
+        //玩家改名
+        self.cmd_map
+            .insert(RoomCode::ModifyNickName.into_u32(), modify_nick_name);
+        //gm命令
+        self.cmd_map
+            .insert(RoomCode::GmCommand.into_u32(), gm_handler::gm_command);
+        //聊天
+        self.cmd_map
+            .insert(RoomCode::Chat.into_u32(), chat_handler::chat);
+        //屏蔽聊天
+        self.cmd_map
+            .insert(RoomCode::ChatMute.into_u32(), chat_handler::chat_mute);
+        //进锦标赛房间
+        self.cmd_map
+            .insert(RoomCode::TournamentJoin.into_u32(), tournament_join);
     }
 }
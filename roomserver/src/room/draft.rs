@@ -0,0 +1,298 @@
+use crate::room::character::Character;
+use log::warn;
+use num_enum::IntoPrimitive;
+use num_enum::TryFromPrimitive;
+use rand::Rng;
+use std::collections::HashMap;
+use std::str::FromStr;
+use tools::protos::base::DraftPickPt;
+use tools::protos::room::S_DRAFT_NOTICE;
+
+///禁选阶段
+#[derive(Debug, Clone, Copy, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum DraftPhase {
+    Ban = 1,    //禁用
+    Pick = 2,   //选择
+    Finish = 3, //结束
+}
+
+impl Default for DraftPhase {
+    fn default() -> Self {
+        DraftPhase::Ban
+    }
+}
+
+impl DraftPhase {
+    pub fn into_u32(self) -> u32 {
+        let res: u8 = self.into();
+        res as u32
+    }
+}
+
+///禁选数据
+#[derive(Debug, Clone, Default)]
+pub struct Draft {
+    pub phase: DraftPhase,        //当前阶段
+    pub ban_orders: Vec<u32>,     //禁用顺序 user_id,每人可能有多次
+    pub pick_orders: Vec<u32>,    //选择顺序 user_id
+    pub turn: usize,              //当前阶段行动下标
+    pub seq: u32,                 //行动序号,每行动一次加1,用来判断超时任务是否过期
+    pub bans: Vec<u32>,           //已禁用的角色
+    pub picks: HashMap<u32, u32>, //已选择的角色 user_id cter_temp_id
+    pub turn_end_time: u64,       //当前行动截止时间
+}
+
+impl Draft {
+    ///pick_orders按段位从高到低排好,禁用顺序反过来,段位低的先禁
+    pub fn new(pick_orders: Vec<u32>, ban_count: usize) -> Self {
+        let mut draft = Draft::default();
+        for _ in 0..ban_count {
+            draft.ban_orders.extend(pick_orders.iter().rev());
+        }
+        draft.pick_orders = pick_orders;
+        if draft.ban_orders.is_empty() {
+            draft.phase = DraftPhase::Pick;
+        }
+        draft
+    }
+
+    ///当前该谁行动
+    pub fn get_turn_user(&self) -> Option<u32> {
+        let orders = match self.phase {
+            DraftPhase::Ban => &self.ban_orders,
+            DraftPhase::Pick => &self.pick_orders,
+            DraftPhase::Finish => return None,
+        };
+        orders.get(self.turn).copied()
+    }
+
+    pub fn is_finish(&self) -> bool {
+        self.phase == DraftPhase::Finish
+    }
+
+    pub fn is_banned(&self, cter_temp_id: u32) -> bool {
+        self.bans.contains(&cter_temp_id)
+    }
+
+    pub fn is_picked(&self, cter_temp_id: u32) -> bool {
+        self.picks.values().any(|&id| id == cter_temp_id)
+    }
+
+    ///禁用或者被选走了
+    pub fn is_unavailable(&self, cter_temp_id: u32) -> bool {
+        self.is_banned(cter_temp_id) || self.is_picked(cter_temp_id)
+    }
+
+    ///超时任务对应的行动还没做的话返回该行动的玩家,旧的超时任务返回None
+    pub fn timeout_user(&self, seq: u32) -> Option<u32> {
+        if self.seq != seq {
+            return None;
+        }
+        self.get_turn_user()
+    }
+
+    ///帮玩家选角色,优先上次用过的,没得选返回0
+    pub fn choose_pick(&self, cters: Vec<&Character>) -> u32 {
+        let mut v: Vec<&Character> = cters
+            .into_iter()
+            .filter(|cter| !self.is_unavailable(cter.cter_temp_id))
+            .collect();
+        if v.is_empty() {
+            return 0;
+        }
+        let used: Vec<&Character> = v
+            .iter()
+            .copied()
+            .filter(|cter| !cter.last_use_skills.is_empty())
+            .collect();
+        if !used.is_empty() {
+            v = used;
+        }
+        let index = rand::thread_rng().gen_range(0..v.len());
+        v[index].cter_temp_id
+    }
+
+    fn check_turn(&self, phase: DraftPhase, user_id: u32) -> anyhow::Result<()> {
+        if self.phase != phase {
+            anyhow::bail!(
+                "draft phase is error!phase:{:?},user_id:{}",
+                self.phase,
+                user_id
+            )
+        }
+        if self.get_turn_user() != Some(user_id) {
+            anyhow::bail!("it is not this player's turn!user_id:{}", user_id)
+        }
+        Ok(())
+    }
+
+    ///禁用角色,cter_temp_id为0表示放弃禁用
+    pub fn ban(&mut self, user_id: u32, cter_temp_id: u32) -> anyhow::Result<()> {
+        self.check_turn(DraftPhase::Ban, user_id)?;
+        if cter_temp_id > 0 {
+            if self.is_banned(cter_temp_id) {
+                anyhow::bail!("this character was banned!cter_id:{}", cter_temp_id)
+            }
+            self.bans.push(cter_temp_id);
+        }
+        self.next_turn();
+        Ok(())
+    }
+
+    ///选择角色,cter_temp_id为0表示没得选了,直接跳过
+    pub fn pick(&mut self, user_id: u32, cter_temp_id: u32) -> anyhow::Result<()> {
+        self.check_turn(DraftPhase::Pick, user_id)?;
+        if cter_temp_id > 0 {
+            if self.is_unavailable(cter_temp_id) {
+                anyhow::bail!("this character is unavailable!cter_id:{}", cter_temp_id)
+            }
+            self.picks.insert(user_id, cter_temp_id);
+        }
+        self.next_turn();
+        Ok(())
+    }
+
+    fn next_turn(&mut self) {
+        self.turn += 1;
+        self.seq += 1;
+        if self.phase == DraftPhase::Ban && self.turn >= self.ban_orders.len() {
+            self.phase = DraftPhase::Pick;
+            self.turn = 0;
+        }
+        if self.phase == DraftPhase::Pick && self.turn >= self.pick_orders.len() {
+            self.phase = DraftPhase::Finish;
+            self.turn = 0;
+        }
+    }
+
+    pub fn build_notice(&self) -> S_DRAFT_NOTICE {
+        let mut proto = S_DRAFT_NOTICE::new();
+        proto.set_phase(self.phase.into_u32());
+        proto.set_user_id(self.get_turn_user().unwrap_or(0));
+        proto.set_end_time(self.turn_end_time);
+        proto.set_orders(self.pick_orders.clone());
+        proto.set_bans(self.bans.clone());
+        for &user_id in self.pick_orders.iter() {
+            if let Some(&cter_temp_id) = self.picks.get(&user_id) {
+                let mut dpp = DraftPickPt::new();
+                dpp.set_user_id(user_id);
+                dpp.set_cter_temp_id(cter_temp_id);
+                proto.picks.push(dpp);
+            }
+        }
+        proto
+    }
+}
+
+///读取禁选配置,没配或者配错了就用默认值
+pub fn get_draft_config(key: &str, default: u64) -> u64 {
+    let res = crate::TEMPLATES.constant_temp_mgr().temps.get(key);
+    match res {
+        Some(temp) => match u64::from_str(temp.value.as_str()) {
+            Ok(value) => value,
+            Err(e) => {
+                warn!("{:?}", e);
+                default
+            }
+        },
+        None => default,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_cter(cter_temp_id: u32, used: bool) -> Character {
+        let mut cter = Character::default();
+        cter.cter_temp_id = cter_temp_id;
+        if used {
+            cter.last_use_skills.push(1);
+        }
+        cter
+    }
+
+    #[test]
+    fn ban_pick_order() {
+        //选人按段位从高到低,禁用反过来
+        let mut draft = Draft::new(vec![1, 2, 3], 1);
+        assert_eq!(draft.ban_orders, vec![3, 2, 1]);
+        assert_eq!(draft.get_turn_user(), Some(3));
+        assert!(draft.ban(1, 101).is_err());
+        assert!(draft.pick(3, 101).is_err());
+        draft.ban(3, 101).unwrap();
+        //已经禁过的不能再禁,放弃禁用可以
+        assert!(draft.ban(2, 101).is_err());
+        draft.ban(2, 0).unwrap();
+        draft.ban(1, 102).unwrap();
+        assert_eq!(draft.phase, DraftPhase::Pick);
+        assert_eq!(draft.bans, vec![101, 102]);
+
+        assert_eq!(draft.get_turn_user(), Some(1));
+        assert!(draft.ban(1, 103).is_err());
+        assert!(draft.pick(2, 103).is_err());
+        draft.pick(1, 103).unwrap();
+        draft.pick(2, 104).unwrap();
+        draft.pick(3, 105).unwrap();
+        assert!(draft.is_finish());
+        assert_eq!(draft.get_turn_user(), None);
+        assert_eq!(draft.picks[&2], 104);
+        assert_eq!(draft.seq, 6);
+    }
+
+    #[test]
+    fn no_ban() {
+        let draft = Draft::new(vec![1, 2], 0);
+        assert_eq!(draft.phase, DraftPhase::Pick);
+        assert_eq!(draft.get_turn_user(), Some(1));
+        let draft = Draft::new(vec![1, 2], 2);
+        assert_eq!(draft.ban_orders, vec![2, 1, 2, 1]);
+    }
+
+    #[test]
+    fn duplicate_pick() {
+        let mut draft = Draft::new(vec![1, 2], 1);
+        draft.ban(2, 101).unwrap();
+        draft.ban(1, 0).unwrap();
+        //禁用的和别人选走的都不能选
+        assert!(draft.pick(1, 101).is_err());
+        draft.pick(1, 102).unwrap();
+        assert!(draft.pick(2, 102).is_err());
+        assert_eq!(draft.get_turn_user(), Some(2));
+        draft.pick(2, 103).unwrap();
+        assert!(draft.is_finish());
+    }
+
+    #[test]
+    fn timeout_auto_pick() {
+        let mut draft = Draft::new(vec![1, 2], 0);
+        let seq = draft.seq;
+        let cters = vec![new_cter(101, true), new_cter(102, false)];
+        let user_id = draft.timeout_user(seq).unwrap();
+        assert_eq!(user_id, 1);
+        //优先上次用过的
+        let cter_temp_id = draft.choose_pick(cters.iter().collect());
+        assert_eq!(cter_temp_id, 101);
+        draft.pick(user_id, cter_temp_id).unwrap();
+        //玩家已经操作过了,旧的超时任务不管用
+        assert_eq!(draft.timeout_user(seq), None);
+
+        //被选走的不会再选
+        let user_id = draft.timeout_user(draft.seq).unwrap();
+        let cter_temp_id = draft.choose_pick(cters.iter().collect());
+        assert_eq!(cter_temp_id, 102);
+        draft.pick(user_id, cter_temp_id).unwrap();
+        assert!(draft.is_finish());
+        assert_eq!(draft.timeout_user(draft.seq), None);
+
+        //没得选了就跳过
+        let mut draft = Draft::new(vec![1, 2], 0);
+        draft.pick(1, 101).unwrap();
+        let cters = vec![new_cter(101, true)];
+        assert_eq!(draft.choose_pick(cters.iter().collect()), 0);
+        draft.pick(2, 0).unwrap();
+        assert!(draft.is_finish());
+        assert!(!draft.picks.contains_key(&2));
+    }
+}
@@ -1,8 +1,12 @@
 pub mod character;
 pub mod custom_room;
+pub mod draft;
 pub mod match_room;
 pub mod member;
 pub mod room;
 pub mod room_model;
+pub mod tournament;
+pub mod two_v_two_custom_room;
+pub mod two_v_two_match_room;
 pub mod world_boss_custom_room;
 pub mod world_boss_match_room;
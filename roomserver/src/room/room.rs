@@ -1,6 +1,9 @@
+use crate::room::character::Character;
+use crate::room::draft::{get_draft_config, Draft, DraftPhase};
 use crate::room::member::{Member, MemberState};
-use crate::room::room_model::{RoomSetting, RoomType};
-use crate::task_timer::Task;
+use crate::room::room_model::{PresenceState, RoomSetting, RoomType};
+use crate::room::tournament::TournamentInfo;
+use crate::task_timer::{build_draft_turn_task, build_match_room_ready_task, Task};
 use crate::ROOM_ID;
 use chrono::{DateTime, Local, Utc};
 use crossbeam::channel::Sender;
@@ -10,21 +13,26 @@ use num_enum::TryFromPrimitive;
 use protobuf::Message;
 use rand::Rng;
 use std::borrow::{Borrow, BorrowMut};
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use tools::cmd_code::{BattleCode, ClientCode, GameCode};
 use tools::macros::GetMutRef;
 use tools::net_message_io::NetHandler;
-use tools::protos::base::{MemberPt, RoomPt};
+use tools::protos::base::{HistoryMessPt, MemberPt, NoticeMessPt, RoomPt};
 use tools::protos::room::{
-    S_CHANGE_TEAM_NOTICE, S_CONFIRM_INTO_ROOM_NOTICE, S_EMOJI, S_EMOJI_NOTICE, S_KICK_MEMBER,
-    S_MATCH_SUCCESS_NOTICE, S_PREPARE_CANCEL, S_PREPARE_CANCEL_NOTICE, S_PUNISH_MATCH_NOTICE,
-    S_ROOM, S_ROOM_ADD_MEMBER_NOTICE, S_ROOM_MEMBER_LEAVE_NOTICE, S_ROOM_NOTICE,
+    S_CHANGE_TEAM_NOTICE, S_CHAT_HISTORY, S_CONFIRM_INTO_ROOM_NOTICE, S_EMOJI, S_EMOJI_NOTICE,
+    S_KICK_MEMBER, S_MATCH_SUCCESS_NOTICE, S_PREPARE_CANCEL, S_PREPARE_CANCEL_NOTICE,
+    S_PUNISH_MATCH_NOTICE, S_REMATCH_NOTICE, S_ROOM, S_ROOM_ADD_MEMBER_NOTICE,
+    S_ROOM_MEMBER_LEAVE_NOTICE, S_ROOM_NOTICE,
 };
-use tools::protos::server_protocol::{B_R_G_PUNISH_MATCH, R_B_START};
+use tools::protos::server_protocol::{B_R_G_PUNISH_MATCH, R_B_START, R_G_SYNC_PRESENCE};
 use tools::util::packet::Packet;
 
 ///最大成员数量
 pub const MEMBER_MAX: usize = 4;
+///组队模式每个队伍的人数
+pub const TEAM_MEMBER_MAX: usize = 2;
+///房间保留的聊天记录条数
+pub const CHAT_HISTORY_MAX: usize = 50;
 
 #[derive(Debug, Clone, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
 #[repr(u8)]
@@ -61,28 +69,43 @@ pub enum MemberLeaveNoticeType {
     Kicked = 2, //被T
 }
 
+///聊天频道
+#[derive(Debug, Clone, Copy, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum ChatChannel {
+    Room = 1,    //房间
+    Battle = 2,  //战斗
+    Team = 3,    //队伍,世界boss和组队模式有
+    Whisper = 4, //私聊
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
 #[repr(u8)]
 pub enum RoomState {
     AwaitConfirm = 0, //等待进入 只有匹配模式才会有到壮体啊
     AwaitReady = 1,   //等待
     ChoiceIndex = 2,  //选择占位
+    Draft = 3,        //禁选阶段 只有排位匹配房才有
 }
 
 ///房间结构体，封装房间必要信息
 #[derive(Clone)]
 pub struct Room {
-    id: u32,                             //房间id
-    room_type: RoomType,                 //房间类型
-    owner_id: u32,                       //房主id
-    pub state: RoomState,                //房间状态
-    pub members: HashMap<u32, Member>,   //玩家对应的队伍
-    pub member_index: [u32; MEMBER_MAX], //玩家对应的位置
-    pub robots: HashSet<u32>,            //机器人
-    pub setting: RoomSetting,            //房间设置
-    pub net_handler: NetHandler,         //tcpsender
-    task_sender: Sender<Task>,           //任务sender
-    time: DateTime<Utc>,                 //房间创建时间
+    id: u32,                                //房间id
+    room_type: RoomType,                    //房间类型
+    owner_id: u32,                          //房主id
+    pub state: RoomState,                   //房间状态
+    pub members: HashMap<u32, Member>,      //玩家对应的队伍
+    pub member_index: [u32; MEMBER_MAX],    //玩家对应的位置
+    pub robots: HashSet<u32>,               //机器人
+    pub setting: RoomSetting,               //房间设置
+    pub net_handler: NetHandler,            //tcpsender
+    task_sender: Sender<Task>,              //任务sender
+    time: DateTime<Utc>,                    //房间创建时间
+    chat_history: VecDeque<HistoryMessPt>,  //聊天记录
+    pub draft: Option<Draft>,               //禁选数据
+    pub tournament: Option<TournamentInfo>, //锦标赛对局信息,只有锦标赛房间才有
+    pub rematch: Option<HashSet<u32>>,      //同意再来一局的玩家,自定义房间打完才有
 }
 
 tools::get_mut_ref!(Room);
@@ -133,6 +156,10 @@ impl Room {
             net_handler: sender,
             task_sender,
             time,
+            chat_history: VecDeque::new(),
+            draft: None,
+            tournament: None,
+            rematch: None,
         };
 
         let mut size = room.members.len() as u8;
@@ -167,12 +194,14 @@ impl Room {
             return true;
         }
         match room_type {
-            RoomType::OneVOneVOneVOneCustom | RoomType::WorldBossCustom => {
+            RoomType::OneVOneVOneVOneCustom
+            | RoomType::WorldBossCustom
+            | RoomType::TwoVTwoCustom => {
                 if self.get_owner_id() == 0 && state != RoomState::ChoiceIndex {
                     return true;
                 }
             }
-            RoomType::OneVOneVOneVOneMatch | RoomType::WorldBoseMatch => {
+            RoomType::OneVOneVOneVOneMatch | RoomType::WorldBoseMatch | RoomType::TwoVTwoMatch => {
                 //最后一个胜者
                 if state == RoomState::ChoiceIndex && self.members.len() == 1 {
                     return true;
@@ -242,6 +271,22 @@ impl Room {
         tcp.node_handler.network().send(endpoint, bytes.as_slice());
     }
 
+    ///同步在线状态给游戏服,机器人不用同步
+    pub fn sync_presence(&mut self, state: PresenceState) {
+        let mut proto = R_G_SYNC_PRESENCE::new();
+        proto.set_presence(state.into_u32());
+        let bytes = proto.write_to_bytes().unwrap();
+        let users: Vec<u32> = self
+            .members
+            .values()
+            .filter(|member| member.robot_temp_id == 0)
+            .map(|member| member.user_id)
+            .collect();
+        for user_id in users {
+            self.send_2_server(GameCode::SyncPresence.into_u32(), user_id, bytes.clone());
+        }
+    }
+
     pub fn get_member_vec(&self) -> Vec<u32> {
         let mut v = Vec::new();
         for member in self.members.keys() {
@@ -321,6 +366,32 @@ impl Room {
 
     ///检查角色
     pub fn check_character(&self, user_id: u32, cter_id: u32) -> anyhow::Result<()> {
+        //只能选游戏服下发的角色,没有dlc权益的角色游戏服不会下发
+        if cter_id != 0 {
+            match self.members.get(&user_id) {
+                Some(member) if member.cters.contains_key(&cter_id) => {}
+                _ => anyhow::bail!(
+                    "this player do not have this character!user_id:{},cter_id:{}",
+                    user_id,
+                    cter_id
+                ),
+            }
+        }
+        //禁选过的房间,禁用的角色不能选,禁选选好的角色不能换
+        if let Some(draft) = self.draft.as_ref() {
+            if self.state == RoomState::Draft {
+                anyhow::bail!("this room is drafting!room_id:{}", self.id)
+            }
+            if draft.picks.contains_key(&user_id) {
+                anyhow::bail!(
+                    "this player's character was locked by draft!user_id:{}",
+                    user_id
+                )
+            }
+            if draft.is_banned(cter_id) {
+                anyhow::bail!("this character was banned!cter_id:{}", cter_id)
+            }
+        }
         for cter in self.members.values() {
             if cter.user_id == user_id {
                 continue;
@@ -510,9 +581,56 @@ impl Room {
             }
             index += 1;
         }
+        //组队模式两边人数要凑齐
+        if self.room_type.is_team_type() && !self.is_team_full() {
+            return false;
+        }
         index >= size
     }
 
+    ///队伍人数
+    pub fn get_team_member_count(&self, team_id: u8) -> usize {
+        self.members
+            .values()
+            .filter(|member| member.team_id == team_id)
+            .count()
+    }
+
+    ///找一个能放下size个人的队伍,优先人少的
+    pub fn find_free_team(&self, size: usize) -> Option<u8> {
+        find_free_team(&self.members, size)
+    }
+
+    ///组队模式每个队伍人都满了
+    pub fn is_team_full(&self) -> bool {
+        let team_count = (MEMBER_MAX / TEAM_MEMBER_MAX) as u8;
+        (1..=team_count).all(|team_id| self.get_team_member_count(team_id) == TEAM_MEMBER_MAX)
+    }
+
+    ///校验能不能换到这个队伍
+    pub fn check_change_team(&self, user_id: u32, team_id: u8) -> anyhow::Result<()> {
+        if !self.room_type.is_team_type() {
+            return Ok(());
+        }
+        let team_count = (MEMBER_MAX / TEAM_MEMBER_MAX) as u8;
+        if team_id > team_count {
+            anyhow::bail!(
+                "team_id is invaild for team room!team_id:{},room_id:{}",
+                team_id,
+                self.id
+            )
+        }
+        let count = self
+            .members
+            .values()
+            .filter(|member| member.team_id == team_id && member.user_id != user_id)
+            .count();
+        if count >= TEAM_MEMBER_MAX {
+            anyhow::bail!("this team is full!team_id:{},room_id:{}", team_id, self.id)
+        }
+        Ok(())
+    }
+
     ///获得房主ID
     pub fn get_owner_id(&self) -> u32 {
         self.owner_id
@@ -560,38 +678,41 @@ impl Room {
     ///添加成员
     pub fn add_member(
         &mut self,
-        mut member: Member,
+        member: Member,
         index: Option<usize>,
         is_need_notice: bool,
     ) -> anyhow::Result<u32> {
-        let mut size = self.members.len() as u8;
         let user_id = member.user_id;
-        size += 1;
-        if member.team_id == 0 {
-            member.team_id = size;
-        }
-        member.join_time = Local::now().timestamp_millis() as u64;
-
-        match index {
-            Some(index) => self.member_index[index] = user_id,
-            None => {
-                for i in 0..self.member_index.len() {
-                    if self.member_index[i] != 0 {
-                        continue;
-                    }
-                    self.member_index[i] = user_id;
-                    member.index = i as u32;
-                    break;
-                }
-            }
+        let is_team_type = self.room_type.is_team_type();
+        if let Err(e) = insert_member(
+            &mut self.members,
+            &mut self.member_index,
+            is_team_type,
+            member,
+            index,
+        ) {
+            anyhow::bail!("{:?},room_id:{}", e, self.id)
         }
-        self.members.insert(user_id, member);
         if is_need_notice {
             self.notice_new_member(user_id);
         }
         Ok(self.id)
     }
 
+    ///整批加入成员,中途失败的话把这一批已经加进来的删掉
+    pub fn add_members(&mut self, members: impl Iterator<Item = Member>) -> anyhow::Result<()> {
+        let is_team_type = self.room_type.is_team_type();
+        if let Err(e) = insert_members(
+            &mut self.members,
+            &mut self.member_index,
+            is_team_type,
+            members,
+        ) {
+            anyhow::bail!("{:?},room_id:{}", e, self.id)
+        }
+        Ok(())
+    }
+
     pub fn notice_confirm_count(&mut self, user_id: u32) {
         let member = self.get_member_mut(&user_id).unwrap();
         member.state = MemberState::NotReady;
@@ -623,6 +744,36 @@ impl Room {
 
         //通知房间里其他人
         self.room_add_member_notice(&user_id);
+
+        //补发聊天记录
+        self.send_chat_history(user_id);
+    }
+
+    ///记录聊天,超过上限就把最早的挤掉
+    pub fn push_chat_history(&mut self, mess: &NoticeMessPt) {
+        let mut hmp = HistoryMessPt::new();
+        hmp.set_user_id(mess.get_user_id());
+        hmp.set_nick_name(mess.get_nick_name().to_owned());
+        hmp.set_channel(mess.get_channel());
+        hmp.set_content(mess.get_content().to_owned());
+        hmp.set_time(mess.get_time());
+        self.chat_history.push_back(hmp);
+        while self.chat_history.len() > CHAT_HISTORY_MAX {
+            self.chat_history.pop_front();
+        }
+    }
+
+    ///下发聊天记录
+    pub fn send_chat_history(&mut self, user_id: u32) {
+        if self.chat_history.is_empty() {
+            return;
+        }
+        let mut sch = S_CHAT_HISTORY::new();
+        for hmp in self.chat_history.iter() {
+            sch.messes.push(hmp.clone());
+        }
+        let bytes = sch.write_to_bytes().unwrap();
+        self.send_2_client(ClientCode::ChatHistory, user_id, bytes);
     }
 
     //随便获得一个玩家,如果玩家id==0,则代表没有玩家了
@@ -685,6 +836,14 @@ impl Room {
             .find(|(_, &id)| id == user_id)
             .unwrap();
         self.member_index[index] = 0;
+        //等再来一局的时候有人不来了或者掉线了,剩下的人都同意了就直接开
+        if let Some(agrees) = self.rematch.as_mut() {
+            agrees.remove(&user_id);
+            if !self.members.is_empty() {
+                self.push_rematch_notice();
+                self.check_rematch();
+            }
+        }
     }
 
     ///换队伍
@@ -760,6 +919,20 @@ impl Room {
         rp
     }
 
+    ///是否是锦标赛房间
+    pub fn is_tournament(&self) -> bool {
+        self.tournament.is_some()
+    }
+
+    ///锦标赛房间人到齐并且都准备好了
+    pub fn check_tournament_ready(&mut self) -> bool {
+        let count = match self.tournament.as_ref() {
+            Some(info) => info.count as usize,
+            None => return false,
+        };
+        self.get_member_count() >= count && self.check_ready()
+    }
+
     pub fn is_started(&self) -> bool {
         if self.state == RoomState::ChoiceIndex {
             true
@@ -768,12 +941,358 @@ impl Room {
         }
     }
 
+    ///是否需要禁选阶段,排位匹配房才有
+    pub fn need_draft(&self) -> bool {
+        if !self.room_type.is_match_type() || self.room_type.is_boss_type() {
+            return false;
+        }
+        get_draft_config("draft_open", 0) > 0
+    }
+
+    ///开始禁选,按段位从高到低排选人顺序,禁用顺序反过来
+    pub fn start_draft(&mut self) {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by(|a, b| {
+            b.league
+                .id
+                .cmp(&a.league.id)
+                .then(b.league.league_score.cmp(&a.league.league_score))
+        });
+        let orders: Vec<u32> = members.iter().map(|member| member.user_id).collect();
+        let ban_count = get_draft_config("draft_ban_count", 1) as usize;
+        let mut draft = Draft::new(orders, ban_count);
+        //接着上一次的序号,避免旧的超时任务误判
+        if let Some(old_draft) = self.draft.as_ref() {
+            draft.seq = old_draft.seq + 1;
+        }
+        //之前选的角色作废,以禁选结果为准
+        for member in self.members.values_mut() {
+            if member.robot_temp_id > 0 {
+                continue;
+            }
+            member.chose_cter = Character::default();
+        }
+        self.draft = Some(draft);
+        self.state = RoomState::Draft;
+        info!(
+            "房间进入禁选阶段,room_type:{:?},room_id:{}",
+            self.room_type, self.id
+        );
+        self.next_draft_turn();
+    }
+
+    ///禁选阶段禁用角色
+    pub fn draft_ban(&mut self, user_id: u32, cter_temp_id: u32) -> anyhow::Result<()> {
+        if self.state != RoomState::Draft || self.draft.is_none() {
+            anyhow::bail!("this room is not drafting!room_id:{}", self.id)
+        }
+        if !crate::TEMPLATES
+            .character_temp_mgr()
+            .temps
+            .contains_key(&cter_temp_id)
+        {
+            anyhow::bail!("this character is invaild!cter_id:{}", cter_temp_id)
+        }
+        self.draft.as_mut().unwrap().ban(user_id, cter_temp_id)?;
+        self.next_draft_turn();
+        Ok(())
+    }
+
+    ///禁选阶段选择角色
+    pub fn draft_pick(&mut self, user_id: u32, cter_temp_id: u32) -> anyhow::Result<()> {
+        if self.state != RoomState::Draft || self.draft.is_none() {
+            anyhow::bail!("this room is not drafting!room_id:{}", self.id)
+        }
+        match self.members.get(&user_id) {
+            Some(member) if member.cters.contains_key(&cter_temp_id) => {}
+            _ => anyhow::bail!(
+                "this player do not have this character!user_id:{},cter_id:{}",
+                user_id,
+                cter_temp_id
+            ),
+        }
+        self.draft.as_mut().unwrap().pick(user_id, cter_temp_id)?;
+        self.next_draft_turn();
+        Ok(())
+    }
+
+    ///禁选行动超时,帮玩家自动禁选
+    pub fn draft_timeout(&mut self, seq: u32) {
+        if self.state != RoomState::Draft {
+            return;
+        }
+        let user_id = match self.draft.as_ref() {
+            Some(draft) => draft.timeout_user(seq),
+            None => return,
+        };
+        let user_id = match user_id {
+            Some(user_id) => user_id,
+            None => return,
+        };
+        info!(
+            "禁选行动超时,自动帮玩家操作,room_id:{},user_id:{}",
+            self.id, user_id
+        );
+        self.draft_auto_action(user_id);
+        self.next_draft_turn();
+    }
+
+    ///推进禁选,轮到机器人直接帮它操作,轮到玩家就等它操作或者超时
+    fn next_draft_turn(&mut self) {
+        let user_id = loop {
+            let draft = match self.draft.as_ref() {
+                Some(draft) => draft,
+                None => return,
+            };
+            if draft.is_finish() {
+                self.finish_draft();
+                return;
+            }
+            let user_id = draft.get_turn_user().unwrap();
+            let is_robot = match self.members.get(&user_id) {
+                Some(member) => member.robot_temp_id > 0,
+                None => true,
+            };
+            if !is_robot {
+                break user_id;
+            }
+            self.draft_auto_action(user_id);
+        };
+        let turn_time = get_draft_config("draft_turn_time", 20000);
+        let draft = self.draft.as_mut().unwrap();
+        draft.turn_end_time = Local::now().timestamp_millis() as u64 + turn_time;
+        let seq = draft.seq;
+        self.draft_notice();
+        build_draft_turn_task(
+            self.room_type,
+            self.id,
+            seq,
+            turn_time,
+            self.task_sender.clone(),
+        );
+        info!(
+            "禁选轮到玩家操作,room_id:{},user_id:{},seq:{}",
+            self.id, user_id, seq
+        );
+    }
+
+    ///自动禁选,机器人和超时的玩家用
+    fn draft_auto_action(&mut self, user_id: u32) {
+        let phase = self.draft.as_ref().unwrap().phase;
+        let res = match phase {
+            DraftPhase::Ban => {
+                let cter_temp_id = self.find_draft_ban(user_id);
+                self.draft.as_mut().unwrap().ban(user_id, cter_temp_id)
+            }
+            DraftPhase::Pick => {
+                let cter_temp_id = self.find_draft_pick(user_id);
+                self.draft.as_mut().unwrap().pick(user_id, cter_temp_id)
+            }
+            DraftPhase::Finish => Ok(()),
+        };
+        if let Err(e) = res {
+            warn!("{:?}", e);
+        }
+    }
+
+    ///成员能用的角色,机器人只有配置的那个
+    fn get_member_cters(member: &Member) -> Vec<u32> {
+        if member.robot_temp_id > 0 {
+            return vec![member.chose_cter.cter_temp_id];
+        }
+        member.cters.keys().copied().collect()
+    }
+
+    ///找要禁用的角色,禁对手里拥有人数最多的
+    fn find_draft_ban(&self, user_id: u32) -> u32 {
+        let draft = self.draft.as_ref().unwrap();
+        let team_id = self.members.get(&user_id).map(|member| member.team_id);
+        let mut count_map: HashMap<u32, usize> = HashMap::new();
+        for member in self.members.values() {
+            if member.user_id == user_id {
+                continue;
+            }
+            //组队模式不禁队友的
+            if self.room_type.is_team_type() && Some(member.team_id) == team_id {
+                continue;
+            }
+            for cter_temp_id in Room::get_member_cters(member) {
+                if cter_temp_id == 0 || draft.is_banned(cter_temp_id) {
+                    continue;
+                }
+                *count_map.entry(cter_temp_id).or_insert(0) += 1;
+            }
+        }
+        let max = count_map.values().copied().max().unwrap_or(0);
+        let v: Vec<u32> = count_map
+            .into_iter()
+            .filter(|&(_, count)| count == max)
+            .map(|(cter_temp_id, _)| cter_temp_id)
+            .collect();
+        if v.is_empty() {
+            return 0;
+        }
+        let index = rand::thread_rng().gen_range(0..v.len());
+        v[index]
+    }
+
+    ///找要选的角色,机器人优先自己配置的角色,玩家优先上次用过的角色
+    fn find_draft_pick(&self, user_id: u32) -> u32 {
+        let draft = self.draft.as_ref().unwrap();
+        let member = match self.members.get(&user_id) {
+            Some(member) => member,
+            None => return 0,
+        };
+        if member.robot_temp_id > 0 {
+            let cter_temp_id = member.chose_cter.cter_temp_id;
+            if !draft.is_unavailable(cter_temp_id) {
+                return cter_temp_id;
+            }
+            //自己的角色没了就换一个机器人配置
+            return crate::TEMPLATES
+                .robot_temp_mgr()
+                .temps
+                .values()
+                .map(|robot_temp| robot_temp.cter_id)
+                .find(|&cter_temp_id| !draft.is_unavailable(cter_temp_id))
+                .unwrap_or(0);
+        }
+        draft.choose_pick(member.cters.values().collect())
+    }
+
+    ///禁选结束,把选好的角色设置上去,进入准备阶段
+    fn finish_draft(&mut self) {
+        let picks = self.draft.as_ref().unwrap().picks.clone();
+        let robot_temp_mgr = crate::TEMPLATES.robot_temp_mgr();
+        for (user_id, cter_temp_id) in picks {
+            let member = match self.members.get_mut(&user_id) {
+                Some(member) => member,
+                None => continue,
+            };
+            if member.robot_temp_id > 0 {
+                if member.chose_cter.cter_temp_id == cter_temp_id {
+                    continue;
+                }
+                //机器人换成对应角色的配置
+                let res = robot_temp_mgr
+                    .temps
+                    .iter()
+                    .find(|(_, robot_temp)| robot_temp.cter_id == cter_temp_id);
+                if let Some((&robot_temp_id, robot_temp)) = res {
+                    member.robot_temp_id = robot_temp_id;
+                    member.chose_cter.cter_temp_id = cter_temp_id;
+                    member.chose_cter.skills = robot_temp.skills.clone();
+                }
+            } else if let Some(cter) = member.cters.get(&cter_temp_id) {
+                let mut cter = cter.clone();
+                cter.skills.clear();
+                member.chose_cter = cter;
+            }
+        }
+        self.state = RoomState::AwaitReady;
+        self.draft_notice();
+        info!(
+            "禁选结束,进入准备阶段,room_type:{:?},room_id:{}",
+            self.room_type, self.id
+        );
+        build_match_room_ready_task(self.id, self.task_sender.clone());
+    }
+
+    ///禁选推送
+    fn draft_notice(&mut self) {
+        let draft = match self.draft.as_ref() {
+            Some(draft) => draft,
+            None => return,
+        };
+        let res = draft.build_notice().write_to_bytes();
+        match res {
+            Ok(bytes) => self.send_2_all_client(ClientCode::DraftNotice, bytes),
+            Err(e) => error!("{:?}", e),
+        }
+    }
+
+    ///自定义房间打完了,等大家选要不要再来一局
+    pub fn open_rematch(&mut self) {
+        self.rematch = Some(HashSet::new());
+        self.push_rematch_notice();
+    }
+
+    ///同意再来一局,用上局的角色直接准备,真人玩家都同意了就直接开
+    pub fn rematch(&mut self, user_id: u32) -> anyhow::Result<()> {
+        if self.state != RoomState::AwaitReady {
+            anyhow::bail!(
+                "the room is not await ready!room_id:{},room_state:{:?}",
+                self.id,
+                self.state
+            )
+        }
+        let member = self.members.get(&user_id);
+        if member.is_none() {
+            anyhow::bail!("this player is not in the room!user_id:{}", user_id)
+        }
+        if member.unwrap().chose_cter.cter_temp_id == 0 {
+            anyhow::bail!("this player has no last character!user_id:{}", user_id)
+        }
+        match self.rematch.as_mut() {
+            Some(agrees) => {
+                agrees.insert(user_id);
+            }
+            None => anyhow::bail!("there is no rematch in this room!room_id:{}", self.id),
+        }
+        self.prepare_cancel(&user_id, true);
+        self.push_rematch_notice();
+        self.check_rematch();
+        Ok(())
+    }
+
+    ///剩下的真人都同意再来一局就直接开,房主走了房间要解散就不开了
+    fn check_rematch(&mut self) {
+        let agrees = match self.rematch.as_ref() {
+            Some(agrees) => agrees,
+            None => return,
+        };
+        if self.state != RoomState::AwaitReady || self.owner_id == 0 {
+            return;
+        }
+        if !is_rematch_agreed(agrees, &self.members) || !self.check_ready() {
+            return;
+        }
+        info!("所有人都同意再来一局,直接开始!room_id:{}", self.id);
+        self.start();
+    }
+
+    ///推送再来一局的同意情况
+    fn push_rematch_notice(&mut self) {
+        let mut srn = S_REMATCH_NOTICE::new();
+        srn.set_room_id(self.id);
+        if let Some(agrees) = self.rematch.as_ref() {
+            srn.set_agrees(agrees.iter().copied().collect());
+        }
+        let bytes = match srn.write_to_bytes() {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                error!("{:?}", e);
+                return;
+            }
+        };
+        let user_ids: Vec<u32> = self
+            .members
+            .values()
+            .filter(|member| member.robot_temp_id == 0)
+            .map(|member| member.user_id)
+            .collect();
+        for user_id in user_ids {
+            self.send_2_client(ClientCode::RematchNotice, user_id, bytes.clone());
+        }
+    }
+
     ///开始游戏
     pub fn start(&mut self) {
         if self.state == RoomState::ChoiceIndex {
             return;
         }
         self.state = RoomState::ChoiceIndex;
+        self.rematch = None;
         //通知战斗服务器，游戏开始战斗
         let user_id = self.owner_id;
         let mut rbs = R_B_START::new();
@@ -787,6 +1306,7 @@ impl Room {
         }
         let bytes = res.unwrap();
         self.send_2_server(BattleCode::Start.into_u32(), user_id, bytes);
+        self.sync_presence(PresenceState::InBattle);
         info!(
             "战斗开始，向战斗服发送BattleCode::Start，room_type:{:?},room_id:{},user_id:{}",
             self.room_type,
@@ -796,6 +1316,102 @@ impl Room {
     }
 }
 
+///再来一局是否都同意了,真人都要同意,至少还得有两个人
+fn is_rematch_agreed(agrees: &HashSet<u32>, members: &HashMap<u32, Member>) -> bool {
+    if members.len() < 2 {
+        return false;
+    }
+    members
+        .values()
+        .filter(|member| member.robot_temp_id == 0)
+        .all(|member| agrees.contains(&member.user_id))
+}
+
+///找一个能放下size个人的队伍,优先人少的
+pub fn find_free_team(members: &HashMap<u32, Member>, size: usize) -> Option<u8> {
+    let team_count = (MEMBER_MAX / TEAM_MEMBER_MAX) as u8;
+    (1..=team_count)
+        .map(|team_id| {
+            let count = members
+                .values()
+                .filter(|member| member.team_id == team_id)
+                .count();
+            (team_id, count)
+        })
+        .filter(|&(_, count)| count + size <= TEAM_MEMBER_MAX)
+        .min_by_key(|&(_, count)| count)
+        .map(|(team_id, _)| team_id)
+}
+
+///把成员放进房间,组队模式没指定队伍的补到人少的队伍
+fn insert_member(
+    members: &mut HashMap<u32, Member>,
+    member_index: &mut [u32; MEMBER_MAX],
+    is_team_type: bool,
+    mut member: Member,
+    index: Option<usize>,
+) -> anyhow::Result<()> {
+    let user_id = member.user_id;
+    if members.contains_key(&user_id) {
+        anyhow::bail!("member is already in room!user_id:{}", user_id)
+    }
+    if is_team_type {
+        if member.team_id == 0 {
+            match find_free_team(members, 1) {
+                Some(team_id) => member.team_id = team_id,
+                None => anyhow::bail!("all teams are full!user_id:{}", user_id),
+            }
+        } else {
+            let team_id = member.team_id;
+            let count = members.values().filter(|m| m.team_id == team_id).count();
+            if count >= TEAM_MEMBER_MAX {
+                anyhow::bail!("team is full!team_id:{}", team_id)
+            }
+        }
+    }
+    if member.team_id == 0 {
+        member.team_id = members.len() as u8 + 1;
+    }
+    member.join_time = Local::now().timestamp_millis() as u64;
+
+    match index {
+        Some(index) => member_index[index] = user_id,
+        None => match member_index.iter().position(|&id| id == 0) {
+            Some(i) => {
+                member_index[i] = user_id;
+                member.index = i as u32;
+            }
+            None => anyhow::bail!("room is full!user_id:{}", user_id),
+        },
+    }
+    members.insert(user_id, member);
+    Ok(())
+}
+
+///整批放进房间,中途失败的话把这一批已经放进来的删掉
+fn insert_members(
+    members: &mut HashMap<u32, Member>,
+    member_index: &mut [u32; MEMBER_MAX],
+    is_team_type: bool,
+    new_members: impl Iterator<Item = Member>,
+) -> anyhow::Result<()> {
+    let mut added = Vec::new();
+    for member in new_members {
+        let user_id = member.user_id;
+        if let Err(e) = insert_member(members, member_index, is_team_type, member, None) {
+            for id in added {
+                members.remove(&id);
+                if let Some(i) = member_index.iter().position(|&index_id| index_id == id) {
+                    member_index[i] = 0;
+                }
+            }
+            return Err(e);
+        }
+        added.push(user_id);
+    }
+    Ok(())
+}
+
 pub fn create_room_id() -> u32 {
     unsafe {
         let size = ROOM_ID.len() - 1;
@@ -811,3 +1427,136 @@ pub fn recycle_room_id(room_id: u32) {
         ROOM_ID.push(room_id);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_members(user_ids: &[u32], robot_ids: &[u32]) -> HashMap<u32, Member> {
+        let mut members = HashMap::new();
+        for &user_id in user_ids.iter().chain(robot_ids.iter()) {
+            let mut member = Member::default();
+            member.user_id = user_id;
+            if robot_ids.contains(&user_id) {
+                member.robot_temp_id = 1;
+            }
+            members.insert(user_id, member);
+        }
+        members
+    }
+
+    #[test]
+    fn rematch_agreed() {
+        let mut agrees = HashSet::new();
+        let mut members = new_members(&[1, 2], &[3]);
+        agrees.insert(1);
+        assert!(!is_rematch_agreed(&agrees, &members));
+        //机器人不用同意
+        agrees.insert(2);
+        assert!(is_rematch_agreed(&agrees, &members));
+
+        //有人不来了,剩下的都同意就能开
+        agrees.remove(&2);
+        members.remove(&2);
+        assert!(is_rematch_agreed(&agrees, &members));
+        //只剩一个人开不了
+        members.remove(&3);
+        assert!(!is_rematch_agreed(&agrees, &members));
+
+        //掉线的人没同意,剩下的人还要等没同意的
+        let members = new_members(&[1, 2, 4], &[]);
+        let mut agrees = HashSet::new();
+        agrees.insert(1);
+        assert!(!is_rematch_agreed(&agrees, &members));
+        let members = new_members(&[1, 4], &[]);
+        assert!(!is_rematch_agreed(&agrees, &members));
+        agrees.insert(4);
+        assert!(is_rematch_agreed(&agrees, &members));
+    }
+
+    fn new_member(user_id: u32, team_id: u8) -> Member {
+        let mut member = Member::default();
+        member.user_id = user_id;
+        member.team_id = team_id;
+        member
+    }
+
+    #[test]
+    fn free_team() {
+        let mut members = HashMap::new();
+        assert_eq!(find_free_team(&members, 2), Some(1));
+        members.insert(1, new_member(1, 1));
+        //优先人少的队伍
+        assert_eq!(find_free_team(&members, 1), Some(2));
+        assert_eq!(find_free_team(&members, 2), Some(2));
+        members.insert(2, new_member(2, 2));
+        assert_eq!(find_free_team(&members, 1), Some(1));
+        assert_eq!(find_free_team(&members, 2), None);
+        members.insert(3, new_member(3, 1));
+        members.insert(4, new_member(4, 2));
+        assert_eq!(find_free_team(&members, 1), None);
+    }
+
+    #[test]
+    fn insert_balance_team() {
+        let mut members = HashMap::new();
+        let mut member_index = [0; MEMBER_MAX];
+        for user_id in 1..=4 {
+            insert_member(
+                &mut members,
+                &mut member_index,
+                true,
+                new_member(user_id, 0),
+                None,
+            )
+            .unwrap();
+        }
+        assert_eq!(members.values().filter(|m| m.team_id == 1).count(), 2);
+        assert_eq!(members.values().filter(|m| m.team_id == 2).count(), 2);
+        assert_eq!(member_index, [1, 2, 3, 4]);
+        //队伍都满了
+        assert!(insert_member(
+            &mut members,
+            &mut member_index,
+            true,
+            new_member(5, 0),
+            None
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn insert_members_rollback() {
+        let mut members = HashMap::new();
+        let mut member_index = [0; MEMBER_MAX];
+        insert_member(
+            &mut members,
+            &mut member_index,
+            true,
+            new_member(1, 1),
+            None,
+        )
+        .unwrap();
+        //第二个人进不了满了的队伍,第一个人要删掉
+        let party = vec![new_member(2, 1), new_member(3, 1)];
+        assert!(insert_members(&mut members, &mut member_index, true, party.into_iter()).is_err());
+        assert_eq!(members.len(), 1);
+        assert_eq!(member_index, [1, 0, 0, 0]);
+
+        //房间满了,前面进来的也要删掉
+        let party = (2..=5).map(|user_id| new_member(user_id, 0));
+        assert!(insert_members(&mut members, &mut member_index, false, party).is_err());
+        assert_eq!(members.len(), 1);
+        assert_eq!(member_index, [1, 0, 0, 0]);
+
+        //重复的人不能把原来的顶掉
+        let party = vec![new_member(2, 2), new_member(1, 2)];
+        assert!(insert_members(&mut members, &mut member_index, true, party.into_iter()).is_err());
+        assert_eq!(members.len(), 1);
+        assert_eq!(members.get(&1).unwrap().team_id, 1);
+
+        let party = vec![new_member(2, 2), new_member(3, 2)];
+        insert_members(&mut members, &mut member_index, true, party.into_iter()).unwrap();
+        assert_eq!(member_index, [1, 2, 3, 0]);
+    }
+}
@@ -19,6 +19,22 @@ pub enum TeamId {
     Max = 4, //最大teamid
 }
 
+///在线状态,同步给游戏服
+#[derive(Debug, Clone, Copy, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum PresenceState {
+    Online = 2,   //在线
+    InRoom = 3,   //房间中
+    InBattle = 4, //战斗中
+}
+
+impl PresenceState {
+    pub fn into_u32(self) -> u32 {
+        let res: u8 = self.into();
+        res as u32
+    }
+}
+
 ///房间类型
 #[derive(Debug, Clone, Copy, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
 #[repr(u8)]
@@ -28,6 +44,8 @@ pub enum RoomType {
     OneVOneVOneVOneMatch = 2,  //1v1v1v1匹配房间
     WorldBossCustom = 3,       //世界boss自定义房间
     WorldBoseMatch = 4,        //世界boss匹配房间
+    TwoVTwoCustom = 5,         //2v2自定义房间
+    TwoVTwoMatch = 6,          //2v2匹配房间
 }
 
 impl RoomType {
@@ -37,7 +55,9 @@ impl RoomType {
     }
 
     pub fn is_match_type(self) -> bool {
-        self == RoomType::OneVOneVOneVOneMatch || self == RoomType::WorldBoseMatch
+        self == RoomType::OneVOneVOneVOneMatch
+            || self == RoomType::WorldBoseMatch
+            || self == RoomType::TwoVTwoMatch
     }
 
     pub fn is_world_boss_type(self) -> bool {
@@ -45,7 +65,14 @@ impl RoomType {
     }
 
     pub fn is_custom_type(self) -> bool {
-        self == RoomType::OneVOneVOneVOneCustom || self == RoomType::WorldBossCustom
+        self == RoomType::OneVOneVOneVOneCustom
+            || self == RoomType::WorldBossCustom
+            || self == RoomType::TwoVTwoCustom
+    }
+
+    ///组队对战模式
+    pub fn is_team_type(self) -> bool {
+        self == RoomType::TwoVTwoCustom || self == RoomType::TwoVTwoMatch
     }
 
     pub fn into_u32(self) -> u32 {
@@ -57,9 +84,10 @@ impl RoomType {
 ///房间设置
 #[derive(Debug, Copy, Clone)]
 pub struct RoomSetting {
-    pub turn_limit_time: u32, //回合限制时间
-    pub season_is_open: bool, //赛季开关
-    pub ai_level: u8,         //ai等级
+    pub turn_limit_time: u32,   //回合限制时间
+    pub turn_reserve_time: u32, //每个玩家的备用时间
+    pub season_is_open: bool,   //赛季开关
+    pub ai_level: u8,           //ai等级
 }
 
 impl Default for RoomSetting {
@@ -93,6 +121,25 @@ impl Default for RoomSetting {
             season_is_open: false,
             ai_level: 3,
             turn_limit_time,
+            turn_reserve_time: get_turn_reserve_time("battle_turn_reserve_time"),
+        }
+    }
+}
+
+///从常量表拿备用时间,不同房间类型配不同的key
+pub fn get_turn_reserve_time(key: &str) -> u32 {
+    let temp = TEMPLATES.constant_temp_mgr().temps.get(key);
+    match temp {
+        Some(temp) => match u32::from_str(temp.value.as_str()) {
+            Ok(res) => res,
+            Err(err) => {
+                error!("{:?}", err);
+                0
+            }
+        },
+        None => {
+            warn!("constant temp's {} is none!", key);
+            0
         }
     }
 }
@@ -101,9 +148,11 @@ impl From<&RoomSettingPt> for RoomSetting {
     fn from(rs_pt: &RoomSettingPt) -> Self {
         let ai_level = rs_pt.ai_level as u8;
         let turn_limit_time = rs_pt.turn_limit_time;
+        let turn_reserve_time = rs_pt.turn_reserve_time;
         let season_is_open = rs_pt.season_is_open;
         let rs = RoomSetting {
             turn_limit_time,
+            turn_reserve_time,
             season_is_open,
             ai_level,
         };
@@ -116,6 +165,7 @@ impl From<&RoomSetting> for RoomSettingPt {
         let mut rsp = RoomSettingPt::new();
         rsp.set_season_is_open(r.season_is_open);
         rsp.set_turn_limit_time(r.turn_limit_time);
+        rsp.set_turn_reserve_time(r.turn_reserve_time);
         rsp.set_ai_level(r.ai_level as u32);
         rsp
     }
@@ -0,0 +1,63 @@
+use log::{error, warn};
+use protobuf::Message;
+use std::str::FromStr;
+use tools::protos::server_protocol::R_C_TOURNAMENT_RESULT;
+
+///锦标赛对局信息,锦标赛房间就是带着这个的自定义房间
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TournamentInfo {
+    pub tournament_id: u32, //锦标赛id
+    pub match_id: u32,      //对局id
+    pub count: u32,         //这局要到的人数
+}
+
+impl TournamentInfo {
+    pub fn new(tournament_id: u32, match_id: u32, count: u32) -> Self {
+        TournamentInfo {
+            tournament_id,
+            match_id,
+            count,
+        }
+    }
+
+    ///对局的唯一key,低32位是对局id,高32位是锦标赛id
+    pub fn get_key(&self) -> u64 {
+        tools::binary::combine_int_2_long(self.match_id, self.tournament_id)
+    }
+
+    ///封装上报给中心服的结果,ranks是按名次排好的玩家id
+    pub fn build_result(&self, ranks: Vec<u32>) -> Option<Vec<u8>> {
+        let mut proto = R_C_TOURNAMENT_RESULT::new();
+        proto.set_tournament_id(self.tournament_id);
+        proto.set_match_id(self.match_id);
+        proto.set_ranks(ranks);
+        match proto.write_to_bytes() {
+            Ok(bytes) => Some(bytes),
+            Err(e) => {
+                error!("{:?}", e);
+                None
+            }
+        }
+    }
+}
+
+///锦标赛房间等人到齐和准备的时间,过了时间没准备好的算弃权
+pub fn get_tournament_ready_time() -> u64 {
+    let res = crate::TEMPLATES
+        .constant_temp_mgr()
+        .temps
+        .get("tournament_ready_time");
+    match res {
+        Some(temp) => match u64::from_str(temp.value.as_str()) {
+            Ok(value) => value,
+            Err(e) => {
+                warn!("{:?}", e);
+                90000
+            }
+        },
+        None => {
+            warn!("the Constant tournament_ready_time is None!pls check!");
+            90000
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use std::{
+    borrow::BorrowMut,
+    collections::{hash_map::RandomState, HashMap},
+};
+
+use crossbeam::channel::Sender;
+use log::info;
+use rayon::slice::ParallelSliceMut;
+use tools::net_message_io::NetHandler;
+
+use crate::{
+    room::room::recycle_room_id,
+    task_timer::{build_confirm_into_room_task, Task},
+};
+
+use super::{
+    member::Member,
+    room::{Room, RoomState, MEMBER_MAX, TEAM_MEMBER_MAX},
+    room_model::{RoomCache, RoomModel, RoomSetting, RoomType},
+};
+
+///2v2匹配房结构体
+#[derive(Clone, Default)]
+pub struct TwoVTwoMatchRoom {
+    pub rooms: HashMap<u32, Room>,  //key:房间id    value:房间结构体
+    pub room_cache: Vec<RoomCache>, //key:房间id    value:房间人数
+}
+
+impl RoomModel for TwoVTwoMatchRoom {
+    fn get_room_type(&self) -> RoomType {
+        RoomType::TwoVTwoMatch
+    }
+
+    fn get_room_mut(&mut self, room_id: &u32) -> Option<&mut Room> {
+        self.rooms.get_mut(room_id)
+    }
+
+    fn get_room_ref(&self, room_id: &u32) -> Option<&Room> {
+        self.rooms.get(room_id)
+    }
+
+    ///创建房间
+    fn create_room(
+        &mut self,
+        owner: Member,
+        _: Option<RoomSetting>,
+        sender: NetHandler,
+        task_sender: crossbeam::channel::Sender<Task>,
+    ) -> anyhow::Result<u32> {
+        let room = Room::new(owner, RoomType::TwoVTwoMatch, sender, task_sender)?;
+        let room_id = room.get_room_id();
+        self.rooms.insert(room_id, room);
+        let mut rc = RoomCache::default();
+        rc.room_id = room_id;
+        rc.count = 1;
+        self.room_cache.push(rc);
+        self.room_cache.par_sort_by(|a, b| b.count.cmp(&a.count));
+        Ok(room_id)
+    }
+
+    ///离开房间
+    fn leave_room(
+        &mut self,
+        notice_type: u8,
+        room_id: &u32,
+        user_id: &u32,
+        need_push_self: bool,
+        _: bool,
+    ) -> anyhow::Result<u32> {
+        let room = self.get_mut_room_by_room_id(room_id)?;
+        let room_id = *room_id;
+        room.remove_member(notice_type, user_id, need_push_self);
+        //改变房间状态
+        room.state = RoomState::AwaitConfirm;
+        let need_remove = room.is_empty();
+        let now_count = room.get_member_count();
+        let mut need_add_cache = false;
+        //如果房间之前是满都，就给所有人取消准备
+        if room.get_state() == RoomState::AwaitConfirm && now_count < MEMBER_MAX {
+            room.do_cancel_prepare();
+            need_add_cache = true;
+        }
+
+        if need_remove {
+            return Ok(room_id);
+        }
+
+        let room_cache = self.get_room_cache_mut(&room_id);
+        if let Some(room_cache) = room_cache {
+            room_cache.count = now_count as u8;
+            //重新排序
+            self.room_cache.par_sort_by(|a, b| b.count.cmp(&a.count));
+        } else if need_add_cache {
+            let mut rc = RoomCache::default();
+            rc.room_id = room_id;
+            rc.count = now_count as u8;
+            self.room_cache.push(rc);
+            //重新排序
+            self.room_cache.par_sort_by(|a, b| b.count.cmp(&a.count));
+            info!(
+                "玩家离开2v2匹配房间，满足条件，将放进重新放入匹配队列,room_id:{}",
+                room_id
+            );
+        }
+        Ok(room_id)
+    }
+
+    ///删除房间
+    fn rm_room(&mut self, room_id: &u32) -> Option<Room> {
+        let res = self.rooms.remove(room_id);
+        self.remove_room_cache(room_id);
+        match res {
+            Some(room) => {
+                recycle_room_id(room.get_room_id());
+                info!(
+                    "删除房间，释放内存！room_type:{:?},room_id:{}",
+                    room.get_room_type(),
+                    room_id
+                );
+                Some(room)
+            }
+            None => None,
+        }
+    }
+
+    fn get_rooms_mut(&mut self) -> &mut HashMap<u32, Room, RandomState> {
+        self.rooms.borrow_mut()
+    }
+}
+
+impl TwoVTwoMatchRoom {
+    pub fn get_room_mut(&mut self, room_id: &u32) -> Option<&mut Room> {
+        self.rooms.get_mut(room_id)
+    }
+
+    pub fn get_room_cache_mut(&mut self, room_id: &u32) -> Option<&mut RoomCache> {
+        self.room_cache.iter_mut().find(|x| x.room_id == *room_id)
+    }
+
+    ///删除缓存房间
+    pub fn remove_room_cache(&mut self, room_id: &u32) {
+        let index = self
+            .room_cache
+            .iter()
+            .position(|room| room.room_id == *room_id);
+        if let Some(index) = index {
+            self.room_cache.remove(index);
+            //重新排序
+            self.room_cache.par_sort_by(|a, b| b.count.cmp(&a.count));
+        }
+    }
+
+    ///匹配,单人和组队都走这里
+    /// 组队的整队进同一个队伍,单人补到人少的队伍,没有能放下的房间就新建一个
+    pub fn quickly_start(
+        &mut self,
+        members: Vec<Member>,
+        sender: NetHandler,
+        task_sender: Sender<Task>,
+    ) -> anyhow::Result<u32> {
+        let size = members.len();
+        if size == 0 || size > TEAM_MEMBER_MAX {
+            anyhow::bail!("party size is error!size:{}", size)
+        }
+        let rooms = &self.rooms;
+        let res = find_match_room(&self.room_cache, |room_id| {
+            rooms.get(&room_id)?.find_free_team(size)
+        });
+        let mut members = members.into_iter();
+        let (room_id, team_id, is_new) = match res {
+            Some((room_id, team_id)) => (room_id, team_id, false),
+            None => {
+                let room_id =
+                    self.create_room(members.next().unwrap(), None, sender, task_sender.clone())?;
+                (room_id, 1, true)
+            }
+        };
+        let room_mut = self.get_mut_room_by_room_id(&room_id)?;
+        let members = members.map(|mut member| {
+            member.team_id = team_id;
+            member
+        });
+        if let Err(e) = room_mut.add_members(members) {
+            //队伍进不全就回滚,新建的房间直接删掉
+            if is_new {
+                self.rm_room(&room_id);
+            }
+            return Err(e);
+        }
+        let count = room_mut.get_member_count();
+        if count >= MEMBER_MAX {
+            self.remove_room_cache(&room_id);
+            info!("2v2匹配房人满,将房间从匹配队列移除！room_id:{}", room_id);
+            //推送匹配成功通知
+            let room_mut = self.rooms.get_mut(&room_id).unwrap();
+            room_mut.push_match_success();
+            //创建检测进入房间延迟任务
+            build_confirm_into_room_task(RoomType::TwoVTwoMatch, room_id, task_sender);
+        } else if let Some(room_cache) = self.get_room_cache_mut(&room_id) {
+            room_cache.count = count as u8;
+            self.room_cache.par_sort_by(|a, b| b.count.cmp(&a.count));
+        }
+        info!(
+            "玩家匹配到2v2房间！当前房间人数：{},party_size:{},team_id:{},room_id:{}",
+            count, size, team_id, room_id
+        );
+        Ok(room_id)
+    }
+}
+
+///按人数从多到少找第一个有队伍能放下的房间,返回房间id和队伍id
+fn find_match_room(
+    room_cache: &[RoomCache],
+    find_team: impl Fn(u32) -> Option<u8>,
+) -> Option<(u32, u8)> {
+    room_cache
+        .iter()
+        .find_map(|rc| Some((rc.room_id, find_team(rc.room_id)?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::room::room::find_free_team;
+
+    fn new_room(teams: &[u8]) -> HashMap<u32, Member> {
+        let mut members = HashMap::new();
+        for (i, &team_id) in teams.iter().enumerate() {
+            let mut member = Member::default();
+            member.user_id = i as u32 + 1;
+            member.team_id = team_id;
+            members.insert(member.user_id, member);
+        }
+        members
+    }
+
+    fn new_cache(rooms: &HashMap<u32, HashMap<u32, Member>>) -> Vec<RoomCache> {
+        let mut room_cache: Vec<RoomCache> = rooms
+            .iter()
+            .map(|(&room_id, members)| {
+                let mut rc = RoomCache::default();
+                rc.room_id = room_id;
+                rc.count = members.len() as u8;
+                rc
+            })
+            .collect();
+        room_cache.sort_by(|a, b| b.count.cmp(&a.count));
+        room_cache
+    }
+
+    #[test]
+    fn quickly_start_room() {
+        let mut rooms = HashMap::new();
+        rooms.insert(1, new_room(&[1, 2]));
+        rooms.insert(2, new_room(&[1, 1, 2]));
+        rooms.insert(3, new_room(&[1]));
+        let room_cache = new_cache(&rooms);
+        let find = |size: usize| {
+            find_match_room(&room_cache, |room_id| {
+                find_free_team(rooms.get(&room_id)?, size)
+            })
+        };
+        //单人补到人最多的房间里人少的队伍
+        assert_eq!(find(1), Some((2, 2)));
+        //两个人的队伍要整队放进一个空队伍
+        assert_eq!(find(2), Some((3, 2)));
+
+        //没有能整队放下的房间
+        rooms.remove(&3);
+        let room_cache = new_cache(&rooms);
+        assert_eq!(
+            find_match_room(&room_cache, |room_id| {
+                find_free_team(rooms.get(&room_id)?, 2)
+            }),
+            None
+        );
+    }
+}
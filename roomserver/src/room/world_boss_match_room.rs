@@ -17,6 +17,11 @@ use rayon::slice::ParallelSliceMut;
 use std::borrow::BorrowMut;
 use std::collections::HashMap;
 use tools::net_message_io::NetHandler;
+///房间里面再进size个人会不会超员,count包含worldboss
+fn can_hold(count: usize, size: usize) -> bool {
+    count + size <= MEMBER_MAX
+}
+
 ///worldboss匹配房结构体
 #[derive(Clone, Default)]
 pub struct WorldBossMatchRoom {
@@ -103,6 +108,63 @@ impl WorldBossMatchRoom {
         Ok(room_id)
     }
 
+    ///组队快速加入,整个队伍进同一个房间,没有能放下整队的房间就新建一个
+    pub fn quickly_start_party(
+        &mut self,
+        members: Vec<Member>,
+        sender: NetHandler,
+        task_sender: Sender<Task>,
+    ) -> anyhow::Result<u32> {
+        let size = members.len();
+        //新房间里面只有worldboss
+        if size == 0 || !can_hold(1, size) {
+            anyhow::bail!("party size is error!size:{}", size)
+        }
+        let res = self
+            .room_cache
+            .iter()
+            .find(|rc| can_hold(rc.count as usize, size))
+            .map(|rc| rc.room_id);
+        let mut members = members.into_iter().map(|mut member| {
+            member.team_id = 1;
+            member
+        });
+        let (room_id, is_new) = match res {
+            Some(room_id) => (room_id, false),
+            None => {
+                let owner = members.next().unwrap();
+                let room_id = self.create_room(owner, None, sender, task_sender.clone())?;
+                (room_id, true)
+            }
+        };
+        let room_mut = self.get_mut_room_by_room_id(&room_id)?;
+        if let Err(e) = room_mut.add_members(members) {
+            //队伍进不全就回滚,新建的房间直接删掉
+            if is_new {
+                self.rm_room(&room_id);
+            }
+            return Err(e);
+        }
+        let count = room_mut.get_member_count();
+        if count >= MEMBER_MAX {
+            self.remove_room_cache(&room_id);
+            info!("匹配房人满,将房间从匹配队列移除！room_id:{}", room_id);
+            //推送匹配成功通知
+            let room_mut = self.rooms.get_mut(&room_id).unwrap();
+            room_mut.push_match_success();
+            //创建检测进入房间延迟任务
+            build_confirm_into_room_task(RoomType::WorldBoseMatch, room_id, task_sender);
+        } else if let Some(room_cache) = self.get_room_cache_mut(&room_id) {
+            room_cache.count = count as u8;
+            self.room_cache.par_sort_by(|a, b| b.count.cmp(&a.count));
+        }
+        info!(
+            "队伍匹配到房间！当前房间人数：{},party_size:{},room_id:{}",
+            count, size, room_id
+        );
+        Ok(room_id)
+    }
+
     fn get_room_cache_last_room_id(&self) -> anyhow::Result<u32> {
         let room_cache = self.room_cache.get(0);
         if room_cache.is_none() {
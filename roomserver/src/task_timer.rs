@@ -1,7 +1,8 @@
 use crate::room::match_room::MatchRoom;
 use crate::room::member::MemberState;
 use crate::room::room::{MemberLeaveNoticeType, RoomState, MEMBER_MAX};
-use crate::room::room_model::{RoomModel, RoomType};
+use crate::room::room_model::{PresenceState, RoomModel, RoomType};
+use crate::room::tournament::TournamentInfo;
 use crate::{Lock, SCHEDULED_MGR};
 use async_std::task::block_on;
 use chrono::Local;
@@ -23,6 +24,8 @@ use tools::protos::room::S_INTO_ROOM_CANCEL_NOTICE;
 pub enum TaskCmd {
     MatchRoomReady = 101,       //匹配房间准备
     MatchRoomConfirmInto = 102, //匹配房间，进入房间
+    DraftTurnTimeout = 103,     //禁选行动超时
+    TournamentRoomStart = 104,  //锦标赛房间到点开始
 }
 
 impl TaskCmd {
@@ -60,6 +63,8 @@ pub fn init_timer(rm: Lock) {
             let f = match task_cmd {
                 TaskCmd::MatchRoomReady => match_room_ready,
                 TaskCmd::MatchRoomConfirmInto => match_room_confirm_into,
+                TaskCmd::DraftTurnTimeout => draft_turn_timeout,
+                TaskCmd::TournamentRoomStart => tournament_room_start,
             };
             let m = move || f(rm_clone, task);
             SCHEDULED_MGR.execute_after(Duration::from_millis(delay), m);
@@ -125,6 +130,14 @@ fn match_room_confirm_into(rm: Lock, task: Task) {
             }
             room = res.unwrap();
         }
+        RoomType::TwoVTwoMatch => {
+            let match_room = lock.two_v_two_match_room.borrow_mut();
+            let res = match_room.get_room_mut(&room_id);
+            if res.is_none() {
+                return;
+            }
+            room = res.unwrap();
+        }
         _ => todo!(),
     }
 
@@ -246,6 +259,7 @@ fn match_room_ready(rm: Lock, task: Task) {
 
             for member_id in rm_v {
                 lock.player_room.remove(&member_id);
+                lock.sync_presence(member_id, PresenceState::Online);
             }
             unsafe {
                 let room = match_room_ptr.as_mut().unwrap().get_room_mut(&room_id);
@@ -325,3 +339,151 @@ pub fn build_confirm_into_room_task(room_type: RoomType, room_id: u32, task_send
         error!("{:?}", e);
     }
 }
+
+///禁选行动超时,帮玩家自动操作
+fn draft_turn_timeout(rm: Lock, task: Task) {
+    let json_value = task.data;
+    let res = json_value.as_object();
+    if res.is_none() {
+        return;
+    }
+    let map = res.unwrap();
+    let room_id = map.get("room_id").and_then(|value| value.as_u64());
+    let room_type = map.get("room_type").and_then(|value| value.as_u64());
+    let seq = map.get("seq").and_then(|value| value.as_u64());
+    if room_id.is_none() || room_type.is_none() || seq.is_none() {
+        return;
+    }
+    let room_type = RoomType::try_from(room_type.unwrap() as u8);
+    if let Err(e) = room_type {
+        error!("{:?}", e);
+        return;
+    }
+
+    let mut lock = block_on(rm.lock());
+    let room = lock.get_room_mut(room_type.unwrap(), room_id.unwrap() as u32);
+    if let Ok(room) = room {
+        room.draft_timeout(seq.unwrap() as u32);
+    }
+}
+
+pub fn build_draft_turn_task(
+    room_type: RoomType,
+    room_id: u32,
+    seq: u32,
+    delay: u64,
+    task_sender: Sender<Task>,
+) {
+    //创建延迟任务，并发送给定时器接收方执行
+    let mut task = Task::default();
+    task.delay = delay + 500;
+    task.cmd = TaskCmd::DraftTurnTimeout as u16;
+    let mut map = Map::new();
+    map.insert("room_id".to_owned(), JsonValue::from(room_id));
+    map.insert("room_type".to_owned(), JsonValue::from(room_type.into_u8()));
+    map.insert("seq".to_owned(), JsonValue::from(seq));
+    task.data = JsonValue::from(map);
+    let res = task_sender.send(task);
+    if let Err(e) = res {
+        error!("{:?}", e);
+    }
+}
+
+///锦标赛房间到点了,没准备好的T出去算弃权,剩下的人够开局就开打,不够就直接上报结果
+fn tournament_room_start(rm: Lock, task: Task) {
+    let json_value = task.data;
+    let res = json_value.as_object();
+    if res.is_none() {
+        return;
+    }
+    let map = res.unwrap();
+    let room_id = map.get("room_id").and_then(|value| value.as_u64());
+    let tournament_id = map.get("tournament_id").and_then(|value| value.as_u64());
+    let match_id = map.get("match_id").and_then(|value| value.as_u64());
+    let count = map.get("count").and_then(|value| value.as_u64());
+    if room_id.is_none() || tournament_id.is_none() || match_id.is_none() || count.is_none() {
+        return;
+    }
+    let room_id = room_id.unwrap() as u32;
+    let info = TournamentInfo::new(
+        tournament_id.unwrap() as u32,
+        match_id.unwrap() as u32,
+        count.unwrap() as u32,
+    );
+
+    let mut lock = block_on(rm.lock());
+    let room = lock
+        .custom_room
+        .get_room_mut(&room_id)
+        .filter(|room| room.tournament.map(|x| x.get_key()) == Some(info.get_key()));
+    //人都走光了,房间已经没了
+    if room.is_none() {
+        lock.report_tournament_result(info, vec![]);
+        return;
+    }
+    let room = room.unwrap();
+    if room.is_started() {
+        return;
+    }
+    //房主选好角色就算准备了
+    room.check_ready();
+    let not_ready: Vec<u32> = room
+        .members
+        .values()
+        .filter(|member| member.state != MemberState::Ready)
+        .map(|member| member.user_id)
+        .collect();
+    for member_id in not_ready {
+        let res = lock.custom_room.leave_room(
+            MemberLeaveNoticeType::Kicked as u8,
+            &room_id,
+            &member_id,
+            true,
+            true,
+        );
+        if let Err(e) = res {
+            error!("{:?}", e);
+            continue;
+        }
+        lock.player_room.remove(&member_id);
+        lock.sync_presence(member_id, PresenceState::Online);
+        info!(
+            "锦标赛房间到点了玩家还没准备,算弃权T出房间!room_id:{},user_id:{}",
+            room_id, member_id
+        );
+    }
+    let room = lock.custom_room.get_room_mut(&room_id).unwrap();
+    if room.get_member_count() >= 2 {
+        room.start();
+        return;
+    }
+    //不够开局,到场的人直接晋级
+    let ranks = room.get_member_vec();
+    lock.rm_room_without_push(RoomType::OneVOneVOneVOneCustom, room_id);
+    lock.report_tournament_result(info, ranks);
+}
+
+pub fn build_tournament_room_start_task(
+    room_id: u32,
+    info: TournamentInfo,
+    delay: u64,
+    task_sender: Sender<Task>,
+) {
+    //创建延迟任务，并发送给定时器接收方执行
+    let mut task = Task::default();
+    task.delay = delay + 500;
+    task.cmd = TaskCmd::TournamentRoomStart as u16;
+    let mut map = Map::new();
+    map.insert("room_id".to_owned(), JsonValue::from(room_id));
+    map.insert(
+        "tournament_id".to_owned(),
+        JsonValue::from(info.tournament_id),
+    );
+    map.insert("match_id".to_owned(), JsonValue::from(info.match_id));
+    map.insert("count".to_owned(), JsonValue::from(info.count));
+    task.data = JsonValue::from(map);
+    let res = task_sender.send(task);
+    if let Err(e) = res {
+        error!("{:?}", e);
+    }
+}
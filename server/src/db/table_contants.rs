@@ -10,3 +10,18 @@ pub const GRADE_FRAME: &str = "t_u_grade_frame";
 
 //灵魂头像
 pub const SOUL: &str = "t_u_soul";
+
+//权益
+pub const ENTITLEMENT: &str = "t_u_entitlement";
+
+//邮箱
+pub const MAIL: &str = "t_u_mail";
+
+//局外任务
+pub const QUEST: &str = "t_u_quest";
+
+//成就
+pub const ACHIEVEMENT: &str = "t_u_achievement";
+
+//改名日志
+pub const NICK_NAME_LOG: &str = "t_nick_name_log";
@@ -0,0 +1,290 @@
+use super::*;
+use crate::TEMPLATES;
+use num_enum::IntoPrimitive;
+use num_enum::TryFromPrimitive;
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::collections::HashMap;
+use tools::protos::base::AchievementPt;
+use tools::templates::achievement_temp::AchievementTemp;
+
+///成就达成条件
+#[derive(Debug, Clone, Copy, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum AchievementCondition {
+    PlayMatch = 1,     //完成匹配
+    Win = 2,           //获胜,参数为角色id,0代表任意
+    WinCharacters = 3, //获胜过的角色数量
+    BattleEvent = 4,   //战斗事件累计,参数为战斗事件类型
+    Kill = 5,          //击杀
+    LoginDays = 6,     //登录天数
+}
+
+///玩家成就
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Achievements {
+    pub user_id: u32,                //玩家id
+    pub progress: HashMap<u32, u32>, //成就进度 key:成就id
+    pub unlocked: HashMap<u32, i64>, //已达成的成就 key:成就id value:达成时间(秒)
+    pub win_cters: Vec<u32>,         //获胜过的角色
+    #[serde(skip_serializing)]
+    pub version: Cell<u32>, //版本号
+}
+
+unsafe impl Send for Achievements {}
+
+unsafe impl Sync for Achievements {}
+
+impl Entity for Achievements {
+    fn set_user_id(&mut self, user_id: u32) {
+        self.user_id = user_id;
+    }
+
+    fn set_ids(&mut self, user_id: u32, _: u32) {
+        self.user_id = user_id;
+    }
+
+    fn update_login_time(&mut self) {}
+
+    fn update_off_time(&mut self) {}
+
+    fn day_reset(&mut self) {}
+
+    fn add_version(&self) {
+        let v = self.version.get() + 1;
+        self.version.set(v);
+    }
+
+    fn clear_version(&self) {
+        self.version.set(0);
+    }
+
+    fn get_version(&self) -> u32 {
+        self.version.get()
+    }
+
+    fn get_tem_id(&self) -> Option<u32> {
+        None
+    }
+
+    fn get_user_id(&self) -> u32 {
+        self.user_id
+    }
+
+    fn get_data(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    fn init(data: serde_json::Value) -> Self
+    where
+        Self: Sized,
+    {
+        let c = serde_json::from_value(data).unwrap();
+        c
+    }
+}
+
+impl EntityData for Achievements {
+    fn try_clone_for_db(&self) -> Box<dyn EntityData> {
+        let res = Box::new(self.clone());
+        self.version.set(0);
+        res
+    }
+}
+
+impl Dao for Achievements {
+    fn get_table_name(&self) -> &str {
+        "t_u_achievement"
+    }
+}
+
+impl Achievements {
+    pub fn new(user_id: u32) -> Self {
+        let mut a = Achievements::default();
+        a.user_id = user_id;
+        a
+    }
+
+    pub fn into_pt(&self, temp: &AchievementTemp) -> AchievementPt {
+        let mut ap = AchievementPt::new();
+        ap.set_achievement_id(temp.id);
+        ap.set_progress(self.progress.get(&temp.id).copied().unwrap_or(0));
+        ap.set_target(temp.target);
+        ap.set_unlock_time(self.unlocked.get(&temp.id).copied().unwrap_or(0));
+        ap
+    }
+
+    pub fn into_pts(&self) -> Vec<AchievementPt> {
+        TEMPLATES
+            .achievement_temp_mgr()
+            .temps
+            .values()
+            .map(|temp| self.into_pt(temp))
+            .collect()
+    }
+
+    ///给满足条件的成就加进度,返回有变化的成就id
+    pub fn add_progress(
+        &mut self,
+        condition: AchievementCondition,
+        par: u32,
+        value: u32,
+    ) -> Vec<u32> {
+        if value == 0 {
+            return Vec::new();
+        }
+        self.update_progress(condition, par, |progress| progress.saturating_add(value))
+    }
+
+    ///获胜,记录获胜过的角色
+    pub fn win(&mut self, cter_id: u32) -> Vec<u32> {
+        let mut changed = self.add_progress(AchievementCondition::Win, cter_id, 1);
+        if cter_id == 0 || self.win_cters.contains(&cter_id) {
+            return changed;
+        }
+        self.win_cters.push(cter_id);
+        self.add_version();
+        let count = self.win_cters.len() as u32;
+        for id in self.update_progress(AchievementCondition::WinCharacters, 0, |_| count) {
+            if !changed.contains(&id) {
+                changed.push(id);
+            }
+        }
+        changed
+    }
+
+    fn update_progress<F>(&mut self, condition: AchievementCondition, par: u32, f: F) -> Vec<u32>
+    where
+        F: Fn(u32) -> u32,
+    {
+        let mut changed = Vec::new();
+        for temp in TEMPLATES.achievement_temp_mgr().temps.values() {
+            if temp.condition != u8::from(condition) || (temp.par != 0 && temp.par != par) {
+                continue;
+            }
+            if self.unlocked.contains_key(&temp.id) {
+                continue;
+            }
+            let progress = self.progress.entry(temp.id).or_insert(0);
+            let res = f(*progress).min(temp.target);
+            if res == *progress {
+                continue;
+            }
+            *progress = res;
+            changed.push(temp.id);
+        }
+        if !changed.is_empty() {
+            self.add_version();
+        }
+        changed
+    }
+
+    ///检查是否达成,返回新达成的成就id
+    pub fn check_unlock(&mut self, ids: &[u32]) -> Vec<u32> {
+        let now = chrono::Local::now().timestamp();
+        let mut res = Vec::new();
+        for id in ids.iter() {
+            if self.unlocked.contains_key(id) {
+                continue;
+            }
+            let temp = match TEMPLATES.achievement_temp_mgr().get_temp(id) {
+                Ok(temp) => temp,
+                Err(e) => {
+                    warn!("{:?}", e);
+                    continue;
+                }
+            };
+            let progress = self.progress.get(id).copied().unwrap_or(0);
+            if progress < temp.target {
+                continue;
+            }
+            self.unlocked.insert(*id, now);
+            res.push(*id);
+        }
+        if !res.is_empty() {
+            self.add_version();
+        }
+        res
+    }
+
+    pub fn query(table_name: &str, user_id: u32) -> Option<Self> {
+        let mut v: Vec<Value> = Vec::new();
+        v.push(Value::UInt(user_id as u64));
+
+        let mut sql = String::new();
+        sql.push_str("select * from ");
+        sql.push_str(table_name);
+        sql.push_str(" where user_id=:user_id");
+
+        let q: Result<QueryResult, mysql::error::Error> = DB_POOL.exe_sql(sql.as_str(), Some(v));
+        if q.is_err() {
+            error!("{:?}", q.err().unwrap());
+            return None;
+        }
+        let q = q.unwrap();
+        let mut res = None;
+        for _qr in q {
+            let (_, data): (u32, serde_json::Value) = mysql::from_row(_qr.unwrap());
+            let c = Achievements::init(data);
+            res = Some(c);
+            break;
+        }
+        if res.is_none() {
+            return None;
+        }
+        let mut c = res.unwrap();
+        c.version = Cell::new(0);
+        Some(c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find_temp(condition: AchievementCondition) -> AchievementTemp {
+        TEMPLATES
+            .achievement_temp_mgr()
+            .temps
+            .values()
+            .find(|temp| temp.condition == u8::from(condition) && temp.par == 0)
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn check_unlock() {
+        let temp = find_temp(AchievementCondition::PlayMatch);
+        let mut a = Achievements::default();
+        a.add_progress(AchievementCondition::PlayMatch, 0, temp.target - 1);
+        assert!(a.check_unlock(&[temp.id]).is_empty());
+        a.add_progress(AchievementCondition::PlayMatch, 0, 1);
+        assert_eq!(a.check_unlock(&[temp.id, u32::MAX]), vec![temp.id]);
+        assert!(a.unlocked.contains_key(&temp.id));
+        //已达成的不会重复达成,也不再加进度
+        assert!(a.check_unlock(&[temp.id]).is_empty());
+        assert!(!a
+            .add_progress(AchievementCondition::PlayMatch, 0, 1)
+            .contains(&temp.id));
+    }
+
+    #[test]
+    fn win() {
+        let win_temp = find_temp(AchievementCondition::Win);
+        let cters_temp = find_temp(AchievementCondition::WinCharacters);
+        let mut a = Achievements::default();
+        let changed = a.win(1001);
+        assert!(changed.contains(&win_temp.id));
+        assert!(changed.contains(&cters_temp.id));
+        assert_eq!(a.progress[&cters_temp.id], 1);
+        //同一个角色再赢不算新角色
+        assert!(!a.win(1001).contains(&cters_temp.id));
+        assert_eq!(a.win_cters, vec![1001]);
+        a.win(1002);
+        assert_eq!(a.progress[&cters_temp.id], cters_temp.target.min(2));
+        //角色id为0不记录
+        a.win(0);
+        assert_eq!(a.win_cters, vec![1001, 1002]);
+    }
+}
@@ -179,9 +179,7 @@ impl Entity for Character {
         unimplemented!()
     }
 
-    fn day_reset(&mut self) {
-        unimplemented!()
-    }
+    fn day_reset(&mut self) {}
 
     fn add_version(&self) {
         let res = self.version.get() + 1;
@@ -0,0 +1,370 @@
+use super::*;
+use crate::db::table_contants::ENTITLEMENT;
+use crate::mgr::timer_mgr::RESET_SCHEDULE;
+use crate::TEMPLATES;
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::collections::HashMap;
+
+///权益状态
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum EntitlementState {
+    Active,   //生效中
+    Canceled, //已取消续订,到期之前依然有效
+    OnHold,   //账号保留/暂停,暂时收回权益
+    Expired,  //已过期
+    Revoked,  //已撤销(退款,作废)
+}
+
+impl Default for EntitlementState {
+    fn default() -> Self {
+        EntitlementState::Active
+    }
+}
+
+///gm发放权益用的购买令牌
+pub const GM_PURCHASE_TOKEN: &str = "gm";
+
+///单个商品的权益数据,由支付校验服推送过来
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EntitlementInfo {
+    pub user_id: u32,            //玩家id
+    pub product_id: String,      //商品id
+    pub purchase_token: String,  //购买令牌
+    pub order_id: String,        //订单id
+    pub is_subscription: bool,   //是否订阅
+    pub state: EntitlementState, //权益状态
+    pub expiry_time: i64,        //到期时间(毫秒),0代表永久
+    pub event_time: i64,         //最后一次更新对应的通知时间(毫秒)
+}
+
+impl EntitlementInfo {
+    ///gm发放或者收回权益,gm发的权益都用同一个令牌,收回的时候能覆盖掉
+    pub fn from_gm(user_id: u32, product_id: String, grant: bool) -> Self {
+        let now = RESET_SCHEDULE.now().timestamp_millis();
+        let mut info = EntitlementInfo::default();
+        info.user_id = user_id;
+        info.product_id = product_id;
+        info.purchase_token = GM_PURCHASE_TOKEN.to_owned();
+        info.order_id = format!("gm_{}", now);
+        info.state = if grant {
+            EntitlementState::Active
+        } else {
+            EntitlementState::Revoked
+        };
+        info.event_time = now;
+        info
+    }
+
+    ///当前是否享有权益
+    pub fn is_valid(&self) -> bool {
+        match self.state {
+            EntitlementState::Active | EntitlementState::Canceled => {}
+            _ => return false,
+        }
+        if self.expiry_time <= 0 {
+            return true;
+        }
+        self.expiry_time > RESET_SCHEDULE.now().timestamp_millis()
+    }
+}
+
+///玩家权益数据
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Entitlement {
+    pub user_id: u32,                                 //玩家id
+    pub entitlements: HashMap<String, EntitlementInfo>, //key:商品id
+    #[serde(skip_serializing)]
+    pub version: Cell<u32>, //版本号
+}
+
+unsafe impl Send for Entitlement {}
+
+unsafe impl Sync for Entitlement {}
+
+impl Entity for Entitlement {
+    fn set_user_id(&mut self, user_id: u32) {
+        self.user_id = user_id;
+    }
+
+    fn set_ids(&mut self, user_id: u32, _: u32) {
+        self.user_id = user_id;
+    }
+
+    fn update_login_time(&mut self) {}
+
+    fn update_off_time(&mut self) {}
+
+    fn day_reset(&mut self) {}
+
+    fn add_version(&self) {
+        let v = self.version.get() + 1;
+        self.version.set(v);
+    }
+
+    fn clear_version(&self) {
+        self.version.set(0);
+    }
+
+    fn get_version(&self) -> u32 {
+        self.version.get()
+    }
+
+    fn get_tem_id(&self) -> Option<u32> {
+        None
+    }
+
+    fn get_user_id(&self) -> u32 {
+        self.user_id
+    }
+
+    fn get_data(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    fn init(data: serde_json::Value) -> Self
+    where
+        Self: Sized,
+    {
+        let c = serde_json::from_value(data).unwrap();
+        c
+    }
+}
+
+impl EntityData for Entitlement {
+    fn try_clone_for_db(&self) -> Box<dyn EntityData> {
+        let res = Box::new(self.clone());
+        self.version.set(0);
+        res
+    }
+}
+
+impl Dao for Entitlement {
+    fn get_table_name(&self) -> &str {
+        "t_u_entitlement"
+    }
+}
+
+impl Entitlement {
+    pub fn new(user_id: u32) -> Self {
+        let mut e = Entitlement::default();
+        e.user_id = user_id;
+        e
+    }
+
+    ///更新权益,过时的通知和没有改动的返回错误
+    pub fn update_entitlement(&mut self, info: EntitlementInfo) -> anyhow::Result<()> {
+        let is_gm = info.purchase_token == GM_PURCHASE_TOKEN;
+        let old = self.entitlements.get_mut(info.product_id.as_str());
+        //gm收回权益,不管当前是哪个令牌发的都直接收回
+        if is_gm && info.state != EntitlementState::Active {
+            if old.is_none() {
+                anyhow::bail!(
+                    "there is no entitlement to revoke!user_id:{},product_id:{}",
+                    self.user_id,
+                    info.product_id
+                )
+            }
+            let old = old.unwrap();
+            if !old.is_valid() {
+                anyhow::bail!(
+                    "entitlement is already invalid!user_id:{},product_id:{},state:{:?}",
+                    self.user_id,
+                    info.product_id,
+                    old.state
+                )
+            }
+            //保留原来的令牌,同一个令牌后面的通知还能接着更新
+            old.state = info.state;
+            old.event_time = info.event_time;
+            self.add_version();
+            return Ok(());
+        }
+        if let Some(old) = old {
+            //同一个商品的新令牌不受旧令牌的通知影响
+            if old.purchase_token != info.purchase_token && info.state != EntitlementState::Active {
+                anyhow::bail!(
+                    "purchase_token is not the current one!user_id:{},product_id:{},purchase_token:{}",
+                    self.user_id,
+                    info.product_id,
+                    info.purchase_token
+                )
+            }
+            if old.purchase_token == info.purchase_token && old.event_time > info.event_time {
+                anyhow::bail!(
+                    "entitlement notification is out of date!user_id:{},product_id:{},event_time:{}",
+                    self.user_id,
+                    info.product_id,
+                    info.event_time
+                )
+            }
+        }
+        self.entitlements.insert(info.product_id.clone(), info);
+        self.add_version();
+        Ok(())
+    }
+
+    ///是否拥有某个商品的权益
+    pub fn has_entitlement(&self, product_id: &str) -> bool {
+        match self.entitlements.get(product_id) {
+            Some(info) => info.is_valid(),
+            None => false,
+        }
+    }
+
+    ///是否拥有某个dlc,dlc配置里面任意一个平台的商品有权益就算
+    pub fn has_dlc(&self, dlc_id: u32) -> bool {
+        let dlc_temp = TEMPLATES.dlc_temp_mgr().get_temp_ref(&dlc_id);
+        match dlc_temp {
+            Some(dlc_temp) => dlc_temp
+                .product_ids
+                .iter()
+                .any(|product_id| self.has_entitlement(product_id.as_str())),
+            None => false,
+        }
+    }
+
+    ///拥有的dlc列表
+    pub fn dlc_list(&self) -> Vec<u32> {
+        let mut v: Vec<u32> = TEMPLATES
+            .dlc_temp_mgr()
+            .temps
+            .keys()
+            .copied()
+            .filter(|dlc_id| self.has_dlc(*dlc_id))
+            .collect();
+        v.sort_unstable();
+        v
+    }
+
+    ///角色是否可用,不是dlc角色的都可以用
+    pub fn can_use_character(&self, cter_temp_id: u32) -> bool {
+        let cter_temp = TEMPLATES.character_temp_mgr().get_temp_ref(&cter_temp_id);
+        match cter_temp {
+            Some(cter_temp) if cter_temp.is_dlc > 0 => self.has_dlc(cter_temp.is_dlc as u32),
+            Some(_) => true,
+            None => false,
+        }
+    }
+
+    pub fn query(table_name: &str, user_id: u32) -> Option<Self> {
+        let mut v: Vec<Value> = Vec::new();
+        v.push(Value::UInt(user_id as u64));
+
+        let mut sql = String::new();
+        sql.push_str("select * from ");
+        sql.push_str(table_name);
+        sql.push_str(" where user_id=:user_id");
+
+        let q: Result<QueryResult, mysql::error::Error> = DB_POOL.exe_sql(sql.as_str(), Some(v));
+        if q.is_err() {
+            error!("{:?}", q.err().unwrap());
+            return None;
+        }
+        let q = q.unwrap();
+        let mut res = None;
+        for _qr in q {
+            let (_, data): (u32, serde_json::Value) = mysql::from_row(_qr.unwrap());
+            let c = Entitlement::init(data);
+            res = Some(c);
+            break;
+        }
+        if res.is_none() {
+            return None;
+        }
+        let mut c = res.unwrap();
+        c.version = Cell::new(0);
+        Some(c)
+    }
+}
+
+///处理支付校验服推送过来的或者gm发的权益变更,没有改动返回错误
+pub fn update_entitlement(gm: &mut GameMgr, info: EntitlementInfo) -> anyhow::Result<()> {
+    let user_id = info.user_id;
+    let product_id = info.product_id.clone();
+    let state = info.state;
+    //玩家在线直接改内存
+    if let Some(user_data) = gm.users.get_mut(&user_id) {
+        user_data.entitlement.update_entitlement(info)?;
+        user_data.add_version();
+        info!(
+            "update entitlement for online user!user_id:{},product_id:{},state:{:?}",
+            user_id, product_id, state
+        );
+        return Ok(());
+    }
+
+    //玩家不在线直接改db
+    match Entitlement::query(ENTITLEMENT, user_id) {
+        Some(mut entitlement) => {
+            entitlement.update_entitlement(info)?;
+            if let Err(e) = entitlement.update() {
+                anyhow::bail!("{:?}", e)
+            }
+        }
+        None => {
+            let mut entitlement = Entitlement::new(user_id);
+            entitlement.update_entitlement(info)?;
+            if let Err(e) = entitlement.insert() {
+                anyhow::bail!("{:?}", e)
+            }
+        }
+    }
+    info!(
+        "update entitlement for offline user!user_id:{},product_id:{},state:{:?}",
+        user_id, product_id, state
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn purchase(token: &str, state: EntitlementState, event_time: i64) -> EntitlementInfo {
+        let mut info = EntitlementInfo::default();
+        info.user_id = 1001;
+        info.product_id = "monthly_card".to_owned();
+        info.purchase_token = token.to_owned();
+        info.state = state;
+        info.event_time = event_time;
+        info
+    }
+
+    #[test]
+    fn gm_revoke_purchase() {
+        let mut entitlement = Entitlement::new(1001);
+        let info = purchase("purchase_token", EntitlementState::Active, 1);
+        assert!(entitlement.update_entitlement(info).is_ok());
+        assert!(entitlement.has_entitlement("monthly_card"));
+
+        let info = EntitlementInfo::from_gm(1001, "monthly_card".to_owned(), false);
+        assert!(entitlement.update_entitlement(info).is_ok());
+        assert!(!entitlement.has_entitlement("monthly_card"));
+        let info = entitlement.entitlements.get("monthly_card").unwrap();
+        assert_eq!(info.state, EntitlementState::Revoked);
+        assert_eq!(info.purchase_token, "purchase_token");
+
+        //已经收回了,再收回一次没有改动
+        let info = EntitlementInfo::from_gm(1001, "monthly_card".to_owned(), false);
+        assert!(entitlement.update_entitlement(info).is_err());
+        let info = EntitlementInfo::from_gm(1001, "other".to_owned(), false);
+        assert!(entitlement.update_entitlement(info).is_err());
+    }
+
+    #[test]
+    fn ignore_other_token() {
+        let mut entitlement = Entitlement::new(1001);
+        let info = purchase("new_token", EntitlementState::Active, 2);
+        assert!(entitlement.update_entitlement(info).is_ok());
+        //旧令牌的取消通知不影响新令牌
+        let info = purchase("old_token", EntitlementState::Revoked, 3);
+        assert!(entitlement.update_entitlement(info).is_err());
+        //同一个令牌过时的通知
+        let info = purchase("new_token", EntitlementState::Expired, 1);
+        assert!(entitlement.update_entitlement(info).is_err());
+        assert!(entitlement.has_entitlement("monthly_card"));
+    }
+}
@@ -0,0 +1,481 @@
+use super::*;
+use crate::mgr::timer_mgr::RESET_SCHEDULE;
+use num_enum::IntoPrimitive;
+use num_enum::TryFromPrimitive;
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use tools::protos::base::{AttachmentPt, MailPt};
+
+///邮箱最多保留的邮件数量
+pub const MAIL_MAX: usize = 100;
+
+///邮件类型
+#[derive(Debug, Clone, Copy, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum MailType {
+    System = 1,    //系统邮件
+    Broadcast = 2, //全服邮件
+}
+
+///附件类型
+#[derive(Debug, Clone, Copy, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum AttachType {
+    GradeFrame = 1, //grade相框
+    Soul = 2,       //灵魂头像
+}
+
+///邮件附件
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Attachment {
+    pub attach_type: u8, //附件类型
+    pub id: u32,         //配置id
+    pub count: u32,      //数量
+}
+
+impl Attachment {
+    pub fn new(attach_type: AttachType, id: u32, count: u32) -> Self {
+        Attachment {
+            attach_type: attach_type.into(),
+            id,
+            count,
+        }
+    }
+
+    pub fn from_pt(pt: &AttachmentPt) -> Self {
+        Attachment {
+            attach_type: pt.get_attach_type() as u8,
+            id: pt.get_id(),
+            count: pt.get_count(),
+        }
+    }
+
+    pub fn into_pt(&self) -> AttachmentPt {
+        let mut ap = AttachmentPt::new();
+        ap.set_attach_type(self.attach_type as u32);
+        ap.set_id(self.id);
+        ap.set_count(self.count);
+        ap
+    }
+}
+
+///邮件
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Mail {
+    pub mail_id: u32,                 //邮件id,邮箱内唯一
+    pub mail_type: u8,                //邮件类型
+    pub title: String,                //标题
+    pub content: String,              //内容
+    pub attachments: Vec<Attachment>, //附件
+    pub send_time: i64,               //发送时间(秒)
+    pub expire_time: i64,             //过期时间(秒),0代表不过期
+    pub is_read: bool,                //是否已读
+    pub is_claimed: bool,             //附件是否已领取
+}
+
+impl Mail {
+    pub fn new(
+        mail_type: MailType,
+        title: String,
+        content: String,
+        attachments: Vec<Attachment>,
+        expire_time: i64,
+    ) -> Self {
+        let mut mail = Mail::default();
+        mail.mail_type = mail_type.into();
+        mail.title = title;
+        mail.content = content;
+        mail.attachments = attachments;
+        mail.send_time = RESET_SCHEDULE.now().timestamp();
+        mail.expire_time = expire_time;
+        mail
+    }
+
+    pub fn from_pt(pt: &MailPt) -> Self {
+        let mut mail = Mail::default();
+        mail.mail_type = pt.get_mail_type() as u8;
+        mail.title = pt.get_title().to_owned();
+        mail.content = pt.get_content().to_owned();
+        mail.attachments = pt
+            .get_attachments()
+            .iter()
+            .map(Attachment::from_pt)
+            .collect();
+        mail.send_time = pt.get_send_time();
+        mail.expire_time = pt.get_expire_time();
+        mail
+    }
+
+    pub fn into_pt(&self) -> MailPt {
+        let mut mp = MailPt::new();
+        mp.set_mail_id(self.mail_id);
+        mp.set_mail_type(self.mail_type as u32);
+        mp.set_title(self.title.clone());
+        mp.set_content(self.content.clone());
+        for attachment in self.attachments.iter() {
+            mp.attachments.push(attachment.into_pt());
+        }
+        mp.set_send_time(self.send_time);
+        mp.set_expire_time(self.expire_time);
+        mp.set_is_read(self.is_read);
+        mp.set_is_claimed(self.is_claimed);
+        mp
+    }
+
+    ///是否过期
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expire_time > 0 && self.expire_time <= now
+    }
+
+    ///是否还有没领的附件
+    pub fn can_claim(&self) -> bool {
+        !self.is_claimed && !self.attachments.is_empty()
+    }
+}
+
+///玩家邮箱
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Mailbox {
+    pub user_id: u32,           //玩家id
+    pub next_id: u32,           //下一封邮件的id
+    pub last_broadcast_id: u64, //已经收过的最后一封全服邮件id
+    pub mails: Vec<Mail>,       //邮件,按发送顺序排列
+    #[serde(skip_serializing)]
+    pub version: Cell<u32>, //版本号
+}
+
+unsafe impl Send for Mailbox {}
+
+unsafe impl Sync for Mailbox {}
+
+impl Entity for Mailbox {
+    fn set_user_id(&mut self, user_id: u32) {
+        self.user_id = user_id;
+    }
+
+    fn set_ids(&mut self, user_id: u32, _: u32) {
+        self.user_id = user_id;
+    }
+
+    fn update_login_time(&mut self) {}
+
+    fn update_off_time(&mut self) {}
+
+    fn day_reset(&mut self) {
+        self.remove_expired();
+    }
+
+    fn add_version(&self) {
+        let v = self.version.get() + 1;
+        self.version.set(v);
+    }
+
+    fn clear_version(&self) {
+        self.version.set(0);
+    }
+
+    fn get_version(&self) -> u32 {
+        self.version.get()
+    }
+
+    fn get_tem_id(&self) -> Option<u32> {
+        None
+    }
+
+    fn get_user_id(&self) -> u32 {
+        self.user_id
+    }
+
+    fn get_data(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    fn init(data: serde_json::Value) -> Self
+    where
+        Self: Sized,
+    {
+        let c = serde_json::from_value(data).unwrap();
+        c
+    }
+}
+
+impl EntityData for Mailbox {
+    fn try_clone_for_db(&self) -> Box<dyn EntityData> {
+        let res = Box::new(self.clone());
+        self.version.set(0);
+        res
+    }
+}
+
+impl Dao for Mailbox {
+    fn get_table_name(&self) -> &str {
+        "t_u_mail"
+    }
+}
+
+impl Mailbox {
+    ///新号不补发建号之前的全服邮件
+    pub fn new(user_id: u32) -> Self {
+        let mut m = Mailbox::default();
+        m.user_id = user_id;
+        m.next_id = 1;
+        m.last_broadcast_id = RESET_SCHEDULE.now().timestamp_millis() as u64;
+        m
+    }
+
+    ///收邮件,返回分配的邮件id
+    pub fn add_mail(&mut self, mut mail: Mail) -> u32 {
+        if self.next_id == 0 {
+            self.next_id = 1;
+        }
+        let mail_id = self.next_id;
+        mail.mail_id = mail_id;
+        self.next_id += 1;
+        self.mails.push(mail);
+        self.trim();
+        self.add_version();
+        mail_id
+    }
+
+    ///邮箱满了先删过期的,再删附件领过的,然后是没有附件的(先删已读的),附件没领的不删
+    fn trim(&mut self) {
+        if self.mails.len() <= MAIL_MAX {
+            return;
+        }
+        let now = RESET_SCHEDULE.now().timestamp();
+        self.mails.retain(|mail| !mail.is_expired(now));
+        while self.mails.len() > MAIL_MAX {
+            let index = self
+                .mails
+                .iter()
+                .position(|mail| mail.is_claimed)
+                .or_else(|| {
+                    self.mails
+                        .iter()
+                        .position(|mail| !mail.can_claim() && mail.is_read)
+                })
+                .or_else(|| self.mails.iter().position(|mail| !mail.can_claim()));
+            match index {
+                Some(index) => {
+                    self.mails.remove(index);
+                }
+                None => {
+                    //剩下的都是附件没领的,宁可超上限也不能吞玩家的东西
+                    warn!(
+                        "mailbox is full of unclaimed attachments!user_id:{},len:{}",
+                        self.user_id,
+                        self.mails.len()
+                    );
+                    break;
+                }
+            }
+        }
+    }
+
+    ///删掉过期邮件,返回删掉的邮件id
+    pub fn remove_expired(&mut self) -> Vec<u32> {
+        let now = RESET_SCHEDULE.now().timestamp();
+        let expired: Vec<u32> = self
+            .mails
+            .iter()
+            .filter(|mail| mail.is_expired(now))
+            .map(|mail| mail.mail_id)
+            .collect();
+        if !expired.is_empty() {
+            self.mails.retain(|mail| !mail.is_expired(now));
+            self.add_version();
+        }
+        expired
+    }
+
+    pub fn get_mail_mut(&mut self, mail_id: u32) -> anyhow::Result<&mut Mail> {
+        let res = self.mails.iter_mut().find(|mail| mail.mail_id == mail_id);
+        match res {
+            Some(mail) => Ok(mail),
+            None => anyhow::bail!("mail is not exist!mail_id:{}", mail_id),
+        }
+    }
+
+    ///标记已读
+    pub fn read(&mut self, mail_id: u32) -> anyhow::Result<()> {
+        let now = RESET_SCHEDULE.now().timestamp();
+        let mail = self.get_mail_mut(mail_id)?;
+        if mail.is_expired(now) {
+            anyhow::bail!("mail is expired!mail_id:{}", mail_id)
+        }
+        if mail.is_read {
+            return Ok(());
+        }
+        mail.is_read = true;
+        self.add_version();
+        Ok(())
+    }
+
+    ///领取附件,领过的和过期的不能再领
+    pub fn claim(&mut self, mail_id: u32) -> anyhow::Result<Vec<Attachment>> {
+        let now = RESET_SCHEDULE.now().timestamp();
+        let mail = self.get_mail_mut(mail_id)?;
+        if mail.is_expired(now) {
+            anyhow::bail!("mail is expired!mail_id:{}", mail_id)
+        }
+        if mail.is_claimed {
+            anyhow::bail!("mail is already claimed!mail_id:{}", mail_id)
+        }
+        if mail.attachments.is_empty() {
+            anyhow::bail!("mail has no attachment!mail_id:{}", mail_id)
+        }
+        mail.is_claimed = true;
+        mail.is_read = true;
+        let attachments = mail.attachments.clone();
+        self.add_version();
+        Ok(attachments)
+    }
+
+    ///删除邮件,还有附件没领的不能删
+    pub fn delete(&mut self, mail_id: u32) -> anyhow::Result<()> {
+        let index = self.mails.iter().position(|mail| mail.mail_id == mail_id);
+        if index.is_none() {
+            anyhow::bail!("mail is not exist!mail_id:{}", mail_id)
+        }
+        let index = index.unwrap();
+        let now = RESET_SCHEDULE.now().timestamp();
+        let mail = self.mails.get(index).unwrap();
+        if mail.can_claim() && !mail.is_expired(now) {
+            anyhow::bail!("mail has attachments unclaimed!mail_id:{}", mail_id)
+        }
+        self.mails.remove(index);
+        self.add_version();
+        Ok(())
+    }
+
+    pub fn query(table_name: &str, user_id: u32) -> Option<Self> {
+        let mut v: Vec<Value> = Vec::new();
+        v.push(Value::UInt(user_id as u64));
+
+        let mut sql = String::new();
+        sql.push_str("select * from ");
+        sql.push_str(table_name);
+        sql.push_str(" where user_id=:user_id");
+
+        let q: Result<QueryResult, mysql::error::Error> = DB_POOL.exe_sql(sql.as_str(), Some(v));
+        if q.is_err() {
+            error!("{:?}", q.err().unwrap());
+            return None;
+        }
+        let q = q.unwrap();
+        let mut res = None;
+        for _qr in q {
+            let (_, data): (u32, serde_json::Value) = mysql::from_row(_qr.unwrap());
+            let c = Mailbox::init(data);
+            res = Some(c);
+            break;
+        }
+        if res.is_none() {
+            return None;
+        }
+        let mut c = res.unwrap();
+        c.version = Cell::new(0);
+        Some(c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_mail(with_attachment: bool, expire_time: i64) -> Mail {
+        let attachments = if with_attachment {
+            vec![Attachment::new(AttachType::Soul, 1, 1)]
+        } else {
+            vec![]
+        };
+        Mail::new(
+            MailType::System,
+            "title".to_owned(),
+            "content".to_owned(),
+            attachments,
+            expire_time,
+        )
+    }
+
+    #[test]
+    fn test_claim() {
+        let now = chrono::Local::now().timestamp();
+        let mut mailbox = Mailbox::new(1);
+        let normal = mailbox.add_mail(new_mail(true, 0));
+        let expired = mailbox.add_mail(new_mail(true, now - 1));
+        let empty = mailbox.add_mail(new_mail(false, 0));
+
+        assert_eq!(mailbox.claim(normal).unwrap().len(), 1);
+        //领过的不能再领
+        assert!(mailbox.claim(normal).is_err());
+        assert!(mailbox.claim(expired).is_err());
+        assert!(mailbox.claim(empty).is_err());
+        assert!(mailbox.claim(100).is_err());
+    }
+
+    #[test]
+    fn test_trim() {
+        let now = chrono::Local::now().timestamp();
+        let mut mailbox = Mailbox::new(1);
+        let unclaimed = mailbox.add_mail(new_mail(true, 0));
+        let empty = mailbox.add_mail(new_mail(false, 0));
+        let claimed = mailbox.add_mail(new_mail(true, 0));
+        mailbox.claim(claimed).unwrap();
+        let expired = mailbox.add_mail(new_mail(true, now - 1));
+        while mailbox.mails.len() < MAIL_MAX {
+            mailbox.add_mail(new_mail(true, 0));
+        }
+        let exist = |mailbox: &Mailbox, mail_id: u32| {
+            mailbox.mails.iter().any(|mail| mail.mail_id == mail_id)
+        };
+
+        //先删过期的,再删领过的,然后是没附件的,附件没领的不删
+        mailbox.add_mail(new_mail(true, 0));
+        assert!(!exist(&mailbox, expired));
+        assert!(exist(&mailbox, claimed));
+        mailbox.add_mail(new_mail(true, 0));
+        assert!(!exist(&mailbox, claimed));
+        assert!(exist(&mailbox, empty));
+        mailbox.add_mail(new_mail(true, 0));
+        assert!(!exist(&mailbox, empty));
+        assert!(exist(&mailbox, unclaimed));
+        mailbox.add_mail(new_mail(true, 0));
+        assert!(exist(&mailbox, unclaimed));
+        assert_eq!(mailbox.mails.len(), MAIL_MAX + 1);
+    }
+
+    #[test]
+    fn test_trim_keep_unclaimed() {
+        let mut mailbox = Mailbox::new(1);
+        let read = mailbox.add_mail(new_mail(false, 0));
+        let unread = mailbox.add_mail(new_mail(false, 0));
+        mailbox.read(read).unwrap();
+        let first = mailbox.add_mail(new_mail(true, 0));
+        while mailbox.mails.len() < MAIL_MAX {
+            mailbox.add_mail(new_mail(true, 0));
+        }
+        let exist = |mailbox: &Mailbox, mail_id: u32| {
+            mailbox.mails.iter().any(|mail| mail.mail_id == mail_id)
+        };
+
+        //没附件的先删已读的
+        mailbox.add_mail(new_mail(true, 0));
+        assert!(!exist(&mailbox, read));
+        assert!(exist(&mailbox, unread));
+        mailbox.add_mail(new_mail(true, 0));
+        assert!(!exist(&mailbox, unread));
+        assert_eq!(mailbox.mails.len(), MAIL_MAX);
+
+        //全是没领的附件,超上限也不删
+        for _ in 0..10 {
+            mailbox.add_mail(new_mail(true, 0));
+        }
+        assert_eq!(mailbox.mails.len(), MAIL_MAX + 10);
+        assert!(exist(&mailbox, first));
+        assert!(mailbox.mails.iter().all(|mail| mail.can_claim()));
+    }
+}
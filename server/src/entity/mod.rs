@@ -1,5 +1,9 @@
+pub mod achievement;
 pub mod character;
+pub mod entitlement;
 pub mod grade_frame;
+pub mod mail;
+pub mod quest;
 pub mod soul;
 pub mod user;
 pub mod user_info;
@@ -52,6 +56,8 @@ pub trait Entity: Send {
 
     ///每日重制（由time_mgr中的定时器调用）
     fn day_reset(&mut self);
+    ///每周重制（由time_mgr中的定时器调用）
+    fn week_reset(&mut self) {}
     ///添加版本号
     fn add_version(&self);
     ///清空版本号
@@ -0,0 +1,384 @@
+use super::*;
+use crate::entity::mail::Attachment;
+use crate::{CONF_MAP, TEMPLATES};
+use num_enum::IntoPrimitive;
+use num_enum::TryFromPrimitive;
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::convert::TryFrom;
+use tools::protos::base::{QuestPt, SummaryDataPt};
+use tools::templates::quest_temp::QuestTemp;
+
+///每日任务默认数量
+const DEFAULT_DAILY_QUEST_COUNT: usize = 3;
+///每周任务默认数量
+const DEFAULT_WEEKLY_QUEST_COUNT: usize = 3;
+
+///任务类型
+#[derive(Debug, Clone, Copy, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum QuestType {
+    Daily = 1,  //每日
+    Weekly = 2, //每周
+}
+
+///任务完成条件
+#[derive(Debug, Clone, Copy, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum QuestCondition {
+    PlayMatch = 1,    //完成匹配
+    UseCharacter = 2, //用指定角色完成匹配,参数为角色id,0代表任意
+    Kill = 3,         //击杀
+    Placement = 4,    //排名达到参数或者更好
+}
+
+///一局匹配的结算数据
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MatchResult {
+    pub cter_id: u32, //使用的角色
+    pub rank: u32,    //名次,从1开始
+    pub kills: u32,   //击杀数
+}
+
+impl From<&SummaryDataPt> for MatchResult {
+    fn from(summary_data: &SummaryDataPt) -> Self {
+        MatchResult {
+            cter_id: summary_data.get_cter_id(),
+            //结算里的排名从0开始
+            rank: summary_data.get_rank() + 1,
+            kills: summary_data.get_kills(),
+        }
+    }
+}
+
+///单个任务
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Quest {
+    pub quest_id: u32,    //任务id
+    pub progress: u32,    //当前进度
+    pub is_claimed: bool, //奖励是否已领取
+}
+
+impl Quest {
+    pub fn new(quest_id: u32) -> Self {
+        let mut quest = Quest::default();
+        quest.quest_id = quest_id;
+        quest
+    }
+
+    pub fn into_pt(&self, temp: &QuestTemp) -> QuestPt {
+        let mut qp = QuestPt::new();
+        qp.set_quest_id(self.quest_id);
+        qp.set_quest_type(temp.quest_type as u32);
+        qp.set_progress(self.progress);
+        qp.set_target(temp.target);
+        qp.set_is_claimed(self.is_claimed);
+        qp
+    }
+
+    ///按结算数据加进度,返回进度是否有变化
+    pub fn add_progress(&mut self, temp: &QuestTemp, res: &MatchResult) -> bool {
+        if self.progress >= temp.target {
+            return false;
+        }
+        let add = match QuestCondition::try_from(temp.condition) {
+            Ok(QuestCondition::PlayMatch) => 1,
+            Ok(QuestCondition::UseCharacter) => (temp.par == 0 || temp.par == res.cter_id) as u32,
+            Ok(QuestCondition::Kill) => res.kills,
+            Ok(QuestCondition::Placement) => (res.rank > 0 && res.rank <= temp.par) as u32,
+            Err(_) => {
+                warn!("quest condition is invalid!quest_id:{}", self.quest_id);
+                0
+            }
+        };
+        if add == 0 {
+            return false;
+        }
+        self.progress = (self.progress + add).min(temp.target);
+        true
+    }
+}
+
+///玩家局外任务
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Quests {
+    pub user_id: u32,       //玩家id
+    pub daily: Vec<Quest>,  //每日任务
+    pub weekly: Vec<Quest>, //每周任务
+    #[serde(skip_serializing)]
+    pub version: Cell<u32>, //版本号
+}
+
+unsafe impl Send for Quests {}
+
+unsafe impl Sync for Quests {}
+
+impl Entity for Quests {
+    fn set_user_id(&mut self, user_id: u32) {
+        self.user_id = user_id;
+    }
+
+    fn set_ids(&mut self, user_id: u32, _: u32) {
+        self.user_id = user_id;
+    }
+
+    fn update_login_time(&mut self) {}
+
+    fn update_off_time(&mut self) {}
+
+    fn day_reset(&mut self) {
+        self.refresh(QuestType::Daily);
+    }
+
+    fn week_reset(&mut self) {
+        self.refresh(QuestType::Weekly);
+    }
+
+    fn add_version(&self) {
+        let v = self.version.get() + 1;
+        self.version.set(v);
+    }
+
+    fn clear_version(&self) {
+        self.version.set(0);
+    }
+
+    fn get_version(&self) -> u32 {
+        self.version.get()
+    }
+
+    fn get_tem_id(&self) -> Option<u32> {
+        None
+    }
+
+    fn get_user_id(&self) -> u32 {
+        self.user_id
+    }
+
+    fn get_data(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    fn init(data: serde_json::Value) -> Self
+    where
+        Self: Sized,
+    {
+        let c = serde_json::from_value(data).unwrap();
+        c
+    }
+}
+
+impl EntityData for Quests {
+    fn try_clone_for_db(&self) -> Box<dyn EntityData> {
+        let res = Box::new(self.clone());
+        self.version.set(0);
+        res
+    }
+}
+
+impl Dao for Quests {
+    fn get_table_name(&self) -> &str {
+        "t_u_quest"
+    }
+}
+
+impl Quests {
+    pub fn new(user_id: u32) -> Self {
+        let mut q = Quests::default();
+        q.user_id = user_id;
+        q.refresh(QuestType::Daily);
+        q.refresh(QuestType::Weekly);
+        q
+    }
+
+    ///从任务池里重新抽任务,进度清0
+    pub fn refresh(&mut self, quest_type: QuestType) {
+        let count = match quest_type {
+            QuestType::Daily => CONF_MAP.get_usize("daily_quest_count", DEFAULT_DAILY_QUEST_COUNT),
+            QuestType::Weekly => {
+                CONF_MAP.get_usize("weekly_quest_count", DEFAULT_WEEKLY_QUEST_COUNT)
+            }
+        };
+        let quests = TEMPLATES
+            .quest_temp_mgr()
+            .random_quests(quest_type.into(), count)
+            .into_iter()
+            .map(Quest::new)
+            .collect();
+        match quest_type {
+            QuestType::Daily => self.daily = quests,
+            QuestType::Weekly => self.weekly = quests,
+        }
+        self.add_version();
+    }
+
+    pub fn get_quest_mut(&mut self, quest_id: u32) -> anyhow::Result<&mut Quest> {
+        let res = self
+            .daily
+            .iter_mut()
+            .chain(self.weekly.iter_mut())
+            .find(|quest| quest.quest_id == quest_id);
+        match res {
+            Some(quest) => Ok(quest),
+            None => anyhow::bail!("quest is not exist!quest_id:{}", quest_id),
+        }
+    }
+
+    ///匹配结算加进度,返回有变化的任务
+    pub fn on_match_result(&mut self, res: &MatchResult) -> Vec<QuestPt> {
+        let temp_mgr = TEMPLATES.quest_temp_mgr();
+        let mut changed = Vec::new();
+        for quest in self.daily.iter_mut().chain(self.weekly.iter_mut()) {
+            let temp = match temp_mgr.get_temp(&quest.quest_id) {
+                Ok(temp) => temp,
+                Err(e) => {
+                    warn!("{:?}", e);
+                    continue;
+                }
+            };
+            if quest.add_progress(temp, res) {
+                changed.push(quest.into_pt(temp));
+            }
+        }
+        if !changed.is_empty() {
+            self.add_version();
+        }
+        changed
+    }
+
+    ///领取奖励,完成了并且没领过的才能领
+    pub fn claim(&mut self, quest_id: u32) -> anyhow::Result<Attachment> {
+        let quest = self.get_quest_mut(quest_id)?;
+        let temp = TEMPLATES.quest_temp_mgr().get_temp(&quest_id)?;
+        if quest.is_claimed {
+            anyhow::bail!("quest is already claimed!quest_id:{}", quest_id)
+        }
+        if quest.progress < temp.target {
+            anyhow::bail!("quest is not complete!quest_id:{}", quest_id)
+        }
+        quest.is_claimed = true;
+        self.add_version();
+        Ok(Attachment {
+            attach_type: temp.reward_type,
+            id: temp.reward_id,
+            count: temp.reward_count,
+        })
+    }
+
+    pub fn into_pts(&self) -> Vec<QuestPt> {
+        let temp_mgr = TEMPLATES.quest_temp_mgr();
+        self.daily
+            .iter()
+            .chain(self.weekly.iter())
+            .filter_map(|quest| {
+                let temp = temp_mgr.get_temp(&quest.quest_id).ok()?;
+                Some(quest.into_pt(temp))
+            })
+            .collect()
+    }
+
+    pub fn query(table_name: &str, user_id: u32) -> Option<Self> {
+        let mut v: Vec<Value> = Vec::new();
+        v.push(Value::UInt(user_id as u64));
+
+        let mut sql = String::new();
+        sql.push_str("select * from ");
+        sql.push_str(table_name);
+        sql.push_str(" where user_id=:user_id");
+
+        let q: Result<QueryResult, mysql::error::Error> = DB_POOL.exe_sql(sql.as_str(), Some(v));
+        if q.is_err() {
+            error!("{:?}", q.err().unwrap());
+            return None;
+        }
+        let q = q.unwrap();
+        let mut res = None;
+        for _qr in q {
+            let (_, data): (u32, serde_json::Value) = mysql::from_row(_qr.unwrap());
+            let c = Quests::init(data);
+            res = Some(c);
+            break;
+        }
+        if res.is_none() {
+            return None;
+        }
+        let mut c = res.unwrap();
+        c.version = Cell::new(0);
+        Some(c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_temp(condition: QuestCondition, par: u32, target: u32) -> QuestTemp {
+        QuestTemp {
+            condition: condition.into(),
+            par,
+            target,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn add_progress() {
+        let mut sdp = SummaryDataPt::new();
+        sdp.set_cter_id(1001);
+        sdp.set_rank(0);
+        sdp.set_kills(2);
+        let first = MatchResult::from(&sdp);
+        assert_eq!(first.rank, 1);
+        sdp.set_rank(2);
+        let third = MatchResult::from(&sdp);
+
+        let temp = new_temp(QuestCondition::PlayMatch, 0, 2);
+        let mut quest = Quest::new(1);
+        assert!(quest.add_progress(&temp, &third));
+        assert!(quest.add_progress(&temp, &third));
+        assert!(!quest.add_progress(&temp, &third));
+        assert_eq!(quest.progress, 2);
+
+        let temp = new_temp(QuestCondition::UseCharacter, 1002, 1);
+        assert!(!Quest::new(2).add_progress(&temp, &first));
+        let temp = new_temp(QuestCondition::UseCharacter, 0, 1);
+        assert!(Quest::new(2).add_progress(&temp, &first));
+
+        let temp = new_temp(QuestCondition::Kill, 0, 3);
+        let mut quest = Quest::new(3);
+        assert!(quest.add_progress(&temp, &first));
+        assert!(quest.add_progress(&temp, &first));
+        assert_eq!(quest.progress, 3);
+
+        //第一名是结算排名0
+        let temp = new_temp(QuestCondition::Placement, 1, 1);
+        assert!(Quest::new(4).add_progress(&temp, &first));
+        assert!(!Quest::new(4).add_progress(&temp, &third));
+        assert!(!Quest::new(4).add_progress(&temp, &MatchResult::default()));
+    }
+
+    #[test]
+    fn claim() {
+        let temp = TEMPLATES
+            .quest_temp_mgr()
+            .temps
+            .values()
+            .next()
+            .unwrap()
+            .clone();
+        let mut quests = Quests::default();
+        quests.daily.push(Quest::new(temp.id));
+        assert!(quests.claim(temp.id).is_err());
+        assert!(quests.claim(u32::MAX).is_err());
+        quests.daily[0].progress = temp.target;
+        let attachment = quests.claim(temp.id).unwrap();
+        assert_eq!(attachment.attach_type, temp.reward_type);
+        assert_eq!(attachment.id, temp.reward_id);
+        assert_eq!(attachment.count, temp.reward_count);
+        assert!(quests.daily[0].is_claimed);
+        //不能重复领
+        assert!(quests.claim(temp.id).is_err());
+    }
+}
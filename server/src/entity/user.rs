@@ -1,8 +1,16 @@
 use super::*;
-use crate::db::table_contants::{CHARACTER, GRADE_FRAME, SOUL, USER};
+use crate::db::table_contants::{
+    ACHIEVEMENT, CHARACTER, ENTITLEMENT, GRADE_FRAME, MAIL, QUEST, SOUL, USER,
+};
+use crate::entity::achievement::Achievements;
 use crate::entity::character::{Character, Characters};
+use crate::entity::entitlement::Entitlement;
 use crate::entity::grade_frame::GradeFrame;
+use crate::entity::mail::Mailbox;
+use crate::entity::quest::Quests;
 use crate::entity::soul::Soul;
+use crate::helper::achievement_helper;
+use crate::mgr::timer_mgr::RESET_SCHEDULE;
 use std::borrow::{Borrow, BorrowMut};
 use std::cell::Cell;
 
@@ -17,6 +25,14 @@ pub struct UserData {
     pub grade_frame: GradeFrame,
     ///灵魂头像
     pub soul: Soul,
+    ///平台权益
+    pub entitlement: Entitlement,
+    ///邮箱
+    pub mailbox: Mailbox,
+    ///局外任务
+    pub quests: Quests,
+    ///成就
+    pub achievements: Achievements,
     ///版本号（大于0代表有修改，需要update到db）
     version: Cell<u32>,
 }
@@ -38,12 +54,20 @@ impl UserData {
         character: Characters,
         grade_frame: GradeFrame,
         soul: Soul,
+        entitlement: Entitlement,
+        mailbox: Mailbox,
+        quests: Quests,
+        achievements: Achievements,
     ) -> UserData {
         UserData {
             user_info,
             character,
             grade_frame,
             soul,
+            entitlement,
+            mailbox,
+            quests,
+            achievements,
             version: Cell::new(0),
         }
     }
@@ -54,7 +78,8 @@ impl UserData {
         if user.is_none() {
             return None;
         }
-        let user = user.unwrap();
+        let mut user = user.unwrap();
+        user.init_reset_time();
 
         let mut gf = GradeFrame::query(GRADE_FRAME, user_id);
         //grade相框数据
@@ -70,6 +95,34 @@ impl UserData {
             async_std::task::spawn(insert_soul(res.clone()));
             soul = Some(res);
         }
+        let mut entitlement = Entitlement::query(ENTITLEMENT, user_id);
+        //平台权益数据
+        if entitlement.is_none() {
+            let res = Entitlement::new(user.user_id);
+            async_std::task::spawn(insert_entitlement(res.clone()));
+            entitlement = Some(res);
+        }
+        let mut mailbox = Mailbox::query(MAIL, user_id);
+        //邮箱数据
+        if mailbox.is_none() {
+            let res = Mailbox::new(user.user_id);
+            async_std::task::spawn(insert_mailbox(res.clone()));
+            mailbox = Some(res);
+        }
+        let mut quests = Quests::query(QUEST, user_id);
+        //局外任务数据
+        if quests.is_none() {
+            let res = Quests::new(user.user_id);
+            async_std::task::spawn(insert_quests(res.clone()));
+            quests = Some(res);
+        }
+        let mut achievements = Achievements::query(ACHIEVEMENT, user_id);
+        //成就数据
+        if achievements.is_none() {
+            let res = Achievements::new(user.user_id);
+            async_std::task::spawn(insert_achievements(res.clone()));
+            achievements = Some(res);
+        }
         //初始化玩家角色数据
         let mut cters = Characters::query(CHARACTER, user_id);
         if cters.is_none() {
@@ -77,7 +130,16 @@ impl UserData {
             async_std::task::spawn(insert_characters(c.clone()));
             cters = Some(c);
         }
-        let ud = UserData::new(user, cters.unwrap(), gf.unwrap(), soul.unwrap());
+        let ud = UserData::new(
+            user,
+            cters.unwrap(),
+            gf.unwrap(),
+            soul.unwrap(),
+            entitlement.unwrap(),
+            mailbox.unwrap(),
+            quests.unwrap(),
+            achievements.unwrap(),
+        );
         Some(ud)
     }
 
@@ -118,6 +180,30 @@ impl UserData {
         if let Err(e) = res {
             error!("{:?}", e);
         }
+        if self.entitlement.get_version() > 0 {
+            let res = self.entitlement.update();
+            if let Err(e) = res {
+                error!("{:?}", e);
+            }
+        }
+        if self.mailbox.get_version() > 0 {
+            let res = self.mailbox.update();
+            if let Err(e) = res {
+                error!("{:?}", e);
+            }
+        }
+        if self.quests.get_version() > 0 {
+            let res = self.quests.update();
+            if let Err(e) = res {
+                error!("{:?}", e);
+            }
+        }
+        if self.achievements.get_version() > 0 {
+            let res = self.achievements.update();
+            if let Err(e) = res {
+                error!("{:?}", e);
+            }
+        }
         self.clear_version();
     }
 
@@ -137,6 +223,15 @@ impl UserData {
         self.character.borrow()
     }
 
+    ///可以使用的角色,dlc角色要有对应的权益才行
+    pub fn get_usable_characters(&self) -> Vec<&Character> {
+        self.get_characters_ref()
+            .cter_map
+            .values()
+            .filter(|cter| self.entitlement.can_use_character(cter.character_id))
+            .collect()
+    }
+
     ///获得character结构体的可变指针
     pub fn get_characters_mut_ref(&mut self) -> &mut Characters {
         self.add_version();
@@ -146,6 +241,38 @@ impl UserData {
     ///每日重制函数
     pub fn day_reset(&mut self) {
         self.user_info.day_reset();
+        self.mailbox.day_reset();
+        self.quests.day_reset();
+    }
+
+    ///每周重制函数
+    pub fn week_reset(&mut self) {
+        self.user_info.week_reset();
+        self.quests.week_reset();
+    }
+
+    ///按上次重置时间补上错过的每日/每周重置,在线玩家由定时器调用,离线玩家登录的时候调用
+    pub fn check_reset(&mut self) -> bool {
+        let schedule = &*RESET_SCHEDULE;
+        let now = schedule.now().timestamp();
+        let mut res = false;
+        if schedule.need_day_reset(self.user_info.last_day_reset, now) {
+            self.day_reset();
+            self.user_info.last_day_reset = now;
+            //累计登录天数
+            achievement_helper::login_day(self);
+            res = true;
+        }
+        if schedule.need_week_reset(self.user_info.last_week_reset, now) {
+            self.week_reset();
+            self.user_info.last_week_reset = now;
+            res = true;
+        }
+        if res {
+            self.user_info.add_version();
+            self.add_version();
+        }
+        res
     }
 
     ///添加数据版本号
@@ -192,3 +319,44 @@ pub async fn insert_soul(soul: Soul) {
         error!("{:?}", e);
     }
 }
+
+pub async fn insert_entitlement(entitlement: Entitlement) {
+    info!(
+        "玩家权益数据不存在,现在创建玩家权益数据:{}",
+        entitlement.user_id
+    );
+    let res = Entitlement::insert(&entitlement);
+    if let Err(e) = res {
+        error!("{:?}", e);
+    }
+}
+
+pub async fn insert_mailbox(mailbox: Mailbox) {
+    info!(
+        "玩家邮箱数据不存在,现在创建玩家邮箱数据:{}",
+        mailbox.user_id
+    );
+    let res = Mailbox::insert(&mailbox);
+    if let Err(e) = res {
+        error!("{:?}", e);
+    }
+}
+
+pub async fn insert_quests(quests: Quests) {
+    info!("玩家任务数据不存在,现在创建玩家任务数据:{}", quests.user_id);
+    let res = Quests::insert(&quests);
+    if let Err(e) = res {
+        error!("{:?}", e);
+    }
+}
+
+pub async fn insert_achievements(achievements: Achievements) {
+    info!(
+        "玩家成就数据不存在,现在创建玩家成就数据:{}",
+        achievements.user_id
+    );
+    let res = Achievements::insert(&achievements);
+    if let Err(e) = res {
+        error!("{:?}", e);
+    }
+}
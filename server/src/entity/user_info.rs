@@ -1,5 +1,7 @@
 use super::*;
 use crate::helper::RankInfo;
+use crate::mgr::party_mgr::Party;
+use crate::mgr::timer_mgr::RESET_SCHEDULE;
 use crate::mgr::RoomType;
 use crate::SEASON;
 use crate::TEMPLATES;
@@ -44,7 +46,11 @@ pub struct User {
     pub total_online_time: u64, //总在线时间
     pub punish_match: PunishMatch, //匹配惩数据
     pub sync_time: u32,    //同步时间
-    pub dlc: Vec<u32>,     //dlc
+    pub dlc: Vec<u32>,     //dlc(已废弃,以权益数据为准)
+    pub last_rename_time: i64, //上次改名时间(秒)
+    pub chat_mute_time: i64, //禁言结束时间(秒),0代表没被禁言
+    pub last_day_reset: i64, //上次每日重置时间(秒)
+    pub last_week_reset: i64, //上次每周重置时间(秒)
     #[serde(skip_serializing)]
     pub version: Cell<u32>, //数据版本号
 }
@@ -254,16 +260,14 @@ impl User {
         self.add_version();
     }
 
-    pub fn get_nick_name(&self) -> &str {
-        self.nick_name.as_str()
+    ///玩家主动改名,记录改名时间用于冷却
+    pub fn rename(&mut self, name: &str) {
+        self.last_rename_time = chrono::Local::now().timestamp();
+        self.set_nick_name(name);
     }
 
-    pub fn set_dlc(&mut self, v: Vec<u32>) {
-        if v.is_empty() {
-            return;
-        }
-        self.dlc = v;
-        self.add_version();
+    pub fn get_nick_name(&self) -> &str {
+        self.nick_name.as_str()
     }
 
     pub fn new(user_id: u32, nick_name: &str) -> Self {
@@ -302,6 +306,21 @@ impl User {
         data
     }
 
+    ///老数据没有重置时间的按刚重置过处理,免得更新之后第一次登录就触发每日/每周重置
+    pub fn init_reset_time(&mut self) {
+        let now = RESET_SCHEDULE.now().timestamp();
+        if self.last_day_reset > 0 && self.last_week_reset > 0 {
+            return;
+        }
+        if self.last_day_reset == 0 {
+            self.last_day_reset = now;
+        }
+        if self.last_week_reset == 0 {
+            self.last_week_reset = now;
+        }
+        self.add_version();
+    }
+
     ///处理匹配惩罚
     pub fn reset_punish_match(&mut self) -> Option<PunishMatch> {
         //先判断是否需要重制
@@ -380,7 +399,7 @@ pub fn create_room(gm: &mut GameMgr, packet: Packet) {
     let punish_match_pt = user_info.punish_match.into();
     pbp.set_punish_match(punish_match_pt);
     //封装角色
-    for cter in user_data.get_characters_ref().cter_map.values() {
+    for cter in user_data.get_usable_characters() {
         let cter_pt = cter.clone().into();
         pbp.cters.push(cter_pt);
     }
@@ -442,7 +461,7 @@ pub fn join_room(gm: &mut GameMgr, packet: Packet) {
     }
     let punish_match_pt = user_info.punish_match.into();
     pbp.set_punish_match(punish_match_pt);
-    for cter in user_data.get_characters_ref().cter_map.values() {
+    for cter in user_data.get_usable_characters() {
         pbp.cters.push(cter.clone().into());
     }
     let mut grj = G_R_JOIN_ROOM::new();
@@ -663,7 +682,42 @@ pub fn search_room(gm: &mut GameMgr, packet: Packet) {
         return;
     }
 
-    let user_data = user_data.unwrap();
+    let mut grs = G_R_SEARCH_ROOM::new();
+    grs.set_room_type(csr.get_room_type());
+    grs.set_pbp(build_player_battle_pt(gm, user_id).unwrap());
+    //组队的话由队长带着队员一起匹配
+    let party = gm.party_mgr.get_party(&user_id).cloned();
+    if let Some(party) = party {
+        let res = build_party_battle_pt(gm, user_id, &party);
+        if let Err(e) = res {
+            warn!("{:?}", e);
+            s_r.is_succ = false;
+            s_r.err_mess = e.to_string();
+            match s_r.write_to_bytes() {
+                Ok(bytes) => gm.send_2_client(ClientCode::Room, user_id, bytes),
+                Err(e) => warn!("{:?}", e),
+            }
+            return;
+        }
+        for pbp in res.unwrap() {
+            grs.party.push(pbp);
+        }
+    }
+    let res = grs.write_to_bytes();
+    match res {
+        Ok(bytes) => {
+            //发给房间
+            gm.send_2_server(RoomCode::SearchRoom.into_u32(), user_id, bytes);
+        }
+        Err(e) => {
+            warn!("{:?}", e)
+        }
+    }
+}
+
+///封装玩家进房间需要的数据
+pub fn build_player_battle_pt(gm: &GameMgr, user_id: u32) -> Option<PlayerBattlePt> {
+    let user_data = gm.users.get(&user_id)?;
     let user_info = user_data.get_user_info_ref();
     let mut pbp = PlayerBattlePt::new();
     pbp.set_user_id(user_id);
@@ -678,22 +732,33 @@ pub fn search_room(gm: &mut GameMgr, packet: Packet) {
     }
     let punish_match_pt = user_info.punish_match.into();
     pbp.set_punish_match(punish_match_pt);
-    for cter in user_data.get_characters_ref().cter_map.values() {
+    for cter in user_data.get_usable_characters() {
         pbp.cters.push(cter.clone().into());
     }
-    let mut grs = G_R_SEARCH_ROOM::new();
-    grs.set_room_type(csr.get_room_type());
-    grs.set_pbp(pbp);
-    let res = grs.write_to_bytes();
-    match res {
-        Ok(bytes) => {
-            //发给房间
-            gm.send_2_server(RoomCode::SearchRoom.into_u32(), user_id, bytes);
+    Some(pbp)
+}
+
+///封装队员的数据,只有队长能发起匹配
+fn build_party_battle_pt(
+    gm: &GameMgr,
+    user_id: u32,
+    party: &Party,
+) -> anyhow::Result<Vec<PlayerBattlePt>> {
+    if party.leader_id != user_id {
+        anyhow::bail!("only party leader can search room!user_id:{}", user_id)
+    }
+    let mut res = Vec::new();
+    for &member in party.members.iter() {
+        if member == user_id {
+            continue;
         }
-        Err(e) => {
-            warn!("{:?}", e)
+        let pbp = build_player_battle_pt(gm, member);
+        if pbp.is_none() {
+            anyhow::bail!("party member is not online!user_id:{}", member)
         }
+        res.push(pbp.unwrap());
     }
+    Ok(res)
 }
 
 ///获得上此排行榜
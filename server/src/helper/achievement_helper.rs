@@ -0,0 +1,151 @@
+use crate::entity::achievement::AchievementCondition;
+use crate::entity::user::UserData;
+use crate::entity::Entity;
+use crate::mgr::game_mgr::GameMgr;
+use crate::TEMPLATES;
+use log::{error, info, warn};
+use protobuf::Message;
+use tools::cmd_code::ClientCode;
+use tools::protos::base::SummaryDataPt;
+use tools::protos::protocol::{S_ACHIEVEMENT_LIST, S_ACHIEVEMENT_NOTICE};
+use tools::util::packet::Packet;
+
+///合并有变化的成就id
+fn merge(changed: &mut Vec<u32>, ids: Vec<u32>) {
+    for id in ids {
+        if !changed.contains(&id) {
+            changed.push(id);
+        }
+    }
+}
+
+///匹配结算的时候加成就进度
+pub fn on_summary(gm: &mut GameMgr, summary_data: &SummaryDataPt) {
+    let user_id = summary_data.get_user_id();
+    let notice = match gm.users.get_mut(&user_id) {
+        Some(user_data) => {
+            let achievements = &mut user_data.achievements;
+            let mut changed = achievements.add_progress(AchievementCondition::PlayMatch, 0, 1);
+            if summary_data.get_rank() == 0 {
+                merge(&mut changed, achievements.win(summary_data.get_cter_id()));
+            }
+            merge(
+                &mut changed,
+                achievements.add_progress(AchievementCondition::Kill, 0, summary_data.get_kills()),
+            );
+            for event in summary_data.get_events() {
+                merge(
+                    &mut changed,
+                    achievements.add_progress(
+                        AchievementCondition::BattleEvent,
+                        event.get_event_type(),
+                        event.get_value(),
+                    ),
+                );
+            }
+            settle(user_data, changed)
+        }
+        None => return,
+    };
+    if let Some(notice) = notice {
+        match notice.write_to_bytes() {
+            Ok(bytes) => gm.send_2_client(ClientCode::AchievementNotice, user_id, bytes),
+            Err(e) => error!("{:?}", e),
+        }
+    }
+}
+
+///每天第一次登录加登录天数,在跨天重置里调用
+/// 这时候还没回登录包,解锁的东西跟着登录包下去,不用单独推送
+pub fn login_day(user_data: &mut UserData) {
+    let changed = user_data
+        .achievements
+        .add_progress(AchievementCondition::LoginDays, 0, 1);
+    settle(user_data, changed);
+}
+
+///登录的时候补发已达成成就对应的相框和头像,配置新加的解锁也能拿到
+pub fn login(user_data: &mut UserData) {
+    let unlocked: Vec<u32> = user_data.achievements.unlocked.keys().copied().collect();
+    let (grade_frames, souls) = grant_unlocks(user_data, &unlocked);
+    if !grade_frames.is_empty() || !souls.is_empty() {
+        user_data.add_version();
+    }
+}
+
+///检查是否达成并发放解锁的东西,有变化就返回推送
+fn settle(user_data: &mut UserData, changed: Vec<u32>) -> Option<S_ACHIEVEMENT_NOTICE> {
+    if changed.is_empty() {
+        return None;
+    }
+    let user_id = user_data.get_user_info_ref().user_id;
+    let unlocked = user_data.achievements.check_unlock(&changed);
+    let mut san = S_ACHIEVEMENT_NOTICE::new();
+    let temp_mgr = TEMPLATES.achievement_temp_mgr();
+    for id in changed.iter() {
+        match temp_mgr.get_temp(id) {
+            Ok(temp) => san.achievements.push(user_data.achievements.into_pt(temp)),
+            Err(e) => warn!("{:?}", e),
+        }
+    }
+    if !unlocked.is_empty() {
+        info!("达成成就!user_id:{},achievements:{:?}", user_id, unlocked);
+        let (grade_frames, souls) = grant_unlocks(user_data, &unlocked);
+        san.grade_frames = grade_frames;
+        san.souls = souls;
+    }
+    user_data.add_version();
+    Some(san)
+}
+
+///发放成就解锁的grade相框和灵魂头像,返回新拿到的
+fn grant_unlocks(user_data: &mut UserData, ids: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let mut grade_frames = Vec::new();
+    for temp in TEMPLATES.grade_frame_temp_mgr().temps.values() {
+        if temp.condition == 0
+            || !ids.contains(&temp.condition)
+            || user_data.grade_frame.grade_frames.contains(&temp.id)
+        {
+            continue;
+        }
+        user_data.grade_frame.grade_frames.push(temp.id);
+        grade_frames.push(temp.id);
+    }
+    if !grade_frames.is_empty() {
+        user_data.grade_frame.add_version();
+    }
+    let mut souls = Vec::new();
+    for temp in TEMPLATES.soul_temp_mgr().temps.values() {
+        if temp.condition == 0
+            || !ids.contains(&temp.condition)
+            || user_data.soul.souls.contains(&temp.id)
+        {
+            continue;
+        }
+        user_data.soul.souls.push(temp.id);
+        souls.push(temp.id);
+    }
+    if !souls.is_empty() {
+        user_data.soul.add_version();
+    }
+    (grade_frames, souls)
+}
+
+///成就列表
+pub fn achievement_list(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let user_data = gm.users.get(&user_id);
+    if user_data.is_none() {
+        warn!("could not find user_data for user_id {}", user_id);
+        return;
+    }
+    let user_data = user_data.unwrap();
+    let mut sal = S_ACHIEVEMENT_LIST::new();
+    for achievement in user_data.achievements.into_pts() {
+        sal.achievements.push(achievement);
+    }
+    match sal.write_to_bytes() {
+        Ok(bytes) => gm.send_2_client(ClientCode::AchievementList, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
@@ -0,0 +1,281 @@
+use crate::entity::Entity;
+use crate::helper::nick_name_helper;
+use crate::mgr::game_mgr::GameMgr;
+use crate::{CONF_MAP, REDIS_INDEX_USERS, REDIS_KEY_CHAT_REPORT, REDIS_POOL};
+use log::{error, info, warn};
+use protobuf::Message;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use tools::cmd_code::{ClientCode, RoomCode};
+use tools::protos::base::NoticeMessPt;
+use tools::protos::room::{C_CHAT, C_CHAT_REPORT, S_CHAT, S_CHAT_REPORT};
+use tools::protos::server_protocol::G_R_CHAT;
+use tools::util::packet::Packet;
+
+///聊天内容默认最大长度
+const DEFAULT_CHAT_MAX_LEN: usize = 100;
+///默认限流时间窗口(秒)
+const DEFAULT_CHAT_RATE_SECS: usize = 10;
+///默认限流时间窗口内最多能发的条数
+const DEFAULT_CHAT_RATE_COUNT: usize = 5;
+///每个玩家最多保留的被举报记录
+const CHAT_REPORT_MAX: usize = 50;
+///举报原因最大长度
+const CHAT_REPORT_REASON_MAX_LEN: usize = 100;
+
+///聊天举报记录,存redis给运营看
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChatReport {
+    pub from_user_id: u32, //举报人
+    pub reason: String,    //举报原因
+    pub time: i64,         //举报时间(秒)
+}
+
+///聊天,校验通过之后交给房间服分发
+pub fn chat(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = C_CHAT::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let res = check_chat(gm, user_id, proto.get_content());
+    if let Err(e) = res {
+        warn!("{:?}", e);
+        let mut sc = S_CHAT::new();
+        sc.set_is_succ(false);
+        sc.set_err_mess(e.to_string());
+        match sc.write_to_bytes() {
+            Ok(bytes) => gm.send_2_client(ClientCode::Chat, user_id, bytes),
+            Err(e) => error!("{:?}", e),
+        }
+        return;
+    }
+    info!(
+        "聊天!user_id:{},channel:{},target_id:{},content:{}",
+        user_id,
+        proto.get_channel(),
+        proto.get_target_id(),
+        proto.get_content()
+    );
+    let mut mess = NoticeMessPt::new();
+    mess.set_user_id(user_id);
+    mess.set_nick_name(res.unwrap());
+    mess.set_channel(proto.get_channel());
+    mess.set_content(proto.take_content());
+    mess.set_time(chrono::Local::now().timestamp_millis());
+    mess.set_target_id(proto.get_target_id());
+    let mut grc = G_R_CHAT::new();
+    grc.set_mess(mess);
+    match grc.write_to_bytes() {
+        Ok(bytes) => gm.send_2_server(RoomCode::Chat.into_u32(), user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+///校验能不能发,通过了返回发送者昵称
+fn check_chat(gm: &mut GameMgr, user_id: u32, content: &str) -> anyhow::Result<String> {
+    let user_data = gm.users.get(&user_id);
+    if user_data.is_none() {
+        anyhow::bail!("this player is not login!user_id:{}", user_id)
+    }
+    let user = user_data.unwrap().get_user_info_ref();
+    let now = chrono::Local::now().timestamp();
+    if user.chat_mute_time > now {
+        anyhow::bail!(
+            "this player is muted!user_id:{},left:{}s",
+            user_id,
+            user.chat_mute_time - now
+        )
+    }
+    let max_len = CONF_MAP.get_usize("chat_max_len", DEFAULT_CHAT_MAX_LEN);
+    let len = content.chars().count();
+    if len == 0 || len > max_len {
+        anyhow::bail!(
+            "chat content length must between 1 and {}!len:{}",
+            max_len,
+            len
+        )
+    }
+    if nick_name_helper::has_block_words(content) {
+        anyhow::bail!("chat content has block word!user_id:{}", user_id)
+    }
+    let nick_name = user.nick_name.clone();
+    check_rate(gm, user_id, now)?;
+    Ok(nick_name)
+}
+
+///限流,时间窗口内发得太多就拒绝
+fn check_rate(gm: &mut GameMgr, user_id: u32, now: i64) -> anyhow::Result<()> {
+    let secs = CONF_MAP.get_usize("chat_rate_secs", DEFAULT_CHAT_RATE_SECS) as i64;
+    let count = CONF_MAP.get_usize("chat_rate_count", DEFAULT_CHAT_RATE_COUNT);
+    let times = gm.chat_times.entry(user_id).or_insert_with(VecDeque::new);
+    while let Some(&time) = times.front() {
+        if now - time < secs {
+            break;
+        }
+        times.pop_front();
+    }
+    if times.len() >= count {
+        anyhow::bail!("chat too fast!user_id:{}", user_id)
+    }
+    times.push_back(now);
+    Ok(())
+}
+
+///举报玩家聊天
+pub fn chat_report(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = C_CHAT_REPORT::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let res = do_report(user_id, proto.get_target_id(), proto.get_reason());
+    let mut scr = S_CHAT_REPORT::new();
+    match res {
+        Ok(_) => scr.set_is_succ(true),
+        Err(e) => {
+            warn!("{:?}", e);
+            scr.set_is_succ(false);
+            scr.set_err_mess(e.to_string());
+        }
+    }
+    match scr.write_to_bytes() {
+        Ok(bytes) => gm.send_2_client(ClientCode::ChatReport, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+///记录举报,同一个人重复举报只保留最新的一条
+fn do_report(user_id: u32, target_id: u32, reason: &str) -> anyhow::Result<()> {
+    if target_id == 0 || target_id == user_id {
+        anyhow::bail!("target_id is error!target_id:{}", target_id)
+    }
+    let reason: String = reason.chars().take(CHAT_REPORT_REASON_MAX_LEN).collect();
+    let target = target_id.to_string();
+    let mut redis_lock = REDIS_POOL.lock().unwrap();
+    let res: Option<String> =
+        redis_lock.hget(REDIS_INDEX_USERS, REDIS_KEY_CHAT_REPORT, target.as_str());
+    let mut reports: Vec<ChatReport> = match res {
+        Some(res) => serde_json::from_str(res.as_str()).unwrap_or_default(),
+        None => Vec::new(),
+    };
+    reports.retain(|report| report.from_user_id != user_id);
+    reports.push(ChatReport {
+        from_user_id: user_id,
+        reason,
+        time: chrono::Local::now().timestamp(),
+    });
+    if reports.len() > CHAT_REPORT_MAX {
+        let size = reports.len() - CHAT_REPORT_MAX;
+        reports.drain(..size);
+    }
+    let json = serde_json::to_string(&reports)?;
+    let _: Option<u32> = redis_lock.hset(
+        REDIS_INDEX_USERS,
+        REDIS_KEY_CHAT_REPORT,
+        target.as_str(),
+        json.as_str(),
+    );
+    info!(
+        "举报玩家聊天!user_id:{},target_id:{},count:{}",
+        user_id,
+        target_id,
+        reports.len()
+    );
+    Ok(())
+}
+
+///禁言,seconds为0就是解除禁言
+pub fn mute(gm: &mut GameMgr, user_id: u32, seconds: i64) -> anyhow::Result<i64> {
+    let user_data = gm.users.get_mut(&user_id);
+    if user_data.is_none() {
+        anyhow::bail!("this player is not online!user_id:{}", user_id)
+    }
+    let user_data = user_data.unwrap();
+    let user = user_data.get_user_info_mut_ref();
+    user.chat_mute_time = if seconds > 0 {
+        chrono::Local::now().timestamp() + seconds
+    } else {
+        0
+    };
+    user.add_version();
+    let mute_time = user.chat_mute_time;
+    user_data.add_version();
+    Ok(mute_time)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::user::UserData;
+    use crate::entity::user_info::User;
+    use crate::helper::gm_helper;
+    use tools::gm::{GmContext, GmLevel};
+
+    fn new_gm(user_id: u32) -> GameMgr {
+        let mut gm = GameMgr::new();
+        let mut user = User::default();
+        user.user_id = user_id;
+        let user_data = UserData::new(
+            user,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        );
+        gm.users.insert(user_id, user_data);
+        gm
+    }
+
+    #[test]
+    fn rate_limit() {
+        let mut gm = new_gm(1001);
+        let secs = CONF_MAP.get_usize("chat_rate_secs", DEFAULT_CHAT_RATE_SECS) as i64;
+        let count = CONF_MAP.get_usize("chat_rate_count", DEFAULT_CHAT_RATE_COUNT);
+        let now = chrono::Local::now().timestamp();
+        for _ in 0..count {
+            assert!(check_rate(&mut gm, 1001, now).is_ok());
+        }
+        assert!(check_rate(&mut gm, 1001, now + 1).is_err());
+        //别人不受影响
+        assert!(check_rate(&mut gm, 1002, now + 1).is_ok());
+        //时间窗口过了又能发,被拒绝的那条不算
+        assert!(check_rate(&mut gm, 1001, now + secs).is_ok());
+        assert_eq!(gm.chat_times.get(&1001).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn mute_by_gm() {
+        let mut gm = new_gm(1001);
+        let mut ctx = GmContext {
+            operator_id: 1,
+            level: GmLevel::Operator,
+            target_id: 1001,
+        };
+        assert!(check_chat(&mut gm, 1001, "hello").is_ok());
+        gm_helper::execute(&mut gm, &ctx, "mute", &["60".to_owned()]).unwrap();
+        assert!(check_chat(&mut gm, 1001, "hello").is_err());
+        //禁言期间的发言不占限流次数
+        assert_eq!(gm.chat_times.get(&1001).unwrap().len(), 1);
+        gm_helper::execute(&mut gm, &ctx, "unmute", &[]).unwrap();
+        assert!(check_chat(&mut gm, 1001, "hello").is_ok());
+
+        //禁言时间要大于0
+        assert!(gm_helper::execute(&mut gm, &ctx, "mute", &["0".to_owned()]).is_err());
+        //qa没有禁言权限
+        ctx.level = GmLevel::Qa;
+        assert!(gm_helper::execute(&mut gm, &ctx, "mute", &["60".to_owned()]).is_err());
+        assert!(check_chat(&mut gm, 1001, "hello").is_ok());
+        //不在线的禁不了
+        ctx.level = GmLevel::Operator;
+        ctx.target_id = 1002;
+        assert!(gm_helper::execute(&mut gm, &ctx, "mute", &["60".to_owned()]).is_err());
+    }
+}
@@ -0,0 +1,547 @@
+use crate::entity::Entity;
+use crate::mgr::game_mgr::GameMgr;
+use crate::mgr::PresenceState;
+use crate::{CONF_MAP, REDIS_INDEX_USERS, REDIS_POOL};
+use crate::{REDIS_KEY_FRIENDS, REDIS_KEY_PRESENCE, REDIS_KEY_UID_2_PID};
+use log::{error, info, warn};
+use protobuf::Message;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use tools::cmd_code::ClientCode;
+use tools::protos::base::FriendPt;
+use tools::protos::protocol::{
+    C_FRIEND_BLOCK, C_FRIEND_REMOVE, C_FRIEND_REPLY, C_FRIEND_REQUEST, S_FRIEND_BLOCK,
+    S_FRIEND_LIST, S_FRIEND_NOTICE, S_FRIEND_REMOVE, S_FRIEND_REPLY, S_FRIEND_REQUEST,
+    S_FRIEND_REQUEST_NOTICE,
+};
+use tools::protos::server_protocol::R_G_SYNC_PRESENCE;
+use tools::redis_pool::RedisPoolTool;
+use tools::util::packet::Packet;
+
+///默认好友上限
+const DEFAULT_FRIEND_MAX: usize = 100;
+///最多保留的好友申请
+const FRIEND_REQUEST_MAX: usize = 50;
+///黑名单上限
+const FRIEND_BLOCK_MAX: usize = 100;
+
+///好友变化推送类型
+const NOTICE_TYPE_ADD: u32 = 1;
+const NOTICE_TYPE_REMOVE: u32 = 2;
+const NOTICE_TYPE_PRESENCE: u32 = 3;
+
+///好友数据,存redis,离线的玩家也能被加好友
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FriendData {
+    pub friends: Vec<u32>,  //好友
+    pub requests: Vec<u32>, //收到的好友申请
+    pub blocks: Vec<u32>,   //黑名单
+}
+
+///在线状态,存redis,多个游戏服都能看到
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PresenceInfo {
+    pub presence: u8,      //在线状态
+    pub nick_name: String, //昵称
+}
+
+fn load_friend_data(redis_lock: &mut RedisPoolTool, user_id: u32) -> FriendData {
+    let res: Option<String> = redis_lock.hget(
+        REDIS_INDEX_USERS,
+        REDIS_KEY_FRIENDS,
+        user_id.to_string().as_str(),
+    );
+    match res {
+        Some(res) => serde_json::from_str(res.as_str()).unwrap_or_default(),
+        None => FriendData::default(),
+    }
+}
+
+fn save_friend_data(redis_lock: &mut RedisPoolTool, user_id: u32, data: &FriendData) {
+    let json = serde_json::to_string(data);
+    if let Err(e) = json {
+        error!("{:?}", e);
+        return;
+    }
+    let _: Option<u32> = redis_lock.hset(
+        REDIS_INDEX_USERS,
+        REDIS_KEY_FRIENDS,
+        user_id.to_string().as_str(),
+        json.unwrap().as_str(),
+    );
+}
+
+fn load_presence(redis_lock: &mut RedisPoolTool, user_id: u32) -> PresenceInfo {
+    let res: Option<String> = redis_lock.hget(
+        REDIS_INDEX_USERS,
+        REDIS_KEY_PRESENCE,
+        user_id.to_string().as_str(),
+    );
+    let mut info: PresenceInfo = match res {
+        Some(res) => serde_json::from_str(res.as_str()).unwrap_or_default(),
+        None => PresenceInfo::default(),
+    };
+    if PresenceState::try_from(info.presence).is_err() {
+        info.presence = PresenceState::Offline.into();
+    }
+    info
+}
+
+fn build_friend_pt(redis_lock: &mut RedisPoolTool, user_id: u32) -> FriendPt {
+    let info = load_presence(redis_lock, user_id);
+    let mut fp = FriendPt::new();
+    fp.set_user_id(user_id);
+    fp.set_nick_name(info.nick_name);
+    fp.set_presence(info.presence as u32);
+    fp
+}
+
+///是否是好友
+pub fn is_friend(user_id: u32, target_id: u32) -> bool {
+    let mut redis_lock = REDIS_POOL.lock().unwrap();
+    load_friend_data(&mut redis_lock, user_id)
+        .friends
+        .contains(&target_id)
+}
+
+///更新在线状态,并推送给本服在线的好友
+pub fn update_presence(gm: &mut GameMgr, user_id: u32, state: PresenceState) {
+    let mut redis_lock = REDIS_POOL.lock().unwrap();
+    let mut info = load_presence(&mut redis_lock, user_id);
+    if let Some(user_data) = gm.users.get(&user_id) {
+        info.nick_name = user_data.get_user_info_ref().nick_name.clone();
+    }
+    info.presence = state.into();
+    let json = serde_json::to_string(&info);
+    if let Err(e) = json {
+        error!("{:?}", e);
+        return;
+    }
+    let _: Option<u32> = redis_lock.hset(
+        REDIS_INDEX_USERS,
+        REDIS_KEY_PRESENCE,
+        user_id.to_string().as_str(),
+        json.unwrap().as_str(),
+    );
+    let friends = load_friend_data(&mut redis_lock, user_id).friends;
+    std::mem::drop(redis_lock);
+
+    let mut fp = FriendPt::new();
+    fp.set_user_id(user_id);
+    fp.set_nick_name(info.nick_name);
+    fp.set_presence(state.into_u32());
+    push_friend_notice(gm, &friends, fp, NOTICE_TYPE_PRESENCE);
+}
+
+///改名之后刷新好友看到的昵称
+pub fn sync_nick_name(gm: &mut GameMgr, user_id: u32) {
+    let presence = {
+        let mut redis_lock = REDIS_POOL.lock().unwrap();
+        load_presence(&mut redis_lock, user_id).presence
+    };
+    let state = PresenceState::try_from(presence).unwrap_or(PresenceState::Online);
+    update_presence(gm, user_id, state);
+}
+
+///推送好友变化,只推本服在线的
+fn push_friend_notice(gm: &mut GameMgr, user_ids: &[u32], fp: FriendPt, notice_type: u32) {
+    let mut sfn = S_FRIEND_NOTICE::new();
+    sfn.set_friend(fp);
+    sfn.set_notice_type(notice_type);
+    let bytes = sfn.write_to_bytes();
+    if let Err(e) = bytes {
+        error!("{:?}", e);
+        return;
+    }
+    let bytes = bytes.unwrap();
+    for user_id in user_ids {
+        if !gm.users.contains_key(user_id) {
+            continue;
+        }
+        gm.send_2_client(ClientCode::FriendNotice, *user_id, bytes.clone());
+    }
+}
+
+///房间服同步过来的在线状态
+pub fn sync_presence(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = R_G_SYNC_PRESENCE::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    //已经下线的就不管了,免得把离线覆盖掉
+    if !gm.users.contains_key(&user_id) {
+        return;
+    }
+    let state = PresenceState::try_from(proto.get_presence() as u8);
+    match state {
+        Ok(state) => update_presence(gm, user_id, state),
+        Err(e) => warn!("{:?}", e),
+    }
+}
+
+///好友列表
+pub fn friend_list(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut sfl = S_FRIEND_LIST::new();
+    {
+        let mut redis_lock = REDIS_POOL.lock().unwrap();
+        let data = load_friend_data(&mut redis_lock, user_id);
+        for &friend_id in data.friends.iter() {
+            sfl.friends
+                .push(build_friend_pt(&mut redis_lock, friend_id));
+        }
+        for &request_id in data.requests.iter() {
+            sfl.requests
+                .push(build_friend_pt(&mut redis_lock, request_id));
+        }
+        for &block_id in data.blocks.iter() {
+            sfl.blocks.push(block_id);
+        }
+    }
+    match sfl.write_to_bytes() {
+        Ok(bytes) => gm.send_2_client(ClientCode::FriendList, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+///申请加好友
+pub fn friend_request(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = C_FRIEND_REQUEST::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let target_id = proto.get_target_id();
+    let res = do_friend_request(gm, user_id, target_id);
+    let mut sfr = S_FRIEND_REQUEST::new();
+    match res {
+        Ok(_) => sfr.set_is_succ(true),
+        Err(e) => {
+            warn!("{:?}", e);
+            sfr.set_is_succ(false);
+            sfr.set_err_mess(e.to_string());
+        }
+    }
+    match sfr.write_to_bytes() {
+        Ok(bytes) => gm.send_2_client(ClientCode::FriendRequest, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+fn do_friend_request(gm: &mut GameMgr, user_id: u32, target_id: u32) -> anyhow::Result<()> {
+    if target_id == 0 || target_id == user_id {
+        anyhow::bail!("target_id is error!target_id:{}", target_id)
+    }
+    let mut redis_lock = REDIS_POOL.lock().unwrap();
+    let pid: Option<String> = redis_lock.hget(
+        REDIS_INDEX_USERS,
+        REDIS_KEY_UID_2_PID,
+        target_id.to_string().as_str(),
+    );
+    if pid.is_none() {
+        anyhow::bail!("this player is not exist!target_id:{}", target_id)
+    }
+    let data = load_friend_data(&mut redis_lock, user_id);
+    let mut target_data = load_friend_data(&mut redis_lock, target_id);
+    if data.friends.contains(&target_id) {
+        anyhow::bail!("already friend!target_id:{}", target_id)
+    }
+    if data.blocks.contains(&target_id) {
+        anyhow::bail!("target is in block list!target_id:{}", target_id)
+    }
+    if target_data.blocks.contains(&user_id) {
+        anyhow::bail!("could not send friend request!target_id:{}", target_id)
+    }
+    //对方也申请过自己,直接成为好友
+    if data.requests.contains(&target_id) {
+        std::mem::drop(redis_lock);
+        return make_friends(gm, user_id, target_id);
+    }
+    if target_data.requests.contains(&user_id) {
+        return Ok(());
+    }
+    target_data.requests.push(user_id);
+    if target_data.requests.len() > FRIEND_REQUEST_MAX {
+        target_data.requests.remove(0);
+    }
+    save_friend_data(&mut redis_lock, target_id, &target_data);
+    let from = build_friend_pt(&mut redis_lock, user_id);
+    std::mem::drop(redis_lock);
+    info!("申请加好友!user_id:{},target_id:{}", user_id, target_id);
+
+    if gm.users.contains_key(&target_id) {
+        let mut sfrn = S_FRIEND_REQUEST_NOTICE::new();
+        sfrn.set_from(from);
+        let bytes = sfrn.write_to_bytes()?;
+        gm.send_2_client(ClientCode::FriendRequestNotice, target_id, bytes);
+    }
+    Ok(())
+}
+
+///成为好友,两边都要加,申请记录也一起删掉
+fn make_friends(gm: &mut GameMgr, user_id: u32, target_id: u32) -> anyhow::Result<()> {
+    let friend_max = CONF_MAP.get_usize("friend_max", DEFAULT_FRIEND_MAX);
+    let mut redis_lock = REDIS_POOL.lock().unwrap();
+    let mut data = load_friend_data(&mut redis_lock, user_id);
+    let mut target_data = load_friend_data(&mut redis_lock, target_id);
+    let res = add_each_other(&mut data, &mut target_data, user_id, target_id, friend_max);
+    if let Err(e) = res {
+        save_friend_data(&mut redis_lock, user_id, &data);
+        return Err(e);
+    }
+    save_friend_data(&mut redis_lock, user_id, &data);
+    save_friend_data(&mut redis_lock, target_id, &target_data);
+    let fp = build_friend_pt(&mut redis_lock, user_id);
+    let target_fp = build_friend_pt(&mut redis_lock, target_id);
+    std::mem::drop(redis_lock);
+    info!("成为好友!user_id:{},target_id:{}", user_id, target_id);
+
+    push_friend_notice(gm, &[target_id], fp, NOTICE_TYPE_ADD);
+    push_friend_notice(gm, &[user_id], target_fp, NOTICE_TYPE_ADD);
+    Ok(())
+}
+
+///两边互加好友,申请记录一起删掉
+fn add_each_other(
+    data: &mut FriendData,
+    target_data: &mut FriendData,
+    user_id: u32,
+    target_id: u32,
+    friend_max: usize,
+) -> anyhow::Result<()> {
+    data.requests.retain(|&id| id != target_id);
+    target_data.requests.retain(|&id| id != user_id);
+    if data.friends.len() >= friend_max {
+        anyhow::bail!("friend list is full!user_id:{}", user_id)
+    }
+    if target_data.friends.len() >= friend_max {
+        anyhow::bail!("target friend list is full!target_id:{}", target_id)
+    }
+    if !data.friends.contains(&target_id) {
+        data.friends.push(target_id);
+    }
+    if !target_data.friends.contains(&user_id) {
+        target_data.friends.push(user_id);
+    }
+    Ok(())
+}
+
+///处理好友申请
+pub fn friend_reply(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = C_FRIEND_REPLY::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let target_id = proto.get_target_id();
+    let agree = proto.get_agree();
+    let res = do_friend_reply(gm, user_id, target_id, agree);
+    let mut sfr = S_FRIEND_REPLY::new();
+    sfr.set_target_id(target_id);
+    sfr.set_agree(agree);
+    match res {
+        Ok(_) => sfr.set_is_succ(true),
+        Err(e) => {
+            warn!("{:?}", e);
+            sfr.set_is_succ(false);
+            sfr.set_err_mess(e.to_string());
+        }
+    }
+    match sfr.write_to_bytes() {
+        Ok(bytes) => gm.send_2_client(ClientCode::FriendReply, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+fn do_friend_reply(
+    gm: &mut GameMgr,
+    user_id: u32,
+    target_id: u32,
+    agree: bool,
+) -> anyhow::Result<()> {
+    let mut redis_lock = REDIS_POOL.lock().unwrap();
+    let mut data = load_friend_data(&mut redis_lock, user_id);
+    if !data.requests.contains(&target_id) {
+        anyhow::bail!("there is no friend request!target_id:{}", target_id)
+    }
+    if !agree {
+        data.requests.retain(|&id| id != target_id);
+        save_friend_data(&mut redis_lock, user_id, &data);
+        return Ok(());
+    }
+    std::mem::drop(redis_lock);
+    make_friends(gm, user_id, target_id)
+}
+
+///删除好友
+pub fn friend_remove(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = C_FRIEND_REMOVE::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let target_id = proto.get_target_id();
+    let mut sfr = S_FRIEND_REMOVE::new();
+    sfr.set_target_id(target_id);
+    if remove_friend(gm, user_id, target_id) {
+        sfr.set_is_succ(true);
+    } else {
+        sfr.set_is_succ(false);
+        sfr.set_err_mess(format!("target is not friend!target_id:{}", target_id));
+    }
+    match sfr.write_to_bytes() {
+        Ok(bytes) => gm.send_2_client(ClientCode::FriendRemove, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+///两边都删掉,返回之前是不是好友
+fn remove_friend(gm: &mut GameMgr, user_id: u32, target_id: u32) -> bool {
+    let mut redis_lock = REDIS_POOL.lock().unwrap();
+    let mut data = load_friend_data(&mut redis_lock, user_id);
+    let mut target_data = load_friend_data(&mut redis_lock, target_id);
+    if !remove_each_other(&mut data, &mut target_data, user_id, target_id) {
+        return false;
+    }
+    save_friend_data(&mut redis_lock, user_id, &data);
+    save_friend_data(&mut redis_lock, target_id, &target_data);
+    let fp = build_friend_pt(&mut redis_lock, user_id);
+    std::mem::drop(redis_lock);
+    info!("删除好友!user_id:{},target_id:{}", user_id, target_id);
+
+    push_friend_notice(gm, &[target_id], fp, NOTICE_TYPE_REMOVE);
+    true
+}
+
+///两边互删好友,返回之前是不是好友
+fn remove_each_other(
+    data: &mut FriendData,
+    target_data: &mut FriendData,
+    user_id: u32,
+    target_id: u32,
+) -> bool {
+    if !data.friends.contains(&target_id) {
+        return false;
+    }
+    data.friends.retain(|&id| id != target_id);
+    target_data.friends.retain(|&id| id != user_id);
+    true
+}
+
+///拉黑/取消拉黑
+pub fn friend_block(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = C_FRIEND_BLOCK::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let target_id = proto.get_target_id();
+    let is_block = proto.get_is_block();
+    let res = do_friend_block(gm, user_id, target_id, is_block);
+    let mut sfb = S_FRIEND_BLOCK::new();
+    sfb.set_target_id(target_id);
+    sfb.set_is_block(is_block);
+    match res {
+        Ok(_) => sfb.set_is_succ(true),
+        Err(e) => {
+            warn!("{:?}", e);
+            sfb.set_is_succ(false);
+            sfb.set_err_mess(e.to_string());
+        }
+    }
+    match sfb.write_to_bytes() {
+        Ok(bytes) => gm.send_2_client(ClientCode::FriendBlock, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+fn do_friend_block(
+    gm: &mut GameMgr,
+    user_id: u32,
+    target_id: u32,
+    is_block: bool,
+) -> anyhow::Result<()> {
+    if target_id == 0 || target_id == user_id {
+        anyhow::bail!("target_id is error!target_id:{}", target_id)
+    }
+    //拉黑的同时删掉好友
+    if is_block {
+        remove_friend(gm, user_id, target_id);
+    }
+    let mut redis_lock = REDIS_POOL.lock().unwrap();
+    let mut data = load_friend_data(&mut redis_lock, user_id);
+    if !is_block {
+        data.blocks.retain(|&id| id != target_id);
+        save_friend_data(&mut redis_lock, user_id, &data);
+        return Ok(());
+    }
+    if data.blocks.contains(&target_id) {
+        return Ok(());
+    }
+    if data.blocks.len() >= FRIEND_BLOCK_MAX {
+        anyhow::bail!("block list is full!user_id:{}", user_id)
+    }
+    data.blocks.push(target_id);
+    data.requests.retain(|&id| id != target_id);
+    save_friend_data(&mut redis_lock, user_id, &data);
+    //自己发给对方的申请也撤掉
+    let mut target_data = load_friend_data(&mut redis_lock, target_id);
+    if target_data.requests.contains(&user_id) {
+        target_data.requests.retain(|&id| id != user_id);
+        save_friend_data(&mut redis_lock, target_id, &target_data);
+    }
+    info!("拉黑玩家!user_id:{},target_id:{}", user_id, target_id);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_and_remove() {
+        let mut a = FriendData::default();
+        let mut b = FriendData::default();
+        a.requests.push(2);
+        b.requests.push(1);
+        add_each_other(&mut a, &mut b, 1, 2, 10).unwrap();
+        assert_eq!(a.friends, vec![2]);
+        assert_eq!(b.friends, vec![1]);
+        assert!(a.requests.is_empty() && b.requests.is_empty());
+        //重复加不会重复记
+        add_each_other(&mut b, &mut a, 2, 1, 10).unwrap();
+        assert_eq!(a.friends, vec![2]);
+        assert_eq!(b.friends, vec![1]);
+
+        //从哪一边删两边都没了
+        assert!(remove_each_other(&mut b, &mut a, 2, 1));
+        assert!(a.friends.is_empty() && b.friends.is_empty());
+        assert!(!remove_each_other(&mut a, &mut b, 1, 2));
+    }
+
+    #[test]
+    fn add_full() {
+        let mut a = FriendData::default();
+        let mut b = FriendData::default();
+        b.friends = vec![3, 4];
+        a.requests.push(2);
+        //对方满了,两边都不加,申请还是删掉
+        assert!(add_each_other(&mut a, &mut b, 1, 2, 2).is_err());
+        assert!(a.friends.is_empty());
+        assert_eq!(b.friends, vec![3, 4]);
+        assert!(a.requests.is_empty());
+        assert!(add_each_other(&mut b, &mut a, 2, 1, 2).is_err());
+        assert!(a.friends.is_empty());
+    }
+}
@@ -0,0 +1,300 @@
+use crate::entity::Entity;
+use crate::helper::chat_helper;
+use crate::mgr::game_mgr::GameMgr;
+use crate::{CONF_MAP, REDIS_INDEX_GAME_SEASON, REDIS_KEY_GAME_SEASON, REDIS_POOL, SEASON};
+use log::{error, info};
+use protobuf::Message;
+use serde_json::Value as JsonValue;
+use tools::cmd_code::{BattleCode, ClientCode, RankCode, RoomCode};
+use tools::gm::{build_result_proto, parse_arg, GmContext, GmLevel, GmRegistry};
+use tools::protos::base::{LeaguePt, SummaryDataPt};
+use tools::protos::protocol::C_GM_COMMAND;
+use tools::protos::room::S_PUNISH_MATCH_NOTICE;
+use tools::protos::server_protocol::{B_S_SUMMARY, G_GM_COMMAND};
+use tools::util::packet::Packet;
+
+///注册游戏服的gm命令,战斗和房间里的命令转发给对应的服务器
+pub fn init_gm_registry(registry: &mut GmRegistry<GameMgr>) {
+    registry.register("help", GmLevel::Qa, "", help);
+    registry.register("set_league", GmLevel::Qa, "<league_id> [score]", set_league);
+    registry.register("reset_punish", GmLevel::Qa, "", reset_punish);
+    registry.register("mute", GmLevel::Operator, "<seconds>", mute);
+    registry.register("unmute", GmLevel::Operator, "", unmute);
+    registry.register(
+        "fast_forward_season",
+        GmLevel::Operator,
+        "<seconds>",
+        fast_forward_season,
+    );
+    registry.register("add_gold", GmLevel::Qa, "<value>", add_gold);
+    registry.register("add_item", GmLevel::Qa, "<item_id>", add_item);
+    registry.register("end_battle", GmLevel::Qa, "[winner_id]", end_battle);
+    registry.register(
+        "spawn_robot",
+        GmLevel::Qa,
+        "<robot_temp_id> [index]",
+        spawn_robot,
+    );
+}
+
+///白名单里的gm权限,配置格式:"gm_users":{"玩家id":权限}
+pub fn gm_level(user_id: u32) -> GmLevel {
+    let gm_users = CONF_MAP.conf.get("gm_users");
+    if gm_users.is_none() {
+        return GmLevel::None;
+    }
+    let level = gm_users
+        .unwrap()
+        .get(user_id.to_string().as_str())
+        .and_then(|v| v.as_u64());
+    match level {
+        Some(level) => GmLevel::from_u32(level as u32),
+        None => GmLevel::None,
+    }
+}
+
+///检查权限并执行gm命令
+pub fn execute(
+    gm: &mut GameMgr,
+    ctx: &GmContext,
+    name: &str,
+    args: &[String],
+) -> anyhow::Result<String> {
+    let f = gm.gm_registry.check(name, ctx.level)?;
+    ctx.check_target()?;
+    info!(
+        "执行gm命令!operator_id:{},target_id:{},name:{},args:{:?}",
+        ctx.operator_id, ctx.target_id, name, args
+    );
+    f(gm, ctx, args)
+}
+
+///客户端发过来的gm命令
+pub fn gm_command(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = C_GM_COMMAND::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let mut target_id = proto.get_target_id();
+    if target_id == 0 {
+        target_id = user_id;
+    }
+    let ctx = GmContext {
+        operator_id: user_id,
+        level: gm_level(user_id),
+        target_id,
+    };
+    let args = proto.get_args().to_vec();
+    let res = execute(gm, &ctx, proto.get_name(), args.as_slice());
+    let bytes = build_result_proto(res).write_to_bytes();
+    match bytes {
+        Ok(bytes) => gm.send_2_client(ClientCode::GmCommand, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+///列出能用的命令
+fn help(gm: &mut GameMgr, ctx: &GmContext, _: &[String]) -> anyhow::Result<String> {
+    Ok(gm.gm_registry.help(ctx.level))
+}
+
+///设置段位和积分,不填积分就用段位的初始积分
+fn set_league(gm: &mut GameMgr, ctx: &GmContext, args: &[String]) -> anyhow::Result<String> {
+    let league_id: i8 = parse_arg(args, 0, "league_id")?;
+    let temp = crate::TEMPLATES.league_temp_mgr().get_temp(&league_id)?;
+    let score = match args.get(1) {
+        Some(_) => parse_arg(args, 1, "score")?,
+        None => temp.score,
+    };
+    let user_data = gm.users.get(&ctx.target_id);
+    if user_data.is_none() {
+        anyhow::bail!("this player is not online!user_id:{}", ctx.target_id)
+    }
+    let name = user_data.unwrap().get_user_info_ref().nick_name.clone();
+
+    let mut league_pt = LeaguePt::new();
+    league_pt.set_league_id(league_id as i32);
+    league_pt.set_league_score(score);
+    league_pt.set_league_time(chrono::Local::now().timestamp_millis());
+    let cters = match gm.get_ri_ref(ctx.target_id) {
+        Some(ri) => ri.get_cters().to_vec(),
+        None => Vec::new(),
+    };
+    gm.update_user_league_id(ctx.target_id, league_pt.clone());
+
+    //走排行榜的更新流程
+    let mut sd = SummaryDataPt::new();
+    sd.set_user_id(ctx.target_id);
+    sd.set_name(name);
+    sd.set_league(league_pt);
+    let mut bss = B_S_SUMMARY::new();
+    bss.set_summary_data(sd);
+    for cter_id in cters {
+        bss.cters.push(cter_id);
+    }
+    let bytes = bss.write_to_bytes()?;
+    gm.send_2_server(RankCode::UpdateRank.into_u32(), ctx.target_id, bytes);
+    Ok(format!(
+        "user_id:{},league_id:{},score:{}",
+        ctx.target_id, league_id, score
+    ))
+}
+
+///清掉匹配惩罚
+fn reset_punish(gm: &mut GameMgr, ctx: &GmContext, _: &[String]) -> anyhow::Result<String> {
+    let user_data = gm.users.get_mut(&ctx.target_id);
+    if user_data.is_none() {
+        anyhow::bail!("this player is not online!user_id:{}", ctx.target_id)
+    }
+    let user_data = user_data.unwrap();
+    let user = user_data.get_user_info_mut_ref();
+    user.punish_match.reset(true);
+    user.add_version();
+    let pm = user.punish_match;
+    user_data.add_version();
+
+    let mut proto = S_PUNISH_MATCH_NOTICE::new();
+    proto.set_user_id(ctx.target_id);
+    proto.set_punish_match(pm.into());
+    let bytes = proto.write_to_bytes()?;
+    gm.send_2_client(ClientCode::PunishMatchPush, ctx.target_id, bytes);
+    Ok(format!("user_id:{}", ctx.target_id))
+}
+
+///禁言
+fn mute(gm: &mut GameMgr, ctx: &GmContext, args: &[String]) -> anyhow::Result<String> {
+    let seconds: i64 = parse_arg(args, 0, "seconds")?;
+    if seconds <= 0 {
+        anyhow::bail!("seconds must be greater than 0!seconds:{}", seconds)
+    }
+    let mute_time = chat_helper::mute(gm, ctx.target_id, seconds)?;
+    Ok(format!("user_id:{},mute_time:{}", ctx.target_id, mute_time))
+}
+
+///解除禁言
+fn unmute(gm: &mut GameMgr, ctx: &GmContext, _: &[String]) -> anyhow::Result<String> {
+    chat_helper::mute(gm, ctx.target_id, 0)?;
+    Ok(format!("user_id:{}", ctx.target_id))
+}
+
+///把赛季结束时间往前拨,最多拨到当前时间
+fn fast_forward_season(_: &mut GameMgr, _: &GmContext, args: &[String]) -> anyhow::Result<String> {
+    let seconds: u64 = parse_arg(args, 0, "seconds")?;
+    let now = chrono::Local::now().timestamp() as u64;
+    let season_id;
+    let next_update_time;
+    unsafe {
+        let time = SEASON.next_update_time.saturating_sub(seconds);
+        SEASON.next_update_time = std::cmp::max(time, now);
+        season_id = SEASON.season_id;
+        next_update_time = SEASON.next_update_time;
+    }
+
+    //同步到redis,重启之后也能读到
+    let mut redis_lock = REDIS_POOL.lock().unwrap();
+    let res: Option<String> =
+        redis_lock.hget(REDIS_INDEX_GAME_SEASON, REDIS_KEY_GAME_SEASON, "101");
+    if let Some(res) = res {
+        let mut value: JsonValue = serde_json::from_str(res.as_str())?;
+        if let Some(map) = value.as_object_mut() {
+            map.insert("next_update_time".to_owned(), next_update_time.into());
+        }
+        let _: Option<u32> = redis_lock.hset(
+            REDIS_INDEX_GAME_SEASON,
+            REDIS_KEY_GAME_SEASON,
+            "101",
+            value.to_string().as_str(),
+        );
+    }
+    Ok(format!(
+        "season_id:{},next_update_time:{}",
+        season_id, next_update_time
+    ))
+}
+
+///转发给战斗服或者房间服执行,结果由它们直接返回给执行者
+fn forward(
+    gm: &mut GameMgr,
+    cmd: u32,
+    name: &str,
+    ctx: &GmContext,
+    args: &[String],
+) -> anyhow::Result<String> {
+    let mut proto = G_GM_COMMAND::new();
+    proto.set_operator_id(ctx.operator_id);
+    proto.set_level(ctx.level.into_u32());
+    proto.set_name(name.to_owned());
+    for arg in args {
+        proto.args.push(arg.clone());
+    }
+    let bytes = proto.write_to_bytes()?;
+    gm.send_2_server(cmd, ctx.target_id, bytes);
+    Ok(format!("forwarded!name:{},user_id:{}", name, ctx.target_id))
+}
+
+fn forward_2_battle(
+    gm: &mut GameMgr,
+    name: &str,
+    ctx: &GmContext,
+    args: &[String],
+) -> anyhow::Result<String> {
+    forward(gm, BattleCode::GmCommand.into_u32(), name, ctx, args)
+}
+
+fn forward_2_room(
+    gm: &mut GameMgr,
+    name: &str,
+    ctx: &GmContext,
+    args: &[String],
+) -> anyhow::Result<String> {
+    forward(gm, RoomCode::GmCommand.into_u32(), name, ctx, args)
+}
+
+///战斗内加金币
+fn add_gold(gm: &mut GameMgr, ctx: &GmContext, args: &[String]) -> anyhow::Result<String> {
+    forward_2_battle(gm, "add_gold", ctx, args)
+}
+
+///战斗内给道具
+fn add_item(gm: &mut GameMgr, ctx: &GmContext, args: &[String]) -> anyhow::Result<String> {
+    forward_2_battle(gm, "add_item", ctx, args)
+}
+
+///强制结束战斗
+fn end_battle(gm: &mut GameMgr, ctx: &GmContext, args: &[String]) -> anyhow::Result<String> {
+    forward_2_battle(gm, "end_battle", ctx, args)
+}
+
+///往房间里加机器人
+fn spawn_robot(gm: &mut GameMgr, ctx: &GmContext, args: &[String]) -> anyhow::Result<String> {
+    forward_2_room(gm, "spawn_robot", ctx, args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qa_target_other() {
+        let mut gm = GameMgr::new();
+        let mut ctx = GmContext {
+            operator_id: 1001,
+            level: GmLevel::Qa,
+            target_id: 1002,
+        };
+        //测试权限不能改别人的数据
+        for &name in ["add_gold", "add_item", "set_league", "reset_punish", "help"].iter() {
+            let res = execute(&mut gm, &ctx, name, &["1".to_owned()]);
+            assert!(res.is_err(), "name:{}", name);
+        }
+        ctx.target_id = ctx.operator_id;
+        assert!(execute(&mut gm, &ctx, "help", &[]).is_ok());
+        //运营可以指定别人
+        ctx.level = GmLevel::Operator;
+        ctx.target_id = 1002;
+        assert!(execute(&mut gm, &ctx, "help", &[]).is_ok());
+    }
+}
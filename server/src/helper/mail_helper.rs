@@ -0,0 +1,517 @@
+use crate::entity::mail::{AttachType, Attachment, Mail, MailType};
+use crate::entity::user::UserData;
+use crate::helper::redis_helper::get_user_from_redis;
+use crate::mgr::game_mgr::GameMgr;
+use crate::mgr::timer_mgr::RESET_SCHEDULE;
+use crate::{
+    CONF_MAP, REDIS_INDEX_USERS, REDIS_KEY_BROADCAST_MAIL, REDIS_KEY_PENDING_MAIL, REDIS_POOL,
+    TEMPLATES,
+};
+use log::{error, info, warn};
+use protobuf::Message;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use tools::cmd_code::ClientCode;
+use tools::protos::base::MailPt;
+use tools::protos::protocol::{
+    C_MAIL_CLAIM, C_MAIL_DELETE, C_MAIL_READ, S_MAIL_CLAIM, S_MAIL_DELETE, S_MAIL_LIST,
+    S_MAIL_NOTICE, S_MAIL_READ,
+};
+use tools::protos::server_protocol::G_SEND_MAIL;
+use tools::util::packet::Packet;
+
+///标题最大长度
+const MAIL_TITLE_MAX_LEN: usize = 50;
+///内容最大长度
+const MAIL_CONTENT_MAX_LEN: usize = 1000;
+///单封邮件最多附件数
+const MAIL_ATTACHMENT_MAX: usize = 20;
+///系统邮件默认有效天数
+const DEFAULT_MAIL_EXPIRE_DAYS: usize = 30;
+///往离线玩家待收邮件列表里追加一封,不碰db里的邮箱,避免和下线存盘互相覆盖
+const APPEND_PENDING_SCRIPT: &str = r#"
+local v = redis.call('HGET', KEYS[1], ARGV[1])
+if v and v ~= '[]' then
+    v = string.sub(v, 1, -2) .. ',' .. ARGV[2] .. ']'
+else
+    v = '[' .. ARGV[2] .. ']'
+end
+return redis.call('HSET', KEYS[1], ARGV[1], v)
+"#;
+///取出并删除玩家待收邮件,和追加互斥
+const TAKE_PENDING_SCRIPT: &str = r#"
+local v = redis.call('HGET', KEYS[1], ARGV[1])
+if v then
+    redis.call('HDEL', KEYS[1], ARGV[1])
+end
+return v
+"#;
+
+///全服邮件,存redis,玩家上线的时候自己拉
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BroadcastMail {
+    pub broadcast_id: u64, //全服邮件id
+    pub mail: Mail,        //邮件
+}
+
+///系统邮件的过期时间
+pub fn default_expire_time() -> i64 {
+    let days = CONF_MAP.get_usize("mail_expire_days", DEFAULT_MAIL_EXPIRE_DAYS) as i64;
+    RESET_SCHEDULE.now().timestamp() + days * 24 * 3600
+}
+
+///放进玩家邮箱,返回新邮件
+pub fn add_mail(user_data: &mut UserData, mail: Mail) -> Option<MailPt> {
+    let mail_id = user_data.mailbox.add_mail(mail);
+    user_data.add_version();
+    user_data
+        .mailbox
+        .mails
+        .iter()
+        .find(|mail| mail.mail_id == mail_id)
+        .map(|mail| mail.into_pt())
+}
+
+///给玩家发邮件,在线直接进邮箱并推送,不在线先存redis,上线的时候再收
+pub fn send_mail(gm: &mut GameMgr, user_id: u32, mail: Mail) -> anyhow::Result<()> {
+    if let Some(user_data) = gm.users.get_mut(&user_id) {
+        if let Some(mail_pt) = add_mail(user_data, mail) {
+            push_mail_notice(gm, user_id, vec![mail_pt]);
+        }
+        info!("给在线玩家发邮件!user_id:{}", user_id);
+        return Ok(());
+    }
+    if get_user_from_redis(user_id).is_none() {
+        anyhow::bail!("this player is not exist!user_id:{}", user_id)
+    }
+    let json = serde_json::to_string(&mail)?;
+    let res: Option<u32> = REDIS_POOL.lock().unwrap().eval(
+        REDIS_INDEX_USERS,
+        APPEND_PENDING_SCRIPT,
+        &[REDIS_KEY_PENDING_MAIL],
+        &[user_id.to_string().as_str(), json.as_str()],
+    );
+    if res.is_none() {
+        anyhow::bail!("save pending mail failed!user_id:{}", user_id)
+    }
+    info!("给离线玩家发邮件!user_id:{}", user_id);
+    Ok(())
+}
+
+///中心服发过来的邮件,包头user_id为收件人,带broadcast_id的是全服邮件
+pub fn receive_mail(gm: &mut GameMgr, packet: Packet) {
+    let mut proto = G_SEND_MAIL::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let mut mail = Mail::from_pt(proto.get_mail());
+    if let Err(e) = check_mail(&mut mail) {
+        warn!("{:?}", e);
+        return;
+    }
+    let broadcast_id = proto.get_broadcast_id();
+    if broadcast_id == 0 {
+        mail.mail_type = MailType::System.into();
+        let user_id = packet.get_user_id();
+        if let Err(e) = send_mail(gm, user_id, mail) {
+            warn!("{:?}", e);
+        }
+        return;
+    }
+    mail.mail_type = MailType::Broadcast.into();
+    let broadcast_mails = save_broadcast_mail(broadcast_id, mail);
+    //在线的玩家直接收
+    let user_ids: Vec<u32> = gm.users.keys().copied().collect();
+    for user_id in user_ids {
+        let user_data = gm.users.get_mut(&user_id).unwrap();
+        let mails = apply_broadcast_mails(user_data, &broadcast_mails);
+        if !mails.is_empty() {
+            push_mail_notice(gm, user_id, mails);
+        }
+    }
+    info!("收到全服邮件!broadcast_id:{}", broadcast_id);
+}
+
+///校验中心服发过来的邮件
+fn check_mail(mail: &mut Mail) -> anyhow::Result<()> {
+    let title_len = mail.title.chars().count();
+    if title_len == 0 || title_len > MAIL_TITLE_MAX_LEN {
+        anyhow::bail!(
+            "mail title length must between 1 and {}!len:{}",
+            MAIL_TITLE_MAX_LEN,
+            title_len
+        )
+    }
+    let content_len = mail.content.chars().count();
+    if content_len > MAIL_CONTENT_MAX_LEN {
+        anyhow::bail!(
+            "mail content is too long!max:{},len:{}",
+            MAIL_CONTENT_MAX_LEN,
+            content_len
+        )
+    }
+    if mail.attachments.len() > MAIL_ATTACHMENT_MAX {
+        anyhow::bail!(
+            "mail has too many attachments!max:{},len:{}",
+            MAIL_ATTACHMENT_MAX,
+            mail.attachments.len()
+        )
+    }
+    for attachment in mail.attachments.iter() {
+        let exist = match AttachType::try_from(attachment.attach_type) {
+            Ok(AttachType::GradeFrame) => TEMPLATES
+                .grade_frame_temp_mgr()
+                .temps
+                .contains_key(&attachment.id),
+            Ok(AttachType::Soul) => TEMPLATES.soul_temp_mgr().temps.contains_key(&attachment.id),
+            Err(_) => false,
+        };
+        if !exist || attachment.count == 0 {
+            anyhow::bail!("mail attachment is invalid!attachment:{:?}", attachment)
+        }
+    }
+    let now = RESET_SCHEDULE.now().timestamp();
+    if mail.is_expired(now) {
+        anyhow::bail!("mail is expired!expire_time:{}", mail.expire_time)
+    }
+    if mail.send_time <= 0 {
+        mail.send_time = now;
+    }
+    Ok(())
+}
+
+///全服邮件存redis,顺便清掉过期的,返回当前所有全服邮件
+fn save_broadcast_mail(broadcast_id: u64, mail: Mail) -> Vec<BroadcastMail> {
+    let mut broadcast_mails = load_broadcast_mails();
+    let now = RESET_SCHEDULE.now().timestamp();
+    let mut redis_lock = REDIS_POOL.lock().unwrap();
+    for bm in broadcast_mails.iter() {
+        if !bm.mail.is_expired(now) {
+            continue;
+        }
+        let _: Option<u32> = redis_lock.hdel(
+            REDIS_INDEX_USERS,
+            REDIS_KEY_BROADCAST_MAIL,
+            bm.broadcast_id.to_string().as_str(),
+        );
+    }
+    broadcast_mails.retain(|bm| !bm.mail.is_expired(now));
+    if broadcast_mails
+        .iter()
+        .any(|bm| bm.broadcast_id == broadcast_id)
+    {
+        return broadcast_mails;
+    }
+    let bm = BroadcastMail { broadcast_id, mail };
+    match serde_json::to_string(&bm) {
+        //每个游戏服都会收到同一封,只存一次
+        Ok(json) => {
+            let _: Option<u32> = redis_lock.hset_nx(
+                REDIS_INDEX_USERS,
+                REDIS_KEY_BROADCAST_MAIL,
+                broadcast_id.to_string().as_str(),
+                json.as_str(),
+            );
+        }
+        Err(e) => error!("{:?}", e),
+    }
+    broadcast_mails.push(bm);
+    broadcast_mails.sort_by(|a, b| a.broadcast_id.cmp(&b.broadcast_id));
+    broadcast_mails
+}
+
+///从redis加载全服邮件,按id排序
+fn load_broadcast_mails() -> Vec<BroadcastMail> {
+    let mut redis_lock = REDIS_POOL.lock().unwrap();
+    let res: Option<Vec<String>> = redis_lock.hvals(REDIS_INDEX_USERS, REDIS_KEY_BROADCAST_MAIL);
+    let mut broadcast_mails: Vec<BroadcastMail> = match res {
+        Some(res) => res
+            .iter()
+            .filter_map(|json| serde_json::from_str(json.as_str()).ok())
+            .collect(),
+        None => Vec::new(),
+    };
+    broadcast_mails.sort_by(|a, b| a.broadcast_id.cmp(&b.broadcast_id));
+    broadcast_mails
+}
+
+///把没收过的全服邮件放进邮箱,返回新邮件
+fn apply_broadcast_mails(
+    user_data: &mut UserData,
+    broadcast_mails: &[BroadcastMail],
+) -> Vec<MailPt> {
+    let now = RESET_SCHEDULE.now().timestamp();
+    let mut mails = Vec::new();
+    for bm in broadcast_mails.iter() {
+        if bm.broadcast_id <= user_data.mailbox.last_broadcast_id {
+            continue;
+        }
+        user_data.mailbox.last_broadcast_id = bm.broadcast_id;
+        user_data.mailbox.add_version();
+        user_data.add_version();
+        if bm.mail.is_expired(now) {
+            continue;
+        }
+        if let Some(mail_pt) = add_mail(user_data, bm.mail.clone()) {
+            mails.push(mail_pt);
+        }
+    }
+    mails
+}
+
+///取出离线期间别人发的邮件
+fn take_pending_mails(user_id: u32) -> Vec<Mail> {
+    let res: Option<Option<String>> = REDIS_POOL.lock().unwrap().eval(
+        REDIS_INDEX_USERS,
+        TAKE_PENDING_SCRIPT,
+        &[REDIS_KEY_PENDING_MAIL],
+        &[user_id.to_string().as_str()],
+    );
+    let res = res.flatten();
+    if res.is_none() {
+        return Vec::new();
+    }
+    match serde_json::from_str(res.unwrap().as_str()) {
+        Ok(mails) => mails,
+        Err(e) => {
+            error!("{:?}", e);
+            Vec::new()
+        }
+    }
+}
+
+///登录的时候收离线期间的邮件和全服邮件,清掉过期邮件
+pub fn login(user_data: &mut UserData) {
+    let user_id = user_data.get_user_info_ref().user_id;
+    for mail in take_pending_mails(user_id) {
+        add_mail(user_data, mail);
+    }
+    let broadcast_mails = load_broadcast_mails();
+    apply_broadcast_mails(user_data, &broadcast_mails);
+    if !user_data.mailbox.remove_expired().is_empty() {
+        user_data.add_version();
+    }
+}
+
+///推送新邮件
+pub fn push_mail_notice(gm: &mut GameMgr, user_id: u32, mails: Vec<MailPt>) {
+    let mut smn = S_MAIL_NOTICE::new();
+    for mail in mails {
+        smn.mails.push(mail);
+    }
+    match smn.write_to_bytes() {
+        Ok(bytes) => gm.send_2_client(ClientCode::MailNotice, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+///邮件列表
+pub fn mail_list(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let user_data = gm.users.get_mut(&user_id);
+    if user_data.is_none() {
+        warn!("could not find user_data for user_id {}", user_id);
+        return;
+    }
+    let user_data = user_data.unwrap();
+    if !user_data.mailbox.remove_expired().is_empty() {
+        user_data.add_version();
+    }
+    let mut sml = S_MAIL_LIST::new();
+    for mail in user_data.mailbox.mails.iter() {
+        sml.mails.push(mail.into_pt());
+    }
+    match sml.write_to_bytes() {
+        Ok(bytes) => gm.send_2_client(ClientCode::MailList, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+///读邮件
+pub fn mail_read(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = C_MAIL_READ::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let mail_id = proto.get_mail_id();
+    let res = match gm.users.get_mut(&user_id) {
+        Some(user_data) => {
+            let res = user_data.mailbox.read(mail_id);
+            if user_data.mailbox.get_version() > 0 {
+                user_data.add_version();
+            }
+            res
+        }
+        None => Err(anyhow::anyhow!(
+            "this player is not login!user_id:{}",
+            user_id
+        )),
+    };
+    let mut smr = S_MAIL_READ::new();
+    smr.set_mail_id(mail_id);
+    match res {
+        Ok(_) => smr.set_is_succ(true),
+        Err(e) => {
+            warn!("{:?}", e);
+            smr.set_is_succ(false);
+            smr.set_err_mess(e.to_string());
+        }
+    }
+    match smr.write_to_bytes() {
+        Ok(bytes) => gm.send_2_client(ClientCode::MailRead, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+///领取附件
+pub fn mail_claim(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = C_MAIL_CLAIM::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let mut smc = S_MAIL_CLAIM::new();
+    let res = match gm.users.get_mut(&user_id) {
+        Some(user_data) => do_mail_claim(user_data, proto.get_mail_id(), &mut smc),
+        None => Err(anyhow::anyhow!(
+            "this player is not login!user_id:{}",
+            user_id
+        )),
+    };
+    match res {
+        Ok(_) => smc.set_is_succ(true),
+        Err(e) => {
+            warn!("{:?}", e);
+            smc.set_is_succ(false);
+            smc.set_err_mess(e.to_string());
+        }
+    }
+    match smc.write_to_bytes() {
+        Ok(bytes) => gm.send_2_client(ClientCode::MailClaim, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+///mail_id为0就领取所有能领的
+fn do_mail_claim(
+    user_data: &mut UserData,
+    mail_id: u32,
+    smc: &mut S_MAIL_CLAIM,
+) -> anyhow::Result<()> {
+    let mail_ids: Vec<u32> = if mail_id > 0 {
+        vec![mail_id]
+    } else {
+        let now = RESET_SCHEDULE.now().timestamp();
+        user_data
+            .mailbox
+            .mails
+            .iter()
+            .filter(|mail| mail.can_claim() && !mail.is_expired(now))
+            .map(|mail| mail.mail_id)
+            .collect()
+    };
+    if mail_ids.is_empty() {
+        anyhow::bail!("there is no mail to claim!")
+    }
+    let user_id = user_data.get_user_info_ref().user_id;
+    for mail_id in mail_ids {
+        //先标记领取再发东西,保证只能领一次
+        let attachments = user_data.mailbox.claim(mail_id)?;
+        grant_attachments(user_data, &attachments);
+        smc.mail_ids.push(mail_id);
+        for attachment in attachments.iter() {
+            smc.attachments.push(attachment.into_pt());
+        }
+        info!("领取邮件附件!user_id:{},mail_id:{}", user_id, mail_id);
+    }
+    user_data.add_version();
+    Ok(())
+}
+
+///发放附件,已经拥有的相框和头像不重复加
+pub fn grant_attachments(user_data: &mut UserData, attachments: &[Attachment]) {
+    for attachment in attachments.iter() {
+        let id = attachment.id;
+        match AttachType::try_from(attachment.attach_type) {
+            Ok(AttachType::GradeFrame) => {
+                if user_data.grade_frame.grade_frames.contains(&id) {
+                    continue;
+                }
+                user_data.grade_frame.grade_frames.push(id);
+                user_data.grade_frame.add_version();
+            }
+            Ok(AttachType::Soul) => {
+                if user_data.soul.souls.contains(&id) {
+                    continue;
+                }
+                user_data.soul.souls.push(id);
+                user_data.soul.add_version();
+            }
+            Err(_) => warn!("attach_type is invalid!attachment:{:?}", attachment),
+        }
+    }
+}
+
+///删除邮件
+pub fn mail_delete(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = C_MAIL_DELETE::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let mut smd = S_MAIL_DELETE::new();
+    let res = match gm.users.get_mut(&user_id) {
+        Some(user_data) => do_mail_delete(user_data, proto.get_mail_id(), &mut smd),
+        None => Err(anyhow::anyhow!(
+            "this player is not login!user_id:{}",
+            user_id
+        )),
+    };
+    match res {
+        Ok(_) => smd.set_is_succ(true),
+        Err(e) => {
+            warn!("{:?}", e);
+            smd.set_is_succ(false);
+            smd.set_err_mess(e.to_string());
+        }
+    }
+    match smd.write_to_bytes() {
+        Ok(bytes) => gm.send_2_client(ClientCode::MailDelete, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+///mail_id为0就删除所有已读并且没有附件可领的
+fn do_mail_delete(
+    user_data: &mut UserData,
+    mail_id: u32,
+    smd: &mut S_MAIL_DELETE,
+) -> anyhow::Result<()> {
+    if mail_id > 0 {
+        user_data.mailbox.delete(mail_id)?;
+        smd.mail_ids.push(mail_id);
+    } else {
+        let mail_ids: Vec<u32> = user_data
+            .mailbox
+            .mails
+            .iter()
+            .filter(|mail| mail.is_read && !mail.can_claim())
+            .map(|mail| mail.mail_id)
+            .collect();
+        for mail_id in mail_ids {
+            user_data.mailbox.delete(mail_id)?;
+            smd.mail_ids.push(mail_id);
+        }
+    }
+    user_data.add_version();
+    Ok(())
+}
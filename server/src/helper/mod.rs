@@ -1,4 +1,14 @@
+pub mod achievement_helper;
+pub mod chat_helper;
+pub mod friend_helper;
+pub mod gm_helper;
+pub mod mail_helper;
+pub mod nick_name_helper;
+pub mod party_helper;
+pub mod quest_helper;
 pub mod redis_helper;
+pub mod tournament_helper;
+pub mod world_boss_helper;
 
 use serde::{Deserialize, Serialize};
 use tools::protos::base::{LeaguePt, RankInfoPt};
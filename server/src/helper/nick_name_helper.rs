@@ -0,0 +1,260 @@
+use crate::db::table_contants::NICK_NAME_LOG;
+use crate::{CONF_MAP, DB_POOL, REDIS_INDEX_USERS, REDIS_KEY_NICK_NAME, REDIS_POOL};
+use log::{error, info, warn};
+use mysql::Value;
+use std::collections::HashSet;
+
+///改名来源:客户端改名请求
+pub const SOURCE_CLIENT: &str = "client";
+///改名来源:客户端同步数据
+pub const SOURCE_SYNC: &str = "sync";
+///改名来源:用户中心
+pub const SOURCE_USER_CENTER: &str = "user_center";
+
+lazy_static! {
+    ///屏蔽词,已经做过归一化
+    static ref BLOCK_WORDS: Vec<String> = load_block_words();
+}
+
+///改名冷却时间默认值(秒)
+const DEFAULT_RENAME_CD: usize = 86400;
+
+///加载屏蔽词文件,一行一个
+fn load_block_words() -> Vec<String> {
+    let path = CONF_MAP.get_str("nick_name_block_words", "config/block_words.txt");
+    let res = std::fs::read_to_string(path.as_str());
+    if let Err(e) = res {
+        warn!("block words file can not read!path:{},err:{:?}", path, e);
+        return Vec::new();
+    }
+    let mut set = HashSet::new();
+    for line in res.unwrap().lines() {
+        let word = normalize(line);
+        if word.is_empty() {
+            continue;
+        }
+        set.insert(word);
+    }
+    info!("加载屏蔽词完成!数量:{}", set.len());
+    set.into_iter().collect()
+}
+
+///全角转半角
+fn to_half_width(c: char) -> char {
+    match c as u32 {
+        0xFF01..=0xFF5E => std::char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        0x3000 => ' ',
+        _ => c,
+    }
+}
+
+///把形近字符统一掉,只用来做屏蔽词匹配
+/// 全角转半角,转小写,常见的数字/符号/西里尔/希腊字母替换成拉丁字母,去掉空白和标点
+pub fn normalize(name: &str) -> String {
+    let mut res = String::with_capacity(name.len());
+    for c in name.chars() {
+        let c = match to_half_width(c) {
+            '0' | 'о' | 'О' | 'ο' | 'Ο' => 'o',
+            '1' | '!' | '|' | 'l' | 'і' | 'І' | 'ι' => 'i',
+            '3' | 'е' | 'Е' | 'ε' => 'e',
+            '4' | '@' | 'а' | 'А' | 'α' => 'a',
+            '5' | '$' | 'ѕ' | 'Ѕ' => 's',
+            '7' => 't',
+            '8' => 'b',
+            'р' | 'Р' | 'ρ' => 'p',
+            'с' | 'С' => 'c',
+            'х' | 'Х' | 'χ' => 'x',
+            'у' | 'У' | 'γ' => 'y',
+            'к' | 'К' | 'κ' => 'k',
+            'м' | 'М' => 'm',
+            'т' | 'Т' | 'τ' => 't',
+            'н' | 'Н' | 'η' => 'h',
+            'в' | 'В' | 'β' => 'b',
+            'ν' => 'v',
+            _ => c,
+        };
+        //空白,标点,零宽字符之类的全部去掉
+        if c.is_whitespace() || c.is_ascii_punctuation() || c.is_control() {
+            continue;
+        }
+        if let '\u{200B}'..='\u{200F}' | '\u{FEFF}' = c {
+            continue;
+        }
+        for lower in c.to_lowercase() {
+            res.push(lower);
+        }
+    }
+    res
+}
+
+///昵称唯一性索引的key,只忽略全半角和大小写,数字和形近字母不能合并
+pub fn index_key(name: &str) -> String {
+    name.chars()
+        .map(to_half_width)
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+///校验长度和字符类型,只允许文字,数字和下划线
+pub fn check_format(name: &str) -> anyhow::Result<()> {
+    let min_len = CONF_MAP.get_usize("nick_name_min_len", 2);
+    let max_len = CONF_MAP.get_usize("nick_name_max_len", 12);
+    let len = name.chars().count();
+    if len < min_len || len > max_len {
+        anyhow::bail!(
+            "nick_name length must between {} and {}!len:{}",
+            min_len,
+            max_len,
+            len
+        )
+    }
+    for c in name.chars() {
+        if c.is_alphanumeric() || c == '_' {
+            continue;
+        }
+        anyhow::bail!("nick_name has illegal character:{:?}", c)
+    }
+    Ok(())
+}
+
+///校验屏蔽词
+pub fn check_block_words(name: &str) -> anyhow::Result<()> {
+    if has_block_words(name) {
+        anyhow::bail!("nick_name has block word!nick_name:{}", name)
+    }
+    Ok(())
+}
+
+///是否包含屏蔽词,昵称和聊天共用
+pub fn has_block_words(text: &str) -> bool {
+    let normalized = normalize(text);
+    BLOCK_WORDS
+        .iter()
+        .any(|word| normalized.contains(word.as_str()))
+}
+
+///校验改名冷却,last_time:上次改名时间(秒),0代表没改过
+pub fn check_cd(last_time: i64) -> anyhow::Result<()> {
+    if last_time <= 0 {
+        return Ok(());
+    }
+    let cd = CONF_MAP.get_usize("nick_name_cd", DEFAULT_RENAME_CD) as i64;
+    let now = chrono::Local::now().timestamp();
+    if now - last_time < cd {
+        anyhow::bail!("nick_name is in cd!left:{}s", cd - (now - last_time))
+    }
+    Ok(())
+}
+
+///redis里面当前游戏的昵称索引key
+fn get_index_key() -> String {
+    let game_id = CONF_MAP.get_usize("game_id", 101);
+    format!("{}_{}", REDIS_KEY_NICK_NAME, game_id)
+}
+
+///占用昵称,同一个游戏内昵称唯一,只差全半角或者大小写的也算重复
+pub fn occupy(user_id: u32, name: &str) -> anyhow::Result<()> {
+    let key = get_index_key();
+    let normalized = index_key(name);
+    let mut redis_lock = REDIS_POOL.lock().unwrap();
+    let res: Option<u32> = redis_lock.hset_nx(
+        REDIS_INDEX_USERS,
+        key.as_str(),
+        normalized.as_str(),
+        user_id.to_string().as_str(),
+    );
+    match res {
+        Some(1) => Ok(()),
+        Some(_) => {
+            //自己占着的也算成功
+            let owner: Option<String> =
+                redis_lock.hget(REDIS_INDEX_USERS, key.as_str(), normalized.as_str());
+            if owner == Some(user_id.to_string()) {
+                return Ok(());
+            }
+            anyhow::bail!("nick_name is already used!nick_name:{}", name)
+        }
+        None => anyhow::bail!("nick_name index is unavailable!"),
+    }
+}
+
+///释放旧昵称
+pub fn release(user_id: u32, name: &str) {
+    if name.is_empty() {
+        return;
+    }
+    let key = get_index_key();
+    let normalized = index_key(name);
+    let mut redis_lock = REDIS_POOL.lock().unwrap();
+    let owner: Option<String> =
+        redis_lock.hget(REDIS_INDEX_USERS, key.as_str(), normalized.as_str());
+    //别人占着的不能删
+    if owner != Some(user_id.to_string()) {
+        return;
+    }
+    let _: Option<u32> = redis_lock.hdel(REDIS_INDEX_USERS, key.as_str(), normalized.as_str());
+}
+
+///记录改名日志
+pub fn audit(user_id: u32, old_name: &str, new_name: &str, source: &str) {
+    info!(
+        "修改昵称!user_id:{},old:{},new:{},source:{}",
+        user_id, old_name, new_name, source
+    );
+    let mut sql = String::new();
+    sql.push_str("insert into ");
+    sql.push_str(NICK_NAME_LOG);
+    sql.push_str(" (user_id,old_name,new_name,source,time) values (?,?,?,?,?)");
+    let v = vec![
+        Value::UInt(user_id as u64),
+        Value::from(old_name),
+        Value::from(new_name),
+        Value::from(source),
+        Value::Int(chrono::Local::now().timestamp()),
+    ];
+    let res = DB_POOL.exe_sql(sql.as_str(), Some(v));
+    if let Err(e) = res {
+        error!("{:?}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("ＡＤＭＩＮ"), "admin");
+        assert_eq!(normalize("4dm1n"), "admin");
+        assert_eq!(normalize("f u.c-k"), "fuck");
+        assert_eq!(normalize("аdmin\u{200B}"), "admin");
+        assert_eq!(normalize("张三"), "张三");
+    }
+
+    #[test]
+    fn test_index_key() {
+        //大小写和全半角算同一个昵称
+        assert_eq!(index_key("Bob"), index_key("bob"));
+        assert_eq!(index_key("ｂｏｂ"), "bob");
+        //数字和形近字母不能合并,不然bob0和bobo只能存在一个
+        assert_ne!(index_key("bob0"), index_key("bobo"));
+        assert_ne!(index_key("l1"), index_key("ll"));
+    }
+
+    #[test]
+    fn test_check_format() {
+        assert!(check_format("张三_01").is_ok());
+        assert!(check_format("a").is_err());
+        assert!(check_format("abcdefghijklm").is_err());
+        assert!(check_format("bad name").is_err());
+        assert!(check_format("bad!").is_err());
+    }
+
+    #[test]
+    fn test_check_cd() {
+        let now = chrono::Local::now().timestamp();
+        assert!(check_cd(0).is_ok());
+        assert!(check_cd(now - 10).is_err());
+        assert!(check_cd(now - 86400 * 2).is_ok());
+    }
+}
@@ -0,0 +1,137 @@
+use crate::entity::Entity;
+use crate::helper::friend_helper;
+use crate::mgr::game_mgr::GameMgr;
+use crate::mgr::party_mgr::Party;
+use log::{error, info, warn};
+use protobuf::Message;
+use tools::cmd_code::ClientCode;
+use tools::protos::protocol::{
+    C_PARTY_INVITE, C_PARTY_JOIN, S_PARTY_INVITE, S_PARTY_INVITE_NOTICE, S_PARTY_JOIN,
+    S_PARTY_NOTICE,
+};
+use tools::util::packet::Packet;
+
+///邀请好友组队
+pub fn party_invite(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = C_PARTY_INVITE::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let res = do_party_invite(gm, user_id, proto.get_target_id());
+    let mut spi = S_PARTY_INVITE::new();
+    match res {
+        Ok(_) => spi.set_is_succ(true),
+        Err(e) => {
+            warn!("{:?}", e);
+            spi.set_is_succ(false);
+            spi.set_err_mess(e.to_string());
+        }
+    }
+    match spi.write_to_bytes() {
+        Ok(bytes) => gm.send_2_client(ClientCode::PartyInvite, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+fn do_party_invite(gm: &mut GameMgr, user_id: u32, target_id: u32) -> anyhow::Result<()> {
+    if target_id == 0 || target_id == user_id {
+        anyhow::bail!("target_id is error!target_id:{}", target_id)
+    }
+    //队伍要一起进房间,只能邀请本服在线的好友
+    if !gm.users.contains_key(&target_id) {
+        anyhow::bail!("target is not online!target_id:{}", target_id)
+    }
+    if !friend_helper::is_friend(user_id, target_id) {
+        anyhow::bail!("target is not friend!target_id:{}", target_id)
+    }
+    let now = chrono::Local::now().timestamp();
+    gm.party_mgr.invite(user_id, target_id, now)?;
+    let nick_name = match gm.users.get(&user_id) {
+        Some(user_data) => user_data.get_user_info_ref().nick_name.clone(),
+        None => String::new(),
+    };
+    let mut spin = S_PARTY_INVITE_NOTICE::new();
+    spin.set_leader_id(user_id);
+    spin.set_nick_name(nick_name);
+    let bytes = spin.write_to_bytes()?;
+    gm.send_2_client(ClientCode::PartyInviteNotice, target_id, bytes);
+    Ok(())
+}
+
+///接受组队邀请
+pub fn party_join(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = C_PARTY_JOIN::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let leader_id = proto.get_leader_id();
+    let res = if gm.users.contains_key(&leader_id) {
+        let now = chrono::Local::now().timestamp();
+        gm.party_mgr.join(user_id, leader_id, now)
+    } else {
+        Err(anyhow::anyhow!(
+            "party leader is not online!leader_id:{}",
+            leader_id
+        ))
+    };
+    let mut spj = S_PARTY_JOIN::new();
+    match res {
+        Ok(party) => {
+            spj.set_is_succ(true);
+            info!("加入队伍!user_id:{},party:{:?}", user_id, party);
+            push_party(gm, &party);
+        }
+        Err(e) => {
+            warn!("{:?}", e);
+            spj.set_is_succ(false);
+            spj.set_err_mess(e.to_string());
+        }
+    }
+    match spj.write_to_bytes() {
+        Ok(bytes) => gm.send_2_client(ClientCode::PartyJoin, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+///离开队伍
+pub fn party_leave(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let party = gm.party_mgr.leave(user_id);
+    if party.is_none() {
+        warn!("this player is not in party!user_id:{}", user_id);
+        return;
+    }
+    info!("离开队伍!user_id:{}", user_id);
+    push_party_to(gm, user_id, &Party::default());
+    push_party(gm, &party.unwrap());
+}
+
+///下线的时候离开队伍
+pub fn off_line(gm: &mut GameMgr, user_id: u32) {
+    let party = gm.party_mgr.off_line(user_id);
+    if let Some(party) = party {
+        push_party(gm, &party);
+    }
+}
+
+///推送队伍变化给队伍里的人
+fn push_party(gm: &mut GameMgr, party: &Party) {
+    for &member in party.members.iter() {
+        push_party_to(gm, member, party);
+    }
+}
+
+fn push_party_to(gm: &mut GameMgr, user_id: u32, party: &Party) {
+    let mut spn = S_PARTY_NOTICE::new();
+    spn.set_party(party.into_pt());
+    match spn.write_to_bytes() {
+        Ok(bytes) => gm.send_2_client(ClientCode::PartyNotice, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
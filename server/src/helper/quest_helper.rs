@@ -0,0 +1,110 @@
+use crate::entity::quest::MatchResult;
+use crate::helper::mail_helper::grant_attachments;
+use crate::mgr::game_mgr::GameMgr;
+use log::{error, info, warn};
+use protobuf::Message;
+use tools::cmd_code::ClientCode;
+use tools::protos::base::{QuestPt, SummaryDataPt};
+use tools::protos::protocol::{C_QUEST_CLAIM, S_QUEST_CLAIM, S_QUEST_LIST, S_QUEST_NOTICE};
+use tools::util::packet::Packet;
+
+///匹配结算的时候加任务进度
+pub fn on_summary(gm: &mut GameMgr, summary_data: &SummaryDataPt) {
+    let user_id = summary_data.get_user_id();
+    let res = MatchResult::from(summary_data);
+    let changed = match gm.users.get_mut(&user_id) {
+        Some(user_data) => {
+            let changed = user_data.quests.on_match_result(&res);
+            if !changed.is_empty() {
+                user_data.add_version();
+            }
+            changed
+        }
+        None => return,
+    };
+    if !changed.is_empty() {
+        push_quest_notice(gm, user_id, changed);
+    }
+}
+
+///推送任务变化
+pub fn push_quest_notice(gm: &mut GameMgr, user_id: u32, quests: Vec<QuestPt>) {
+    let mut sqn = S_QUEST_NOTICE::new();
+    for quest in quests {
+        sqn.quests.push(quest);
+    }
+    match sqn.write_to_bytes() {
+        Ok(bytes) => gm.send_2_client(ClientCode::QuestNotice, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+///重置之后把新任务推给在线玩家
+pub fn push_all_quests(gm: &mut GameMgr, user_id: u32) {
+    let quests = match gm.users.get(&user_id) {
+        Some(user_data) => user_data.quests.into_pts(),
+        None => return,
+    };
+    push_quest_notice(gm, user_id, quests);
+}
+
+///任务列表
+pub fn quest_list(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let user_data = gm.users.get(&user_id);
+    if user_data.is_none() {
+        warn!("could not find user_data for user_id {}", user_id);
+        return;
+    }
+    let user_data = user_data.unwrap();
+    let mut sql = S_QUEST_LIST::new();
+    for quest in user_data.quests.into_pts() {
+        sql.quests.push(quest);
+    }
+    match sql.write_to_bytes() {
+        Ok(bytes) => gm.send_2_client(ClientCode::QuestList, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+///领取任务奖励
+pub fn quest_claim(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = C_QUEST_CLAIM::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let quest_id = proto.get_quest_id();
+    let mut sqc = S_QUEST_CLAIM::new();
+    sqc.set_quest_id(quest_id);
+    let res = match gm.users.get_mut(&user_id) {
+        //先标记领取再发东西,保证只能领一次
+        Some(user_data) => user_data.quests.claim(quest_id).map(|attachment| {
+            grant_attachments(user_data, &[attachment.clone()]);
+            user_data.add_version();
+            attachment
+        }),
+        None => Err(anyhow::anyhow!(
+            "this player is not login!user_id:{}",
+            user_id
+        )),
+    };
+    match res {
+        Ok(attachment) => {
+            info!("领取任务奖励!user_id:{},quest_id:{}", user_id, quest_id);
+            sqc.set_is_succ(true);
+            sqc.attachments.push(attachment.into_pt());
+        }
+        Err(e) => {
+            warn!("{:?}", e);
+            sqc.set_is_succ(false);
+            sqc.set_err_mess(e.to_string());
+        }
+    }
+    match sqc.write_to_bytes() {
+        Ok(bytes) => gm.send_2_client(ClientCode::QuestClaim, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
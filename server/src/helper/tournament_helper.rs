@@ -0,0 +1,76 @@
+use crate::entity::user_info::build_player_battle_pt;
+use crate::mgr::game_mgr::GameMgr;
+use log::{error, warn};
+use protobuf::Message;
+use tools::cmd_code::{CenterCode, ClientCode, RoomCode};
+use tools::protos::protocol::{C_TOURNAMENT_SIGN_UP, S_TOURNAMENT_SIGN_UP};
+use tools::protos::server_protocol::{
+    G_C_TOURNAMENT_SIGN_UP, G_R_TOURNAMENT_JOIN, G_TOURNAMENT_MATCH,
+};
+use tools::util::packet::Packet;
+
+///锦标赛报名,带上昵称和段位转发给中心服,由中心服回客户端
+pub fn tournament_sign_up(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = C_TOURNAMENT_SIGN_UP::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let user_data = gm.users.get(&user_id);
+    if user_data.is_none() {
+        warn!("could not find user_data for user_id {}", user_id);
+        let mut sts = S_TOURNAMENT_SIGN_UP::new();
+        sts.set_tournament_id(proto.tournament_id);
+        sts.set_err_mess("this player is not login!".to_owned());
+        match sts.write_to_bytes() {
+            Ok(bytes) => gm.send_2_client(ClientCode::TournamentSignUp, user_id, bytes),
+            Err(e) => error!("{:?}", e),
+        }
+        return;
+    }
+    let nick_name = user_data
+        .unwrap()
+        .get_user_info_ref()
+        .get_nick_name()
+        .to_owned();
+    let mut gcts = G_C_TOURNAMENT_SIGN_UP::new();
+    gcts.set_tournament_id(proto.tournament_id);
+    gcts.set_nick_name(nick_name);
+    if let Some(rank_pt) = gm.get_ri_ref(user_id) {
+        gcts.set_league(rank_pt.get_league().clone());
+    }
+    match gcts.write_to_bytes() {
+        Ok(bytes) => gm.send_2_server(CenterCode::TournamentSignUp.into_u32(), user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
+///中心服通知开赛,带上玩家数据去房间服进锦标赛房间
+pub fn tournament_match(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut proto = G_TOURNAMENT_MATCH::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let pbp = build_player_battle_pt(gm, user_id);
+    if pbp.is_none() {
+        warn!(
+            "tournament match:this player is not online!user_id:{},tournament_id:{}",
+            user_id, proto.tournament_id
+        );
+        return;
+    }
+    let mut grtj = G_R_TOURNAMENT_JOIN::new();
+    grtj.set_tournament_id(proto.tournament_id);
+    grtj.set_match_id(proto.match_id);
+    grtj.set_count(proto.count);
+    grtj.set_pbp(pbp.unwrap());
+    match grtj.write_to_bytes() {
+        Ok(bytes) => gm.send_2_server(RoomCode::TournamentJoin.into_u32(), user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
@@ -0,0 +1,73 @@
+use crate::entity::mail::{AttachType, Attachment, Mail, MailType};
+use crate::entity::user::UserData;
+use crate::helper::mail_helper;
+use crate::{REDIS_INDEX_RANK, REDIS_KEY_WORLD_BOSS_REWARD, REDIS_POOL};
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use tools::protos::base::MailPt;
+
+///取出并删除玩家待领取的奖励,和排行榜服追加奖励互斥
+const TAKE_REWARD_SCRIPT: &str = r#"
+local v = redis.call('HGET', KEYS[1], ARGV[1])
+if v then
+    redis.call('HDEL', KEYS[1], ARGV[1])
+end
+return v
+"#;
+
+///世界boss奖励,排行榜服结算的时候写到redis
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorldBossReward {
+    pub world_boss_id: i32,     //哪一轮的boss
+    pub rank: u32,              //名次,从1开始
+    pub grade_frames: Vec<u32>, //奖励grade相框
+    pub souls: Vec<u32>,        //奖励灵魂头像
+}
+
+///领取世界boss奖励,奖励通过邮件发到邮箱,返回新邮件
+pub fn claim_reward(user_data: &mut UserData) -> Vec<MailPt> {
+    let user_id = user_data.get_user_info_ref().user_id.to_string();
+    let res: Option<Option<String>> = REDIS_POOL.lock().unwrap().eval(
+        REDIS_INDEX_RANK,
+        TAKE_REWARD_SCRIPT,
+        &[REDIS_KEY_WORLD_BOSS_REWARD],
+        &[user_id.as_str()],
+    );
+    let res = res.flatten();
+    if res.is_none() {
+        return Vec::new();
+    }
+    let rewards: Result<Vec<WorldBossReward>, _> = serde_json::from_str(res.unwrap().as_str());
+    if let Err(e) = rewards {
+        error!("{:?}", e);
+        return Vec::new();
+    }
+    let mut mails = Vec::new();
+    for reward in rewards.unwrap() {
+        let mut attachments = Vec::new();
+        for id in reward.grade_frames {
+            attachments.push(Attachment::new(AttachType::GradeFrame, id, 1));
+        }
+        for id in reward.souls {
+            attachments.push(Attachment::new(AttachType::Soul, id, 1));
+        }
+        let mail = Mail::new(
+            MailType::System,
+            "世界boss奖励".to_owned(),
+            format!(
+                "世界boss:{}已被击败,你的伤害排名第{}名",
+                reward.world_boss_id, reward.rank
+            ),
+            attachments,
+            mail_helper::default_expire_time(),
+        );
+        if let Some(mail_pt) = mail_helper::add_mail(user_data, mail) {
+            mails.push(mail_pt);
+        }
+        info!(
+            "发放世界boss奖励邮件!user_id:{},world_boss_id:{},rank:{}",
+            user_id, reward.world_boss_id, reward.rank
+        );
+    }
+    mails
+}
@@ -5,7 +5,10 @@ mod mgr;
 mod net;
 use crate::db::dbtool::DbPool;
 use crate::mgr::game_mgr::GameMgr;
-use crate::net::http::{SavePlayerHttpHandler, StopServerHttpHandler};
+use crate::net::http::{
+    EntitlementHttpHandler, GmCommandHttpHandler, GmEntitlementHttpHandler, SavePlayerHttpHandler,
+    StopServerHttpHandler,
+};
 use crate::net::tcp_server;
 use async_std::task::block_on;
 use tools::thread_pool::ThreadWorkPool;
@@ -18,6 +21,8 @@ use log::{error, info, warn};
 use std::env;
 use tools::conf::Conf;
 use tools::http::HttpServerHandler;
+use tools::metrics::MetricsHttpHandler;
+use tools::protos::base::WorldBossRankPt;
 use tools::redis_pool::RedisPoolTool;
 use tools::templates::template::{init_temps_mgr, TemplatesMgr};
 
@@ -84,6 +89,22 @@ const REDIS_KEY_GAME_SEASON: &str = "game_season";
 const REDIS_KEY_WORLD_BOSS: &str = "world_boss";
 ///redis user_id对应平台id key
 const REDIS_KEY_UID_2_PID: &str = "uid_2_pid";
+///redis 昵称索引key,后面拼游戏id
+const REDIS_KEY_NICK_NAME: &str = "nick_name";
+///redis 世界boss待领取奖励key
+const REDIS_KEY_WORLD_BOSS_REWARD: &str = "world_boss_reward";
+///redis 聊天举报key
+const REDIS_KEY_CHAT_REPORT: &str = "chat_report";
+///redis 好友数据key
+const REDIS_KEY_FRIENDS: &str = "friends";
+///redis 在线状态key
+const REDIS_KEY_PRESENCE: &str = "presence";
+///redis 全服邮件key
+const REDIS_KEY_BROADCAST_MAIL: &str = "broadcast_mail";
+///redis 离线玩家待收邮件key
+const REDIS_KEY_PENDING_MAIL: &str = "pending_mail";
+///redis 定时任务上次执行时间key,后面拼服务器名
+const REDIS_KEY_SCHEDULER: &str = "scheduler";
 
 ///上个赛季排行
 const REDIS_KEY_LAST_RANK: &str = "last_rank";
@@ -114,6 +135,10 @@ impl Season {
 pub struct WorldBoss {
     world_boss_id: i32,
     next_update_time: u64,
+    hp: u64,                     //全服剩余血量,排行榜服推过来的
+    max_hp: u64,                 //最大血量
+    milestone: u32,              //当前血量节点
+    ranks: Vec<WorldBossRankPt>, //伤害排行
 }
 
 impl WorldBoss {
@@ -121,6 +146,10 @@ impl WorldBoss {
         WorldBoss {
             world_boss_id: 0,
             next_update_time: 0,
+            hp: 0,
+            max_hp: 0,
+            milestone: 0,
+            ranks: Vec::new(),
         }
     }
 }
@@ -160,6 +189,12 @@ fn main() {
 }
 
 fn init_log() {
+    //配置了log_format就用tracing输出,日志会带上trace_id/user_id/room_id/battle_id
+    let log_format = CONF_MAP.get_str("log_format", "");
+    if !log_format.is_empty() {
+        tools::my_log::init_trace_log(log_format == "json");
+        return;
+    }
     let info_log = &CONF_MAP.get_str("info_log_path", "");
     let error_log = &CONF_MAP.get_str("error_log_path", "");
     tools::my_log::init_log(info_log, error_log);
@@ -290,6 +325,12 @@ fn init_http_server(gm: Lock) {
     tools::http::Builder::new()
         .route(Box::new(SavePlayerHttpHandler::new(gm.clone())))
         .route(Box::new(StopServerHttpHandler::new(gm.clone())))
+        .route(Box::new(EntitlementHttpHandler::new(gm.clone())))
+        .route(Box::new(GmEntitlementHttpHandler::new(gm.clone())))
+        .route(Box::new(GmCommandHttpHandler::new(gm.clone())))
+        .route(Box::new(MetricsHttpHandler::new(move || {
+            block_on(gm.lock()).collect_metrics();
+        })))
         .bind(http_port);
 }
 
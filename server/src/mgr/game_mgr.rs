@@ -8,19 +8,38 @@ use chrono::Local;
 use log::{error, info, warn};
 use protobuf::Message;
 use std::collections::hash_map::RandomState;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::convert::TryFrom;
 use std::str::FromStr;
-use tools::cmd_code::{ClientCode, GameCode, ServerCommonCode};
+use tools::cmd_code::{ClientCode, GameCode, RoomCode, ServerCommonCode};
+use tools::gm::GmRegistry;
+use tools::metrics;
 use tools::net_message_io::NetHandler;
 use tools::protos::base::{PlayerPt, PunishMatchPt, WorldBossPt};
 use tools::protos::base::{RankInfoPt, SeasonPt};
-use tools::protos::protocol::{C_SYNC_DATA, S_SYNC_DATA, S_USER_LOGIN};
-use tools::protos::server_protocol::{B_S_SUMMARY, G_S_MODIFY_NICK_NAME, UPDATE_WORLD_BOSS_PUSH};
+use tools::protos::protocol::{
+    C_MODIFY_NICK_NAME, C_SYNC_DATA, S_MODIFY_NICK_NAME, S_SHOW_WORLD_BOSS, S_SYNC_DATA,
+    S_USER_LOGIN,
+};
+use tools::protos::server_protocol::{
+    B_S_SUMMARY, G_S_MODIFY_NICK_NAME, R_G_WORLD_BOSS_PUSH, UPDATE_WORLD_BOSS_PUSH,
+};
+use tools::trace;
 use tools::util::packet::Packet;
 use tools::{cmd_code::RankCode, protos::base::LeaguePt};
 
-use super::RoomType;
+use super::party_mgr::PartyMgr;
+use super::{PresenceState, RoomType};
+use crate::helper::achievement_helper;
+use crate::helper::chat_helper;
+use crate::helper::friend_helper;
+use crate::helper::gm_helper;
+use crate::helper::mail_helper;
+use crate::helper::nick_name_helper;
+use crate::helper::party_helper;
+use crate::helper::quest_helper;
+use crate::helper::tournament_helper;
+use crate::helper::world_boss_helper;
 use crate::helper::RankInfo;
 use rayon::prelude::ParallelSliceMut;
 
@@ -35,6 +54,9 @@ pub struct GameMgr {
     pub user_best_rank: HashMap<u32, RankInfoPt>, //玩家最佳排行
     net_handler: Option<NetHandler>,              //tcpchannel
     pub cmd_map: HashMap<u32, fn(&mut GameMgr, Packet), RandomState>, //命令管理
+    pub gm_registry: GmRegistry<GameMgr>,         //gm命令
+    pub chat_times: HashMap<u32, VecDeque<i64>>,  //玩家最近的发言时间,聊天限流用
+    pub party_mgr: PartyMgr,                      //组队
 }
 
 impl GameMgr {
@@ -48,9 +70,13 @@ impl GameMgr {
             last_season_rank: Vec::new(),
             user_best_rank: HashMap::new(),
             cmd_map: HashMap::new(),
+            gm_registry: GmRegistry::default(),
+            chat_times: HashMap::new(),
+            party_mgr: PartyMgr::default(),
         };
         //初始化命令
         gm.cmd_init();
+        gm_helper::init_gm_registry(&mut gm.gm_registry);
         gm
     }
 
@@ -146,6 +172,22 @@ impl GameMgr {
         tcp.node_handler.network().send(endpoint, bytes.as_slice());
     }
 
+    ///昵称改了之后通知排行榜服和房间服更新缓存
+    pub fn push_nick_name(&mut self, user_id: u32, nick_name: &str) {
+        let mut grnn = G_S_MODIFY_NICK_NAME::new();
+        grnn.set_nick_name(nick_name.to_owned());
+        let bytes = grnn.write_to_bytes();
+        match bytes {
+            Ok(bytes) => {
+                self.send_2_server(RankCode::ModifyNickName.into_u32(), user_id, bytes.clone());
+                self.send_2_server(RoomCode::ModifyNickName.into_u32(), user_id, bytes);
+            }
+            Err(e) => {
+                error!("{:?}", e);
+            }
+        }
+    }
+
     pub fn save_user_http(&mut self) {
         let time = std::time::SystemTime::now();
         let mut count: u32 = 0;
@@ -163,6 +205,11 @@ impl GameMgr {
         );
     }
 
+    ///刷新监控指标
+    pub fn collect_metrics(&self) {
+        metrics::set_gauge("online_users", self.users.len() as i64);
+    }
+
     ///保存玩家数据
     pub fn save_user(&mut self, sender: crossbeam::channel::Sender<Vec<Box<dyn EntityData>>>) {
         let time = std::time::SystemTime::now();
@@ -188,6 +235,22 @@ impl GameMgr {
             if ud.soul.get_version() > 0 {
                 v.push(ud.soul.try_clone_for_db());
             }
+            //平台权益数据
+            if ud.entitlement.get_version() > 0 {
+                v.push(ud.entitlement.try_clone_for_db());
+            }
+            //邮箱数据
+            if ud.mailbox.get_version() > 0 {
+                v.push(ud.mailbox.try_clone_for_db());
+            }
+            //局外任务数据
+            if ud.quests.get_version() > 0 {
+                v.push(ud.quests.try_clone_for_db());
+            }
+            //成就数据
+            if ud.achievements.get_version() > 0 {
+                v.push(ud.achievements.try_clone_for_db());
+            }
             //由于这里是深拷贝，所以在这里提前清空版本号，不然在接收方那边执行update，清空的版本号也是clone的
             ud.clear_version();
         }
@@ -201,6 +264,12 @@ impl GameMgr {
                 _ => {}
             }
         }
+        metrics::set_gauge("save_channel_backlog", sender.len() as i64);
+        metrics::observe(
+            "save_user_duration_seconds",
+            metrics::NO_CMD,
+            time.elapsed().unwrap_or_default(),
+        );
         info!(
             "开始执行定时保存玩家，发送数量:{},耗时:{}ms",
             count,
@@ -211,9 +280,15 @@ impl GameMgr {
     ///执行函数，通过packet拿到cmd，然后从cmdmap拿到函数指针调用
     pub fn invok(&mut self, packet: Packet) {
         let cmd = packet.get_cmd();
+        let _trace = trace::enter(&packet);
         let f = self.cmd_map.get_mut(&cmd);
         match f {
-            Some(func) => func(self, packet),
+            Some(func) => {
+                let func = *func;
+                let time = std::time::Instant::now();
+                func(self, packet);
+                metrics::record_invok(cmd, time.elapsed());
+            }
             None => {
                 error!("there is no cmd:{}", cmd);
             }
@@ -249,8 +324,74 @@ impl GameMgr {
         self.cmd_map.insert(GameCode::Summary.into_u32(), summary);
         self.cmd_map
             .insert(GameCode::SyncRankNickName.into_u32(), sync_rank_nick_name);
+        self.cmd_map
+            .insert(GameCode::ModifyNickName.into_u32(), modify_nick_name);
         self.cmd_map
             .insert(GameCode::UpdateWorldBossPush.into_u32(), update_worldboss);
+        self.cmd_map
+            .insert(GameCode::WorldBossPush.into_u32(), world_boss_push);
+        self.cmd_map
+            .insert(GameCode::ShowWorldBoss.into_u32(), show_world_boss);
+        self.cmd_map
+            .insert(GameCode::GmCommand.into_u32(), gm_helper::gm_command);
+        self.cmd_map
+            .insert(GameCode::Chat.into_u32(), chat_helper::chat);
+        self.cmd_map
+            .insert(GameCode::ChatReport.into_u32(), chat_helper::chat_report);
+        self.cmd_map
+            .insert(GameCode::FriendList.into_u32(), friend_helper::friend_list);
+        self.cmd_map.insert(
+            GameCode::FriendRequest.into_u32(),
+            friend_helper::friend_request,
+        );
+        self.cmd_map.insert(
+            GameCode::FriendReply.into_u32(),
+            friend_helper::friend_reply,
+        );
+        self.cmd_map.insert(
+            GameCode::FriendRemove.into_u32(),
+            friend_helper::friend_remove,
+        );
+        self.cmd_map.insert(
+            GameCode::FriendBlock.into_u32(),
+            friend_helper::friend_block,
+        );
+        self.cmd_map.insert(
+            GameCode::SyncPresence.into_u32(),
+            friend_helper::sync_presence,
+        );
+        self.cmd_map
+            .insert(GameCode::PartyInvite.into_u32(), party_helper::party_invite);
+        self.cmd_map
+            .insert(GameCode::PartyJoin.into_u32(), party_helper::party_join);
+        self.cmd_map
+            .insert(GameCode::PartyLeave.into_u32(), party_helper::party_leave);
+        self.cmd_map
+            .insert(GameCode::MailList.into_u32(), mail_helper::mail_list);
+        self.cmd_map
+            .insert(GameCode::MailRead.into_u32(), mail_helper::mail_read);
+        self.cmd_map
+            .insert(GameCode::MailClaim.into_u32(), mail_helper::mail_claim);
+        self.cmd_map
+            .insert(GameCode::MailDelete.into_u32(), mail_helper::mail_delete);
+        self.cmd_map
+            .insert(GameCode::QuestList.into_u32(), quest_helper::quest_list);
+        self.cmd_map
+            .insert(GameCode::QuestClaim.into_u32(), quest_helper::quest_claim);
+        self.cmd_map.insert(
+            GameCode::AchievementList.into_u32(),
+            achievement_helper::achievement_list,
+        );
+        self.cmd_map
+            .insert(GameCode::SendMail.into_u32(), mail_helper::receive_mail);
+        self.cmd_map.insert(
+            GameCode::TournamentSignUp.into_u32(),
+            tournament_helper::tournament_sign_up,
+        );
+        self.cmd_map.insert(
+            GameCode::TournamentMatch.into_u32(),
+            tournament_helper::tournament_match,
+        );
     }
 
     ///user结构体转proto
@@ -277,7 +418,7 @@ impl GameMgr {
         ppt.set_nick_name(nick_name.to_string());
         let last_character = user_info.last_character;
         ppt.set_last_character(last_character);
-        ppt.dlc.push(1);
+        ppt.dlc = user.entitlement.dlc_list();
         let punish_match_pt: PunishMatchPt = user_info.punish_match.into();
         ppt.set_punish_match(punish_match_pt);
         ppt.set_grade(user_info.grade);
@@ -309,7 +450,9 @@ impl GameMgr {
             season_pt.set_season_id(crate::SEASON.season_id as u32);
             season_pt.set_end_time(crate::SEASON.next_update_time * 1000);
             world_boss_pt.set_world_boss_id(crate::WORLD_BOSS.world_boss_id as u32);
-            world_boss_pt.set_end_time(crate::WORLD_BOSS.next_update_time * 1000)
+            world_boss_pt.set_end_time(crate::WORLD_BOSS.next_update_time * 1000);
+            world_boss_pt.set_hp(crate::WORLD_BOSS.hp);
+            world_boss_pt.set_max_hp(crate::WORLD_BOSS.max_hp);
         }
         lr.set_season_pt(season_pt);
         lr.set_world_boss_pt(world_boss_pt);
@@ -327,7 +470,7 @@ impl GameMgr {
 
         lr.last_logoff_time = time;
 
-        for cter in user.get_characters_ref().cter_map.values() {
+        for cter in user.get_usable_characters() {
             lr.cters.push(cter.clone().into())
         }
 
@@ -391,12 +534,18 @@ fn sync(gm: &mut GameMgr, packet: Packet) {
 
     if csd.player_pt.is_some() {
         let pp = csd.player_pt.unwrap();
-        let nick_name = user.get_user_info_mut_ref().get_nick_name();
-        if pp.get_nick_name() != nick_name {
-            user.get_user_info_mut_ref()
-                .set_nick_name(pp.get_nick_name());
+        let nick_name = user.get_user_info_ref().get_nick_name();
+        //改名统一走昵称校验
+        if !pp.get_nick_name().is_empty() && pp.get_nick_name() != nick_name {
+            let res = do_modify_nick_name(
+                gm,
+                user_id,
+                pp.get_nick_name(),
+                nick_name_helper::SOURCE_SYNC,
+            );
+            send_modify_nick_name_res(gm, user_id, res);
         }
-        user.get_user_info_mut_ref().set_dlc(pp.dlc);
+        //dlc只认服务器的权益数据,客户端同步过来的不要
     }
 
     let mut s_s_d = S_SYNC_DATA::new();
@@ -413,13 +562,85 @@ fn sync(gm: &mut GameMgr, packet: Packet) {
 ///玩家离线
 fn off_line(gm: &mut GameMgr, packet: Packet) {
     let user_id = packet.get_user_id();
+    party_helper::off_line(gm, user_id);
+    friend_helper::update_presence(gm, user_id, PresenceState::Offline);
     let user = gm.users.remove(&user_id);
+    gm.chat_times.remove(&user_id);
     if let Some(mut user_data) = user {
         user_data.update_off();
         info!("游戏服已处理玩家离线 for id:{}", user_id);
     }
 }
 
+///修改昵称
+fn modify_nick_name(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let mut cmnn = C_MODIFY_NICK_NAME::new();
+    let res = cmnn.merge_from_bytes(packet.get_data());
+    if let Err(e) = res {
+        error!("{:?}", e);
+        return;
+    }
+    let res = do_modify_nick_name(
+        gm,
+        user_id,
+        cmnn.get_nick_name(),
+        nick_name_helper::SOURCE_CLIENT,
+    );
+    send_modify_nick_name_res(gm, user_id, res);
+}
+
+///返回客户端改名结果
+fn send_modify_nick_name_res(gm: &mut GameMgr, user_id: u32, res: anyhow::Result<()>) {
+    let mut smnn = S_MODIFY_NICK_NAME::new();
+    match res {
+        Ok(_) => {
+            smnn.is_succ = true;
+        }
+        Err(e) => {
+            warn!("modify nick_name fail!user_id:{},err:{:?}", user_id, e);
+            smnn.is_succ = false;
+            smnn.err_mess = e.to_string();
+        }
+    }
+    gm.send_2_client(
+        ClientCode::NickNameModify,
+        user_id,
+        smnn.write_to_bytes().unwrap(),
+    );
+}
+
+///执行改名,依次校验格式,屏蔽词,冷却,唯一性
+pub fn do_modify_nick_name(
+    gm: &mut GameMgr,
+    user_id: u32,
+    nick_name: &str,
+    source: &str,
+) -> anyhow::Result<()> {
+    let user = gm.users.get_mut(&user_id);
+    if user.is_none() {
+        anyhow::bail!("user data is null for id:{}", user_id)
+    }
+    let user = user.unwrap();
+    let old_name = user.get_user_info_ref().get_nick_name().to_owned();
+    if old_name == nick_name {
+        return Ok(());
+    }
+    nick_name_helper::check_format(nick_name)?;
+    nick_name_helper::check_block_words(nick_name)?;
+    nick_name_helper::check_cd(user.get_user_info_ref().last_rename_time)?;
+    nick_name_helper::occupy(user_id, nick_name)?;
+    //索引key一样的说明只是改了大小写之类的,索引不用删
+    if nick_name_helper::index_key(old_name.as_str()) != nick_name_helper::index_key(nick_name) {
+        nick_name_helper::release(user_id, old_name.as_str());
+    }
+    user.get_user_info_mut_ref().rename(nick_name);
+    nick_name_helper::audit(user_id, old_name.as_str(), nick_name, source);
+    gm.push_nick_name(user_id, nick_name);
+    friend_helper::sync_nick_name(gm, user_id);
+    Ok(())
+}
+
 pub fn sync_rank_nick_name(gm: &mut GameMgr, packet: Packet) {
     let user_id = packet.get_user_id();
     let mut proto = G_S_MODIFY_NICK_NAME::new();
@@ -450,6 +671,73 @@ pub fn update_worldboss(_: &mut GameMgr, packet: Packet) {
     }
 }
 
+///封装世界boss信息
+fn build_show_world_boss() -> S_SHOW_WORLD_BOSS {
+    let mut sswb = S_SHOW_WORLD_BOSS::new();
+    unsafe {
+        sswb.set_world_boss_id(crate::WORLD_BOSS.world_boss_id as u32);
+        sswb.set_hp(crate::WORLD_BOSS.hp);
+        sswb.set_max_hp(crate::WORLD_BOSS.max_hp);
+        sswb.set_milestone(crate::WORLD_BOSS.milestone);
+        for rank in crate::WORLD_BOSS.ranks.iter() {
+            sswb.ranks.push(rank.clone());
+        }
+    }
+    sswb
+}
+
+///世界boss全服状态推送,排行榜服过来的
+pub fn world_boss_push(gm: &mut GameMgr, packet: Packet) {
+    let mut proto = R_G_WORLD_BOSS_PUSH::new();
+    let res = proto.merge_from_bytes(packet.get_data());
+    if let Err(err) = res {
+        error!("{:?}", err);
+        return;
+    }
+    unsafe {
+        crate::WORLD_BOSS.world_boss_id = proto.world_boss_id;
+        crate::WORLD_BOSS.hp = proto.hp;
+        crate::WORLD_BOSS.max_hp = proto.max_hp;
+        crate::WORLD_BOSS.milestone = proto.milestone;
+        crate::WORLD_BOSS.ranks = proto.take_ranks().into_vec();
+    }
+    //结算了,在线玩家直接收奖励邮件
+    if proto.is_settled {
+        let user_ids: Vec<u32> = gm.users.keys().copied().collect();
+        for user_id in user_ids {
+            let user_data = gm.users.get_mut(&user_id).unwrap();
+            let mails = world_boss_helper::claim_reward(user_data);
+            if !mails.is_empty() {
+                mail_helper::push_mail_notice(gm, user_id, mails);
+            }
+        }
+    }
+    if !proto.notice {
+        return;
+    }
+    //跨过血量节点或者结算了,推送给所有在线玩家
+    let bytes = build_show_world_boss().write_to_bytes();
+    if let Err(e) = bytes {
+        error!("{:?}", e);
+        return;
+    }
+    let bytes = bytes.unwrap();
+    let user_ids: Vec<u32> = gm.users.keys().copied().collect();
+    for user_id in user_ids {
+        gm.send_2_client(ClientCode::ShowWorldBoss, user_id, bytes.clone());
+    }
+}
+
+///请求世界boss信息
+fn show_world_boss(gm: &mut GameMgr, packet: Packet) {
+    let user_id = packet.get_user_id();
+    let bytes = build_show_world_boss().write_to_bytes();
+    match bytes {
+        Ok(bytes) => gm.send_2_client(ClientCode::ShowWorldBoss, user_id, bytes),
+        Err(e) => error!("{:?}", e),
+    }
+}
+
 ///房间战斗结算
 pub fn summary(gm: &mut GameMgr, packet: Packet) {
     let mut bgs = B_S_SUMMARY::new();
@@ -482,7 +770,8 @@ pub fn summary(gm: &mut GameMgr, packet: Packet) {
     if room_type.is_match_type() {
         //第一名就加grade
         user_data.user_info.set_grade(grade);
-        if room_type == RoomType::OneVOneVOneVOneMatch {
+        //排位模式更新段位
+        if room_type == RoomType::OneVOneVOneVOneMatch || room_type == RoomType::TwoVTwoMatch {
             bgs.cters.extend_from_slice(cters.as_slice());
             let league_pt = summary_data_pt.get_league();
             //更新段位积分
@@ -501,4 +790,8 @@ pub fn summary(gm: &mut GameMgr, packet: Packet) {
             }
         }
     }
+    //局外任务进度
+    quest_helper::on_summary(gm, &summary_data_pt);
+    //成就进度
+    achievement_helper::on_summary(gm, &summary_data_pt);
 }
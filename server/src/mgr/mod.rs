@@ -1,4 +1,5 @@
 pub mod game_mgr;
+pub mod party_mgr;
 pub mod timer_mgr;
 
 use num_enum::IntoPrimitive;
@@ -12,6 +13,8 @@ pub enum RoomType {
     OneVOneVOneVOneMatch = 2,  //1v1v1v1匹配房间
     WorldBossCustom = 3,       //世界boss自定义房间
     WorldBoseMatch = 4,        //世界boss匹配房间
+    TwoVTwoCustom = 5,         //2v2自定义房间
+    TwoVTwoMatch = 6,          //2v2匹配房间
 }
 
 impl RoomType {
@@ -26,6 +29,25 @@ impl RoomType {
     }
 
     pub fn is_match_type(self) -> bool {
-        self == RoomType::OneVOneVOneVOneMatch || self == RoomType::WorldBoseMatch
+        self == RoomType::OneVOneVOneVOneMatch
+            || self == RoomType::WorldBoseMatch
+            || self == RoomType::TwoVTwoMatch
+    }
+}
+
+///在线状态
+#[derive(Debug, Clone, Copy, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum PresenceState {
+    Offline = 1,  //离线
+    Online = 2,   //在线
+    InRoom = 3,   //房间中
+    InBattle = 4, //战斗中
+}
+
+impl PresenceState {
+    pub fn into_u32(self) -> u32 {
+        let res: u8 = self.into();
+        res as u32
     }
 }
@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+use tools::protos::base::PartyPt;
+
+///队伍人数上限,和房间人数一致
+pub const PARTY_MEMBER_MAX: usize = 4;
+///组队邀请有效时间(秒)
+pub const PARTY_INVITE_TTL: i64 = 60;
+
+///队伍
+#[derive(Debug, Clone, Default)]
+pub struct Party {
+    pub leader_id: u32,    //队长id,0代表队伍已经解散
+    pub members: Vec<u32>, //队员id,第一个是队长
+}
+
+impl Party {
+    pub fn into_pt(&self) -> PartyPt {
+        let mut pp = PartyPt::new();
+        pp.set_leader_id(self.leader_id);
+        if self.leader_id == 0 {
+            return pp;
+        }
+        for &member in self.members.iter() {
+            pp.members.push(member);
+        }
+        pp
+    }
+}
+
+///组队管理,只管本服在线的玩家
+#[derive(Debug, Default)]
+pub struct PartyMgr {
+    parties: HashMap<u32, Party>,             //key:队长id value:队伍
+    user_party: HashMap<u32, u32>,            //key:玩家id value:队长id
+    invites: HashMap<u32, HashMap<u32, i64>>, //key:被邀请的玩家id value:邀请他的队长id和邀请时间(秒)
+}
+
+impl PartyMgr {
+    ///玩家所在的队伍
+    pub fn get_party(&self, user_id: &u32) -> Option<&Party> {
+        let leader_id = self.user_party.get(user_id)?;
+        self.parties.get(leader_id)
+    }
+
+    ///邀请组队,没有队伍的话对方接受了才创建
+    pub fn invite(&mut self, user_id: u32, target_id: u32, now: i64) -> anyhow::Result<()> {
+        self.remove_expired_invites(now);
+        if let Some(party) = self.get_party(&user_id) {
+            if party.leader_id != user_id {
+                anyhow::bail!("only party leader can invite!user_id:{}", user_id)
+            }
+            if party.members.len() >= PARTY_MEMBER_MAX {
+                anyhow::bail!("party is full!leader_id:{}", user_id)
+            }
+        }
+        if self.user_party.contains_key(&target_id) {
+            anyhow::bail!("target is already in party!target_id:{}", target_id)
+        }
+        self.invites
+            .entry(target_id)
+            .or_insert_with(HashMap::new)
+            .insert(user_id, now);
+        Ok(())
+    }
+
+    ///接受邀请,返回加入之后的队伍
+    pub fn join(&mut self, user_id: u32, leader_id: u32, now: i64) -> anyhow::Result<Party> {
+        if self.user_party.contains_key(&user_id) {
+            anyhow::bail!("this player is already in party!user_id:{}", user_id)
+        }
+        let invite_time = match self.invites.get_mut(&user_id) {
+            Some(leaders) => leaders.remove(&leader_id),
+            None => None,
+        };
+        let invite_time = match invite_time {
+            Some(time) => time,
+            None => anyhow::bail!(
+                "there is no party invite!user_id:{},leader_id:{}",
+                user_id,
+                leader_id
+            ),
+        };
+        if now - invite_time > PARTY_INVITE_TTL {
+            anyhow::bail!(
+                "party invite is expired!user_id:{},leader_id:{}",
+                user_id,
+                leader_id
+            )
+        }
+        //邀请之后对方可能进了别人的队伍
+        if let Some(&id) = self.user_party.get(&leader_id) {
+            if id != leader_id {
+                anyhow::bail!("inviter is not party leader!leader_id:{}", leader_id)
+            }
+        }
+        if let Some(party) = self.parties.get(&leader_id) {
+            if party.members.len() >= PARTY_MEMBER_MAX {
+                anyhow::bail!("party is full!leader_id:{}", leader_id)
+            }
+        }
+        let party = self.parties.entry(leader_id).or_insert_with(|| Party {
+            leader_id,
+            members: vec![leader_id],
+        });
+        party.members.push(user_id);
+        let party = party.clone();
+        self.user_party.insert(leader_id, leader_id);
+        self.user_party.insert(user_id, leader_id);
+        //进了队伍,收到的别的邀请都作废
+        self.invites.remove(&leader_id);
+        self.invites.remove(&user_id);
+        Ok(party)
+    }
+
+    ///离开队伍,返回需要通知的剩余队伍,队长走了由下一个人接任,只剩一个人就解散(leader_id为0)
+    pub fn leave(&mut self, user_id: u32) -> Option<Party> {
+        let leader_id = self.user_party.remove(&user_id)?;
+        let mut party = self.parties.remove(&leader_id)?;
+        party.members.retain(|&id| id != user_id);
+        if party.members.len() <= 1 {
+            for id in party.members.iter() {
+                self.user_party.remove(id);
+            }
+            //队伍解散,之前发的邀请作废
+            self.remove_invites_from(leader_id);
+            party.leader_id = 0;
+            return Some(party);
+        }
+        party.leader_id = party.members[0];
+        if party.leader_id != leader_id {
+            //换了队长,老队长发的邀请作废
+            self.remove_invites_from(leader_id);
+        }
+        for &id in party.members.iter() {
+            self.user_party.insert(id, party.leader_id);
+        }
+        self.parties.insert(party.leader_id, party.clone());
+        Some(party)
+    }
+
+    ///玩家下线,清掉收到的和发出去的邀请并离开队伍
+    pub fn off_line(&mut self, user_id: u32) -> Option<Party> {
+        self.invites.remove(&user_id);
+        self.remove_invites_from(user_id);
+        self.leave(user_id)
+    }
+
+    ///清掉某个队长发出去的邀请
+    fn remove_invites_from(&mut self, leader_id: u32) {
+        for leaders in self.invites.values_mut() {
+            leaders.remove(&leader_id);
+        }
+        self.invites.retain(|_, leaders| !leaders.is_empty());
+    }
+
+    ///清掉过期的邀请
+    fn remove_expired_invites(&mut self, now: i64) {
+        for leaders in self.invites.values_mut() {
+            leaders.retain(|_, &mut time| now - time <= PARTY_INVITE_TTL);
+        }
+        self.invites.retain(|_, leaders| !leaders.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_and_leave() {
+        let mut pm = PartyMgr::default();
+        //没邀请不能进
+        assert!(pm.join(2, 1, 0).is_err());
+        pm.invite(1, 2, 0).unwrap();
+        pm.invite(1, 3, 0).unwrap();
+        let party = pm.join(2, 1, 1).unwrap();
+        assert_eq!(party.leader_id, 1);
+        assert_eq!(party.members, vec![1, 2]);
+        //邀请只能用一次,队员不能邀请
+        assert!(pm.join(2, 1, 1).is_err());
+        assert!(pm.invite(2, 4, 1).is_err());
+        assert!(pm.invite(1, 2, 1).is_err());
+        pm.join(3, 1, 1).unwrap();
+
+        //队员离开,队伍还在
+        let party = pm.leave(3).unwrap();
+        assert_eq!(party.members, vec![1, 2]);
+        assert!(pm.get_party(&3).is_none());
+        //再离开一个就解散
+        let party = pm.leave(2).unwrap();
+        assert_eq!(party.leader_id, 0);
+        assert!(pm.get_party(&1).is_none());
+        assert!(pm.leave(1).is_none());
+    }
+
+    #[test]
+    fn full_party() {
+        let mut pm = PartyMgr::default();
+        for id in 2..=PARTY_MEMBER_MAX as u32 + 1 {
+            pm.invite(1, id, 0).unwrap();
+        }
+        for id in 2..=PARTY_MEMBER_MAX as u32 {
+            pm.join(id, 1, 0).unwrap();
+        }
+        assert!(pm.invite(1, 10, 0).is_err());
+        //满了之后接受之前的邀请也进不来
+        assert!(pm.join(PARTY_MEMBER_MAX as u32 + 1, 1, 0).is_err());
+    }
+
+    #[test]
+    fn invite_expired() {
+        let mut pm = PartyMgr::default();
+        pm.invite(1, 2, 0).unwrap();
+        assert!(pm.join(2, 1, PARTY_INVITE_TTL + 1).is_err());
+        pm.invite(1, 2, 0).unwrap();
+        //别的邀请顺手清掉过期的
+        pm.invite(3, 4, PARTY_INVITE_TTL + 1).unwrap();
+        assert!(!pm.invites.contains_key(&2));
+    }
+
+    #[test]
+    fn invite_cleared() {
+        let mut pm = PartyMgr::default();
+        //进了队伍,别人的邀请作废
+        pm.invite(1, 2, 0).unwrap();
+        pm.invite(3, 2, 0).unwrap();
+        pm.join(2, 1, 0).unwrap();
+        assert!(pm.join(2, 3, 0).is_err());
+        assert!(!pm.invites.contains_key(&2));
+
+        //解散之后之前的邀请作废
+        pm.invite(1, 4, 0).unwrap();
+        pm.leave(2);
+        assert!(pm.join(4, 1, 0).is_err());
+
+        //换了队长,老队长的邀请作废
+        pm.invite(1, 2, 0).unwrap();
+        pm.join(2, 1, 0).unwrap();
+        pm.invite(1, 3, 0).unwrap();
+        pm.join(3, 1, 0).unwrap();
+        pm.invite(1, 4, 0).unwrap();
+        let party = pm.leave(1).unwrap();
+        assert_eq!(party.leader_id, 2);
+        assert!(pm.join(4, 1, 0).is_err());
+        assert_eq!(pm.get_party(&3).unwrap().leader_id, 2);
+
+        //下线了发出去的邀请也作废
+        pm.invite(2, 5, 0).unwrap();
+        pm.off_line(2);
+        assert!(pm.join(5, 2, 0).is_err());
+    }
+}
@@ -1,16 +1,79 @@
 use async_std::task::block_on;
-use chrono::{Local, Timelike};
+use chrono::{DateTime, FixedOffset};
 use log::{error, info};
 use std::time::Duration;
 use std::time::SystemTime;
+use tools::scheduler::{self, CronExpr, RedisJobStore, Scheduler};
 
-use crate::Lock;
+use crate::helper::quest_helper;
+use crate::{Lock, CONF_MAP, REDIS_INDEX_GAME_SEASON, REDIS_KEY_SCHEDULER, REDIS_POOL};
+
+///每日重置默认cron表达式,每天0点
+const DEFAULT_DAY_RESET_CRON: &str = "0 0 * * *";
+///每周重置默认cron表达式,每周一0点
+const DEFAULT_WEEK_RESET_CRON: &str = "0 0 * * 1";
+
+lazy_static! {
+    ///每日/每周重置配置
+    pub static ref RESET_SCHEDULE: ResetSchedule = ResetSchedule::init();
+}
+
+///每日/每周重置配置,时区和cron表达式都从配置文件读
+pub struct ResetSchedule {
+    pub tz: FixedOffset, //重置用的时区
+    pub day: CronExpr,   //每日重置
+    pub week: CronExpr,  //每周重置
+}
+
+impl ResetSchedule {
+    fn init() -> Self {
+        let tz = match scheduler::parse_timezone(CONF_MAP.get_str("timezone", "").as_str()) {
+            Ok(tz) => tz,
+            Err(e) => {
+                error!("{:?}", e);
+                scheduler::local_timezone()
+            }
+        };
+        ResetSchedule {
+            tz,
+            day: parse_cron("day_reset_cron", DEFAULT_DAY_RESET_CRON),
+            week: parse_cron("week_reset_cron", DEFAULT_WEEK_RESET_CRON),
+        }
+    }
+
+    ///配置时区的当前时间,跟重置相关的时间都用这个
+    pub fn now(&self) -> DateTime<FixedOffset> {
+        scheduler::now_in(&self.tz)
+    }
+
+    ///last(秒)之后是否过了每日重置点
+    pub fn need_day_reset(&self, last: i64, now: i64) -> bool {
+        scheduler::is_missed(&self.day, &self.tz, last, now)
+    }
+
+    ///last(秒)之后是否过了每周重置点
+    pub fn need_week_reset(&self, last: i64, now: i64) -> bool {
+        scheduler::is_missed(&self.week, &self.tz, last, now)
+    }
+}
+
+///配置的cron表达式有问题就用默认的
+fn parse_cron(key: &str, default: &'static str) -> CronExpr {
+    let expr = CONF_MAP.get_str(key, default);
+    match CronExpr::parse(expr.as_str()) {
+        Ok(expr) => expr,
+        Err(e) => {
+            error!("{} is invalid,use default!{:?}", key, e);
+            CronExpr::parse(default).unwrap()
+        }
+    }
+}
 
 ///初始化定时器任务函数
 pub fn init_timer(gm: Lock) {
     let time = SystemTime::now();
-    //每日零点任务
-    zero_day(gm.clone());
+    //每日/每周重置任务
+    reset_timer(gm.clone());
     //每5分钟保存玩家数据
     save_timer(gm.clone());
     info!(
@@ -19,40 +82,52 @@ pub fn init_timer(gm: Lock) {
     )
 }
 
-///每日零点执行的任务
-fn zero_day(gm: Lock) {
-    let mut next_time_tmp: i64 = 0;
-    //每天0点执行
-    let zero_day = move || loop {
-        let now_time = Local::now();
-        let next_time = now_time
-            .with_hour(23)
-            .unwrap()
-            .with_minute(59)
-            .unwrap()
-            .with_second(59)
-            .unwrap();
-        let res = next_time.timestamp() - now_time.timestamp();
-        if next_time_tmp == next_time.timestamp() {
-            std::thread::sleep(Duration::from_secs(2 as u64));
-            continue;
+///每日/每周重置任务,停服期间错过的启动时补执行
+/// 重置只处理在线玩家,离线玩家上线的时候按自己的上次重置时间补
+fn reset_timer(gm: Lock) {
+    let server_name = CONF_MAP.get_str("server_name", "game");
+    let store = RedisJobStore::new(
+        REDIS_POOL.clone(),
+        REDIS_INDEX_GAME_SEASON,
+        format!("{}_{}", REDIS_KEY_SCHEDULER, server_name),
+    );
+    let schedule = &*RESET_SCHEDULE;
+    let mut scheduler = Scheduler::new(schedule.tz, Box::new(store));
+    let jobs = vec![
+        ("day_reset", schedule.day.clone()),
+        ("week_reset", schedule.week.clone()),
+    ];
+    for (name, expr) in jobs {
+        let gm = gm.clone();
+        let res = scheduler.add_job(name, expr, true, move || reset_online_users(&gm, name));
+        if let Err(e) = res {
+            error!("{:?}", e);
         }
-        std::thread::sleep(Duration::from_secs(res as u64));
-        info!("开始执行0点任务");
-        let now_time = SystemTime::now();
-        let mut lock = block_on(gm.lock());
-        for u in lock.users.values_mut() {
-            u.day_reset();
+    }
+    scheduler.start("RESET_TIMER");
+}
+
+///在线玩家检查重置
+fn reset_online_users(gm: &Lock, name: &str) {
+    let now_time = SystemTime::now();
+    let mut lock = block_on(gm.lock());
+    let mut user_ids = Vec::new();
+    for (user_id, u) in lock.users.iter_mut() {
+        if u.check_reset() {
             u.update();
+            user_ids.push(*user_id);
         }
-        info!(
-            "零点重制完成！重制玩家数量:{},耗时{:?}ms",
-            lock.users.len(),
-            now_time.elapsed().unwrap().as_millis()
-        );
-        next_time_tmp = next_time.timestamp();
-    };
-    std::thread::spawn(zero_day);
+    }
+    //任务刷新了,推给在线玩家
+    for user_id in user_ids.iter() {
+        quest_helper::push_all_quests(&mut lock, *user_id);
+    }
+    info!(
+        "{}完成！重制玩家数量:{},耗时{:?}ms",
+        name,
+        user_ids.len(),
+        now_time.elapsed().unwrap().as_millis()
+    );
 }
 
 ///保存玩家数据的定时器任务函数
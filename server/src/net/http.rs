@@ -1,13 +1,37 @@
+use crate::entity::entitlement::{update_entitlement, EntitlementInfo};
 use crate::entity::save_player_http;
+use crate::helper::gm_helper;
 use crate::Lock;
+use async_std::task::block_on;
 use http_types::Error as HttpTypesError;
-use log::info;
+use log::{info, warn};
 use serde_json::json;
 use serde_json::value::Value as JsonValue;
 use std::collections::HashMap;
 use std::time::Duration;
+use tools::gm::{GmContext, GmLevel};
 use tools::http::HttpServerHandler;
 
+///校验配置里的gm_token,没配置的时候一律拒绝
+fn check_gm_token(params: &JsonValue) -> anyhow::Result<()> {
+    let token = params.get("token").and_then(|v| v.as_str()).unwrap_or("");
+    let gm_token = crate::CONF_MAP.get_str("gm_token", "");
+    if gm_token.is_empty() || token != gm_token {
+        anyhow::bail!("gm token is error!")
+    }
+    Ok(())
+}
+
+///校验支付校验服带过来的entitlement_token,没配置的时候一律拒绝
+fn check_entitlement_token(uri_params: &HashMap<String, String>) -> anyhow::Result<()> {
+    let token = uri_params.get("token").map(|t| t.as_str()).unwrap_or("");
+    let entitlement_token = crate::CONF_MAP.get_str("entitlement_token", "");
+    if entitlement_token.is_empty() || token != entitlement_token {
+        anyhow::bail!("entitlement token is error!")
+    }
+    Ok(())
+}
+
 ///保存玩家数据
 pub struct SavePlayerHttpHandler {
     gm: Lock,
@@ -67,3 +91,135 @@ impl HttpServerHandler for StopServerHttpHandler {
         Ok(value)
     }
 }
+
+///支付校验服推送的权益变更
+pub struct EntitlementHttpHandler {
+    gm: Lock,
+}
+
+impl EntitlementHttpHandler {
+    pub fn new(gm: Lock) -> Self {
+        EntitlementHttpHandler { gm }
+    }
+}
+
+impl HttpServerHandler for EntitlementHttpHandler {
+    fn get_path(&self) -> &str {
+        "/entitlement"
+    }
+
+    fn do_post(
+        &mut self,
+        _uri: String,
+        _uri_params: HashMap<String, String>,
+        _json_params: &[u8],
+    ) -> anyhow::Result<serde_json::Value> {
+        check_entitlement_token(&_uri_params)?;
+        let info: EntitlementInfo = serde_json::from_slice(_json_params)?;
+        let mut gm = block_on(self.gm.lock());
+        //过时的通知不用支付校验服重发,打个日志就行
+        if let Err(e) = update_entitlement(&mut gm, info) {
+            warn!("{:?}", e);
+        }
+        let value = json!({ "status":"OK" });
+        Ok(value)
+    }
+}
+
+///gm发放或收回权益,需要gm_token
+pub struct GmEntitlementHttpHandler {
+    gm: Lock,
+}
+
+impl GmEntitlementHttpHandler {
+    pub fn new(gm: Lock) -> Self {
+        GmEntitlementHttpHandler { gm }
+    }
+}
+
+impl HttpServerHandler for GmEntitlementHttpHandler {
+    fn get_path(&self) -> &str {
+        "/gm/entitlement"
+    }
+
+    fn do_post(
+        &mut self,
+        _uri: String,
+        _uri_params: HashMap<String, String>,
+        _json_params: &[u8],
+    ) -> anyhow::Result<serde_json::Value> {
+        let params: JsonValue = serde_json::from_slice(_json_params)?;
+        check_gm_token(&params)?;
+        let user_id = params.get("user_id").and_then(|v| v.as_u64());
+        let product_id = params.get("product_id").and_then(|v| v.as_str());
+        if user_id.is_none() || product_id.is_none() {
+            anyhow::bail!("user_id or product_id is None!")
+        }
+        let grant = params
+            .get("grant")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+        let info = EntitlementInfo::from_gm(
+            user_id.unwrap() as u32,
+            product_id.unwrap().to_owned(),
+            grant,
+        );
+        let mut gm = block_on(self.gm.lock());
+        update_entitlement(&mut gm, info)?;
+        let value = json!({ "status":"OK" });
+        Ok(value)
+    }
+}
+
+///gm命令,用配置里的gm_token校验,按运营权限执行
+pub struct GmCommandHttpHandler {
+    gm: Lock,
+}
+
+impl GmCommandHttpHandler {
+    pub fn new(gm: Lock) -> Self {
+        GmCommandHttpHandler { gm }
+    }
+}
+
+impl HttpServerHandler for GmCommandHttpHandler {
+    fn get_path(&self) -> &str {
+        "/gm/command"
+    }
+
+    fn do_post(
+        &mut self,
+        _uri: String,
+        _uri_params: HashMap<String, String>,
+        _json_params: &[u8],
+    ) -> anyhow::Result<serde_json::Value> {
+        let params: JsonValue = serde_json::from_slice(_json_params)?;
+        check_gm_token(&params)?;
+        let name = params.get("name").and_then(|v| v.as_str());
+        if name.is_none() {
+            anyhow::bail!("name is None!")
+        }
+        let target_id = params.get("user_id").and_then(|v| v.as_u64()).unwrap_or(0);
+        let mut args = Vec::new();
+        if let Some(array) = params.get("args").and_then(|v| v.as_array()) {
+            for arg in array {
+                match arg.as_str() {
+                    Some(arg) => args.push(arg.to_owned()),
+                    None => args.push(arg.to_string()),
+                }
+            }
+        }
+        let ctx = GmContext {
+            operator_id: 0,
+            level: GmLevel::Operator,
+            target_id: target_id as u32,
+        };
+        let mut gm = block_on(self.gm.lock());
+        let res = gm_helper::execute(&mut gm, &ctx, name.unwrap(), args.as_slice());
+        let value = match res {
+            Ok(result) => json!({ "status":"OK","result":result }),
+            Err(e) => json!({ "status":"FAIL","err_mess":e.to_string() }),
+        };
+        Ok(value)
+    }
+}
@@ -1,21 +1,32 @@
 use tools::net_message_io::{NetHandler, TransportWay};
-use tools::protos::server_protocol::G_S_MODIFY_NICK_NAME;
 
+use crate::entity::achievement::Achievements;
 use crate::entity::character::Characters;
+use crate::entity::entitlement::Entitlement;
 use crate::entity::grade_frame::GradeFrame;
+use crate::entity::mail::Mailbox;
+use crate::entity::quest::Quests;
 use crate::entity::soul::Soul;
 use crate::entity::user::{
-    insert_characters, insert_grade_frame, insert_soul, insert_user, UserData,
+    insert_achievements, insert_characters, insert_entitlement, insert_grade_frame, insert_mailbox,
+    insert_quests, insert_soul, insert_user, UserData,
 };
 use crate::entity::user_info::User;
+use crate::helper::achievement_helper;
+use crate::helper::friend_helper;
+use crate::helper::mail_helper;
+use crate::helper::nick_name_helper;
 use crate::helper::redis_helper::get_user_from_redis;
+use crate::helper::world_boss_helper;
 use crate::mgr::game_mgr::GameMgr;
+use crate::mgr::PresenceState;
 use crate::Lock;
 use async_trait::async_trait;
 use log::{error, info, warn};
 use protobuf::Message;
-use tools::cmd_code::{ClientCode, GameCode, RankCode, ServerCommonCode};
+use tools::cmd_code::{ClientCode, GameCode, ServerCommonCode};
 use tools::protos::protocol::C_USER_LOGIN;
+use tools::trace;
 use tools::util::packet::Packet;
 
 #[derive(Clone)]
@@ -113,6 +124,13 @@ async fn handler_mess_s(gm: Lock, packet: Packet) {
     if cmd != GameCode::Login.into_u32()
         && cmd != GameCode::UnloadUser.into_u32()
         && cmd != GameCode::SyncRank.into_u32()
+        && cmd != GameCode::FriendList.into_u32()
+        && cmd != GameCode::PartyLeave.into_u32()
+        && cmd != GameCode::MailList.into_u32()
+        && cmd != GameCode::MailClaim.into_u32()
+        && cmd != GameCode::MailDelete.into_u32()
+        && cmd != GameCode::QuestList.into_u32()
+        && cmd != GameCode::AchievementList.into_u32()
         && cmd != ServerCommonCode::ReloadTemps.into_u32()
         && packet.get_data().is_empty()
     {
@@ -134,6 +152,8 @@ async fn login(gm: Lock, packet: Packet) -> anyhow::Result<()> {
     //玩家id
     let user_id = packet.get_user_id();
     let mut gm_lock = gm.lock().await;
+    //登录回包和登录触发的通知都带上这个包的trace id
+    let _trace = trace::enter(&packet);
     let user_data = gm_lock.users.contains_key(&user_id);
     //走登录流程
     //如果内存没有数据，则从数据库里面找
@@ -156,6 +176,16 @@ async fn login(gm: Lock, packet: Packet) -> anyhow::Result<()> {
     user.update_login();
     //处理重制惩罚时间
     user.reset_punish_match();
+    //补上离线期间错过的每日/每周重置
+    user_data.check_reset();
+    //补发成就解锁的相框和头像
+    achievement_helper::login(user_data);
+    //收离线期间的全服邮件
+    mail_helper::login(user_data);
+    //领取离线期间结算的世界boss奖励
+    world_boss_helper::claim_reward(user_data);
+    //通知好友上线了
+    friend_helper::update_presence(&mut gm_lock, user_id, PresenceState::Online);
 
     //返回客户端
     let lr = gm_lock.user2proto(user_id);
@@ -194,21 +224,26 @@ fn check_nick_name(gm_lock: &mut async_std::sync::MutexGuard<GameMgr>, user_id:
         return;
     }
 
-    user.set_nick_name(redis_nick_name);
-    //通知排行榜服务器
-    let mut grnn = G_S_MODIFY_NICK_NAME::new();
-    grnn.set_nick_name(redis_nick_name.to_owned());
-    let bytes = grnn.write_to_bytes();
-    match bytes {
-        Ok(bytes) => {
-            info!("执行修改昵称函数!");
-            //通知其排行榜服
-            gm_lock.send_2_server(RankCode::ModifyNickName.into_u32(), user_id, bytes);
-        }
-        Err(e) => {
-            error!("{:?}", e);
+    //用户中心的昵称直接认,只同步一下唯一性索引,占用成功了才释放旧昵称
+    let old_name = user.nick_name.clone();
+    match nick_name_helper::occupy(user_id, redis_nick_name) {
+        Ok(_) => {
+            let old_key = nick_name_helper::index_key(old_name.as_str());
+            if old_key != nick_name_helper::index_key(redis_nick_name) {
+                nick_name_helper::release(user_id, old_name.as_str());
+            }
         }
+        Err(e) => warn!("{:?}", e),
     }
+    user.set_nick_name(redis_nick_name);
+    nick_name_helper::audit(
+        user_id,
+        old_name.as_str(),
+        redis_nick_name,
+        nick_name_helper::SOURCE_USER_CENTER,
+    );
+    //通知排行榜服和房间服
+    gm_lock.push_nick_name(user_id, redis_nick_name);
 }
 
 ///初始化玩家数据
@@ -233,6 +268,9 @@ fn init_user_data(user_id: u32) -> anyhow::Result<UserData> {
             anyhow::bail!(str)
         }
         let mut user = User::new(user_id, nick_name.unwrap().as_str().unwrap());
+        if let Err(e) = nick_name_helper::occupy(user_id, user.nick_name.as_str()) {
+            warn!("{:?}", e);
+        }
         //以下入库采用异步执行，以免造成io堵塞
         //玩家角色数据
         let c = Characters::new(user.user_id);
@@ -241,6 +279,14 @@ fn init_user_data(user_id: u32) -> anyhow::Result<UserData> {
         let grade_frame = GradeFrame::new(user.user_id);
         //灵魂头像
         let soul = Soul::new(user.user_id);
+        //平台权益
+        let entitlement = Entitlement::new(user.user_id);
+        //邮箱
+        let mailbox = Mailbox::new(user.user_id);
+        //局外任务
+        let quests = Quests::new(user.user_id);
+        //成就
+        let achievements = Achievements::new(user.user_id);
 
         //封装到userdata里
         ud = Some(UserData::new(
@@ -248,6 +294,10 @@ fn init_user_data(user_id: u32) -> anyhow::Result<UserData> {
             c.clone(),
             grade_frame.clone(),
             soul.clone(),
+            entitlement.clone(),
+            mailbox.clone(),
+            quests.clone(),
+            achievements.clone(),
         ));
 
         //异步持久化到db
@@ -255,6 +305,10 @@ fn init_user_data(user_id: u32) -> anyhow::Result<UserData> {
         async_std::task::spawn(insert_characters(c));
         async_std::task::spawn(insert_soul(soul));
         async_std::task::spawn(insert_grade_frame(grade_frame));
+        async_std::task::spawn(insert_entitlement(entitlement));
+        async_std::task::spawn(insert_mailbox(mailbox));
+        async_std::task::spawn(insert_quests(quests));
+        async_std::task::spawn(insert_achievements(achievements));
     }
     Ok(ud.unwrap())
 }
@@ -0,0 +1,29 @@
+//! 第三方接口mock服务器(谷歌,steam,微信),本地联调和集成测试用
+//!
+//! 用法: cargo run --example mock_api --features mock_api -- [端口,默认18080] [脚本路径,默认examples/mock_api/mock.json]
+//!
+//! 把各个服的第三方地址配置成 http://127.0.0.1:18080 即可:
+//! gg_check: google_oauth_url,token_url(加/token),androidpublisher_url,服务账号json里面的token_uri(加/token)
+//! gateserver: config.conf里面steam的url
+//! httpserver: wx_token_url,wx_api_url
+//!
+//! 路由和脚本格式见tools::mock_api
+use std::str::FromStr;
+
+pub fn main() {
+    tools::my_log::init_trace_log(false);
+    let args: Vec<String> = std::env::args().collect();
+    let port = match args.get(1) {
+        Some(port) => u16::from_str(port.as_str()).unwrap(),
+        None => 18080,
+    };
+    let script_path = match args.get(2) {
+        Some(path) => path.clone(),
+        None => "examples/mock_api/mock.json".to_owned(),
+    };
+    if let Err(e) = tools::mock_api::start(port, script_path.as_str()) {
+        log::error!("{:?}", e);
+        return;
+    }
+    std::thread::park();
+}
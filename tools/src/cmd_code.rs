@@ -28,6 +28,8 @@ pub enum GameCode {
     Login = 1003,
     //同步数据
     SyncData = 1004,
+    //修改昵称
+    ModifyNickName = 1005,
     //创建房间
     CreateRoom = 1006,
     //加入房间
@@ -50,6 +52,54 @@ pub enum GameCode {
     GetLastSeasonRank = 1015,
     //同步排行榜名字
     SyncRankNickName = 1016,
+    //请求世界boss信息
+    ShowWorldBoss = 1017,
+    //gm命令
+    GmCommand = 1018,
+    //聊天
+    Chat = 1019,
+    //举报聊天
+    ChatReport = 1020,
+    //好友列表
+    FriendList = 1021,
+    //申请加好友
+    FriendRequest = 1022,
+    //处理好友申请
+    FriendReply = 1023,
+    //删除好友
+    FriendRemove = 1024,
+    //拉黑
+    FriendBlock = 1025,
+    //邀请组队
+    PartyInvite = 1026,
+    //接受组队邀请
+    PartyJoin = 1027,
+    //离开队伍
+    PartyLeave = 1028,
+    //同步在线状态(房间服发过来)
+    SyncPresence = 1029,
+    //邮件列表
+    MailList = 1030,
+    //读邮件
+    MailRead = 1031,
+    //领取邮件附件
+    MailClaim = 1032,
+    //删除邮件
+    MailDelete = 1033,
+    //局外任务列表
+    QuestList = 1034,
+    //领取任务奖励
+    QuestClaim = 1035,
+    //成就列表
+    AchievementList = 1036,
+    //锦标赛报名
+    TournamentSignUp = 1037,
+    //中心服通知进锦标赛房间
+    TournamentMatch = 9995,
+    //中心服发邮件
+    SendMail = 9996,
+    //世界boss全服状态推送
+    WorldBossPush = 9997,
     //更新worldboss通知
     UpdateWorldBossPush = 9998,
     //卸载玩家数据
@@ -105,6 +155,16 @@ pub enum RoomCode {
     BattleKickMember = 20017,
     //选择ai角色
     ChoiceAI = 20018,
+    //修改昵称同步
+    ModifyNickName = 20019,
+    //屏蔽玩家聊天
+    ChatMute = 20020,
+    //禁选阶段禁用角色
+    DraftBan = 20021,
+    //禁选阶段选择角色
+    DraftPick = 20022,
+    //再来一局
+    Rematch = 20023,
     //--------------------------------------以下战斗相关---------------------------
     //战斗结算
     Summary = 21000,
@@ -112,6 +172,12 @@ pub enum RoomCode {
     UpdateSeasonPush = 22000,
     //更新worldboss通知
     UpdateWorldBossPush = 22001,
+    //游戏服转发的gm命令
+    GmCommand = 22002,
+    //游戏服校验过的聊天消息
+    Chat = 22003,
+    //进锦标赛房间
+    TournamentJoin = 22004,
     //返回最大值
     Max = 30000,
 }
@@ -134,6 +200,9 @@ pub enum BattleCode {
     Pos = 30005,              //架势请求
     Emoji = 30006,            //表情符号
     Buy = 30007,              //购买
+    Surrender = 30008,        //投降
+    VoteEnd = 30009,          //投票结束战斗
+    GmCommand = 39996,        //游戏服转发的gm命令
     UpdateSeasonPush = 39997, //更新赛季通知
     OffLine = 39998,          //掉线
     LeaveRoom = 39999,        //离开房间
@@ -152,9 +221,11 @@ impl BattleCode {
 #[repr(u32)]
 pub enum RankCode {
     Min = 40001,
-    UpdateRank = 40002,       //更新排行榜
-    ModifyNickName = 40003,   //修改名字
-    UpdateSeasonPush = 40004, //更新赛季通知
+    UpdateRank = 40002,          //更新排行榜
+    ModifyNickName = 40003,      //修改名字
+    UpdateSeasonPush = 40004,    //更新赛季通知
+    WorldBossDamage = 40005,     //世界boss伤害上报
+    UpdateWorldBossPush = 40006, //更新worldboss通知
     Max = 50000,
 }
 
@@ -170,8 +241,9 @@ impl RankCode {
 #[repr(u32)]
 pub enum GateCode {
     Min = 50001,
-    StopServer = 50002, //停服
-    KickPlayer = 50003, //T玩家下线
+    StopServer = 50002,      //停服
+    KickPlayer = 50003,      //T玩家下线
+    SyncOnlineUsers = 50004, //断线重连后同步在线玩家
     Max = 60000,
 }
 
@@ -182,6 +254,25 @@ impl GateCode {
     }
 }
 
+///中心服自己处理的命令号段枚举 60001-70000
+#[derive(Debug, Copy, Clone, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum CenterCode {
+    Min = 60001,
+    TournamentList = 60002,    //锦标赛列表(客户端请求指令)
+    TournamentSignUp = 60003,  //锦标赛报名(游戏服转发)
+    TournamentBracket = 60004, //锦标赛对阵图(客户端请求指令)
+    TournamentResult = 60005,  //锦标赛对局结果(房间服上报)
+    Max = 70000,
+}
+
+impl CenterCode {
+    pub fn into_u32(self) -> u32 {
+        let res: u32 = self.into();
+        res
+    }
+}
+
 ///客户端专属命令号段枚举 10001-20000
 #[derive(Debug, Copy, Clone, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
 #[repr(u32)]
@@ -257,6 +348,8 @@ pub enum ClientCode {
     MapRefreshNotice = 10040,
     //结算推送
     SummaryNotice = 10041,
+    //托管状态推送
+    AutoNotice = 10042,
     //-------------------------------------战斗结束---------------------------
     //取消匹配
     CancelSearch = 10060,
@@ -268,6 +361,32 @@ pub enum ClientCode {
     ModifyGradeFrameAndSoul = 10082,
     //获得上赛季排行榜返回
     GetLastSeasonRank = 10083,
+    //世界boss信息返回
+    ShowWorldBoss = 10084,
+    //gm命令返回
+    GmCommand = 10085,
+    //好友列表返回
+    FriendList = 10086,
+    //申请加好友返回
+    FriendRequest = 10087,
+    //收到好友申请推送
+    FriendRequestNotice = 10088,
+    //处理好友申请返回
+    FriendReply = 10089,
+    //好友变化推送
+    FriendNotice = 10090,
+    //删除好友返回
+    FriendRemove = 10091,
+    //拉黑返回
+    FriendBlock = 10092,
+    //邀请组队返回
+    PartyInvite = 10093,
+    //收到组队邀请推送
+    PartyInviteNotice = 10094,
+    //接受组队邀请返回
+    PartyJoin = 10095,
+    //队伍变化推送
+    PartyNotice = 10096,
     //任务推送
     MissionNoice = 10101,
     //购买推送
@@ -276,6 +395,58 @@ pub enum ClientCode {
     PunishMatchPush = 10103,
     //选择ai角色通知
     ChoiceAINotice = 10201,
+    //聊天返回
+    Chat = 10202,
+    //聊天推送
+    ChatNotice = 10203,
+    //聊天记录推送
+    ChatHistory = 10204,
+    //屏蔽玩家聊天返回
+    ChatMute = 10205,
+    //举报玩家聊天返回
+    ChatReport = 10206,
+    //邮件列表返回
+    MailList = 10207,
+    //读邮件返回
+    MailRead = 10208,
+    //领取邮件附件返回
+    MailClaim = 10209,
+    //删除邮件返回
+    MailDelete = 10210,
+    //新邮件推送
+    MailNotice = 10211,
+    //局外任务列表返回
+    QuestList = 10212,
+    //领取任务奖励返回
+    QuestClaim = 10213,
+    //任务进度变化推送
+    QuestNotice = 10214,
+    //成就列表返回
+    AchievementList = 10215,
+    //成就进度变化推送
+    AchievementNotice = 10216,
+    //禁用角色返回
+    DraftBan = 10217,
+    //禁选阶段选择角色返回
+    DraftPick = 10218,
+    //禁选阶段推送
+    DraftNotice = 10219,
+    //锦标赛列表返回
+    TournamentList = 10220,
+    //锦标赛报名返回
+    TournamentSignUp = 10221,
+    //锦标赛对阵图返回
+    TournamentBracket = 10222,
+    //锦标赛推送
+    TournamentNotice = 10223,
+    //投降返回
+    Surrender = 10224,
+    //投票结束战斗推送
+    VoteEndNotice = 10225,
+    //再来一局返回
+    Rematch = 10226,
+    //再来一局推送
+    RematchNotice = 10227,
     //最大命令号
     Max = 20000,
 }
@@ -0,0 +1,201 @@
+use crate::protos::protocol::S_GM_COMMAND;
+use log::warn;
+use num_enum::IntoPrimitive;
+use num_enum::TryFromPrimitive;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fmt::Write;
+use std::str::FromStr;
+
+///gm权限等级,等级高的可以执行等级低的命令
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum GmLevel {
+    ///没有权限
+    None = 0,
+    ///测试,可以改玩家自己和战斗内的数据
+    Qa = 1,
+    ///运营,可以改全服数据
+    Operator = 2,
+}
+
+impl Default for GmLevel {
+    fn default() -> Self {
+        GmLevel::None
+    }
+}
+
+impl GmLevel {
+    pub fn into_u32(self) -> u32 {
+        let res: u8 = self.into();
+        res as u32
+    }
+
+    ///从u32转,不认识的当没有权限
+    pub fn from_u32(value: u32) -> Self {
+        GmLevel::try_from(value as u8).unwrap_or(GmLevel::None)
+    }
+}
+
+///gm命令执行上下文
+#[derive(Debug, Clone, Default)]
+pub struct GmContext {
+    pub operator_id: u32, //执行者id,http过来的为0
+    pub level: GmLevel,   //执行者权限
+    pub target_id: u32,   //目标玩家id
+}
+
+impl GmContext {
+    ///测试权限只能改自己,运营才能指定别的玩家
+    pub fn check_target(&self) -> anyhow::Result<()> {
+        if self.level >= GmLevel::Operator || self.target_id == self.operator_id {
+            return Ok(());
+        }
+        anyhow::bail!(
+            "gm level is not enough to target other player!operator_id:{},target_id:{},level:{:?}",
+            self.operator_id,
+            self.target_id,
+            self.level
+        )
+    }
+}
+
+///gm命令函数,返回执行结果的描述
+pub type GmFn<T> = fn(&mut T, &GmContext, &[String]) -> anyhow::Result<String>;
+
+///gm命令
+pub struct GmCommand<T> {
+    pub level: GmLevel,      //需要的权限
+    pub usage: &'static str, //用法说明
+    pub func: GmFn<T>,       //函数指针
+}
+
+///gm命令注册表,每个服各自注册自己能执行的命令
+pub struct GmRegistry<T> {
+    cmds: BTreeMap<&'static str, GmCommand<T>>,
+}
+
+impl<T> Default for GmRegistry<T> {
+    fn default() -> Self {
+        GmRegistry {
+            cmds: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T> GmRegistry<T> {
+    ///注册命令
+    pub fn register(
+        &mut self,
+        name: &'static str,
+        level: GmLevel,
+        usage: &'static str,
+        func: GmFn<T>,
+    ) {
+        self.cmds.insert(name, GmCommand { level, usage, func });
+    }
+
+    ///是否有这个命令
+    pub fn contains(&self, name: &str) -> bool {
+        self.cmds.contains_key(name)
+    }
+
+    ///检查权限,通过了返回函数指针,由调用方执行
+    pub fn check(&self, name: &str, level: GmLevel) -> anyhow::Result<GmFn<T>> {
+        let cmd = self.cmds.get(name);
+        if cmd.is_none() {
+            anyhow::bail!("there is no gm command!name:{}", name)
+        }
+        let cmd = cmd.unwrap();
+        if level == GmLevel::None || level < cmd.level {
+            anyhow::bail!(
+                "gm level is not enough!name:{},need:{:?},level:{:?}",
+                name,
+                cmd.level,
+                level
+            )
+        }
+        Ok(cmd.func)
+    }
+
+    ///列出这个权限能执行的命令
+    pub fn help(&self, level: GmLevel) -> String {
+        let mut s = String::new();
+        for (name, cmd) in self.cmds.iter() {
+            if level < cmd.level {
+                continue;
+            }
+            let _ = writeln!(s, "{} {}", name, cmd.usage);
+        }
+        s
+    }
+}
+
+///解析第index个参数
+pub fn parse_arg<V: FromStr>(args: &[String], index: usize, name: &str) -> anyhow::Result<V> {
+    let arg = args.get(index);
+    if arg.is_none() {
+        anyhow::bail!("missing gm arg!index:{},name:{}", index, name)
+    }
+    let arg = arg.unwrap();
+    let res = V::from_str(arg.as_str());
+    match res {
+        Ok(v) => Ok(v),
+        Err(_) => anyhow::bail!("gm arg is error!name:{},value:{}", name, arg),
+    }
+}
+
+///执行结果封装成返回给客户端的proto
+pub fn build_result_proto(res: anyhow::Result<String>) -> S_GM_COMMAND {
+    let mut proto = S_GM_COMMAND::new();
+    match res {
+        Ok(result) => {
+            proto.set_is_succ(true);
+            proto.set_result(result);
+        }
+        Err(e) => {
+            warn!("gm command failed!{:?}", e);
+            proto.set_is_succ(false);
+            proto.set_err_mess(e.to_string());
+        }
+    }
+    proto
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn add(value: &mut i32, _ctx: &GmContext, args: &[String]) -> anyhow::Result<String> {
+        *value += parse_arg::<i32>(args, 0, "value")?;
+        Ok(value.to_string())
+    }
+
+    #[test]
+    fn check_gm_level() {
+        let mut registry: GmRegistry<i32> = GmRegistry::default();
+        registry.register("add", GmLevel::Operator, "<value>", add);
+        assert!(registry.check("add", GmLevel::Qa).is_err());
+        assert!(registry.check("none", GmLevel::Operator).is_err());
+        let f = registry.check("add", GmLevel::Operator).unwrap();
+        let mut value = 1;
+        let ctx = GmContext::default();
+        assert_eq!(f(&mut value, &ctx, &["2".to_owned()]).unwrap(), "3");
+        assert!(f(&mut value, &ctx, &["x".to_owned()]).is_err());
+        assert!(registry.help(GmLevel::Qa).is_empty());
+    }
+
+    #[test]
+    fn check_target() {
+        let mut ctx = GmContext {
+            operator_id: 1,
+            level: GmLevel::Qa,
+            target_id: 1,
+        };
+        assert!(ctx.check_target().is_ok());
+        ctx.target_id = 2;
+        assert!(ctx.check_target().is_err());
+        ctx.level = GmLevel::Operator;
+        assert!(ctx.check_target().is_ok());
+    }
+}
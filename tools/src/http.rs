@@ -9,8 +9,15 @@ use axum::{
 };
 use serde_json::json;
 
+///默认的响应Content-Type
+pub const DEFAULT_CONTENT_TYPE: &str = "text/html;application/json;charset=utf-8";
+
 pub trait HttpServerHandler: Send + Sync {
     fn get_path(&self) -> &str;
+    ///get请求响应的Content-Type,有特殊格式要求的自己重写
+    fn get_content_type(&self) -> &str {
+        DEFAULT_CONTENT_TYPE
+    }
     fn do_post(
         &mut self,
         _uri: String,
@@ -48,6 +55,7 @@ impl Builder {
         let handler_lock = handler.lock_blocking();
 
         let path = handler_lock.get_path().to_owned();
+        let content_type = HeaderValue::from_str(handler_lock.get_content_type()).unwrap();
 
         let handler_lock = handler.clone();
         let do_post_c = |uri: axum::http::Uri,
@@ -87,10 +95,7 @@ impl Builder {
         let handler_lock = handler.clone();
         let do_get_c = |uri: axum::http::Uri, Query(uri_params): Query<HashMap<String, String>>| async move {
             let mut headers = HeaderMap::new();
-            headers.insert(
-                "Content-Type",
-                HeaderValue::from_str("text/html;application/json;charset=utf-8").unwrap(),
-            );
+            headers.insert("Content-Type", content_type);
             headers.insert(
                 "Access-Control-Allow-Origin",
                 HeaderValue::from_str("*").unwrap(),
@@ -33,19 +33,25 @@ pub mod binary;
 pub mod cmd_code;
 pub mod conf;
 pub mod excel;
+pub mod gm;
 pub mod http;
 pub mod json;
 pub mod macros;
+pub mod metrics;
+#[cfg(any(test, feature = "mock_api"))]
+pub mod mock_api;
 pub mod my_log;
 pub mod net_message_io;
 pub mod protos;
 pub mod redis_pool;
+pub mod scheduler;
 pub mod rpc_client;
 pub mod rpc_server;
 pub mod tcp;
 pub mod tcp_tokio;
 pub mod templates;
 pub mod thread_pool;
+pub mod trace;
 pub mod util;
 pub mod ws;
 
@@ -0,0 +1,207 @@
+use super::*;
+use crate::http::HttpServerHandler;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::time::Duration;
+
+///全局指标注册表,每个进程一份
+pub static METRICS: Lazy<Mutex<MetricsRegistry>> =
+    Lazy::new(|| Mutex::new(MetricsRegistry::default()));
+
+///不按命令号区分的指标用这个
+pub const NO_CMD: u32 = 0;
+
+///直方图的桶上限(秒)
+const BUCKETS: [f64; 12] = [
+    0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5,
+];
+
+///直方图
+#[derive(Debug, Clone, Default)]
+pub struct Histogram {
+    pub buckets: [u64; 12], //每个桶的累计数量
+    pub sum: f64,           //总和
+    pub count: u64,         //总数
+}
+
+impl Histogram {
+    pub fn observe(&mut self, value: f64) {
+        for (index, le) in BUCKETS.iter().enumerate() {
+            if value <= *le {
+                self.buckets[index] += 1;
+            }
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+}
+
+///指标注册表,key为(指标名,命令号)
+#[derive(Debug, Default)]
+pub struct MetricsRegistry {
+    counters: BTreeMap<(&'static str, u32), u64>,
+    gauges: BTreeMap<(&'static str, u32), i64>,
+    histograms: BTreeMap<(&'static str, u32), Histogram>,
+}
+
+impl MetricsRegistry {
+    ///计数器加value
+    pub fn add_counter(&mut self, name: &'static str, cmd: u32, value: u64) {
+        *self.counters.entry((name, cmd)).or_insert(0) += value;
+    }
+
+    ///设置gauge
+    pub fn set_gauge(&mut self, name: &'static str, cmd: u32, value: i64) {
+        self.gauges.insert((name, cmd), value);
+    }
+
+    ///直方图记录一次耗时
+    pub fn observe(&mut self, name: &'static str, cmd: u32, dur: Duration) {
+        self.histograms
+            .entry((name, cmd))
+            .or_insert_with(Histogram::default)
+            .observe(dur.as_secs_f64());
+    }
+
+    ///输出prometheus文本格式
+    pub fn render(&self) -> String {
+        let mut s = String::new();
+        let mut last_name = "";
+        for ((name, cmd), value) in self.counters.iter() {
+            if *name != last_name {
+                let _ = writeln!(s, "# TYPE {} counter", name);
+                last_name = *name;
+            }
+            let _ = writeln!(s, "{}{} {}", name, labels(*cmd, None), value);
+        }
+        for ((name, cmd), value) in self.gauges.iter() {
+            if *name != last_name {
+                let _ = writeln!(s, "# TYPE {} gauge", name);
+                last_name = *name;
+            }
+            let _ = writeln!(s, "{}{} {}", name, labels(*cmd, None), value);
+        }
+        for ((name, cmd), h) in self.histograms.iter() {
+            if *name != last_name {
+                let _ = writeln!(s, "# TYPE {} histogram", name);
+                last_name = *name;
+            }
+            for (index, le) in BUCKETS.iter().enumerate() {
+                let le = le.to_string();
+                let _ = writeln!(
+                    s,
+                    "{}_bucket{} {}",
+                    name,
+                    labels(*cmd, Some(le.as_str())),
+                    h.buckets[index]
+                );
+            }
+            let _ = writeln!(
+                s,
+                "{}_bucket{} {}",
+                name,
+                labels(*cmd, Some("+Inf")),
+                h.count
+            );
+            let _ = writeln!(s, "{}_sum{} {}", name, labels(*cmd, None), h.sum);
+            let _ = writeln!(s, "{}_count{} {}", name, labels(*cmd, None), h.count);
+        }
+        s
+    }
+}
+
+///拼标签,命令号为0的时候不带cmd标签
+fn labels(cmd: u32, le: Option<&str>) -> String {
+    let mut v = Vec::new();
+    if cmd != NO_CMD {
+        v.push(format!("cmd=\"{}\"", cmd));
+    }
+    if let Some(le) = le {
+        v.push(format!("le=\"{}\"", le));
+    }
+    if v.is_empty() {
+        return String::new();
+    }
+    format!("{{{}}}", v.join(","))
+}
+
+///计数器加1
+pub fn inc_counter(name: &'static str, cmd: u32) {
+    METRICS.lock().unwrap().add_counter(name, cmd, 1);
+}
+
+///设置gauge
+pub fn set_gauge(name: &'static str, value: i64) {
+    METRICS.lock().unwrap().set_gauge(name, NO_CMD, value);
+}
+
+///直方图记录一次耗时
+pub fn observe(name: &'static str, cmd: u32, dur: Duration) {
+    METRICS.lock().unwrap().observe(name, cmd, dur);
+}
+
+///记录一次命令分发:包数量和处理耗时
+pub fn record_invok(cmd: u32, dur: Duration) {
+    let mut lock = METRICS.lock().unwrap();
+    lock.add_counter("packets_total", cmd, 1);
+    lock.observe("invok_duration_seconds", cmd, dur);
+}
+
+///输出prometheus文本格式
+pub fn render() -> String {
+    METRICS.lock().unwrap().render()
+}
+
+///prometheus拉取指标的http接口,拉取前先调用collect刷新gauge
+pub struct MetricsHttpHandler {
+    collect: Box<dyn FnMut() + Send + Sync>,
+}
+
+impl MetricsHttpHandler {
+    pub fn new(collect: impl FnMut() + Send + Sync + 'static) -> Self {
+        MetricsHttpHandler {
+            collect: Box::new(collect),
+        }
+    }
+}
+
+impl HttpServerHandler for MetricsHttpHandler {
+    fn get_path(&self) -> &str {
+        "/metrics"
+    }
+
+    ///prometheus文本格式
+    fn get_content_type(&self) -> &str {
+        "text/plain; version=0.0.4"
+    }
+
+    fn do_get(
+        &mut self,
+        _uri: String,
+        _uri_params: HashMap<String, String>,
+    ) -> anyhow::Result<String> {
+        (self.collect)();
+        Ok(render())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_prometheus_text() {
+        let mut registry = MetricsRegistry::default();
+        registry.add_counter("packets_total", 1003, 2);
+        registry.set_gauge("online_users", NO_CMD, 5);
+        registry.observe("invok_duration_seconds", 1003, Duration::from_millis(20));
+        let s = registry.render();
+        assert!(s.contains("# TYPE packets_total counter\n"));
+        assert!(s.contains("packets_total{cmd=\"1003\"} 2\n"));
+        assert!(s.contains("online_users 5\n"));
+        assert!(s.contains("invok_duration_seconds_bucket{cmd=\"1003\",le=\"0.01\"} 0\n"));
+        assert!(s.contains("invok_duration_seconds_bucket{cmd=\"1003\",le=\"0.025\"} 1\n"));
+        assert!(s.contains("invok_duration_seconds_count{cmd=\"1003\"} 1\n"));
+    }
+}
@@ -0,0 +1,247 @@
+//! 第三方接口mock服务器(谷歌,steam,微信),本地联调和客户端测试用
+//!
+//! 脚本每次请求都会重新读取,改完文件马上生效,格式:
+//! {"路由名":{"key":{"fail":true}}} 返回412模拟接口报错
+//! {"路由名":{"key":{"body":{...}}}} 返回指定内容
+//! key没有配置的时候找"*",都没有就返回默认的成功数据
+use super::*;
+use crate::http::{Builder, HttpServerHandler};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+///启动mock服务器,等端口能连上了再返回
+pub fn start(port: u16, script_path: &str) -> anyhow::Result<()> {
+    let mut builder = Builder::new();
+    for &(name, path) in ROUTES.iter() {
+        let handler = MockHandler {
+            name,
+            path,
+            script_path: script_path.to_owned(),
+        };
+        builder = builder.route(Box::new(handler));
+    }
+    builder.bind(port);
+    let addr = std::net::SocketAddr::from(([127, 0, 0, 1], port));
+    let start_time = Instant::now();
+    while std::net::TcpStream::connect_timeout(&addr, Duration::from_millis(100)).is_err() {
+        if start_time.elapsed() > Duration::from_secs(5) {
+            anyhow::bail!("mock api start fail!port:{}", port)
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    info!(
+        "mock api listening on 0.0.0.0:{},script:{}",
+        port, script_path
+    );
+    Ok(())
+}
+
+///路由名,路径
+const ROUTES: [(&str, &str); 9] = [
+    ("google_token", "/token"),
+    ("google_tokeninfo", "/tokeninfo"),
+    (
+        "google_product",
+        "/androidpublisher/v3/applications/:package/purchases/products/:product/tokens/:token",
+    ),
+    (
+        "google_subscription",
+        "/androidpublisher/v3/applications/:package/purchases/subscriptions/:product/tokens/:token",
+    ),
+    (
+        "google_voided",
+        "/androidpublisher/v3/applications/:package/purchases/voidedpurchases",
+    ),
+    ("steam_auth", "/ISteamUserAuth/AuthenticateUserTicket/v1/"),
+    ("steam_ownership", "/ISteamUser/CheckAppOwnership/v2/"),
+    ("wx_token", "/WXAPIService.asmx/GetAccessTokenS"),
+    ("wx_subscribe", "/cgi-bin/message/subscribe/send"),
+];
+
+struct MockHandler {
+    name: &'static str,
+    path: &'static str,
+    script_path: String,
+}
+
+impl MockHandler {
+    ///按key从脚本里面找配置的返回,返回None代表走默认数据
+    fn scripted(&self, keys: &[String]) -> anyhow::Result<Option<Value>> {
+        let content = match std::fs::read_to_string(self.script_path.as_str()) {
+            Ok(content) => content,
+            Err(_) => return Ok(None),
+        };
+        let script = Value::from_str(content.as_str())?;
+        let route = match script.get(self.name) {
+            Some(route) => route,
+            None => return Ok(None),
+        };
+        let mut res = None;
+        for key in keys.iter().map(|k| k.as_str()).chain(std::iter::once("*")) {
+            if let Some(v) = route.get(key) {
+                res = Some(v);
+                break;
+            }
+        }
+        let res = match res {
+            Some(res) => res,
+            None => return Ok(None),
+        };
+        if res.get("fail").and_then(|v| v.as_bool()).unwrap_or(false) {
+            anyhow::bail!("mock fail!route:{},keys:{:?}", self.name, keys);
+        }
+        Ok(res.get("body").cloned())
+    }
+
+    ///处理请求,返回内容
+    fn handle(
+        &self,
+        uri: &str,
+        params: &HashMap<String, String>,
+        body: &[u8],
+    ) -> anyhow::Result<Value> {
+        let now = chrono::Local::now().timestamp_millis();
+        let param = |key: &str| params.get(key).cloned().unwrap_or_default();
+        //路径参数最后一段是令牌,确认购买的时候带着:acknowledge后缀
+        let path = uri.split('?').next().unwrap_or("");
+        let last = path.rsplit('/').next().unwrap_or("");
+        let acknowledge = last.ends_with(":acknowledge");
+        let purchase_token = last.trim_end_matches(":acknowledge").to_owned();
+
+        let (keys, default) = match self.name {
+            "google_token" => {
+                let form: HashMap<String, String> =
+                    url::form_urlencoded::parse(body).into_owned().collect();
+                let code = form.get("code").cloned().unwrap_or_default();
+                let default = json!({
+                    "access_token": format!("mock_access_token_{}", now),
+                    "expires_in": 3599,
+                    "token_type": "Bearer",
+                    "id_token": format!("mock_id_token_{}", code),
+                });
+                (vec![code], default)
+            }
+            "google_tokeninfo" => {
+                let id_token = param("id_token");
+                let iat = now / 1000;
+                let default = json!({
+                    "iss": "https://accounts.google.com",
+                    "azp": "mock_client_id",
+                    "aud": "mock_client_id",
+                    "sub": format!("mock_{}", id_token),
+                    "at_hash": "mock_at_hash",
+                    "iat": iat.to_string(),
+                    "exp": (iat + 3600).to_string(),
+                    "alg": "RS256",
+                    "kid": "mock_kid",
+                    "typ": "JWT",
+                });
+                (vec![id_token], default)
+            }
+            "google_product" | "google_subscription" if acknowledge => {
+                //确认购买单独一个脚本名字,方便单独模拟确认失败
+                let handler = MockHandler {
+                    name: "google_acknowledge",
+                    path: self.path,
+                    script_path: self.script_path.clone(),
+                };
+                let res = handler.scripted(&[purchase_token])?;
+                return Ok(res.unwrap_or(json!({})));
+            }
+            "google_product" => {
+                let default = json!({
+                    "kind": "androidpublisher#productPurchase",
+                    "purchaseState": 0,
+                    "consumptionState": 0,
+                    "acknowledgementState": 0,
+                    "orderId": format!("GPA.mock-{}", purchase_token),
+                });
+                (vec![purchase_token], default)
+            }
+            "google_subscription" => {
+                let default = json!({
+                    "startTimeMillis": now.to_string(),
+                    "expiryTimeMillis": (now + 30 * 24 * 3600 * 1000).to_string(),
+                    "autoRenewing": true,
+                    "paymentState": 1,
+                    "acknowledgementState": 0,
+                    "orderId": format!("GPA.mock-{}", purchase_token),
+                });
+                (vec![purchase_token], default)
+            }
+            "google_voided" => {
+                //默认没有作废的购买,要模拟退款的话在脚本里面配置voidedPurchases
+                (vec![], json!({ "voidedPurchases": [] }))
+            }
+            "steam_auth" => {
+                let ticket = param("ticket");
+                let default = json!({
+                    "response": {
+                        "params": {
+                            "result": "OK",
+                            "steamid": "76561197960287930",
+                            "ownersteamid": "76561197960287930",
+                            "vacbanned": false,
+                            "publisherbanned": false,
+                        }
+                    }
+                });
+                (vec![ticket], default)
+            }
+            "steam_ownership" => {
+                let steam_id = param("steamid");
+                let app_id = param("appid");
+                let default = json!({
+                    "appownership": {
+                        "ownsapp": true,
+                        "permanent": true,
+                        "timestamp": chrono::Local::now().to_rfc3339(),
+                        "ownersteamid": steam_id.clone(),
+                        "result": "OK",
+                    }
+                });
+                (vec![format!("{}:{}", steam_id, app_id), steam_id], default)
+            }
+            "wx_token" => (vec![param("appId")], json!("mock_wx_access_token")),
+            "wx_subscribe" => {
+                let json = Value::from_str(std::str::from_utf8(body)?).unwrap_or(Value::Null);
+                let to_user = json.get("touser").and_then(|v| v.as_str()).unwrap_or("");
+                (vec![to_user.to_owned()], json!({"errcode":0,"errmsg":"ok"}))
+            }
+            _ => (vec![], json!({})),
+        };
+        let res = self.scripted(&keys)?.unwrap_or(default);
+        info!("mock {} {} -> {}", self.name, uri, res);
+        Ok(res)
+    }
+}
+
+impl HttpServerHandler for MockHandler {
+    fn get_path(&self) -> &str {
+        self.path
+    }
+
+    fn do_get(
+        &mut self,
+        uri: String,
+        uri_params: HashMap<String, String>,
+    ) -> anyhow::Result<String> {
+        let res = self.handle(uri.as_str(), &uri_params, &[])?;
+        //纯字符串的返回(比如微信的access_token)不要带引号
+        match res.as_str() {
+            Some(s) => Ok(s.to_owned()),
+            None => Ok(res.to_string()),
+        }
+    }
+
+    fn do_post(
+        &mut self,
+        uri: String,
+        uri_params: HashMap<String, String>,
+        json_params: &[u8],
+    ) -> anyhow::Result<Value> {
+        self.handle(uri.as_str(), &uri_params, json_params)
+    }
+}
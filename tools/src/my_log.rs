@@ -1,5 +1,6 @@
 use log::info;
 use std::time;
+use tracing_subscriber::EnvFilter;
 
 ///初始化日志
 ///传入日志配置文件（yaml）
@@ -11,3 +12,25 @@ pub fn init_log(config_path: &str) {
         log_time.elapsed().unwrap().as_millis()
     );
 }
+
+///初始化tracing日志,log宏打的日志也会转给tracing,带上当前span里的trace_id/user_id/room_id/battle_id
+///json为true的时候输出json格式,方便日志采集
+pub fn init_trace_log(json: bool) {
+    let log_time = time::SystemTime::now();
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let builder = tracing_subscriber::fmt().with_env_filter(filter);
+    if json {
+        builder
+            .json()
+            .with_current_span(true)
+            .with_span_list(false)
+            .init();
+    } else {
+        builder.init();
+    }
+    info!(
+        "tracing日志模块初始化完成！json:{},耗时:{}ms",
+        json,
+        log_time.elapsed().unwrap().as_millis()
+    );
+}
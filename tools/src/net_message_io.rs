@@ -1,54 +1,104 @@
 use async_std::task::block_on;
 use bincode::ErrorKind;
 use log::{error, info, warn};
-use message_io::network::{Endpoint, NetEvent, Transport};
+use message_io::network::{Endpoint, NetEvent, SendStatus, Transport};
 use message_io::node::{self, NodeHandler};
 
 use async_trait::async_trait;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::net::SocketAddr;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 
 use serde::{Deserialize, Serialize};
 
+use crate::tcp::{reconnect_delay, CLIENT_BUFFER_LIMIT};
+
 #[async_trait]
 pub trait MessageHandler {
     ///tcp client should not impl this func
     async fn try_clone(&self) -> Self;
 
-    ///this func just for tcp client
+    ///this func just for tcp client,reconnect with backoff when the connection was closed
     async fn connect(&mut self, transport: TransportWay, addr: &str) {
         let transport = match transport {
             TransportWay::Tcp => Transport::FramedTcp,
             TransportWay::Udp => Transport::Udp,
             TransportWay::Ws => Transport::Ws,
         };
-        let (handler, listener) = node::split::<()>();
-
-        let (server, _) = handler.network().connect(transport, addr).unwrap();
+        let mut is_reconnect = false;
+        let mut attempt = 0;
+        //写端在重连之间保持不变,断线期间的消息缓存在这里
+        let outbound = Arc::new(Mutex::new(Outbound::default()));
+        loop {
+            let (handler, listener) = node::split::<()>();
 
-        listener.for_each(move |event| match event.network() {
-            NetEvent::Connected(endpoint, ok) => match ok {
-                true => {
-                    let th = NetHandler::new(handler.clone(), server);
-                    block_on(self.on_open(th));
-                    info!("connect server({:?}) success!", endpoint.addr());
+            let res = handler.network().connect(transport, addr);
+            if let Err(e) = res {
+                let dur = reconnect_delay(attempt);
+                error!(
+                    "connect server({}) fail!{:?},retry after {}ms",
+                    addr,
+                    e,
+                    dur.as_millis()
+                );
+                std::thread::sleep(dur);
+                attempt += 1;
+                continue;
+            }
+            let (server, _) = res.unwrap();
+            //这一次连接是否成功过
+            let mut connected = false;
+            let this = &mut *self;
+            let connected_ref = &mut connected;
+            let outbound = outbound.clone();
+            listener.for_each(move |event| match event.network() {
+                NetEvent::Connected(endpoint, ok) => match ok {
+                    true => {
+                        *connected_ref = true;
+                        //先绑定连接,重连钩子里的注册消息直接发,别的消息进缓存,跑完再补发
+                        outbound.lock().unwrap().bind(handler.clone(), server);
+                        let th = NetHandler::with_outbound(outbound.clone(), false);
+                        block_on(this.on_open(th));
+                        if is_reconnect {
+                            let hook = NetHandler::with_outbound(outbound.clone(), true);
+                            block_on(this.on_reconnect(hook));
+                        }
+                        outbound.lock().unwrap().flush_buffer();
+                        info!("connect server({:?}) success!", endpoint.addr());
+                    }
+                    false => {
+                        warn!("connect server({:?}) fail!", endpoint.addr());
+                        handler.stop();
+                    }
+                },
+                NetEvent::Message(_endpoint, data) => {
+                    block_on(this.on_message(data));
                 }
-                false => {
-                    warn!("connect server({:?}) fail!", endpoint.addr());
+                NetEvent::Disconnected(endpoint) => {
+                    //断开了,之后的消息先缓存起来,等重连成功再补发
+                    outbound.lock().unwrap().unbind();
+                    block_on(this.on_close());
+                    info!("disconnect with server({:?})!", endpoint.addr());
+                    handler.stop();
                 }
-            },
-            NetEvent::Message(_endpoint, data) => {
-                block_on(self.on_message(data));
+                _ => {}
+            });
+            if connected {
+                is_reconnect = true;
+                attempt = 0;
             }
-            NetEvent::Disconnected(endpoint) => {
-                block_on(self.on_close());
-                info!("disconnect with server({:?})!", endpoint.addr());
-            }
-            _ => {}
-        });
+            let dur = reconnect_delay(attempt);
+            info!("reconnect server({}) after {}ms", addr, dur.as_millis());
+            std::thread::sleep(dur);
+            attempt += 1;
+        }
     }
 
+    ///called after on_open when the connection was rebuilt,used to register identity again,
+    ///mess sent by the given handler go out before the mess buffered while disconnected
+    async fn on_reconnect(&mut self, _net_handler: NetHandler) {}
+
     ///Triggered when there is a new client connection
     async fn on_open(&mut self, net_handler: NetHandler);
 
@@ -64,6 +114,8 @@ pub trait MessageHandler {
 pub struct NetHandler {
     pub node_handler: NodeHandler<()>,
     pub endpoint: Endpoint,
+    outbound: Option<Arc<Mutex<Outbound>>>, //客户端连接的写端,断线的时候缓存消息
+    hook: bool,                             //重连钩子用的,消息不进缓存
 }
 
 impl NetHandler {
@@ -71,15 +123,117 @@ impl NetHandler {
         NetHandler {
             node_handler: node_handler,
             endpoint: endpoint,
+            outbound: None,
+            hook: false,
+        }
+    }
+
+    ///客户端连接用,消息都经过写端,断线期间缓存起来
+    fn with_outbound(outbound: Arc<Mutex<Outbound>>, hook: bool) -> Self {
+        let (node_handler, endpoint) = outbound.lock().unwrap().conn.clone().unwrap();
+        NetHandler {
+            node_handler,
+            endpoint,
+            outbound: Some(outbound),
+            hook,
         }
     }
 
     pub fn send(&self, mess: &[u8]) {
+        if let Some(outbound) = self.outbound.as_ref() {
+            let mut outbound = outbound.lock().unwrap();
+            if self.hook {
+                outbound.write_hook(mess);
+            } else {
+                outbound.write(mess);
+            }
+            return;
+        }
         let endpoint = self.endpoint;
         self.node_handler.network().send(endpoint, mess);
     }
 }
 
+///客户端写端,断线期间的消息先缓存起来,重连成功之后补发
+#[derive(Default)]
+struct Outbound {
+    conn: Option<(NodeHandler<()>, Endpoint)>, //当前连接
+    buffer: VecDeque<Vec<u8>>,                 //断线期间缓存的消息
+    hold: bool,                                //刚连上还没补发缓存,这期间的消息都进缓存
+}
+
+impl Outbound {
+    ///绑定新的连接,缓存的消息等flush_buffer的时候再补发
+    fn bind(&mut self, node_handler: NodeHandler<()>, endpoint: Endpoint) {
+        self.conn = Some((node_handler, endpoint));
+        self.hold = true;
+    }
+
+    ///补发断线期间缓存的消息
+    fn flush_buffer(&mut self) {
+        self.hold = false;
+        if self.buffer.is_empty() {
+            return;
+        }
+        info!("重连成功,补发缓存的消息!size:{}", self.buffer.len());
+        while let Some(bytes) = self.buffer.pop_front() {
+            if !self.send_conn(bytes.as_slice()) {
+                self.buffer.push_front(bytes);
+                break;
+            }
+        }
+    }
+
+    ///解绑连接
+    fn unbind(&mut self) {
+        self.hold = false;
+        self.conn = None;
+    }
+
+    ///写重连钩子里的注册消息,还没补发缓存的时候插到缓存前面直接发
+    fn write_hook(&mut self, mess: &[u8]) {
+        if !self.hold {
+            self.write(mess);
+            return;
+        }
+        if !self.send_conn(mess) {
+            warn!("send hook mess failed!size:{}", mess.len());
+        }
+    }
+
+    ///写消息,没有连接、还没补发完缓存或者发送失败就缓存起来,保证顺序
+    fn write(&mut self, mess: &[u8]) {
+        if !self.hold && self.buffer.is_empty() && self.send_conn(mess) {
+            return;
+        }
+        if self.buffer.len() >= CLIENT_BUFFER_LIMIT {
+            self.buffer.pop_front();
+            warn!(
+                "net client buffer is full!drop the oldest mess!limit:{}",
+                CLIENT_BUFFER_LIMIT
+            );
+        }
+        self.buffer.push_back(mess.to_vec());
+    }
+
+    ///发到当前连接,连接不可用返回false
+    fn send_conn(&mut self, mess: &[u8]) -> bool {
+        let (node_handler, endpoint) = match self.conn.as_ref() {
+            Some(conn) => conn,
+            None => return false,
+        };
+        match node_handler.network().send(*endpoint, mess) {
+            SendStatus::Sent => true,
+            //包太大的重发也没用,直接丢掉
+            SendStatus::MaxPacketSizeExceeded => {
+                error!("mess is too large!size:{}", mess.len());
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub enum FromClientMessage {
     Ping,
@@ -29,6 +29,7 @@ pub struct RoomSettingPt {
     pub turn_limit_time: u32,
     pub season_is_open: bool,
     pub ai_level: u32,
+    pub turn_reserve_time: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -89,6 +90,21 @@ impl RoomSettingPt {
     pub fn set_ai_level(&mut self, v: u32) {
         self.ai_level = v;
     }
+
+    // uint32 turn_reserve_time = 4;
+
+
+    pub fn get_turn_reserve_time(&self) -> u32 {
+        self.turn_reserve_time
+    }
+    pub fn clear_turn_reserve_time(&mut self) {
+        self.turn_reserve_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_turn_reserve_time(&mut self, v: u32) {
+        self.turn_reserve_time = v;
+    }
 }
 
 impl ::protobuf::Message for RoomSettingPt {
@@ -121,6 +137,13 @@ impl ::protobuf::Message for RoomSettingPt {
                     let tmp = is.read_uint32()?;
                     self.ai_level = tmp;
                 },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.turn_reserve_time = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -142,6 +165,9 @@ impl ::protobuf::Message for RoomSettingPt {
         if self.ai_level != 0 {
             my_size += ::protobuf::rt::value_size(3, self.ai_level, ::protobuf::wire_format::WireTypeVarint);
         }
+        if self.turn_reserve_time != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.turn_reserve_time, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -157,6 +183,9 @@ impl ::protobuf::Message for RoomSettingPt {
         if self.ai_level != 0 {
             os.write_uint32(3, self.ai_level)?;
         }
+        if self.turn_reserve_time != 0 {
+            os.write_uint32(4, self.turn_reserve_time)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -210,6 +239,11 @@ impl ::protobuf::Message for RoomSettingPt {
                 |m: &RoomSettingPt| { &m.ai_level },
                 |m: &mut RoomSettingPt| { &mut m.ai_level },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "turn_reserve_time",
+                |m: &RoomSettingPt| { &m.turn_reserve_time },
+                |m: &mut RoomSettingPt| { &mut m.turn_reserve_time },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<RoomSettingPt>(
                 "RoomSettingPt",
                 fields,
@@ -229,6 +263,7 @@ impl ::protobuf::Clear for RoomSettingPt {
         self.turn_limit_time = 0;
         self.season_is_open = false;
         self.ai_level = 0;
+        self.turn_reserve_time = 0;
         self.unknown_fields.clear();
     }
 }
@@ -957,74 +992,101 @@ impl ::protobuf::reflect::ProtobufValue for SeasonPt {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct WorldBossPt {
+pub struct WorldBossRankPt {
     // message fields
-    pub world_boss_id: u32,
-    pub start_time: u64,
-    pub end_time: u64,
+    pub user_id: u32,
+    pub name: ::std::string::String,
+    pub damage: u64,
+    pub rank: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a WorldBossPt {
-    fn default() -> &'a WorldBossPt {
-        <WorldBossPt as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a WorldBossRankPt {
+    fn default() -> &'a WorldBossRankPt {
+        <WorldBossRankPt as ::protobuf::Message>::default_instance()
     }
 }
 
-impl WorldBossPt {
-    pub fn new() -> WorldBossPt {
+impl WorldBossRankPt {
+    pub fn new() -> WorldBossRankPt {
         ::std::default::Default::default()
     }
 
-    // uint32 world_boss_id = 1;
+    // uint32 user_id = 1;
 
 
-    pub fn get_world_boss_id(&self) -> u32 {
-        self.world_boss_id
+    pub fn get_user_id(&self) -> u32 {
+        self.user_id
     }
-    pub fn clear_world_boss_id(&mut self) {
-        self.world_boss_id = 0;
+    pub fn clear_user_id(&mut self) {
+        self.user_id = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_world_boss_id(&mut self, v: u32) {
-        self.world_boss_id = v;
+    pub fn set_user_id(&mut self, v: u32) {
+        self.user_id = v;
     }
 
-    // uint64 start_time = 2;
+    // string name = 2;
 
 
-    pub fn get_start_time(&self) -> u64 {
-        self.start_time
+    pub fn get_name(&self) -> &str {
+        &self.name
     }
-    pub fn clear_start_time(&mut self) {
-        self.start_time = 0;
+    pub fn clear_name(&mut self) {
+        self.name.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_start_time(&mut self, v: u64) {
-        self.start_time = v;
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
     }
 
-    // uint64 end_time = 3;
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
 
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
 
-    pub fn get_end_time(&self) -> u64 {
-        self.end_time
+    // uint64 damage = 3;
+
+
+    pub fn get_damage(&self) -> u64 {
+        self.damage
     }
-    pub fn clear_end_time(&mut self) {
-        self.end_time = 0;
+    pub fn clear_damage(&mut self) {
+        self.damage = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_end_time(&mut self, v: u64) {
-        self.end_time = v;
+    pub fn set_damage(&mut self, v: u64) {
+        self.damage = v;
+    }
+
+    // uint32 rank = 4;
+
+
+    pub fn get_rank(&self) -> u32 {
+        self.rank
+    }
+    pub fn clear_rank(&mut self) {
+        self.rank = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rank(&mut self, v: u32) {
+        self.rank = v;
     }
 }
 
-impl ::protobuf::Message for WorldBossPt {
+impl ::protobuf::Message for WorldBossRankPt {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1038,21 +1100,24 @@ impl ::protobuf::Message for WorldBossPt {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint32()?;
-                    self.world_boss_id = tmp;
+                    self.user_id = tmp;
                 },
                 2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.start_time = tmp;
+                    self.damage = tmp;
                 },
-                3 => {
+                4 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_uint64()?;
-                    self.end_time = tmp;
+                    let tmp = is.read_uint32()?;
+                    self.rank = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1066,14 +1131,17 @@ impl ::protobuf::Message for WorldBossPt {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.world_boss_id != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.world_boss_id, ::protobuf::wire_format::WireTypeVarint);
+        if self.user_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.user_id, ::protobuf::wire_format::WireTypeVarint);
         }
-        if self.start_time != 0 {
-            my_size += ::protobuf::rt::value_size(2, self.start_time, ::protobuf::wire_format::WireTypeVarint);
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
         }
-        if self.end_time != 0 {
-            my_size += ::protobuf::rt::value_size(3, self.end_time, ::protobuf::wire_format::WireTypeVarint);
+        if self.damage != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.damage, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.rank != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.rank, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1081,14 +1149,17 @@ impl ::protobuf::Message for WorldBossPt {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if self.world_boss_id != 0 {
-            os.write_uint32(1, self.world_boss_id)?;
+        if self.user_id != 0 {
+            os.write_uint32(1, self.user_id)?;
         }
-        if self.start_time != 0 {
-            os.write_uint64(2, self.start_time)?;
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
         }
-        if self.end_time != 0 {
-            os.write_uint64(3, self.end_time)?;
+        if self.damage != 0 {
+            os.write_uint64(3, self.damage)?;
+        }
+        if self.rank != 0 {
+            os.write_uint32(4, self.rank)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1120,8 +1191,8 @@ impl ::protobuf::Message for WorldBossPt {
         Self::descriptor_static()
     }
 
-    fn new() -> WorldBossPt {
-        WorldBossPt::new()
+    fn new() -> WorldBossRankPt {
+        WorldBossRankPt::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1129,326 +1200,3807 @@ impl ::protobuf::Message for WorldBossPt {
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                "world_boss_id",
-                |m: &WorldBossPt| { &m.world_boss_id },
-                |m: &mut WorldBossPt| { &mut m.world_boss_id },
+                "user_id",
+                |m: &WorldBossRankPt| { &m.user_id },
+                |m: &mut WorldBossRankPt| { &mut m.user_id },
             ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                "start_time",
-                |m: &WorldBossPt| { &m.start_time },
-                |m: &mut WorldBossPt| { &mut m.start_time },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &WorldBossRankPt| { &m.name },
+                |m: &mut WorldBossRankPt| { &mut m.name },
             ));
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                "end_time",
-                |m: &WorldBossPt| { &m.end_time },
-                |m: &mut WorldBossPt| { &mut m.end_time },
+                "damage",
+                |m: &WorldBossRankPt| { &m.damage },
+                |m: &mut WorldBossRankPt| { &mut m.damage },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorldBossPt>(
-                "WorldBossPt",
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "rank",
+                |m: &WorldBossRankPt| { &m.rank },
+                |m: &mut WorldBossRankPt| { &mut m.rank },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorldBossRankPt>(
+                "WorldBossRankPt",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static WorldBossPt {
-        static instance: ::protobuf::rt::LazyV2<WorldBossPt> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(WorldBossPt::new)
+    fn default_instance() -> &'static WorldBossRankPt {
+        static instance: ::protobuf::rt::LazyV2<WorldBossRankPt> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WorldBossRankPt::new)
     }
 }
 
-impl ::protobuf::Clear for WorldBossPt {
+impl ::protobuf::Clear for WorldBossRankPt {
     fn clear(&mut self) {
-        self.world_boss_id = 0;
-        self.start_time = 0;
-        self.end_time = 0;
+        self.user_id = 0;
+        self.name.clear();
+        self.damage = 0;
+        self.rank = 0;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for WorldBossPt {
+impl ::std::fmt::Debug for WorldBossRankPt {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for WorldBossPt {
+impl ::protobuf::reflect::ProtobufValue for WorldBossRankPt {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct MemberPt {
+pub struct WorldBossPt {
     // message fields
-    pub user_id: u32,
-    pub nick_name: ::std::string::String,
-    pub cter: ::protobuf::SingularPtrField<CharacterPt>,
-    pub state: u32,
-    pub grade: u32,
-    pub grade_frame: u32,
-    pub soul: u32,
-    pub team_id: u32,
-    pub join_time: u64,
-    pub league: ::protobuf::SingularPtrField<LeaguePt>,
-    pub robot_temp_id: u32,
-    pub index: u32,
+    pub world_boss_id: u32,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub hp: u64,
+    pub max_hp: u64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a MemberPt {
-    fn default() -> &'a MemberPt {
-        <MemberPt as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a WorldBossPt {
+    fn default() -> &'a WorldBossPt {
+        <WorldBossPt as ::protobuf::Message>::default_instance()
     }
 }
 
-impl MemberPt {
-    pub fn new() -> MemberPt {
+impl WorldBossPt {
+    pub fn new() -> WorldBossPt {
         ::std::default::Default::default()
     }
 
-    // uint32 user_id = 1;
+    // uint32 world_boss_id = 1;
 
 
-    pub fn get_user_id(&self) -> u32 {
-        self.user_id
+    pub fn get_world_boss_id(&self) -> u32 {
+        self.world_boss_id
     }
-    pub fn clear_user_id(&mut self) {
-        self.user_id = 0;
+    pub fn clear_world_boss_id(&mut self) {
+        self.world_boss_id = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_user_id(&mut self, v: u32) {
-        self.user_id = v;
+    pub fn set_world_boss_id(&mut self, v: u32) {
+        self.world_boss_id = v;
     }
 
-    // string nick_name = 2;
+    // uint64 start_time = 2;
 
 
-    pub fn get_nick_name(&self) -> &str {
-        &self.nick_name
+    pub fn get_start_time(&self) -> u64 {
+        self.start_time
     }
-    pub fn clear_nick_name(&mut self) {
-        self.nick_name.clear();
+    pub fn clear_start_time(&mut self) {
+        self.start_time = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_nick_name(&mut self, v: ::std::string::String) {
-        self.nick_name = v;
+    pub fn set_start_time(&mut self, v: u64) {
+        self.start_time = v;
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_nick_name(&mut self) -> &mut ::std::string::String {
-        &mut self.nick_name
+    // uint64 end_time = 3;
+
+
+    pub fn get_end_time(&self) -> u64 {
+        self.end_time
+    }
+    pub fn clear_end_time(&mut self) {
+        self.end_time = 0;
     }
 
-    // Take field
-    pub fn take_nick_name(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.nick_name, ::std::string::String::new())
+    // Param is passed by value, moved
+    pub fn set_end_time(&mut self, v: u64) {
+        self.end_time = v;
     }
 
-    // .protos.CharacterPt cter = 3;
+    // uint64 hp = 4;
 
 
-    pub fn get_cter(&self) -> &CharacterPt {
-        self.cter.as_ref().unwrap_or_else(|| <CharacterPt as ::protobuf::Message>::default_instance())
+    pub fn get_hp(&self) -> u64 {
+        self.hp
     }
-    pub fn clear_cter(&mut self) {
-        self.cter.clear();
+    pub fn clear_hp(&mut self) {
+        self.hp = 0;
     }
 
-    pub fn has_cter(&self) -> bool {
-        self.cter.is_some()
+    // Param is passed by value, moved
+    pub fn set_hp(&mut self, v: u64) {
+        self.hp = v;
+    }
+
+    // uint64 max_hp = 5;
+
+
+    pub fn get_max_hp(&self) -> u64 {
+        self.max_hp
+    }
+    pub fn clear_max_hp(&mut self) {
+        self.max_hp = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_cter(&mut self, v: CharacterPt) {
-        self.cter = ::protobuf::SingularPtrField::some(v);
+    pub fn set_max_hp(&mut self, v: u64) {
+        self.max_hp = v;
     }
+}
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_cter(&mut self) -> &mut CharacterPt {
-        if self.cter.is_none() {
+impl ::protobuf::Message for WorldBossPt {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.world_boss_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start_time = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.end_time = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.hp = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.max_hp = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.world_boss_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.world_boss_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.start_time != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.start_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end_time != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.end_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.hp != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.hp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.max_hp != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.max_hp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.world_boss_id != 0 {
+            os.write_uint32(1, self.world_boss_id)?;
+        }
+        if self.start_time != 0 {
+            os.write_uint64(2, self.start_time)?;
+        }
+        if self.end_time != 0 {
+            os.write_uint64(3, self.end_time)?;
+        }
+        if self.hp != 0 {
+            os.write_uint64(4, self.hp)?;
+        }
+        if self.max_hp != 0 {
+            os.write_uint64(5, self.max_hp)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorldBossPt {
+        WorldBossPt::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "world_boss_id",
+                |m: &WorldBossPt| { &m.world_boss_id },
+                |m: &mut WorldBossPt| { &mut m.world_boss_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "start_time",
+                |m: &WorldBossPt| { &m.start_time },
+                |m: &mut WorldBossPt| { &mut m.start_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "end_time",
+                |m: &WorldBossPt| { &m.end_time },
+                |m: &mut WorldBossPt| { &mut m.end_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "hp",
+                |m: &WorldBossPt| { &m.hp },
+                |m: &mut WorldBossPt| { &mut m.hp },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "max_hp",
+                |m: &WorldBossPt| { &m.max_hp },
+                |m: &mut WorldBossPt| { &mut m.max_hp },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorldBossPt>(
+                "WorldBossPt",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WorldBossPt {
+        static instance: ::protobuf::rt::LazyV2<WorldBossPt> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WorldBossPt::new)
+    }
+}
+
+impl ::protobuf::Clear for WorldBossPt {
+    fn clear(&mut self) {
+        self.world_boss_id = 0;
+        self.start_time = 0;
+        self.end_time = 0;
+        self.hp = 0;
+        self.max_hp = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorldBossPt {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorldBossPt {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MemberPt {
+    // message fields
+    pub user_id: u32,
+    pub nick_name: ::std::string::String,
+    pub cter: ::protobuf::SingularPtrField<CharacterPt>,
+    pub state: u32,
+    pub grade: u32,
+    pub grade_frame: u32,
+    pub soul: u32,
+    pub team_id: u32,
+    pub join_time: u64,
+    pub league: ::protobuf::SingularPtrField<LeaguePt>,
+    pub robot_temp_id: u32,
+    pub index: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MemberPt {
+    fn default() -> &'a MemberPt {
+        <MemberPt as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MemberPt {
+    pub fn new() -> MemberPt {
+        ::std::default::Default::default()
+    }
+
+    // uint32 user_id = 1;
+
+
+    pub fn get_user_id(&self) -> u32 {
+        self.user_id
+    }
+    pub fn clear_user_id(&mut self) {
+        self.user_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: u32) {
+        self.user_id = v;
+    }
+
+    // string nick_name = 2;
+
+
+    pub fn get_nick_name(&self) -> &str {
+        &self.nick_name
+    }
+    pub fn clear_nick_name(&mut self) {
+        self.nick_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nick_name(&mut self, v: ::std::string::String) {
+        self.nick_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nick_name(&mut self) -> &mut ::std::string::String {
+        &mut self.nick_name
+    }
+
+    // Take field
+    pub fn take_nick_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.nick_name, ::std::string::String::new())
+    }
+
+    // .protos.CharacterPt cter = 3;
+
+
+    pub fn get_cter(&self) -> &CharacterPt {
+        self.cter.as_ref().unwrap_or_else(|| <CharacterPt as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_cter(&mut self) {
+        self.cter.clear();
+    }
+
+    pub fn has_cter(&self) -> bool {
+        self.cter.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cter(&mut self, v: CharacterPt) {
+        self.cter = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cter(&mut self) -> &mut CharacterPt {
+        if self.cter.is_none() {
             self.cter.set_default();
         }
-        self.cter.as_mut().unwrap()
+        self.cter.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_cter(&mut self) -> CharacterPt {
+        self.cter.take().unwrap_or_else(|| CharacterPt::new())
+    }
+
+    // uint32 state = 4;
+
+
+    pub fn get_state(&self) -> u32 {
+        self.state
+    }
+    pub fn clear_state(&mut self) {
+        self.state = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: u32) {
+        self.state = v;
+    }
+
+    // uint32 grade = 5;
+
+
+    pub fn get_grade(&self) -> u32 {
+        self.grade
+    }
+    pub fn clear_grade(&mut self) {
+        self.grade = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grade(&mut self, v: u32) {
+        self.grade = v;
+    }
+
+    // uint32 grade_frame = 6;
+
+
+    pub fn get_grade_frame(&self) -> u32 {
+        self.grade_frame
+    }
+    pub fn clear_grade_frame(&mut self) {
+        self.grade_frame = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grade_frame(&mut self, v: u32) {
+        self.grade_frame = v;
+    }
+
+    // uint32 soul = 7;
+
+
+    pub fn get_soul(&self) -> u32 {
+        self.soul
+    }
+    pub fn clear_soul(&mut self) {
+        self.soul = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_soul(&mut self, v: u32) {
+        self.soul = v;
+    }
+
+    // uint32 team_id = 8;
+
+
+    pub fn get_team_id(&self) -> u32 {
+        self.team_id
+    }
+    pub fn clear_team_id(&mut self) {
+        self.team_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_team_id(&mut self, v: u32) {
+        self.team_id = v;
+    }
+
+    // uint64 join_time = 9;
+
+
+    pub fn get_join_time(&self) -> u64 {
+        self.join_time
+    }
+    pub fn clear_join_time(&mut self) {
+        self.join_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_join_time(&mut self, v: u64) {
+        self.join_time = v;
+    }
+
+    // .protos.LeaguePt league = 10;
+
+
+    pub fn get_league(&self) -> &LeaguePt {
+        self.league.as_ref().unwrap_or_else(|| <LeaguePt as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_league(&mut self) {
+        self.league.clear();
+    }
+
+    pub fn has_league(&self) -> bool {
+        self.league.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_league(&mut self, v: LeaguePt) {
+        self.league = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_league(&mut self) -> &mut LeaguePt {
+        if self.league.is_none() {
+            self.league.set_default();
+        }
+        self.league.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_league(&mut self) -> LeaguePt {
+        self.league.take().unwrap_or_else(|| LeaguePt::new())
+    }
+
+    // uint32 robot_temp_id = 11;
+
+
+    pub fn get_robot_temp_id(&self) -> u32 {
+        self.robot_temp_id
+    }
+    pub fn clear_robot_temp_id(&mut self) {
+        self.robot_temp_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_robot_temp_id(&mut self, v: u32) {
+        self.robot_temp_id = v;
+    }
+
+    // uint32 index = 12;
+
+
+    pub fn get_index(&self) -> u32 {
+        self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: u32) {
+        self.index = v;
+    }
+}
+
+impl ::protobuf::Message for MemberPt {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cter {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.league {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.user_id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.nick_name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.cter)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.state = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.grade = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.grade_frame = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.soul = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.team_id = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.join_time = tmp;
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.league)?;
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.robot_temp_id = tmp;
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.index = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.user_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.user_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.nick_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.nick_name);
+        }
+        if let Some(ref v) = self.cter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.state != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.state, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.grade != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.grade, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.grade_frame != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.grade_frame, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.soul != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.soul, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.team_id != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.team_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.join_time != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.join_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.league.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.robot_temp_id != 0 {
+            my_size += ::protobuf::rt::value_size(11, self.robot_temp_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.index != 0 {
+            my_size += ::protobuf::rt::value_size(12, self.index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.user_id != 0 {
+            os.write_uint32(1, self.user_id)?;
+        }
+        if !self.nick_name.is_empty() {
+            os.write_string(2, &self.nick_name)?;
+        }
+        if let Some(ref v) = self.cter.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.state != 0 {
+            os.write_uint32(4, self.state)?;
+        }
+        if self.grade != 0 {
+            os.write_uint32(5, self.grade)?;
+        }
+        if self.grade_frame != 0 {
+            os.write_uint32(6, self.grade_frame)?;
+        }
+        if self.soul != 0 {
+            os.write_uint32(7, self.soul)?;
+        }
+        if self.team_id != 0 {
+            os.write_uint32(8, self.team_id)?;
+        }
+        if self.join_time != 0 {
+            os.write_uint64(9, self.join_time)?;
+        }
+        if let Some(ref v) = self.league.as_ref() {
+            os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.robot_temp_id != 0 {
+            os.write_uint32(11, self.robot_temp_id)?;
+        }
+        if self.index != 0 {
+            os.write_uint32(12, self.index)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MemberPt {
+        MemberPt::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "user_id",
+                |m: &MemberPt| { &m.user_id },
+                |m: &mut MemberPt| { &mut m.user_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "nick_name",
+                |m: &MemberPt| { &m.nick_name },
+                |m: &mut MemberPt| { &mut m.nick_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CharacterPt>>(
+                "cter",
+                |m: &MemberPt| { &m.cter },
+                |m: &mut MemberPt| { &mut m.cter },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "state",
+                |m: &MemberPt| { &m.state },
+                |m: &mut MemberPt| { &mut m.state },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "grade",
+                |m: &MemberPt| { &m.grade },
+                |m: &mut MemberPt| { &mut m.grade },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "grade_frame",
+                |m: &MemberPt| { &m.grade_frame },
+                |m: &mut MemberPt| { &mut m.grade_frame },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "soul",
+                |m: &MemberPt| { &m.soul },
+                |m: &mut MemberPt| { &mut m.soul },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "team_id",
+                |m: &MemberPt| { &m.team_id },
+                |m: &mut MemberPt| { &mut m.team_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "join_time",
+                |m: &MemberPt| { &m.join_time },
+                |m: &mut MemberPt| { &mut m.join_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LeaguePt>>(
+                "league",
+                |m: &MemberPt| { &m.league },
+                |m: &mut MemberPt| { &mut m.league },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "robot_temp_id",
+                |m: &MemberPt| { &m.robot_temp_id },
+                |m: &mut MemberPt| { &mut m.robot_temp_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "index",
+                |m: &MemberPt| { &m.index },
+                |m: &mut MemberPt| { &mut m.index },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MemberPt>(
+                "MemberPt",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MemberPt {
+        static instance: ::protobuf::rt::LazyV2<MemberPt> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MemberPt::new)
+    }
+}
+
+impl ::protobuf::Clear for MemberPt {
+    fn clear(&mut self) {
+        self.user_id = 0;
+        self.nick_name.clear();
+        self.cter.clear();
+        self.state = 0;
+        self.grade = 0;
+        self.grade_frame = 0;
+        self.soul = 0;
+        self.team_id = 0;
+        self.join_time = 0;
+        self.league.clear();
+        self.robot_temp_id = 0;
+        self.index = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MemberPt {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MemberPt {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorldCellPt {
+    // message fields
+    pub index: u32,
+    pub world_cell_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WorldCellPt {
+    fn default() -> &'a WorldCellPt {
+        <WorldCellPt as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WorldCellPt {
+    pub fn new() -> WorldCellPt {
+        ::std::default::Default::default()
+    }
+
+    // uint32 index = 1;
+
+
+    pub fn get_index(&self) -> u32 {
+        self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: u32) {
+        self.index = v;
+    }
+
+    // uint32 world_cell_id = 2;
+
+
+    pub fn get_world_cell_id(&self) -> u32 {
+        self.world_cell_id
+    }
+    pub fn clear_world_cell_id(&mut self) {
+        self.world_cell_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_world_cell_id(&mut self, v: u32) {
+        self.world_cell_id = v;
+    }
+}
+
+impl ::protobuf::Message for WorldCellPt {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.index = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.world_cell_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.index != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.world_cell_id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.world_cell_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.index != 0 {
+            os.write_uint32(1, self.index)?;
+        }
+        if self.world_cell_id != 0 {
+            os.write_uint32(2, self.world_cell_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorldCellPt {
+        WorldCellPt::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "index",
+                |m: &WorldCellPt| { &m.index },
+                |m: &mut WorldCellPt| { &mut m.index },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "world_cell_id",
+                |m: &WorldCellPt| { &m.world_cell_id },
+                |m: &mut WorldCellPt| { &mut m.world_cell_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorldCellPt>(
+                "WorldCellPt",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WorldCellPt {
+        static instance: ::protobuf::rt::LazyV2<WorldCellPt> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WorldCellPt::new)
+    }
+}
+
+impl ::protobuf::Clear for WorldCellPt {
+    fn clear(&mut self) {
+        self.index = 0;
+        self.world_cell_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorldCellPt {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorldCellPt {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RoomPt {
+    // message fields
+    pub room_id: u32,
+    pub owner_id: u32,
+    pub room_type: u32,
+    pub room_status: u32,
+    pub setting: ::protobuf::SingularPtrField<RoomSettingPt>,
+    pub members: ::protobuf::RepeatedField<MemberPt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RoomPt {
+    fn default() -> &'a RoomPt {
+        <RoomPt as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RoomPt {
+    pub fn new() -> RoomPt {
+        ::std::default::Default::default()
+    }
+
+    // uint32 room_id = 1;
+
+
+    pub fn get_room_id(&self) -> u32 {
+        self.room_id
+    }
+    pub fn clear_room_id(&mut self) {
+        self.room_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_room_id(&mut self, v: u32) {
+        self.room_id = v;
+    }
+
+    // uint32 owner_id = 2;
+
+
+    pub fn get_owner_id(&self) -> u32 {
+        self.owner_id
+    }
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u32) {
+        self.owner_id = v;
+    }
+
+    // uint32 room_type = 3;
+
+
+    pub fn get_room_type(&self) -> u32 {
+        self.room_type
+    }
+    pub fn clear_room_type(&mut self) {
+        self.room_type = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_room_type(&mut self, v: u32) {
+        self.room_type = v;
+    }
+
+    // uint32 room_status = 4;
+
+
+    pub fn get_room_status(&self) -> u32 {
+        self.room_status
+    }
+    pub fn clear_room_status(&mut self) {
+        self.room_status = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_room_status(&mut self, v: u32) {
+        self.room_status = v;
+    }
+
+    // .protos.RoomSettingPt setting = 5;
+
+
+    pub fn get_setting(&self) -> &RoomSettingPt {
+        self.setting.as_ref().unwrap_or_else(|| <RoomSettingPt as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_setting(&mut self) {
+        self.setting.clear();
+    }
+
+    pub fn has_setting(&self) -> bool {
+        self.setting.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_setting(&mut self, v: RoomSettingPt) {
+        self.setting = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_setting(&mut self) -> &mut RoomSettingPt {
+        if self.setting.is_none() {
+            self.setting.set_default();
+        }
+        self.setting.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_setting(&mut self) -> RoomSettingPt {
+        self.setting.take().unwrap_or_else(|| RoomSettingPt::new())
+    }
+
+    // repeated .protos.MemberPt members = 6;
+
+
+    pub fn get_members(&self) -> &[MemberPt] {
+        &self.members
+    }
+    pub fn clear_members(&mut self) {
+        self.members.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_members(&mut self, v: ::protobuf::RepeatedField<MemberPt>) {
+        self.members = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_members(&mut self) -> &mut ::protobuf::RepeatedField<MemberPt> {
+        &mut self.members
+    }
+
+    // Take field
+    pub fn take_members(&mut self) -> ::protobuf::RepeatedField<MemberPt> {
+        ::std::mem::replace(&mut self.members, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RoomPt {
+    fn is_initialized(&self) -> bool {
+        for v in &self.setting {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.members {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.room_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.owner_id = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.room_type = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.room_status = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.setting)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.members)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.room_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.room_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.owner_id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.owner_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.room_type != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.room_type, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.room_status != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.room_status, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.setting.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.members {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.room_id != 0 {
+            os.write_uint32(1, self.room_id)?;
+        }
+        if self.owner_id != 0 {
+            os.write_uint32(2, self.owner_id)?;
+        }
+        if self.room_type != 0 {
+            os.write_uint32(3, self.room_type)?;
+        }
+        if self.room_status != 0 {
+            os.write_uint32(4, self.room_status)?;
+        }
+        if let Some(ref v) = self.setting.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.members {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RoomPt {
+        RoomPt::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "room_id",
+                |m: &RoomPt| { &m.room_id },
+                |m: &mut RoomPt| { &mut m.room_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "owner_id",
+                |m: &RoomPt| { &m.owner_id },
+                |m: &mut RoomPt| { &mut m.owner_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "room_type",
+                |m: &RoomPt| { &m.room_type },
+                |m: &mut RoomPt| { &mut m.room_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "room_status",
+                |m: &RoomPt| { &m.room_status },
+                |m: &mut RoomPt| { &mut m.room_status },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RoomSettingPt>>(
+                "setting",
+                |m: &RoomPt| { &m.setting },
+                |m: &mut RoomPt| { &mut m.setting },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<MemberPt>>(
+                "members",
+                |m: &RoomPt| { &m.members },
+                |m: &mut RoomPt| { &mut m.members },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RoomPt>(
+                "RoomPt",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RoomPt {
+        static instance: ::protobuf::rt::LazyV2<RoomPt> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RoomPt::new)
+    }
+}
+
+impl ::protobuf::Clear for RoomPt {
+    fn clear(&mut self) {
+        self.room_id = 0;
+        self.owner_id = 0;
+        self.room_type = 0;
+        self.room_status = 0;
+        self.setting.clear();
+        self.members.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RoomPt {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RoomPt {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DraftPickPt {
+    // message fields
+    pub user_id: u32,
+    pub cter_temp_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DraftPickPt {
+    fn default() -> &'a DraftPickPt {
+        <DraftPickPt as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DraftPickPt {
+    pub fn new() -> DraftPickPt {
+        ::std::default::Default::default()
+    }
+
+    // uint32 user_id = 1;
+
+
+    pub fn get_user_id(&self) -> u32 {
+        self.user_id
+    }
+    pub fn clear_user_id(&mut self) {
+        self.user_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: u32) {
+        self.user_id = v;
+    }
+
+    // uint32 cter_temp_id = 2;
+
+
+    pub fn get_cter_temp_id(&self) -> u32 {
+        self.cter_temp_id
+    }
+    pub fn clear_cter_temp_id(&mut self) {
+        self.cter_temp_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cter_temp_id(&mut self, v: u32) {
+        self.cter_temp_id = v;
+    }
+}
+
+impl ::protobuf::Message for DraftPickPt {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.user_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.cter_temp_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.user_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.user_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.cter_temp_id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.cter_temp_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.user_id != 0 {
+            os.write_uint32(1, self.user_id)?;
+        }
+        if self.cter_temp_id != 0 {
+            os.write_uint32(2, self.cter_temp_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DraftPickPt {
+        DraftPickPt::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "user_id",
+                |m: &DraftPickPt| { &m.user_id },
+                |m: &mut DraftPickPt| { &mut m.user_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "cter_temp_id",
+                |m: &DraftPickPt| { &m.cter_temp_id },
+                |m: &mut DraftPickPt| { &mut m.cter_temp_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DraftPickPt>(
+                "DraftPickPt",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DraftPickPt {
+        static instance: ::protobuf::rt::LazyV2<DraftPickPt> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DraftPickPt::new)
+    }
+}
+
+impl ::protobuf::Clear for DraftPickPt {
+    fn clear(&mut self) {
+        self.user_id = 0;
+        self.cter_temp_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DraftPickPt {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DraftPickPt {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct HistoryMessPt {
+    // message fields
+    pub user_id: u32,
+    pub nick_name: ::std::string::String,
+    pub channel: u32,
+    pub content: ::std::string::String,
+    pub time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a HistoryMessPt {
+    fn default() -> &'a HistoryMessPt {
+        <HistoryMessPt as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HistoryMessPt {
+    pub fn new() -> HistoryMessPt {
+        ::std::default::Default::default()
+    }
+
+    // uint32 user_id = 1;
+
+
+    pub fn get_user_id(&self) -> u32 {
+        self.user_id
+    }
+    pub fn clear_user_id(&mut self) {
+        self.user_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: u32) {
+        self.user_id = v;
+    }
+
+    // string nick_name = 2;
+
+
+    pub fn get_nick_name(&self) -> &str {
+        &self.nick_name
+    }
+    pub fn clear_nick_name(&mut self) {
+        self.nick_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nick_name(&mut self, v: ::std::string::String) {
+        self.nick_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nick_name(&mut self) -> &mut ::std::string::String {
+        &mut self.nick_name
+    }
+
+    // Take field
+    pub fn take_nick_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.nick_name, ::std::string::String::new())
+    }
+
+    // uint32 channel = 3;
+
+
+    pub fn get_channel(&self) -> u32 {
+        self.channel
+    }
+    pub fn clear_channel(&mut self) {
+        self.channel = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_channel(&mut self, v: u32) {
+        self.channel = v;
+    }
+
+    // string content = 4;
+
+
+    pub fn get_content(&self) -> &str {
+        &self.content
+    }
+    pub fn clear_content(&mut self) {
+        self.content.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content(&mut self, v: ::std::string::String) {
+        self.content = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content(&mut self) -> &mut ::std::string::String {
+        &mut self.content
+    }
+
+    // Take field
+    pub fn take_content(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.content, ::std::string::String::new())
+    }
+
+    // int64 time = 5;
+
+
+    pub fn get_time(&self) -> i64 {
+        self.time
+    }
+    pub fn clear_time(&mut self) {
+        self.time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_time(&mut self, v: i64) {
+        self.time = v;
+    }
+}
+
+impl ::protobuf::Message for HistoryMessPt {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.user_id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.nick_name)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.channel = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.content)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.user_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.user_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.nick_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.nick_name);
+        }
+        if self.channel != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.channel, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.content.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.content);
+        }
+        if self.time != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.user_id != 0 {
+            os.write_uint32(1, self.user_id)?;
+        }
+        if !self.nick_name.is_empty() {
+            os.write_string(2, &self.nick_name)?;
+        }
+        if self.channel != 0 {
+            os.write_uint32(3, self.channel)?;
+        }
+        if !self.content.is_empty() {
+            os.write_string(4, &self.content)?;
+        }
+        if self.time != 0 {
+            os.write_int64(5, self.time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> HistoryMessPt {
+        HistoryMessPt::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "user_id",
+                |m: &HistoryMessPt| { &m.user_id },
+                |m: &mut HistoryMessPt| { &mut m.user_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "nick_name",
+                |m: &HistoryMessPt| { &m.nick_name },
+                |m: &mut HistoryMessPt| { &mut m.nick_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "channel",
+                |m: &HistoryMessPt| { &m.channel },
+                |m: &mut HistoryMessPt| { &mut m.channel },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "content",
+                |m: &HistoryMessPt| { &m.content },
+                |m: &mut HistoryMessPt| { &mut m.content },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "time",
+                |m: &HistoryMessPt| { &m.time },
+                |m: &mut HistoryMessPt| { &mut m.time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<HistoryMessPt>(
+                "HistoryMessPt",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static HistoryMessPt {
+        static instance: ::protobuf::rt::LazyV2<HistoryMessPt> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(HistoryMessPt::new)
+    }
+}
+
+impl ::protobuf::Clear for HistoryMessPt {
+    fn clear(&mut self) {
+        self.user_id = 0;
+        self.nick_name.clear();
+        self.channel = 0;
+        self.content.clear();
+        self.time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for HistoryMessPt {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HistoryMessPt {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct NoticeMessPt {
+    // message fields
+    pub user_id: u32,
+    pub nick_name: ::std::string::String,
+    pub channel: u32,
+    pub content: ::std::string::String,
+    pub time: i64,
+    pub target_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a NoticeMessPt {
+    fn default() -> &'a NoticeMessPt {
+        <NoticeMessPt as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl NoticeMessPt {
+    pub fn new() -> NoticeMessPt {
+        ::std::default::Default::default()
+    }
+
+    // uint32 user_id = 1;
+
+
+    pub fn get_user_id(&self) -> u32 {
+        self.user_id
+    }
+    pub fn clear_user_id(&mut self) {
+        self.user_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: u32) {
+        self.user_id = v;
+    }
+
+    // string nick_name = 2;
+
+
+    pub fn get_nick_name(&self) -> &str {
+        &self.nick_name
+    }
+    pub fn clear_nick_name(&mut self) {
+        self.nick_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nick_name(&mut self, v: ::std::string::String) {
+        self.nick_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nick_name(&mut self) -> &mut ::std::string::String {
+        &mut self.nick_name
+    }
+
+    // Take field
+    pub fn take_nick_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.nick_name, ::std::string::String::new())
+    }
+
+    // uint32 channel = 3;
+
+
+    pub fn get_channel(&self) -> u32 {
+        self.channel
+    }
+    pub fn clear_channel(&mut self) {
+        self.channel = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_channel(&mut self, v: u32) {
+        self.channel = v;
+    }
+
+    // string content = 4;
+
+
+    pub fn get_content(&self) -> &str {
+        &self.content
+    }
+    pub fn clear_content(&mut self) {
+        self.content.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content(&mut self, v: ::std::string::String) {
+        self.content = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content(&mut self) -> &mut ::std::string::String {
+        &mut self.content
+    }
+
+    // Take field
+    pub fn take_content(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.content, ::std::string::String::new())
+    }
+
+    // int64 time = 5;
+
+
+    pub fn get_time(&self) -> i64 {
+        self.time
+    }
+    pub fn clear_time(&mut self) {
+        self.time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_time(&mut self, v: i64) {
+        self.time = v;
+    }
+
+    // uint32 target_id = 6;
+
+
+    pub fn get_target_id(&self) -> u32 {
+        self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: u32) {
+        self.target_id = v;
+    }
+}
+
+impl ::protobuf::Message for NoticeMessPt {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.user_id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.nick_name)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.channel = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.content)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.time = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.target_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.user_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.user_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.nick_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.nick_name);
+        }
+        if self.channel != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.channel, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.content.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.content);
+        }
+        if self.time != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.target_id != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.target_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.user_id != 0 {
+            os.write_uint32(1, self.user_id)?;
+        }
+        if !self.nick_name.is_empty() {
+            os.write_string(2, &self.nick_name)?;
+        }
+        if self.channel != 0 {
+            os.write_uint32(3, self.channel)?;
+        }
+        if !self.content.is_empty() {
+            os.write_string(4, &self.content)?;
+        }
+        if self.time != 0 {
+            os.write_int64(5, self.time)?;
+        }
+        if self.target_id != 0 {
+            os.write_uint32(6, self.target_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> NoticeMessPt {
+        NoticeMessPt::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "user_id",
+                |m: &NoticeMessPt| { &m.user_id },
+                |m: &mut NoticeMessPt| { &mut m.user_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "nick_name",
+                |m: &NoticeMessPt| { &m.nick_name },
+                |m: &mut NoticeMessPt| { &mut m.nick_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "channel",
+                |m: &NoticeMessPt| { &m.channel },
+                |m: &mut NoticeMessPt| { &mut m.channel },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "content",
+                |m: &NoticeMessPt| { &m.content },
+                |m: &mut NoticeMessPt| { &mut m.content },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "time",
+                |m: &NoticeMessPt| { &m.time },
+                |m: &mut NoticeMessPt| { &mut m.time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "target_id",
+                |m: &NoticeMessPt| { &m.target_id },
+                |m: &mut NoticeMessPt| { &mut m.target_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<NoticeMessPt>(
+                "NoticeMessPt",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static NoticeMessPt {
+        static instance: ::protobuf::rt::LazyV2<NoticeMessPt> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(NoticeMessPt::new)
+    }
+}
+
+impl ::protobuf::Clear for NoticeMessPt {
+    fn clear(&mut self) {
+        self.user_id = 0;
+        self.nick_name.clear();
+        self.channel = 0;
+        self.content.clear();
+        self.time = 0;
+        self.target_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for NoticeMessPt {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NoticeMessPt {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FriendPt {
+    // message fields
+    pub user_id: u32,
+    pub nick_name: ::std::string::String,
+    pub presence: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FriendPt {
+    fn default() -> &'a FriendPt {
+        <FriendPt as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FriendPt {
+    pub fn new() -> FriendPt {
+        ::std::default::Default::default()
+    }
+
+    // uint32 user_id = 1;
+
+
+    pub fn get_user_id(&self) -> u32 {
+        self.user_id
+    }
+    pub fn clear_user_id(&mut self) {
+        self.user_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: u32) {
+        self.user_id = v;
+    }
+
+    // string nick_name = 2;
+
+
+    pub fn get_nick_name(&self) -> &str {
+        &self.nick_name
+    }
+    pub fn clear_nick_name(&mut self) {
+        self.nick_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nick_name(&mut self, v: ::std::string::String) {
+        self.nick_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nick_name(&mut self) -> &mut ::std::string::String {
+        &mut self.nick_name
+    }
+
+    // Take field
+    pub fn take_nick_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.nick_name, ::std::string::String::new())
+    }
+
+    // uint32 presence = 3;
+
+
+    pub fn get_presence(&self) -> u32 {
+        self.presence
+    }
+    pub fn clear_presence(&mut self) {
+        self.presence = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_presence(&mut self, v: u32) {
+        self.presence = v;
+    }
+}
+
+impl ::protobuf::Message for FriendPt {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.user_id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.nick_name)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.presence = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.user_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.user_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.nick_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.nick_name);
+        }
+        if self.presence != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.presence, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.user_id != 0 {
+            os.write_uint32(1, self.user_id)?;
+        }
+        if !self.nick_name.is_empty() {
+            os.write_string(2, &self.nick_name)?;
+        }
+        if self.presence != 0 {
+            os.write_uint32(3, self.presence)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FriendPt {
+        FriendPt::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "user_id",
+                |m: &FriendPt| { &m.user_id },
+                |m: &mut FriendPt| { &mut m.user_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "nick_name",
+                |m: &FriendPt| { &m.nick_name },
+                |m: &mut FriendPt| { &mut m.nick_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "presence",
+                |m: &FriendPt| { &m.presence },
+                |m: &mut FriendPt| { &mut m.presence },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FriendPt>(
+                "FriendPt",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FriendPt {
+        static instance: ::protobuf::rt::LazyV2<FriendPt> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FriendPt::new)
+    }
+}
+
+impl ::protobuf::Clear for FriendPt {
+    fn clear(&mut self) {
+        self.user_id = 0;
+        self.nick_name.clear();
+        self.presence = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FriendPt {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FriendPt {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PartyPt {
+    // message fields
+    pub leader_id: u32,
+    pub members: ::std::vec::Vec<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PartyPt {
+    fn default() -> &'a PartyPt {
+        <PartyPt as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PartyPt {
+    pub fn new() -> PartyPt {
+        ::std::default::Default::default()
+    }
+
+    // uint32 leader_id = 1;
+
+
+    pub fn get_leader_id(&self) -> u32 {
+        self.leader_id
+    }
+    pub fn clear_leader_id(&mut self) {
+        self.leader_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_leader_id(&mut self, v: u32) {
+        self.leader_id = v;
+    }
+
+    // repeated uint32 members = 2;
+
+
+    pub fn get_members(&self) -> &[u32] {
+        &self.members
+    }
+    pub fn clear_members(&mut self) {
+        self.members.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_members(&mut self, v: ::std::vec::Vec<u32>) {
+        self.members = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_members(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.members
+    }
+
+    // Take field
+    pub fn take_members(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.members, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for PartyPt {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.leader_id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.members)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.leader_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.leader_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.members {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.leader_id != 0 {
+            os.write_uint32(1, self.leader_id)?;
+        }
+        for v in &self.members {
+            os.write_uint32(2, *v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PartyPt {
+        PartyPt::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "leader_id",
+                |m: &PartyPt| { &m.leader_id },
+                |m: &mut PartyPt| { &mut m.leader_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "members",
+                |m: &PartyPt| { &m.members },
+                |m: &mut PartyPt| { &mut m.members },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PartyPt>(
+                "PartyPt",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PartyPt {
+        static instance: ::protobuf::rt::LazyV2<PartyPt> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PartyPt::new)
+    }
+}
+
+impl ::protobuf::Clear for PartyPt {
+    fn clear(&mut self) {
+        self.leader_id = 0;
+        self.members.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PartyPt {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PartyPt {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AttachmentPt {
+    // message fields
+    pub attach_type: u32,
+    pub id: u32,
+    pub count: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AttachmentPt {
+    fn default() -> &'a AttachmentPt {
+        <AttachmentPt as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AttachmentPt {
+    pub fn new() -> AttachmentPt {
+        ::std::default::Default::default()
+    }
+
+    // uint32 attach_type = 1;
+
+
+    pub fn get_attach_type(&self) -> u32 {
+        self.attach_type
+    }
+    pub fn clear_attach_type(&mut self) {
+        self.attach_type = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attach_type(&mut self, v: u32) {
+        self.attach_type = v;
+    }
+
+    // uint32 id = 2;
+
+
+    pub fn get_id(&self) -> u32 {
+        self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u32) {
+        self.id = v;
+    }
+
+    // uint32 count = 3;
+
+
+    pub fn get_count(&self) -> u32 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u32) {
+        self.count = v;
+    }
+}
+
+impl ::protobuf::Message for AttachmentPt {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.attach_type = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.id = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.attach_type != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.attach_type, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.attach_type != 0 {
+            os.write_uint32(1, self.attach_type)?;
+        }
+        if self.id != 0 {
+            os.write_uint32(2, self.id)?;
+        }
+        if self.count != 0 {
+            os.write_uint32(3, self.count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AttachmentPt {
+        AttachmentPt::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "attach_type",
+                |m: &AttachmentPt| { &m.attach_type },
+                |m: &mut AttachmentPt| { &mut m.attach_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "id",
+                |m: &AttachmentPt| { &m.id },
+                |m: &mut AttachmentPt| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "count",
+                |m: &AttachmentPt| { &m.count },
+                |m: &mut AttachmentPt| { &mut m.count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AttachmentPt>(
+                "AttachmentPt",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AttachmentPt {
+        static instance: ::protobuf::rt::LazyV2<AttachmentPt> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AttachmentPt::new)
+    }
+}
+
+impl ::protobuf::Clear for AttachmentPt {
+    fn clear(&mut self) {
+        self.attach_type = 0;
+        self.id = 0;
+        self.count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AttachmentPt {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AttachmentPt {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MailPt {
+    // message fields
+    pub mail_id: u32,
+    pub mail_type: u32,
+    pub title: ::std::string::String,
+    pub content: ::std::string::String,
+    pub attachments: ::protobuf::RepeatedField<AttachmentPt>,
+    pub send_time: i64,
+    pub expire_time: i64,
+    pub is_read: bool,
+    pub is_claimed: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MailPt {
+    fn default() -> &'a MailPt {
+        <MailPt as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MailPt {
+    pub fn new() -> MailPt {
+        ::std::default::Default::default()
+    }
+
+    // uint32 mail_id = 1;
+
+
+    pub fn get_mail_id(&self) -> u32 {
+        self.mail_id
+    }
+    pub fn clear_mail_id(&mut self) {
+        self.mail_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mail_id(&mut self, v: u32) {
+        self.mail_id = v;
+    }
+
+    // uint32 mail_type = 2;
+
+
+    pub fn get_mail_type(&self) -> u32 {
+        self.mail_type
+    }
+    pub fn clear_mail_type(&mut self) {
+        self.mail_type = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mail_type(&mut self, v: u32) {
+        self.mail_type = v;
+    }
+
+    // string title = 3;
+
+
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+    pub fn clear_title(&mut self) {
+        self.title.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_title(&mut self, v: ::std::string::String) {
+        self.title = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_title(&mut self) -> &mut ::std::string::String {
+        &mut self.title
+    }
+
+    // Take field
+    pub fn take_title(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.title, ::std::string::String::new())
+    }
+
+    // string content = 4;
+
+
+    pub fn get_content(&self) -> &str {
+        &self.content
+    }
+    pub fn clear_content(&mut self) {
+        self.content.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content(&mut self, v: ::std::string::String) {
+        self.content = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content(&mut self) -> &mut ::std::string::String {
+        &mut self.content
+    }
+
+    // Take field
+    pub fn take_content(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.content, ::std::string::String::new())
+    }
+
+    // repeated .protos.AttachmentPt attachments = 5;
+
+
+    pub fn get_attachments(&self) -> &[AttachmentPt] {
+        &self.attachments
+    }
+    pub fn clear_attachments(&mut self) {
+        self.attachments.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attachments(&mut self, v: ::protobuf::RepeatedField<AttachmentPt>) {
+        self.attachments = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_attachments(&mut self) -> &mut ::protobuf::RepeatedField<AttachmentPt> {
+        &mut self.attachments
+    }
+
+    // Take field
+    pub fn take_attachments(&mut self) -> ::protobuf::RepeatedField<AttachmentPt> {
+        ::std::mem::replace(&mut self.attachments, ::protobuf::RepeatedField::new())
+    }
+
+    // int64 send_time = 6;
+
+
+    pub fn get_send_time(&self) -> i64 {
+        self.send_time
+    }
+    pub fn clear_send_time(&mut self) {
+        self.send_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_send_time(&mut self, v: i64) {
+        self.send_time = v;
+    }
+
+    // int64 expire_time = 7;
+
+
+    pub fn get_expire_time(&self) -> i64 {
+        self.expire_time
+    }
+    pub fn clear_expire_time(&mut self) {
+        self.expire_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expire_time(&mut self, v: i64) {
+        self.expire_time = v;
+    }
+
+    // bool is_read = 8;
+
+
+    pub fn get_is_read(&self) -> bool {
+        self.is_read
+    }
+    pub fn clear_is_read(&mut self) {
+        self.is_read = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_read(&mut self, v: bool) {
+        self.is_read = v;
+    }
+
+    // bool is_claimed = 9;
+
+
+    pub fn get_is_claimed(&self) -> bool {
+        self.is_claimed
+    }
+    pub fn clear_is_claimed(&mut self) {
+        self.is_claimed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_claimed(&mut self, v: bool) {
+        self.is_claimed = v;
+    }
+}
+
+impl ::protobuf::Message for MailPt {
+    fn is_initialized(&self) -> bool {
+        for v in &self.attachments {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.mail_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.mail_type = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.title)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.content)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.attachments)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.send_time = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.expire_time = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_read = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_claimed = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.mail_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.mail_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.mail_type != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.mail_type, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.title.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.title);
+        }
+        if !self.content.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.content);
+        }
+        for value in &self.attachments {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.send_time != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.send_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.expire_time != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.expire_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.is_read != false {
+            my_size += 2;
+        }
+        if self.is_claimed != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    // Take field
-    pub fn take_cter(&mut self) -> CharacterPt {
-        self.cter.take().unwrap_or_else(|| CharacterPt::new())
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.mail_id != 0 {
+            os.write_uint32(1, self.mail_id)?;
+        }
+        if self.mail_type != 0 {
+            os.write_uint32(2, self.mail_type)?;
+        }
+        if !self.title.is_empty() {
+            os.write_string(3, &self.title)?;
+        }
+        if !self.content.is_empty() {
+            os.write_string(4, &self.content)?;
+        }
+        for v in &self.attachments {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.send_time != 0 {
+            os.write_int64(6, self.send_time)?;
+        }
+        if self.expire_time != 0 {
+            os.write_int64(7, self.expire_time)?;
+        }
+        if self.is_read != false {
+            os.write_bool(8, self.is_read)?;
+        }
+        if self.is_claimed != false {
+            os.write_bool(9, self.is_claimed)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    // uint32 state = 4;
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
 
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
 
-    pub fn get_state(&self) -> u32 {
-        self.state
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
     }
-    pub fn clear_state(&mut self) {
-        self.state = 0;
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MailPt {
+        MailPt::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "mail_id",
+                |m: &MailPt| { &m.mail_id },
+                |m: &mut MailPt| { &mut m.mail_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "mail_type",
+                |m: &MailPt| { &m.mail_type },
+                |m: &mut MailPt| { &mut m.mail_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "title",
+                |m: &MailPt| { &m.title },
+                |m: &mut MailPt| { &mut m.title },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "content",
+                |m: &MailPt| { &m.content },
+                |m: &mut MailPt| { &mut m.content },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AttachmentPt>>(
+                "attachments",
+                |m: &MailPt| { &m.attachments },
+                |m: &mut MailPt| { &mut m.attachments },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "send_time",
+                |m: &MailPt| { &m.send_time },
+                |m: &mut MailPt| { &mut m.send_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "expire_time",
+                |m: &MailPt| { &m.expire_time },
+                |m: &mut MailPt| { &mut m.expire_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_read",
+                |m: &MailPt| { &m.is_read },
+                |m: &mut MailPt| { &mut m.is_read },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_claimed",
+                |m: &MailPt| { &m.is_claimed },
+                |m: &mut MailPt| { &mut m.is_claimed },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MailPt>(
+                "MailPt",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MailPt {
+        static instance: ::protobuf::rt::LazyV2<MailPt> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MailPt::new)
+    }
+}
+
+impl ::protobuf::Clear for MailPt {
+    fn clear(&mut self) {
+        self.mail_id = 0;
+        self.mail_type = 0;
+        self.title.clear();
+        self.content.clear();
+        self.attachments.clear();
+        self.send_time = 0;
+        self.expire_time = 0;
+        self.is_read = false;
+        self.is_claimed = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MailPt {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MailPt {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QuestPt {
+    // message fields
+    pub quest_id: u32,
+    pub quest_type: u32,
+    pub progress: u32,
+    pub target: u32,
+    pub is_claimed: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QuestPt {
+    fn default() -> &'a QuestPt {
+        <QuestPt as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QuestPt {
+    pub fn new() -> QuestPt {
+        ::std::default::Default::default()
+    }
+
+    // uint32 quest_id = 1;
+
+
+    pub fn get_quest_id(&self) -> u32 {
+        self.quest_id
+    }
+    pub fn clear_quest_id(&mut self) {
+        self.quest_id = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_state(&mut self, v: u32) {
-        self.state = v;
+    pub fn set_quest_id(&mut self, v: u32) {
+        self.quest_id = v;
     }
 
-    // uint32 grade = 5;
+    // uint32 quest_type = 2;
 
 
-    pub fn get_grade(&self) -> u32 {
-        self.grade
+    pub fn get_quest_type(&self) -> u32 {
+        self.quest_type
     }
-    pub fn clear_grade(&mut self) {
-        self.grade = 0;
+    pub fn clear_quest_type(&mut self) {
+        self.quest_type = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_grade(&mut self, v: u32) {
-        self.grade = v;
+    pub fn set_quest_type(&mut self, v: u32) {
+        self.quest_type = v;
     }
 
-    // uint32 grade_frame = 6;
+    // uint32 progress = 3;
 
 
-    pub fn get_grade_frame(&self) -> u32 {
-        self.grade_frame
+    pub fn get_progress(&self) -> u32 {
+        self.progress
+    }
+    pub fn clear_progress(&mut self) {
+        self.progress = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_progress(&mut self, v: u32) {
+        self.progress = v;
+    }
+
+    // uint32 target = 4;
+
+
+    pub fn get_target(&self) -> u32 {
+        self.target
+    }
+    pub fn clear_target(&mut self) {
+        self.target = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: u32) {
+        self.target = v;
+    }
+
+    // bool is_claimed = 5;
+
+
+    pub fn get_is_claimed(&self) -> bool {
+        self.is_claimed
+    }
+    pub fn clear_is_claimed(&mut self) {
+        self.is_claimed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_claimed(&mut self, v: bool) {
+        self.is_claimed = v;
+    }
+}
+
+impl ::protobuf::Message for QuestPt {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.quest_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.quest_type = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.progress = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.target = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_claimed = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.quest_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.quest_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.quest_type != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.quest_type, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.progress != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.progress, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.target != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.target, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.is_claimed != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.quest_id != 0 {
+            os.write_uint32(1, self.quest_id)?;
+        }
+        if self.quest_type != 0 {
+            os.write_uint32(2, self.quest_type)?;
+        }
+        if self.progress != 0 {
+            os.write_uint32(3, self.progress)?;
+        }
+        if self.target != 0 {
+            os.write_uint32(4, self.target)?;
+        }
+        if self.is_claimed != false {
+            os.write_bool(5, self.is_claimed)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
     }
-    pub fn clear_grade_frame(&mut self) {
-        self.grade_frame = 0;
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
     }
 
-    // Param is passed by value, moved
-    pub fn set_grade_frame(&mut self, v: u32) {
-        self.grade_frame = v;
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
     }
 
-    // uint32 soul = 7;
+    fn new() -> QuestPt {
+        QuestPt::new()
+    }
 
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "quest_id",
+                |m: &QuestPt| { &m.quest_id },
+                |m: &mut QuestPt| { &mut m.quest_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "quest_type",
+                |m: &QuestPt| { &m.quest_type },
+                |m: &mut QuestPt| { &mut m.quest_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "progress",
+                |m: &QuestPt| { &m.progress },
+                |m: &mut QuestPt| { &mut m.progress },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "target",
+                |m: &QuestPt| { &m.target },
+                |m: &mut QuestPt| { &mut m.target },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_claimed",
+                |m: &QuestPt| { &m.is_claimed },
+                |m: &mut QuestPt| { &mut m.is_claimed },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QuestPt>(
+                "QuestPt",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
 
-    pub fn get_soul(&self) -> u32 {
-        self.soul
+    fn default_instance() -> &'static QuestPt {
+        static instance: ::protobuf::rt::LazyV2<QuestPt> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QuestPt::new)
     }
-    pub fn clear_soul(&mut self) {
-        self.soul = 0;
+}
+
+impl ::protobuf::Clear for QuestPt {
+    fn clear(&mut self) {
+        self.quest_id = 0;
+        self.quest_type = 0;
+        self.progress = 0;
+        self.target = 0;
+        self.is_claimed = false;
+        self.unknown_fields.clear();
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_soul(&mut self, v: u32) {
-        self.soul = v;
+impl ::std::fmt::Debug for QuestPt {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    // uint32 team_id = 8;
+impl ::protobuf::reflect::ProtobufValue for QuestPt {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
 
+#[derive(PartialEq,Clone,Default)]
+pub struct AchievementPt {
+    // message fields
+    pub achievement_id: u32,
+    pub progress: u32,
+    pub target: u32,
+    pub unlock_time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
 
-    pub fn get_team_id(&self) -> u32 {
-        self.team_id
-    }
-    pub fn clear_team_id(&mut self) {
-        self.team_id = 0;
+impl<'a> ::std::default::Default for &'a AchievementPt {
+    fn default() -> &'a AchievementPt {
+        <AchievementPt as ::protobuf::Message>::default_instance()
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_team_id(&mut self, v: u32) {
-        self.team_id = v;
+impl AchievementPt {
+    pub fn new() -> AchievementPt {
+        ::std::default::Default::default()
     }
 
-    // uint64 join_time = 9;
+    // uint32 achievement_id = 1;
 
 
-    pub fn get_join_time(&self) -> u64 {
-        self.join_time
+    pub fn get_achievement_id(&self) -> u32 {
+        self.achievement_id
     }
-    pub fn clear_join_time(&mut self) {
-        self.join_time = 0;
+    pub fn clear_achievement_id(&mut self) {
+        self.achievement_id = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_join_time(&mut self, v: u64) {
-        self.join_time = v;
+    pub fn set_achievement_id(&mut self, v: u32) {
+        self.achievement_id = v;
     }
 
-    // .protos.LeaguePt league = 10;
+    // uint32 progress = 2;
 
 
-    pub fn get_league(&self) -> &LeaguePt {
-        self.league.as_ref().unwrap_or_else(|| <LeaguePt as ::protobuf::Message>::default_instance())
-    }
-    pub fn clear_league(&mut self) {
-        self.league.clear();
+    pub fn get_progress(&self) -> u32 {
+        self.progress
     }
-
-    pub fn has_league(&self) -> bool {
-        self.league.is_some()
+    pub fn clear_progress(&mut self) {
+        self.progress = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_league(&mut self, v: LeaguePt) {
-        self.league = ::protobuf::SingularPtrField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_league(&mut self) -> &mut LeaguePt {
-        if self.league.is_none() {
-            self.league.set_default();
-        }
-        self.league.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_league(&mut self) -> LeaguePt {
-        self.league.take().unwrap_or_else(|| LeaguePt::new())
+    pub fn set_progress(&mut self, v: u32) {
+        self.progress = v;
     }
 
-    // uint32 robot_temp_id = 11;
+    // uint32 target = 3;
 
 
-    pub fn get_robot_temp_id(&self) -> u32 {
-        self.robot_temp_id
+    pub fn get_target(&self) -> u32 {
+        self.target
     }
-    pub fn clear_robot_temp_id(&mut self) {
-        self.robot_temp_id = 0;
+    pub fn clear_target(&mut self) {
+        self.target = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_robot_temp_id(&mut self, v: u32) {
-        self.robot_temp_id = v;
+    pub fn set_target(&mut self, v: u32) {
+        self.target = v;
     }
 
-    // uint32 index = 12;
+    // int64 unlock_time = 4;
 
 
-    pub fn get_index(&self) -> u32 {
-        self.index
+    pub fn get_unlock_time(&self) -> i64 {
+        self.unlock_time
     }
-    pub fn clear_index(&mut self) {
-        self.index = 0;
+    pub fn clear_unlock_time(&mut self) {
+        self.unlock_time = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_index(&mut self, v: u32) {
-        self.index = v;
+    pub fn set_unlock_time(&mut self, v: i64) {
+        self.unlock_time = v;
     }
 }
 
-impl ::protobuf::Message for MemberPt {
+impl ::protobuf::Message for AchievementPt {
     fn is_initialized(&self) -> bool {
-        for v in &self.cter {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.league {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -1461,72 +5013,28 @@ impl ::protobuf::Message for MemberPt {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint32()?;
-                    self.user_id = tmp;
+                    self.achievement_id = tmp;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.nick_name)?;
-                },
-                3 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.cter)?;
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.state = tmp;
-                },
-                5 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.grade = tmp;
-                },
-                6 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint32()?;
-                    self.grade_frame = tmp;
-                },
-                7 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.soul = tmp;
-                },
-                8 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.team_id = tmp;
-                },
-                9 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.join_time = tmp;
-                },
-                10 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.league)?;
+                    self.progress = tmp;
                 },
-                11 => {
+                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint32()?;
-                    self.robot_temp_id = tmp;
+                    self.target = tmp;
                 },
-                12 => {
+                4 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_uint32()?;
-                    self.index = tmp;
+                    let tmp = is.read_int64()?;
+                    self.unlock_time = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1538,91 +5046,37 @@ impl ::protobuf::Message for MemberPt {
 
     // Compute sizes of nested messages
     #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        if self.user_id != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.user_id, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if !self.nick_name.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.nick_name);
-        }
-        if let Some(ref v) = self.cter.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        }
-        if self.state != 0 {
-            my_size += ::protobuf::rt::value_size(4, self.state, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.grade != 0 {
-            my_size += ::protobuf::rt::value_size(5, self.grade, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.grade_frame != 0 {
-            my_size += ::protobuf::rt::value_size(6, self.grade_frame, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.soul != 0 {
-            my_size += ::protobuf::rt::value_size(7, self.soul, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.team_id != 0 {
-            my_size += ::protobuf::rt::value_size(8, self.team_id, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.join_time != 0 {
-            my_size += ::protobuf::rt::value_size(9, self.join_time, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(ref v) = self.league.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        }
-        if self.robot_temp_id != 0 {
-            my_size += ::protobuf::rt::value_size(11, self.robot_temp_id, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.index != 0 {
-            my_size += ::protobuf::rt::value_size(12, self.index, ::protobuf::wire_format::WireTypeVarint);
-        }
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
-    }
-
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if self.user_id != 0 {
-            os.write_uint32(1, self.user_id)?;
-        }
-        if !self.nick_name.is_empty() {
-            os.write_string(2, &self.nick_name)?;
-        }
-        if let Some(ref v) = self.cter.as_ref() {
-            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        }
-        if self.state != 0 {
-            os.write_uint32(4, self.state)?;
-        }
-        if self.grade != 0 {
-            os.write_uint32(5, self.grade)?;
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.achievement_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.achievement_id, ::protobuf::wire_format::WireTypeVarint);
         }
-        if self.grade_frame != 0 {
-            os.write_uint32(6, self.grade_frame)?;
+        if self.progress != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.progress, ::protobuf::wire_format::WireTypeVarint);
         }
-        if self.soul != 0 {
-            os.write_uint32(7, self.soul)?;
+        if self.target != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.target, ::protobuf::wire_format::WireTypeVarint);
         }
-        if self.team_id != 0 {
-            os.write_uint32(8, self.team_id)?;
+        if self.unlock_time != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.unlock_time, ::protobuf::wire_format::WireTypeVarint);
         }
-        if self.join_time != 0 {
-            os.write_uint64(9, self.join_time)?;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.achievement_id != 0 {
+            os.write_uint32(1, self.achievement_id)?;
         }
-        if let Some(ref v) = self.league.as_ref() {
-            os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if self.progress != 0 {
+            os.write_uint32(2, self.progress)?;
         }
-        if self.robot_temp_id != 0 {
-            os.write_uint32(11, self.robot_temp_id)?;
+        if self.target != 0 {
+            os.write_uint32(3, self.target)?;
         }
-        if self.index != 0 {
-            os.write_uint32(12, self.index)?;
+        if self.unlock_time != 0 {
+            os.write_int64(4, self.unlock_time)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1654,8 +5108,8 @@ impl ::protobuf::Message for MemberPt {
         Self::descriptor_static()
     }
 
-    fn new() -> MemberPt {
-        MemberPt::new()
+    fn new() -> AchievementPt {
+        AchievementPt::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1663,162 +5117,269 @@ impl ::protobuf::Message for MemberPt {
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                "user_id",
-                |m: &MemberPt| { &m.user_id },
-                |m: &mut MemberPt| { &mut m.user_id },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "nick_name",
-                |m: &MemberPt| { &m.nick_name },
-                |m: &mut MemberPt| { &mut m.nick_name },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CharacterPt>>(
-                "cter",
-                |m: &MemberPt| { &m.cter },
-                |m: &mut MemberPt| { &mut m.cter },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                "state",
-                |m: &MemberPt| { &m.state },
-                |m: &mut MemberPt| { &mut m.state },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                "grade",
-                |m: &MemberPt| { &m.grade },
-                |m: &mut MemberPt| { &mut m.grade },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                "grade_frame",
-                |m: &MemberPt| { &m.grade_frame },
-                |m: &mut MemberPt| { &mut m.grade_frame },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                "soul",
-                |m: &MemberPt| { &m.soul },
-                |m: &mut MemberPt| { &mut m.soul },
+                "achievement_id",
+                |m: &AchievementPt| { &m.achievement_id },
+                |m: &mut AchievementPt| { &mut m.achievement_id },
             ));
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                "team_id",
-                |m: &MemberPt| { &m.team_id },
-                |m: &mut MemberPt| { &mut m.team_id },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                "join_time",
-                |m: &MemberPt| { &m.join_time },
-                |m: &mut MemberPt| { &mut m.join_time },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<LeaguePt>>(
-                "league",
-                |m: &MemberPt| { &m.league },
-                |m: &mut MemberPt| { &mut m.league },
+                "progress",
+                |m: &AchievementPt| { &m.progress },
+                |m: &mut AchievementPt| { &mut m.progress },
             ));
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                "robot_temp_id",
-                |m: &MemberPt| { &m.robot_temp_id },
-                |m: &mut MemberPt| { &mut m.robot_temp_id },
+                "target",
+                |m: &AchievementPt| { &m.target },
+                |m: &mut AchievementPt| { &mut m.target },
             ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                "index",
-                |m: &MemberPt| { &m.index },
-                |m: &mut MemberPt| { &mut m.index },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "unlock_time",
+                |m: &AchievementPt| { &m.unlock_time },
+                |m: &mut AchievementPt| { &mut m.unlock_time },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MemberPt>(
-                "MemberPt",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AchievementPt>(
+                "AchievementPt",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static MemberPt {
-        static instance: ::protobuf::rt::LazyV2<MemberPt> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(MemberPt::new)
+    fn default_instance() -> &'static AchievementPt {
+        static instance: ::protobuf::rt::LazyV2<AchievementPt> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AchievementPt::new)
     }
 }
 
-impl ::protobuf::Clear for MemberPt {
+impl ::protobuf::Clear for AchievementPt {
     fn clear(&mut self) {
-        self.user_id = 0;
-        self.nick_name.clear();
-        self.cter.clear();
-        self.state = 0;
-        self.grade = 0;
-        self.grade_frame = 0;
-        self.soul = 0;
-        self.team_id = 0;
-        self.join_time = 0;
-        self.league.clear();
-        self.robot_temp_id = 0;
-        self.index = 0;
+        self.achievement_id = 0;
+        self.progress = 0;
+        self.target = 0;
+        self.unlock_time = 0;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for MemberPt {
+impl ::std::fmt::Debug for AchievementPt {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for MemberPt {
+impl ::protobuf::reflect::ProtobufValue for AchievementPt {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct WorldCellPt {
+pub struct TournamentPt {
     // message fields
-    pub index: u32,
-    pub world_cell_id: u32,
+    pub tournament_id: u32,
+    pub name: ::std::string::String,
+    pub format: u32,
+    pub state: u32,
+    pub sign_up_start: u64,
+    pub sign_up_end: u64,
+    pub start_time: u64,
+    pub max_players: u32,
+    pub advance_count: u32,
+    pub player_count: u32,
+    pub round: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a WorldCellPt {
-    fn default() -> &'a WorldCellPt {
-        <WorldCellPt as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a TournamentPt {
+    fn default() -> &'a TournamentPt {
+        <TournamentPt as ::protobuf::Message>::default_instance()
     }
 }
 
-impl WorldCellPt {
-    pub fn new() -> WorldCellPt {
+impl TournamentPt {
+    pub fn new() -> TournamentPt {
         ::std::default::Default::default()
     }
 
-    // uint32 index = 1;
+    // uint32 tournament_id = 1;
 
 
-    pub fn get_index(&self) -> u32 {
-        self.index
+    pub fn get_tournament_id(&self) -> u32 {
+        self.tournament_id
     }
-    pub fn clear_index(&mut self) {
-        self.index = 0;
+    pub fn clear_tournament_id(&mut self) {
+        self.tournament_id = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_index(&mut self, v: u32) {
-        self.index = v;
+    pub fn set_tournament_id(&mut self, v: u32) {
+        self.tournament_id = v;
     }
 
-    // uint32 world_cell_id = 2;
+    // string name = 2;
 
 
-    pub fn get_world_cell_id(&self) -> u32 {
-        self.world_cell_id
+    pub fn get_name(&self) -> &str {
+        &self.name
     }
-    pub fn clear_world_cell_id(&mut self) {
-        self.world_cell_id = 0;
+    pub fn clear_name(&mut self) {
+        self.name.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_world_cell_id(&mut self, v: u32) {
-        self.world_cell_id = v;
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // uint32 format = 3;
+
+
+    pub fn get_format(&self) -> u32 {
+        self.format
+    }
+    pub fn clear_format(&mut self) {
+        self.format = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_format(&mut self, v: u32) {
+        self.format = v;
+    }
+
+    // uint32 state = 4;
+
+
+    pub fn get_state(&self) -> u32 {
+        self.state
+    }
+    pub fn clear_state(&mut self) {
+        self.state = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: u32) {
+        self.state = v;
+    }
+
+    // uint64 sign_up_start = 5;
+
+
+    pub fn get_sign_up_start(&self) -> u64 {
+        self.sign_up_start
+    }
+    pub fn clear_sign_up_start(&mut self) {
+        self.sign_up_start = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sign_up_start(&mut self, v: u64) {
+        self.sign_up_start = v;
+    }
+
+    // uint64 sign_up_end = 6;
+
+
+    pub fn get_sign_up_end(&self) -> u64 {
+        self.sign_up_end
+    }
+    pub fn clear_sign_up_end(&mut self) {
+        self.sign_up_end = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sign_up_end(&mut self, v: u64) {
+        self.sign_up_end = v;
+    }
+
+    // uint64 start_time = 7;
+
+
+    pub fn get_start_time(&self) -> u64 {
+        self.start_time
+    }
+    pub fn clear_start_time(&mut self) {
+        self.start_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_time(&mut self, v: u64) {
+        self.start_time = v;
+    }
+
+    // uint32 max_players = 8;
+
+
+    pub fn get_max_players(&self) -> u32 {
+        self.max_players
+    }
+    pub fn clear_max_players(&mut self) {
+        self.max_players = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_players(&mut self, v: u32) {
+        self.max_players = v;
+    }
+
+    // uint32 advance_count = 9;
+
+
+    pub fn get_advance_count(&self) -> u32 {
+        self.advance_count
+    }
+    pub fn clear_advance_count(&mut self) {
+        self.advance_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_advance_count(&mut self, v: u32) {
+        self.advance_count = v;
+    }
+
+    // uint32 player_count = 10;
+
+
+    pub fn get_player_count(&self) -> u32 {
+        self.player_count
+    }
+    pub fn clear_player_count(&mut self) {
+        self.player_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_count(&mut self, v: u32) {
+        self.player_count = v;
+    }
+
+    // uint32 round = 11;
+
+
+    pub fn get_round(&self) -> u32 {
+        self.round
+    }
+    pub fn clear_round(&mut self) {
+        self.round = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_round(&mut self, v: u32) {
+        self.round = v;
     }
 }
 
-impl ::protobuf::Message for WorldCellPt {
+impl ::protobuf::Message for TournamentPt {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1832,14 +5393,73 @@ impl ::protobuf::Message for WorldCellPt {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint32()?;
-                    self.index = tmp;
+                    self.tournament_id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.format = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.state = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.sign_up_start = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.sign_up_end = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start_time = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.max_players = tmp;
                 },
-                2 => {
+                9 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint32()?;
-                    self.world_cell_id = tmp;
+                    self.advance_count = tmp;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.player_count = tmp;
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.round = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1853,11 +5473,38 @@ impl ::protobuf::Message for WorldCellPt {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.index != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.index, ::protobuf::wire_format::WireTypeVarint);
+        if self.tournament_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.tournament_id, ::protobuf::wire_format::WireTypeVarint);
         }
-        if self.world_cell_id != 0 {
-            my_size += ::protobuf::rt::value_size(2, self.world_cell_id, ::protobuf::wire_format::WireTypeVarint);
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if self.format != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.format, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.state != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.state, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.sign_up_start != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.sign_up_start, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.sign_up_end != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.sign_up_end, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.start_time != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.start_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.max_players != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.max_players, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.advance_count != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.advance_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.player_count != 0 {
+            my_size += ::protobuf::rt::value_size(10, self.player_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.round != 0 {
+            my_size += ::protobuf::rt::value_size(11, self.round, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1865,11 +5512,38 @@ impl ::protobuf::Message for WorldCellPt {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if self.index != 0 {
-            os.write_uint32(1, self.index)?;
+        if self.tournament_id != 0 {
+            os.write_uint32(1, self.tournament_id)?;
         }
-        if self.world_cell_id != 0 {
-            os.write_uint32(2, self.world_cell_id)?;
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if self.format != 0 {
+            os.write_uint32(3, self.format)?;
+        }
+        if self.state != 0 {
+            os.write_uint32(4, self.state)?;
+        }
+        if self.sign_up_start != 0 {
+            os.write_uint64(5, self.sign_up_start)?;
+        }
+        if self.sign_up_end != 0 {
+            os.write_uint64(6, self.sign_up_end)?;
+        }
+        if self.start_time != 0 {
+            os.write_uint64(7, self.start_time)?;
+        }
+        if self.max_players != 0 {
+            os.write_uint32(8, self.max_players)?;
+        }
+        if self.advance_count != 0 {
+            os.write_uint32(9, self.advance_count)?;
+        }
+        if self.player_count != 0 {
+            os.write_uint32(10, self.player_count)?;
+        }
+        if self.round != 0 {
+            os.write_uint32(11, self.round)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1901,8 +5575,8 @@ impl ::protobuf::Message for WorldCellPt {
         Self::descriptor_static()
     }
 
-    fn new() -> WorldCellPt {
-        WorldCellPt::new()
+    fn new() -> TournamentPt {
+        TournamentPt::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1910,205 +5584,232 @@ impl ::protobuf::Message for WorldCellPt {
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                "index",
-                |m: &WorldCellPt| { &m.index },
-                |m: &mut WorldCellPt| { &mut m.index },
+                "tournament_id",
+                |m: &TournamentPt| { &m.tournament_id },
+                |m: &mut TournamentPt| { &mut m.tournament_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &TournamentPt| { &m.name },
+                |m: &mut TournamentPt| { &mut m.name },
             ));
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                "world_cell_id",
-                |m: &WorldCellPt| { &m.world_cell_id },
-                |m: &mut WorldCellPt| { &mut m.world_cell_id },
+                "format",
+                |m: &TournamentPt| { &m.format },
+                |m: &mut TournamentPt| { &mut m.format },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorldCellPt>(
-                "WorldCellPt",
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "state",
+                |m: &TournamentPt| { &m.state },
+                |m: &mut TournamentPt| { &mut m.state },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "sign_up_start",
+                |m: &TournamentPt| { &m.sign_up_start },
+                |m: &mut TournamentPt| { &mut m.sign_up_start },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "sign_up_end",
+                |m: &TournamentPt| { &m.sign_up_end },
+                |m: &mut TournamentPt| { &mut m.sign_up_end },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "start_time",
+                |m: &TournamentPt| { &m.start_time },
+                |m: &mut TournamentPt| { &mut m.start_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "max_players",
+                |m: &TournamentPt| { &m.max_players },
+                |m: &mut TournamentPt| { &mut m.max_players },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "advance_count",
+                |m: &TournamentPt| { &m.advance_count },
+                |m: &mut TournamentPt| { &mut m.advance_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "player_count",
+                |m: &TournamentPt| { &m.player_count },
+                |m: &mut TournamentPt| { &mut m.player_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "round",
+                |m: &TournamentPt| { &m.round },
+                |m: &mut TournamentPt| { &mut m.round },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<TournamentPt>(
+                "TournamentPt",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static WorldCellPt {
-        static instance: ::protobuf::rt::LazyV2<WorldCellPt> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(WorldCellPt::new)
+    fn default_instance() -> &'static TournamentPt {
+        static instance: ::protobuf::rt::LazyV2<TournamentPt> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(TournamentPt::new)
     }
 }
 
-impl ::protobuf::Clear for WorldCellPt {
+impl ::protobuf::Clear for TournamentPt {
     fn clear(&mut self) {
-        self.index = 0;
-        self.world_cell_id = 0;
+        self.tournament_id = 0;
+        self.name.clear();
+        self.format = 0;
+        self.state = 0;
+        self.sign_up_start = 0;
+        self.sign_up_end = 0;
+        self.start_time = 0;
+        self.max_players = 0;
+        self.advance_count = 0;
+        self.player_count = 0;
+        self.round = 0;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for WorldCellPt {
+impl ::std::fmt::Debug for TournamentPt {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for WorldCellPt {
+impl ::protobuf::reflect::ProtobufValue for TournamentPt {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct RoomPt {
+pub struct TournamentPlayerPt {
     // message fields
-    pub room_id: u32,
-    pub owner_id: u32,
-    pub room_type: u32,
-    pub room_status: u32,
-    pub setting: ::protobuf::SingularPtrField<RoomSettingPt>,
-    pub members: ::protobuf::RepeatedField<MemberPt>,
+    pub user_id: u32,
+    pub name: ::std::string::String,
+    pub seed: u32,
+    pub losses: u32,
+    pub eliminated: bool,
+    pub final_rank: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a RoomPt {
-    fn default() -> &'a RoomPt {
-        <RoomPt as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a TournamentPlayerPt {
+    fn default() -> &'a TournamentPlayerPt {
+        <TournamentPlayerPt as ::protobuf::Message>::default_instance()
     }
 }
 
-impl RoomPt {
-    pub fn new() -> RoomPt {
+impl TournamentPlayerPt {
+    pub fn new() -> TournamentPlayerPt {
         ::std::default::Default::default()
     }
 
-    // uint32 room_id = 1;
+    // uint32 user_id = 1;
 
 
-    pub fn get_room_id(&self) -> u32 {
-        self.room_id
+    pub fn get_user_id(&self) -> u32 {
+        self.user_id
     }
-    pub fn clear_room_id(&mut self) {
-        self.room_id = 0;
+    pub fn clear_user_id(&mut self) {
+        self.user_id = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_room_id(&mut self, v: u32) {
-        self.room_id = v;
+    pub fn set_user_id(&mut self, v: u32) {
+        self.user_id = v;
     }
 
-    // uint32 owner_id = 2;
+    // string name = 2;
 
 
-    pub fn get_owner_id(&self) -> u32 {
-        self.owner_id
+    pub fn get_name(&self) -> &str {
+        &self.name
     }
-    pub fn clear_owner_id(&mut self) {
-        self.owner_id = 0;
+    pub fn clear_name(&mut self) {
+        self.name.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_owner_id(&mut self, v: u32) {
-        self.owner_id = v;
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
     }
 
-    // uint32 room_type = 3;
-
-
-    pub fn get_room_type(&self) -> u32 {
-        self.room_type
-    }
-    pub fn clear_room_type(&mut self) {
-        self.room_type = 0;
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
     }
 
-    // Param is passed by value, moved
-    pub fn set_room_type(&mut self, v: u32) {
-        self.room_type = v;
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
     }
 
-    // uint32 room_status = 4;
+    // uint32 seed = 3;
 
 
-    pub fn get_room_status(&self) -> u32 {
-        self.room_status
+    pub fn get_seed(&self) -> u32 {
+        self.seed
     }
-    pub fn clear_room_status(&mut self) {
-        self.room_status = 0;
+    pub fn clear_seed(&mut self) {
+        self.seed = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_room_status(&mut self, v: u32) {
-        self.room_status = v;
+    pub fn set_seed(&mut self, v: u32) {
+        self.seed = v;
     }
 
-    // .protos.RoomSettingPt setting = 5;
+    // uint32 losses = 4;
 
 
-    pub fn get_setting(&self) -> &RoomSettingPt {
-        self.setting.as_ref().unwrap_or_else(|| <RoomSettingPt as ::protobuf::Message>::default_instance())
+    pub fn get_losses(&self) -> u32 {
+        self.losses
     }
-    pub fn clear_setting(&mut self) {
-        self.setting.clear();
-    }
-
-    pub fn has_setting(&self) -> bool {
-        self.setting.is_some()
+    pub fn clear_losses(&mut self) {
+        self.losses = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_setting(&mut self, v: RoomSettingPt) {
-        self.setting = ::protobuf::SingularPtrField::some(v);
+    pub fn set_losses(&mut self, v: u32) {
+        self.losses = v;
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_setting(&mut self) -> &mut RoomSettingPt {
-        if self.setting.is_none() {
-            self.setting.set_default();
-        }
-        self.setting.as_mut().unwrap()
-    }
+    // bool eliminated = 5;
 
-    // Take field
-    pub fn take_setting(&mut self) -> RoomSettingPt {
-        self.setting.take().unwrap_or_else(|| RoomSettingPt::new())
-    }
 
-    // repeated .protos.MemberPt members = 6;
-
-
-    pub fn get_members(&self) -> &[MemberPt] {
-        &self.members
+    pub fn get_eliminated(&self) -> bool {
+        self.eliminated
     }
-    pub fn clear_members(&mut self) {
-        self.members.clear();
+    pub fn clear_eliminated(&mut self) {
+        self.eliminated = false;
     }
 
     // Param is passed by value, moved
-    pub fn set_members(&mut self, v: ::protobuf::RepeatedField<MemberPt>) {
-        self.members = v;
+    pub fn set_eliminated(&mut self, v: bool) {
+        self.eliminated = v;
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_members(&mut self) -> &mut ::protobuf::RepeatedField<MemberPt> {
-        &mut self.members
+    // uint32 final_rank = 6;
+
+
+    pub fn get_final_rank(&self) -> u32 {
+        self.final_rank
+    }
+    pub fn clear_final_rank(&mut self) {
+        self.final_rank = 0;
     }
 
-    // Take field
-    pub fn take_members(&mut self) -> ::protobuf::RepeatedField<MemberPt> {
-        ::std::mem::replace(&mut self.members, ::protobuf::RepeatedField::new())
+    // Param is passed by value, moved
+    pub fn set_final_rank(&mut self, v: u32) {
+        self.final_rank = v;
     }
 }
 
-impl ::protobuf::Message for RoomPt {
+impl ::protobuf::Message for TournamentPlayerPt {
     fn is_initialized(&self) -> bool {
-        for v in &self.setting {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.members {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -2121,34 +5822,38 @@ impl ::protobuf::Message for RoomPt {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint32()?;
-                    self.room_id = tmp;
+                    self.user_id = tmp;
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.owner_id = tmp;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
                 },
                 3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint32()?;
-                    self.room_type = tmp;
+                    self.seed = tmp;
                 },
                 4 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint32()?;
-                    self.room_status = tmp;
+                    self.losses = tmp;
                 },
                 5 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.setting)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.eliminated = tmp;
                 },
                 6 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.members)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.final_rank = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -2162,54 +5867,48 @@ impl ::protobuf::Message for RoomPt {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.room_id != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.room_id, ::protobuf::wire_format::WireTypeVarint);
+        if self.user_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.user_id, ::protobuf::wire_format::WireTypeVarint);
         }
-        if self.owner_id != 0 {
-            my_size += ::protobuf::rt::value_size(2, self.owner_id, ::protobuf::wire_format::WireTypeVarint);
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
         }
-        if self.room_type != 0 {
-            my_size += ::protobuf::rt::value_size(3, self.room_type, ::protobuf::wire_format::WireTypeVarint);
+        if self.seed != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.seed, ::protobuf::wire_format::WireTypeVarint);
         }
-        if self.room_status != 0 {
-            my_size += ::protobuf::rt::value_size(4, self.room_status, ::protobuf::wire_format::WireTypeVarint);
+        if self.losses != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.losses, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(ref v) = self.setting.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        if self.eliminated != false {
+            my_size += 2;
+        }
+        if self.final_rank != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.final_rank, ::protobuf::wire_format::WireTypeVarint);
         }
-        for value in &self.members {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if self.room_id != 0 {
-            os.write_uint32(1, self.room_id)?;
+        if self.user_id != 0 {
+            os.write_uint32(1, self.user_id)?;
         }
-        if self.owner_id != 0 {
-            os.write_uint32(2, self.owner_id)?;
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
         }
-        if self.room_type != 0 {
-            os.write_uint32(3, self.room_type)?;
+        if self.seed != 0 {
+            os.write_uint32(3, self.seed)?;
         }
-        if self.room_status != 0 {
-            os.write_uint32(4, self.room_status)?;
+        if self.losses != 0 {
+            os.write_uint32(4, self.losses)?;
         }
-        if let Some(ref v) = self.setting.as_ref() {
-            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if self.eliminated != false {
+            os.write_bool(5, self.eliminated)?;
+        }
+        if self.final_rank != 0 {
+            os.write_uint32(6, self.final_rank)?;
         }
-        for v in &self.members {
-            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2240,8 +5939,8 @@ impl ::protobuf::Message for RoomPt {
         Self::descriptor_static()
     }
 
-    fn new() -> RoomPt {
-        RoomPt::new()
+    fn new() -> TournamentPlayerPt {
+        TournamentPlayerPt::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -2249,209 +5948,210 @@ impl ::protobuf::Message for RoomPt {
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                "room_id",
-                |m: &RoomPt| { &m.room_id },
-                |m: &mut RoomPt| { &mut m.room_id },
+                "user_id",
+                |m: &TournamentPlayerPt| { &m.user_id },
+                |m: &mut TournamentPlayerPt| { &mut m.user_id },
             ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                "owner_id",
-                |m: &RoomPt| { &m.owner_id },
-                |m: &mut RoomPt| { &mut m.owner_id },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &TournamentPlayerPt| { &m.name },
+                |m: &mut TournamentPlayerPt| { &mut m.name },
             ));
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                "room_type",
-                |m: &RoomPt| { &m.room_type },
-                |m: &mut RoomPt| { &mut m.room_type },
+                "seed",
+                |m: &TournamentPlayerPt| { &m.seed },
+                |m: &mut TournamentPlayerPt| { &mut m.seed },
             ));
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                "room_status",
-                |m: &RoomPt| { &m.room_status },
-                |m: &mut RoomPt| { &mut m.room_status },
+                "losses",
+                |m: &TournamentPlayerPt| { &m.losses },
+                |m: &mut TournamentPlayerPt| { &mut m.losses },
             ));
-            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RoomSettingPt>>(
-                "setting",
-                |m: &RoomPt| { &m.setting },
-                |m: &mut RoomPt| { &mut m.setting },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "eliminated",
+                |m: &TournamentPlayerPt| { &m.eliminated },
+                |m: &mut TournamentPlayerPt| { &mut m.eliminated },
             ));
-            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<MemberPt>>(
-                "members",
-                |m: &RoomPt| { &m.members },
-                |m: &mut RoomPt| { &mut m.members },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "final_rank",
+                |m: &TournamentPlayerPt| { &m.final_rank },
+                |m: &mut TournamentPlayerPt| { &mut m.final_rank },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RoomPt>(
-                "RoomPt",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<TournamentPlayerPt>(
+                "TournamentPlayerPt",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static RoomPt {
-        static instance: ::protobuf::rt::LazyV2<RoomPt> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(RoomPt::new)
+    fn default_instance() -> &'static TournamentPlayerPt {
+        static instance: ::protobuf::rt::LazyV2<TournamentPlayerPt> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(TournamentPlayerPt::new)
     }
 }
 
-impl ::protobuf::Clear for RoomPt {
+impl ::protobuf::Clear for TournamentPlayerPt {
     fn clear(&mut self) {
-        self.room_id = 0;
-        self.owner_id = 0;
-        self.room_type = 0;
-        self.room_status = 0;
-        self.setting.clear();
-        self.members.clear();
+        self.user_id = 0;
+        self.name.clear();
+        self.seed = 0;
+        self.losses = 0;
+        self.eliminated = false;
+        self.final_rank = 0;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for RoomPt {
+impl ::std::fmt::Debug for TournamentPlayerPt {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for RoomPt {
+impl ::protobuf::reflect::ProtobufValue for TournamentPlayerPt {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct HistoryMessPt {
+pub struct TournamentMatchPt {
+    // message fields
+    pub match_id: u32,
+    pub round: u32,
+    pub bracket: u32,
+    pub state: u32,
+    pub players: ::std::vec::Vec<u32>,
+    pub ranks: ::std::vec::Vec<u32>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a HistoryMessPt {
-    fn default() -> &'a HistoryMessPt {
-        <HistoryMessPt as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a TournamentMatchPt {
+    fn default() -> &'a TournamentMatchPt {
+        <TournamentMatchPt as ::protobuf::Message>::default_instance()
     }
 }
 
-impl HistoryMessPt {
-    pub fn new() -> HistoryMessPt {
+impl TournamentMatchPt {
+    pub fn new() -> TournamentMatchPt {
         ::std::default::Default::default()
     }
-}
 
-impl ::protobuf::Message for HistoryMessPt {
-    fn is_initialized(&self) -> bool {
-        true
-    }
+    // uint32 match_id = 1;
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
-                },
-            };
-        }
-        ::std::result::Result::Ok(())
-    }
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
+    pub fn get_match_id(&self) -> u32 {
+        self.match_id
     }
-
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        os.write_unknown_fields(self.get_unknown_fields())?;
-        ::std::result::Result::Ok(())
+    pub fn clear_match_id(&mut self) {
+        self.match_id = 0;
     }
 
-    fn get_cached_size(&self) -> u32 {
-        self.cached_size.get()
+    // Param is passed by value, moved
+    pub fn set_match_id(&mut self, v: u32) {
+        self.match_id = v;
     }
 
-    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
-        &self.unknown_fields
+    // uint32 round = 2;
+
+
+    pub fn get_round(&self) -> u32 {
+        self.round
+    }
+    pub fn clear_round(&mut self) {
+        self.round = 0;
     }
 
-    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
-        &mut self.unknown_fields
+    // Param is passed by value, moved
+    pub fn set_round(&mut self, v: u32) {
+        self.round = v;
     }
 
-    fn as_any(&self) -> &dyn (::std::any::Any) {
-        self as &dyn (::std::any::Any)
+    // uint32 bracket = 3;
+
+
+    pub fn get_bracket(&self) -> u32 {
+        self.bracket
     }
-    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
-        self as &mut dyn (::std::any::Any)
+    pub fn clear_bracket(&mut self) {
+        self.bracket = 0;
     }
-    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
-        self
+
+    // Param is passed by value, moved
+    pub fn set_bracket(&mut self, v: u32) {
+        self.bracket = v;
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        Self::descriptor_static()
+    // uint32 state = 4;
+
+
+    pub fn get_state(&self) -> u32 {
+        self.state
+    }
+    pub fn clear_state(&mut self) {
+        self.state = 0;
     }
 
-    fn new() -> HistoryMessPt {
-        HistoryMessPt::new()
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: u32) {
+        self.state = v;
     }
 
-    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
-        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
-        descriptor.get(|| {
-            let fields = ::std::vec::Vec::new();
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<HistoryMessPt>(
-                "HistoryMessPt",
-                fields,
-                file_descriptor_proto()
-            )
-        })
+    // repeated uint32 players = 5;
+
+
+    pub fn get_players(&self) -> &[u32] {
+        &self.players
+    }
+    pub fn clear_players(&mut self) {
+        self.players.clear();
     }
 
-    fn default_instance() -> &'static HistoryMessPt {
-        static instance: ::protobuf::rt::LazyV2<HistoryMessPt> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(HistoryMessPt::new)
+    // Param is passed by value, moved
+    pub fn set_players(&mut self, v: ::std::vec::Vec<u32>) {
+        self.players = v;
     }
-}
 
-impl ::protobuf::Clear for HistoryMessPt {
-    fn clear(&mut self) {
-        self.unknown_fields.clear();
+    // Mutable pointer to the field.
+    pub fn mut_players(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.players
     }
-}
 
-impl ::std::fmt::Debug for HistoryMessPt {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+    // Take field
+    pub fn take_players(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.players, ::std::vec::Vec::new())
     }
-}
 
-impl ::protobuf::reflect::ProtobufValue for HistoryMessPt {
-    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
-        ::protobuf::reflect::ReflectValueRef::Message(self)
+    // repeated uint32 ranks = 6;
+
+
+    pub fn get_ranks(&self) -> &[u32] {
+        &self.ranks
+    }
+    pub fn clear_ranks(&mut self) {
+        self.ranks.clear();
     }
-}
 
-#[derive(PartialEq,Clone,Default)]
-pub struct NoticeMessPt {
-    // special fields
-    pub unknown_fields: ::protobuf::UnknownFields,
-    pub cached_size: ::protobuf::CachedSize,
-}
+    // Param is passed by value, moved
+    pub fn set_ranks(&mut self, v: ::std::vec::Vec<u32>) {
+        self.ranks = v;
+    }
 
-impl<'a> ::std::default::Default for &'a NoticeMessPt {
-    fn default() -> &'a NoticeMessPt {
-        <NoticeMessPt as ::protobuf::Message>::default_instance()
+    // Mutable pointer to the field.
+    pub fn mut_ranks(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.ranks
     }
-}
 
-impl NoticeMessPt {
-    pub fn new() -> NoticeMessPt {
-        ::std::default::Default::default()
+    // Take field
+    pub fn take_ranks(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.ranks, ::std::vec::Vec::new())
     }
 }
 
-impl ::protobuf::Message for NoticeMessPt {
+impl ::protobuf::Message for TournamentMatchPt {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -2460,6 +6160,40 @@ impl ::protobuf::Message for NoticeMessPt {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.match_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.round = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.bracket = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.state = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.players)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.ranks)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2472,12 +6206,48 @@ impl ::protobuf::Message for NoticeMessPt {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
+        if self.match_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.match_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.round != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.round, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.bracket != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.bracket, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.state != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.state, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.players {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.ranks {
+            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.match_id != 0 {
+            os.write_uint32(1, self.match_id)?;
+        }
+        if self.round != 0 {
+            os.write_uint32(2, self.round)?;
+        }
+        if self.bracket != 0 {
+            os.write_uint32(3, self.bracket)?;
+        }
+        if self.state != 0 {
+            os.write_uint32(4, self.state)?;
+        }
+        for v in &self.players {
+            os.write_uint32(5, *v)?;
+        };
+        for v in &self.ranks {
+            os.write_uint32(6, *v)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2508,41 +6278,77 @@ impl ::protobuf::Message for NoticeMessPt {
         Self::descriptor_static()
     }
 
-    fn new() -> NoticeMessPt {
-        NoticeMessPt::new()
+    fn new() -> TournamentMatchPt {
+        TournamentMatchPt::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
-            let fields = ::std::vec::Vec::new();
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<NoticeMessPt>(
-                "NoticeMessPt",
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "match_id",
+                |m: &TournamentMatchPt| { &m.match_id },
+                |m: &mut TournamentMatchPt| { &mut m.match_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "round",
+                |m: &TournamentMatchPt| { &m.round },
+                |m: &mut TournamentMatchPt| { &mut m.round },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "bracket",
+                |m: &TournamentMatchPt| { &m.bracket },
+                |m: &mut TournamentMatchPt| { &mut m.bracket },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "state",
+                |m: &TournamentMatchPt| { &m.state },
+                |m: &mut TournamentMatchPt| { &mut m.state },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "players",
+                |m: &TournamentMatchPt| { &m.players },
+                |m: &mut TournamentMatchPt| { &mut m.players },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "ranks",
+                |m: &TournamentMatchPt| { &m.ranks },
+                |m: &mut TournamentMatchPt| { &mut m.ranks },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<TournamentMatchPt>(
+                "TournamentMatchPt",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static NoticeMessPt {
-        static instance: ::protobuf::rt::LazyV2<NoticeMessPt> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(NoticeMessPt::new)
+    fn default_instance() -> &'static TournamentMatchPt {
+        static instance: ::protobuf::rt::LazyV2<TournamentMatchPt> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(TournamentMatchPt::new)
     }
 }
 
-impl ::protobuf::Clear for NoticeMessPt {
+impl ::protobuf::Clear for TournamentMatchPt {
     fn clear(&mut self) {
+        self.match_id = 0;
+        self.round = 0;
+        self.bracket = 0;
+        self.state = 0;
+        self.players.clear();
+        self.ranks.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for NoticeMessPt {
+impl ::std::fmt::Debug for TournamentMatchPt {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for NoticeMessPt {
+impl ::protobuf::reflect::ProtobufValue for TournamentMatchPt {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
@@ -3809,6 +7615,8 @@ pub struct BattlePlayerPt {
     pub is_robot: bool,
     pub is_died: bool,
     pub cters: ::protobuf::RepeatedField<BattleCharacterPt>,
+    pub is_auto: bool,
+    pub reserve_time: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -4046,6 +7854,36 @@ impl BattlePlayerPt {
     pub fn take_cters(&mut self) -> ::protobuf::RepeatedField<BattleCharacterPt> {
         ::std::mem::replace(&mut self.cters, ::protobuf::RepeatedField::new())
     }
+
+    // bool is_auto = 12;
+
+
+    pub fn get_is_auto(&self) -> bool {
+        self.is_auto
+    }
+    pub fn clear_is_auto(&mut self) {
+        self.is_auto = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_auto(&mut self, v: bool) {
+        self.is_auto = v;
+    }
+
+    // uint32 reserve_time = 13;
+
+
+    pub fn get_reserve_time(&self) -> u32 {
+        self.reserve_time
+    }
+    pub fn clear_reserve_time(&mut self) {
+        self.reserve_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reserve_time(&mut self, v: u32) {
+        self.reserve_time = v;
+    }
 }
 
 impl ::protobuf::Message for BattlePlayerPt {
@@ -4133,6 +7971,20 @@ impl ::protobuf::Message for BattlePlayerPt {
                 11 => {
                     ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cters)?;
                 },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_auto = tmp;
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.reserve_time = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -4181,6 +8033,12 @@ impl ::protobuf::Message for BattlePlayerPt {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        if self.is_auto != false {
+            my_size += 2;
+        }
+        if self.reserve_time != 0 {
+            my_size += ::protobuf::rt::value_size(13, self.reserve_time, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -4226,6 +8084,12 @@ impl ::protobuf::Message for BattlePlayerPt {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         };
+        if self.is_auto != false {
+            os.write_bool(12, self.is_auto)?;
+        }
+        if self.reserve_time != 0 {
+            os.write_uint32(13, self.reserve_time)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4319,6 +8183,16 @@ impl ::protobuf::Message for BattlePlayerPt {
                 |m: &BattlePlayerPt| { &m.cters },
                 |m: &mut BattlePlayerPt| { &mut m.cters },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_auto",
+                |m: &BattlePlayerPt| { &m.is_auto },
+                |m: &mut BattlePlayerPt| { &mut m.is_auto },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "reserve_time",
+                |m: &BattlePlayerPt| { &m.reserve_time },
+                |m: &mut BattlePlayerPt| { &mut m.reserve_time },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<BattlePlayerPt>(
                 "BattlePlayerPt",
                 fields,
@@ -4346,6 +8220,8 @@ impl ::protobuf::Clear for BattlePlayerPt {
         self.is_robot = false;
         self.is_died = false;
         self.cters.clear();
+        self.is_auto = false;
+        self.reserve_time = 0;
         self.unknown_fields.clear();
     }
 }
@@ -6071,6 +9947,8 @@ pub struct SummaryDataPt {
     pub rank: u32,
     pub reward_score: i32,
     pub league: ::protobuf::SingularPtrField<LeaguePt>,
+    pub kills: u32,
+    pub events: ::protobuf::RepeatedField<BattleEventPt>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -6220,6 +10098,46 @@ impl SummaryDataPt {
     pub fn take_league(&mut self) -> LeaguePt {
         self.league.take().unwrap_or_else(|| LeaguePt::new())
     }
+
+    // uint32 kills = 8;
+
+
+    pub fn get_kills(&self) -> u32 {
+        self.kills
+    }
+    pub fn clear_kills(&mut self) {
+        self.kills = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kills(&mut self, v: u32) {
+        self.kills = v;
+    }
+
+    // repeated .protos.BattleEventPt events = 9;
+
+
+    pub fn get_events(&self) -> &[BattleEventPt] {
+        &self.events
+    }
+    pub fn clear_events(&mut self) {
+        self.events.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_events(&mut self, v: ::protobuf::RepeatedField<BattleEventPt>) {
+        self.events = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_events(&mut self) -> &mut ::protobuf::RepeatedField<BattleEventPt> {
+        &mut self.events
+    }
+
+    // Take field
+    pub fn take_events(&mut self) -> ::protobuf::RepeatedField<BattleEventPt> {
+        ::std::mem::replace(&mut self.events, ::protobuf::RepeatedField::new())
+    }
 }
 
 impl ::protobuf::Message for SummaryDataPt {
@@ -6229,6 +10147,11 @@ impl ::protobuf::Message for SummaryDataPt {
                 return false;
             }
         };
+        for v in &self.events {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -6277,6 +10200,16 @@ impl ::protobuf::Message for SummaryDataPt {
                 7 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.league)?;
                 },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.kills = tmp;
+                },
+                9 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.events)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -6311,6 +10244,13 @@ impl ::protobuf::Message for SummaryDataPt {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if self.kills != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.kills, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.events {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -6340,6 +10280,14 @@ impl ::protobuf::Message for SummaryDataPt {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if self.kills != 0 {
+            os.write_uint32(8, self.kills)?;
+        }
+        for v in &self.events {
+            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -6413,6 +10361,16 @@ impl ::protobuf::Message for SummaryDataPt {
                 |m: &SummaryDataPt| { &m.league },
                 |m: &mut SummaryDataPt| { &mut m.league },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "kills",
+                |m: &SummaryDataPt| { &m.kills },
+                |m: &mut SummaryDataPt| { &mut m.kills },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BattleEventPt>>(
+                "events",
+                |m: &SummaryDataPt| { &m.events },
+                |m: &mut SummaryDataPt| { &mut m.events },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<SummaryDataPt>(
                 "SummaryDataPt",
                 fields,
@@ -6436,6 +10394,8 @@ impl ::protobuf::Clear for SummaryDataPt {
         self.rank = 0;
         self.reward_score = 0;
         self.league.clear();
+        self.kills = 0;
+        self.events.clear();
         self.unknown_fields.clear();
     }
 }
@@ -6452,6 +10412,193 @@ impl ::protobuf::reflect::ProtobufValue for SummaryDataPt {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct BattleEventPt {
+    // message fields
+    pub event_type: u32,
+    pub value: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BattleEventPt {
+    fn default() -> &'a BattleEventPt {
+        <BattleEventPt as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BattleEventPt {
+    pub fn new() -> BattleEventPt {
+        ::std::default::Default::default()
+    }
+
+    // uint32 event_type = 1;
+
+
+    pub fn get_event_type(&self) -> u32 {
+        self.event_type
+    }
+    pub fn clear_event_type(&mut self) {
+        self.event_type = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_event_type(&mut self, v: u32) {
+        self.event_type = v;
+    }
+
+    // uint32 value = 2;
+
+
+    pub fn get_value(&self) -> u32 {
+        self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: u32) {
+        self.value = v;
+    }
+}
+
+impl ::protobuf::Message for BattleEventPt {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.event_type = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.value = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.event_type != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.event_type, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.value != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.value, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.event_type != 0 {
+            os.write_uint32(1, self.event_type)?;
+        }
+        if self.value != 0 {
+            os.write_uint32(2, self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BattleEventPt {
+        BattleEventPt::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "event_type",
+                |m: &BattleEventPt| { &m.event_type },
+                |m: &mut BattleEventPt| { &mut m.event_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "value",
+                |m: &BattleEventPt| { &m.value },
+                |m: &mut BattleEventPt| { &mut m.value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BattleEventPt>(
+                "BattleEventPt",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BattleEventPt {
+        static instance: ::protobuf::rt::LazyV2<BattleEventPt> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BattleEventPt::new)
+    }
+}
+
+impl ::protobuf::Clear for BattleEventPt {
+    fn clear(&mut self) {
+        self.event_type = 0;
+        self.value = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BattleEventPt {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BattleEventPt {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct CterStatusPt {
     // message fields
@@ -7667,108 +11814,168 @@ impl ::protobuf::reflect::ProtobufValue for RankInfoPt {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\nbase.proto\x12\x06protos\"x\n\rRoomSettingPt\x12&\n\x0fturn_limit_ti\
-    me\x18\x01\x20\x01(\rR\rturnLimitTime\x12$\n\x0eseason_is_open\x18\x02\
-    \x20\x01(\x08R\x0cseasonIsOpen\x12\x19\n\x08ai_level\x18\x03\x20\x01(\rR\
-    \x07aiLevel\"\xac\x02\n\x08PlayerPt\x12\x1b\n\tnick_name\x18\x01\x20\x01\
-    (\tR\x08nickName\x12%\n\x0elast_character\x18\x02\x20\x01(\rR\rlastChara\
-    cter\x128\n\x0cpunish_match\x18\x03\x20\x01(\x0b2\x15.protos.PunishMatch\
-    PtR\x0bpunishMatch\x12\x14\n\x05grade\x18\x04\x20\x01(\rR\x05grade\x12\
-    \x1f\n\x0bgrade_frame\x18\x05\x20\x01(\rR\ngradeFrame\x12\x12\n\x04soul\
-    \x18\x06\x20\x01(\rR\x04soul\x12\x1b\n\tbest_rank\x18\x07\x20\x01(\x05R\
-    \x08bestRank\x12(\n\x06league\x18\x08\x20\x01(\x0b2\x10.protos.LeaguePtR\
-    \x06league\x12\x10\n\x03dlc\x18\t\x20\x03(\rR\x03dlc\"a\n\x08SeasonPt\
-    \x12\x1b\n\tseason_id\x18\x01\x20\x01(\rR\x08seasonId\x12\x1d\n\nstart_t\
-    ime\x18\x02\x20\x01(\x04R\tstartTime\x12\x19\n\x08end_time\x18\x03\x20\
-    \x01(\x04R\x07endTime\"k\n\x0bWorldBossPt\x12\"\n\rworld_boss_id\x18\x01\
-    \x20\x01(\rR\x0bworldBossId\x12\x1d\n\nstart_time\x18\x02\x20\x01(\x04R\
-    \tstartTime\x12\x19\n\x08end_time\x18\x03\x20\x01(\x04R\x07endTime\"\xe4\
-    \x02\n\x08MemberPt\x12\x17\n\x07user_id\x18\x01\x20\x01(\rR\x06userId\
-    \x12\x1b\n\tnick_name\x18\x02\x20\x01(\tR\x08nickName\x12'\n\x04cter\x18\
-    \x03\x20\x01(\x0b2\x13.protos.CharacterPtR\x04cter\x12\x14\n\x05state\
-    \x18\x04\x20\x01(\rR\x05state\x12\x14\n\x05grade\x18\x05\x20\x01(\rR\x05\
-    grade\x12\x1f\n\x0bgrade_frame\x18\x06\x20\x01(\rR\ngradeFrame\x12\x12\n\
-    \x04soul\x18\x07\x20\x01(\rR\x04soul\x12\x17\n\x07team_id\x18\x08\x20\
-    \x01(\rR\x06teamId\x12\x1b\n\tjoin_time\x18\t\x20\x01(\x04R\x08joinTime\
-    \x12(\n\x06league\x18\n\x20\x01(\x0b2\x10.protos.LeaguePtR\x06league\x12\
-    \"\n\rrobot_temp_id\x18\x0b\x20\x01(\rR\x0brobotTempId\x12\x14\n\x05inde\
-    x\x18\x0c\x20\x01(\rR\x05index\"G\n\x0bWorldCellPt\x12\x14\n\x05index\
-    \x18\x01\x20\x01(\rR\x05index\x12\"\n\rworld_cell_id\x18\x02\x20\x01(\rR\
-    \x0bworldCellId\"\xd7\x01\n\x06RoomPt\x12\x17\n\x07room_id\x18\x01\x20\
-    \x01(\rR\x06roomId\x12\x19\n\x08owner_id\x18\x02\x20\x01(\rR\x07ownerId\
-    \x12\x1b\n\troom_type\x18\x03\x20\x01(\rR\x08roomType\x12\x1f\n\x0broom_\
-    status\x18\x04\x20\x01(\rR\nroomStatus\x12/\n\x07setting\x18\x05\x20\x01\
-    (\x0b2\x15.protos.RoomSettingPtR\x07setting\x12*\n\x07members\x18\x06\
-    \x20\x03(\x0b2\x10.protos.MemberPtR\x07members\"\x0f\n\rHistoryMessPt\"\
-    \x0e\n\x0cNoticeMessPt\"o\n\x0bCharacterPt\x12\x20\n\x0ccter_temp_id\x18\
-    \x01\x20\x01(\rR\ncterTempId\x12&\n\x0flast_use_skills\x18\x02\x20\x03(\
-    \rR\rlastUseSkills\x12\x16\n\x06skills\x18\x03\x20\x03(\rR\x06skills\"Q\
-    \n\x07SkillPt\x12\x19\n\x08skill_id\x18\x01\x20\x01(\rR\x07skillId\x12\
-    \x0e\n\x02cd\x18\x02\x20\x01(\rR\x02cd\x12\x1b\n\tis_active\x18\x03\x20\
-    \x01(\x08R\x08isActive\"\xd9\x02\n\x11BattleCharacterPt\x12\x17\n\x07use\
-    r_id\x18\x01\x20\x01(\rR\x06userId\x12\x17\n\x07cter_id\x18\x02\x20\x01(\
-    \rR\x06cterId\x12\x20\n\x0ccter_temp_id\x18\x03\x20\x01(\rR\ncterTempId\
-    \x12\x10\n\x03atk\x18\x04\x20\x01(\rR\x03atk\x12\x0e\n\x02hp\x18\x05\x20\
-    \x01(\rR\x02hp\x12\x18\n\x07defence\x18\x06\x20\x01(\rR\x07defence\x12\
-    \x16\n\x06energy\x18\x07\x20\x01(\rR\x06energy\x12\x14\n\x05index\x18\
-    \x08\x20\x01(\rR\x05index\x12\x19\n\x08is_major\x18\t\x20\x01(\x08R\x07i\
-    sMajor\x12\x14\n\x05buffs\x18\n\x20\x03(\rR\x05buffs\x12'\n\x06skills\
-    \x18\x0b\x20\x03(\x0b2\x0f.protos.SkillPtR\x06skills\x12\x14\n\x05items\
-    \x18\x0c\x20\x03(\rR\x05items\x12\x16\n\x06minons\x18\r\x20\x03(\rR\x06m\
-    inons\":\n\tIndexCter\x12\x17\n\x07cter_id\x18\x01\x20\x01(\rR\x06cterId\
-    \x12\x14\n\x05index\x18\x02\x20\x01(\rR\x05index\"\xe5\x02\n\x0eBattlePl\
-    ayerPt\x12\x17\n\x07user_id\x18\x01\x20\x01(\rR\x06userId\x12\x12\n\x04n\
-    ame\x18\x02\x20\x01(\tR\x04name\x12(\n\x06league\x18\x03\x20\x01(\x0b2\
-    \x10.protos.LeaguePtR\x06league\x12\x12\n\x04gold\x18\x04\x20\x01(\rR\
-    \x04gold\x12\x14\n\x05grade\x18\x05\x20\x01(\rR\x05grade\x12\x1d\n\nmajo\
-    r_cter\x18\x06\x20\x01(\rR\tmajorCter\x12!\n\x0ccurrent_cter\x18\x07\x20\
-    \x01(\rR\x0bcurrentCter\x12+\n\x07mission\x18\x08\x20\x01(\x0b2\x11.prot\
-    os.MissionPtR\x07mission\x12\x19\n\x08is_robot\x18\t\x20\x01(\x08R\x07is\
-    Robot\x12\x17\n\x07is_died\x18\n\x20\x01(\x08R\x06isDied\x12/\n\x05cters\
-    \x18\x0b\x20\x03(\x0b2\x19.protos.BattleCharacterPtR\x05cters\"b\n\tMiss\
-    ionPt\x12\x1d\n\nmission_id\x18\x01\x20\x01(\rR\tmissionId\x12\x1a\n\x08\
-    progress\x18\x02\x20\x01(\rR\x08progress\x12\x1a\n\x08complete\x18\x03\
-    \x20\x01(\x08R\x08complete\"N\n\x08EffectPt\x12\x1f\n\x0beffect_type\x18\
-    \x02\x20\x01(\rR\neffectType\x12!\n\x0ceffect_value\x18\x03\x20\x01(\rR\
-    \x0beffectValue\"\xed\x02\n\x08TargetPt\x12!\n\x0ctarget_value\x18\x01\
-    \x20\x03(\rR\x0btargetValue\x12*\n\x07effects\x18\x02\x20\x03(\x0b2\x10.\
-    protos.EffectPtR\x07effects\x12\x1b\n\tadd_buffs\x18\x03\x20\x03(\rR\x08\
-    addBuffs\x12=\n\rpassiveEffect\x18\x04\x20\x03(\x0b2\x17.protos.TriggerE\
-    ffectPtR\rpassiveEffect\x12\x1d\n\nlost_buffs\x18\x05\x20\x03(\rR\tlostB\
-    uffs\x12\x1f\n\x0blost_skills\x18\x06\x20\x03(\rR\nlostSkills\x12@\n\x0e\
-    transform_cter\x18\x07\x20\x01(\x0b2\x19.protos.BattleCharacterPtR\rtran\
-    sformCter\x124\n\x08new_cter\x18\x08\x20\x01(\x0b2\x19.protos.BattleChar\
-    acterPtR\x07newCter\"T\n\x0fTriggerEffectPt\x12\x17\n\x07buff_id\x18\x01\
-    \x20\x01(\rR\x06buffId\x12\x12\n\x04type\x18\x02\x20\x01(\rR\x04type\x12\
-    \x14\n\x05value\x18\x03\x20\x01(\rR\x05value\"\xed\x01\n\x0cActionUnitPt\
-    \x12\x1b\n\tfrom_cter\x18\x01\x20\x01(\rR\x08fromCter\x12\x1f\n\x0bactio\
-    n_type\x18\x02\x20\x01(\rR\nactionType\x12!\n\x0caction_value\x18\x03\
-    \x20\x03(\rR\x0bactionValue\x12*\n\x07targets\x18\x04\x20\x03(\x0b2\x10.\
-    protos.TargetPtR\x07targets\x12\x1d\n\nlost_buffs\x18\x05\x20\x03(\rR\tl\
-    ostBuffs\x121\n\x15is_reward_move_points\x18\x06\x20\x01(\x08R\x12isRewa\
-    rdMovePoints\"i\n\x06BuffPt\x12\x17\n\x07buff_id\x18\x01\x20\x01(\rR\x06\
-    buffId\x12'\n\x0ftrigger_timesed\x18\x02\x20\x01(\rR\x0etriggerTimesed\
-    \x12\x1d\n\nkeep_times\x18\x03\x20\x01(\rR\tkeepTimes\"\xcc\x01\n\rSumma\
-    ryDataPt\x12\x17\n\x07user_id\x18\x01\x20\x01(\rR\x06userId\x12\x12\n\
-    \x04name\x18\x02\x20\x01(\tR\x04name\x12\x17\n\x07cter_id\x18\x03\x20\
-    \x01(\rR\x06cterId\x12\x14\n\x05grade\x18\x04\x20\x01(\rR\x05grade\x12\
-    \x12\n\x04rank\x18\x05\x20\x01(\rR\x04rank\x12!\n\x0creward_score\x18\
-    \x06\x20\x01(\x05R\x0brewardScore\x12(\n\x06league\x18\x07\x20\x01(\x0b2\
-    \x10.protos.LeaguePtR\x06league\"e\n\x0cCterStatusPt\x12\x17\n\x07user_i\
-    d\x18\x01\x20\x01(\rR\x06userId\x12\x16\n\x06skills\x18\x02\x20\x03(\rR\
-    \x06skills\x12$\n\x05buffs\x18\x03\x20\x03(\x0b2\x0e.protos.BuffPtR\x05b\
-    uffs\"H\n\nCellBuffPt\x12\x14\n\x05index\x18\x01\x20\x01(\rR\x05index\
-    \x12$\n\x05buffs\x18\x02\x20\x03(\x0b2\x0e.protos.BuffPtR\x05buffs\"f\n\
-    \rPunishMatchPt\x12\x1d\n\nstart_time\x18\x01\x20\x01(\x03R\tstartTime\
-    \x12\x1b\n\tpunish_id\x18\x02\x20\x01(\rR\x08punishId\x12\x19\n\x08today\
-    _id\x18\x03\x20\x01(\rR\x07todayId\"k\n\x08LeaguePt\x12!\n\x0cleague_sco\
-    re\x18\x01\x20\x01(\x05R\x0bleagueScore\x12\x1b\n\tleague_id\x18\x02\x20\
-    \x01(\x05R\x08leagueId\x12\x1f\n\x0bleague_time\x18\x03\x20\x01(\x03R\nl\
-    eagueTime\"\x8d\x01\n\nRankInfoPt\x12\x17\n\x07user_id\x18\x01\x20\x01(\
-    \rR\x06userId\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x12\n\
-    \x04rank\x18\x03\x20\x01(\x05R\x04rank\x12(\n\x06league\x18\x04\x20\x01(\
-    \x0b2\x10.protos.LeaguePtR\x06league\x12\x14\n\x05cters\x18\x05\x20\x03(\
-    \rR\x05ctersb\x06proto3\
+    \n\nbase.proto\x12\x06protos\"\xa4\x01\n\rRoomSettingPt\x12&\n\x0fturn_l\
+    imit_time\x18\x01\x20\x01(\rR\rturnLimitTime\x12$\n\x0eseason_is_open\
+    \x18\x02\x20\x01(\x08R\x0cseasonIsOpen\x12\x19\n\x08ai_level\x18\x03\x20\
+    \x01(\rR\x07aiLevel\x12*\n\x11turn_reserve_time\x18\x04\x20\x01(\rR\x0ft\
+    urnReserveTime\"\xac\x02\n\x08PlayerPt\x12\x1b\n\tnick_name\x18\x01\x20\
+    \x01(\tR\x08nickName\x12%\n\x0elast_character\x18\x02\x20\x01(\rR\rlastC\
+    haracter\x128\n\x0cpunish_match\x18\x03\x20\x01(\x0b2\x15.protos.PunishM\
+    atchPtR\x0bpunishMatch\x12\x14\n\x05grade\x18\x04\x20\x01(\rR\x05grade\
+    \x12\x1f\n\x0bgrade_frame\x18\x05\x20\x01(\rR\ngradeFrame\x12\x12\n\x04s\
+    oul\x18\x06\x20\x01(\rR\x04soul\x12\x1b\n\tbest_rank\x18\x07\x20\x01(\
+    \x05R\x08bestRank\x12(\n\x06league\x18\x08\x20\x01(\x0b2\x10.protos.Leag\
+    uePtR\x06league\x12\x10\n\x03dlc\x18\t\x20\x03(\rR\x03dlc\"a\n\x08Season\
+    Pt\x12\x1b\n\tseason_id\x18\x01\x20\x01(\rR\x08seasonId\x12\x1d\n\nstart\
+    _time\x18\x02\x20\x01(\x04R\tstartTime\x12\x19\n\x08end_time\x18\x03\x20\
+    \x01(\x04R\x07endTime\"j\n\x0fWorldBossRankPt\x12\x17\n\x07user_id\x18\
+    \x01\x20\x01(\rR\x06userId\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\
+    \x12\x16\n\x06damage\x18\x03\x20\x01(\x04R\x06damage\x12\x12\n\x04rank\
+    \x18\x04\x20\x01(\rR\x04rank\"\x92\x01\n\x0bWorldBossPt\x12\"\n\rworld_b\
+    oss_id\x18\x01\x20\x01(\rR\x0bworldBossId\x12\x1d\n\nstart_time\x18\x02\
+    \x20\x01(\x04R\tstartTime\x12\x19\n\x08end_time\x18\x03\x20\x01(\x04R\
+    \x07endTime\x12\x0e\n\x02hp\x18\x04\x20\x01(\x04R\x02hp\x12\x15\n\x06max\
+    _hp\x18\x05\x20\x01(\x04R\x05maxHp\"\xe4\x02\n\x08MemberPt\x12\x17\n\x07\
+    user_id\x18\x01\x20\x01(\rR\x06userId\x12\x1b\n\tnick_name\x18\x02\x20\
+    \x01(\tR\x08nickName\x12'\n\x04cter\x18\x03\x20\x01(\x0b2\x13.protos.Cha\
+    racterPtR\x04cter\x12\x14\n\x05state\x18\x04\x20\x01(\rR\x05state\x12\
+    \x14\n\x05grade\x18\x05\x20\x01(\rR\x05grade\x12\x1f\n\x0bgrade_frame\
+    \x18\x06\x20\x01(\rR\ngradeFrame\x12\x12\n\x04soul\x18\x07\x20\x01(\rR\
+    \x04soul\x12\x17\n\x07team_id\x18\x08\x20\x01(\rR\x06teamId\x12\x1b\n\tj\
+    oin_time\x18\t\x20\x01(\x04R\x08joinTime\x12(\n\x06league\x18\n\x20\x01(\
+    \x0b2\x10.protos.LeaguePtR\x06league\x12\"\n\rrobot_temp_id\x18\x0b\x20\
+    \x01(\rR\x0brobotTempId\x12\x14\n\x05index\x18\x0c\x20\x01(\rR\x05index\
+    \"G\n\x0bWorldCellPt\x12\x14\n\x05index\x18\x01\x20\x01(\rR\x05index\x12\
+    \"\n\rworld_cell_id\x18\x02\x20\x01(\rR\x0bworldCellId\"\xd7\x01\n\x06Ro\
+    omPt\x12\x17\n\x07room_id\x18\x01\x20\x01(\rR\x06roomId\x12\x19\n\x08own\
+    er_id\x18\x02\x20\x01(\rR\x07ownerId\x12\x1b\n\troom_type\x18\x03\x20\
+    \x01(\rR\x08roomType\x12\x1f\n\x0broom_status\x18\x04\x20\x01(\rR\nroomS\
+    tatus\x12/\n\x07setting\x18\x05\x20\x01(\x0b2\x15.protos.RoomSettingPtR\
+    \x07setting\x12*\n\x07members\x18\x06\x20\x03(\x0b2\x10.protos.MemberPtR\
+    \x07members\"H\n\x0bDraftPickPt\x12\x17\n\x07user_id\x18\x01\x20\x01(\rR\
+    \x06userId\x12\x20\n\x0ccter_temp_id\x18\x02\x20\x01(\rR\ncterTempId\"\
+    \x8d\x01\n\rHistoryMessPt\x12\x17\n\x07user_id\x18\x01\x20\x01(\rR\x06us\
+    erId\x12\x1b\n\tnick_name\x18\x02\x20\x01(\tR\x08nickName\x12\x18\n\x07c\
+    hannel\x18\x03\x20\x01(\rR\x07channel\x12\x18\n\x07content\x18\x04\x20\
+    \x01(\tR\x07content\x12\x12\n\x04time\x18\x05\x20\x01(\x03R\x04time\"\
+    \xa9\x01\n\x0cNoticeMessPt\x12\x17\n\x07user_id\x18\x01\x20\x01(\rR\x06u\
+    serId\x12\x1b\n\tnick_name\x18\x02\x20\x01(\tR\x08nickName\x12\x18\n\x07\
+    channel\x18\x03\x20\x01(\rR\x07channel\x12\x18\n\x07content\x18\x04\x20\
+    \x01(\tR\x07content\x12\x12\n\x04time\x18\x05\x20\x01(\x03R\x04time\x12\
+    \x1b\n\ttarget_id\x18\x06\x20\x01(\rR\x08targetId\"\\\n\x08FriendPt\x12\
+    \x17\n\x07user_id\x18\x01\x20\x01(\rR\x06userId\x12\x1b\n\tnick_name\x18\
+    \x02\x20\x01(\tR\x08nickName\x12\x1a\n\x08presence\x18\x03\x20\x01(\rR\
+    \x08presence\"@\n\x07PartyPt\x12\x1b\n\tleader_id\x18\x01\x20\x01(\rR\
+    \x08leaderId\x12\x18\n\x07members\x18\x02\x20\x03(\rR\x07members\"U\n\
+    \x0cAttachmentPt\x12\x1f\n\x0battach_type\x18\x01\x20\x01(\rR\nattachTyp\
+    e\x12\x0e\n\x02id\x18\x02\x20\x01(\rR\x02id\x12\x14\n\x05count\x18\x03\
+    \x20\x01(\rR\x05count\"\x9c\x02\n\x06MailPt\x12\x17\n\x07mail_id\x18\x01\
+    \x20\x01(\rR\x06mailId\x12\x1b\n\tmail_type\x18\x02\x20\x01(\rR\x08mailT\
+    ype\x12\x14\n\x05title\x18\x03\x20\x01(\tR\x05title\x12\x18\n\x07content\
+    \x18\x04\x20\x01(\tR\x07content\x126\n\x0battachments\x18\x05\x20\x03(\
+    \x0b2\x14.protos.AttachmentPtR\x0battachments\x12\x1b\n\tsend_time\x18\
+    \x06\x20\x01(\x03R\x08sendTime\x12\x1f\n\x0bexpire_time\x18\x07\x20\x01(\
+    \x03R\nexpireTime\x12\x17\n\x07is_read\x18\x08\x20\x01(\x08R\x06isRead\
+    \x12\x1d\n\nis_claimed\x18\t\x20\x01(\x08R\tisClaimed\"\x96\x01\n\x07Que\
+    stPt\x12\x19\n\x08quest_id\x18\x01\x20\x01(\rR\x07questId\x12\x1d\n\nque\
+    st_type\x18\x02\x20\x01(\rR\tquestType\x12\x1a\n\x08progress\x18\x03\x20\
+    \x01(\rR\x08progress\x12\x16\n\x06target\x18\x04\x20\x01(\rR\x06target\
+    \x12\x1d\n\nis_claimed\x18\x05\x20\x01(\x08R\tisClaimed\"\x8b\x01\n\rAch\
+    ievementPt\x12%\n\x0eachievement_id\x18\x01\x20\x01(\rR\rachievementId\
+    \x12\x1a\n\x08progress\x18\x02\x20\x01(\rR\x08progress\x12\x16\n\x06targ\
+    et\x18\x03\x20\x01(\rR\x06target\x12\x1f\n\x0bunlock_time\x18\x04\x20\
+    \x01(\x03R\nunlockTime\"\xd7\x02\n\x0cTournamentPt\x12#\n\rtournament_id\
+    \x18\x01\x20\x01(\rR\x0ctournamentId\x12\x12\n\x04name\x18\x02\x20\x01(\
+    \tR\x04name\x12\x16\n\x06format\x18\x03\x20\x01(\rR\x06format\x12\x14\n\
+    \x05state\x18\x04\x20\x01(\rR\x05state\x12\"\n\rsign_up_start\x18\x05\
+    \x20\x01(\x04R\x0bsignUpStart\x12\x1e\n\x0bsign_up_end\x18\x06\x20\x01(\
+    \x04R\tsignUpEnd\x12\x1d\n\nstart_time\x18\x07\x20\x01(\x04R\tstartTime\
+    \x12\x1f\n\x0bmax_players\x18\x08\x20\x01(\rR\nmaxPlayers\x12#\n\radvanc\
+    e_count\x18\t\x20\x01(\rR\x0cadvanceCount\x12!\n\x0cplayer_count\x18\n\
+    \x20\x01(\rR\x0bplayerCount\x12\x14\n\x05round\x18\x0b\x20\x01(\rR\x05ro\
+    und\"\xac\x01\n\x12TournamentPlayerPt\x12\x17\n\x07user_id\x18\x01\x20\
+    \x01(\rR\x06userId\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x12\
+    \n\x04seed\x18\x03\x20\x01(\rR\x04seed\x12\x16\n\x06losses\x18\x04\x20\
+    \x01(\rR\x06losses\x12\x1e\n\neliminated\x18\x05\x20\x01(\x08R\neliminat\
+    ed\x12\x1d\n\nfinal_rank\x18\x06\x20\x01(\rR\tfinalRank\"\xa4\x01\n\x11T\
+    ournamentMatchPt\x12\x19\n\x08match_id\x18\x01\x20\x01(\rR\x07matchId\
+    \x12\x14\n\x05round\x18\x02\x20\x01(\rR\x05round\x12\x18\n\x07bracket\
+    \x18\x03\x20\x01(\rR\x07bracket\x12\x14\n\x05state\x18\x04\x20\x01(\rR\
+    \x05state\x12\x18\n\x07players\x18\x05\x20\x03(\rR\x07players\x12\x14\n\
+    \x05ranks\x18\x06\x20\x03(\rR\x05ranks\"o\n\x0bCharacterPt\x12\x20\n\x0c\
+    cter_temp_id\x18\x01\x20\x01(\rR\ncterTempId\x12&\n\x0flast_use_skills\
+    \x18\x02\x20\x03(\rR\rlastUseSkills\x12\x16\n\x06skills\x18\x03\x20\x03(\
+    \rR\x06skills\"Q\n\x07SkillPt\x12\x19\n\x08skill_id\x18\x01\x20\x01(\rR\
+    \x07skillId\x12\x0e\n\x02cd\x18\x02\x20\x01(\rR\x02cd\x12\x1b\n\tis_acti\
+    ve\x18\x03\x20\x01(\x08R\x08isActive\"\xd9\x02\n\x11BattleCharacterPt\
+    \x12\x17\n\x07user_id\x18\x01\x20\x01(\rR\x06userId\x12\x17\n\x07cter_id\
+    \x18\x02\x20\x01(\rR\x06cterId\x12\x20\n\x0ccter_temp_id\x18\x03\x20\x01\
+    (\rR\ncterTempId\x12\x10\n\x03atk\x18\x04\x20\x01(\rR\x03atk\x12\x0e\n\
+    \x02hp\x18\x05\x20\x01(\rR\x02hp\x12\x18\n\x07defence\x18\x06\x20\x01(\r\
+    R\x07defence\x12\x16\n\x06energy\x18\x07\x20\x01(\rR\x06energy\x12\x14\n\
+    \x05index\x18\x08\x20\x01(\rR\x05index\x12\x19\n\x08is_major\x18\t\x20\
+    \x01(\x08R\x07isMajor\x12\x14\n\x05buffs\x18\n\x20\x03(\rR\x05buffs\x12'\
+    \n\x06skills\x18\x0b\x20\x03(\x0b2\x0f.protos.SkillPtR\x06skills\x12\x14\
+    \n\x05items\x18\x0c\x20\x03(\rR\x05items\x12\x16\n\x06minons\x18\r\x20\
+    \x03(\rR\x06minons\":\n\tIndexCter\x12\x17\n\x07cter_id\x18\x01\x20\x01(\
+    \rR\x06cterId\x12\x14\n\x05index\x18\x02\x20\x01(\rR\x05index\"\xa1\x03\
+    \n\x0eBattlePlayerPt\x12\x17\n\x07user_id\x18\x01\x20\x01(\rR\x06userId\
+    \x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12(\n\x06league\x18\x03\
+    \x20\x01(\x0b2\x10.protos.LeaguePtR\x06league\x12\x12\n\x04gold\x18\x04\
+    \x20\x01(\rR\x04gold\x12\x14\n\x05grade\x18\x05\x20\x01(\rR\x05grade\x12\
+    \x1d\n\nmajor_cter\x18\x06\x20\x01(\rR\tmajorCter\x12!\n\x0ccurrent_cter\
+    \x18\x07\x20\x01(\rR\x0bcurrentCter\x12+\n\x07mission\x18\x08\x20\x01(\
+    \x0b2\x11.protos.MissionPtR\x07mission\x12\x19\n\x08is_robot\x18\t\x20\
+    \x01(\x08R\x07isRobot\x12\x17\n\x07is_died\x18\n\x20\x01(\x08R\x06isDied\
+    \x12/\n\x05cters\x18\x0b\x20\x03(\x0b2\x19.protos.BattleCharacterPtR\x05\
+    cters\x12\x17\n\x07is_auto\x18\x0c\x20\x01(\x08R\x06isAuto\x12!\n\x0cres\
+    erve_time\x18\r\x20\x01(\rR\x0breserveTime\"b\n\tMissionPt\x12\x1d\n\nmi\
+    ssion_id\x18\x01\x20\x01(\rR\tmissionId\x12\x1a\n\x08progress\x18\x02\
+    \x20\x01(\rR\x08progress\x12\x1a\n\x08complete\x18\x03\x20\x01(\x08R\x08\
+    complete\"N\n\x08EffectPt\x12\x1f\n\x0beffect_type\x18\x02\x20\x01(\rR\n\
+    effectType\x12!\n\x0ceffect_value\x18\x03\x20\x01(\rR\x0beffectValue\"\
+    \xed\x02\n\x08TargetPt\x12!\n\x0ctarget_value\x18\x01\x20\x03(\rR\x0btar\
+    getValue\x12*\n\x07effects\x18\x02\x20\x03(\x0b2\x10.protos.EffectPtR\
+    \x07effects\x12\x1b\n\tadd_buffs\x18\x03\x20\x03(\rR\x08addBuffs\x12=\n\
+    \rpassiveEffect\x18\x04\x20\x03(\x0b2\x17.protos.TriggerEffectPtR\rpassi\
+    veEffect\x12\x1d\n\nlost_buffs\x18\x05\x20\x03(\rR\tlostBuffs\x12\x1f\n\
+    \x0blost_skills\x18\x06\x20\x03(\rR\nlostSkills\x12@\n\x0etransform_cter\
+    \x18\x07\x20\x01(\x0b2\x19.protos.BattleCharacterPtR\rtransformCter\x124\
+    \n\x08new_cter\x18\x08\x20\x01(\x0b2\x19.protos.BattleCharacterPtR\x07ne\
+    wCter\"T\n\x0fTriggerEffectPt\x12\x17\n\x07buff_id\x18\x01\x20\x01(\rR\
+    \x06buffId\x12\x12\n\x04type\x18\x02\x20\x01(\rR\x04type\x12\x14\n\x05va\
+    lue\x18\x03\x20\x01(\rR\x05value\"\xed\x01\n\x0cActionUnitPt\x12\x1b\n\t\
+    from_cter\x18\x01\x20\x01(\rR\x08fromCter\x12\x1f\n\x0baction_type\x18\
+    \x02\x20\x01(\rR\nactionType\x12!\n\x0caction_value\x18\x03\x20\x03(\rR\
+    \x0bactionValue\x12*\n\x07targets\x18\x04\x20\x03(\x0b2\x10.protos.Targe\
+    tPtR\x07targets\x12\x1d\n\nlost_buffs\x18\x05\x20\x03(\rR\tlostBuffs\x12\
+    1\n\x15is_reward_move_points\x18\x06\x20\x01(\x08R\x12isRewardMovePoints\
+    \"i\n\x06BuffPt\x12\x17\n\x07buff_id\x18\x01\x20\x01(\rR\x06buffId\x12'\
+    \n\x0ftrigger_timesed\x18\x02\x20\x01(\rR\x0etriggerTimesed\x12\x1d\n\nk\
+    eep_times\x18\x03\x20\x01(\rR\tkeepTimes\"\x91\x02\n\rSummaryDataPt\x12\
+    \x17\n\x07user_id\x18\x01\x20\x01(\rR\x06userId\x12\x12\n\x04name\x18\
+    \x02\x20\x01(\tR\x04name\x12\x17\n\x07cter_id\x18\x03\x20\x01(\rR\x06cte\
+    rId\x12\x14\n\x05grade\x18\x04\x20\x01(\rR\x05grade\x12\x12\n\x04rank\
+    \x18\x05\x20\x01(\rR\x04rank\x12!\n\x0creward_score\x18\x06\x20\x01(\x05\
+    R\x0brewardScore\x12(\n\x06league\x18\x07\x20\x01(\x0b2\x10.protos.Leagu\
+    ePtR\x06league\x12\x14\n\x05kills\x18\x08\x20\x01(\rR\x05kills\x12-\n\
+    \x06events\x18\t\x20\x03(\x0b2\x15.protos.BattleEventPtR\x06events\"D\n\
+    \rBattleEventPt\x12\x1d\n\nevent_type\x18\x01\x20\x01(\rR\teventType\x12\
+    \x14\n\x05value\x18\x02\x20\x01(\rR\x05value\"e\n\x0cCterStatusPt\x12\
+    \x17\n\x07user_id\x18\x01\x20\x01(\rR\x06userId\x12\x16\n\x06skills\x18\
+    \x02\x20\x03(\rR\x06skills\x12$\n\x05buffs\x18\x03\x20\x03(\x0b2\x0e.pro\
+    tos.BuffPtR\x05buffs\"H\n\nCellBuffPt\x12\x14\n\x05index\x18\x01\x20\x01\
+    (\rR\x05index\x12$\n\x05buffs\x18\x02\x20\x03(\x0b2\x0e.protos.BuffPtR\
+    \x05buffs\"f\n\rPunishMatchPt\x12\x1d\n\nstart_time\x18\x01\x20\x01(\x03\
+    R\tstartTime\x12\x1b\n\tpunish_id\x18\x02\x20\x01(\rR\x08punishId\x12\
+    \x19\n\x08today_id\x18\x03\x20\x01(\rR\x07todayId\"k\n\x08LeaguePt\x12!\
+    \n\x0cleague_score\x18\x01\x20\x01(\x05R\x0bleagueScore\x12\x1b\n\tleagu\
+    e_id\x18\x02\x20\x01(\x05R\x08leagueId\x12\x1f\n\x0bleague_time\x18\x03\
+    \x20\x01(\x03R\nleagueTime\"\x8d\x01\n\nRankInfoPt\x12\x17\n\x07user_id\
+    \x18\x01\x20\x01(\rR\x06userId\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04\
+    name\x12\x12\n\x04rank\x18\x03\x20\x01(\x05R\x04rank\x12(\n\x06league\
+    \x18\x04\x20\x01(\x0b2\x10.protos.LeaguePtR\x06league\x12\x14\n\x05cters\
+    \x18\x05\x20\x03(\rR\x05ctersb\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
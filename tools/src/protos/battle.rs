@@ -1526,6 +1526,7 @@ pub struct S_BATTLE_TURN_NOTICE {
     pub user_id: u32,
     pub battle_players: ::protobuf::RepeatedField<super::base::BattlePlayerPt>,
     pub cell_buffs: ::protobuf::RepeatedField<super::base::CellBuffPt>,
+    pub turn_time: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -1621,6 +1622,21 @@ impl S_BATTLE_TURN_NOTICE {
     pub fn take_cell_buffs(&mut self) -> ::protobuf::RepeatedField<super::base::CellBuffPt> {
         ::std::mem::replace(&mut self.cell_buffs, ::protobuf::RepeatedField::new())
     }
+
+    // uint32 turn_time = 5;
+
+
+    pub fn get_turn_time(&self) -> u32 {
+        self.turn_time
+    }
+    pub fn clear_turn_time(&mut self) {
+        self.turn_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_turn_time(&mut self, v: u32) {
+        self.turn_time = v;
+    }
 }
 
 impl ::protobuf::Message for S_BATTLE_TURN_NOTICE {
@@ -1662,6 +1678,13 @@ impl ::protobuf::Message for S_BATTLE_TURN_NOTICE {
                 4 => {
                     ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cell_buffs)?;
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.turn_time = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1688,6 +1711,9 @@ impl ::protobuf::Message for S_BATTLE_TURN_NOTICE {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        if self.turn_time != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.turn_time, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1710,6 +1736,9 @@ impl ::protobuf::Message for S_BATTLE_TURN_NOTICE {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         };
+        if self.turn_time != 0 {
+            os.write_uint32(5, self.turn_time)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1768,6 +1797,11 @@ impl ::protobuf::Message for S_BATTLE_TURN_NOTICE {
                 |m: &S_BATTLE_TURN_NOTICE| { &m.cell_buffs },
                 |m: &mut S_BATTLE_TURN_NOTICE| { &mut m.cell_buffs },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "turn_time",
+                |m: &S_BATTLE_TURN_NOTICE| { &m.turn_time },
+                |m: &mut S_BATTLE_TURN_NOTICE| { &mut m.turn_time },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_BATTLE_TURN_NOTICE>(
                 "S_BATTLE_TURN_NOTICE",
                 fields,
@@ -1788,6 +1822,7 @@ impl ::protobuf::Clear for S_BATTLE_TURN_NOTICE {
         self.user_id = 0;
         self.battle_players.clear();
         self.cell_buffs.clear();
+        self.turn_time = 0;
         self.unknown_fields.clear();
     }
 }
@@ -2664,6 +2699,193 @@ impl ::protobuf::reflect::ProtobufValue for S_SUMMARY_NOTICE {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct S_AUTO_NOTICE {
+    // message fields
+    pub user_id: u32,
+    pub is_auto: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_AUTO_NOTICE {
+    fn default() -> &'a S_AUTO_NOTICE {
+        <S_AUTO_NOTICE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_AUTO_NOTICE {
+    pub fn new() -> S_AUTO_NOTICE {
+        ::std::default::Default::default()
+    }
+
+    // uint32 user_id = 1;
+
+
+    pub fn get_user_id(&self) -> u32 {
+        self.user_id
+    }
+    pub fn clear_user_id(&mut self) {
+        self.user_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: u32) {
+        self.user_id = v;
+    }
+
+    // bool is_auto = 2;
+
+
+    pub fn get_is_auto(&self) -> bool {
+        self.is_auto
+    }
+    pub fn clear_is_auto(&mut self) {
+        self.is_auto = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_auto(&mut self, v: bool) {
+        self.is_auto = v;
+    }
+}
+
+impl ::protobuf::Message for S_AUTO_NOTICE {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.user_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_auto = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.user_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.user_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.is_auto != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.user_id != 0 {
+            os.write_uint32(1, self.user_id)?;
+        }
+        if self.is_auto != false {
+            os.write_bool(2, self.is_auto)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_AUTO_NOTICE {
+        S_AUTO_NOTICE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "user_id",
+                |m: &S_AUTO_NOTICE| { &m.user_id },
+                |m: &mut S_AUTO_NOTICE| { &mut m.user_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_auto",
+                |m: &S_AUTO_NOTICE| { &m.is_auto },
+                |m: &mut S_AUTO_NOTICE| { &mut m.is_auto },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_AUTO_NOTICE>(
+                "S_AUTO_NOTICE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_AUTO_NOTICE {
+        static instance: ::protobuf::rt::LazyV2<S_AUTO_NOTICE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_AUTO_NOTICE::new)
+    }
+}
+
+impl ::protobuf::Clear for S_AUTO_NOTICE {
+    fn clear(&mut self) {
+        self.user_id = 0;
+        self.is_auto = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_AUTO_NOTICE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_AUTO_NOTICE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct S_MISSION_NOTICE {
     // message fields
@@ -3225,6 +3447,731 @@ impl ::protobuf::reflect::ProtobufValue for S_BUY_NOTICE {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct C_SURRENDER {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_SURRENDER {
+    fn default() -> &'a C_SURRENDER {
+        <C_SURRENDER as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_SURRENDER {
+    pub fn new() -> C_SURRENDER {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for C_SURRENDER {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_SURRENDER {
+        C_SURRENDER::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_SURRENDER>(
+                "C_SURRENDER",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_SURRENDER {
+        static instance: ::protobuf::rt::LazyV2<C_SURRENDER> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_SURRENDER::new)
+    }
+}
+
+impl ::protobuf::Clear for C_SURRENDER {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_SURRENDER {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_SURRENDER {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_SURRENDER {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_SURRENDER {
+    fn default() -> &'a S_SURRENDER {
+        <S_SURRENDER as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_SURRENDER {
+    pub fn new() -> S_SURRENDER {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for S_SURRENDER {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_SURRENDER {
+        S_SURRENDER::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_SURRENDER| { &m.is_succ },
+                |m: &mut S_SURRENDER| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_SURRENDER| { &m.err_mess },
+                |m: &mut S_SURRENDER| { &mut m.err_mess },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_SURRENDER>(
+                "S_SURRENDER",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_SURRENDER {
+        static instance: ::protobuf::rt::LazyV2<S_SURRENDER> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_SURRENDER::new)
+    }
+}
+
+impl ::protobuf::Clear for S_SURRENDER {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_SURRENDER {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_SURRENDER {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_VOTE_END {
+    // message fields
+    pub agree: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_VOTE_END {
+    fn default() -> &'a C_VOTE_END {
+        <C_VOTE_END as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_VOTE_END {
+    pub fn new() -> C_VOTE_END {
+        ::std::default::Default::default()
+    }
+
+    // bool agree = 1;
+
+
+    pub fn get_agree(&self) -> bool {
+        self.agree
+    }
+    pub fn clear_agree(&mut self) {
+        self.agree = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_agree(&mut self, v: bool) {
+        self.agree = v;
+    }
+}
+
+impl ::protobuf::Message for C_VOTE_END {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.agree = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.agree != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.agree != false {
+            os.write_bool(1, self.agree)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_VOTE_END {
+        C_VOTE_END::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "agree",
+                |m: &C_VOTE_END| { &m.agree },
+                |m: &mut C_VOTE_END| { &mut m.agree },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_VOTE_END>(
+                "C_VOTE_END",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_VOTE_END {
+        static instance: ::protobuf::rt::LazyV2<C_VOTE_END> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_VOTE_END::new)
+    }
+}
+
+impl ::protobuf::Clear for C_VOTE_END {
+    fn clear(&mut self) {
+        self.agree = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_VOTE_END {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_VOTE_END {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_VOTE_END_NOTICE {
+    // message fields
+    pub user_id: u32,
+    pub agree: bool,
+    pub votes: ::std::vec::Vec<u32>,
+    pub need_count: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_VOTE_END_NOTICE {
+    fn default() -> &'a S_VOTE_END_NOTICE {
+        <S_VOTE_END_NOTICE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_VOTE_END_NOTICE {
+    pub fn new() -> S_VOTE_END_NOTICE {
+        ::std::default::Default::default()
+    }
+
+    // uint32 user_id = 1;
+
+
+    pub fn get_user_id(&self) -> u32 {
+        self.user_id
+    }
+    pub fn clear_user_id(&mut self) {
+        self.user_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: u32) {
+        self.user_id = v;
+    }
+
+    // bool agree = 2;
+
+
+    pub fn get_agree(&self) -> bool {
+        self.agree
+    }
+    pub fn clear_agree(&mut self) {
+        self.agree = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_agree(&mut self, v: bool) {
+        self.agree = v;
+    }
+
+    // repeated uint32 votes = 3;
+
+
+    pub fn get_votes(&self) -> &[u32] {
+        &self.votes
+    }
+    pub fn clear_votes(&mut self) {
+        self.votes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_votes(&mut self, v: ::std::vec::Vec<u32>) {
+        self.votes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_votes(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.votes
+    }
+
+    // Take field
+    pub fn take_votes(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.votes, ::std::vec::Vec::new())
+    }
+
+    // uint32 need_count = 4;
+
+
+    pub fn get_need_count(&self) -> u32 {
+        self.need_count
+    }
+    pub fn clear_need_count(&mut self) {
+        self.need_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_need_count(&mut self, v: u32) {
+        self.need_count = v;
+    }
+}
+
+impl ::protobuf::Message for S_VOTE_END_NOTICE {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.user_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.agree = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.votes)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.need_count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.user_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.user_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.agree != false {
+            my_size += 2;
+        }
+        for value in &self.votes {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if self.need_count != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.need_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.user_id != 0 {
+            os.write_uint32(1, self.user_id)?;
+        }
+        if self.agree != false {
+            os.write_bool(2, self.agree)?;
+        }
+        for v in &self.votes {
+            os.write_uint32(3, *v)?;
+        };
+        if self.need_count != 0 {
+            os.write_uint32(4, self.need_count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_VOTE_END_NOTICE {
+        S_VOTE_END_NOTICE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "user_id",
+                |m: &S_VOTE_END_NOTICE| { &m.user_id },
+                |m: &mut S_VOTE_END_NOTICE| { &mut m.user_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "agree",
+                |m: &S_VOTE_END_NOTICE| { &m.agree },
+                |m: &mut S_VOTE_END_NOTICE| { &mut m.agree },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "votes",
+                |m: &S_VOTE_END_NOTICE| { &m.votes },
+                |m: &mut S_VOTE_END_NOTICE| { &mut m.votes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "need_count",
+                |m: &S_VOTE_END_NOTICE| { &m.need_count },
+                |m: &mut S_VOTE_END_NOTICE| { &mut m.need_count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_VOTE_END_NOTICE>(
+                "S_VOTE_END_NOTICE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_VOTE_END_NOTICE {
+        static instance: ::protobuf::rt::LazyV2<S_VOTE_END_NOTICE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_VOTE_END_NOTICE::new)
+    }
+}
+
+impl ::protobuf::Clear for S_VOTE_END_NOTICE {
+    fn clear(&mut self) {
+        self.user_id = 0;
+        self.agree = false;
+        self.votes.clear();
+        self.need_count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_VOTE_END_NOTICE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_VOTE_END_NOTICE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x0cbattle.proto\x12\x06protos\x1a\nbase.proto\"\xe3\x01\n\x0eS_START_\
     NOTICE\x12\x1f\n\x0broom_status\x18\x01\x20\x01(\rR\nroomStatus\x12\x1e\
@@ -3243,27 +4190,36 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     =\n\x0ebattle_players\x18\x01\x20\x03(\x0b2\x16.protos.BattlePlayerPtR\r\
     battlePlayers\x12\x19\n\x08map_data\x18\x02\x20\x01(\tR\x07mapData\"J\n\
     \x0fS_ACTION_NOTICE\x127\n\x0caction_uints\x18\x01\x20\x03(\x0b2\x14.pro\
-    tos.ActionUnitPtR\x0bactionUints\"\xc2\x01\n\x14S_BATTLE_TURN_NOTICE\x12\
+    tos.ActionUnitPtR\x0bactionUints\"\xdf\x01\n\x14S_BATTLE_TURN_NOTICE\x12\
     \x1f\n\x0bcycle_count\x18\x01\x20\x01(\rR\ncycleCount\x12\x17\n\x07user_\
     id\x18\x02\x20\x01(\rR\x06userId\x12=\n\x0ebattle_players\x18\x03\x20\
     \x03(\x0b2\x16.protos.BattlePlayerPtR\rbattlePlayers\x121\n\ncell_buffs\
-    \x18\x04\x20\x03(\x0b2\x12.protos.CellBuffPtR\tcellBuffs\"6\n\x05C_POS\
-    \x12\x12\n\x04type\x18\x01\x20\x01(\rR\x04type\x12\x19\n\x08skill_id\x18\
-    \x02\x20\x01(\rR\x07skillId\"V\n\x0cS_POS_NOTICE\x12\x17\n\x07cter_id\
-    \x18\x01\x20\x01(\rR\x06cterId\x12\x12\n\x04type\x18\x02\x20\x01(\rR\x04\
-    type\x12\x19\n\x08skill_id\x18\x03\x20\x01(\rR\x07skillId\"\xbf\x01\n\
-    \x14S_MAP_REFRESH_NOTICE\x12\x1f\n\x0broom_status\x18\x01\x20\x01(\rR\nr\
-    oomStatus\x12\x1e\n\x0btile_map_id\x18\x02\x20\x01(\rR\ttileMapId\x122\n\
-    \nworld_cell\x18\x03\x20\x03(\x0b2\x13.protos.WorldCellPtR\tworldCell\
-    \x122\n\x0bindex_cters\x18\x04\x20\x03(\x0b2\x11.protos.IndexCterR\ninde\
-    xCters\"N\n\x10S_SUMMARY_NOTICE\x12:\n\rsummary_datas\x18\x01\x20\x03(\
-    \x0b2\x15.protos.SummaryDataPtR\x0csummaryDatas\"k\n\x10S_MISSION_NOTICE\
-    \x12\x17\n\x07user_id\x18\x01\x20\x01(\rR\x06userId\x12\x1d\n\nmission_i\
-    d\x18\x02\x20\x01(\rR\tmissionId\x12\x1f\n\x0bnotice_type\x18\x03\x20\
-    \x01(\rR\nnoticeType\".\n\x05C_BUY\x12%\n\x0emerchandise_id\x18\x01\x20\
-    \x01(\rR\rmerchandiseId\"N\n\x0cS_BUY_NOTICE\x12\x17\n\x07user_id\x18\
-    \x01\x20\x01(\rR\x06userId\x12%\n\x0emerchandise_id\x18\x02\x20\x01(\rR\
-    \rmerchandiseIdb\x06proto3\
+    \x18\x04\x20\x03(\x0b2\x12.protos.CellBuffPtR\tcellBuffs\x12\x1b\n\tturn\
+    _time\x18\x05\x20\x01(\rR\x08turnTime\"6\n\x05C_POS\x12\x12\n\x04type\
+    \x18\x01\x20\x01(\rR\x04type\x12\x19\n\x08skill_id\x18\x02\x20\x01(\rR\
+    \x07skillId\"V\n\x0cS_POS_NOTICE\x12\x17\n\x07cter_id\x18\x01\x20\x01(\r\
+    R\x06cterId\x12\x12\n\x04type\x18\x02\x20\x01(\rR\x04type\x12\x19\n\x08s\
+    kill_id\x18\x03\x20\x01(\rR\x07skillId\"\xbf\x01\n\x14S_MAP_REFRESH_NOTI\
+    CE\x12\x1f\n\x0broom_status\x18\x01\x20\x01(\rR\nroomStatus\x12\x1e\n\
+    \x0btile_map_id\x18\x02\x20\x01(\rR\ttileMapId\x122\n\nworld_cell\x18\
+    \x03\x20\x03(\x0b2\x13.protos.WorldCellPtR\tworldCell\x122\n\x0bindex_ct\
+    ers\x18\x04\x20\x03(\x0b2\x11.protos.IndexCterR\nindexCters\"N\n\x10S_SU\
+    MMARY_NOTICE\x12:\n\rsummary_datas\x18\x01\x20\x03(\x0b2\x15.protos.Summ\
+    aryDataPtR\x0csummaryDatas\"A\n\rS_AUTO_NOTICE\x12\x17\n\x07user_id\x18\
+    \x01\x20\x01(\rR\x06userId\x12\x17\n\x07is_auto\x18\x02\x20\x01(\x08R\
+    \x06isAuto\"k\n\x10S_MISSION_NOTICE\x12\x17\n\x07user_id\x18\x01\x20\x01\
+    (\rR\x06userId\x12\x1d\n\nmission_id\x18\x02\x20\x01(\rR\tmissionId\x12\
+    \x1f\n\x0bnotice_type\x18\x03\x20\x01(\rR\nnoticeType\".\n\x05C_BUY\x12%\
+    \n\x0emerchandise_id\x18\x01\x20\x01(\rR\rmerchandiseId\"N\n\x0cS_BUY_NO\
+    TICE\x12\x17\n\x07user_id\x18\x01\x20\x01(\rR\x06userId\x12%\n\x0emercha\
+    ndise_id\x18\x02\x20\x01(\rR\rmerchandiseId\"\r\n\x0bC_SURRENDER\"A\n\
+    \x0bS_SURRENDER\x12\x17\n\x07is_succ\x18\x01\x20\x01(\x08R\x06isSucc\x12\
+    \x19\n\x08err_mess\x18\x02\x20\x01(\tR\x07errMess\"\"\n\nC_VOTE_END\x12\
+    \x14\n\x05agree\x18\x01\x20\x01(\x08R\x05agree\"w\n\x11S_VOTE_END_NOTICE\
+    \x12\x17\n\x07user_id\x18\x01\x20\x01(\rR\x06userId\x12\x14\n\x05agree\
+    \x18\x02\x20\x01(\x08R\x05agree\x12\x14\n\x05votes\x18\x03\x20\x03(\rR\
+    \x05votes\x12\x1d\n\nneed_count\x18\x04\x20\x01(\rR\tneedCountb\x06proto\
+    3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
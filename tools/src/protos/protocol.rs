@@ -2770,6 +2770,9016 @@ impl ::protobuf::reflect::ProtobufValue for S_GET_LAST_SEASON_RANK {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct C_SHOW_WORLD_BOSS {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_SHOW_WORLD_BOSS {
+    fn default() -> &'a C_SHOW_WORLD_BOSS {
+        <C_SHOW_WORLD_BOSS as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_SHOW_WORLD_BOSS {
+    pub fn new() -> C_SHOW_WORLD_BOSS {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for C_SHOW_WORLD_BOSS {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_SHOW_WORLD_BOSS {
+        C_SHOW_WORLD_BOSS::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_SHOW_WORLD_BOSS>(
+                "C_SHOW_WORLD_BOSS",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_SHOW_WORLD_BOSS {
+        static instance: ::protobuf::rt::LazyV2<C_SHOW_WORLD_BOSS> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_SHOW_WORLD_BOSS::new)
+    }
+}
+
+impl ::protobuf::Clear for C_SHOW_WORLD_BOSS {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_SHOW_WORLD_BOSS {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_SHOW_WORLD_BOSS {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_SHOW_WORLD_BOSS {
+    // message fields
+    pub world_boss_id: u32,
+    pub hp: u64,
+    pub max_hp: u64,
+    pub milestone: u32,
+    pub ranks: ::protobuf::RepeatedField<super::base::WorldBossRankPt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_SHOW_WORLD_BOSS {
+    fn default() -> &'a S_SHOW_WORLD_BOSS {
+        <S_SHOW_WORLD_BOSS as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_SHOW_WORLD_BOSS {
+    pub fn new() -> S_SHOW_WORLD_BOSS {
+        ::std::default::Default::default()
+    }
+
+    // uint32 world_boss_id = 1;
+
+
+    pub fn get_world_boss_id(&self) -> u32 {
+        self.world_boss_id
+    }
+    pub fn clear_world_boss_id(&mut self) {
+        self.world_boss_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_world_boss_id(&mut self, v: u32) {
+        self.world_boss_id = v;
+    }
+
+    // uint64 hp = 2;
+
+
+    pub fn get_hp(&self) -> u64 {
+        self.hp
+    }
+    pub fn clear_hp(&mut self) {
+        self.hp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hp(&mut self, v: u64) {
+        self.hp = v;
+    }
+
+    // uint64 max_hp = 3;
+
+
+    pub fn get_max_hp(&self) -> u64 {
+        self.max_hp
+    }
+    pub fn clear_max_hp(&mut self) {
+        self.max_hp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_hp(&mut self, v: u64) {
+        self.max_hp = v;
+    }
+
+    // uint32 milestone = 4;
+
+
+    pub fn get_milestone(&self) -> u32 {
+        self.milestone
+    }
+    pub fn clear_milestone(&mut self) {
+        self.milestone = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_milestone(&mut self, v: u32) {
+        self.milestone = v;
+    }
+
+    // repeated .protos.WorldBossRankPt ranks = 5;
+
+
+    pub fn get_ranks(&self) -> &[super::base::WorldBossRankPt] {
+        &self.ranks
+    }
+    pub fn clear_ranks(&mut self) {
+        self.ranks.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ranks(&mut self, v: ::protobuf::RepeatedField<super::base::WorldBossRankPt>) {
+        self.ranks = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ranks(&mut self) -> &mut ::protobuf::RepeatedField<super::base::WorldBossRankPt> {
+        &mut self.ranks
+    }
+
+    // Take field
+    pub fn take_ranks(&mut self) -> ::protobuf::RepeatedField<super::base::WorldBossRankPt> {
+        ::std::mem::replace(&mut self.ranks, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for S_SHOW_WORLD_BOSS {
+    fn is_initialized(&self) -> bool {
+        for v in &self.ranks {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.world_boss_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.hp = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.max_hp = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.milestone = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.ranks)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.world_boss_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.world_boss_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.hp != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.hp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.max_hp != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.max_hp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.milestone != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.milestone, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.ranks {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.world_boss_id != 0 {
+            os.write_uint32(1, self.world_boss_id)?;
+        }
+        if self.hp != 0 {
+            os.write_uint64(2, self.hp)?;
+        }
+        if self.max_hp != 0 {
+            os.write_uint64(3, self.max_hp)?;
+        }
+        if self.milestone != 0 {
+            os.write_uint32(4, self.milestone)?;
+        }
+        for v in &self.ranks {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_SHOW_WORLD_BOSS {
+        S_SHOW_WORLD_BOSS::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "world_boss_id",
+                |m: &S_SHOW_WORLD_BOSS| { &m.world_boss_id },
+                |m: &mut S_SHOW_WORLD_BOSS| { &mut m.world_boss_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "hp",
+                |m: &S_SHOW_WORLD_BOSS| { &m.hp },
+                |m: &mut S_SHOW_WORLD_BOSS| { &mut m.hp },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "max_hp",
+                |m: &S_SHOW_WORLD_BOSS| { &m.max_hp },
+                |m: &mut S_SHOW_WORLD_BOSS| { &mut m.max_hp },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "milestone",
+                |m: &S_SHOW_WORLD_BOSS| { &m.milestone },
+                |m: &mut S_SHOW_WORLD_BOSS| { &mut m.milestone },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::WorldBossRankPt>>(
+                "ranks",
+                |m: &S_SHOW_WORLD_BOSS| { &m.ranks },
+                |m: &mut S_SHOW_WORLD_BOSS| { &mut m.ranks },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_SHOW_WORLD_BOSS>(
+                "S_SHOW_WORLD_BOSS",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_SHOW_WORLD_BOSS {
+        static instance: ::protobuf::rt::LazyV2<S_SHOW_WORLD_BOSS> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_SHOW_WORLD_BOSS::new)
+    }
+}
+
+impl ::protobuf::Clear for S_SHOW_WORLD_BOSS {
+    fn clear(&mut self) {
+        self.world_boss_id = 0;
+        self.hp = 0;
+        self.max_hp = 0;
+        self.milestone = 0;
+        self.ranks.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_SHOW_WORLD_BOSS {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_SHOW_WORLD_BOSS {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_GM_COMMAND {
+    // message fields
+    pub name: ::std::string::String,
+    pub args: ::protobuf::RepeatedField<::std::string::String>,
+    pub target_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_GM_COMMAND {
+    fn default() -> &'a C_GM_COMMAND {
+        <C_GM_COMMAND as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_GM_COMMAND {
+    pub fn new() -> C_GM_COMMAND {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // repeated string args = 2;
+
+
+    pub fn get_args(&self) -> &[::std::string::String] {
+        &self.args
+    }
+    pub fn clear_args(&mut self) {
+        self.args.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_args(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.args = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_args(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.args
+    }
+
+    // Take field
+    pub fn take_args(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.args, ::protobuf::RepeatedField::new())
+    }
+
+    // uint32 target_id = 3;
+
+
+    pub fn get_target_id(&self) -> u32 {
+        self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: u32) {
+        self.target_id = v;
+    }
+}
+
+impl ::protobuf::Message for C_GM_COMMAND {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.args)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.target_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        for value in &self.args {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        if self.target_id != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.target_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        for v in &self.args {
+            os.write_string(2, &v)?;
+        };
+        if self.target_id != 0 {
+            os.write_uint32(3, self.target_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_GM_COMMAND {
+        C_GM_COMMAND::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &C_GM_COMMAND| { &m.name },
+                |m: &mut C_GM_COMMAND| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "args",
+                |m: &C_GM_COMMAND| { &m.args },
+                |m: &mut C_GM_COMMAND| { &mut m.args },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "target_id",
+                |m: &C_GM_COMMAND| { &m.target_id },
+                |m: &mut C_GM_COMMAND| { &mut m.target_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_GM_COMMAND>(
+                "C_GM_COMMAND",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_GM_COMMAND {
+        static instance: ::protobuf::rt::LazyV2<C_GM_COMMAND> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_GM_COMMAND::new)
+    }
+}
+
+impl ::protobuf::Clear for C_GM_COMMAND {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.args.clear();
+        self.target_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_GM_COMMAND {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_GM_COMMAND {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_GM_COMMAND {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    pub result: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_GM_COMMAND {
+    fn default() -> &'a S_GM_COMMAND {
+        <S_GM_COMMAND as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_GM_COMMAND {
+    pub fn new() -> S_GM_COMMAND {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+
+    // string result = 3;
+
+
+    pub fn get_result(&self) -> &str {
+        &self.result
+    }
+    pub fn clear_result(&mut self) {
+        self.result.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_result(&mut self, v: ::std::string::String) {
+        self.result = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_result(&mut self) -> &mut ::std::string::String {
+        &mut self.result
+    }
+
+    // Take field
+    pub fn take_result(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.result, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for S_GM_COMMAND {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.result)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        if !self.result.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.result);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        if !self.result.is_empty() {
+            os.write_string(3, &self.result)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_GM_COMMAND {
+        S_GM_COMMAND::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_GM_COMMAND| { &m.is_succ },
+                |m: &mut S_GM_COMMAND| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_GM_COMMAND| { &m.err_mess },
+                |m: &mut S_GM_COMMAND| { &mut m.err_mess },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "result",
+                |m: &S_GM_COMMAND| { &m.result },
+                |m: &mut S_GM_COMMAND| { &mut m.result },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_GM_COMMAND>(
+                "S_GM_COMMAND",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_GM_COMMAND {
+        static instance: ::protobuf::rt::LazyV2<S_GM_COMMAND> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_GM_COMMAND::new)
+    }
+}
+
+impl ::protobuf::Clear for S_GM_COMMAND {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.result.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_GM_COMMAND {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_GM_COMMAND {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_FRIEND_LIST {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_FRIEND_LIST {
+    fn default() -> &'a C_FRIEND_LIST {
+        <C_FRIEND_LIST as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_FRIEND_LIST {
+    pub fn new() -> C_FRIEND_LIST {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for C_FRIEND_LIST {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_FRIEND_LIST {
+        C_FRIEND_LIST::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_FRIEND_LIST>(
+                "C_FRIEND_LIST",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_FRIEND_LIST {
+        static instance: ::protobuf::rt::LazyV2<C_FRIEND_LIST> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_FRIEND_LIST::new)
+    }
+}
+
+impl ::protobuf::Clear for C_FRIEND_LIST {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_FRIEND_LIST {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_FRIEND_LIST {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_FRIEND_LIST {
+    // message fields
+    pub friends: ::protobuf::RepeatedField<super::base::FriendPt>,
+    pub requests: ::protobuf::RepeatedField<super::base::FriendPt>,
+    pub blocks: ::std::vec::Vec<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_FRIEND_LIST {
+    fn default() -> &'a S_FRIEND_LIST {
+        <S_FRIEND_LIST as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_FRIEND_LIST {
+    pub fn new() -> S_FRIEND_LIST {
+        ::std::default::Default::default()
+    }
+
+    // repeated .protos.FriendPt friends = 1;
+
+
+    pub fn get_friends(&self) -> &[super::base::FriendPt] {
+        &self.friends
+    }
+    pub fn clear_friends(&mut self) {
+        self.friends.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_friends(&mut self, v: ::protobuf::RepeatedField<super::base::FriendPt>) {
+        self.friends = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_friends(&mut self) -> &mut ::protobuf::RepeatedField<super::base::FriendPt> {
+        &mut self.friends
+    }
+
+    // Take field
+    pub fn take_friends(&mut self) -> ::protobuf::RepeatedField<super::base::FriendPt> {
+        ::std::mem::replace(&mut self.friends, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .protos.FriendPt requests = 2;
+
+
+    pub fn get_requests(&self) -> &[super::base::FriendPt] {
+        &self.requests
+    }
+    pub fn clear_requests(&mut self) {
+        self.requests.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requests(&mut self, v: ::protobuf::RepeatedField<super::base::FriendPt>) {
+        self.requests = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_requests(&mut self) -> &mut ::protobuf::RepeatedField<super::base::FriendPt> {
+        &mut self.requests
+    }
+
+    // Take field
+    pub fn take_requests(&mut self) -> ::protobuf::RepeatedField<super::base::FriendPt> {
+        ::std::mem::replace(&mut self.requests, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated uint32 blocks = 3;
+
+
+    pub fn get_blocks(&self) -> &[u32] {
+        &self.blocks
+    }
+    pub fn clear_blocks(&mut self) {
+        self.blocks.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_blocks(&mut self, v: ::std::vec::Vec<u32>) {
+        self.blocks = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_blocks(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.blocks
+    }
+
+    // Take field
+    pub fn take_blocks(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.blocks, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for S_FRIEND_LIST {
+    fn is_initialized(&self) -> bool {
+        for v in &self.friends {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.requests {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.friends)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.requests)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.blocks)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.friends {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.requests {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.blocks {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.friends {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.requests {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.blocks {
+            os.write_uint32(3, *v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_FRIEND_LIST {
+        S_FRIEND_LIST::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::FriendPt>>(
+                "friends",
+                |m: &S_FRIEND_LIST| { &m.friends },
+                |m: &mut S_FRIEND_LIST| { &mut m.friends },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::FriendPt>>(
+                "requests",
+                |m: &S_FRIEND_LIST| { &m.requests },
+                |m: &mut S_FRIEND_LIST| { &mut m.requests },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "blocks",
+                |m: &S_FRIEND_LIST| { &m.blocks },
+                |m: &mut S_FRIEND_LIST| { &mut m.blocks },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_FRIEND_LIST>(
+                "S_FRIEND_LIST",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_FRIEND_LIST {
+        static instance: ::protobuf::rt::LazyV2<S_FRIEND_LIST> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_FRIEND_LIST::new)
+    }
+}
+
+impl ::protobuf::Clear for S_FRIEND_LIST {
+    fn clear(&mut self) {
+        self.friends.clear();
+        self.requests.clear();
+        self.blocks.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_FRIEND_LIST {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_FRIEND_LIST {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_FRIEND_REQUEST {
+    // message fields
+    pub target_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_FRIEND_REQUEST {
+    fn default() -> &'a C_FRIEND_REQUEST {
+        <C_FRIEND_REQUEST as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_FRIEND_REQUEST {
+    pub fn new() -> C_FRIEND_REQUEST {
+        ::std::default::Default::default()
+    }
+
+    // uint32 target_id = 1;
+
+
+    pub fn get_target_id(&self) -> u32 {
+        self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: u32) {
+        self.target_id = v;
+    }
+}
+
+impl ::protobuf::Message for C_FRIEND_REQUEST {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.target_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.target_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.target_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.target_id != 0 {
+            os.write_uint32(1, self.target_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_FRIEND_REQUEST {
+        C_FRIEND_REQUEST::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "target_id",
+                |m: &C_FRIEND_REQUEST| { &m.target_id },
+                |m: &mut C_FRIEND_REQUEST| { &mut m.target_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_FRIEND_REQUEST>(
+                "C_FRIEND_REQUEST",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_FRIEND_REQUEST {
+        static instance: ::protobuf::rt::LazyV2<C_FRIEND_REQUEST> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_FRIEND_REQUEST::new)
+    }
+}
+
+impl ::protobuf::Clear for C_FRIEND_REQUEST {
+    fn clear(&mut self) {
+        self.target_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_FRIEND_REQUEST {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_FRIEND_REQUEST {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_FRIEND_REQUEST {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_FRIEND_REQUEST {
+    fn default() -> &'a S_FRIEND_REQUEST {
+        <S_FRIEND_REQUEST as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_FRIEND_REQUEST {
+    pub fn new() -> S_FRIEND_REQUEST {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for S_FRIEND_REQUEST {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_FRIEND_REQUEST {
+        S_FRIEND_REQUEST::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_FRIEND_REQUEST| { &m.is_succ },
+                |m: &mut S_FRIEND_REQUEST| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_FRIEND_REQUEST| { &m.err_mess },
+                |m: &mut S_FRIEND_REQUEST| { &mut m.err_mess },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_FRIEND_REQUEST>(
+                "S_FRIEND_REQUEST",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_FRIEND_REQUEST {
+        static instance: ::protobuf::rt::LazyV2<S_FRIEND_REQUEST> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_FRIEND_REQUEST::new)
+    }
+}
+
+impl ::protobuf::Clear for S_FRIEND_REQUEST {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_FRIEND_REQUEST {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_FRIEND_REQUEST {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_FRIEND_REQUEST_NOTICE {
+    // message fields
+    pub from: ::protobuf::SingularPtrField<super::base::FriendPt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_FRIEND_REQUEST_NOTICE {
+    fn default() -> &'a S_FRIEND_REQUEST_NOTICE {
+        <S_FRIEND_REQUEST_NOTICE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_FRIEND_REQUEST_NOTICE {
+    pub fn new() -> S_FRIEND_REQUEST_NOTICE {
+        ::std::default::Default::default()
+    }
+
+    // .protos.FriendPt from = 1;
+
+
+    pub fn get_from(&self) -> &super::base::FriendPt {
+        self.from.as_ref().unwrap_or_else(|| <super::base::FriendPt as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_from(&mut self) {
+        self.from.clear();
+    }
+
+    pub fn has_from(&self) -> bool {
+        self.from.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_from(&mut self, v: super::base::FriendPt) {
+        self.from = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_from(&mut self) -> &mut super::base::FriendPt {
+        if self.from.is_none() {
+            self.from.set_default();
+        }
+        self.from.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_from(&mut self) -> super::base::FriendPt {
+        self.from.take().unwrap_or_else(|| super::base::FriendPt::new())
+    }
+}
+
+impl ::protobuf::Message for S_FRIEND_REQUEST_NOTICE {
+    fn is_initialized(&self) -> bool {
+        for v in &self.from {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.from)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.from.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.from.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_FRIEND_REQUEST_NOTICE {
+        S_FRIEND_REQUEST_NOTICE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::FriendPt>>(
+                "from",
+                |m: &S_FRIEND_REQUEST_NOTICE| { &m.from },
+                |m: &mut S_FRIEND_REQUEST_NOTICE| { &mut m.from },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_FRIEND_REQUEST_NOTICE>(
+                "S_FRIEND_REQUEST_NOTICE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_FRIEND_REQUEST_NOTICE {
+        static instance: ::protobuf::rt::LazyV2<S_FRIEND_REQUEST_NOTICE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_FRIEND_REQUEST_NOTICE::new)
+    }
+}
+
+impl ::protobuf::Clear for S_FRIEND_REQUEST_NOTICE {
+    fn clear(&mut self) {
+        self.from.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_FRIEND_REQUEST_NOTICE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_FRIEND_REQUEST_NOTICE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_FRIEND_REPLY {
+    // message fields
+    pub target_id: u32,
+    pub agree: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_FRIEND_REPLY {
+    fn default() -> &'a C_FRIEND_REPLY {
+        <C_FRIEND_REPLY as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_FRIEND_REPLY {
+    pub fn new() -> C_FRIEND_REPLY {
+        ::std::default::Default::default()
+    }
+
+    // uint32 target_id = 1;
+
+
+    pub fn get_target_id(&self) -> u32 {
+        self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: u32) {
+        self.target_id = v;
+    }
+
+    // bool agree = 2;
+
+
+    pub fn get_agree(&self) -> bool {
+        self.agree
+    }
+    pub fn clear_agree(&mut self) {
+        self.agree = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_agree(&mut self, v: bool) {
+        self.agree = v;
+    }
+}
+
+impl ::protobuf::Message for C_FRIEND_REPLY {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.target_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.agree = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.target_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.target_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.agree != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.target_id != 0 {
+            os.write_uint32(1, self.target_id)?;
+        }
+        if self.agree != false {
+            os.write_bool(2, self.agree)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_FRIEND_REPLY {
+        C_FRIEND_REPLY::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "target_id",
+                |m: &C_FRIEND_REPLY| { &m.target_id },
+                |m: &mut C_FRIEND_REPLY| { &mut m.target_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "agree",
+                |m: &C_FRIEND_REPLY| { &m.agree },
+                |m: &mut C_FRIEND_REPLY| { &mut m.agree },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_FRIEND_REPLY>(
+                "C_FRIEND_REPLY",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_FRIEND_REPLY {
+        static instance: ::protobuf::rt::LazyV2<C_FRIEND_REPLY> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_FRIEND_REPLY::new)
+    }
+}
+
+impl ::protobuf::Clear for C_FRIEND_REPLY {
+    fn clear(&mut self) {
+        self.target_id = 0;
+        self.agree = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_FRIEND_REPLY {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_FRIEND_REPLY {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_FRIEND_REPLY {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    pub target_id: u32,
+    pub agree: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_FRIEND_REPLY {
+    fn default() -> &'a S_FRIEND_REPLY {
+        <S_FRIEND_REPLY as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_FRIEND_REPLY {
+    pub fn new() -> S_FRIEND_REPLY {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+
+    // uint32 target_id = 3;
+
+
+    pub fn get_target_id(&self) -> u32 {
+        self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: u32) {
+        self.target_id = v;
+    }
+
+    // bool agree = 4;
+
+
+    pub fn get_agree(&self) -> bool {
+        self.agree
+    }
+    pub fn clear_agree(&mut self) {
+        self.agree = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_agree(&mut self, v: bool) {
+        self.agree = v;
+    }
+}
+
+impl ::protobuf::Message for S_FRIEND_REPLY {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.target_id = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.agree = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        if self.target_id != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.target_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.agree != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        if self.target_id != 0 {
+            os.write_uint32(3, self.target_id)?;
+        }
+        if self.agree != false {
+            os.write_bool(4, self.agree)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_FRIEND_REPLY {
+        S_FRIEND_REPLY::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_FRIEND_REPLY| { &m.is_succ },
+                |m: &mut S_FRIEND_REPLY| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_FRIEND_REPLY| { &m.err_mess },
+                |m: &mut S_FRIEND_REPLY| { &mut m.err_mess },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "target_id",
+                |m: &S_FRIEND_REPLY| { &m.target_id },
+                |m: &mut S_FRIEND_REPLY| { &mut m.target_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "agree",
+                |m: &S_FRIEND_REPLY| { &m.agree },
+                |m: &mut S_FRIEND_REPLY| { &mut m.agree },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_FRIEND_REPLY>(
+                "S_FRIEND_REPLY",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_FRIEND_REPLY {
+        static instance: ::protobuf::rt::LazyV2<S_FRIEND_REPLY> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_FRIEND_REPLY::new)
+    }
+}
+
+impl ::protobuf::Clear for S_FRIEND_REPLY {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.target_id = 0;
+        self.agree = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_FRIEND_REPLY {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_FRIEND_REPLY {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_FRIEND_NOTICE {
+    // message fields
+    pub friend: ::protobuf::SingularPtrField<super::base::FriendPt>,
+    pub notice_type: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_FRIEND_NOTICE {
+    fn default() -> &'a S_FRIEND_NOTICE {
+        <S_FRIEND_NOTICE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_FRIEND_NOTICE {
+    pub fn new() -> S_FRIEND_NOTICE {
+        ::std::default::Default::default()
+    }
+
+    // .protos.FriendPt friend = 1;
+
+
+    pub fn get_friend(&self) -> &super::base::FriendPt {
+        self.friend.as_ref().unwrap_or_else(|| <super::base::FriendPt as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_friend(&mut self) {
+        self.friend.clear();
+    }
+
+    pub fn has_friend(&self) -> bool {
+        self.friend.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_friend(&mut self, v: super::base::FriendPt) {
+        self.friend = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_friend(&mut self) -> &mut super::base::FriendPt {
+        if self.friend.is_none() {
+            self.friend.set_default();
+        }
+        self.friend.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_friend(&mut self) -> super::base::FriendPt {
+        self.friend.take().unwrap_or_else(|| super::base::FriendPt::new())
+    }
+
+    // uint32 notice_type = 2;
+
+
+    pub fn get_notice_type(&self) -> u32 {
+        self.notice_type
+    }
+    pub fn clear_notice_type(&mut self) {
+        self.notice_type = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_notice_type(&mut self, v: u32) {
+        self.notice_type = v;
+    }
+}
+
+impl ::protobuf::Message for S_FRIEND_NOTICE {
+    fn is_initialized(&self) -> bool {
+        for v in &self.friend {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.friend)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.notice_type = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.friend.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.notice_type != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.notice_type, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.friend.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.notice_type != 0 {
+            os.write_uint32(2, self.notice_type)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_FRIEND_NOTICE {
+        S_FRIEND_NOTICE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::FriendPt>>(
+                "friend",
+                |m: &S_FRIEND_NOTICE| { &m.friend },
+                |m: &mut S_FRIEND_NOTICE| { &mut m.friend },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "notice_type",
+                |m: &S_FRIEND_NOTICE| { &m.notice_type },
+                |m: &mut S_FRIEND_NOTICE| { &mut m.notice_type },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_FRIEND_NOTICE>(
+                "S_FRIEND_NOTICE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_FRIEND_NOTICE {
+        static instance: ::protobuf::rt::LazyV2<S_FRIEND_NOTICE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_FRIEND_NOTICE::new)
+    }
+}
+
+impl ::protobuf::Clear for S_FRIEND_NOTICE {
+    fn clear(&mut self) {
+        self.friend.clear();
+        self.notice_type = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_FRIEND_NOTICE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_FRIEND_NOTICE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_FRIEND_REMOVE {
+    // message fields
+    pub target_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_FRIEND_REMOVE {
+    fn default() -> &'a C_FRIEND_REMOVE {
+        <C_FRIEND_REMOVE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_FRIEND_REMOVE {
+    pub fn new() -> C_FRIEND_REMOVE {
+        ::std::default::Default::default()
+    }
+
+    // uint32 target_id = 1;
+
+
+    pub fn get_target_id(&self) -> u32 {
+        self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: u32) {
+        self.target_id = v;
+    }
+}
+
+impl ::protobuf::Message for C_FRIEND_REMOVE {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.target_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.target_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.target_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.target_id != 0 {
+            os.write_uint32(1, self.target_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_FRIEND_REMOVE {
+        C_FRIEND_REMOVE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "target_id",
+                |m: &C_FRIEND_REMOVE| { &m.target_id },
+                |m: &mut C_FRIEND_REMOVE| { &mut m.target_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_FRIEND_REMOVE>(
+                "C_FRIEND_REMOVE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_FRIEND_REMOVE {
+        static instance: ::protobuf::rt::LazyV2<C_FRIEND_REMOVE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_FRIEND_REMOVE::new)
+    }
+}
+
+impl ::protobuf::Clear for C_FRIEND_REMOVE {
+    fn clear(&mut self) {
+        self.target_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_FRIEND_REMOVE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_FRIEND_REMOVE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_FRIEND_REMOVE {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    pub target_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_FRIEND_REMOVE {
+    fn default() -> &'a S_FRIEND_REMOVE {
+        <S_FRIEND_REMOVE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_FRIEND_REMOVE {
+    pub fn new() -> S_FRIEND_REMOVE {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+
+    // uint32 target_id = 3;
+
+
+    pub fn get_target_id(&self) -> u32 {
+        self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: u32) {
+        self.target_id = v;
+    }
+}
+
+impl ::protobuf::Message for S_FRIEND_REMOVE {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.target_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        if self.target_id != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.target_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        if self.target_id != 0 {
+            os.write_uint32(3, self.target_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_FRIEND_REMOVE {
+        S_FRIEND_REMOVE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_FRIEND_REMOVE| { &m.is_succ },
+                |m: &mut S_FRIEND_REMOVE| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_FRIEND_REMOVE| { &m.err_mess },
+                |m: &mut S_FRIEND_REMOVE| { &mut m.err_mess },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "target_id",
+                |m: &S_FRIEND_REMOVE| { &m.target_id },
+                |m: &mut S_FRIEND_REMOVE| { &mut m.target_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_FRIEND_REMOVE>(
+                "S_FRIEND_REMOVE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_FRIEND_REMOVE {
+        static instance: ::protobuf::rt::LazyV2<S_FRIEND_REMOVE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_FRIEND_REMOVE::new)
+    }
+}
+
+impl ::protobuf::Clear for S_FRIEND_REMOVE {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.target_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_FRIEND_REMOVE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_FRIEND_REMOVE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_FRIEND_BLOCK {
+    // message fields
+    pub target_id: u32,
+    pub is_block: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_FRIEND_BLOCK {
+    fn default() -> &'a C_FRIEND_BLOCK {
+        <C_FRIEND_BLOCK as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_FRIEND_BLOCK {
+    pub fn new() -> C_FRIEND_BLOCK {
+        ::std::default::Default::default()
+    }
+
+    // uint32 target_id = 1;
+
+
+    pub fn get_target_id(&self) -> u32 {
+        self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: u32) {
+        self.target_id = v;
+    }
+
+    // bool is_block = 2;
+
+
+    pub fn get_is_block(&self) -> bool {
+        self.is_block
+    }
+    pub fn clear_is_block(&mut self) {
+        self.is_block = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_block(&mut self, v: bool) {
+        self.is_block = v;
+    }
+}
+
+impl ::protobuf::Message for C_FRIEND_BLOCK {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.target_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_block = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.target_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.target_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.is_block != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.target_id != 0 {
+            os.write_uint32(1, self.target_id)?;
+        }
+        if self.is_block != false {
+            os.write_bool(2, self.is_block)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_FRIEND_BLOCK {
+        C_FRIEND_BLOCK::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "target_id",
+                |m: &C_FRIEND_BLOCK| { &m.target_id },
+                |m: &mut C_FRIEND_BLOCK| { &mut m.target_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_block",
+                |m: &C_FRIEND_BLOCK| { &m.is_block },
+                |m: &mut C_FRIEND_BLOCK| { &mut m.is_block },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_FRIEND_BLOCK>(
+                "C_FRIEND_BLOCK",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_FRIEND_BLOCK {
+        static instance: ::protobuf::rt::LazyV2<C_FRIEND_BLOCK> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_FRIEND_BLOCK::new)
+    }
+}
+
+impl ::protobuf::Clear for C_FRIEND_BLOCK {
+    fn clear(&mut self) {
+        self.target_id = 0;
+        self.is_block = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_FRIEND_BLOCK {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_FRIEND_BLOCK {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_FRIEND_BLOCK {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    pub target_id: u32,
+    pub is_block: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_FRIEND_BLOCK {
+    fn default() -> &'a S_FRIEND_BLOCK {
+        <S_FRIEND_BLOCK as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_FRIEND_BLOCK {
+    pub fn new() -> S_FRIEND_BLOCK {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+
+    // uint32 target_id = 3;
+
+
+    pub fn get_target_id(&self) -> u32 {
+        self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: u32) {
+        self.target_id = v;
+    }
+
+    // bool is_block = 4;
+
+
+    pub fn get_is_block(&self) -> bool {
+        self.is_block
+    }
+    pub fn clear_is_block(&mut self) {
+        self.is_block = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_block(&mut self, v: bool) {
+        self.is_block = v;
+    }
+}
+
+impl ::protobuf::Message for S_FRIEND_BLOCK {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.target_id = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_block = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        if self.target_id != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.target_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.is_block != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        if self.target_id != 0 {
+            os.write_uint32(3, self.target_id)?;
+        }
+        if self.is_block != false {
+            os.write_bool(4, self.is_block)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_FRIEND_BLOCK {
+        S_FRIEND_BLOCK::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_FRIEND_BLOCK| { &m.is_succ },
+                |m: &mut S_FRIEND_BLOCK| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_FRIEND_BLOCK| { &m.err_mess },
+                |m: &mut S_FRIEND_BLOCK| { &mut m.err_mess },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "target_id",
+                |m: &S_FRIEND_BLOCK| { &m.target_id },
+                |m: &mut S_FRIEND_BLOCK| { &mut m.target_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_block",
+                |m: &S_FRIEND_BLOCK| { &m.is_block },
+                |m: &mut S_FRIEND_BLOCK| { &mut m.is_block },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_FRIEND_BLOCK>(
+                "S_FRIEND_BLOCK",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_FRIEND_BLOCK {
+        static instance: ::protobuf::rt::LazyV2<S_FRIEND_BLOCK> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_FRIEND_BLOCK::new)
+    }
+}
+
+impl ::protobuf::Clear for S_FRIEND_BLOCK {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.target_id = 0;
+        self.is_block = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_FRIEND_BLOCK {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_FRIEND_BLOCK {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_PARTY_INVITE {
+    // message fields
+    pub target_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_PARTY_INVITE {
+    fn default() -> &'a C_PARTY_INVITE {
+        <C_PARTY_INVITE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_PARTY_INVITE {
+    pub fn new() -> C_PARTY_INVITE {
+        ::std::default::Default::default()
+    }
+
+    // uint32 target_id = 1;
+
+
+    pub fn get_target_id(&self) -> u32 {
+        self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: u32) {
+        self.target_id = v;
+    }
+}
+
+impl ::protobuf::Message for C_PARTY_INVITE {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.target_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.target_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.target_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.target_id != 0 {
+            os.write_uint32(1, self.target_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_PARTY_INVITE {
+        C_PARTY_INVITE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "target_id",
+                |m: &C_PARTY_INVITE| { &m.target_id },
+                |m: &mut C_PARTY_INVITE| { &mut m.target_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_PARTY_INVITE>(
+                "C_PARTY_INVITE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_PARTY_INVITE {
+        static instance: ::protobuf::rt::LazyV2<C_PARTY_INVITE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_PARTY_INVITE::new)
+    }
+}
+
+impl ::protobuf::Clear for C_PARTY_INVITE {
+    fn clear(&mut self) {
+        self.target_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_PARTY_INVITE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_PARTY_INVITE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_PARTY_INVITE {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_PARTY_INVITE {
+    fn default() -> &'a S_PARTY_INVITE {
+        <S_PARTY_INVITE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_PARTY_INVITE {
+    pub fn new() -> S_PARTY_INVITE {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for S_PARTY_INVITE {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_PARTY_INVITE {
+        S_PARTY_INVITE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_PARTY_INVITE| { &m.is_succ },
+                |m: &mut S_PARTY_INVITE| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_PARTY_INVITE| { &m.err_mess },
+                |m: &mut S_PARTY_INVITE| { &mut m.err_mess },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_PARTY_INVITE>(
+                "S_PARTY_INVITE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_PARTY_INVITE {
+        static instance: ::protobuf::rt::LazyV2<S_PARTY_INVITE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_PARTY_INVITE::new)
+    }
+}
+
+impl ::protobuf::Clear for S_PARTY_INVITE {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_PARTY_INVITE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_PARTY_INVITE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_PARTY_INVITE_NOTICE {
+    // message fields
+    pub leader_id: u32,
+    pub nick_name: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_PARTY_INVITE_NOTICE {
+    fn default() -> &'a S_PARTY_INVITE_NOTICE {
+        <S_PARTY_INVITE_NOTICE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_PARTY_INVITE_NOTICE {
+    pub fn new() -> S_PARTY_INVITE_NOTICE {
+        ::std::default::Default::default()
+    }
+
+    // uint32 leader_id = 1;
+
+
+    pub fn get_leader_id(&self) -> u32 {
+        self.leader_id
+    }
+    pub fn clear_leader_id(&mut self) {
+        self.leader_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_leader_id(&mut self, v: u32) {
+        self.leader_id = v;
+    }
+
+    // string nick_name = 2;
+
+
+    pub fn get_nick_name(&self) -> &str {
+        &self.nick_name
+    }
+    pub fn clear_nick_name(&mut self) {
+        self.nick_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nick_name(&mut self, v: ::std::string::String) {
+        self.nick_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nick_name(&mut self) -> &mut ::std::string::String {
+        &mut self.nick_name
+    }
+
+    // Take field
+    pub fn take_nick_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.nick_name, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for S_PARTY_INVITE_NOTICE {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.leader_id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.nick_name)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.leader_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.leader_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.nick_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.nick_name);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.leader_id != 0 {
+            os.write_uint32(1, self.leader_id)?;
+        }
+        if !self.nick_name.is_empty() {
+            os.write_string(2, &self.nick_name)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_PARTY_INVITE_NOTICE {
+        S_PARTY_INVITE_NOTICE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "leader_id",
+                |m: &S_PARTY_INVITE_NOTICE| { &m.leader_id },
+                |m: &mut S_PARTY_INVITE_NOTICE| { &mut m.leader_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "nick_name",
+                |m: &S_PARTY_INVITE_NOTICE| { &m.nick_name },
+                |m: &mut S_PARTY_INVITE_NOTICE| { &mut m.nick_name },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_PARTY_INVITE_NOTICE>(
+                "S_PARTY_INVITE_NOTICE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_PARTY_INVITE_NOTICE {
+        static instance: ::protobuf::rt::LazyV2<S_PARTY_INVITE_NOTICE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_PARTY_INVITE_NOTICE::new)
+    }
+}
+
+impl ::protobuf::Clear for S_PARTY_INVITE_NOTICE {
+    fn clear(&mut self) {
+        self.leader_id = 0;
+        self.nick_name.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_PARTY_INVITE_NOTICE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_PARTY_INVITE_NOTICE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_PARTY_JOIN {
+    // message fields
+    pub leader_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_PARTY_JOIN {
+    fn default() -> &'a C_PARTY_JOIN {
+        <C_PARTY_JOIN as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_PARTY_JOIN {
+    pub fn new() -> C_PARTY_JOIN {
+        ::std::default::Default::default()
+    }
+
+    // uint32 leader_id = 1;
+
+
+    pub fn get_leader_id(&self) -> u32 {
+        self.leader_id
+    }
+    pub fn clear_leader_id(&mut self) {
+        self.leader_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_leader_id(&mut self, v: u32) {
+        self.leader_id = v;
+    }
+}
+
+impl ::protobuf::Message for C_PARTY_JOIN {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.leader_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.leader_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.leader_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.leader_id != 0 {
+            os.write_uint32(1, self.leader_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_PARTY_JOIN {
+        C_PARTY_JOIN::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "leader_id",
+                |m: &C_PARTY_JOIN| { &m.leader_id },
+                |m: &mut C_PARTY_JOIN| { &mut m.leader_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_PARTY_JOIN>(
+                "C_PARTY_JOIN",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_PARTY_JOIN {
+        static instance: ::protobuf::rt::LazyV2<C_PARTY_JOIN> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_PARTY_JOIN::new)
+    }
+}
+
+impl ::protobuf::Clear for C_PARTY_JOIN {
+    fn clear(&mut self) {
+        self.leader_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_PARTY_JOIN {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_PARTY_JOIN {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_PARTY_JOIN {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_PARTY_JOIN {
+    fn default() -> &'a S_PARTY_JOIN {
+        <S_PARTY_JOIN as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_PARTY_JOIN {
+    pub fn new() -> S_PARTY_JOIN {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for S_PARTY_JOIN {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_PARTY_JOIN {
+        S_PARTY_JOIN::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_PARTY_JOIN| { &m.is_succ },
+                |m: &mut S_PARTY_JOIN| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_PARTY_JOIN| { &m.err_mess },
+                |m: &mut S_PARTY_JOIN| { &mut m.err_mess },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_PARTY_JOIN>(
+                "S_PARTY_JOIN",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_PARTY_JOIN {
+        static instance: ::protobuf::rt::LazyV2<S_PARTY_JOIN> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_PARTY_JOIN::new)
+    }
+}
+
+impl ::protobuf::Clear for S_PARTY_JOIN {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_PARTY_JOIN {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_PARTY_JOIN {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_PARTY_NOTICE {
+    // message fields
+    pub party: ::protobuf::SingularPtrField<super::base::PartyPt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_PARTY_NOTICE {
+    fn default() -> &'a S_PARTY_NOTICE {
+        <S_PARTY_NOTICE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_PARTY_NOTICE {
+    pub fn new() -> S_PARTY_NOTICE {
+        ::std::default::Default::default()
+    }
+
+    // .protos.PartyPt party = 1;
+
+
+    pub fn get_party(&self) -> &super::base::PartyPt {
+        self.party.as_ref().unwrap_or_else(|| <super::base::PartyPt as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_party(&mut self) {
+        self.party.clear();
+    }
+
+    pub fn has_party(&self) -> bool {
+        self.party.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_party(&mut self, v: super::base::PartyPt) {
+        self.party = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_party(&mut self) -> &mut super::base::PartyPt {
+        if self.party.is_none() {
+            self.party.set_default();
+        }
+        self.party.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_party(&mut self) -> super::base::PartyPt {
+        self.party.take().unwrap_or_else(|| super::base::PartyPt::new())
+    }
+}
+
+impl ::protobuf::Message for S_PARTY_NOTICE {
+    fn is_initialized(&self) -> bool {
+        for v in &self.party {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.party)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.party.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.party.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_PARTY_NOTICE {
+        S_PARTY_NOTICE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::PartyPt>>(
+                "party",
+                |m: &S_PARTY_NOTICE| { &m.party },
+                |m: &mut S_PARTY_NOTICE| { &mut m.party },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_PARTY_NOTICE>(
+                "S_PARTY_NOTICE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_PARTY_NOTICE {
+        static instance: ::protobuf::rt::LazyV2<S_PARTY_NOTICE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_PARTY_NOTICE::new)
+    }
+}
+
+impl ::protobuf::Clear for S_PARTY_NOTICE {
+    fn clear(&mut self) {
+        self.party.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_PARTY_NOTICE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_PARTY_NOTICE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_PARTY_LEAVE {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_PARTY_LEAVE {
+    fn default() -> &'a C_PARTY_LEAVE {
+        <C_PARTY_LEAVE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_PARTY_LEAVE {
+    pub fn new() -> C_PARTY_LEAVE {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for C_PARTY_LEAVE {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_PARTY_LEAVE {
+        C_PARTY_LEAVE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_PARTY_LEAVE>(
+                "C_PARTY_LEAVE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_PARTY_LEAVE {
+        static instance: ::protobuf::rt::LazyV2<C_PARTY_LEAVE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_PARTY_LEAVE::new)
+    }
+}
+
+impl ::protobuf::Clear for C_PARTY_LEAVE {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_PARTY_LEAVE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_PARTY_LEAVE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_MAIL_LIST {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_MAIL_LIST {
+    fn default() -> &'a C_MAIL_LIST {
+        <C_MAIL_LIST as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_MAIL_LIST {
+    pub fn new() -> C_MAIL_LIST {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for C_MAIL_LIST {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_MAIL_LIST {
+        C_MAIL_LIST::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_MAIL_LIST>(
+                "C_MAIL_LIST",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_MAIL_LIST {
+        static instance: ::protobuf::rt::LazyV2<C_MAIL_LIST> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_MAIL_LIST::new)
+    }
+}
+
+impl ::protobuf::Clear for C_MAIL_LIST {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_MAIL_LIST {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_MAIL_LIST {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_MAIL_LIST {
+    // message fields
+    pub mails: ::protobuf::RepeatedField<super::base::MailPt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_MAIL_LIST {
+    fn default() -> &'a S_MAIL_LIST {
+        <S_MAIL_LIST as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_MAIL_LIST {
+    pub fn new() -> S_MAIL_LIST {
+        ::std::default::Default::default()
+    }
+
+    // repeated .protos.MailPt mails = 1;
+
+
+    pub fn get_mails(&self) -> &[super::base::MailPt] {
+        &self.mails
+    }
+    pub fn clear_mails(&mut self) {
+        self.mails.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mails(&mut self, v: ::protobuf::RepeatedField<super::base::MailPt>) {
+        self.mails = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_mails(&mut self) -> &mut ::protobuf::RepeatedField<super::base::MailPt> {
+        &mut self.mails
+    }
+
+    // Take field
+    pub fn take_mails(&mut self) -> ::protobuf::RepeatedField<super::base::MailPt> {
+        ::std::mem::replace(&mut self.mails, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for S_MAIL_LIST {
+    fn is_initialized(&self) -> bool {
+        for v in &self.mails {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.mails)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.mails {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.mails {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_MAIL_LIST {
+        S_MAIL_LIST::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::MailPt>>(
+                "mails",
+                |m: &S_MAIL_LIST| { &m.mails },
+                |m: &mut S_MAIL_LIST| { &mut m.mails },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_MAIL_LIST>(
+                "S_MAIL_LIST",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_MAIL_LIST {
+        static instance: ::protobuf::rt::LazyV2<S_MAIL_LIST> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_MAIL_LIST::new)
+    }
+}
+
+impl ::protobuf::Clear for S_MAIL_LIST {
+    fn clear(&mut self) {
+        self.mails.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_MAIL_LIST {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_MAIL_LIST {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_MAIL_READ {
+    // message fields
+    pub mail_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_MAIL_READ {
+    fn default() -> &'a C_MAIL_READ {
+        <C_MAIL_READ as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_MAIL_READ {
+    pub fn new() -> C_MAIL_READ {
+        ::std::default::Default::default()
+    }
+
+    // uint32 mail_id = 1;
+
+
+    pub fn get_mail_id(&self) -> u32 {
+        self.mail_id
+    }
+    pub fn clear_mail_id(&mut self) {
+        self.mail_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mail_id(&mut self, v: u32) {
+        self.mail_id = v;
+    }
+}
+
+impl ::protobuf::Message for C_MAIL_READ {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.mail_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.mail_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.mail_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.mail_id != 0 {
+            os.write_uint32(1, self.mail_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_MAIL_READ {
+        C_MAIL_READ::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "mail_id",
+                |m: &C_MAIL_READ| { &m.mail_id },
+                |m: &mut C_MAIL_READ| { &mut m.mail_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_MAIL_READ>(
+                "C_MAIL_READ",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_MAIL_READ {
+        static instance: ::protobuf::rt::LazyV2<C_MAIL_READ> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_MAIL_READ::new)
+    }
+}
+
+impl ::protobuf::Clear for C_MAIL_READ {
+    fn clear(&mut self) {
+        self.mail_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_MAIL_READ {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_MAIL_READ {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_MAIL_READ {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    pub mail_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_MAIL_READ {
+    fn default() -> &'a S_MAIL_READ {
+        <S_MAIL_READ as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_MAIL_READ {
+    pub fn new() -> S_MAIL_READ {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+
+    // uint32 mail_id = 3;
+
+
+    pub fn get_mail_id(&self) -> u32 {
+        self.mail_id
+    }
+    pub fn clear_mail_id(&mut self) {
+        self.mail_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mail_id(&mut self, v: u32) {
+        self.mail_id = v;
+    }
+}
+
+impl ::protobuf::Message for S_MAIL_READ {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.mail_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        if self.mail_id != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.mail_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        if self.mail_id != 0 {
+            os.write_uint32(3, self.mail_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_MAIL_READ {
+        S_MAIL_READ::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_MAIL_READ| { &m.is_succ },
+                |m: &mut S_MAIL_READ| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_MAIL_READ| { &m.err_mess },
+                |m: &mut S_MAIL_READ| { &mut m.err_mess },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "mail_id",
+                |m: &S_MAIL_READ| { &m.mail_id },
+                |m: &mut S_MAIL_READ| { &mut m.mail_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_MAIL_READ>(
+                "S_MAIL_READ",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_MAIL_READ {
+        static instance: ::protobuf::rt::LazyV2<S_MAIL_READ> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_MAIL_READ::new)
+    }
+}
+
+impl ::protobuf::Clear for S_MAIL_READ {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.mail_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_MAIL_READ {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_MAIL_READ {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_MAIL_CLAIM {
+    // message fields
+    pub mail_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_MAIL_CLAIM {
+    fn default() -> &'a C_MAIL_CLAIM {
+        <C_MAIL_CLAIM as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_MAIL_CLAIM {
+    pub fn new() -> C_MAIL_CLAIM {
+        ::std::default::Default::default()
+    }
+
+    // uint32 mail_id = 1;
+
+
+    pub fn get_mail_id(&self) -> u32 {
+        self.mail_id
+    }
+    pub fn clear_mail_id(&mut self) {
+        self.mail_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mail_id(&mut self, v: u32) {
+        self.mail_id = v;
+    }
+}
+
+impl ::protobuf::Message for C_MAIL_CLAIM {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.mail_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.mail_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.mail_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.mail_id != 0 {
+            os.write_uint32(1, self.mail_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_MAIL_CLAIM {
+        C_MAIL_CLAIM::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "mail_id",
+                |m: &C_MAIL_CLAIM| { &m.mail_id },
+                |m: &mut C_MAIL_CLAIM| { &mut m.mail_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_MAIL_CLAIM>(
+                "C_MAIL_CLAIM",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_MAIL_CLAIM {
+        static instance: ::protobuf::rt::LazyV2<C_MAIL_CLAIM> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_MAIL_CLAIM::new)
+    }
+}
+
+impl ::protobuf::Clear for C_MAIL_CLAIM {
+    fn clear(&mut self) {
+        self.mail_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_MAIL_CLAIM {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_MAIL_CLAIM {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_MAIL_CLAIM {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    pub mail_ids: ::std::vec::Vec<u32>,
+    pub attachments: ::protobuf::RepeatedField<super::base::AttachmentPt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_MAIL_CLAIM {
+    fn default() -> &'a S_MAIL_CLAIM {
+        <S_MAIL_CLAIM as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_MAIL_CLAIM {
+    pub fn new() -> S_MAIL_CLAIM {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+
+    // repeated uint32 mail_ids = 3;
+
+
+    pub fn get_mail_ids(&self) -> &[u32] {
+        &self.mail_ids
+    }
+    pub fn clear_mail_ids(&mut self) {
+        self.mail_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mail_ids(&mut self, v: ::std::vec::Vec<u32>) {
+        self.mail_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_mail_ids(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.mail_ids
+    }
+
+    // Take field
+    pub fn take_mail_ids(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.mail_ids, ::std::vec::Vec::new())
+    }
+
+    // repeated .protos.AttachmentPt attachments = 4;
+
+
+    pub fn get_attachments(&self) -> &[super::base::AttachmentPt] {
+        &self.attachments
+    }
+    pub fn clear_attachments(&mut self) {
+        self.attachments.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attachments(&mut self, v: ::protobuf::RepeatedField<super::base::AttachmentPt>) {
+        self.attachments = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_attachments(&mut self) -> &mut ::protobuf::RepeatedField<super::base::AttachmentPt> {
+        &mut self.attachments
+    }
+
+    // Take field
+    pub fn take_attachments(&mut self) -> ::protobuf::RepeatedField<super::base::AttachmentPt> {
+        ::std::mem::replace(&mut self.attachments, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for S_MAIL_CLAIM {
+    fn is_initialized(&self) -> bool {
+        for v in &self.attachments {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.mail_ids)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.attachments)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        for value in &self.mail_ids {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.attachments {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        for v in &self.mail_ids {
+            os.write_uint32(3, *v)?;
+        };
+        for v in &self.attachments {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_MAIL_CLAIM {
+        S_MAIL_CLAIM::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_MAIL_CLAIM| { &m.is_succ },
+                |m: &mut S_MAIL_CLAIM| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_MAIL_CLAIM| { &m.err_mess },
+                |m: &mut S_MAIL_CLAIM| { &mut m.err_mess },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "mail_ids",
+                |m: &S_MAIL_CLAIM| { &m.mail_ids },
+                |m: &mut S_MAIL_CLAIM| { &mut m.mail_ids },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::AttachmentPt>>(
+                "attachments",
+                |m: &S_MAIL_CLAIM| { &m.attachments },
+                |m: &mut S_MAIL_CLAIM| { &mut m.attachments },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_MAIL_CLAIM>(
+                "S_MAIL_CLAIM",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_MAIL_CLAIM {
+        static instance: ::protobuf::rt::LazyV2<S_MAIL_CLAIM> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_MAIL_CLAIM::new)
+    }
+}
+
+impl ::protobuf::Clear for S_MAIL_CLAIM {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.mail_ids.clear();
+        self.attachments.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_MAIL_CLAIM {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_MAIL_CLAIM {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_MAIL_DELETE {
+    // message fields
+    pub mail_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_MAIL_DELETE {
+    fn default() -> &'a C_MAIL_DELETE {
+        <C_MAIL_DELETE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_MAIL_DELETE {
+    pub fn new() -> C_MAIL_DELETE {
+        ::std::default::Default::default()
+    }
+
+    // uint32 mail_id = 1;
+
+
+    pub fn get_mail_id(&self) -> u32 {
+        self.mail_id
+    }
+    pub fn clear_mail_id(&mut self) {
+        self.mail_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mail_id(&mut self, v: u32) {
+        self.mail_id = v;
+    }
+}
+
+impl ::protobuf::Message for C_MAIL_DELETE {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.mail_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.mail_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.mail_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.mail_id != 0 {
+            os.write_uint32(1, self.mail_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_MAIL_DELETE {
+        C_MAIL_DELETE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "mail_id",
+                |m: &C_MAIL_DELETE| { &m.mail_id },
+                |m: &mut C_MAIL_DELETE| { &mut m.mail_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_MAIL_DELETE>(
+                "C_MAIL_DELETE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_MAIL_DELETE {
+        static instance: ::protobuf::rt::LazyV2<C_MAIL_DELETE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_MAIL_DELETE::new)
+    }
+}
+
+impl ::protobuf::Clear for C_MAIL_DELETE {
+    fn clear(&mut self) {
+        self.mail_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_MAIL_DELETE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_MAIL_DELETE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_MAIL_DELETE {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    pub mail_ids: ::std::vec::Vec<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_MAIL_DELETE {
+    fn default() -> &'a S_MAIL_DELETE {
+        <S_MAIL_DELETE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_MAIL_DELETE {
+    pub fn new() -> S_MAIL_DELETE {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+
+    // repeated uint32 mail_ids = 3;
+
+
+    pub fn get_mail_ids(&self) -> &[u32] {
+        &self.mail_ids
+    }
+    pub fn clear_mail_ids(&mut self) {
+        self.mail_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mail_ids(&mut self, v: ::std::vec::Vec<u32>) {
+        self.mail_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_mail_ids(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.mail_ids
+    }
+
+    // Take field
+    pub fn take_mail_ids(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.mail_ids, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for S_MAIL_DELETE {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.mail_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        for value in &self.mail_ids {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        for v in &self.mail_ids {
+            os.write_uint32(3, *v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_MAIL_DELETE {
+        S_MAIL_DELETE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_MAIL_DELETE| { &m.is_succ },
+                |m: &mut S_MAIL_DELETE| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_MAIL_DELETE| { &m.err_mess },
+                |m: &mut S_MAIL_DELETE| { &mut m.err_mess },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "mail_ids",
+                |m: &S_MAIL_DELETE| { &m.mail_ids },
+                |m: &mut S_MAIL_DELETE| { &mut m.mail_ids },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_MAIL_DELETE>(
+                "S_MAIL_DELETE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_MAIL_DELETE {
+        static instance: ::protobuf::rt::LazyV2<S_MAIL_DELETE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_MAIL_DELETE::new)
+    }
+}
+
+impl ::protobuf::Clear for S_MAIL_DELETE {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.mail_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_MAIL_DELETE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_MAIL_DELETE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_MAIL_NOTICE {
+    // message fields
+    pub mails: ::protobuf::RepeatedField<super::base::MailPt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_MAIL_NOTICE {
+    fn default() -> &'a S_MAIL_NOTICE {
+        <S_MAIL_NOTICE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_MAIL_NOTICE {
+    pub fn new() -> S_MAIL_NOTICE {
+        ::std::default::Default::default()
+    }
+
+    // repeated .protos.MailPt mails = 1;
+
+
+    pub fn get_mails(&self) -> &[super::base::MailPt] {
+        &self.mails
+    }
+    pub fn clear_mails(&mut self) {
+        self.mails.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mails(&mut self, v: ::protobuf::RepeatedField<super::base::MailPt>) {
+        self.mails = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_mails(&mut self) -> &mut ::protobuf::RepeatedField<super::base::MailPt> {
+        &mut self.mails
+    }
+
+    // Take field
+    pub fn take_mails(&mut self) -> ::protobuf::RepeatedField<super::base::MailPt> {
+        ::std::mem::replace(&mut self.mails, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for S_MAIL_NOTICE {
+    fn is_initialized(&self) -> bool {
+        for v in &self.mails {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.mails)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.mails {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.mails {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_MAIL_NOTICE {
+        S_MAIL_NOTICE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::MailPt>>(
+                "mails",
+                |m: &S_MAIL_NOTICE| { &m.mails },
+                |m: &mut S_MAIL_NOTICE| { &mut m.mails },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_MAIL_NOTICE>(
+                "S_MAIL_NOTICE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_MAIL_NOTICE {
+        static instance: ::protobuf::rt::LazyV2<S_MAIL_NOTICE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_MAIL_NOTICE::new)
+    }
+}
+
+impl ::protobuf::Clear for S_MAIL_NOTICE {
+    fn clear(&mut self) {
+        self.mails.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_MAIL_NOTICE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_MAIL_NOTICE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_QUEST_LIST {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_QUEST_LIST {
+    fn default() -> &'a C_QUEST_LIST {
+        <C_QUEST_LIST as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_QUEST_LIST {
+    pub fn new() -> C_QUEST_LIST {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for C_QUEST_LIST {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_QUEST_LIST {
+        C_QUEST_LIST::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_QUEST_LIST>(
+                "C_QUEST_LIST",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_QUEST_LIST {
+        static instance: ::protobuf::rt::LazyV2<C_QUEST_LIST> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_QUEST_LIST::new)
+    }
+}
+
+impl ::protobuf::Clear for C_QUEST_LIST {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_QUEST_LIST {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_QUEST_LIST {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_QUEST_LIST {
+    // message fields
+    pub quests: ::protobuf::RepeatedField<super::base::QuestPt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_QUEST_LIST {
+    fn default() -> &'a S_QUEST_LIST {
+        <S_QUEST_LIST as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_QUEST_LIST {
+    pub fn new() -> S_QUEST_LIST {
+        ::std::default::Default::default()
+    }
+
+    // repeated .protos.QuestPt quests = 1;
+
+
+    pub fn get_quests(&self) -> &[super::base::QuestPt] {
+        &self.quests
+    }
+    pub fn clear_quests(&mut self) {
+        self.quests.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_quests(&mut self, v: ::protobuf::RepeatedField<super::base::QuestPt>) {
+        self.quests = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_quests(&mut self) -> &mut ::protobuf::RepeatedField<super::base::QuestPt> {
+        &mut self.quests
+    }
+
+    // Take field
+    pub fn take_quests(&mut self) -> ::protobuf::RepeatedField<super::base::QuestPt> {
+        ::std::mem::replace(&mut self.quests, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for S_QUEST_LIST {
+    fn is_initialized(&self) -> bool {
+        for v in &self.quests {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.quests)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.quests {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.quests {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_QUEST_LIST {
+        S_QUEST_LIST::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::QuestPt>>(
+                "quests",
+                |m: &S_QUEST_LIST| { &m.quests },
+                |m: &mut S_QUEST_LIST| { &mut m.quests },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_QUEST_LIST>(
+                "S_QUEST_LIST",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_QUEST_LIST {
+        static instance: ::protobuf::rt::LazyV2<S_QUEST_LIST> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_QUEST_LIST::new)
+    }
+}
+
+impl ::protobuf::Clear for S_QUEST_LIST {
+    fn clear(&mut self) {
+        self.quests.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_QUEST_LIST {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_QUEST_LIST {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_QUEST_CLAIM {
+    // message fields
+    pub quest_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_QUEST_CLAIM {
+    fn default() -> &'a C_QUEST_CLAIM {
+        <C_QUEST_CLAIM as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_QUEST_CLAIM {
+    pub fn new() -> C_QUEST_CLAIM {
+        ::std::default::Default::default()
+    }
+
+    // uint32 quest_id = 1;
+
+
+    pub fn get_quest_id(&self) -> u32 {
+        self.quest_id
+    }
+    pub fn clear_quest_id(&mut self) {
+        self.quest_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_quest_id(&mut self, v: u32) {
+        self.quest_id = v;
+    }
+}
+
+impl ::protobuf::Message for C_QUEST_CLAIM {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.quest_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.quest_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.quest_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.quest_id != 0 {
+            os.write_uint32(1, self.quest_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_QUEST_CLAIM {
+        C_QUEST_CLAIM::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "quest_id",
+                |m: &C_QUEST_CLAIM| { &m.quest_id },
+                |m: &mut C_QUEST_CLAIM| { &mut m.quest_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_QUEST_CLAIM>(
+                "C_QUEST_CLAIM",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_QUEST_CLAIM {
+        static instance: ::protobuf::rt::LazyV2<C_QUEST_CLAIM> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_QUEST_CLAIM::new)
+    }
+}
+
+impl ::protobuf::Clear for C_QUEST_CLAIM {
+    fn clear(&mut self) {
+        self.quest_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_QUEST_CLAIM {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_QUEST_CLAIM {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_QUEST_CLAIM {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    pub quest_id: u32,
+    pub attachments: ::protobuf::RepeatedField<super::base::AttachmentPt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_QUEST_CLAIM {
+    fn default() -> &'a S_QUEST_CLAIM {
+        <S_QUEST_CLAIM as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_QUEST_CLAIM {
+    pub fn new() -> S_QUEST_CLAIM {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+
+    // uint32 quest_id = 3;
+
+
+    pub fn get_quest_id(&self) -> u32 {
+        self.quest_id
+    }
+    pub fn clear_quest_id(&mut self) {
+        self.quest_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_quest_id(&mut self, v: u32) {
+        self.quest_id = v;
+    }
+
+    // repeated .protos.AttachmentPt attachments = 4;
+
+
+    pub fn get_attachments(&self) -> &[super::base::AttachmentPt] {
+        &self.attachments
+    }
+    pub fn clear_attachments(&mut self) {
+        self.attachments.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attachments(&mut self, v: ::protobuf::RepeatedField<super::base::AttachmentPt>) {
+        self.attachments = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_attachments(&mut self) -> &mut ::protobuf::RepeatedField<super::base::AttachmentPt> {
+        &mut self.attachments
+    }
+
+    // Take field
+    pub fn take_attachments(&mut self) -> ::protobuf::RepeatedField<super::base::AttachmentPt> {
+        ::std::mem::replace(&mut self.attachments, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for S_QUEST_CLAIM {
+    fn is_initialized(&self) -> bool {
+        for v in &self.attachments {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.quest_id = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.attachments)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        if self.quest_id != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.quest_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.attachments {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        if self.quest_id != 0 {
+            os.write_uint32(3, self.quest_id)?;
+        }
+        for v in &self.attachments {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_QUEST_CLAIM {
+        S_QUEST_CLAIM::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_QUEST_CLAIM| { &m.is_succ },
+                |m: &mut S_QUEST_CLAIM| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_QUEST_CLAIM| { &m.err_mess },
+                |m: &mut S_QUEST_CLAIM| { &mut m.err_mess },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "quest_id",
+                |m: &S_QUEST_CLAIM| { &m.quest_id },
+                |m: &mut S_QUEST_CLAIM| { &mut m.quest_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::AttachmentPt>>(
+                "attachments",
+                |m: &S_QUEST_CLAIM| { &m.attachments },
+                |m: &mut S_QUEST_CLAIM| { &mut m.attachments },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_QUEST_CLAIM>(
+                "S_QUEST_CLAIM",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_QUEST_CLAIM {
+        static instance: ::protobuf::rt::LazyV2<S_QUEST_CLAIM> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_QUEST_CLAIM::new)
+    }
+}
+
+impl ::protobuf::Clear for S_QUEST_CLAIM {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.quest_id = 0;
+        self.attachments.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_QUEST_CLAIM {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_QUEST_CLAIM {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_QUEST_NOTICE {
+    // message fields
+    pub quests: ::protobuf::RepeatedField<super::base::QuestPt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_QUEST_NOTICE {
+    fn default() -> &'a S_QUEST_NOTICE {
+        <S_QUEST_NOTICE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_QUEST_NOTICE {
+    pub fn new() -> S_QUEST_NOTICE {
+        ::std::default::Default::default()
+    }
+
+    // repeated .protos.QuestPt quests = 1;
+
+
+    pub fn get_quests(&self) -> &[super::base::QuestPt] {
+        &self.quests
+    }
+    pub fn clear_quests(&mut self) {
+        self.quests.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_quests(&mut self, v: ::protobuf::RepeatedField<super::base::QuestPt>) {
+        self.quests = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_quests(&mut self) -> &mut ::protobuf::RepeatedField<super::base::QuestPt> {
+        &mut self.quests
+    }
+
+    // Take field
+    pub fn take_quests(&mut self) -> ::protobuf::RepeatedField<super::base::QuestPt> {
+        ::std::mem::replace(&mut self.quests, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for S_QUEST_NOTICE {
+    fn is_initialized(&self) -> bool {
+        for v in &self.quests {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.quests)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.quests {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.quests {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_QUEST_NOTICE {
+        S_QUEST_NOTICE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::QuestPt>>(
+                "quests",
+                |m: &S_QUEST_NOTICE| { &m.quests },
+                |m: &mut S_QUEST_NOTICE| { &mut m.quests },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_QUEST_NOTICE>(
+                "S_QUEST_NOTICE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_QUEST_NOTICE {
+        static instance: ::protobuf::rt::LazyV2<S_QUEST_NOTICE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_QUEST_NOTICE::new)
+    }
+}
+
+impl ::protobuf::Clear for S_QUEST_NOTICE {
+    fn clear(&mut self) {
+        self.quests.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_QUEST_NOTICE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_QUEST_NOTICE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_ACHIEVEMENT_LIST {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_ACHIEVEMENT_LIST {
+    fn default() -> &'a C_ACHIEVEMENT_LIST {
+        <C_ACHIEVEMENT_LIST as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_ACHIEVEMENT_LIST {
+    pub fn new() -> C_ACHIEVEMENT_LIST {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for C_ACHIEVEMENT_LIST {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_ACHIEVEMENT_LIST {
+        C_ACHIEVEMENT_LIST::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_ACHIEVEMENT_LIST>(
+                "C_ACHIEVEMENT_LIST",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_ACHIEVEMENT_LIST {
+        static instance: ::protobuf::rt::LazyV2<C_ACHIEVEMENT_LIST> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_ACHIEVEMENT_LIST::new)
+    }
+}
+
+impl ::protobuf::Clear for C_ACHIEVEMENT_LIST {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_ACHIEVEMENT_LIST {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_ACHIEVEMENT_LIST {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_ACHIEVEMENT_LIST {
+    // message fields
+    pub achievements: ::protobuf::RepeatedField<super::base::AchievementPt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_ACHIEVEMENT_LIST {
+    fn default() -> &'a S_ACHIEVEMENT_LIST {
+        <S_ACHIEVEMENT_LIST as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_ACHIEVEMENT_LIST {
+    pub fn new() -> S_ACHIEVEMENT_LIST {
+        ::std::default::Default::default()
+    }
+
+    // repeated .protos.AchievementPt achievements = 1;
+
+
+    pub fn get_achievements(&self) -> &[super::base::AchievementPt] {
+        &self.achievements
+    }
+    pub fn clear_achievements(&mut self) {
+        self.achievements.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_achievements(&mut self, v: ::protobuf::RepeatedField<super::base::AchievementPt>) {
+        self.achievements = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_achievements(&mut self) -> &mut ::protobuf::RepeatedField<super::base::AchievementPt> {
+        &mut self.achievements
+    }
+
+    // Take field
+    pub fn take_achievements(&mut self) -> ::protobuf::RepeatedField<super::base::AchievementPt> {
+        ::std::mem::replace(&mut self.achievements, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for S_ACHIEVEMENT_LIST {
+    fn is_initialized(&self) -> bool {
+        for v in &self.achievements {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.achievements)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.achievements {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.achievements {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_ACHIEVEMENT_LIST {
+        S_ACHIEVEMENT_LIST::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::AchievementPt>>(
+                "achievements",
+                |m: &S_ACHIEVEMENT_LIST| { &m.achievements },
+                |m: &mut S_ACHIEVEMENT_LIST| { &mut m.achievements },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_ACHIEVEMENT_LIST>(
+                "S_ACHIEVEMENT_LIST",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_ACHIEVEMENT_LIST {
+        static instance: ::protobuf::rt::LazyV2<S_ACHIEVEMENT_LIST> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_ACHIEVEMENT_LIST::new)
+    }
+}
+
+impl ::protobuf::Clear for S_ACHIEVEMENT_LIST {
+    fn clear(&mut self) {
+        self.achievements.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_ACHIEVEMENT_LIST {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_ACHIEVEMENT_LIST {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_ACHIEVEMENT_NOTICE {
+    // message fields
+    pub achievements: ::protobuf::RepeatedField<super::base::AchievementPt>,
+    pub grade_frames: ::std::vec::Vec<u32>,
+    pub souls: ::std::vec::Vec<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_ACHIEVEMENT_NOTICE {
+    fn default() -> &'a S_ACHIEVEMENT_NOTICE {
+        <S_ACHIEVEMENT_NOTICE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_ACHIEVEMENT_NOTICE {
+    pub fn new() -> S_ACHIEVEMENT_NOTICE {
+        ::std::default::Default::default()
+    }
+
+    // repeated .protos.AchievementPt achievements = 1;
+
+
+    pub fn get_achievements(&self) -> &[super::base::AchievementPt] {
+        &self.achievements
+    }
+    pub fn clear_achievements(&mut self) {
+        self.achievements.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_achievements(&mut self, v: ::protobuf::RepeatedField<super::base::AchievementPt>) {
+        self.achievements = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_achievements(&mut self) -> &mut ::protobuf::RepeatedField<super::base::AchievementPt> {
+        &mut self.achievements
+    }
+
+    // Take field
+    pub fn take_achievements(&mut self) -> ::protobuf::RepeatedField<super::base::AchievementPt> {
+        ::std::mem::replace(&mut self.achievements, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated uint32 grade_frames = 2;
+
+
+    pub fn get_grade_frames(&self) -> &[u32] {
+        &self.grade_frames
+    }
+    pub fn clear_grade_frames(&mut self) {
+        self.grade_frames.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grade_frames(&mut self, v: ::std::vec::Vec<u32>) {
+        self.grade_frames = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_grade_frames(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.grade_frames
+    }
+
+    // Take field
+    pub fn take_grade_frames(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.grade_frames, ::std::vec::Vec::new())
+    }
+
+    // repeated uint32 souls = 3;
+
+
+    pub fn get_souls(&self) -> &[u32] {
+        &self.souls
+    }
+    pub fn clear_souls(&mut self) {
+        self.souls.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_souls(&mut self, v: ::std::vec::Vec<u32>) {
+        self.souls = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_souls(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.souls
+    }
+
+    // Take field
+    pub fn take_souls(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.souls, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for S_ACHIEVEMENT_NOTICE {
+    fn is_initialized(&self) -> bool {
+        for v in &self.achievements {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.achievements)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.grade_frames)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.souls)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.achievements {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.grade_frames {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.souls {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.achievements {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.grade_frames {
+            os.write_uint32(2, *v)?;
+        };
+        for v in &self.souls {
+            os.write_uint32(3, *v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_ACHIEVEMENT_NOTICE {
+        S_ACHIEVEMENT_NOTICE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::AchievementPt>>(
+                "achievements",
+                |m: &S_ACHIEVEMENT_NOTICE| { &m.achievements },
+                |m: &mut S_ACHIEVEMENT_NOTICE| { &mut m.achievements },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "grade_frames",
+                |m: &S_ACHIEVEMENT_NOTICE| { &m.grade_frames },
+                |m: &mut S_ACHIEVEMENT_NOTICE| { &mut m.grade_frames },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "souls",
+                |m: &S_ACHIEVEMENT_NOTICE| { &m.souls },
+                |m: &mut S_ACHIEVEMENT_NOTICE| { &mut m.souls },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_ACHIEVEMENT_NOTICE>(
+                "S_ACHIEVEMENT_NOTICE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_ACHIEVEMENT_NOTICE {
+        static instance: ::protobuf::rt::LazyV2<S_ACHIEVEMENT_NOTICE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_ACHIEVEMENT_NOTICE::new)
+    }
+}
+
+impl ::protobuf::Clear for S_ACHIEVEMENT_NOTICE {
+    fn clear(&mut self) {
+        self.achievements.clear();
+        self.grade_frames.clear();
+        self.souls.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_ACHIEVEMENT_NOTICE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_ACHIEVEMENT_NOTICE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_TOURNAMENT_LIST {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_TOURNAMENT_LIST {
+    fn default() -> &'a C_TOURNAMENT_LIST {
+        <C_TOURNAMENT_LIST as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_TOURNAMENT_LIST {
+    pub fn new() -> C_TOURNAMENT_LIST {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for C_TOURNAMENT_LIST {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_TOURNAMENT_LIST {
+        C_TOURNAMENT_LIST::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_TOURNAMENT_LIST>(
+                "C_TOURNAMENT_LIST",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_TOURNAMENT_LIST {
+        static instance: ::protobuf::rt::LazyV2<C_TOURNAMENT_LIST> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_TOURNAMENT_LIST::new)
+    }
+}
+
+impl ::protobuf::Clear for C_TOURNAMENT_LIST {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_TOURNAMENT_LIST {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_TOURNAMENT_LIST {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_TOURNAMENT_LIST {
+    // message fields
+    pub tournaments: ::protobuf::RepeatedField<super::base::TournamentPt>,
+    pub signed_ids: ::std::vec::Vec<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_TOURNAMENT_LIST {
+    fn default() -> &'a S_TOURNAMENT_LIST {
+        <S_TOURNAMENT_LIST as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_TOURNAMENT_LIST {
+    pub fn new() -> S_TOURNAMENT_LIST {
+        ::std::default::Default::default()
+    }
+
+    // repeated .protos.TournamentPt tournaments = 1;
+
+
+    pub fn get_tournaments(&self) -> &[super::base::TournamentPt] {
+        &self.tournaments
+    }
+    pub fn clear_tournaments(&mut self) {
+        self.tournaments.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tournaments(&mut self, v: ::protobuf::RepeatedField<super::base::TournamentPt>) {
+        self.tournaments = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tournaments(&mut self) -> &mut ::protobuf::RepeatedField<super::base::TournamentPt> {
+        &mut self.tournaments
+    }
+
+    // Take field
+    pub fn take_tournaments(&mut self) -> ::protobuf::RepeatedField<super::base::TournamentPt> {
+        ::std::mem::replace(&mut self.tournaments, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated uint32 signed_ids = 2;
+
+
+    pub fn get_signed_ids(&self) -> &[u32] {
+        &self.signed_ids
+    }
+    pub fn clear_signed_ids(&mut self) {
+        self.signed_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signed_ids(&mut self, v: ::std::vec::Vec<u32>) {
+        self.signed_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_signed_ids(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.signed_ids
+    }
+
+    // Take field
+    pub fn take_signed_ids(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.signed_ids, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for S_TOURNAMENT_LIST {
+    fn is_initialized(&self) -> bool {
+        for v in &self.tournaments {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.tournaments)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.signed_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.tournaments {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.signed_ids {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.tournaments {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.signed_ids {
+            os.write_uint32(2, *v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_TOURNAMENT_LIST {
+        S_TOURNAMENT_LIST::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::TournamentPt>>(
+                "tournaments",
+                |m: &S_TOURNAMENT_LIST| { &m.tournaments },
+                |m: &mut S_TOURNAMENT_LIST| { &mut m.tournaments },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "signed_ids",
+                |m: &S_TOURNAMENT_LIST| { &m.signed_ids },
+                |m: &mut S_TOURNAMENT_LIST| { &mut m.signed_ids },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_TOURNAMENT_LIST>(
+                "S_TOURNAMENT_LIST",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_TOURNAMENT_LIST {
+        static instance: ::protobuf::rt::LazyV2<S_TOURNAMENT_LIST> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_TOURNAMENT_LIST::new)
+    }
+}
+
+impl ::protobuf::Clear for S_TOURNAMENT_LIST {
+    fn clear(&mut self) {
+        self.tournaments.clear();
+        self.signed_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_TOURNAMENT_LIST {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_TOURNAMENT_LIST {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_TOURNAMENT_SIGN_UP {
+    // message fields
+    pub tournament_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_TOURNAMENT_SIGN_UP {
+    fn default() -> &'a C_TOURNAMENT_SIGN_UP {
+        <C_TOURNAMENT_SIGN_UP as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_TOURNAMENT_SIGN_UP {
+    pub fn new() -> C_TOURNAMENT_SIGN_UP {
+        ::std::default::Default::default()
+    }
+
+    // uint32 tournament_id = 1;
+
+
+    pub fn get_tournament_id(&self) -> u32 {
+        self.tournament_id
+    }
+    pub fn clear_tournament_id(&mut self) {
+        self.tournament_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tournament_id(&mut self, v: u32) {
+        self.tournament_id = v;
+    }
+}
+
+impl ::protobuf::Message for C_TOURNAMENT_SIGN_UP {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.tournament_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.tournament_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.tournament_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.tournament_id != 0 {
+            os.write_uint32(1, self.tournament_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_TOURNAMENT_SIGN_UP {
+        C_TOURNAMENT_SIGN_UP::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "tournament_id",
+                |m: &C_TOURNAMENT_SIGN_UP| { &m.tournament_id },
+                |m: &mut C_TOURNAMENT_SIGN_UP| { &mut m.tournament_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_TOURNAMENT_SIGN_UP>(
+                "C_TOURNAMENT_SIGN_UP",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_TOURNAMENT_SIGN_UP {
+        static instance: ::protobuf::rt::LazyV2<C_TOURNAMENT_SIGN_UP> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_TOURNAMENT_SIGN_UP::new)
+    }
+}
+
+impl ::protobuf::Clear for C_TOURNAMENT_SIGN_UP {
+    fn clear(&mut self) {
+        self.tournament_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_TOURNAMENT_SIGN_UP {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_TOURNAMENT_SIGN_UP {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_TOURNAMENT_SIGN_UP {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    pub tournament_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_TOURNAMENT_SIGN_UP {
+    fn default() -> &'a S_TOURNAMENT_SIGN_UP {
+        <S_TOURNAMENT_SIGN_UP as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_TOURNAMENT_SIGN_UP {
+    pub fn new() -> S_TOURNAMENT_SIGN_UP {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+
+    // uint32 tournament_id = 3;
+
+
+    pub fn get_tournament_id(&self) -> u32 {
+        self.tournament_id
+    }
+    pub fn clear_tournament_id(&mut self) {
+        self.tournament_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tournament_id(&mut self, v: u32) {
+        self.tournament_id = v;
+    }
+}
+
+impl ::protobuf::Message for S_TOURNAMENT_SIGN_UP {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.tournament_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        if self.tournament_id != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.tournament_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        if self.tournament_id != 0 {
+            os.write_uint32(3, self.tournament_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_TOURNAMENT_SIGN_UP {
+        S_TOURNAMENT_SIGN_UP::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_TOURNAMENT_SIGN_UP| { &m.is_succ },
+                |m: &mut S_TOURNAMENT_SIGN_UP| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_TOURNAMENT_SIGN_UP| { &m.err_mess },
+                |m: &mut S_TOURNAMENT_SIGN_UP| { &mut m.err_mess },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "tournament_id",
+                |m: &S_TOURNAMENT_SIGN_UP| { &m.tournament_id },
+                |m: &mut S_TOURNAMENT_SIGN_UP| { &mut m.tournament_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_TOURNAMENT_SIGN_UP>(
+                "S_TOURNAMENT_SIGN_UP",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_TOURNAMENT_SIGN_UP {
+        static instance: ::protobuf::rt::LazyV2<S_TOURNAMENT_SIGN_UP> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_TOURNAMENT_SIGN_UP::new)
+    }
+}
+
+impl ::protobuf::Clear for S_TOURNAMENT_SIGN_UP {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.tournament_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_TOURNAMENT_SIGN_UP {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_TOURNAMENT_SIGN_UP {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_TOURNAMENT_BRACKET {
+    // message fields
+    pub tournament_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_TOURNAMENT_BRACKET {
+    fn default() -> &'a C_TOURNAMENT_BRACKET {
+        <C_TOURNAMENT_BRACKET as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_TOURNAMENT_BRACKET {
+    pub fn new() -> C_TOURNAMENT_BRACKET {
+        ::std::default::Default::default()
+    }
+
+    // uint32 tournament_id = 1;
+
+
+    pub fn get_tournament_id(&self) -> u32 {
+        self.tournament_id
+    }
+    pub fn clear_tournament_id(&mut self) {
+        self.tournament_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tournament_id(&mut self, v: u32) {
+        self.tournament_id = v;
+    }
+}
+
+impl ::protobuf::Message for C_TOURNAMENT_BRACKET {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.tournament_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.tournament_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.tournament_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.tournament_id != 0 {
+            os.write_uint32(1, self.tournament_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_TOURNAMENT_BRACKET {
+        C_TOURNAMENT_BRACKET::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "tournament_id",
+                |m: &C_TOURNAMENT_BRACKET| { &m.tournament_id },
+                |m: &mut C_TOURNAMENT_BRACKET| { &mut m.tournament_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_TOURNAMENT_BRACKET>(
+                "C_TOURNAMENT_BRACKET",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_TOURNAMENT_BRACKET {
+        static instance: ::protobuf::rt::LazyV2<C_TOURNAMENT_BRACKET> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_TOURNAMENT_BRACKET::new)
+    }
+}
+
+impl ::protobuf::Clear for C_TOURNAMENT_BRACKET {
+    fn clear(&mut self) {
+        self.tournament_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_TOURNAMENT_BRACKET {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_TOURNAMENT_BRACKET {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_TOURNAMENT_BRACKET {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    pub tournament: ::protobuf::SingularPtrField<super::base::TournamentPt>,
+    pub players: ::protobuf::RepeatedField<super::base::TournamentPlayerPt>,
+    pub matches: ::protobuf::RepeatedField<super::base::TournamentMatchPt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_TOURNAMENT_BRACKET {
+    fn default() -> &'a S_TOURNAMENT_BRACKET {
+        <S_TOURNAMENT_BRACKET as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_TOURNAMENT_BRACKET {
+    pub fn new() -> S_TOURNAMENT_BRACKET {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+
+    // .protos.TournamentPt tournament = 3;
+
+
+    pub fn get_tournament(&self) -> &super::base::TournamentPt {
+        self.tournament.as_ref().unwrap_or_else(|| <super::base::TournamentPt as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_tournament(&mut self) {
+        self.tournament.clear();
+    }
+
+    pub fn has_tournament(&self) -> bool {
+        self.tournament.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tournament(&mut self, v: super::base::TournamentPt) {
+        self.tournament = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tournament(&mut self) -> &mut super::base::TournamentPt {
+        if self.tournament.is_none() {
+            self.tournament.set_default();
+        }
+        self.tournament.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_tournament(&mut self) -> super::base::TournamentPt {
+        self.tournament.take().unwrap_or_else(|| super::base::TournamentPt::new())
+    }
+
+    // repeated .protos.TournamentPlayerPt players = 4;
+
+
+    pub fn get_players(&self) -> &[super::base::TournamentPlayerPt] {
+        &self.players
+    }
+    pub fn clear_players(&mut self) {
+        self.players.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_players(&mut self, v: ::protobuf::RepeatedField<super::base::TournamentPlayerPt>) {
+        self.players = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_players(&mut self) -> &mut ::protobuf::RepeatedField<super::base::TournamentPlayerPt> {
+        &mut self.players
+    }
+
+    // Take field
+    pub fn take_players(&mut self) -> ::protobuf::RepeatedField<super::base::TournamentPlayerPt> {
+        ::std::mem::replace(&mut self.players, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .protos.TournamentMatchPt matches = 5;
+
+
+    pub fn get_matches(&self) -> &[super::base::TournamentMatchPt] {
+        &self.matches
+    }
+    pub fn clear_matches(&mut self) {
+        self.matches.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_matches(&mut self, v: ::protobuf::RepeatedField<super::base::TournamentMatchPt>) {
+        self.matches = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_matches(&mut self) -> &mut ::protobuf::RepeatedField<super::base::TournamentMatchPt> {
+        &mut self.matches
+    }
+
+    // Take field
+    pub fn take_matches(&mut self) -> ::protobuf::RepeatedField<super::base::TournamentMatchPt> {
+        ::std::mem::replace(&mut self.matches, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for S_TOURNAMENT_BRACKET {
+    fn is_initialized(&self) -> bool {
+        for v in &self.tournament {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.players {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.matches {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.tournament)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.players)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.matches)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        if let Some(ref v) = self.tournament.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.players {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.matches {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        if let Some(ref v) = self.tournament.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.players {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.matches {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_TOURNAMENT_BRACKET {
+        S_TOURNAMENT_BRACKET::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_TOURNAMENT_BRACKET| { &m.is_succ },
+                |m: &mut S_TOURNAMENT_BRACKET| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_TOURNAMENT_BRACKET| { &m.err_mess },
+                |m: &mut S_TOURNAMENT_BRACKET| { &mut m.err_mess },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::TournamentPt>>(
+                "tournament",
+                |m: &S_TOURNAMENT_BRACKET| { &m.tournament },
+                |m: &mut S_TOURNAMENT_BRACKET| { &mut m.tournament },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::TournamentPlayerPt>>(
+                "players",
+                |m: &S_TOURNAMENT_BRACKET| { &m.players },
+                |m: &mut S_TOURNAMENT_BRACKET| { &mut m.players },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::TournamentMatchPt>>(
+                "matches",
+                |m: &S_TOURNAMENT_BRACKET| { &m.matches },
+                |m: &mut S_TOURNAMENT_BRACKET| { &mut m.matches },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_TOURNAMENT_BRACKET>(
+                "S_TOURNAMENT_BRACKET",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_TOURNAMENT_BRACKET {
+        static instance: ::protobuf::rt::LazyV2<S_TOURNAMENT_BRACKET> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_TOURNAMENT_BRACKET::new)
+    }
+}
+
+impl ::protobuf::Clear for S_TOURNAMENT_BRACKET {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.tournament.clear();
+        self.players.clear();
+        self.matches.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_TOURNAMENT_BRACKET {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_TOURNAMENT_BRACKET {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_TOURNAMENT_NOTICE {
+    // message fields
+    pub tournament_id: u32,
+    pub notice_type: u32,
+    pub round: u32,
+    pub match_id: u32,
+    pub final_rank: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_TOURNAMENT_NOTICE {
+    fn default() -> &'a S_TOURNAMENT_NOTICE {
+        <S_TOURNAMENT_NOTICE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_TOURNAMENT_NOTICE {
+    pub fn new() -> S_TOURNAMENT_NOTICE {
+        ::std::default::Default::default()
+    }
+
+    // uint32 tournament_id = 1;
+
+
+    pub fn get_tournament_id(&self) -> u32 {
+        self.tournament_id
+    }
+    pub fn clear_tournament_id(&mut self) {
+        self.tournament_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tournament_id(&mut self, v: u32) {
+        self.tournament_id = v;
+    }
+
+    // uint32 notice_type = 2;
+
+
+    pub fn get_notice_type(&self) -> u32 {
+        self.notice_type
+    }
+    pub fn clear_notice_type(&mut self) {
+        self.notice_type = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_notice_type(&mut self, v: u32) {
+        self.notice_type = v;
+    }
+
+    // uint32 round = 3;
+
+
+    pub fn get_round(&self) -> u32 {
+        self.round
+    }
+    pub fn clear_round(&mut self) {
+        self.round = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_round(&mut self, v: u32) {
+        self.round = v;
+    }
+
+    // uint32 match_id = 4;
+
+
+    pub fn get_match_id(&self) -> u32 {
+        self.match_id
+    }
+    pub fn clear_match_id(&mut self) {
+        self.match_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_match_id(&mut self, v: u32) {
+        self.match_id = v;
+    }
+
+    // uint32 final_rank = 5;
+
+
+    pub fn get_final_rank(&self) -> u32 {
+        self.final_rank
+    }
+    pub fn clear_final_rank(&mut self) {
+        self.final_rank = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_final_rank(&mut self, v: u32) {
+        self.final_rank = v;
+    }
+}
+
+impl ::protobuf::Message for S_TOURNAMENT_NOTICE {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.tournament_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.notice_type = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.round = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.match_id = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.final_rank = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.tournament_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.tournament_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.notice_type != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.notice_type, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.round != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.round, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.match_id != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.match_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.final_rank != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.final_rank, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.tournament_id != 0 {
+            os.write_uint32(1, self.tournament_id)?;
+        }
+        if self.notice_type != 0 {
+            os.write_uint32(2, self.notice_type)?;
+        }
+        if self.round != 0 {
+            os.write_uint32(3, self.round)?;
+        }
+        if self.match_id != 0 {
+            os.write_uint32(4, self.match_id)?;
+        }
+        if self.final_rank != 0 {
+            os.write_uint32(5, self.final_rank)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_TOURNAMENT_NOTICE {
+        S_TOURNAMENT_NOTICE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "tournament_id",
+                |m: &S_TOURNAMENT_NOTICE| { &m.tournament_id },
+                |m: &mut S_TOURNAMENT_NOTICE| { &mut m.tournament_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "notice_type",
+                |m: &S_TOURNAMENT_NOTICE| { &m.notice_type },
+                |m: &mut S_TOURNAMENT_NOTICE| { &mut m.notice_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "round",
+                |m: &S_TOURNAMENT_NOTICE| { &m.round },
+                |m: &mut S_TOURNAMENT_NOTICE| { &mut m.round },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "match_id",
+                |m: &S_TOURNAMENT_NOTICE| { &m.match_id },
+                |m: &mut S_TOURNAMENT_NOTICE| { &mut m.match_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "final_rank",
+                |m: &S_TOURNAMENT_NOTICE| { &m.final_rank },
+                |m: &mut S_TOURNAMENT_NOTICE| { &mut m.final_rank },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_TOURNAMENT_NOTICE>(
+                "S_TOURNAMENT_NOTICE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_TOURNAMENT_NOTICE {
+        static instance: ::protobuf::rt::LazyV2<S_TOURNAMENT_NOTICE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_TOURNAMENT_NOTICE::new)
+    }
+}
+
+impl ::protobuf::Clear for S_TOURNAMENT_NOTICE {
+    fn clear(&mut self) {
+        self.tournament_id = 0;
+        self.notice_type = 0;
+        self.round = 0;
+        self.match_id = 0;
+        self.final_rank = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_TOURNAMENT_NOTICE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_TOURNAMENT_NOTICE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x0eprotocol.proto\x12\x06protos\x1a\nbase.proto\"{\n\x0cC_USER_LOGIN\
     \x12+\n\x11register_platform\x18\x01\x20\x01(\tR\x10registerPlatform\x12\
@@ -2800,8 +11810,95 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x1dS_MODIFY_GRADE_FRAME_AND_SOUL\x12\x17\n\x07is_succ\x18\x01\x20\x01\
     (\x08R\x06isSucc\x12\x19\n\x08err_mess\x18\x02\x20\x01(\tR\x07errMess\"\
     \x18\n\x16C_GET_LAST_SEASON_RANK\"B\n\x16S_GET_LAST_SEASON_RANK\x12(\n\
-    \x05ranks\x18\x01\x20\x03(\x0b2\x12.protos.RankInfoPtR\x05ranksb\x06prot\
-    o3\
+    \x05ranks\x18\x01\x20\x03(\x0b2\x12.protos.RankInfoPtR\x05ranks\"\x13\n\
+    \x11C_SHOW_WORLD_BOSS\"\xab\x01\n\x11S_SHOW_WORLD_BOSS\x12\"\n\rworld_bo\
+    ss_id\x18\x01\x20\x01(\rR\x0bworldBossId\x12\x0e\n\x02hp\x18\x02\x20\x01\
+    (\x04R\x02hp\x12\x15\n\x06max_hp\x18\x03\x20\x01(\x04R\x05maxHp\x12\x1c\
+    \n\tmilestone\x18\x04\x20\x01(\rR\tmilestone\x12-\n\x05ranks\x18\x05\x20\
+    \x03(\x0b2\x17.protos.WorldBossRankPtR\x05ranks\"S\n\x0cC_GM_COMMAND\x12\
+    \x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x12\n\x04args\x18\x02\x20\
+    \x03(\tR\x04args\x12\x1b\n\ttarget_id\x18\x03\x20\x01(\rR\x08targetId\"Z\
+    \n\x0cS_GM_COMMAND\x12\x17\n\x07is_succ\x18\x01\x20\x01(\x08R\x06isSucc\
+    \x12\x19\n\x08err_mess\x18\x02\x20\x01(\tR\x07errMess\x12\x16\n\x06resul\
+    t\x18\x03\x20\x01(\tR\x06result\"\x0f\n\rC_FRIEND_LIST\"\x81\x01\n\rS_FR\
+    IEND_LIST\x12*\n\x07friends\x18\x01\x20\x03(\x0b2\x10.protos.FriendPtR\
+    \x07friends\x12,\n\x08requests\x18\x02\x20\x03(\x0b2\x10.protos.FriendPt\
+    R\x08requests\x12\x16\n\x06blocks\x18\x03\x20\x03(\rR\x06blocks\"/\n\x10\
+    C_FRIEND_REQUEST\x12\x1b\n\ttarget_id\x18\x01\x20\x01(\rR\x08targetId\"F\
+    \n\x10S_FRIEND_REQUEST\x12\x17\n\x07is_succ\x18\x01\x20\x01(\x08R\x06isS\
+    ucc\x12\x19\n\x08err_mess\x18\x02\x20\x01(\tR\x07errMess\"?\n\x17S_FRIEN\
+    D_REQUEST_NOTICE\x12$\n\x04from\x18\x01\x20\x01(\x0b2\x10.protos.FriendP\
+    tR\x04from\"C\n\x0eC_FRIEND_REPLY\x12\x1b\n\ttarget_id\x18\x01\x20\x01(\
+    \rR\x08targetId\x12\x14\n\x05agree\x18\x02\x20\x01(\x08R\x05agree\"w\n\
+    \x0eS_FRIEND_REPLY\x12\x17\n\x07is_succ\x18\x01\x20\x01(\x08R\x06isSucc\
+    \x12\x19\n\x08err_mess\x18\x02\x20\x01(\tR\x07errMess\x12\x1b\n\ttarget_\
+    id\x18\x03\x20\x01(\rR\x08targetId\x12\x14\n\x05agree\x18\x04\x20\x01(\
+    \x08R\x05agree\"\\\n\x0fS_FRIEND_NOTICE\x12(\n\x06friend\x18\x01\x20\x01\
+    (\x0b2\x10.protos.FriendPtR\x06friend\x12\x1f\n\x0bnotice_type\x18\x02\
+    \x20\x01(\rR\nnoticeType\".\n\x0fC_FRIEND_REMOVE\x12\x1b\n\ttarget_id\
+    \x18\x01\x20\x01(\rR\x08targetId\"b\n\x0fS_FRIEND_REMOVE\x12\x17\n\x07is\
+    _succ\x18\x01\x20\x01(\x08R\x06isSucc\x12\x19\n\x08err_mess\x18\x02\x20\
+    \x01(\tR\x07errMess\x12\x1b\n\ttarget_id\x18\x03\x20\x01(\rR\x08targetId\
+    \"H\n\x0eC_FRIEND_BLOCK\x12\x1b\n\ttarget_id\x18\x01\x20\x01(\rR\x08targ\
+    etId\x12\x19\n\x08is_block\x18\x02\x20\x01(\x08R\x07isBlock\"|\n\x0eS_FR\
+    IEND_BLOCK\x12\x17\n\x07is_succ\x18\x01\x20\x01(\x08R\x06isSucc\x12\x19\
+    \n\x08err_mess\x18\x02\x20\x01(\tR\x07errMess\x12\x1b\n\ttarget_id\x18\
+    \x03\x20\x01(\rR\x08targetId\x12\x19\n\x08is_block\x18\x04\x20\x01(\x08R\
+    \x07isBlock\"-\n\x0eC_PARTY_INVITE\x12\x1b\n\ttarget_id\x18\x01\x20\x01(\
+    \rR\x08targetId\"D\n\x0eS_PARTY_INVITE\x12\x17\n\x07is_succ\x18\x01\x20\
+    \x01(\x08R\x06isSucc\x12\x19\n\x08err_mess\x18\x02\x20\x01(\tR\x07errMes\
+    s\"Q\n\x15S_PARTY_INVITE_NOTICE\x12\x1b\n\tleader_id\x18\x01\x20\x01(\rR\
+    \x08leaderId\x12\x1b\n\tnick_name\x18\x02\x20\x01(\tR\x08nickName\"+\n\
+    \x0cC_PARTY_JOIN\x12\x1b\n\tleader_id\x18\x01\x20\x01(\rR\x08leaderId\"B\
+    \n\x0cS_PARTY_JOIN\x12\x17\n\x07is_succ\x18\x01\x20\x01(\x08R\x06isSucc\
+    \x12\x19\n\x08err_mess\x18\x02\x20\x01(\tR\x07errMess\"7\n\x0eS_PARTY_NO\
+    TICE\x12%\n\x05party\x18\x01\x20\x01(\x0b2\x0f.protos.PartyPtR\x05party\
+    \"\x0f\n\rC_PARTY_LEAVE\"\r\n\x0bC_MAIL_LIST\"3\n\x0bS_MAIL_LIST\x12$\n\
+    \x05mails\x18\x01\x20\x03(\x0b2\x0e.protos.MailPtR\x05mails\"&\n\x0bC_MA\
+    IL_READ\x12\x17\n\x07mail_id\x18\x01\x20\x01(\rR\x06mailId\"Z\n\x0bS_MAI\
+    L_READ\x12\x17\n\x07is_succ\x18\x01\x20\x01(\x08R\x06isSucc\x12\x19\n\
+    \x08err_mess\x18\x02\x20\x01(\tR\x07errMess\x12\x17\n\x07mail_id\x18\x03\
+    \x20\x01(\rR\x06mailId\"'\n\x0cC_MAIL_CLAIM\x12\x17\n\x07mail_id\x18\x01\
+    \x20\x01(\rR\x06mailId\"\x95\x01\n\x0cS_MAIL_CLAIM\x12\x17\n\x07is_succ\
+    \x18\x01\x20\x01(\x08R\x06isSucc\x12\x19\n\x08err_mess\x18\x02\x20\x01(\
+    \tR\x07errMess\x12\x19\n\x08mail_ids\x18\x03\x20\x03(\rR\x07mailIds\x126\
+    \n\x0battachments\x18\x04\x20\x03(\x0b2\x14.protos.AttachmentPtR\x0batta\
+    chments\"(\n\rC_MAIL_DELETE\x12\x17\n\x07mail_id\x18\x01\x20\x01(\rR\x06\
+    mailId\"^\n\rS_MAIL_DELETE\x12\x17\n\x07is_succ\x18\x01\x20\x01(\x08R\
+    \x06isSucc\x12\x19\n\x08err_mess\x18\x02\x20\x01(\tR\x07errMess\x12\x19\
+    \n\x08mail_ids\x18\x03\x20\x03(\rR\x07mailIds\"5\n\rS_MAIL_NOTICE\x12$\n\
+    \x05mails\x18\x01\x20\x03(\x0b2\x0e.protos.MailPtR\x05mails\"\x0e\n\x0cC\
+    _QUEST_LIST\"7\n\x0cS_QUEST_LIST\x12'\n\x06quests\x18\x01\x20\x03(\x0b2\
+    \x0f.protos.QuestPtR\x06quests\"*\n\rC_QUEST_CLAIM\x12\x19\n\x08quest_id\
+    \x18\x01\x20\x01(\rR\x07questId\"\x96\x01\n\rS_QUEST_CLAIM\x12\x17\n\x07\
+    is_succ\x18\x01\x20\x01(\x08R\x06isSucc\x12\x19\n\x08err_mess\x18\x02\
+    \x20\x01(\tR\x07errMess\x12\x19\n\x08quest_id\x18\x03\x20\x01(\rR\x07que\
+    stId\x126\n\x0battachments\x18\x04\x20\x03(\x0b2\x14.protos.AttachmentPt\
+    R\x0battachments\"9\n\x0eS_QUEST_NOTICE\x12'\n\x06quests\x18\x01\x20\x03\
+    (\x0b2\x0f.protos.QuestPtR\x06quests\"\x14\n\x12C_ACHIEVEMENT_LIST\"O\n\
+    \x12S_ACHIEVEMENT_LIST\x129\n\x0cachievements\x18\x01\x20\x03(\x0b2\x15.\
+    protos.AchievementPtR\x0cachievements\"\x8a\x01\n\x14S_ACHIEVEMENT_NOTIC\
+    E\x129\n\x0cachievements\x18\x01\x20\x03(\x0b2\x15.protos.AchievementPtR\
+    \x0cachievements\x12!\n\x0cgrade_frames\x18\x02\x20\x03(\rR\x0bgradeFram\
+    es\x12\x14\n\x05souls\x18\x03\x20\x03(\rR\x05souls\"\x13\n\x11C_TOURNAME\
+    NT_LIST\"j\n\x11S_TOURNAMENT_LIST\x126\n\x0btournaments\x18\x01\x20\x03(\
+    \x0b2\x14.protos.TournamentPtR\x0btournaments\x12\x1d\n\nsigned_ids\x18\
+    \x02\x20\x03(\rR\tsignedIds\";\n\x14C_TOURNAMENT_SIGN_UP\x12#\n\rtournam\
+    ent_id\x18\x01\x20\x01(\rR\x0ctournamentId\"o\n\x14S_TOURNAMENT_SIGN_UP\
+    \x12\x17\n\x07is_succ\x18\x01\x20\x01(\x08R\x06isSucc\x12\x19\n\x08err_m\
+    ess\x18\x02\x20\x01(\tR\x07errMess\x12#\n\rtournament_id\x18\x03\x20\x01\
+    (\rR\x0ctournamentId\";\n\x14C_TOURNAMENT_BRACKET\x12#\n\rtournament_id\
+    \x18\x01\x20\x01(\rR\x0ctournamentId\"\xeb\x01\n\x14S_TOURNAMENT_BRACKET\
+    \x12\x17\n\x07is_succ\x18\x01\x20\x01(\x08R\x06isSucc\x12\x19\n\x08err_m\
+    ess\x18\x02\x20\x01(\tR\x07errMess\x124\n\ntournament\x18\x03\x20\x01(\
+    \x0b2\x14.protos.TournamentPtR\ntournament\x124\n\x07players\x18\x04\x20\
+    \x03(\x0b2\x1a.protos.TournamentPlayerPtR\x07players\x123\n\x07matches\
+    \x18\x05\x20\x03(\x0b2\x19.protos.TournamentMatchPtR\x07matches\"\xab\
+    \x01\n\x13S_TOURNAMENT_NOTICE\x12#\n\rtournament_id\x18\x01\x20\x01(\rR\
+    \x0ctournamentId\x12\x1f\n\x0bnotice_type\x18\x02\x20\x01(\rR\nnoticeTyp\
+    e\x12\x14\n\x05round\x18\x03\x20\x01(\rR\x05round\x12\x19\n\x08match_id\
+    \x18\x04\x20\x01(\rR\x07matchId\x12\x1d\n\nfinal_rank\x18\x05\x20\x01(\r\
+    R\tfinalRankb\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -6562,6 +6562,3156 @@ impl ::protobuf::reflect::ProtobufValue for S_CHOICE_AI_NOTICE {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct C_CHAT {
+    // message fields
+    pub channel: u32,
+    pub content: ::std::string::String,
+    pub target_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_CHAT {
+    fn default() -> &'a C_CHAT {
+        <C_CHAT as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_CHAT {
+    pub fn new() -> C_CHAT {
+        ::std::default::Default::default()
+    }
+
+    // uint32 channel = 1;
+
+
+    pub fn get_channel(&self) -> u32 {
+        self.channel
+    }
+    pub fn clear_channel(&mut self) {
+        self.channel = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_channel(&mut self, v: u32) {
+        self.channel = v;
+    }
+
+    // string content = 2;
+
+
+    pub fn get_content(&self) -> &str {
+        &self.content
+    }
+    pub fn clear_content(&mut self) {
+        self.content.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content(&mut self, v: ::std::string::String) {
+        self.content = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content(&mut self) -> &mut ::std::string::String {
+        &mut self.content
+    }
+
+    // Take field
+    pub fn take_content(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.content, ::std::string::String::new())
+    }
+
+    // uint32 target_id = 3;
+
+
+    pub fn get_target_id(&self) -> u32 {
+        self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: u32) {
+        self.target_id = v;
+    }
+}
+
+impl ::protobuf::Message for C_CHAT {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.channel = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.content)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.target_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.channel != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.channel, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.content.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.content);
+        }
+        if self.target_id != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.target_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.channel != 0 {
+            os.write_uint32(1, self.channel)?;
+        }
+        if !self.content.is_empty() {
+            os.write_string(2, &self.content)?;
+        }
+        if self.target_id != 0 {
+            os.write_uint32(3, self.target_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_CHAT {
+        C_CHAT::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "channel",
+                |m: &C_CHAT| { &m.channel },
+                |m: &mut C_CHAT| { &mut m.channel },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "content",
+                |m: &C_CHAT| { &m.content },
+                |m: &mut C_CHAT| { &mut m.content },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "target_id",
+                |m: &C_CHAT| { &m.target_id },
+                |m: &mut C_CHAT| { &mut m.target_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_CHAT>(
+                "C_CHAT",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_CHAT {
+        static instance: ::protobuf::rt::LazyV2<C_CHAT> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_CHAT::new)
+    }
+}
+
+impl ::protobuf::Clear for C_CHAT {
+    fn clear(&mut self) {
+        self.channel = 0;
+        self.content.clear();
+        self.target_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_CHAT {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_CHAT {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_CHAT {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_CHAT {
+    fn default() -> &'a S_CHAT {
+        <S_CHAT as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_CHAT {
+    pub fn new() -> S_CHAT {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for S_CHAT {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_CHAT {
+        S_CHAT::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_CHAT| { &m.is_succ },
+                |m: &mut S_CHAT| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_CHAT| { &m.err_mess },
+                |m: &mut S_CHAT| { &mut m.err_mess },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_CHAT>(
+                "S_CHAT",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_CHAT {
+        static instance: ::protobuf::rt::LazyV2<S_CHAT> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_CHAT::new)
+    }
+}
+
+impl ::protobuf::Clear for S_CHAT {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_CHAT {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_CHAT {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_CHAT_NOTICE {
+    // message fields
+    pub mess: ::protobuf::SingularPtrField<super::base::NoticeMessPt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_CHAT_NOTICE {
+    fn default() -> &'a S_CHAT_NOTICE {
+        <S_CHAT_NOTICE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_CHAT_NOTICE {
+    pub fn new() -> S_CHAT_NOTICE {
+        ::std::default::Default::default()
+    }
+
+    // .protos.NoticeMessPt mess = 1;
+
+
+    pub fn get_mess(&self) -> &super::base::NoticeMessPt {
+        self.mess.as_ref().unwrap_or_else(|| <super::base::NoticeMessPt as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_mess(&mut self) {
+        self.mess.clear();
+    }
+
+    pub fn has_mess(&self) -> bool {
+        self.mess.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mess(&mut self, v: super::base::NoticeMessPt) {
+        self.mess = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_mess(&mut self) -> &mut super::base::NoticeMessPt {
+        if self.mess.is_none() {
+            self.mess.set_default();
+        }
+        self.mess.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_mess(&mut self) -> super::base::NoticeMessPt {
+        self.mess.take().unwrap_or_else(|| super::base::NoticeMessPt::new())
+    }
+}
+
+impl ::protobuf::Message for S_CHAT_NOTICE {
+    fn is_initialized(&self) -> bool {
+        for v in &self.mess {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.mess)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.mess.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.mess.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_CHAT_NOTICE {
+        S_CHAT_NOTICE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::NoticeMessPt>>(
+                "mess",
+                |m: &S_CHAT_NOTICE| { &m.mess },
+                |m: &mut S_CHAT_NOTICE| { &mut m.mess },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_CHAT_NOTICE>(
+                "S_CHAT_NOTICE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_CHAT_NOTICE {
+        static instance: ::protobuf::rt::LazyV2<S_CHAT_NOTICE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_CHAT_NOTICE::new)
+    }
+}
+
+impl ::protobuf::Clear for S_CHAT_NOTICE {
+    fn clear(&mut self) {
+        self.mess.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_CHAT_NOTICE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_CHAT_NOTICE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_CHAT_HISTORY {
+    // message fields
+    pub messes: ::protobuf::RepeatedField<super::base::HistoryMessPt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_CHAT_HISTORY {
+    fn default() -> &'a S_CHAT_HISTORY {
+        <S_CHAT_HISTORY as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_CHAT_HISTORY {
+    pub fn new() -> S_CHAT_HISTORY {
+        ::std::default::Default::default()
+    }
+
+    // repeated .protos.HistoryMessPt messes = 1;
+
+
+    pub fn get_messes(&self) -> &[super::base::HistoryMessPt] {
+        &self.messes
+    }
+    pub fn clear_messes(&mut self) {
+        self.messes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_messes(&mut self, v: ::protobuf::RepeatedField<super::base::HistoryMessPt>) {
+        self.messes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_messes(&mut self) -> &mut ::protobuf::RepeatedField<super::base::HistoryMessPt> {
+        &mut self.messes
+    }
+
+    // Take field
+    pub fn take_messes(&mut self) -> ::protobuf::RepeatedField<super::base::HistoryMessPt> {
+        ::std::mem::replace(&mut self.messes, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for S_CHAT_HISTORY {
+    fn is_initialized(&self) -> bool {
+        for v in &self.messes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.messes)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.messes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.messes {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_CHAT_HISTORY {
+        S_CHAT_HISTORY::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::HistoryMessPt>>(
+                "messes",
+                |m: &S_CHAT_HISTORY| { &m.messes },
+                |m: &mut S_CHAT_HISTORY| { &mut m.messes },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_CHAT_HISTORY>(
+                "S_CHAT_HISTORY",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_CHAT_HISTORY {
+        static instance: ::protobuf::rt::LazyV2<S_CHAT_HISTORY> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_CHAT_HISTORY::new)
+    }
+}
+
+impl ::protobuf::Clear for S_CHAT_HISTORY {
+    fn clear(&mut self) {
+        self.messes.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_CHAT_HISTORY {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_CHAT_HISTORY {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_CHAT_MUTE {
+    // message fields
+    pub target_id: u32,
+    pub is_mute: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_CHAT_MUTE {
+    fn default() -> &'a C_CHAT_MUTE {
+        <C_CHAT_MUTE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_CHAT_MUTE {
+    pub fn new() -> C_CHAT_MUTE {
+        ::std::default::Default::default()
+    }
+
+    // uint32 target_id = 1;
+
+
+    pub fn get_target_id(&self) -> u32 {
+        self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: u32) {
+        self.target_id = v;
+    }
+
+    // bool is_mute = 2;
+
+
+    pub fn get_is_mute(&self) -> bool {
+        self.is_mute
+    }
+    pub fn clear_is_mute(&mut self) {
+        self.is_mute = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_mute(&mut self, v: bool) {
+        self.is_mute = v;
+    }
+}
+
+impl ::protobuf::Message for C_CHAT_MUTE {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.target_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_mute = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.target_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.target_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.is_mute != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.target_id != 0 {
+            os.write_uint32(1, self.target_id)?;
+        }
+        if self.is_mute != false {
+            os.write_bool(2, self.is_mute)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_CHAT_MUTE {
+        C_CHAT_MUTE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "target_id",
+                |m: &C_CHAT_MUTE| { &m.target_id },
+                |m: &mut C_CHAT_MUTE| { &mut m.target_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_mute",
+                |m: &C_CHAT_MUTE| { &m.is_mute },
+                |m: &mut C_CHAT_MUTE| { &mut m.is_mute },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_CHAT_MUTE>(
+                "C_CHAT_MUTE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_CHAT_MUTE {
+        static instance: ::protobuf::rt::LazyV2<C_CHAT_MUTE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_CHAT_MUTE::new)
+    }
+}
+
+impl ::protobuf::Clear for C_CHAT_MUTE {
+    fn clear(&mut self) {
+        self.target_id = 0;
+        self.is_mute = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_CHAT_MUTE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_CHAT_MUTE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_CHAT_MUTE {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    pub target_id: u32,
+    pub is_mute: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_CHAT_MUTE {
+    fn default() -> &'a S_CHAT_MUTE {
+        <S_CHAT_MUTE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_CHAT_MUTE {
+    pub fn new() -> S_CHAT_MUTE {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+
+    // uint32 target_id = 3;
+
+
+    pub fn get_target_id(&self) -> u32 {
+        self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: u32) {
+        self.target_id = v;
+    }
+
+    // bool is_mute = 4;
+
+
+    pub fn get_is_mute(&self) -> bool {
+        self.is_mute
+    }
+    pub fn clear_is_mute(&mut self) {
+        self.is_mute = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_mute(&mut self, v: bool) {
+        self.is_mute = v;
+    }
+}
+
+impl ::protobuf::Message for S_CHAT_MUTE {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.target_id = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_mute = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        if self.target_id != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.target_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.is_mute != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        if self.target_id != 0 {
+            os.write_uint32(3, self.target_id)?;
+        }
+        if self.is_mute != false {
+            os.write_bool(4, self.is_mute)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_CHAT_MUTE {
+        S_CHAT_MUTE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_CHAT_MUTE| { &m.is_succ },
+                |m: &mut S_CHAT_MUTE| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_CHAT_MUTE| { &m.err_mess },
+                |m: &mut S_CHAT_MUTE| { &mut m.err_mess },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "target_id",
+                |m: &S_CHAT_MUTE| { &m.target_id },
+                |m: &mut S_CHAT_MUTE| { &mut m.target_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_mute",
+                |m: &S_CHAT_MUTE| { &m.is_mute },
+                |m: &mut S_CHAT_MUTE| { &mut m.is_mute },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_CHAT_MUTE>(
+                "S_CHAT_MUTE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_CHAT_MUTE {
+        static instance: ::protobuf::rt::LazyV2<S_CHAT_MUTE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_CHAT_MUTE::new)
+    }
+}
+
+impl ::protobuf::Clear for S_CHAT_MUTE {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.target_id = 0;
+        self.is_mute = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_CHAT_MUTE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_CHAT_MUTE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_CHAT_REPORT {
+    // message fields
+    pub target_id: u32,
+    pub reason: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_CHAT_REPORT {
+    fn default() -> &'a C_CHAT_REPORT {
+        <C_CHAT_REPORT as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_CHAT_REPORT {
+    pub fn new() -> C_CHAT_REPORT {
+        ::std::default::Default::default()
+    }
+
+    // uint32 target_id = 1;
+
+
+    pub fn get_target_id(&self) -> u32 {
+        self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: u32) {
+        self.target_id = v;
+    }
+
+    // string reason = 2;
+
+
+    pub fn get_reason(&self) -> &str {
+        &self.reason
+    }
+    pub fn clear_reason(&mut self) {
+        self.reason.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reason(&mut self, v: ::std::string::String) {
+        self.reason = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_reason(&mut self) -> &mut ::std::string::String {
+        &mut self.reason
+    }
+
+    // Take field
+    pub fn take_reason(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.reason, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for C_CHAT_REPORT {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.target_id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.reason)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.target_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.target_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.reason.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.reason);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.target_id != 0 {
+            os.write_uint32(1, self.target_id)?;
+        }
+        if !self.reason.is_empty() {
+            os.write_string(2, &self.reason)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_CHAT_REPORT {
+        C_CHAT_REPORT::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "target_id",
+                |m: &C_CHAT_REPORT| { &m.target_id },
+                |m: &mut C_CHAT_REPORT| { &mut m.target_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "reason",
+                |m: &C_CHAT_REPORT| { &m.reason },
+                |m: &mut C_CHAT_REPORT| { &mut m.reason },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_CHAT_REPORT>(
+                "C_CHAT_REPORT",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_CHAT_REPORT {
+        static instance: ::protobuf::rt::LazyV2<C_CHAT_REPORT> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_CHAT_REPORT::new)
+    }
+}
+
+impl ::protobuf::Clear for C_CHAT_REPORT {
+    fn clear(&mut self) {
+        self.target_id = 0;
+        self.reason.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_CHAT_REPORT {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_CHAT_REPORT {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_CHAT_REPORT {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_CHAT_REPORT {
+    fn default() -> &'a S_CHAT_REPORT {
+        <S_CHAT_REPORT as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_CHAT_REPORT {
+    pub fn new() -> S_CHAT_REPORT {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for S_CHAT_REPORT {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_CHAT_REPORT {
+        S_CHAT_REPORT::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_CHAT_REPORT| { &m.is_succ },
+                |m: &mut S_CHAT_REPORT| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_CHAT_REPORT| { &m.err_mess },
+                |m: &mut S_CHAT_REPORT| { &mut m.err_mess },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_CHAT_REPORT>(
+                "S_CHAT_REPORT",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_CHAT_REPORT {
+        static instance: ::protobuf::rt::LazyV2<S_CHAT_REPORT> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_CHAT_REPORT::new)
+    }
+}
+
+impl ::protobuf::Clear for S_CHAT_REPORT {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_CHAT_REPORT {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_CHAT_REPORT {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_DRAFT_BAN {
+    // message fields
+    pub cter_temp_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_DRAFT_BAN {
+    fn default() -> &'a C_DRAFT_BAN {
+        <C_DRAFT_BAN as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_DRAFT_BAN {
+    pub fn new() -> C_DRAFT_BAN {
+        ::std::default::Default::default()
+    }
+
+    // uint32 cter_temp_id = 1;
+
+
+    pub fn get_cter_temp_id(&self) -> u32 {
+        self.cter_temp_id
+    }
+    pub fn clear_cter_temp_id(&mut self) {
+        self.cter_temp_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cter_temp_id(&mut self, v: u32) {
+        self.cter_temp_id = v;
+    }
+}
+
+impl ::protobuf::Message for C_DRAFT_BAN {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.cter_temp_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.cter_temp_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.cter_temp_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.cter_temp_id != 0 {
+            os.write_uint32(1, self.cter_temp_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_DRAFT_BAN {
+        C_DRAFT_BAN::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "cter_temp_id",
+                |m: &C_DRAFT_BAN| { &m.cter_temp_id },
+                |m: &mut C_DRAFT_BAN| { &mut m.cter_temp_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_DRAFT_BAN>(
+                "C_DRAFT_BAN",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_DRAFT_BAN {
+        static instance: ::protobuf::rt::LazyV2<C_DRAFT_BAN> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_DRAFT_BAN::new)
+    }
+}
+
+impl ::protobuf::Clear for C_DRAFT_BAN {
+    fn clear(&mut self) {
+        self.cter_temp_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_DRAFT_BAN {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_DRAFT_BAN {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_DRAFT_BAN {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_DRAFT_BAN {
+    fn default() -> &'a S_DRAFT_BAN {
+        <S_DRAFT_BAN as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_DRAFT_BAN {
+    pub fn new() -> S_DRAFT_BAN {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for S_DRAFT_BAN {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_DRAFT_BAN {
+        S_DRAFT_BAN::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_DRAFT_BAN| { &m.is_succ },
+                |m: &mut S_DRAFT_BAN| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_DRAFT_BAN| { &m.err_mess },
+                |m: &mut S_DRAFT_BAN| { &mut m.err_mess },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_DRAFT_BAN>(
+                "S_DRAFT_BAN",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_DRAFT_BAN {
+        static instance: ::protobuf::rt::LazyV2<S_DRAFT_BAN> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_DRAFT_BAN::new)
+    }
+}
+
+impl ::protobuf::Clear for S_DRAFT_BAN {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_DRAFT_BAN {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_DRAFT_BAN {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_DRAFT_PICK {
+    // message fields
+    pub cter_temp_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_DRAFT_PICK {
+    fn default() -> &'a C_DRAFT_PICK {
+        <C_DRAFT_PICK as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_DRAFT_PICK {
+    pub fn new() -> C_DRAFT_PICK {
+        ::std::default::Default::default()
+    }
+
+    // uint32 cter_temp_id = 1;
+
+
+    pub fn get_cter_temp_id(&self) -> u32 {
+        self.cter_temp_id
+    }
+    pub fn clear_cter_temp_id(&mut self) {
+        self.cter_temp_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cter_temp_id(&mut self, v: u32) {
+        self.cter_temp_id = v;
+    }
+}
+
+impl ::protobuf::Message for C_DRAFT_PICK {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.cter_temp_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.cter_temp_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.cter_temp_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.cter_temp_id != 0 {
+            os.write_uint32(1, self.cter_temp_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_DRAFT_PICK {
+        C_DRAFT_PICK::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "cter_temp_id",
+                |m: &C_DRAFT_PICK| { &m.cter_temp_id },
+                |m: &mut C_DRAFT_PICK| { &mut m.cter_temp_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_DRAFT_PICK>(
+                "C_DRAFT_PICK",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_DRAFT_PICK {
+        static instance: ::protobuf::rt::LazyV2<C_DRAFT_PICK> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_DRAFT_PICK::new)
+    }
+}
+
+impl ::protobuf::Clear for C_DRAFT_PICK {
+    fn clear(&mut self) {
+        self.cter_temp_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_DRAFT_PICK {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_DRAFT_PICK {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_DRAFT_PICK {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_DRAFT_PICK {
+    fn default() -> &'a S_DRAFT_PICK {
+        <S_DRAFT_PICK as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_DRAFT_PICK {
+    pub fn new() -> S_DRAFT_PICK {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for S_DRAFT_PICK {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_DRAFT_PICK {
+        S_DRAFT_PICK::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_DRAFT_PICK| { &m.is_succ },
+                |m: &mut S_DRAFT_PICK| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_DRAFT_PICK| { &m.err_mess },
+                |m: &mut S_DRAFT_PICK| { &mut m.err_mess },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_DRAFT_PICK>(
+                "S_DRAFT_PICK",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_DRAFT_PICK {
+        static instance: ::protobuf::rt::LazyV2<S_DRAFT_PICK> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_DRAFT_PICK::new)
+    }
+}
+
+impl ::protobuf::Clear for S_DRAFT_PICK {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_DRAFT_PICK {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_DRAFT_PICK {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_DRAFT_NOTICE {
+    // message fields
+    pub phase: u32,
+    pub user_id: u32,
+    pub end_time: u64,
+    pub orders: ::std::vec::Vec<u32>,
+    pub bans: ::std::vec::Vec<u32>,
+    pub picks: ::protobuf::RepeatedField<super::base::DraftPickPt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_DRAFT_NOTICE {
+    fn default() -> &'a S_DRAFT_NOTICE {
+        <S_DRAFT_NOTICE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_DRAFT_NOTICE {
+    pub fn new() -> S_DRAFT_NOTICE {
+        ::std::default::Default::default()
+    }
+
+    // uint32 phase = 1;
+
+
+    pub fn get_phase(&self) -> u32 {
+        self.phase
+    }
+    pub fn clear_phase(&mut self) {
+        self.phase = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_phase(&mut self, v: u32) {
+        self.phase = v;
+    }
+
+    // uint32 user_id = 2;
+
+
+    pub fn get_user_id(&self) -> u32 {
+        self.user_id
+    }
+    pub fn clear_user_id(&mut self) {
+        self.user_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: u32) {
+        self.user_id = v;
+    }
+
+    // uint64 end_time = 3;
+
+
+    pub fn get_end_time(&self) -> u64 {
+        self.end_time
+    }
+    pub fn clear_end_time(&mut self) {
+        self.end_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_end_time(&mut self, v: u64) {
+        self.end_time = v;
+    }
+
+    // repeated uint32 orders = 4;
+
+
+    pub fn get_orders(&self) -> &[u32] {
+        &self.orders
+    }
+    pub fn clear_orders(&mut self) {
+        self.orders.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_orders(&mut self, v: ::std::vec::Vec<u32>) {
+        self.orders = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_orders(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.orders
+    }
+
+    // Take field
+    pub fn take_orders(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.orders, ::std::vec::Vec::new())
+    }
+
+    // repeated uint32 bans = 5;
+
+
+    pub fn get_bans(&self) -> &[u32] {
+        &self.bans
+    }
+    pub fn clear_bans(&mut self) {
+        self.bans.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bans(&mut self, v: ::std::vec::Vec<u32>) {
+        self.bans = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_bans(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.bans
+    }
+
+    // Take field
+    pub fn take_bans(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.bans, ::std::vec::Vec::new())
+    }
+
+    // repeated .protos.DraftPickPt picks = 6;
+
+
+    pub fn get_picks(&self) -> &[super::base::DraftPickPt] {
+        &self.picks
+    }
+    pub fn clear_picks(&mut self) {
+        self.picks.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_picks(&mut self, v: ::protobuf::RepeatedField<super::base::DraftPickPt>) {
+        self.picks = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_picks(&mut self) -> &mut ::protobuf::RepeatedField<super::base::DraftPickPt> {
+        &mut self.picks
+    }
+
+    // Take field
+    pub fn take_picks(&mut self) -> ::protobuf::RepeatedField<super::base::DraftPickPt> {
+        ::std::mem::replace(&mut self.picks, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for S_DRAFT_NOTICE {
+    fn is_initialized(&self) -> bool {
+        for v in &self.picks {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.phase = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.user_id = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.end_time = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.orders)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.bans)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.picks)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.phase != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.phase, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.user_id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.user_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.end_time != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.end_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.orders {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.bans {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.picks {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.phase != 0 {
+            os.write_uint32(1, self.phase)?;
+        }
+        if self.user_id != 0 {
+            os.write_uint32(2, self.user_id)?;
+        }
+        if self.end_time != 0 {
+            os.write_uint64(3, self.end_time)?;
+        }
+        for v in &self.orders {
+            os.write_uint32(4, *v)?;
+        };
+        for v in &self.bans {
+            os.write_uint32(5, *v)?;
+        };
+        for v in &self.picks {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_DRAFT_NOTICE {
+        S_DRAFT_NOTICE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "phase",
+                |m: &S_DRAFT_NOTICE| { &m.phase },
+                |m: &mut S_DRAFT_NOTICE| { &mut m.phase },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "user_id",
+                |m: &S_DRAFT_NOTICE| { &m.user_id },
+                |m: &mut S_DRAFT_NOTICE| { &mut m.user_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "end_time",
+                |m: &S_DRAFT_NOTICE| { &m.end_time },
+                |m: &mut S_DRAFT_NOTICE| { &mut m.end_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "orders",
+                |m: &S_DRAFT_NOTICE| { &m.orders },
+                |m: &mut S_DRAFT_NOTICE| { &mut m.orders },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "bans",
+                |m: &S_DRAFT_NOTICE| { &m.bans },
+                |m: &mut S_DRAFT_NOTICE| { &mut m.bans },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::DraftPickPt>>(
+                "picks",
+                |m: &S_DRAFT_NOTICE| { &m.picks },
+                |m: &mut S_DRAFT_NOTICE| { &mut m.picks },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_DRAFT_NOTICE>(
+                "S_DRAFT_NOTICE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_DRAFT_NOTICE {
+        static instance: ::protobuf::rt::LazyV2<S_DRAFT_NOTICE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_DRAFT_NOTICE::new)
+    }
+}
+
+impl ::protobuf::Clear for S_DRAFT_NOTICE {
+    fn clear(&mut self) {
+        self.phase = 0;
+        self.user_id = 0;
+        self.end_time = 0;
+        self.orders.clear();
+        self.bans.clear();
+        self.picks.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_DRAFT_NOTICE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_DRAFT_NOTICE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct C_REMATCH {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a C_REMATCH {
+    fn default() -> &'a C_REMATCH {
+        <C_REMATCH as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl C_REMATCH {
+    pub fn new() -> C_REMATCH {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for C_REMATCH {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> C_REMATCH {
+        C_REMATCH::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<C_REMATCH>(
+                "C_REMATCH",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static C_REMATCH {
+        static instance: ::protobuf::rt::LazyV2<C_REMATCH> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(C_REMATCH::new)
+    }
+}
+
+impl ::protobuf::Clear for C_REMATCH {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for C_REMATCH {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for C_REMATCH {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_REMATCH {
+    // message fields
+    pub is_succ: bool,
+    pub err_mess: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_REMATCH {
+    fn default() -> &'a S_REMATCH {
+        <S_REMATCH as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_REMATCH {
+    pub fn new() -> S_REMATCH {
+        ::std::default::Default::default()
+    }
+
+    // bool is_succ = 1;
+
+
+    pub fn get_is_succ(&self) -> bool {
+        self.is_succ
+    }
+    pub fn clear_is_succ(&mut self) {
+        self.is_succ = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_succ(&mut self, v: bool) {
+        self.is_succ = v;
+    }
+
+    // string err_mess = 2;
+
+
+    pub fn get_err_mess(&self) -> &str {
+        &self.err_mess
+    }
+    pub fn clear_err_mess(&mut self) {
+        self.err_mess.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err_mess(&mut self, v: ::std::string::String) {
+        self.err_mess = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_err_mess(&mut self) -> &mut ::std::string::String {
+        &mut self.err_mess
+    }
+
+    // Take field
+    pub fn take_err_mess(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.err_mess, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for S_REMATCH {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_succ = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.err_mess)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.is_succ != false {
+            my_size += 2;
+        }
+        if !self.err_mess.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.err_mess);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.is_succ != false {
+            os.write_bool(1, self.is_succ)?;
+        }
+        if !self.err_mess.is_empty() {
+            os.write_string(2, &self.err_mess)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_REMATCH {
+        S_REMATCH::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_succ",
+                |m: &S_REMATCH| { &m.is_succ },
+                |m: &mut S_REMATCH| { &mut m.is_succ },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "err_mess",
+                |m: &S_REMATCH| { &m.err_mess },
+                |m: &mut S_REMATCH| { &mut m.err_mess },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_REMATCH>(
+                "S_REMATCH",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_REMATCH {
+        static instance: ::protobuf::rt::LazyV2<S_REMATCH> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_REMATCH::new)
+    }
+}
+
+impl ::protobuf::Clear for S_REMATCH {
+    fn clear(&mut self) {
+        self.is_succ = false;
+        self.err_mess.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_REMATCH {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_REMATCH {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct S_REMATCH_NOTICE {
+    // message fields
+    pub room_id: u32,
+    pub agrees: ::std::vec::Vec<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a S_REMATCH_NOTICE {
+    fn default() -> &'a S_REMATCH_NOTICE {
+        <S_REMATCH_NOTICE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl S_REMATCH_NOTICE {
+    pub fn new() -> S_REMATCH_NOTICE {
+        ::std::default::Default::default()
+    }
+
+    // uint32 room_id = 1;
+
+
+    pub fn get_room_id(&self) -> u32 {
+        self.room_id
+    }
+    pub fn clear_room_id(&mut self) {
+        self.room_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_room_id(&mut self, v: u32) {
+        self.room_id = v;
+    }
+
+    // repeated uint32 agrees = 2;
+
+
+    pub fn get_agrees(&self) -> &[u32] {
+        &self.agrees
+    }
+    pub fn clear_agrees(&mut self) {
+        self.agrees.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_agrees(&mut self, v: ::std::vec::Vec<u32>) {
+        self.agrees = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_agrees(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.agrees
+    }
+
+    // Take field
+    pub fn take_agrees(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.agrees, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for S_REMATCH_NOTICE {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.room_id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.agrees)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.room_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.room_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.agrees {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.room_id != 0 {
+            os.write_uint32(1, self.room_id)?;
+        }
+        for v in &self.agrees {
+            os.write_uint32(2, *v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> S_REMATCH_NOTICE {
+        S_REMATCH_NOTICE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "room_id",
+                |m: &S_REMATCH_NOTICE| { &m.room_id },
+                |m: &mut S_REMATCH_NOTICE| { &mut m.room_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "agrees",
+                |m: &S_REMATCH_NOTICE| { &m.agrees },
+                |m: &mut S_REMATCH_NOTICE| { &mut m.agrees },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<S_REMATCH_NOTICE>(
+                "S_REMATCH_NOTICE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static S_REMATCH_NOTICE {
+        static instance: ::protobuf::rt::LazyV2<S_REMATCH_NOTICE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(S_REMATCH_NOTICE::new)
+    }
+}
+
+impl ::protobuf::Clear for S_REMATCH_NOTICE {
+    fn clear(&mut self) {
+        self.room_id = 0;
+        self.agrees.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for S_REMATCH_NOTICE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for S_REMATCH_NOTICE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\nroom.proto\x12\x06protos\x1a\nbase.proto\"]\n\rC_CREATE_ROOM\x12\x1b\
     \n\troom_type\x18\x01\x20\x01(\rR\x08roomType\x12/\n\x07setting\x18\x02\
@@ -6620,7 +9770,37 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     dex\x18\x01\x20\x01(\rR\x05index\x12\"\n\rrobot_temp_id\x18\x02\x20\x01(\
     \rR\x0brobotTempId\"g\n\x12S_CHOICE_AI_NOTICE\x12\x14\n\x05index\x18\x01\
     \x20\x01(\rR\x05index\x12\"\n\rrobot_temp_id\x18\x02\x20\x01(\rR\x0brobo\
-    tTempId\x12\x17\n\x07user_id\x18\x03\x20\x01(\rR\x06userIdb\x06proto3\
+    tTempId\x12\x17\n\x07user_id\x18\x03\x20\x01(\rR\x06userId\"Y\n\x06C_CHA\
+    T\x12\x18\n\x07channel\x18\x01\x20\x01(\rR\x07channel\x12\x18\n\x07conte\
+    nt\x18\x02\x20\x01(\tR\x07content\x12\x1b\n\ttarget_id\x18\x03\x20\x01(\
+    \rR\x08targetId\"<\n\x06S_CHAT\x12\x17\n\x07is_succ\x18\x01\x20\x01(\x08\
+    R\x06isSucc\x12\x19\n\x08err_mess\x18\x02\x20\x01(\tR\x07errMess\"9\n\rS\
+    _CHAT_NOTICE\x12(\n\x04mess\x18\x01\x20\x01(\x0b2\x14.protos.NoticeMessP\
+    tR\x04mess\"?\n\x0eS_CHAT_HISTORY\x12-\n\x06messes\x18\x01\x20\x03(\x0b2\
+    \x15.protos.HistoryMessPtR\x06messes\"C\n\x0bC_CHAT_MUTE\x12\x1b\n\ttarg\
+    et_id\x18\x01\x20\x01(\rR\x08targetId\x12\x17\n\x07is_mute\x18\x02\x20\
+    \x01(\x08R\x06isMute\"w\n\x0bS_CHAT_MUTE\x12\x17\n\x07is_succ\x18\x01\
+    \x20\x01(\x08R\x06isSucc\x12\x19\n\x08err_mess\x18\x02\x20\x01(\tR\x07er\
+    rMess\x12\x1b\n\ttarget_id\x18\x03\x20\x01(\rR\x08targetId\x12\x17\n\x07\
+    is_mute\x18\x04\x20\x01(\x08R\x06isMute\"D\n\rC_CHAT_REPORT\x12\x1b\n\tt\
+    arget_id\x18\x01\x20\x01(\rR\x08targetId\x12\x16\n\x06reason\x18\x02\x20\
+    \x01(\tR\x06reason\"C\n\rS_CHAT_REPORT\x12\x17\n\x07is_succ\x18\x01\x20\
+    \x01(\x08R\x06isSucc\x12\x19\n\x08err_mess\x18\x02\x20\x01(\tR\x07errMes\
+    s\"/\n\x0bC_DRAFT_BAN\x12\x20\n\x0ccter_temp_id\x18\x01\x20\x01(\rR\ncte\
+    rTempId\"A\n\x0bS_DRAFT_BAN\x12\x17\n\x07is_succ\x18\x01\x20\x01(\x08R\
+    \x06isSucc\x12\x19\n\x08err_mess\x18\x02\x20\x01(\tR\x07errMess\"0\n\x0c\
+    C_DRAFT_PICK\x12\x20\n\x0ccter_temp_id\x18\x01\x20\x01(\rR\ncterTempId\"\
+    B\n\x0cS_DRAFT_PICK\x12\x17\n\x07is_succ\x18\x01\x20\x01(\x08R\x06isSucc\
+    \x12\x19\n\x08err_mess\x18\x02\x20\x01(\tR\x07errMess\"\xb1\x01\n\x0eS_D\
+    RAFT_NOTICE\x12\x14\n\x05phase\x18\x01\x20\x01(\rR\x05phase\x12\x17\n\
+    \x07user_id\x18\x02\x20\x01(\rR\x06userId\x12\x19\n\x08end_time\x18\x03\
+    \x20\x01(\x04R\x07endTime\x12\x16\n\x06orders\x18\x04\x20\x03(\rR\x06ord\
+    ers\x12\x12\n\x04bans\x18\x05\x20\x03(\rR\x04bans\x12)\n\x05picks\x18\
+    \x06\x20\x03(\x0b2\x13.protos.DraftPickPtR\x05picks\"\x0b\n\tC_REMATCH\"\
+    ?\n\tS_REMATCH\x12\x17\n\x07is_succ\x18\x01\x20\x01(\x08R\x06isSucc\x12\
+    \x19\n\x08err_mess\x18\x02\x20\x01(\tR\x07errMess\"C\n\x10S_REMATCH_NOTI\
+    CE\x12\x17\n\x07room_id\x18\x01\x20\x01(\rR\x06roomId\x12\x16\n\x06agree\
+    s\x18\x02\x20\x03(\rR\x06agreesb\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
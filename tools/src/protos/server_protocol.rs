@@ -712,6 +712,7 @@ pub struct G_R_SEARCH_ROOM {
     // message fields
     pub room_type: u32,
     pub pbp: ::protobuf::SingularPtrField<PlayerBattlePt>,
+    pub party: ::protobuf::RepeatedField<PlayerBattlePt>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -775,6 +776,31 @@ impl G_R_SEARCH_ROOM {
     pub fn take_pbp(&mut self) -> PlayerBattlePt {
         self.pbp.take().unwrap_or_else(|| PlayerBattlePt::new())
     }
+
+    // repeated .protos.PlayerBattlePt party = 3;
+
+
+    pub fn get_party(&self) -> &[PlayerBattlePt] {
+        &self.party
+    }
+    pub fn clear_party(&mut self) {
+        self.party.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_party(&mut self, v: ::protobuf::RepeatedField<PlayerBattlePt>) {
+        self.party = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_party(&mut self) -> &mut ::protobuf::RepeatedField<PlayerBattlePt> {
+        &mut self.party
+    }
+
+    // Take field
+    pub fn take_party(&mut self) -> ::protobuf::RepeatedField<PlayerBattlePt> {
+        ::std::mem::replace(&mut self.party, ::protobuf::RepeatedField::new())
+    }
 }
 
 impl ::protobuf::Message for G_R_SEARCH_ROOM {
@@ -784,6 +810,11 @@ impl ::protobuf::Message for G_R_SEARCH_ROOM {
                 return false;
             }
         };
+        for v in &self.party {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -801,6 +832,9 @@ impl ::protobuf::Message for G_R_SEARCH_ROOM {
                 2 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.pbp)?;
                 },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.party)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -820,6 +854,10 @@ impl ::protobuf::Message for G_R_SEARCH_ROOM {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        for value in &self.party {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -834,6 +872,11 @@ impl ::protobuf::Message for G_R_SEARCH_ROOM {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        for v in &self.party {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -882,6 +925,11 @@ impl ::protobuf::Message for G_R_SEARCH_ROOM {
                 |m: &G_R_SEARCH_ROOM| { &m.pbp },
                 |m: &mut G_R_SEARCH_ROOM| { &mut m.pbp },
             ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PlayerBattlePt>>(
+                "party",
+                |m: &G_R_SEARCH_ROOM| { &m.party },
+                |m: &mut G_R_SEARCH_ROOM| { &mut m.party },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<G_R_SEARCH_ROOM>(
                 "G_R_SEARCH_ROOM",
                 fields,
@@ -900,6 +948,7 @@ impl ::protobuf::Clear for G_R_SEARCH_ROOM {
     fn clear(&mut self) {
         self.room_type = 0;
         self.pbp.clear();
+        self.party.clear();
         self.unknown_fields.clear();
     }
 }
@@ -1171,6 +1220,7 @@ pub struct B_R_SUMMARY {
     // message fields
     pub room_type: u32,
     pub room_id: u32,
+    pub ranks: ::std::vec::Vec<u32>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -1216,6 +1266,31 @@ impl B_R_SUMMARY {
     pub fn set_room_id(&mut self, v: u32) {
         self.room_id = v;
     }
+
+    // repeated uint32 ranks = 3;
+
+
+    pub fn get_ranks(&self) -> &[u32] {
+        &self.ranks
+    }
+    pub fn clear_ranks(&mut self) {
+        self.ranks.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ranks(&mut self, v: ::std::vec::Vec<u32>) {
+        self.ranks = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ranks(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.ranks
+    }
+
+    // Take field
+    pub fn take_ranks(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.ranks, ::std::vec::Vec::new())
+    }
 }
 
 impl ::protobuf::Message for B_R_SUMMARY {
@@ -1241,6 +1316,9 @@ impl ::protobuf::Message for B_R_SUMMARY {
                     let tmp = is.read_uint32()?;
                     self.room_id = tmp;
                 },
+                3 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.ranks)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1259,6 +1337,9 @@ impl ::protobuf::Message for B_R_SUMMARY {
         if self.room_id != 0 {
             my_size += ::protobuf::rt::value_size(2, self.room_id, ::protobuf::wire_format::WireTypeVarint);
         }
+        for value in &self.ranks {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1271,6 +1352,9 @@ impl ::protobuf::Message for B_R_SUMMARY {
         if self.room_id != 0 {
             os.write_uint32(2, self.room_id)?;
         }
+        for v in &self.ranks {
+            os.write_uint32(3, *v)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1319,6 +1403,11 @@ impl ::protobuf::Message for B_R_SUMMARY {
                 |m: &B_R_SUMMARY| { &m.room_id },
                 |m: &mut B_R_SUMMARY| { &mut m.room_id },
             ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "ranks",
+                |m: &B_R_SUMMARY| { &m.ranks },
+                |m: &mut B_R_SUMMARY| { &mut m.ranks },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<B_R_SUMMARY>(
                 "B_R_SUMMARY",
                 fields,
@@ -1337,6 +1426,7 @@ impl ::protobuf::Clear for B_R_SUMMARY {
     fn clear(&mut self) {
         self.room_type = 0;
         self.room_id = 0;
+        self.ranks.clear();
         self.unknown_fields.clear();
     }
 }
@@ -2673,6 +2763,2368 @@ impl ::protobuf::reflect::ProtobufValue for UPDATE_WORLD_BOSS_PUSH {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct B_R_WORLD_BOSS_DAMAGE {
+    // message fields
+    pub world_boss_id: i32,
+    pub damages: ::protobuf::RepeatedField<super::base::WorldBossRankPt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a B_R_WORLD_BOSS_DAMAGE {
+    fn default() -> &'a B_R_WORLD_BOSS_DAMAGE {
+        <B_R_WORLD_BOSS_DAMAGE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl B_R_WORLD_BOSS_DAMAGE {
+    pub fn new() -> B_R_WORLD_BOSS_DAMAGE {
+        ::std::default::Default::default()
+    }
+
+    // int32 world_boss_id = 1;
+
+
+    pub fn get_world_boss_id(&self) -> i32 {
+        self.world_boss_id
+    }
+    pub fn clear_world_boss_id(&mut self) {
+        self.world_boss_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_world_boss_id(&mut self, v: i32) {
+        self.world_boss_id = v;
+    }
+
+    // repeated .protos.WorldBossRankPt damages = 2;
+
+
+    pub fn get_damages(&self) -> &[super::base::WorldBossRankPt] {
+        &self.damages
+    }
+    pub fn clear_damages(&mut self) {
+        self.damages.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_damages(&mut self, v: ::protobuf::RepeatedField<super::base::WorldBossRankPt>) {
+        self.damages = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_damages(&mut self) -> &mut ::protobuf::RepeatedField<super::base::WorldBossRankPt> {
+        &mut self.damages
+    }
+
+    // Take field
+    pub fn take_damages(&mut self) -> ::protobuf::RepeatedField<super::base::WorldBossRankPt> {
+        ::std::mem::replace(&mut self.damages, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for B_R_WORLD_BOSS_DAMAGE {
+    fn is_initialized(&self) -> bool {
+        for v in &self.damages {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.world_boss_id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.damages)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.world_boss_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.world_boss_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.damages {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.world_boss_id != 0 {
+            os.write_int32(1, self.world_boss_id)?;
+        }
+        for v in &self.damages {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> B_R_WORLD_BOSS_DAMAGE {
+        B_R_WORLD_BOSS_DAMAGE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "world_boss_id",
+                |m: &B_R_WORLD_BOSS_DAMAGE| { &m.world_boss_id },
+                |m: &mut B_R_WORLD_BOSS_DAMAGE| { &mut m.world_boss_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::WorldBossRankPt>>(
+                "damages",
+                |m: &B_R_WORLD_BOSS_DAMAGE| { &m.damages },
+                |m: &mut B_R_WORLD_BOSS_DAMAGE| { &mut m.damages },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<B_R_WORLD_BOSS_DAMAGE>(
+                "B_R_WORLD_BOSS_DAMAGE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static B_R_WORLD_BOSS_DAMAGE {
+        static instance: ::protobuf::rt::LazyV2<B_R_WORLD_BOSS_DAMAGE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(B_R_WORLD_BOSS_DAMAGE::new)
+    }
+}
+
+impl ::protobuf::Clear for B_R_WORLD_BOSS_DAMAGE {
+    fn clear(&mut self) {
+        self.world_boss_id = 0;
+        self.damages.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for B_R_WORLD_BOSS_DAMAGE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for B_R_WORLD_BOSS_DAMAGE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct R_G_WORLD_BOSS_PUSH {
+    // message fields
+    pub world_boss_id: i32,
+    pub hp: u64,
+    pub max_hp: u64,
+    pub milestone: u32,
+    pub notice: bool,
+    pub is_settled: bool,
+    pub ranks: ::protobuf::RepeatedField<super::base::WorldBossRankPt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a R_G_WORLD_BOSS_PUSH {
+    fn default() -> &'a R_G_WORLD_BOSS_PUSH {
+        <R_G_WORLD_BOSS_PUSH as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl R_G_WORLD_BOSS_PUSH {
+    pub fn new() -> R_G_WORLD_BOSS_PUSH {
+        ::std::default::Default::default()
+    }
+
+    // int32 world_boss_id = 1;
+
+
+    pub fn get_world_boss_id(&self) -> i32 {
+        self.world_boss_id
+    }
+    pub fn clear_world_boss_id(&mut self) {
+        self.world_boss_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_world_boss_id(&mut self, v: i32) {
+        self.world_boss_id = v;
+    }
+
+    // uint64 hp = 2;
+
+
+    pub fn get_hp(&self) -> u64 {
+        self.hp
+    }
+    pub fn clear_hp(&mut self) {
+        self.hp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hp(&mut self, v: u64) {
+        self.hp = v;
+    }
+
+    // uint64 max_hp = 3;
+
+
+    pub fn get_max_hp(&self) -> u64 {
+        self.max_hp
+    }
+    pub fn clear_max_hp(&mut self) {
+        self.max_hp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_hp(&mut self, v: u64) {
+        self.max_hp = v;
+    }
+
+    // uint32 milestone = 4;
+
+
+    pub fn get_milestone(&self) -> u32 {
+        self.milestone
+    }
+    pub fn clear_milestone(&mut self) {
+        self.milestone = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_milestone(&mut self, v: u32) {
+        self.milestone = v;
+    }
+
+    // bool notice = 5;
+
+
+    pub fn get_notice(&self) -> bool {
+        self.notice
+    }
+    pub fn clear_notice(&mut self) {
+        self.notice = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_notice(&mut self, v: bool) {
+        self.notice = v;
+    }
+
+    // bool is_settled = 6;
+
+
+    pub fn get_is_settled(&self) -> bool {
+        self.is_settled
+    }
+    pub fn clear_is_settled(&mut self) {
+        self.is_settled = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_settled(&mut self, v: bool) {
+        self.is_settled = v;
+    }
+
+    // repeated .protos.WorldBossRankPt ranks = 7;
+
+
+    pub fn get_ranks(&self) -> &[super::base::WorldBossRankPt] {
+        &self.ranks
+    }
+    pub fn clear_ranks(&mut self) {
+        self.ranks.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ranks(&mut self, v: ::protobuf::RepeatedField<super::base::WorldBossRankPt>) {
+        self.ranks = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ranks(&mut self) -> &mut ::protobuf::RepeatedField<super::base::WorldBossRankPt> {
+        &mut self.ranks
+    }
+
+    // Take field
+    pub fn take_ranks(&mut self) -> ::protobuf::RepeatedField<super::base::WorldBossRankPt> {
+        ::std::mem::replace(&mut self.ranks, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for R_G_WORLD_BOSS_PUSH {
+    fn is_initialized(&self) -> bool {
+        for v in &self.ranks {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.world_boss_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.hp = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.max_hp = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.milestone = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.notice = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_settled = tmp;
+                },
+                7 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.ranks)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.world_boss_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.world_boss_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.hp != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.hp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.max_hp != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.max_hp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.milestone != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.milestone, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.notice != false {
+            my_size += 2;
+        }
+        if self.is_settled != false {
+            my_size += 2;
+        }
+        for value in &self.ranks {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.world_boss_id != 0 {
+            os.write_int32(1, self.world_boss_id)?;
+        }
+        if self.hp != 0 {
+            os.write_uint64(2, self.hp)?;
+        }
+        if self.max_hp != 0 {
+            os.write_uint64(3, self.max_hp)?;
+        }
+        if self.milestone != 0 {
+            os.write_uint32(4, self.milestone)?;
+        }
+        if self.notice != false {
+            os.write_bool(5, self.notice)?;
+        }
+        if self.is_settled != false {
+            os.write_bool(6, self.is_settled)?;
+        }
+        for v in &self.ranks {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> R_G_WORLD_BOSS_PUSH {
+        R_G_WORLD_BOSS_PUSH::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "world_boss_id",
+                |m: &R_G_WORLD_BOSS_PUSH| { &m.world_boss_id },
+                |m: &mut R_G_WORLD_BOSS_PUSH| { &mut m.world_boss_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "hp",
+                |m: &R_G_WORLD_BOSS_PUSH| { &m.hp },
+                |m: &mut R_G_WORLD_BOSS_PUSH| { &mut m.hp },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "max_hp",
+                |m: &R_G_WORLD_BOSS_PUSH| { &m.max_hp },
+                |m: &mut R_G_WORLD_BOSS_PUSH| { &mut m.max_hp },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "milestone",
+                |m: &R_G_WORLD_BOSS_PUSH| { &m.milestone },
+                |m: &mut R_G_WORLD_BOSS_PUSH| { &mut m.milestone },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "notice",
+                |m: &R_G_WORLD_BOSS_PUSH| { &m.notice },
+                |m: &mut R_G_WORLD_BOSS_PUSH| { &mut m.notice },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_settled",
+                |m: &R_G_WORLD_BOSS_PUSH| { &m.is_settled },
+                |m: &mut R_G_WORLD_BOSS_PUSH| { &mut m.is_settled },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::WorldBossRankPt>>(
+                "ranks",
+                |m: &R_G_WORLD_BOSS_PUSH| { &m.ranks },
+                |m: &mut R_G_WORLD_BOSS_PUSH| { &mut m.ranks },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<R_G_WORLD_BOSS_PUSH>(
+                "R_G_WORLD_BOSS_PUSH",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static R_G_WORLD_BOSS_PUSH {
+        static instance: ::protobuf::rt::LazyV2<R_G_WORLD_BOSS_PUSH> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(R_G_WORLD_BOSS_PUSH::new)
+    }
+}
+
+impl ::protobuf::Clear for R_G_WORLD_BOSS_PUSH {
+    fn clear(&mut self) {
+        self.world_boss_id = 0;
+        self.hp = 0;
+        self.max_hp = 0;
+        self.milestone = 0;
+        self.notice = false;
+        self.is_settled = false;
+        self.ranks.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for R_G_WORLD_BOSS_PUSH {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for R_G_WORLD_BOSS_PUSH {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct G_GM_COMMAND {
+    // message fields
+    pub operator_id: u32,
+    pub level: u32,
+    pub name: ::std::string::String,
+    pub args: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a G_GM_COMMAND {
+    fn default() -> &'a G_GM_COMMAND {
+        <G_GM_COMMAND as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl G_GM_COMMAND {
+    pub fn new() -> G_GM_COMMAND {
+        ::std::default::Default::default()
+    }
+
+    // uint32 operator_id = 1;
+
+
+    pub fn get_operator_id(&self) -> u32 {
+        self.operator_id
+    }
+    pub fn clear_operator_id(&mut self) {
+        self.operator_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_operator_id(&mut self, v: u32) {
+        self.operator_id = v;
+    }
+
+    // uint32 level = 2;
+
+
+    pub fn get_level(&self) -> u32 {
+        self.level
+    }
+    pub fn clear_level(&mut self) {
+        self.level = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_level(&mut self, v: u32) {
+        self.level = v;
+    }
+
+    // string name = 3;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // repeated string args = 4;
+
+
+    pub fn get_args(&self) -> &[::std::string::String] {
+        &self.args
+    }
+    pub fn clear_args(&mut self) {
+        self.args.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_args(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.args = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_args(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.args
+    }
+
+    // Take field
+    pub fn take_args(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.args, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for G_GM_COMMAND {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.operator_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.level = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.args)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.operator_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.operator_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.level != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.level, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.name);
+        }
+        for value in &self.args {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.operator_id != 0 {
+            os.write_uint32(1, self.operator_id)?;
+        }
+        if self.level != 0 {
+            os.write_uint32(2, self.level)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(3, &self.name)?;
+        }
+        for v in &self.args {
+            os.write_string(4, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> G_GM_COMMAND {
+        G_GM_COMMAND::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "operator_id",
+                |m: &G_GM_COMMAND| { &m.operator_id },
+                |m: &mut G_GM_COMMAND| { &mut m.operator_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "level",
+                |m: &G_GM_COMMAND| { &m.level },
+                |m: &mut G_GM_COMMAND| { &mut m.level },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &G_GM_COMMAND| { &m.name },
+                |m: &mut G_GM_COMMAND| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "args",
+                |m: &G_GM_COMMAND| { &m.args },
+                |m: &mut G_GM_COMMAND| { &mut m.args },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<G_GM_COMMAND>(
+                "G_GM_COMMAND",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static G_GM_COMMAND {
+        static instance: ::protobuf::rt::LazyV2<G_GM_COMMAND> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(G_GM_COMMAND::new)
+    }
+}
+
+impl ::protobuf::Clear for G_GM_COMMAND {
+    fn clear(&mut self) {
+        self.operator_id = 0;
+        self.level = 0;
+        self.name.clear();
+        self.args.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for G_GM_COMMAND {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for G_GM_COMMAND {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct G_R_CHAT {
+    // message fields
+    pub mess: ::protobuf::SingularPtrField<super::base::NoticeMessPt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a G_R_CHAT {
+    fn default() -> &'a G_R_CHAT {
+        <G_R_CHAT as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl G_R_CHAT {
+    pub fn new() -> G_R_CHAT {
+        ::std::default::Default::default()
+    }
+
+    // .protos.NoticeMessPt mess = 1;
+
+
+    pub fn get_mess(&self) -> &super::base::NoticeMessPt {
+        self.mess.as_ref().unwrap_or_else(|| <super::base::NoticeMessPt as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_mess(&mut self) {
+        self.mess.clear();
+    }
+
+    pub fn has_mess(&self) -> bool {
+        self.mess.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mess(&mut self, v: super::base::NoticeMessPt) {
+        self.mess = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_mess(&mut self) -> &mut super::base::NoticeMessPt {
+        if self.mess.is_none() {
+            self.mess.set_default();
+        }
+        self.mess.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_mess(&mut self) -> super::base::NoticeMessPt {
+        self.mess.take().unwrap_or_else(|| super::base::NoticeMessPt::new())
+    }
+}
+
+impl ::protobuf::Message for G_R_CHAT {
+    fn is_initialized(&self) -> bool {
+        for v in &self.mess {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.mess)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.mess.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.mess.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> G_R_CHAT {
+        G_R_CHAT::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::NoticeMessPt>>(
+                "mess",
+                |m: &G_R_CHAT| { &m.mess },
+                |m: &mut G_R_CHAT| { &mut m.mess },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<G_R_CHAT>(
+                "G_R_CHAT",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static G_R_CHAT {
+        static instance: ::protobuf::rt::LazyV2<G_R_CHAT> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(G_R_CHAT::new)
+    }
+}
+
+impl ::protobuf::Clear for G_R_CHAT {
+    fn clear(&mut self) {
+        self.mess.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for G_R_CHAT {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for G_R_CHAT {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct R_G_SYNC_PRESENCE {
+    // message fields
+    pub presence: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a R_G_SYNC_PRESENCE {
+    fn default() -> &'a R_G_SYNC_PRESENCE {
+        <R_G_SYNC_PRESENCE as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl R_G_SYNC_PRESENCE {
+    pub fn new() -> R_G_SYNC_PRESENCE {
+        ::std::default::Default::default()
+    }
+
+    // uint32 presence = 1;
+
+
+    pub fn get_presence(&self) -> u32 {
+        self.presence
+    }
+    pub fn clear_presence(&mut self) {
+        self.presence = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_presence(&mut self, v: u32) {
+        self.presence = v;
+    }
+}
+
+impl ::protobuf::Message for R_G_SYNC_PRESENCE {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.presence = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.presence != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.presence, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.presence != 0 {
+            os.write_uint32(1, self.presence)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> R_G_SYNC_PRESENCE {
+        R_G_SYNC_PRESENCE::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "presence",
+                |m: &R_G_SYNC_PRESENCE| { &m.presence },
+                |m: &mut R_G_SYNC_PRESENCE| { &mut m.presence },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<R_G_SYNC_PRESENCE>(
+                "R_G_SYNC_PRESENCE",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static R_G_SYNC_PRESENCE {
+        static instance: ::protobuf::rt::LazyV2<R_G_SYNC_PRESENCE> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(R_G_SYNC_PRESENCE::new)
+    }
+}
+
+impl ::protobuf::Clear for R_G_SYNC_PRESENCE {
+    fn clear(&mut self) {
+        self.presence = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for R_G_SYNC_PRESENCE {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for R_G_SYNC_PRESENCE {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct G_SEND_MAIL {
+    // message fields
+    pub mail: ::protobuf::SingularPtrField<super::base::MailPt>,
+    pub broadcast_id: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a G_SEND_MAIL {
+    fn default() -> &'a G_SEND_MAIL {
+        <G_SEND_MAIL as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl G_SEND_MAIL {
+    pub fn new() -> G_SEND_MAIL {
+        ::std::default::Default::default()
+    }
+
+    // .protos.MailPt mail = 1;
+
+
+    pub fn get_mail(&self) -> &super::base::MailPt {
+        self.mail.as_ref().unwrap_or_else(|| <super::base::MailPt as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_mail(&mut self) {
+        self.mail.clear();
+    }
+
+    pub fn has_mail(&self) -> bool {
+        self.mail.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mail(&mut self, v: super::base::MailPt) {
+        self.mail = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_mail(&mut self) -> &mut super::base::MailPt {
+        if self.mail.is_none() {
+            self.mail.set_default();
+        }
+        self.mail.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_mail(&mut self) -> super::base::MailPt {
+        self.mail.take().unwrap_or_else(|| super::base::MailPt::new())
+    }
+
+    // uint64 broadcast_id = 2;
+
+
+    pub fn get_broadcast_id(&self) -> u64 {
+        self.broadcast_id
+    }
+    pub fn clear_broadcast_id(&mut self) {
+        self.broadcast_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_broadcast_id(&mut self, v: u64) {
+        self.broadcast_id = v;
+    }
+}
+
+impl ::protobuf::Message for G_SEND_MAIL {
+    fn is_initialized(&self) -> bool {
+        for v in &self.mail {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.mail)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.broadcast_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.mail.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.broadcast_id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.broadcast_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.mail.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.broadcast_id != 0 {
+            os.write_uint64(2, self.broadcast_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> G_SEND_MAIL {
+        G_SEND_MAIL::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::MailPt>>(
+                "mail",
+                |m: &G_SEND_MAIL| { &m.mail },
+                |m: &mut G_SEND_MAIL| { &mut m.mail },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "broadcast_id",
+                |m: &G_SEND_MAIL| { &m.broadcast_id },
+                |m: &mut G_SEND_MAIL| { &mut m.broadcast_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<G_SEND_MAIL>(
+                "G_SEND_MAIL",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static G_SEND_MAIL {
+        static instance: ::protobuf::rt::LazyV2<G_SEND_MAIL> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(G_SEND_MAIL::new)
+    }
+}
+
+impl ::protobuf::Clear for G_SEND_MAIL {
+    fn clear(&mut self) {
+        self.mail.clear();
+        self.broadcast_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for G_SEND_MAIL {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for G_SEND_MAIL {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct G_C_TOURNAMENT_SIGN_UP {
+    // message fields
+    pub tournament_id: u32,
+    pub nick_name: ::std::string::String,
+    pub league: ::protobuf::SingularPtrField<super::base::LeaguePt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a G_C_TOURNAMENT_SIGN_UP {
+    fn default() -> &'a G_C_TOURNAMENT_SIGN_UP {
+        <G_C_TOURNAMENT_SIGN_UP as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl G_C_TOURNAMENT_SIGN_UP {
+    pub fn new() -> G_C_TOURNAMENT_SIGN_UP {
+        ::std::default::Default::default()
+    }
+
+    // uint32 tournament_id = 1;
+
+
+    pub fn get_tournament_id(&self) -> u32 {
+        self.tournament_id
+    }
+    pub fn clear_tournament_id(&mut self) {
+        self.tournament_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tournament_id(&mut self, v: u32) {
+        self.tournament_id = v;
+    }
+
+    // string nick_name = 2;
+
+
+    pub fn get_nick_name(&self) -> &str {
+        &self.nick_name
+    }
+    pub fn clear_nick_name(&mut self) {
+        self.nick_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nick_name(&mut self, v: ::std::string::String) {
+        self.nick_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nick_name(&mut self) -> &mut ::std::string::String {
+        &mut self.nick_name
+    }
+
+    // Take field
+    pub fn take_nick_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.nick_name, ::std::string::String::new())
+    }
+
+    // .protos.LeaguePt league = 3;
+
+
+    pub fn get_league(&self) -> &super::base::LeaguePt {
+        self.league.as_ref().unwrap_or_else(|| <super::base::LeaguePt as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_league(&mut self) {
+        self.league.clear();
+    }
+
+    pub fn has_league(&self) -> bool {
+        self.league.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_league(&mut self, v: super::base::LeaguePt) {
+        self.league = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_league(&mut self) -> &mut super::base::LeaguePt {
+        if self.league.is_none() {
+            self.league.set_default();
+        }
+        self.league.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_league(&mut self) -> super::base::LeaguePt {
+        self.league.take().unwrap_or_else(|| super::base::LeaguePt::new())
+    }
+}
+
+impl ::protobuf::Message for G_C_TOURNAMENT_SIGN_UP {
+    fn is_initialized(&self) -> bool {
+        for v in &self.league {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.tournament_id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.nick_name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.league)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.tournament_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.tournament_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.nick_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.nick_name);
+        }
+        if let Some(ref v) = self.league.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.tournament_id != 0 {
+            os.write_uint32(1, self.tournament_id)?;
+        }
+        if !self.nick_name.is_empty() {
+            os.write_string(2, &self.nick_name)?;
+        }
+        if let Some(ref v) = self.league.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> G_C_TOURNAMENT_SIGN_UP {
+        G_C_TOURNAMENT_SIGN_UP::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "tournament_id",
+                |m: &G_C_TOURNAMENT_SIGN_UP| { &m.tournament_id },
+                |m: &mut G_C_TOURNAMENT_SIGN_UP| { &mut m.tournament_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "nick_name",
+                |m: &G_C_TOURNAMENT_SIGN_UP| { &m.nick_name },
+                |m: &mut G_C_TOURNAMENT_SIGN_UP| { &mut m.nick_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::base::LeaguePt>>(
+                "league",
+                |m: &G_C_TOURNAMENT_SIGN_UP| { &m.league },
+                |m: &mut G_C_TOURNAMENT_SIGN_UP| { &mut m.league },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<G_C_TOURNAMENT_SIGN_UP>(
+                "G_C_TOURNAMENT_SIGN_UP",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static G_C_TOURNAMENT_SIGN_UP {
+        static instance: ::protobuf::rt::LazyV2<G_C_TOURNAMENT_SIGN_UP> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(G_C_TOURNAMENT_SIGN_UP::new)
+    }
+}
+
+impl ::protobuf::Clear for G_C_TOURNAMENT_SIGN_UP {
+    fn clear(&mut self) {
+        self.tournament_id = 0;
+        self.nick_name.clear();
+        self.league.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for G_C_TOURNAMENT_SIGN_UP {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for G_C_TOURNAMENT_SIGN_UP {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct G_TOURNAMENT_MATCH {
+    // message fields
+    pub tournament_id: u32,
+    pub match_id: u32,
+    pub count: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a G_TOURNAMENT_MATCH {
+    fn default() -> &'a G_TOURNAMENT_MATCH {
+        <G_TOURNAMENT_MATCH as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl G_TOURNAMENT_MATCH {
+    pub fn new() -> G_TOURNAMENT_MATCH {
+        ::std::default::Default::default()
+    }
+
+    // uint32 tournament_id = 1;
+
+
+    pub fn get_tournament_id(&self) -> u32 {
+        self.tournament_id
+    }
+    pub fn clear_tournament_id(&mut self) {
+        self.tournament_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tournament_id(&mut self, v: u32) {
+        self.tournament_id = v;
+    }
+
+    // uint32 match_id = 2;
+
+
+    pub fn get_match_id(&self) -> u32 {
+        self.match_id
+    }
+    pub fn clear_match_id(&mut self) {
+        self.match_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_match_id(&mut self, v: u32) {
+        self.match_id = v;
+    }
+
+    // uint32 count = 3;
+
+
+    pub fn get_count(&self) -> u32 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u32) {
+        self.count = v;
+    }
+}
+
+impl ::protobuf::Message for G_TOURNAMENT_MATCH {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.tournament_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.match_id = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.tournament_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.tournament_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.match_id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.match_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.tournament_id != 0 {
+            os.write_uint32(1, self.tournament_id)?;
+        }
+        if self.match_id != 0 {
+            os.write_uint32(2, self.match_id)?;
+        }
+        if self.count != 0 {
+            os.write_uint32(3, self.count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> G_TOURNAMENT_MATCH {
+        G_TOURNAMENT_MATCH::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "tournament_id",
+                |m: &G_TOURNAMENT_MATCH| { &m.tournament_id },
+                |m: &mut G_TOURNAMENT_MATCH| { &mut m.tournament_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "match_id",
+                |m: &G_TOURNAMENT_MATCH| { &m.match_id },
+                |m: &mut G_TOURNAMENT_MATCH| { &mut m.match_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "count",
+                |m: &G_TOURNAMENT_MATCH| { &m.count },
+                |m: &mut G_TOURNAMENT_MATCH| { &mut m.count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<G_TOURNAMENT_MATCH>(
+                "G_TOURNAMENT_MATCH",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static G_TOURNAMENT_MATCH {
+        static instance: ::protobuf::rt::LazyV2<G_TOURNAMENT_MATCH> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(G_TOURNAMENT_MATCH::new)
+    }
+}
+
+impl ::protobuf::Clear for G_TOURNAMENT_MATCH {
+    fn clear(&mut self) {
+        self.tournament_id = 0;
+        self.match_id = 0;
+        self.count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for G_TOURNAMENT_MATCH {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for G_TOURNAMENT_MATCH {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct G_R_TOURNAMENT_JOIN {
+    // message fields
+    pub tournament_id: u32,
+    pub match_id: u32,
+    pub count: u32,
+    pub pbp: ::protobuf::SingularPtrField<PlayerBattlePt>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a G_R_TOURNAMENT_JOIN {
+    fn default() -> &'a G_R_TOURNAMENT_JOIN {
+        <G_R_TOURNAMENT_JOIN as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl G_R_TOURNAMENT_JOIN {
+    pub fn new() -> G_R_TOURNAMENT_JOIN {
+        ::std::default::Default::default()
+    }
+
+    // uint32 tournament_id = 1;
+
+
+    pub fn get_tournament_id(&self) -> u32 {
+        self.tournament_id
+    }
+    pub fn clear_tournament_id(&mut self) {
+        self.tournament_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tournament_id(&mut self, v: u32) {
+        self.tournament_id = v;
+    }
+
+    // uint32 match_id = 2;
+
+
+    pub fn get_match_id(&self) -> u32 {
+        self.match_id
+    }
+    pub fn clear_match_id(&mut self) {
+        self.match_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_match_id(&mut self, v: u32) {
+        self.match_id = v;
+    }
+
+    // uint32 count = 3;
+
+
+    pub fn get_count(&self) -> u32 {
+        self.count
+    }
+    pub fn clear_count(&mut self) {
+        self.count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u32) {
+        self.count = v;
+    }
+
+    // .protos.PlayerBattlePt pbp = 4;
+
+
+    pub fn get_pbp(&self) -> &PlayerBattlePt {
+        self.pbp.as_ref().unwrap_or_else(|| <PlayerBattlePt as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_pbp(&mut self) {
+        self.pbp.clear();
+    }
+
+    pub fn has_pbp(&self) -> bool {
+        self.pbp.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pbp(&mut self, v: PlayerBattlePt) {
+        self.pbp = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_pbp(&mut self) -> &mut PlayerBattlePt {
+        if self.pbp.is_none() {
+            self.pbp.set_default();
+        }
+        self.pbp.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_pbp(&mut self) -> PlayerBattlePt {
+        self.pbp.take().unwrap_or_else(|| PlayerBattlePt::new())
+    }
+}
+
+impl ::protobuf::Message for G_R_TOURNAMENT_JOIN {
+    fn is_initialized(&self) -> bool {
+        for v in &self.pbp {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.tournament_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.match_id = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.count = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.pbp)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.tournament_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.tournament_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.match_id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.match_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.pbp.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.tournament_id != 0 {
+            os.write_uint32(1, self.tournament_id)?;
+        }
+        if self.match_id != 0 {
+            os.write_uint32(2, self.match_id)?;
+        }
+        if self.count != 0 {
+            os.write_uint32(3, self.count)?;
+        }
+        if let Some(ref v) = self.pbp.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> G_R_TOURNAMENT_JOIN {
+        G_R_TOURNAMENT_JOIN::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "tournament_id",
+                |m: &G_R_TOURNAMENT_JOIN| { &m.tournament_id },
+                |m: &mut G_R_TOURNAMENT_JOIN| { &mut m.tournament_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "match_id",
+                |m: &G_R_TOURNAMENT_JOIN| { &m.match_id },
+                |m: &mut G_R_TOURNAMENT_JOIN| { &mut m.match_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "count",
+                |m: &G_R_TOURNAMENT_JOIN| { &m.count },
+                |m: &mut G_R_TOURNAMENT_JOIN| { &mut m.count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PlayerBattlePt>>(
+                "pbp",
+                |m: &G_R_TOURNAMENT_JOIN| { &m.pbp },
+                |m: &mut G_R_TOURNAMENT_JOIN| { &mut m.pbp },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<G_R_TOURNAMENT_JOIN>(
+                "G_R_TOURNAMENT_JOIN",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static G_R_TOURNAMENT_JOIN {
+        static instance: ::protobuf::rt::LazyV2<G_R_TOURNAMENT_JOIN> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(G_R_TOURNAMENT_JOIN::new)
+    }
+}
+
+impl ::protobuf::Clear for G_R_TOURNAMENT_JOIN {
+    fn clear(&mut self) {
+        self.tournament_id = 0;
+        self.match_id = 0;
+        self.count = 0;
+        self.pbp.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for G_R_TOURNAMENT_JOIN {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for G_R_TOURNAMENT_JOIN {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct R_C_TOURNAMENT_RESULT {
+    // message fields
+    pub tournament_id: u32,
+    pub match_id: u32,
+    pub ranks: ::std::vec::Vec<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a R_C_TOURNAMENT_RESULT {
+    fn default() -> &'a R_C_TOURNAMENT_RESULT {
+        <R_C_TOURNAMENT_RESULT as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl R_C_TOURNAMENT_RESULT {
+    pub fn new() -> R_C_TOURNAMENT_RESULT {
+        ::std::default::Default::default()
+    }
+
+    // uint32 tournament_id = 1;
+
+
+    pub fn get_tournament_id(&self) -> u32 {
+        self.tournament_id
+    }
+    pub fn clear_tournament_id(&mut self) {
+        self.tournament_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tournament_id(&mut self, v: u32) {
+        self.tournament_id = v;
+    }
+
+    // uint32 match_id = 2;
+
+
+    pub fn get_match_id(&self) -> u32 {
+        self.match_id
+    }
+    pub fn clear_match_id(&mut self) {
+        self.match_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_match_id(&mut self, v: u32) {
+        self.match_id = v;
+    }
+
+    // repeated uint32 ranks = 3;
+
+
+    pub fn get_ranks(&self) -> &[u32] {
+        &self.ranks
+    }
+    pub fn clear_ranks(&mut self) {
+        self.ranks.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ranks(&mut self, v: ::std::vec::Vec<u32>) {
+        self.ranks = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ranks(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.ranks
+    }
+
+    // Take field
+    pub fn take_ranks(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.ranks, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for R_C_TOURNAMENT_RESULT {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.tournament_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.match_id = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.ranks)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.tournament_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.tournament_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.match_id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.match_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.ranks {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.tournament_id != 0 {
+            os.write_uint32(1, self.tournament_id)?;
+        }
+        if self.match_id != 0 {
+            os.write_uint32(2, self.match_id)?;
+        }
+        for v in &self.ranks {
+            os.write_uint32(3, *v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> R_C_TOURNAMENT_RESULT {
+        R_C_TOURNAMENT_RESULT::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "tournament_id",
+                |m: &R_C_TOURNAMENT_RESULT| { &m.tournament_id },
+                |m: &mut R_C_TOURNAMENT_RESULT| { &mut m.tournament_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "match_id",
+                |m: &R_C_TOURNAMENT_RESULT| { &m.match_id },
+                |m: &mut R_C_TOURNAMENT_RESULT| { &mut m.match_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "ranks",
+                |m: &R_C_TOURNAMENT_RESULT| { &m.ranks },
+                |m: &mut R_C_TOURNAMENT_RESULT| { &mut m.ranks },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<R_C_TOURNAMENT_RESULT>(
+                "R_C_TOURNAMENT_RESULT",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static R_C_TOURNAMENT_RESULT {
+        static instance: ::protobuf::rt::LazyV2<R_C_TOURNAMENT_RESULT> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(R_C_TOURNAMENT_RESULT::new)
+    }
+}
+
+impl ::protobuf::Clear for R_C_TOURNAMENT_RESULT {
+    fn clear(&mut self) {
+        self.tournament_id = 0;
+        self.match_id = 0;
+        self.ranks.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for R_C_TOURNAMENT_RESULT {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for R_C_TOURNAMENT_RESULT {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x15server_protocol.proto\x12\x06protos\x1a\nbase.proto\"4\n\tR_B_STAR\
     T\x12'\n\x07room_pt\x18\x01\x20\x01(\x0b2\x0e.protos.RoomPtR\x06roomPt\"\
@@ -2681,29 +5133,59 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     PtR\x03pbp\x12/\n\x07setting\x18\x03\x20\x01(\x0b2\x15.protos.RoomSettin\
     gPtR\x07setting\"o\n\rG_R_JOIN_ROOM\x12\x17\n\x07room_id\x18\x01\x20\x01\
     (\rR\x06roomId\x12\x1b\n\troom_type\x18\x02\x20\x01(\rR\x08roomType\x12(\
-    \n\x03pbp\x18\x03\x20\x01(\x0b2\x16.protos.PlayerBattlePtR\x03pbp\"X\n\
-    \x0fG_R_SEARCH_ROOM\x12\x1b\n\troom_type\x18\x01\x20\x01(\rR\x08roomType\
-    \x12(\n\x03pbp\x18\x02\x20\x01(\x0b2\x16.protos.PlayerBattlePtR\x03pbp\"\
-    z\n\x0bB_S_SUMMARY\x12\x1b\n\troom_type\x18\x01\x20\x01(\rR\x08roomType\
-    \x12\x14\n\x05cters\x18\x02\x20\x03(\rR\x05cters\x128\n\x0csummary_data\
-    \x18\x03\x20\x01(\x0b2\x15.protos.SummaryDataPtR\x0bsummaryData\"C\n\x0b\
-    B_R_SUMMARY\x12\x1b\n\troom_type\x18\x01\x20\x01(\rR\x08roomType\x12\x17\
-    \n\x07room_id\x18\x02\x20\x01(\rR\x06roomId\"\xa0\x02\n\x0ePlayerBattleP\
-    t\x12\x17\n\x07user_id\x18\x01\x20\x01(\rR\x06userId\x12\x1b\n\tnick_nam\
-    e\x18\x02\x20\x01(\tR\x08nickName\x12\x14\n\x05grade\x18\x03\x20\x01(\rR\
-    \x05grade\x12\x1f\n\x0bgrade_frame\x18\x04\x20\x01(\rR\ngradeFrame\x12\
-    \x12\n\x04soul\x18\x05\x20\x01(\rR\x04soul\x12(\n\x06league\x18\x06\x20\
-    \x01(\x0b2\x10.protos.LeaguePtR\x06league\x128\n\x0cpunish_match\x18\x07\
-    \x20\x01(\x0b2\x15.protos.PunishMatchPtR\x0bpunishMatch\x12)\n\x05cters\
-    \x18\x08\x20\x03(\x0b2\x13.protos.CharacterPtR\x05cters\"N\n\x12B_R_G_PU\
-    NISH_MATCH\x128\n\x0cpunish_match\x18\x01\x20\x01(\x0b2\x15.protos.Punis\
-    hMatchPtR\x0bpunishMatch\"\x0f\n\rR_G_SYNC_RANK\"p\n\x11R_S_UPDATE_SEASO\
-    N\x12\x1b\n\tseason_id\x18\x01\x20\x01(\x05R\x08seasonId\x12\x14\n\x05ro\
-    und\x18\x02\x20\x01(\rR\x05round\x12(\n\x10next_update_time\x18\x03\x20\
-    \x01(\x04R\x0enextUpdateTime\"3\n\x14G_S_MODIFY_NICK_NAME\x12\x1b\n\tnic\
-    k_name\x18\x01\x20\x01(\tR\x08nickName\"f\n\x16UPDATE_WORLD_BOSS_PUSH\
-    \x12\"\n\rworld_boss_id\x18\x01\x20\x01(\x05R\x0bworldBossId\x12(\n\x10n\
-    ext_update_time\x18\x02\x20\x01(\x04R\x0enextUpdateTimeb\x06proto3\
+    \n\x03pbp\x18\x03\x20\x01(\x0b2\x16.protos.PlayerBattlePtR\x03pbp\"\x86\
+    \x01\n\x0fG_R_SEARCH_ROOM\x12\x1b\n\troom_type\x18\x01\x20\x01(\rR\x08ro\
+    omType\x12(\n\x03pbp\x18\x02\x20\x01(\x0b2\x16.protos.PlayerBattlePtR\
+    \x03pbp\x12,\n\x05party\x18\x03\x20\x03(\x0b2\x16.protos.PlayerBattlePtR\
+    \x05party\"z\n\x0bB_S_SUMMARY\x12\x1b\n\troom_type\x18\x01\x20\x01(\rR\
+    \x08roomType\x12\x14\n\x05cters\x18\x02\x20\x03(\rR\x05cters\x128\n\x0cs\
+    ummary_data\x18\x03\x20\x01(\x0b2\x15.protos.SummaryDataPtR\x0bsummaryDa\
+    ta\"Y\n\x0bB_R_SUMMARY\x12\x1b\n\troom_type\x18\x01\x20\x01(\rR\x08roomT\
+    ype\x12\x17\n\x07room_id\x18\x02\x20\x01(\rR\x06roomId\x12\x14\n\x05rank\
+    s\x18\x03\x20\x03(\rR\x05ranks\"\xa0\x02\n\x0ePlayerBattlePt\x12\x17\n\
+    \x07user_id\x18\x01\x20\x01(\rR\x06userId\x12\x1b\n\tnick_name\x18\x02\
+    \x20\x01(\tR\x08nickName\x12\x14\n\x05grade\x18\x03\x20\x01(\rR\x05grade\
+    \x12\x1f\n\x0bgrade_frame\x18\x04\x20\x01(\rR\ngradeFrame\x12\x12\n\x04s\
+    oul\x18\x05\x20\x01(\rR\x04soul\x12(\n\x06league\x18\x06\x20\x01(\x0b2\
+    \x10.protos.LeaguePtR\x06league\x128\n\x0cpunish_match\x18\x07\x20\x01(\
+    \x0b2\x15.protos.PunishMatchPtR\x0bpunishMatch\x12)\n\x05cters\x18\x08\
+    \x20\x03(\x0b2\x13.protos.CharacterPtR\x05cters\"N\n\x12B_R_G_PUNISH_MAT\
+    CH\x128\n\x0cpunish_match\x18\x01\x20\x01(\x0b2\x15.protos.PunishMatchPt\
+    R\x0bpunishMatch\"\x0f\n\rR_G_SYNC_RANK\"p\n\x11R_S_UPDATE_SEASON\x12\
+    \x1b\n\tseason_id\x18\x01\x20\x01(\x05R\x08seasonId\x12\x14\n\x05round\
+    \x18\x02\x20\x01(\rR\x05round\x12(\n\x10next_update_time\x18\x03\x20\x01\
+    (\x04R\x0enextUpdateTime\"3\n\x14G_S_MODIFY_NICK_NAME\x12\x1b\n\tnick_na\
+    me\x18\x01\x20\x01(\tR\x08nickName\"f\n\x16UPDATE_WORLD_BOSS_PUSH\x12\"\
+    \n\rworld_boss_id\x18\x01\x20\x01(\x05R\x0bworldBossId\x12(\n\x10next_up\
+    date_time\x18\x02\x20\x01(\x04R\x0enextUpdateTime\"n\n\x15B_R_WORLD_BOSS\
+    _DAMAGE\x12\"\n\rworld_boss_id\x18\x01\x20\x01(\x05R\x0bworldBossId\x121\
+    \n\x07damages\x18\x02\x20\x03(\x0b2\x17.protos.WorldBossRankPtR\x07damag\
+    es\"\xe4\x01\n\x13R_G_WORLD_BOSS_PUSH\x12\"\n\rworld_boss_id\x18\x01\x20\
+    \x01(\x05R\x0bworldBossId\x12\x0e\n\x02hp\x18\x02\x20\x01(\x04R\x02hp\
+    \x12\x15\n\x06max_hp\x18\x03\x20\x01(\x04R\x05maxHp\x12\x1c\n\tmilestone\
+    \x18\x04\x20\x01(\rR\tmilestone\x12\x16\n\x06notice\x18\x05\x20\x01(\x08\
+    R\x06notice\x12\x1d\n\nis_settled\x18\x06\x20\x01(\x08R\tisSettled\x12-\
+    \n\x05ranks\x18\x07\x20\x03(\x0b2\x17.protos.WorldBossRankPtR\x05ranks\"\
+    m\n\x0cG_GM_COMMAND\x12\x1f\n\x0boperator_id\x18\x01\x20\x01(\rR\noperat\
+    orId\x12\x14\n\x05level\x18\x02\x20\x01(\rR\x05level\x12\x12\n\x04name\
+    \x18\x03\x20\x01(\tR\x04name\x12\x12\n\x04args\x18\x04\x20\x03(\tR\x04ar\
+    gs\"4\n\x08G_R_CHAT\x12(\n\x04mess\x18\x01\x20\x01(\x0b2\x14.protos.Noti\
+    ceMessPtR\x04mess\"/\n\x11R_G_SYNC_PRESENCE\x12\x1a\n\x08presence\x18\
+    \x01\x20\x01(\rR\x08presence\"T\n\x0bG_SEND_MAIL\x12\"\n\x04mail\x18\x01\
+    \x20\x01(\x0b2\x0e.protos.MailPtR\x04mail\x12!\n\x0cbroadcast_id\x18\x02\
+    \x20\x01(\x04R\x0bbroadcastId\"\x84\x01\n\x16G_C_TOURNAMENT_SIGN_UP\x12#\
+    \n\rtournament_id\x18\x01\x20\x01(\rR\x0ctournamentId\x12\x1b\n\tnick_na\
+    me\x18\x02\x20\x01(\tR\x08nickName\x12(\n\x06league\x18\x03\x20\x01(\x0b\
+    2\x10.protos.LeaguePtR\x06league\"j\n\x12G_TOURNAMENT_MATCH\x12#\n\rtour\
+    nament_id\x18\x01\x20\x01(\rR\x0ctournamentId\x12\x19\n\x08match_id\x18\
+    \x02\x20\x01(\rR\x07matchId\x12\x14\n\x05count\x18\x03\x20\x01(\rR\x05co\
+    unt\"\x95\x01\n\x13G_R_TOURNAMENT_JOIN\x12#\n\rtournament_id\x18\x01\x20\
+    \x01(\rR\x0ctournamentId\x12\x19\n\x08match_id\x18\x02\x20\x01(\rR\x07ma\
+    tchId\x12\x14\n\x05count\x18\x03\x20\x01(\rR\x05count\x12(\n\x03pbp\x18\
+    \x04\x20\x01(\x0b2\x16.protos.PlayerBattlePtR\x03pbp\"m\n\x15R_C_TOURNAM\
+    ENT_RESULT\x12#\n\rtournament_id\x18\x01\x20\x01(\rR\x0ctournamentId\x12\
+    \x19\n\x08match_id\x18\x02\x20\x01(\rR\x07matchId\x12\x14\n\x05ranks\x18\
+    \x03\x20\x03(\rR\x05ranksb\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -137,6 +137,31 @@ impl RedisPoolTool {
         }
     }
 
+    ///hash字段不存在的时候才写入,写入成功返回1,已存在返回0
+    pub fn hset_nx<T: FromRedisValue>(
+        &mut self,
+        index: u32,
+        hkey: &str,
+        key: &str,
+        value: &str,
+    ) -> Option<T> {
+        let mut pip = get_pip();
+        let conn_mut = &mut self.conn;
+        pip.cmd("select").arg(index).execute(conn_mut);
+        let res = conn_mut.hset_nx(hkey, key, value);
+        pip.cmd("select").arg(0).execute(conn_mut);
+        match res {
+            Ok(v) => Some(v),
+            Err(e) => {
+                error!(
+                    "{:?},index:{},hkey:{:?},key:{:?},value:{:?}",
+                    e, index, hkey, key, value
+                );
+                None
+            }
+        }
+    }
+
     ///读hash数据结构
     pub fn hget<T: FromRedisValue>(&mut self, index: u32, hkey: &str, key: &str) -> Option<T> {
         let mut pip = get_pip();
@@ -217,6 +242,38 @@ impl RedisPoolTool {
         }
     }
 
+    ///执行lua脚本,整个脚本在redis里原子执行
+    /// keys:脚本里的KEYS
+    /// args:脚本里的ARGV
+    pub fn eval<T: FromRedisValue>(
+        &mut self,
+        index: u32,
+        script: &str,
+        keys: &[&str],
+        args: &[&str],
+    ) -> Option<T> {
+        let mut pip = get_pip();
+        let conn_mut = &mut self.conn;
+        pip.cmd("select").arg(index).execute(conn_mut);
+        let script = redis::Script::new(script);
+        let mut invocation = script.prepare_invoke();
+        for &key in keys {
+            invocation.key(key);
+        }
+        for &arg in args {
+            invocation.arg(arg);
+        }
+        let res = invocation.invoke(conn_mut);
+        pip.cmd("select").arg(0).execute(conn_mut);
+        match res {
+            Ok(v) => Some(v),
+            Err(e) => {
+                error!("{:?},index:{},keys:{:?},args:{:?}", e, index, keys, args);
+                None
+            }
+        }
+    }
+
     ///读hash数据结构
     pub fn test<T: FromRedisValue>(&mut self, index: u32, hkey: &str, key: &str) -> Option<T> {
         let mut pip = get_pip();
@@ -0,0 +1,577 @@
+use super::*;
+use crate::redis_pool::RedisPoolTool;
+use chrono::{DateTime, Datelike, FixedOffset, Local, NaiveDate, Offset, TimeZone, Timelike, Utc};
+use crossbeam::channel::{RecvTimeoutError, Sender};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+///调度线程最多睡这么久就醒一次,防止系统时间被调整之后睡过头
+const MAX_SLEEP_SECS: i64 = 60;
+
+///找下一个触发点最多往后找的天数,找不到说明表达式永远不会触发(比如2月30号)
+const MAX_SEARCH_DAYS: u32 = 366 * 5;
+
+///cron表达式,格式为"分 时 日 月 周"
+/// 支持"*","*/n","a-b","a-b/n","a,b,c",周里面0和7都代表周日
+/// 日和周都限定了的时候满足一个就行,有一个以*开头(包括*/n)的时候要同时满足,和Vixie cron一致
+#[derive(Debug, Clone)]
+pub struct CronExpr {
+    minutes: Vec<bool>,  //0-59
+    hours: Vec<bool>,    //0-23
+    days: Vec<bool>,     //1-31
+    months: Vec<bool>,   //1-12
+    weekdays: Vec<bool>, //0-6,0是周日
+    day_any: bool,       //日是否以*开头
+    weekday_any: bool,   //周是否以*开头
+}
+
+impl FromStr for CronExpr {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CronExpr::parse(s)
+    }
+}
+
+impl CronExpr {
+    pub fn parse(expr: &str) -> anyhow::Result<Self> {
+        let expr = match expr.trim() {
+            "@hourly" => "0 * * * *",
+            "@daily" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            other => other,
+        };
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        if fields.len() != 5 {
+            anyhow::bail!("cron expr must have 5 fields!expr:{}", expr)
+        }
+        let mut weekdays = parse_field(fields[4], 0, 7)?;
+        if weekdays[7] {
+            weekdays[0] = true;
+        }
+        weekdays.truncate(7);
+        Ok(CronExpr {
+            minutes: parse_field(fields[0], 0, 59)?,
+            hours: parse_field(fields[1], 0, 23)?,
+            days: parse_field(fields[2], 1, 31)?,
+            months: parse_field(fields[3], 1, 12)?,
+            weekdays,
+            day_any: fields[2].starts_with('*'),
+            weekday_any: fields[4].starts_with('*'),
+        })
+    }
+
+    ///time之后(不含)的下一个触发点,按time所在时区算
+    pub fn next_after(&self, time: &DateTime<FixedOffset>) -> Option<DateTime<FixedOffset>> {
+        let naive = time.naive_local();
+        let mut date = naive.date();
+        let mut hour = naive.hour();
+        let mut minute = naive.minute() + 1;
+        for _ in 0..MAX_SEARCH_DAYS {
+            if self.match_date(&date) {
+                while hour < 24 {
+                    if self.hours[hour as usize] {
+                        while minute < 60 {
+                            if self.minutes[minute as usize] {
+                                let next = date.and_hms_opt(hour, minute, 0)?;
+                                return time.offset().from_local_datetime(&next).single();
+                            }
+                            minute += 1;
+                        }
+                    }
+                    hour += 1;
+                    minute = 0;
+                }
+            }
+            date = date.succ_opt()?;
+            hour = 0;
+            minute = 0;
+        }
+        None
+    }
+
+    fn match_date(&self, date: &NaiveDate) -> bool {
+        if !self.months[date.month() as usize] {
+            return false;
+        }
+        let day = self.days[date.day() as usize];
+        let weekday = self.weekdays[date.weekday().num_days_from_sunday() as usize];
+        if self.day_any || self.weekday_any {
+            day && weekday
+        } else {
+            day || weekday
+        }
+    }
+}
+
+///解析cron的单个字段,返回下标为值的命中表
+fn parse_field(field: &str, min: u32, max: u32) -> anyhow::Result<Vec<bool>> {
+    let mut res = vec![false; max as usize + 1];
+    for part in field.split(',') {
+        let (range, step) = match part.find('/') {
+            Some(index) => (&part[..index], part[index + 1..].parse::<u32>()?),
+            None => (part, 1),
+        };
+        if step == 0 {
+            anyhow::bail!("cron step can not be 0!field:{}", field)
+        }
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some(index) = range.find('-') {
+            (
+                range[..index].parse::<u32>()?,
+                range[index + 1..].parse::<u32>()?,
+            )
+        } else {
+            let v = range.parse::<u32>()?;
+            //"a/n"代表从a开始每n个触发一次
+            if part.contains('/') {
+                (v, max)
+            } else {
+                (v, v)
+            }
+        };
+        if start < min || end > max || start > end {
+            anyhow::bail!("cron field is out of range!field:{}", field)
+        }
+        let mut v = start;
+        while v <= end {
+            res[v as usize] = true;
+            v += step;
+        }
+    }
+    Ok(res)
+}
+
+///本机时区
+pub fn local_timezone() -> FixedOffset {
+    Local::now().offset().fix()
+}
+
+///解析时区,支持"+08:00","-0530","+8","UTC",空字符串或者"local"代表本机时区
+pub fn parse_timezone(s: &str) -> anyhow::Result<FixedOffset> {
+    let s = s.trim();
+    if s.is_empty() || s.eq_ignore_ascii_case("local") {
+        return Ok(local_timezone());
+    }
+    if s.eq_ignore_ascii_case("utc") || s == "Z" {
+        return Ok(Utc.fix());
+    }
+    let (sign, rest) = match s.chars().next() {
+        Some('+') => (1, &s[1..]),
+        Some('-') => (-1, &s[1..]),
+        _ => anyhow::bail!("timezone must start with +/-!timezone:{}", s),
+    };
+    let rest = rest.replace(':', "");
+    let (hour, minute) = match rest.len() {
+        1 | 2 => (rest.parse::<i32>()?, 0),
+        4 => (rest[..2].parse::<i32>()?, rest[2..].parse::<i32>()?),
+        _ => anyhow::bail!("timezone format is invalid!timezone:{}", s),
+    };
+    if hour > 14 || minute >= 60 {
+        anyhow::bail!("timezone is out of range!timezone:{}", s)
+    }
+    FixedOffset::east_opt(sign * (hour * 3600 + minute * 60))
+        .ok_or_else(|| anyhow::anyhow!("timezone is out of range!timezone:{}", s))
+}
+
+///指定时区的当前时间
+pub fn now_in(tz: &FixedOffset) -> DateTime<FixedOffset> {
+    Utc::now().with_timezone(tz)
+}
+
+///last(秒)之后,now(秒)之前(含)是否有触发点,last<=0代表从来没执行过
+pub fn is_missed(expr: &CronExpr, tz: &FixedOffset, last: i64, now: i64) -> bool {
+    if last <= 0 {
+        return true;
+    }
+    match next_time(expr, tz, last) {
+        Some(next) => next <= now,
+        None => false,
+    }
+}
+
+///time(秒)之后的下一个触发点(秒)
+pub fn next_time(expr: &CronExpr, tz: &FixedOffset, time: i64) -> Option<i64> {
+    let time = tz.timestamp_opt(time, 0).single()?;
+    expr.next_after(&time).map(|next| next.timestamp())
+}
+
+///任务上次执行时间的存储,用来在重启之后补执行错过的任务
+pub trait JobStore: Send {
+    ///上次执行时间(秒)
+    fn load(&mut self, name: &str) -> Option<i64>;
+    ///保存执行时间(秒)
+    fn save(&mut self, name: &str, time: i64);
+}
+
+///存内存里,重启就没了,测试用
+#[derive(Debug, Default)]
+pub struct MemoryJobStore {
+    times: HashMap<String, i64>,
+}
+
+impl JobStore for MemoryJobStore {
+    fn load(&mut self, name: &str) -> Option<i64> {
+        self.times.get(name).copied()
+    }
+
+    fn save(&mut self, name: &str, time: i64) {
+        self.times.insert(name.to_owned(), time);
+    }
+}
+
+///存redis的hash里,key为任务名
+pub struct RedisJobStore {
+    redis: Arc<Mutex<RedisPoolTool>>,
+    index: u32,
+    key: String,
+}
+
+impl RedisJobStore {
+    pub fn new(redis: Arc<Mutex<RedisPoolTool>>, index: u32, key: String) -> Self {
+        RedisJobStore { redis, index, key }
+    }
+}
+
+impl JobStore for RedisJobStore {
+    fn load(&mut self, name: &str) -> Option<i64> {
+        let mut lock = self.redis.lock().unwrap();
+        let res: Option<String> = lock.hget(self.index, self.key.as_str(), name);
+        res.and_then(|s| s.parse::<i64>().ok())
+    }
+
+    fn save(&mut self, name: &str, time: i64) {
+        let mut lock = self.redis.lock().unwrap();
+        let _: Option<u32> = lock.hset(
+            self.index,
+            self.key.as_str(),
+            name,
+            time.to_string().as_str(),
+        );
+    }
+}
+
+///定时任务
+struct Job {
+    name: String,                  //任务名
+    expr: CronExpr,                //cron表达式
+    catch_up: bool,                //停服期间错过的启动时是否补执行一次
+    next: Option<i64>,             //下次执行时间(秒)
+    func: Box<dyn FnMut() + Send>, //执行函数
+}
+
+///按cron表达式执行任务的调度器,所有任务在同一个线程里串行执行
+pub struct Scheduler {
+    tz: FixedOffset,
+    store: Box<dyn JobStore>,
+    jobs: Vec<Job>,
+}
+
+impl Scheduler {
+    pub fn new(tz: FixedOffset, store: Box<dyn JobStore>) -> Self {
+        Scheduler {
+            tz,
+            store,
+            jobs: Vec::new(),
+        }
+    }
+
+    ///添加任务,name需要唯一,会作为存储上次执行时间的key
+    pub fn add_job<F>(
+        &mut self,
+        name: &str,
+        expr: CronExpr,
+        catch_up: bool,
+        func: F,
+    ) -> anyhow::Result<()>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        if self.jobs.iter().any(|job| job.name == name) {
+            anyhow::bail!("scheduler job is repeated!name:{}", name)
+        }
+        self.jobs.push(Job {
+            name: name.to_owned(),
+            expr,
+            catch_up,
+            next: None,
+            func: Box::new(func),
+        });
+        Ok(())
+    }
+
+    ///启动时补执行停服期间错过的任务,并算好下次执行时间
+    /// 没有执行记录的任务只记录当前时间,不补执行
+    pub fn catch_up(&mut self, now: i64) {
+        for job in self.jobs.iter_mut() {
+            match self.store.load(job.name.as_str()) {
+                Some(last) => {
+                    if job.catch_up && is_missed(&job.expr, &self.tz, last, now) {
+                        info!("补执行错过的定时任务!name:{},last:{}", job.name, last);
+                        (job.func)();
+                        self.store.save(job.name.as_str(), now);
+                    }
+                }
+                None => self.store.save(job.name.as_str(), now),
+            }
+            job.next = next_time(&job.expr, &self.tz, now);
+            if job.next.is_none() {
+                warn!("定时任务永远不会触发!name:{}", job.name);
+            }
+        }
+    }
+
+    ///执行到点的任务,返回最近的下次执行时间(秒)
+    pub fn tick(&mut self, now: i64) -> Option<i64> {
+        let mut min_next: Option<i64> = None;
+        for job in self.jobs.iter_mut() {
+            if let Some(next) = job.next {
+                if next <= now {
+                    info!("开始执行定时任务!name:{}", job.name);
+                    (job.func)();
+                    self.store.save(job.name.as_str(), now);
+                    job.next = next_time(&job.expr, &self.tz, now);
+                }
+            }
+            if let Some(next) = job.next {
+                min_next = Some(min_next.map_or(next, |min| min.min(next)));
+            }
+        }
+        min_next
+    }
+
+    ///起一个线程跑调度
+    pub fn start(mut self, thread_name: &str) {
+        let m = move || {
+            self.catch_up(Utc::now().timestamp());
+            loop {
+                let now = Utc::now().timestamp();
+                let next = self.tick(now);
+                let secs = next
+                    .map_or(MAX_SLEEP_SECS, |next| next - now)
+                    .clamp(1, MAX_SLEEP_SECS);
+                std::thread::sleep(Duration::from_secs(secs as u64));
+            }
+        };
+        let res = std::thread::Builder::new()
+            .name(thread_name.to_owned())
+            .spawn(m);
+        if let Err(e) = res {
+            error!("{:?}", e);
+        }
+    }
+}
+
+type DelayTask = Box<dyn FnOnce() + Send>;
+
+///延迟任务执行器,到点之后丢到线程池里执行
+pub struct DelayTimer {
+    sender: Sender<(Instant, DelayTask)>,
+}
+
+impl DelayTimer {
+    pub fn with_name(name: &str, threads: usize) -> Self {
+        let (sender, rec) = crossbeam::channel::unbounded::<(Instant, DelayTask)>();
+        let pool = threadpool::Builder::new()
+            .thread_name(name.to_owned())
+            .num_threads(threads)
+            .build();
+        let m = move || {
+            let mut heap: BinaryHeap<Reverse<(Instant, u64)>> = BinaryHeap::new();
+            let mut tasks: HashMap<u64, DelayTask> = HashMap::new();
+            let mut seq: u64 = 0;
+            loop {
+                //先把到点的任务丢出去
+                let now = Instant::now();
+                while let Some(&Reverse((time, id))) = heap.peek() {
+                    if time > now {
+                        break;
+                    }
+                    heap.pop();
+                    if let Some(task) = tasks.remove(&id) {
+                        pool.execute(task);
+                    }
+                }
+                let res = match heap.peek() {
+                    Some(&Reverse((time, _))) => rec.recv_timeout(time - now),
+                    None => rec.recv().map_err(|_| RecvTimeoutError::Disconnected),
+                };
+                match res {
+                    Ok((time, task)) => {
+                        seq += 1;
+                        heap.push(Reverse((time, seq)));
+                        tasks.insert(seq, task);
+                    }
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+        };
+        let res = std::thread::Builder::new()
+            .name(format!("{}_DELAY", name))
+            .spawn(m);
+        if let Err(e) = res {
+            error!("{:?}", e);
+        }
+        DelayTimer { sender }
+    }
+
+    ///delay之后执行f
+    pub fn execute_after<F>(&self, delay: Duration, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let res = self.sender.send((Instant::now() + delay, Box::new(f)));
+        if let Err(e) = res {
+            error!("{}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(tz: &FixedOffset, y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<FixedOffset> {
+        tz.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn parse_cron() {
+        assert!(CronExpr::parse("0 0 * * *").is_ok());
+        assert!(CronExpr::parse("*/15 1-5,20 1 */2 1-5").is_ok());
+        assert!(CronExpr::parse("@weekly").is_ok());
+        assert!(CronExpr::parse("0 0 * *").is_err());
+        assert!(CronExpr::parse("60 0 * * *").is_err());
+        assert!(CronExpr::parse("0 0 0 * *").is_err());
+        assert!(CronExpr::parse("*/0 0 * * *").is_err());
+    }
+
+    #[test]
+    fn cron_next_after() {
+        let tz = parse_timezone("+08:00").unwrap();
+        let daily = CronExpr::parse("0 0 * * *").unwrap();
+        let next = daily.next_after(&time(&tz, 2024, 2, 28, 23, 59)).unwrap();
+        assert_eq!(next, time(&tz, 2024, 2, 29, 0, 0));
+        //正好在触发点上要算下一次
+        let next = daily.next_after(&time(&tz, 2024, 2, 29, 0, 0)).unwrap();
+        assert_eq!(next, time(&tz, 2024, 3, 1, 0, 0));
+
+        //2024-03-06是周三,下一个周一是03-11,7也代表周日
+        let weekly = CronExpr::parse("30 4 * * 1").unwrap();
+        let next = weekly.next_after(&time(&tz, 2024, 3, 6, 12, 0)).unwrap();
+        assert_eq!(next, time(&tz, 2024, 3, 11, 4, 30));
+        let sunday = CronExpr::parse("0 0 * * 7").unwrap();
+        let next = sunday.next_after(&time(&tz, 2024, 3, 6, 12, 0)).unwrap();
+        assert_eq!(next, time(&tz, 2024, 3, 10, 0, 0));
+
+        let step = CronExpr::parse("*/20 * * * *").unwrap();
+        let next = step.next_after(&time(&tz, 2024, 3, 6, 12, 41)).unwrap();
+        assert_eq!(next, time(&tz, 2024, 3, 6, 13, 0));
+
+        //日和周都限定了满足一个就行
+        let either = CronExpr::parse("0 0 1 * 1").unwrap();
+        let next = either.next_after(&time(&tz, 2024, 3, 6, 12, 0)).unwrap();
+        assert_eq!(next, time(&tz, 2024, 3, 11, 0, 0));
+        //日是*/n的时候要和周同时满足,03-04是周一但是双号
+        let both = CronExpr::parse("0 0 */2 * 1").unwrap();
+        let next = both.next_after(&time(&tz, 2024, 3, 1, 12, 0)).unwrap();
+        assert_eq!(next, time(&tz, 2024, 3, 11, 0, 0));
+
+        let never = CronExpr::parse("0 0 30 2 *").unwrap();
+        assert!(never.next_after(&time(&tz, 2024, 3, 6, 12, 0)).is_none());
+    }
+
+    #[test]
+    fn timezone() {
+        assert_eq!(
+            parse_timezone("+08:00").unwrap().local_minus_utc(),
+            8 * 3600
+        );
+        assert_eq!(
+            parse_timezone("-0530").unwrap().local_minus_utc(),
+            -(5 * 3600 + 1800)
+        );
+        assert_eq!(parse_timezone("+9").unwrap().local_minus_utc(), 9 * 3600);
+        assert_eq!(parse_timezone("UTC").unwrap().local_minus_utc(), 0);
+        assert!(parse_timezone("8").is_err());
+        assert!(parse_timezone("+15:00").is_err());
+    }
+
+    #[test]
+    fn missed_reset() {
+        let daily = CronExpr::parse("0 0 * * *").unwrap();
+        let utc = parse_timezone("UTC").unwrap();
+        let tz8 = parse_timezone("+08:00").unwrap();
+        let last = time(&utc, 2024, 3, 6, 15, 0).timestamp();
+        let now = time(&utc, 2024, 3, 6, 17, 0).timestamp();
+        //utc还没跨天,东八区已经跨天了
+        assert!(!is_missed(&daily, &utc, last, now));
+        assert!(is_missed(&daily, &tz8, last, now));
+        assert!(is_missed(&daily, &utc, 0, now));
+    }
+
+    #[test]
+    fn scheduler_catch_up() {
+        let utc = parse_timezone("UTC").unwrap();
+        let last = time(&utc, 2024, 3, 5, 12, 0).timestamp();
+        let now = time(&utc, 2024, 3, 6, 12, 0).timestamp();
+        let mut store = MemoryJobStore::default();
+        store.save("day_reset", last);
+        store.save("no_catch_up", last);
+
+        let count = Arc::new(Mutex::new(HashMap::<&'static str, u32>::new()));
+        let daily = CronExpr::parse("0 0 * * *").unwrap();
+        let mut scheduler = Scheduler::new(utc, Box::new(store));
+        for &(name, catch_up) in [
+            ("day_reset", true),
+            ("no_catch_up", false),
+            ("new_job", true),
+        ]
+        .iter()
+        {
+            let count = count.clone();
+            scheduler
+                .add_job(name, daily.clone(), catch_up, move || {
+                    *count.lock().unwrap().entry(name).or_insert(0) += 1;
+                })
+                .unwrap();
+        }
+        assert!(scheduler.add_job("day_reset", daily, true, || {}).is_err());
+
+        //停服期间错过的只补一次,没有记录的新任务不补
+        scheduler.catch_up(now);
+        assert_eq!(count.lock().unwrap().get("day_reset"), Some(&1));
+        assert_eq!(count.lock().unwrap().get("no_catch_up"), None);
+        assert_eq!(count.lock().unwrap().get("new_job"), None);
+
+        let next = time(&utc, 2024, 3, 7, 0, 0).timestamp();
+        assert_eq!(scheduler.tick(next - 1), Some(next));
+        assert_eq!(count.lock().unwrap().get("day_reset"), Some(&1));
+        scheduler.tick(next);
+        assert_eq!(count.lock().unwrap().get("day_reset"), Some(&2));
+        assert_eq!(count.lock().unwrap().get("no_catch_up"), Some(&1));
+        assert_eq!(count.lock().unwrap().get("new_job"), Some(&1));
+    }
+
+    #[test]
+    fn delay_timer() {
+        let timer = DelayTimer::with_name("TEST_TIMER", 2);
+        let (sender, rec) = crossbeam::channel::unbounded();
+        for &(id, delay) in [(2u32, 200u64), (1, 50), (3, 400)].iter() {
+            let sender = sender.clone();
+            timer.execute_after(Duration::from_millis(delay), move || {
+                sender.send(id).unwrap();
+            });
+        }
+        let res: Vec<u32> = (0..3)
+            .map(|_| rec.recv_timeout(Duration::from_secs(2)).unwrap())
+            .collect();
+        assert_eq!(res, vec![1, 2, 3]);
+    }
+}
@@ -2,13 +2,23 @@ use super::*;
 use async_trait::async_trait;
 use crossbeam::channel::{Receiver, Sender};
 use net2::TcpStreamExt;
+use rand::Rng;
+use std::collections::VecDeque;
 use std::io;
 use std::io::{Read, Write};
 use std::marker::{Send, Sync};
 use std::net::Shutdown;
 use std::net::TcpStream;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
+///重连退避的初始时间(毫秒)
+pub const RECONNECT_BASE_MILLIS: u64 = 500;
+///重连退避的最大时间(毫秒)
+pub const RECONNECT_MAX_MILLIS: u64 = 30_000;
+///tcp客户端断线期间最多缓存的消息数量
+pub const CLIENT_BUFFER_LIMIT: usize = 10240;
+
 ///The TCP server side handler is used to handle TCP general events, such as connections,
 /// closing connections, having data transfers
 #[async_trait]
@@ -482,6 +492,20 @@ pub fn other(err: &io::Error) -> bool {
     err.kind() == io::ErrorKind::Other
 }
 
+pub fn write_zero(err: &io::Error) -> bool {
+    err.kind() == io::ErrorKind::WriteZero
+}
+
+///重连退避时间,按次数指数增长,再加上随机抖动,避免一堆客户端同时重连
+pub fn reconnect_delay(attempt: u32) -> Duration {
+    let millis = RECONNECT_BASE_MILLIS
+        .saturating_mul(1_u64 << attempt.min(16))
+        .min(RECONNECT_MAX_MILLIS);
+    let half = millis / 2;
+    let jitter = rand::thread_rng().gen_range(0..=half);
+    Duration::from_millis(half + jitter)
+}
+
 ///TCP client handler, used to extend TCP events
 #[async_trait]
 pub trait ClientHandler: Send + Sync {
@@ -491,100 +515,78 @@ pub trait ClientHandler: Send + Sync {
     async fn on_close(&mut self);
     ///called when have mess from server
     async fn on_message(&mut self, mess: Vec<u8>);
-    ///start read mess from server
+    ///called after on_open when the connection was rebuilt,used to register identity again,
+    ///mess sent by the given sender go out before the mess buffered while disconnected
+    async fn on_reconnect(&mut self, _sender: Sender<Vec<u8>>) {}
+    ///start read mess from server,reconnect with backoff when the connection was closed
     async fn on_read(&mut self, address: String) {
-        let read = new_tcp_client(address.as_str());
-        if let Err(e) = read {
-            error!("{:?}", e);
-            return;
-        }
-        let mut read = read.unwrap();
-        let write = read.try_clone();
-        if let Err(e) = write {
-            error!("{:?}", e);
-            return;
-        }
-        let write = write.unwrap();
+        //sender在重连之间保持不变,断线期间的消息缓存在writer里
         let (sender, rec) = crossbeam::channel::bounded(102400);
+        let writer = Arc::new(Mutex::new(ClientWriter::new()));
         //start reading the sender message
-        read_sender_mess_client(rec, write);
-        //trigger socket open event
-        self.on_open(sender.clone()).await;
+        read_sender_mess_client(rec, writer.clone());
+        //钩子里的注册消息单独走一个channel,不进缓存,比缓存的消息先发
+        let (hook_sender, hook_rec) = crossbeam::channel::unbounded();
+        let mut is_reconnect = false;
         //u8 array,for read data from socket client
         let mut read_bytes: [u8; 51200] = [0; 51200];
-        info!("start read from {:?}", address);
         loop {
-            //start read
-            let size = read.read(&mut read_bytes);
-            match size {
-                Ok(size) => {
-                    if size == 0 {
-                        info!("tcp客户端断开链接！尝试链接服务器！");
-                        self.on_close().await;
-                        break;
-                    }
-                    //如果读取到的字节数大于0则交给handler
-                    if size > 0 {
-                        //读取到字节交给handler处理来处理
-                        let mut v = Vec::new();
-                        v.extend_from_slice(&read_bytes[..size]);
-                        self.on_message(v).await;
-                    }
-                }
-                // Would block "errors" are the OS's way of saying that the
-                // connection is not actually ready to perform this I/O operation.
-                Err(ref err) if would_block(err) => {
-                    continue;
-                }
-                Err(ref err) if interrupted(err) => {
-                    warn!("{:?}", err);
-                    continue;
-                }
-                Err(ref err) if time_out(err) => {
-                    //warn!("{:?}",err);
-                    continue;
-                }
-                Err(ref err)
-                    if reset(err)
-                        | connection_refused(err)
-                        | aborted(err)
-                        | not_connected(err)
-                        | broken_pipe(err) =>
-                {
-                    self.on_close().await;
-                    break;
-                }
-                Err(ref err) if other(err) => {
-                    warn!("{:?}", err);
-                    continue;
-                }
-                // Other errors we'll consider fatal.
-                Err(err) => {
-                    error!("TCP-CLIENT:{:?}", err);
-                    self.on_close().await;
-                    break;
+            let read = new_tcp_client(address.as_str());
+            if let Err(e) = read {
+                error!("{:?}", e);
+                return;
+            }
+            let mut read = read.unwrap();
+            let write = read.try_clone();
+            if let Err(e) = write {
+                error!("{:?}", e);
+                let _ = read.shutdown(Shutdown::Both);
+                std::thread::sleep(reconnect_delay(0));
+                continue;
+            }
+            //先绑定连接,缓存的消息等钩子跑完再补发
+            writer.lock().unwrap().bind(write.unwrap());
+            //trigger socket open event
+            self.on_open(sender.clone()).await;
+            if is_reconnect {
+                self.on_reconnect(hook_sender.clone()).await;
+            }
+            //先发注册消息,再补发缓存,期间别的消息都进缓存排在后面
+            {
+                let mut lock = writer.lock().unwrap();
+                for bytes in hook_rec.try_iter() {
+                    lock.write_hook(bytes);
                 }
+                lock.flush_buffer();
             }
-        }
-    }
-}
-
-///Read the data from the sender of the handler
-fn read_sender_mess_client(rec: Receiver<Vec<u8>>, tcp_stream: std::net::TcpStream) {
-    let mut tcp_stream = tcp_stream;
-    let m = move || loop {
-        let result = rec.recv();
-        match result {
-            Ok(data) => {
-                let bytes = data;
-                let write = tcp_stream.write(&bytes[..]);
-
-                match write {
+            is_reconnect = true;
+            info!("start read from {:?}", address);
+            loop {
+                //start read
+                let size = read.read(&mut read_bytes);
+                match size {
                     Ok(size) => {
                         if size == 0 {
-                            let _ = tcp_stream.shutdown(Shutdown::Both);
+                            info!("tcp客户端断开链接！尝试链接服务器！");
                             break;
                         }
+                        //读取到字节交给handler处理来处理
+                        let mut v = Vec::new();
+                        v.extend_from_slice(&read_bytes[..size]);
+                        self.on_message(v).await;
+                    }
+                    // Would block "errors" are the OS's way of saying that the
+                    // connection is not actually ready to perform this I/O operation.
+                    Err(ref err) if would_block(err) => {
+                        continue;
+                    }
+                    Err(ref err) if interrupted(err) => {
+                        warn!("{:?}", err);
+                        continue;
+                    }
+                    Err(ref err) if time_out(err) => {
+                        //warn!("{:?}",err);
+                        continue;
                     }
                     Err(ref err)
                         if reset(err)
@@ -594,19 +596,136 @@ fn read_sender_mess_client(rec: Receiver<Vec<u8>>, tcp_stream: std::net::TcpStre
                             | broken_pipe(err) =>
                     {
                         warn!("{:?}", err);
-                        let _ = tcp_stream.shutdown(Shutdown::Both);
                         break;
                     }
-                    Err(ref err) => {
-                        error!("{:?}", err);
+                    Err(ref err) if other(err) => {
+                        warn!("{:?}", err);
                         continue;
                     }
-                };
-                let res = tcp_stream.flush();
-                if let Err(e) = res {
-                    error!("{:?}", e);
+                    // Other errors we'll consider fatal.
+                    Err(err) => {
+                        error!("TCP-CLIENT:{:?}", err);
+                        break;
+                    }
                 }
             }
+            //断开了,之后的消息先缓存起来,等重连成功再补发
+            writer.lock().unwrap().unbind();
+            self.on_close().await;
+        }
+    }
+}
+
+///tcp客户端写端,断线期间的消息先缓存起来,重连成功之后补发
+struct ClientWriter {
+    stream: Option<TcpStream>, //当前连接
+    buffer: VecDeque<Vec<u8>>, //断线期间缓存的消息
+    hold: bool,                //刚连上还没补发缓存,这期间的消息都进缓存
+}
+
+impl ClientWriter {
+    fn new() -> Self {
+        ClientWriter {
+            stream: None,
+            buffer: VecDeque::new(),
+            hold: false,
+        }
+    }
+
+    ///绑定新的连接,缓存的消息等flush_buffer的时候再补发
+    fn bind(&mut self, stream: TcpStream) {
+        self.stream = Some(stream);
+        self.hold = true;
+    }
+
+    ///补发断线期间缓存的消息
+    fn flush_buffer(&mut self) {
+        self.hold = false;
+        if self.buffer.is_empty() {
+            return;
+        }
+        info!("重连成功,补发缓存的消息!size:{}", self.buffer.len());
+        while let Some(bytes) = self.buffer.pop_front() {
+            if !self.write_stream(bytes.as_slice()) {
+                self.buffer.push_front(bytes);
+                break;
+            }
+        }
+    }
+
+    ///解绑连接
+    fn unbind(&mut self) {
+        self.hold = false;
+        if let Some(ts) = self.stream.take() {
+            let _ = ts.shutdown(Shutdown::Both);
+        }
+    }
+
+    ///写钩子里的注册消息,不进缓存,连接不可用就丢掉,下次重连钩子会再发
+    fn write_hook(&mut self, bytes: Vec<u8>) {
+        if !self.write_stream(bytes.as_slice()) {
+            warn!("write hook mess failed!size:{}", bytes.len());
+        }
+    }
+
+    ///写消息,没有连接、还没补发完缓存或者写失败就缓存起来,保证顺序
+    fn write(&mut self, bytes: Vec<u8>) {
+        if !self.hold && self.buffer.is_empty() && self.write_stream(bytes.as_slice()) {
+            return;
+        }
+        if self.buffer.len() >= CLIENT_BUFFER_LIMIT {
+            self.buffer.pop_front();
+            warn!(
+                "tcp client buffer is full!drop the oldest mess!limit:{}",
+                CLIENT_BUFFER_LIMIT
+            );
+        }
+        self.buffer.push_back(bytes);
+    }
+
+    ///写到当前连接,连接不可用返回false
+    fn write_stream(&mut self, bytes: &[u8]) -> bool {
+        let tcp_stream = match self.stream.as_mut() {
+            Some(ts) => ts,
+            None => return false,
+        };
+        let res = tcp_stream.write_all(bytes);
+        match res {
+            Ok(_) => {}
+            Err(ref err)
+                if reset(err)
+                    | connection_refused(err)
+                    | aborted(err)
+                    | not_connected(err)
+                    | broken_pipe(err)
+                    | write_zero(err) =>
+            {
+                warn!("{:?}", err);
+                self.unbind();
+                return false;
+            }
+            //其他错误这条消息就丢掉了
+            Err(ref err) => {
+                error!("{:?}", err);
+                return true;
+            }
+        }
+        let res = tcp_stream.flush();
+        if let Err(e) = res {
+            error!("{:?}", e);
+        }
+        true
+    }
+}
+
+///Read the data from the sender of the handler
+fn read_sender_mess_client(rec: Receiver<Vec<u8>>, writer: Arc<Mutex<ClientWriter>>) {
+    let m = move || loop {
+        let result = rec.recv();
+        match result {
+            Ok(bytes) => {
+                writer.lock().unwrap().write(bytes);
+            }
             Err(e) => {
                 error!("{:?}", e);
                 break;
@@ -620,23 +739,25 @@ fn read_sender_mess_client(rec: Receiver<Vec<u8>>, tcp_stream: std::net::TcpStre
 ///new tcp client
 #[warn(unused_assignments)]
 fn new_tcp_client(address: &str) -> anyhow::Result<TcpStream> {
-    let mut ts: Option<std::io::Result<TcpStream>>;
-    let result: Option<TcpStream>;
-    let dur = Duration::from_secs(5);
-    loop {
-        ts = Some(connect(address));
-        let res = ts.unwrap();
-        if let Err(e) = res {
-            error!("连接服务器失败！{:?},{}", address, e.to_string());
-            //睡5s
-            std::thread::sleep(dur);
-            continue;
+    let mut attempt = 0;
+    let result = loop {
+        let res = connect(address);
+        match res {
+            Ok(ts) => break ts,
+            Err(e) => {
+                let dur = reconnect_delay(attempt);
+                error!(
+                    "连接服务器失败！{:?},{},{}ms后重试",
+                    address,
+                    e.to_string(),
+                    dur.as_millis()
+                );
+                std::thread::sleep(dur);
+                attempt += 1;
+            }
         }
-        result = Some(res.unwrap());
-        break;
-    }
+    };
 
-    let result = result.unwrap();
     //设置参数
     set_tream_param(&result)?;
     info!("连接服务器成功！{:?}", address);
@@ -666,3 +787,33 @@ fn connect(address: &str) -> std::io::Result<TcpStream> {
     let ts = TcpStream::connect(address);
     ts
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    #[test]
+    fn client_writer_order() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let mut writer = ClientWriter::new();
+        //断线期间缓存
+        writer.write(b"A".to_vec());
+        writer.bind(TcpStream::connect(addr).unwrap());
+        let (mut server, _) = listener.accept().unwrap();
+        //还没补发缓存,排在缓存后面
+        writer.write(b"B".to_vec());
+        assert_eq!(writer.buffer.len(), 2);
+        //钩子里的注册消息直接发
+        writer.write_hook(b"H".to_vec());
+        writer.flush_buffer();
+        assert!(writer.buffer.is_empty());
+        writer.write(b"C".to_vec());
+        writer.unbind();
+
+        let mut res = String::new();
+        server.read_to_string(&mut res).unwrap();
+        assert_eq!(res, "HABC");
+    }
+}
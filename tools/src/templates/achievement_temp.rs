@@ -0,0 +1,44 @@
+use crate::templates::template::{Template, TemplateMgrTrait};
+use std::collections::HashMap;
+
+///成就配置,grade相框和灵魂头像配置里面的condition填成就id,成就达成的时候解锁
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone)]
+pub struct AchievementTemp {
+    pub id: u32,       //成就id
+    pub condition: u8, //达成条件 1:完成匹配 2:获胜 3:获胜过的角色数量 4:战斗事件累计 5:击杀 6:登录天数
+    pub par: u32,      //条件参数,2:角色id(0代表任意) 4:战斗事件类型
+    pub target: u32,   //目标值
+}
+
+impl Template for AchievementTemp {}
+
+///成就配置管理器
+#[derive(Debug, Default, Clone)]
+pub struct AchievementTempMgr {
+    pub temps: HashMap<u32, AchievementTemp>, //key:id
+}
+
+impl AchievementTempMgr {
+    pub fn init(&mut self, t: Vec<AchievementTemp>) {
+        for tt in t {
+            self.temps.insert(tt.id, tt);
+        }
+    }
+
+    pub fn get_temp(&self, id: &u32) -> anyhow::Result<&AchievementTemp> {
+        match self.temps.get(id) {
+            Some(temp) => Ok(temp),
+            None => anyhow::bail!("AchievementTemp is none for id:{}", id),
+        }
+    }
+}
+
+impl TemplateMgrTrait for AchievementTempMgr {
+    fn is_empty(&self) -> bool {
+        self.temps.is_empty()
+    }
+
+    fn clear(&mut self) {
+        self.temps.clear();
+    }
+}
@@ -6,6 +6,8 @@ use std::collections::HashMap;
 pub struct BattleLimitTimeTemp {
     pub id: u8,
     pub ms: u32,
+    #[serde(default)]
+    pub reserve_ms: u32, //每个玩家的备用时间
 }
 
 impl Template for BattleLimitTimeTemp {}
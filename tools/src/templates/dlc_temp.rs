@@ -0,0 +1,39 @@
+use crate::templates::template::{Template, TemplateMgrTrait};
+use std::collections::HashMap;
+
+///dlc配置,一个dlc可以对应多个平台的商品
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Default)]
+pub struct DlcTemp {
+    pub id: u32,                  //dlc id,对应角色配置里面的is_dlc
+    pub product_ids: Vec<String>, //各平台的商品id,任意一个有权益就算拥有,steam的是steam_{app_id}
+}
+
+impl Template for DlcTemp {}
+
+///dlc配置管理器
+#[derive(Debug, Default, Clone)]
+pub struct DlcTempMgr {
+    pub temps: HashMap<u32, DlcTemp>,
+}
+
+impl DlcTempMgr {
+    pub fn init(&mut self, t: Vec<DlcTemp>) {
+        for tt in t {
+            self.temps.insert(tt.id, tt);
+        }
+    }
+
+    pub fn get_temp_ref(&self, id: &u32) -> Option<&DlcTemp> {
+        self.temps.get(id)
+    }
+}
+
+impl TemplateMgrTrait for DlcTempMgr {
+    fn is_empty(&self) -> bool {
+        self.temps.is_empty()
+    }
+
+    fn clear(&mut self) {
+        self.temps.clear();
+    }
+}
@@ -4,7 +4,7 @@ use std::collections::HashMap;
 #[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone)]
 pub struct GradeFrameTemp {
     pub id: u32,
-    pub condition: u32,
+    pub condition: u32, //解锁条件,大于0代表达成对应成就解锁
 }
 
 impl Template for GradeFrameTemp {}
@@ -1,8 +1,10 @@
+pub mod achievement_temp;
 pub mod battle_limit_time_temp;
 pub mod buff_temp;
 pub mod cell_temp;
 pub mod character_temp;
 pub mod constant_temp;
+pub mod dlc_temp;
 pub mod emoji_temp;
 pub mod grade_frame_temp;
 pub mod item_temp;
@@ -11,6 +13,8 @@ pub mod market_temp;
 pub mod merchandise_temp;
 pub mod mission_temp;
 pub mod punish_temp;
+pub mod quest_temp;
+pub mod robot_profile_temp;
 pub mod robot_temp;
 pub mod season_temp;
 pub mod skill_judge_temp;
@@ -0,0 +1,63 @@
+use crate::templates::template::{Template, TemplateMgrTrait};
+use rand::seq::SliceRandom;
+use std::collections::HashMap;
+
+///局外任务配置
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone)]
+pub struct QuestTemp {
+    pub id: u32,           //任务id
+    pub quest_type: u8,    //任务类型 1:每日 2:每周
+    pub condition: u8,     //完成条件 1:完成匹配 2:用指定角色完成匹配 3:击杀 4:排名达到
+    pub par: u32,          //条件参数,2:角色id(0代表任意) 4:名次
+    pub target: u32,       //目标值
+    pub reward_type: u8,   //奖励类型,和邮件附件类型一致
+    pub reward_id: u32,    //奖励配置id
+    pub reward_count: u32, //奖励数量
+}
+
+impl Template for QuestTemp {}
+
+///局外任务配置管理器
+#[derive(Debug, Default, Clone)]
+pub struct QuestTempMgr {
+    pub temps: HashMap<u32, QuestTemp>, //key:id
+    pub pools: HashMap<u8, Vec<u32>>,   //任务池 key:任务类型 value:任务id
+}
+
+impl QuestTempMgr {
+    pub fn init(&mut self, t: Vec<QuestTemp>) {
+        for tt in t {
+            self.pools.entry(tt.quest_type).or_default().push(tt.id);
+            self.temps.insert(tt.id, tt);
+        }
+    }
+
+    pub fn get_temp(&self, id: &u32) -> anyhow::Result<&QuestTemp> {
+        match self.temps.get(id) {
+            Some(temp) => Ok(temp),
+            None => anyhow::bail!("QuestTemp is none for id:{}", id),
+        }
+    }
+
+    ///从任务池里随机抽count个不重复的任务
+    pub fn random_quests(&self, quest_type: u8, count: usize) -> Vec<u32> {
+        match self.pools.get(&quest_type) {
+            Some(pool) => pool
+                .choose_multiple(&mut rand::thread_rng(), count)
+                .copied()
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+impl TemplateMgrTrait for QuestTempMgr {
+    fn is_empty(&self) -> bool {
+        self.temps.is_empty()
+    }
+
+    fn clear(&mut self) {
+        self.temps.clear();
+        self.pools.clear();
+    }
+}
@@ -0,0 +1,87 @@
+use crate::templates::template::{Template, TemplateMgrTrait};
+use std::collections::HashMap;
+
+///目标偏好:血量最多的
+pub const TARGET_PREFERENCE_HP_MAX: u8 = 0;
+///目标偏好:血量最少的
+pub const TARGET_PREFERENCE_HP_MIN: u8 = 1;
+///目标偏好:领先的玩家(金币最多)
+pub const TARGET_PREFERENCE_LEADER: u8 = 2;
+
+///机器人性格难度配置
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct RobotProfileTemp {
+    pub id: u32,
+    pub aggression: u8,          //攻击欲望,0-100,越大越喜欢打人
+    pub risk_tolerance: u8,      //冒险程度,0-100,越大越敢翻未知地图块
+    pub remember_size: u32,      //记忆队列长度
+    pub mistake_rate: u8,        //失误率,0-100,失误的时候随便挑一个能做的事情
+    pub think_delay: Vec<u64>,   //思考延迟范围(毫秒)[最小,最大]
+    pub shop_priority: Vec<u32>, //购物优先级,商品id按优先级排列
+    pub target_preference: u8,   //攻击目标偏好
+    #[serde(default)]
+    pub plan_level: u8, //推演等级,0:不推演
+}
+
+impl Default for RobotProfileTemp {
+    fn default() -> Self {
+        RobotProfileTemp {
+            id: 0,
+            aggression: 50,
+            risk_tolerance: 50,
+            remember_size: 3,
+            mistake_rate: 0,
+            think_delay: vec![5000, 5000],
+            shop_priority: vec![],
+            target_preference: TARGET_PREFERENCE_HP_MAX,
+            plan_level: 0,
+        }
+    }
+}
+
+impl Template for RobotProfileTemp {}
+
+impl RobotProfileTemp {
+    ///思考延迟的范围
+    pub fn think_delay_range(&self) -> (u64, u64) {
+        let min = self.think_delay.get(0).copied().unwrap_or(5000);
+        let max = self.think_delay.get(1).copied().unwrap_or(min);
+        (min, std::cmp::max(min, max))
+    }
+
+    ///商品的购买优先级,越小越优先,没配置的排最后
+    pub fn shop_priority_of(&self, merchandise_id: u32) -> usize {
+        self.shop_priority
+            .iter()
+            .position(|&id| id == merchandise_id)
+            .unwrap_or(self.shop_priority.len())
+    }
+}
+
+///机器人性格难度配置管理器
+#[derive(Debug, Default, Clone)]
+pub struct RobotProfileTempMgr {
+    pub temps: HashMap<u32, RobotProfileTemp>,
+}
+
+impl RobotProfileTempMgr {
+    pub fn init(&mut self, t: Vec<RobotProfileTemp>) {
+        for tt in t {
+            self.temps.insert(tt.id, tt);
+        }
+    }
+
+    pub fn get_temp_ref(&self, id: &u32) -> Option<&RobotProfileTemp> {
+        self.temps.get(id)
+    }
+}
+
+impl TemplateMgrTrait for RobotProfileTempMgr {
+    fn is_empty(&self) -> bool {
+        self.temps.is_empty()
+    }
+
+    fn clear(&mut self) {
+        self.temps.clear();
+    }
+}
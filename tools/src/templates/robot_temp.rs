@@ -8,6 +8,10 @@ pub struct RobotTemp {
     pub cter_id: u32,
     pub skills: Vec<u32>,
     pub merchandises: Vec<u32>,
+    #[serde(default)]
+    pub level: u8, //难度等级,0:按固定期望值仲裁,大于0:推演搜索,越大推演越深
+    #[serde(default)]
+    pub profile: u32, //性格难度配置id,0:跟随房间ai等级
 }
 
 impl Template for RobotTemp {}
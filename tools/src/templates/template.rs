@@ -29,11 +29,17 @@ use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
 
+use super::achievement_temp::{AchievementTemp, AchievementTempMgr};
+use super::dlc_temp::{DlcTemp, DlcTempMgr};
 use super::market_temp::MarketTemp;
 use super::market_temp::MarketTempMgr;
 use super::merchandise_temp::{MerchandiseTemp, MerchandiseTempMgr};
 use super::mission_temp::{MissionTemp, MissionTempMgr};
-use super::template_name_constants::{MARKET, MERCHANDISE, MISSION, WORLD_BOSS};
+use super::quest_temp::{QuestTemp, QuestTempMgr};
+use super::robot_profile_temp::{RobotProfileTemp, RobotProfileTempMgr};
+use super::template_name_constants::{
+    ACHIEVEMENT, DLC, MARKET, MERCHANDISE, MISSION, QUEST, ROBOT_PROFILE, WORLD_BOSS,
+};
 use super::world_boss_temp::{WorldBossTemp, WorldBossTempMgr};
 
 pub trait Template {}
@@ -69,6 +75,10 @@ pub struct TemplatesMgr {
     merchandise_temp_mgr: MerchandiseTempMgr,           //商品配置
     mission_temp_mgr: MissionTempMgr,                   //任务配置
     worldboss_temp_mgr: WorldBossTempMgr,               //worldboss
+    robot_profile_temp_mgr: RobotProfileTempMgr,        //机器人性格难度配置
+    dlc_temp_mgr: DlcTempMgr,                           //dlc配置
+    quest_temp_mgr: QuestTempMgr,                       //局外任务配置
+    achievement_temp_mgr: AchievementTempMgr,           //成就配置
 }
 
 impl TemplatesMgr {
@@ -103,6 +113,10 @@ impl TemplatesMgr {
             mgr_mut.merchandise_temp_mgr.clear();
             mgr_mut.mission_temp_mgr.clear();
             mgr_mut.worldboss_temp_mgr.clear();
+            mgr_mut.robot_profile_temp_mgr.clear();
+            mgr_mut.dlc_temp_mgr.clear();
+            mgr_mut.quest_temp_mgr.clear();
+            mgr_mut.achievement_temp_mgr.clear();
             let res = read_templates_from_dir(path, mgr_mut);
             if let Err(e) = res {
                 error!("{:?}", e);
@@ -226,6 +240,26 @@ impl TemplatesMgr {
     pub fn worldboss_temp_mgr(&self) -> &WorldBossTempMgr {
         &self.worldboss_temp_mgr
     }
+
+    /// Get a reference to the templates mgr's robot profile temp mgr.
+    pub fn robot_profile_temp_mgr(&self) -> &RobotProfileTempMgr {
+        &self.robot_profile_temp_mgr
+    }
+
+    /// Get a reference to the templates mgr's dlc temp mgr.
+    pub fn dlc_temp_mgr(&self) -> &DlcTempMgr {
+        &self.dlc_temp_mgr
+    }
+
+    /// Get a reference to the templates mgr's quest temp mgr.
+    pub fn quest_temp_mgr(&self) -> &QuestTempMgr {
+        &self.quest_temp_mgr
+    }
+
+    /// Get a reference to the templates mgr's achievement temp mgr.
+    pub fn achievement_temp_mgr(&self) -> &AchievementTempMgr {
+        &self.achievement_temp_mgr
+    }
 }
 
 pub fn init_temps_mgr(path: &str) -> TemplatesMgr {
@@ -333,5 +367,17 @@ fn init_temps(temps_mgr: &mut TemplatesMgr, name: String, context: &str) {
     } else if name.eq_ignore_ascii_case(WORLD_BOSS) {
         let v: Vec<WorldBossTemp> = serde_json::from_str(context).unwrap();
         temps_mgr.worldboss_temp_mgr.init(v);
+    } else if name.eq_ignore_ascii_case(ROBOT_PROFILE) {
+        let v: Vec<RobotProfileTemp> = serde_json::from_str(context).unwrap();
+        temps_mgr.robot_profile_temp_mgr.init(v);
+    } else if name.eq_ignore_ascii_case(DLC) {
+        let v: Vec<DlcTemp> = serde_json::from_str(context).unwrap();
+        temps_mgr.dlc_temp_mgr.init(v);
+    } else if name.eq_ignore_ascii_case(QUEST) {
+        let v: Vec<QuestTemp> = serde_json::from_str(context).unwrap();
+        temps_mgr.quest_temp_mgr.init(v);
+    } else if name.eq_ignore_ascii_case(ACHIEVEMENT) {
+        let v: Vec<AchievementTemp> = serde_json::from_str(context).unwrap();
+        temps_mgr.achievement_temp_mgr.init(v);
     }
 }
@@ -43,3 +43,11 @@ pub const MISSION: &str = "Mission";
 pub const MERCHANDISE: &str = "Merchandise";
 
 pub const WORLD_BOSS: &str = "WorldBoss";
+
+pub const ROBOT_PROFILE: &str = "RobotProfile";
+
+pub const DLC: &str = "Dlc";
+
+pub const QUEST: &str = "Quest";
+
+pub const ACHIEVEMENT: &str = "Achievement";
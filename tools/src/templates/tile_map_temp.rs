@@ -1,3 +1,4 @@
+use crate::templates::skill_scope_temp::CoordTemp;
 use crate::templates::template::{Template, TemplateMgrTrait};
 use anyhow::Result;
 use std::collections::HashMap;
@@ -14,10 +15,32 @@ pub struct TileMapTemp {
     pub member_count_key: u8,
     pub market_index: usize,
     pub market_id: u32,
+    #[serde(default = "default_width")]
+    pub width: u32, //地图宽(x轴格子数)
+    #[serde(default = "default_height")]
+    pub height: u32, //地图高(y轴格子数)
+    #[serde(default = "default_layout")]
+    pub layout: Vec<CoordTemp>, //相邻格子的坐标偏移
 }
 
 impl Template for TileMapTemp {}
 
+pub fn default_width() -> u32 {
+    6
+}
+
+pub fn default_height() -> u32 {
+    5
+}
+
+///默认六边形地图一圈的偏移
+pub fn default_layout() -> Vec<CoordTemp> {
+    [(0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1)]
+        .iter()
+        .map(|&(x, y)| CoordTemp { x, y })
+        .collect()
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone)]
 pub struct CellRare {
     pub rare: u16,
@@ -8,10 +8,35 @@ pub struct WorldBossTemp {
     pub robot_id: u32,
     pub map_ids: Vec<u32>,
     pub turn_order_index: usize,
+    #[serde(default)]
+    pub hp: u64, //全服共享血量
+    #[serde(default)]
+    pub milestones: Vec<u32>, //需要广播的血量节点(剩余百分比)
+    #[serde(default)]
+    pub rewards: Vec<WorldBossRewardTemp>, //伤害排行奖励,按顺序取第一个满足条件的
 }
 
 impl Template for WorldBossTemp {}
 
+///世界boss奖励档位
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone)]
+#[serde(default)]
+pub struct WorldBossRewardTemp {
+    pub rank: u32,              //名次上限(包含),0代表所有参与者
+    pub need_kill: bool,        //是否需要boss被击杀才发
+    pub grade_frames: Vec<u32>, //奖励grade相框
+    pub souls: Vec<u32>,        //奖励灵魂头像
+}
+
+impl WorldBossTemp {
+    ///根据名次和是否击杀找奖励档位
+    pub fn get_reward(&self, rank: u32, killed: bool) -> Option<&WorldBossRewardTemp> {
+        self.rewards
+            .iter()
+            .find(|r| (!r.need_kill || killed) && (r.rank == 0 || rank <= r.rank))
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct WorldBossTempMgr {
     pub temps: HashMap<u32, WorldBossTemp>,
@@ -23,6 +48,10 @@ impl WorldBossTempMgr {
             self.temps.insert(tt.cter_id, tt);
         }
     }
+
+    pub fn get_temp_ref(&self, cter_id: &u32) -> Option<&WorldBossTemp> {
+        self.temps.get(cter_id)
+    }
 }
 
 impl TemplateMgrTrait for WorldBossTempMgr {
@@ -34,3 +63,40 @@ impl TemplateMgrTrait for WorldBossTempMgr {
         self.temps.clear();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reward(rank: u32, need_kill: bool, soul: u32) -> WorldBossRewardTemp {
+        WorldBossRewardTemp {
+            rank,
+            need_kill,
+            souls: vec![soul],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn get_reward() {
+        let temp = WorldBossTemp {
+            rewards: vec![
+                reward(1, true, 1),
+                reward(10, false, 2),
+                reward(0, false, 3),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(temp.get_reward(1, true).unwrap().souls, vec![1]);
+        //没击杀拿不到击杀档位
+        assert_eq!(temp.get_reward(1, false).unwrap().souls, vec![2]);
+        assert_eq!(temp.get_reward(10, true).unwrap().souls, vec![2]);
+        assert_eq!(temp.get_reward(11, false).unwrap().souls, vec![3]);
+        let temp = WorldBossTemp {
+            rewards: vec![reward(1, true, 1)],
+            ..Default::default()
+        };
+        assert!(temp.get_reward(2, true).is_none());
+        assert!(temp.get_reward(1, false).is_none());
+    }
+}
@@ -0,0 +1,78 @@
+use crate::util::packet::Packet;
+use rand::Rng;
+use std::cell::Cell;
+use tracing::field::Empty;
+use tracing::span::EnteredSpan;
+use tracing::Span;
+
+pub use tracing::Instrument;
+
+thread_local! {
+    ///当前线程正在处理的包的trace id,处理过程中新建的包都会带上它
+    static CURRENT_TRACE_ID: Cell<u64> = Cell::new(0);
+}
+
+///生成新的trace id,gate收到客户端包的时候调用
+pub fn new_trace_id() -> u64 {
+    let mut rng = rand::thread_rng();
+    loop {
+        let id: u64 = rng.gen();
+        if id != 0 {
+            return id;
+        }
+    }
+}
+
+///当前线程的trace id,没有就是0
+pub fn current_trace_id() -> u64 {
+    CURRENT_TRACE_ID.with(|c| c.get())
+}
+
+///处理一个包时创建的span,room_id和battle_id等拿到了再调用record_xxx填上
+pub fn packet_span(packet: &Packet) -> Span {
+    tracing::info_span!(
+        "packet",
+        trace_id = %format!("{:016x}", packet.get_trace_id()),
+        cmd = packet.get_cmd(),
+        user_id = packet.get_user_id(),
+        room_id = Empty,
+        battle_id = Empty,
+    )
+}
+
+///进入一个包的处理范围,期间新建的包会继承这个包的trace id,drop的时候还原
+pub struct TraceScope {
+    _span: EnteredSpan,
+    prev_trace_id: u64,
+}
+
+impl Drop for TraceScope {
+    fn drop(&mut self) {
+        let prev = self.prev_trace_id;
+        CURRENT_TRACE_ID.with(|c| c.set(prev));
+    }
+}
+
+///进入一个包的处理范围,只能在同步代码里用,不要跨await持有
+pub fn enter(packet: &Packet) -> TraceScope {
+    let prev_trace_id = CURRENT_TRACE_ID.with(|c| c.replace(packet.get_trace_id()));
+    TraceScope {
+        _span: packet_span(packet).entered(),
+        prev_trace_id,
+    }
+}
+
+///给当前span记上房间id
+pub fn record_room_id(room_id: u32) {
+    Span::current().record("room_id", &room_id);
+}
+
+///给当前span记上战斗id
+pub fn record_battle_id(battle_id: u32) {
+    Span::current().record("battle_id", &battle_id);
+}
+
+///给当前span记上玩家id
+pub fn record_user_id(user_id: u32) {
+    Span::current().record("user_id", &user_id);
+}
@@ -175,6 +175,10 @@ pub mod packet {
     use std::collections::VecDeque;
 
     use crate::util::bytebuf::ByteBuf;
+
+    ///服务器之间通信的包头长度
+    pub const SERVER_HEADER_LEN: u32 = 26;
+
     #[derive(Debug, Default, Clone)]
     pub struct PacketDes {
         cmd: u32,
@@ -183,6 +187,7 @@ pub mod packet {
         is_broad: bool,    //是否需要广播
         is_client: bool,   //是否客户端
         server_token: u32, //服务器对应的token
+        trace_id: u64,     //链路追踪id,gate收到客户端包的时候生成,服务器之间转发一直带着
     }
 
     #[derive(Debug, Default, Clone)]
@@ -200,6 +205,7 @@ pub mod packet {
                 is_broad: false,
                 is_client: true,
                 server_token: 0,
+                trace_id: crate::trace::current_trace_id(),
             }
         }
     }
@@ -239,6 +245,15 @@ pub mod packet {
             self.packet_des.server_token
         }
 
+        pub fn set_trace_id(&mut self, trace_id: u64) {
+            self.packet_des.trace_id = trace_id;
+        }
+
+        ///获得trace id
+        pub fn get_trace_id(&self) -> u64 {
+            self.packet_des.trace_id
+        }
+
         pub fn set_is_client(&mut self, is_client: bool) {
             self.packet_des.is_client = is_client;
         }
@@ -257,13 +272,15 @@ pub mod packet {
                 let is_client = bb.read_u8()? != 0;
                 let is_broad = bb.read_u8()? != 0;
                 let server_token = bb.read_u32()?;
-                let body_size = len - 18;
+                let trace_id = bb.read_u64()?;
+                let body_size = len - SERVER_HEADER_LEN;
                 let mut packet = Packet::new(cmd, len, user_id);
                 packet.set_user_id(user_id);
                 packet.set_cmd(cmd);
                 packet.set_is_client(is_client);
                 packet.set_is_broad(is_broad);
                 packet.set_server_token(server_token);
+                packet.set_trace_id(trace_id);
                 if body_size > 0 {
                     packet.set_data(bb.read_bytes_size(body_size as usize)?);
                 }
@@ -302,10 +319,12 @@ pub mod packet {
             let is_client = bb.read_u8()? != 0;
             let is_broad = bb.read_u8()? != 0;
             let server_token = bb.read_u32()?;
+            let trace_id = bb.read_u64()?;
             let mut packet = Packet::new(cmd, 0, user_id);
             packet.set_is_client(is_client);
             packet.set_is_broad(is_broad);
             packet.set_server_token(server_token);
+            packet.set_trace_id(trace_id);
             packet.set_data(bb.read_bytes_size(len as usize)?);
             Ok(packet)
         }
@@ -406,11 +425,12 @@ pub mod packet {
         pub fn to_server_bytebuf(&self) -> ByteBuf {
             let mut bb = ByteBuf::new();
             bb.push_u32(self.get_cmd());
-            bb.push_u32(18 + self.get_data().len() as u32);
+            bb.push_u32(SERVER_HEADER_LEN + self.get_data().len() as u32);
             bb.push_u32(self.get_user_id());
             bb.push(self.is_client() as u8);
             bb.push(self.is_broad() as u8);
             bb.push_u32(self.get_server_token());
+            bb.push_u64(self.get_trace_id());
             bb.push_array(self.get_data());
             bb
         }